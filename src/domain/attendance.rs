@@ -0,0 +1,165 @@
+use chrono::{NaiveDate, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::employee_import::ImportRowStatus;
+
+/// Minutes clock punches can be rounded to.
+pub const PUNCH_ROUNDING_INCREMENTS: [u32; 3] = [5, 10, 15];
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// How a payroll adjusts clock punches before turning them into timesheet hours.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct PunchPolicy {
+    pub payroll_id: Uuid,
+    /// Punches are rounded to the nearest multiple of these minutes; one of 5, 10 or 15.
+    pub rounding_minutes: Option<u32>,
+    /// A clock-in up to this many minutes after a rounding increment is moved back to it,
+    /// and a clock-out up to this many minutes before one is moved forward to it.
+    pub grace_minutes: u32,
+}
+
+impl PunchPolicy {
+    /// Applied when a payroll has not configured punch rules: punches are used as recorded.
+    pub fn standard(payroll_id: Uuid) -> Self {
+        Self {
+            payroll_id,
+            rounding_minutes: None,
+            grace_minutes: 0,
+        }
+    }
+
+    pub fn adjust_clock_in(&self, time: NaiveTime) -> NaiveTime {
+        self.adjust(time, true)
+    }
+
+    pub fn adjust_clock_out(&self, time: NaiveTime) -> NaiveTime {
+        self.adjust(time, false)
+    }
+
+    /// Rounds `time` to the nearest increment unless the grace period pulls a clock-in back
+    /// or a clock-out forward; a clock-out rounded up to midnight wraps to `00:00`.
+    fn adjust(&self, time: NaiveTime, clock_in: bool) -> NaiveTime {
+        let Some(minutes) = self.rounding_minutes.filter(|minutes| *minutes > 0) else {
+            return time;
+        };
+        let increment = minutes * 60;
+        let grace = self.grace_minutes * 60;
+        let seconds = time.num_seconds_from_midnight();
+        let offset = seconds % increment;
+        let previous = seconds - offset;
+        let round_back = if clock_in && offset <= grace {
+            true
+        } else if !clock_in && offset > 0 && increment - offset <= grace {
+            false
+        } else {
+            offset * 2 < increment
+        };
+        let rounded = if round_back {
+            previous
+        } else {
+            previous + increment
+        };
+
+        NaiveTime::from_num_seconds_from_midnight_opt(rounded % SECONDS_PER_DAY, 0).unwrap_or(time)
+    }
+}
+
+/// One clock-in/clock-out pair exported by a time-clock system.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct ClockRecord {
+    /// Employee `id_number` the time clock knows them by.
+    pub id_number: String,
+    /// `YYYY-MM-DD`.
+    pub date: String,
+    /// `HH:MM` or `HH:MM:SS`.
+    pub clock_in: String,
+    /// `HH:MM` or `HH:MM:SS`; earlier than `clock_in` for shifts past midnight.
+    pub clock_out: String,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttendanceAnomalyKind {
+    /// A scheduled working day without any punches.
+    MissingDay,
+    /// Punches on a day the schedule has off.
+    UnscheduledDay,
+    /// Worked hours beyond the daily or weekly overtime threshold.
+    ExcessiveOvertime,
+}
+
+/// Something in the imported attendance that needs a reviewer's attention.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct AttendanceAnomaly {
+    pub employee_id: Uuid,
+    pub kind: AttendanceAnomalyKind,
+    /// The day concerned, or the Monday of the week for weekly overtime.
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    /// Hours worked on the day or week, when any.
+    pub hours: Option<f64>,
+    pub detail: String,
+}
+
+/// Outcome of importing a single clock record.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct AttendanceImportRow {
+    /// CSV line, or position in the JSON array starting at 1.
+    pub line: u64,
+    pub status: ImportRowStatus,
+    pub employee_id: Option<Uuid>,
+    /// Timesheet entry created for the shift.
+    pub entry_id: Option<Uuid>,
+    /// Entry holding the hours an overnight shift worked after midnight.
+    pub next_day_entry_id: Option<Uuid>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct AttendanceImportReport {
+    pub imported: usize,
+    pub failed: usize,
+    pub rows: Vec<AttendanceImportRow>,
+    /// Findings from reconciling the period against each employee's schedule.
+    pub anomalies: Vec<AttendanceAnomaly>,
+}
+
+impl AttendanceImportReport {
+    pub fn record_imported(
+        &mut self,
+        line: u64,
+        employee_id: Uuid,
+        entry_id: Uuid,
+        next_day_entry_id: Option<Uuid>,
+    ) {
+        self.imported += 1;
+        self.rows.push(AttendanceImportRow {
+            line,
+            status: ImportRowStatus::Created,
+            employee_id: Some(employee_id),
+            entry_id: Some(entry_id),
+            next_day_entry_id,
+            error: None,
+        });
+    }
+
+    pub fn record_failed(
+        &mut self,
+        line: u64,
+        employee_id: Option<Uuid>,
+        error: impl Into<String>,
+    ) {
+        self.failed += 1;
+        self.rows.push(AttendanceImportRow {
+            line,
+            status: ImportRowStatus::Failed,
+            employee_id,
+            entry_id: None,
+            next_day_entry_id: None,
+            error: Some(error.into()),
+        });
+    }
+}
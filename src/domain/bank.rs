@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -8,6 +9,22 @@ pub struct Bank {
     pub id: Uuid,
     pub name: String,
     pub organization_id: Uuid,
+    /// 8 or 11 character SWIFT/BIC code.
+    pub swift_code: Option<String>,
+    /// National routing number or sort code, without separators.
+    pub routing_number: Option<String>,
+    /// Two-letter ISO 3166-1 country code.
+    pub country: Option<String>,
+    pub address: Option<String>,
+    /// Checks applied to account numbers of employees paid through this bank.
+    pub account_rule: Option<AccountRule>,
+    /// File format payment batches for this bank are rendered in.
+    pub payment_format: Option<PaymentFormat>,
+    /// Catalog entry the SWIFT code, routing number and country are inherited from.
+    pub catalog_id: Option<Uuid>,
+    /// Set when the bank's organization was soft-deleted; hidden until it is restored.
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 impl Bank {
@@ -16,6 +33,150 @@ impl Bank {
             id,
             name: name.into(),
             organization_id,
+            swift_code: None,
+            routing_number: None,
+            country: None,
+            address: None,
+            account_rule: None,
+            payment_format: None,
+            catalog_id: None,
+            archived_at: None,
         }
     }
 }
+
+/// A national bank recorded once for all organizations. Organization banks linked to an
+/// entry inherit its SWIFT code, routing number and country, and follow later changes.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct CatalogBank {
+    pub id: Uuid,
+    pub name: String,
+    pub swift_code: Option<String>,
+    pub routing_number: Option<String>,
+    pub country: Option<String>,
+}
+
+/// Layout of the payment file a bank accepts.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PaymentFormat {
+    /// ISO 20022 pain.001 SEPA credit transfer; the bank needs a SWIFT/BIC code.
+    Sepa,
+    /// NACHA ACH file; the bank needs a US ABA routing number.
+    Nacha,
+    /// CSV laid out by an organization-defined template.
+    CustomCsv { template_id: Uuid },
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountChecksum {
+    /// ISO 13616 IBAN: country code, two check digits and an account that is 1 mod 97.
+    IbanMod97,
+    /// Luhn mod-10 over an all-digit account number.
+    Luhn,
+}
+
+/// Shape an account number must have to be accepted by a bank. Checks run on the compact
+/// form: upper-cased, without spaces or hyphens.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct AccountRule {
+    pub min_length: Option<u32>,
+    pub max_length: Option<u32>,
+    /// The account has to start with one of these; any start is accepted when empty.
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+    pub checksum: Option<AccountChecksum>,
+}
+
+impl AccountRule {
+    /// Upper-cases `account` and drops spaces and hyphens.
+    pub fn compact(account: &str) -> String {
+        account
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-'))
+            .collect::<String>()
+            .to_ascii_uppercase()
+    }
+
+    /// Returns the compact account, or why the rule rejects it.
+    pub fn check(&self, account: &str) -> Result<String, String> {
+        let account = Self::compact(account);
+        let length = account.chars().count() as u32;
+        if let Some(min_length) = self.min_length
+            && length < min_length
+        {
+            return Err(format!("must be at least {min_length} characters long"));
+        }
+        if let Some(max_length) = self.max_length
+            && length > max_length
+        {
+            return Err(format!("must be at most {max_length} characters long"));
+        }
+        if !self.prefixes.is_empty()
+            && !self
+                .prefixes
+                .iter()
+                .any(|prefix| account.starts_with(prefix.as_str()))
+        {
+            return Err(format!("must start with {}", self.prefixes.join(", ")));
+        }
+        match self.checksum {
+            Some(AccountChecksum::IbanMod97) if !iban_checksum_matches(&account) => {
+                Err("fails the IBAN mod-97 check".to_string())
+            }
+            Some(AccountChecksum::Luhn) if !luhn_checksum_matches(&account) => {
+                Err("fails the Luhn check".to_string())
+            }
+            _ => Ok(account),
+        }
+    }
+}
+
+/// Moves the country code and check digits to the end, spells letters as numbers (A = 10)
+/// and requires the result to be 1 modulo 97.
+fn iban_checksum_matches(iban: &str) -> bool {
+    let bytes = iban.as_bytes();
+    if bytes.len() < 5
+        || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+        || !bytes[2..4].iter().all(u8::is_ascii_digit)
+        || !bytes.iter().all(u8::is_ascii_alphanumeric)
+    {
+        return false;
+    }
+
+    let mut remainder = 0u32;
+    for byte in bytes[4..].iter().chain(&bytes[..4]) {
+        let value = if byte.is_ascii_digit() {
+            u32::from(byte - b'0')
+        } else {
+            u32::from(byte - b'A') + 10
+        };
+        let scale = if value >= 10 { 100 } else { 10 };
+        remainder = (remainder * scale + value) % 97;
+    }
+    remainder == 1
+}
+
+/// Doubles every second digit from the right and requires the sum to be a multiple of ten.
+fn luhn_checksum_matches(number: &str) -> bool {
+    if number.is_empty() || !number.bytes().all(|byte| byte.is_ascii_digit()) {
+        return false;
+    }
+
+    number
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(position, byte)| {
+            let digit = u32::from(byte - b'0');
+            if position % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum::<u32>()
+        .is_multiple_of(10)
+}
@@ -0,0 +1,50 @@
+/// Columns of the benchmarking export, in output order.
+pub const BENCHMARK_COLUMNS: [&str; 8] = [
+    "grade_band",
+    "grade_level",
+    "grade_code",
+    "region",
+    "currency",
+    // Annual pay band; `pay_band_to` is exclusive.
+    "pay_band_from",
+    "pay_band_to",
+    "headcount",
+];
+
+/// Width of the annual pay bands employees are counted in, unless the caller asks otherwise.
+pub const DEFAULT_PAY_BAND_WIDTH: u32 = 5_000;
+
+/// One cell of the export: the grade, region, currency and pay band an employee falls in.
+/// Nothing in it points back to an individual.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BenchmarkBucket {
+    pub grade_band: String,
+    pub grade_level: Option<u32>,
+    pub grade_code: String,
+    pub region: String,
+    pub currency: String,
+    pub pay_band_from: u64,
+}
+
+impl BenchmarkBucket {
+    /// Lower bound of the `width`-wide band `annual_pay` falls in.
+    pub fn band_floor(annual_pay: f64, width: u32) -> u64 {
+        let width = u64::from(width);
+        (annual_pay.max(0.0) as u64 / width) * width
+    }
+
+    pub fn row(&self, width: u32, headcount: usize) -> Vec<String> {
+        vec![
+            self.grade_band.clone(),
+            self.grade_level
+                .map(|level| level.to_string())
+                .unwrap_or_default(),
+            self.grade_code.clone(),
+            self.region.clone(),
+            self.currency.clone(),
+            self.pay_band_from.to_string(),
+            (self.pay_band_from + u64::from(width)).to_string(),
+            headcount.to_string(),
+        ]
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Binary object (photos, logos, documents) together with its media type.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Blob {
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+impl Blob {
+    pub fn new(content_type: impl Into<String>, data: Vec<u8>) -> Self {
+        Self {
+            content_type: content_type.into(),
+            data,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Longest range, in days, one calendar request may cover.
+pub const MAX_CALENDAR_RANGE_DAYS: i64 = 366;
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CalendarEventKind {
+    FiscalYearStart,
+    /// A pending salary adjustment or leave request that has to be decided by this date.
+    ApprovalDeadline,
+    SalaryAdjustment,
+    Leave,
+    LeaveBlackout,
+    ContractEnd,
+    ProbationEnd,
+    WorkPermitExpiry,
+}
+
+/// One dated entry of the organization calendar. Multi-day events carry an `end_date`.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct CalendarEvent {
+    pub kind: CalendarEventKind,
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    /// Last day of the event, inclusive; `None` for single-day events.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub end_date: Option<NaiveDate>,
+    pub title: String,
+    pub payroll_id: Option<Uuid>,
+    pub division_id: Option<Uuid>,
+    pub employee_id: Option<Uuid>,
+    /// The salary adjustment batch, leave request or blackout the event comes from.
+    pub source_id: Option<Uuid>,
+}
+
+impl CalendarEvent {
+    pub fn on(kind: CalendarEventKind, date: NaiveDate, title: impl Into<String>) -> Self {
+        Self {
+            kind,
+            date,
+            end_date: None,
+            title: title.into(),
+            payroll_id: None,
+            division_id: None,
+            employee_id: None,
+            source_id: None,
+        }
+    }
+
+    /// Whether any day of the event falls between `from` and `to`, inclusive.
+    pub fn overlaps(&self, from: NaiveDate, to: NaiveDate) -> bool {
+        self.date <= to && self.end_date.unwrap_or(self.date) >= from
+    }
+}
+
+/// Everything scheduled across an organization between `from` and `to`, by date.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct OrganizationCalendar {
+    pub organization_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub to: NaiveDate,
+    pub events: Vec<CalendarEvent>,
+}
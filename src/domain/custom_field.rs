@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Value kinds accepted for organization-defined employee attributes.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    Boolean,
+    /// `YYYY-MM-DD` string.
+    Date,
+}
+
+/// Extra employee attribute declared by an organization (e.g. union code).
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct CustomFieldDefinition {
+    pub id: Uuid,
+    pub name: String,
+    pub field_type: CustomFieldType,
+    pub required: bool,
+    pub organization_id: Uuid,
+}
+
+impl CustomFieldDefinition {
+    pub fn new(
+        id: Uuid,
+        name: impl Into<String>,
+        field_type: CustomFieldType,
+        required: bool,
+        organization_id: Uuid,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            field_type,
+            required,
+            organization_id,
+        }
+    }
+}
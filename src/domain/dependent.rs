@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Family member declared by an employee for tax exemptions and benefits.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct Dependent {
+    pub id: Uuid,
+    pub name: String,
+    pub relationship: String,
+    #[schema(value_type = String, format = Date)]
+    pub date_of_birth: NaiveDate,
+    pub employee_id: Uuid,
+}
+
+impl Dependent {
+    pub fn new(
+        id: Uuid,
+        name: impl Into<String>,
+        relationship: impl Into<String>,
+        date_of_birth: NaiveDate,
+        employee_id: Uuid,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            relationship: relationship.into(),
+            date_of_birth,
+            employee_id,
+        }
+    }
+}
@@ -10,6 +10,12 @@ pub struct Division {
     pub budget_code: String,
     pub payroll_id: Uuid,
     pub parent_division_id: Option<Uuid>,
+    /// Employee responsible for the division.
+    pub manager_employee_id: Option<Uuid>,
+    /// Establishment plan: maximum active employees and filled positions. Unlimited when `None`.
+    pub headcount_budget: Option<u32>,
+    /// Where the division's employees work, e.g. `North` or `US-CA`; used to bucket reports.
+    pub region: Option<String>,
 }
 
 impl Division {
@@ -21,6 +27,9 @@ impl Division {
         budget_code: impl Into<String>,
         payroll_id: Uuid,
         parent_division_id: Option<Uuid>,
+        manager_employee_id: Option<Uuid>,
+        headcount_budget: Option<u32>,
+        region: Option<String>,
     ) -> Self {
         Self {
             id,
@@ -29,6 +38,9 @@ impl Division {
             budget_code: budget_code.into(),
             payroll_id,
             parent_division_id,
+            manager_employee_id,
+            headcount_budget,
+            region,
         }
     }
 }
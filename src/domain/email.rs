@@ -0,0 +1,17 @@
+/// Plain-text email handed to an [`EmailSender`](crate::services::email::EmailSender).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+impl EmailMessage {
+    pub fn new(to: impl Into<String>, subject: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            to: to.into(),
+            subject: subject.into(),
+            body: body.into(),
+        }
+    }
+}
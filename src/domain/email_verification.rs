@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Outstanding code sent to an employee's email; at most one exists per employee.
+///
+/// Only a SHA-256 digest of the code is kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmailVerification {
+    pub employee_id: Uuid,
+    /// Address the code was sent to; confirming fails if the employee's email changed since.
+    pub email: String,
+    pub code_hash: String,
+    pub expires_at: DateTime<Utc>,
+    /// Wrong guesses left before the code is discarded.
+    pub attempts_left: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl EmailVerification {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
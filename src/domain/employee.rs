@@ -1,16 +1,302 @@
-use chrono::NaiveDate;
-use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Employment lifecycle stage; see [`EmployeeStatus::can_transition_to`] for legal moves.
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum EmployeeStatus {
+    Onboarding,
+    Active,
+    OnLeave,
+    Suspended,
+    /// Only left through a rehire.
+    Terminated,
+}
+
+impl EmployeeStatus {
+    pub const ALL: [Self; 5] = [
+        Self::Onboarding,
+        Self::Active,
+        Self::OnLeave,
+        Self::Suspended,
+        Self::Terminated,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Onboarding => "Onboarding",
+            Self::Active => "Active",
+            Self::OnLeave => "OnLeave",
+            Self::Suspended => "Suspended",
+            Self::Terminated => "Terminated",
+        }
+    }
+
+    /// Parses a status ignoring case, spaces, `_` and `-`, so values stored as free text
+    /// before the enum existed (`On Leave`, `Inactive`, `Leaver`) still load.
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized: String = value
+            .chars()
+            .filter(|ch| !ch.is_whitespace() && *ch != '_' && *ch != '-')
+            .flat_map(char::to_lowercase)
+            .collect();
+
+        Self::ALL
+            .into_iter()
+            .find(|status| status.aliases().contains(&normalized.as_str()))
+    }
+
+    /// Every normalized spelling [`EmployeeStatus::parse`] accepts for `self`, for queries
+    /// that filter on the stored text.
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::Onboarding => &["onboarding"],
+            Self::Active => &["active"],
+            Self::OnLeave => &["onleave"],
+            Self::Suspended => &["suspended", "inactive"],
+            Self::Terminated => &["terminated", "leaver"],
+        }
+    }
+
+    /// Whether `EmployeeService` may move an employee from `self` to `next`.
+    pub fn can_transition_to(self, next: Self) -> bool {
+        use EmployeeStatus::*;
+
+        self == next
+            || matches!(
+                (self, next),
+                (Onboarding, Active)
+                    | (Active | OnLeave | Suspended, Active | OnLeave | Suspended)
+                    | (Onboarding | Active | OnLeave | Suspended, Terminated)
+            )
+    }
+}
+
+impl fmt::Display for EmployeeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmployeeStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "unknown employee status `{value}`, expected one of Onboarding, Active, OnLeave, Suspended, Terminated"
+            ))
+        })
+    }
+}
+
+/// How much of an employee's net pay goes to one account.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentAmount {
+    /// Fixed sum, paid before any percentage split.
+    Fixed(f64),
+    /// Share of the pay left after fixed sums; an employee's percentages add up to 100.
+    Percentage(f64),
+}
+
+/// Whether an account has been confirmed to belong to the employee.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountVerificationStatus {
+    #[default]
+    Unverified,
+    /// Micro-deposits were sent or a document was requested, and the outcome is not known yet.
+    Pending,
+    Verified,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountVerificationMethod {
+    /// Small deposits the employee confirms the amounts of.
+    MicroDeposit,
+    /// A bank letter, voided cheque or statement showing the account holder.
+    Document,
+}
+
+/// Verification state of one payment account, reset whenever its bank or account changes.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct AccountVerification {
+    pub status: AccountVerificationStatus,
+    pub method: Option<AccountVerificationMethod>,
+    /// Micro-deposit trace number or identifier of the document that was checked.
+    pub reference: Option<String>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// One destination account for an employee's pay.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct PaymentInstruction {
+    pub bank_id: Uuid,
+    pub account: String,
+    pub amount: PaymentAmount,
+    /// Lower priorities are paid first.
+    pub priority: u32,
+    /// Recorded through the verification endpoints; ignored when instructions are saved.
+    #[serde(default)]
+    pub verification: AccountVerification,
+}
+
+/// Permit allowing a foreign national to work.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct WorkPermit {
+    /// Masked to its last characters in responses.
+    pub number: String,
+    /// Issuing country as an ISO 3166-1 alpha-2 code.
+    pub country: String,
+    #[schema(value_type = String, format = Date)]
+    pub expires_on: NaiveDate,
+}
+
+impl WorkPermit {
+    /// The permit with all but the last few characters of its number replaced by `*`.
+    pub fn masked(&self) -> Self {
+        let length = self.number.chars().count();
+        let visible = (length / 2).min(4);
+        let number = self
+            .number
+            .chars()
+            .enumerate()
+            .map(|(index, c)| if index < length - visible { '*' } else { c })
+            .collect();
+        Self {
+            number,
+            country: self.country.clone(),
+            expires_on: self.expires_on,
+        }
+    }
+
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.expires_on < today
+    }
+}
+
+/// Part of a net pay amount routed to one account.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct PaymentAllocation {
+    pub bank_id: Uuid,
+    pub account: String,
+    pub amount: f64,
+}
+
+impl PaymentInstruction {
+    /// Splits `net_pay` across `instructions` for a payment batch.
+    ///
+    /// Fixed sums are paid in priority order until the pay runs out; the rest is
+    /// split by percentage, rounded to cents, with the last percentage
+    /// instruction absorbing any rounding difference.
+    pub fn allocate(instructions: &[PaymentInstruction], net_pay: f64) -> Vec<PaymentAllocation> {
+        let mut ordered: Vec<&PaymentInstruction> = instructions.iter().collect();
+        ordered.sort_by_key(|instruction| instruction.priority);
+
+        let mut remaining = net_pay.max(0.0);
+        let mut amounts = vec![0.0; ordered.len()];
+        for (index, instruction) in ordered.iter().enumerate() {
+            if let PaymentAmount::Fixed(fixed) = instruction.amount {
+                let paid = fixed.min(remaining);
+                amounts[index] = paid;
+                remaining -= paid;
+            }
+        }
+
+        let to_split = remaining;
+        let last_percentage = ordered
+            .iter()
+            .rposition(|instruction| matches!(instruction.amount, PaymentAmount::Percentage(_)));
+        for (index, instruction) in ordered.iter().enumerate() {
+            if let PaymentAmount::Percentage(percentage) = instruction.amount {
+                let share = if Some(index) == last_percentage {
+                    remaining
+                } else {
+                    (to_split * percentage / 100.0 * 100.0).round() / 100.0
+                };
+                let share = share.min(remaining);
+                amounts[index] = share;
+                remaining -= share;
+            }
+        }
+
+        ordered
+            .into_iter()
+            .zip(amounts)
+            .map(|(instruction, amount)| PaymentAllocation {
+                bank_id: instruction.bank_id,
+                account: instruction.account.clone(),
+                amount,
+            })
+            .collect()
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.verification.status == AccountVerificationStatus::Verified
+    }
+
+    /// Like [`PaymentInstruction::allocate`], but returns `None` when `blocks_unverified` is
+    /// set and any account is unverified. The whole payment is held back rather than split
+    /// over the verified accounts only, which would send money the employee routed elsewhere.
+    pub fn allocate_for_batch(
+        instructions: &[PaymentInstruction],
+        net_pay: f64,
+        blocks_unverified: bool,
+    ) -> Option<Vec<PaymentAllocation>> {
+        if blocks_unverified && !instructions.iter().all(PaymentInstruction::is_verified) {
+            return None;
+        }
+
+        Some(Self::allocate(instructions, net_pay))
+    }
+}
+
+/// An account that is not verified yet, and so is held out of payment batches on payrolls
+/// that block unverified accounts.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct UnverifiedAccount {
+    pub employee_id: Uuid,
+    pub division_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub bank_id: Uuid,
+    pub account: String,
+    pub priority: u32,
+    pub status: AccountVerificationStatus,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct UnverifiedAccountReport {
+    pub payroll_id: Uuid,
+    /// Whether the payroll holds employees with any of these accounts out of payment batches.
+    pub blocks_unverified_accounts: bool,
+    pub accounts: Vec<UnverifiedAccount>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
 pub struct Employee {
     pub id: Uuid,
     pub id_number: String,
+    /// Identifier assigned by an external HR system, used to match repeated imports.
+    pub external_id: Option<String>,
     pub last_name: String,
     pub first_name: String,
     pub address: String,
     pub phone: String,
+    /// Address payslips and self-service notices are sent to, unique within the organization.
+    pub email: Option<String>,
+    /// When the employee confirmed a code sent to `email`; cleared whenever the email changes.
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub email_verified_at: Option<DateTime<Utc>>,
     pub place_of_birth: String,
     #[schema(value_type = String, format = Date)]
     pub date_of_birth: NaiveDate,
@@ -21,26 +307,50 @@ pub struct Employee {
     pub hire_date: NaiveDate,
     #[schema(value_type = Option<String>, format = Date)]
     pub termination_date: Option<NaiveDate>,
+    /// Last day of the probation period, if one applies.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub probation_end_date: Option<NaiveDate>,
+    /// Last day of a fixed-term contract; `None` for open-ended employment.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub contract_end_date: Option<NaiveDate>,
+    pub work_permit: Option<WorkPermit>,
     pub clasification: String,
     pub job_id: Uuid,
-    pub bank_id: Uuid,
-    pub bank_account: String,
-    pub status: String,
+    /// Accounts the net pay is split across, ordered by priority.
+    pub payment_instructions: Vec<PaymentInstruction>,
+    pub status: EmployeeStatus,
+    /// Weekly hours used when no work schedule is assigned.
     pub hours: i32,
+    /// Work schedule the employee's expected hours and overtime come from.
+    pub schedule_id: Option<Uuid>,
     pub division_id: Uuid,
     pub payroll_id: Uuid,
+    /// Employee this one reports to, within the same payroll.
+    pub supervisor_id: Option<Uuid>,
+    pub photo_url: Option<String>,
+    /// Values for the organization's custom field definitions, keyed by field name.
+    #[schema(value_type = Object)]
+    pub custom_fields: BTreeMap<String, JsonValue>,
 }
 
 impl Employee {
+    /// Key the employee's photo is stored under in the blob store.
+    pub fn photo_key(employee_id: Uuid) -> String {
+        format!("employee_photo_{employee_id}")
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[allow(clippy::field_reassign_with_default)]
     pub fn new(
         id: Uuid,
         id_number: impl Into<String>,
+        external_id: Option<String>,
         last_name: impl Into<String>,
         first_name: impl Into<String>,
         address: impl Into<String>,
         phone: impl Into<String>,
+        email: Option<String>,
+        email_verified_at: Option<DateTime<Utc>>,
         place_of_birth: impl Into<String>,
         date_of_birth: NaiveDate,
         nationality: impl Into<String>,
@@ -48,22 +358,31 @@ impl Employee {
         gender: impl Into<String>,
         hire_date: NaiveDate,
         termination_date: Option<NaiveDate>,
+        probation_end_date: Option<NaiveDate>,
+        contract_end_date: Option<NaiveDate>,
+        work_permit: Option<WorkPermit>,
         clasification: impl Into<String>,
         job_id: Uuid,
-        bank_id: Uuid,
-        bank_account: impl Into<String>,
-        status: impl Into<String>,
+        payment_instructions: Vec<PaymentInstruction>,
+        status: EmployeeStatus,
         hours: i32,
+        schedule_id: Option<Uuid>,
         division_id: Uuid,
         payroll_id: Uuid,
+        supervisor_id: Option<Uuid>,
+        photo_url: Option<String>,
+        custom_fields: BTreeMap<String, JsonValue>,
     ) -> Self {
         Self {
             id,
             id_number: id_number.into(),
+            external_id,
             last_name: last_name.into(),
             first_name: first_name.into(),
             address: address.into(),
             phone: phone.into(),
+            email,
+            email_verified_at,
             place_of_birth: place_of_birth.into(),
             date_of_birth,
             nationality: nationality.into(),
@@ -71,14 +390,20 @@ impl Employee {
             gender: gender.into(),
             hire_date,
             termination_date,
+            probation_end_date,
+            contract_end_date,
+            work_permit,
             clasification: clasification.into(),
             job_id,
-            bank_id,
-            bank_account: bank_account.into(),
-            status: status.into(),
+            payment_instructions,
+            status,
             hours,
+            schedule_id,
             division_id,
             payroll_id,
+            supervisor_id,
+            photo_url,
+            custom_fields,
         }
     }
 }
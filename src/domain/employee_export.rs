@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Columns available to employee exports, in default order.
+pub const EMPLOYEE_EXPORT_COLUMNS: [&str; 27] = [
+    "id",
+    "id_number",
+    "external_id",
+    "last_name",
+    "first_name",
+    "address",
+    "phone",
+    "email",
+    "place_of_birth",
+    "date_of_birth",
+    "nationality",
+    "marital_status",
+    "gender",
+    "hire_date",
+    "termination_date",
+    "probation_end_date",
+    "contract_end_date",
+    "clasification",
+    "job_id",
+    // Highest-priority payment instruction.
+    "bank_id",
+    "bank_account",
+    "status",
+    "hours",
+    "schedule_id",
+    "division_id",
+    "payroll_id",
+    "supervisor_id",
+];
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Xlsx => "xlsx",
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Employee attribute used to recognise rows that describe an existing employee.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EmployeeMatchKey {
+    IdNumber,
+    ExternalId,
+}
+
+impl EmployeeMatchKey {
+    /// Import field holding the key.
+    pub fn field(self) -> &'static str {
+        match self {
+            Self::IdNumber => "id_number",
+            Self::ExternalId => "external_id",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportRowStatus {
+    Created,
+    Updated,
+    Failed,
+}
+
+/// Outcome of importing a single CSV line.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct EmployeeImportRow {
+    pub line: u64,
+    pub status: ImportRowStatus,
+    pub employee_id: Option<Uuid>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct EmployeeImportReport {
+    pub created: usize,
+    pub updated: usize,
+    pub failed: usize,
+    pub rows: Vec<EmployeeImportRow>,
+    /// Employees absent from the file and not yet terminated, left for a termination to record.
+    pub flagged_leavers: Vec<Uuid>,
+}
+
+impl EmployeeImportReport {
+    pub fn record_created(&mut self, line: u64, employee_id: Uuid) {
+        self.created += 1;
+        self.record(line, ImportRowStatus::Created, Some(employee_id), None);
+    }
+
+    pub fn record_updated(&mut self, line: u64, employee_id: Uuid) {
+        self.updated += 1;
+        self.record(line, ImportRowStatus::Updated, Some(employee_id), None);
+    }
+
+    pub fn record_failed(&mut self, line: u64, error: impl Into<String>) {
+        self.failed += 1;
+        self.record(line, ImportRowStatus::Failed, None, Some(error.into()));
+    }
+
+    fn record(
+        &mut self,
+        line: u64,
+        status: ImportRowStatus,
+        employee_id: Option<Uuid>,
+        error: Option<String>,
+    ) {
+        self.rows.push(EmployeeImportRow {
+            line,
+            status,
+            employee_id,
+            error,
+        });
+    }
+}
@@ -0,0 +1,129 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::employee::EmployeeStatus;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EmploymentEventKind {
+    Termination,
+    Rehire,
+    StatusChange,
+    JobChange,
+    Transfer,
+    SalaryAdjustment,
+}
+
+/// Terms recorded when an employee leaves.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct TerminationTerms {
+    /// Organization-defined code such as `RESIGNATION` or `DISMISSAL`.
+    pub reason_code: String,
+    pub eligible_for_rehire: bool,
+    pub eligible_for_severance: bool,
+    /// Whether final-pay items are still owed in the next payroll run.
+    pub final_pay_pending: bool,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct StatusChange {
+    pub from: EmployeeStatus,
+    pub to: EmployeeStatus,
+}
+
+/// A move between jobs, carrying both salaries so pay changes stay visible after job edits.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct JobChange {
+    pub from_job_id: Uuid,
+    pub to_job_id: Uuid,
+    pub from_salary: f64,
+    pub to_salary: f64,
+}
+
+/// A move to another division, made when the employee's division is deleted with its staff
+/// reassigned.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct DivisionTransfer {
+    pub from_division_id: Uuid,
+    pub to_division_id: Uuid,
+}
+
+/// A raise applied to the employee's job by an approved salary adjustment batch.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct SalaryAdjustmentChange {
+    pub batch_id: Uuid,
+    pub job_id: Uuid,
+    pub from_salary: f64,
+    pub to_salary: f64,
+}
+
+/// A dated change to an employee's employment, kept after the employee record moves on.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct EmploymentEvent {
+    pub id: Uuid,
+    pub employee_id: Uuid,
+    pub kind: EmploymentEventKind,
+    #[schema(value_type = String, format = Date)]
+    pub effective_date: NaiveDate,
+    /// Present on terminations.
+    pub termination: Option<TerminationTerms>,
+    /// Present on status changes.
+    pub status_change: Option<StatusChange>,
+    /// Present on job changes.
+    pub job_change: Option<JobChange>,
+    /// Present on transfers.
+    pub transfer: Option<DivisionTransfer>,
+    /// Present on salary adjustments.
+    pub salary_adjustment: Option<SalaryAdjustmentChange>,
+    #[schema(value_type = String, format = DateTime)]
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl EmploymentEvent {
+    /// Creates an event without details; set the field matching `kind` afterwards.
+    pub fn new(
+        id: Uuid,
+        employee_id: Uuid,
+        kind: EmploymentEventKind,
+        effective_date: NaiveDate,
+        recorded_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            employee_id,
+            kind,
+            effective_date,
+            termination: None,
+            status_change: None,
+            job_change: None,
+            transfer: None,
+            salary_adjustment: None,
+            recorded_at,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineEntryKind {
+    Hire,
+    Termination,
+    Rehire,
+    StatusChange,
+    JobChange,
+    Transfer,
+    SalaryAdjustment,
+    PositionAssigned,
+    PositionVacated,
+}
+
+/// One line of an employee's activity feed.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct TimelineEntry {
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    pub kind: TimelineEntryKind,
+    pub description: String,
+}
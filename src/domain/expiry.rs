@@ -0,0 +1,102 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::employee::{Employee, WorkPermit};
+
+/// Longest look-ahead, in days, an expiry report may cover.
+pub const MAX_EXPIRY_WINDOW_DAYS: u32 = 366;
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub enum ExpiryKind {
+    ContractEnd,
+    ProbationEnd,
+    WorkPermit,
+}
+
+/// A contract, probation period or work permit that ends inside the report window.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct Expiry {
+    pub employee_id: Uuid,
+    pub id_number: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub division_id: Uuid,
+    pub payroll_id: Uuid,
+    pub kind: ExpiryKind,
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    /// Days from the report date; zero when it ends today.
+    pub days_remaining: i64,
+}
+
+impl Expiry {
+    /// Everything of `employee`'s that ends between `from` and `to`, inclusive.
+    pub fn due(employee: &Employee, from: NaiveDate, to: NaiveDate) -> Vec<Self> {
+        [
+            (ExpiryKind::ContractEnd, employee.contract_end_date),
+            (ExpiryKind::ProbationEnd, employee.probation_end_date),
+            (
+                ExpiryKind::WorkPermit,
+                employee
+                    .work_permit
+                    .as_ref()
+                    .map(|permit| permit.expires_on),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(kind, date)| {
+            let date = date.filter(|date| (from..=to).contains(date))?;
+            Some(Self {
+                employee_id: employee.id,
+                id_number: employee.id_number.clone(),
+                first_name: employee.first_name.clone(),
+                last_name: employee.last_name.clone(),
+                division_id: employee.division_id,
+                payroll_id: employee.payroll_id,
+                kind,
+                date,
+                days_remaining: (date - from).num_days(),
+            })
+        })
+        .collect()
+    }
+}
+
+/// A work permit that has lapsed or lapses inside the report window.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct PermitExpiry {
+    pub employee_id: Uuid,
+    pub id_number: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub division_id: Uuid,
+    pub payroll_id: Uuid,
+    /// The permit with its number masked.
+    pub work_permit: WorkPermit,
+    /// Days from the report date; negative once the permit has lapsed.
+    pub days_remaining: i64,
+    pub expired: bool,
+}
+
+impl PermitExpiry {
+    /// `employee`'s permit if it expires on or before `to`, however long ago.
+    pub fn due(employee: &Employee, today: NaiveDate, to: NaiveDate) -> Option<Self> {
+        let permit = employee
+            .work_permit
+            .as_ref()
+            .filter(|permit| permit.expires_on <= to)?;
+        Some(Self {
+            employee_id: employee.id,
+            id_number: employee.id_number.clone(),
+            first_name: employee.first_name.clone(),
+            last_name: employee.last_name.clone(),
+            division_id: employee.division_id,
+            payroll_id: employee.payroll_id,
+            work_permit: permit.masked(),
+            days_remaining: (permit.expires_on - today).num_days(),
+            expired: permit.is_expired(today),
+        })
+    }
+}
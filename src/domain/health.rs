@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use utoipa::ToSchema;
 
@@ -17,3 +18,125 @@ impl Health {
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrationStatus {
+    /// Not called since the process started.
+    Unknown,
+    Healthy,
+    /// The latest call failed.
+    Failing,
+}
+
+/// Outcome of the calls this process has made to one downstream integration.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct IntegrationHealth {
+    pub name: String,
+    pub status: IntegrationStatus,
+    /// How the integration is wired, e.g. that emails only go to the log.
+    pub detail: Option<String>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub last_success_at: Option<DateTime<Utc>>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    /// Failures since the last success.
+    pub consecutive_failures: u32,
+}
+
+impl IntegrationHealth {
+    pub fn new(name: impl Into<String>, detail: Option<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: IntegrationStatus::Unknown,
+            detail,
+            last_success_at: None,
+            last_failure_at: None,
+            last_error: None,
+            consecutive_failures: 0,
+        }
+    }
+
+    pub fn record_success(&mut self, at: DateTime<Utc>) {
+        self.status = IntegrationStatus::Healthy;
+        self.last_success_at = Some(at);
+        self.consecutive_failures = 0;
+    }
+
+    pub fn record_failure(&mut self, at: DateTime<Utc>, error: impl Into<String>) {
+        self.status = IntegrationStatus::Failing;
+        self.last_failure_at = Some(at);
+        self.last_error = Some(error.into());
+        self.consecutive_failures += 1;
+    }
+}
+
+/// Every registered integration, `failing` overall as soon as one of them is.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct IntegrationHealthReport {
+    /// `failing` when any integration is, `healthy` otherwise.
+    pub status: IntegrationStatus,
+    pub integrations: Vec<IntegrationHealth>,
+}
+
+impl IntegrationHealthReport {
+    pub fn new(integrations: Vec<IntegrationHealth>) -> Self {
+        let status = if integrations
+            .iter()
+            .any(|integration| integration.status == IntegrationStatus::Failing)
+        {
+            IntegrationStatus::Failing
+        } else {
+            IntegrationStatus::Healthy
+        };
+
+        Self {
+            status,
+            integrations,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyStatus {
+    Up,
+    /// The ping failed or did not answer in time.
+    Down,
+}
+
+/// Result of pinging one dependency the service cannot work without.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct DependencyHealth {
+    pub name: String,
+    pub status: DependencyStatus,
+    /// Time the ping took, or waited before timing out.
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Whether every dependency answered, `down` overall as soon as one of them did not.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct ReadinessReport {
+    pub status: DependencyStatus,
+    pub dependencies: Vec<DependencyHealth>,
+}
+
+impl ReadinessReport {
+    pub fn new(dependencies: Vec<DependencyHealth>) -> Self {
+        let status = if dependencies
+            .iter()
+            .any(|dependency| dependency.status == DependencyStatus::Down)
+        {
+            DependencyStatus::Down
+        } else {
+            DependencyStatus::Up
+        };
+
+        Self {
+            status,
+            dependencies,
+        }
+    }
+}
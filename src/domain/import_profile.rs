@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Employee attributes that imported CSV columns can be mapped onto.
+pub const EMPLOYEE_IMPORT_FIELDS: [&str; 22] = [
+    "id_number",
+    "external_id",
+    "last_name",
+    "first_name",
+    "address",
+    "phone",
+    "email",
+    "place_of_birth",
+    "date_of_birth",
+    "nationality",
+    "marital_status",
+    "gender",
+    "hire_date",
+    "termination_date",
+    "probation_end_date",
+    "contract_end_date",
+    "clasification",
+    "job_id",
+    "bank_id",
+    "bank_account",
+    "status",
+    "hours",
+];
+
+/// Date format assumed when a CSV is imported without a profile.
+pub const DEFAULT_IMPORT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Organization-defined recipe for reading a legacy CSV layout into employees.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct ImportProfile {
+    pub id: Uuid,
+    pub name: String,
+    /// Source CSV column name mapped to the employee field it populates.
+    pub column_mappings: BTreeMap<String, String>,
+    /// `chrono` format string used for every date column.
+    pub date_format: String,
+    /// Per employee field, source values replaced by the stored value.
+    pub value_translations: BTreeMap<String, BTreeMap<String, String>>,
+    pub organization_id: Uuid,
+}
+
+impl ImportProfile {
+    pub fn new(
+        id: Uuid,
+        name: impl Into<String>,
+        column_mappings: BTreeMap<String, String>,
+        date_format: impl Into<String>,
+        value_translations: BTreeMap<String, BTreeMap<String, String>>,
+        organization_id: Uuid,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            column_mappings,
+            date_format: date_format.into(),
+            value_translations,
+            organization_id,
+        }
+    }
+
+    /// Employee field populated by `column`, if the profile maps it.
+    pub fn target_field(&self, column: &str) -> Option<&str> {
+        self.column_mappings.get(column).map(String::as_str)
+    }
+
+    /// Applies the configured value translation for `field`, if any.
+    pub fn translate<'a>(&'a self, field: &str, value: &'a str) -> &'a str {
+        self.value_translations
+            .get(field)
+            .and_then(|translations| translations.get(value))
+            .map(String::as_str)
+            .unwrap_or(value)
+    }
+}
@@ -2,21 +2,217 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::{
+    organization::Employer, organization_settings::RoundingRule, work_schedule::WorkExpectation,
+};
+
+/// Working days per week for employees without a schedule, unless the organization sets its own.
+pub const DEFAULT_WORKING_DAYS_PER_WEEK: usize = 5;
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
 pub struct Job {
     pub id: Uuid,
     pub job_title: String,
+    pub description: Option<String>,
+    /// Duties of the role, one per entry, in display order.
+    pub responsibilities: Vec<String>,
+    /// Qualifications a holder of the role must have, one per entry.
+    pub qualifications: Vec<String>,
+    /// Rate paid per `pay_basis` unit.
     pub salary: f64,
+    pub pay_basis: PayBasis,
+    /// ISO 4217 code of `salary`; `None` for the organization's operating currency.
+    pub currency: Option<String>,
+    /// Lowest salary the job should pay, if the range has a floor.
+    pub salary_min: Option<f64>,
+    /// Highest salary the job should pay, if the range has a ceiling.
+    pub salary_max: Option<f64>,
+    /// Grade whose salary band `salary` must fall in.
+    pub grade_id: Option<Uuid>,
+    /// Seats budgeted for the job across its payroll; `None` leaves it unlimited.
+    pub budgeted_headcount: Option<u32>,
+    pub headcount_policy: HeadcountPolicy,
     pub payroll_id: Uuid,
 }
 
+/// What happens when an assignment would take a job past its budgeted headcount.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HeadcountPolicy {
+    /// Allow the assignment and log a warning.
+    #[default]
+    Warn,
+    /// Refuse the assignment with a conflict.
+    Enforce,
+}
+
+/// Budgeted versus filled seats for one job.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct JobHeadcount {
+    pub job_id: Uuid,
+    pub job_title: String,
+    pub budgeted_headcount: Option<u32>,
+    pub headcount_policy: HeadcountPolicy,
+    /// Employees holding the job who are not terminated.
+    pub filled: u32,
+    /// Seats left, or `None` when the job has no budget.
+    pub available: Option<u32>,
+    pub over_budget: bool,
+}
+
+impl JobHeadcount {
+    pub fn new(job: &Job, filled: u32) -> Self {
+        Self {
+            job_id: job.id,
+            job_title: job.job_title.clone(),
+            budgeted_headcount: job.budgeted_headcount,
+            headcount_policy: job.headcount_policy,
+            filled,
+            available: job
+                .budgeted_headcount
+                .map(|budget| budget.saturating_sub(filled)),
+            over_budget: job.budgeted_headcount.is_some_and(|budget| filled > budget),
+        }
+    }
+}
+
+/// Unit of time a job's salary is quoted per.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayBasis {
+    Hourly,
+    Daily,
+    #[default]
+    Monthly,
+    Annual,
+}
+
+impl PayBasis {
+    /// What `rate` comes to over a year of `expectation`'s working pattern.
+    pub fn annual_amount(self, rate: f64, expectation: &WorkExpectation) -> f64 {
+        match self {
+            PayBasis::Hourly => rate * expectation.weekly_hours * 52.0,
+            PayBasis::Daily => rate * expectation.working_days_per_week as f64 * 52.0,
+            PayBasis::Monthly => rate * 12.0,
+            PayBasis::Annual => rate,
+        }
+    }
+}
+
+/// How often employees are paid.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayPeriod {
+    Weekly,
+    Biweekly,
+    SemiMonthly,
+    #[default]
+    Monthly,
+}
+
+impl PayPeriod {
+    pub fn per_year(self) -> f64 {
+        match self {
+            PayPeriod::Weekly => 52.0,
+            PayPeriod::Biweekly => 26.0,
+            PayPeriod::SemiMonthly => 24.0,
+            PayPeriod::Monthly => 12.0,
+        }
+    }
+}
+
+/// A job's salary converted into one pay period's amount for a given employee.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct PeriodPay {
+    /// Who pays, as printed on the pay statement.
+    pub employer: Employer,
+    pub employee_id: Uuid,
+    pub job_id: Uuid,
+    pub pay_basis: PayBasis,
+    pub rate: f64,
+    pub currency: Option<String>,
+    pub period: PayPeriod,
+    /// Weekly hours the conversion assumed.
+    pub weekly_hours: f64,
+    /// Gross amount for one period, rounded by the organization's rounding rule.
+    pub amount: f64,
+}
+
+/// Where a job's salary sits relative to its own salary range.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SalaryRangeStatus {
+    /// The job has no range configured.
+    Unset,
+    Below,
+    Within,
+    Above,
+}
+
 impl Job {
-    pub fn new(id: Uuid, job_title: impl Into<String>, salary: f64, payroll_id: Uuid) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        job_title: impl Into<String>,
+        description: Option<String>,
+        responsibilities: Vec<String>,
+        qualifications: Vec<String>,
+        salary: f64,
+        pay_basis: PayBasis,
+        currency: Option<String>,
+        salary_min: Option<f64>,
+        salary_max: Option<f64>,
+        grade_id: Option<Uuid>,
+        budgeted_headcount: Option<u32>,
+        headcount_policy: HeadcountPolicy,
+        payroll_id: Uuid,
+    ) -> Self {
         Self {
             id,
             job_title: job_title.into(),
+            description,
+            responsibilities,
+            qualifications,
             salary,
+            pay_basis,
+            currency,
+            salary_min,
+            salary_max,
+            grade_id,
+            budgeted_headcount,
+            headcount_policy,
             payroll_id,
         }
     }
+
+    /// Gross pay for one `period` of an employee working to `expectation`.
+    pub fn period_amount(
+        &self,
+        period: PayPeriod,
+        expectation: &WorkExpectation,
+        rounding: RoundingRule,
+    ) -> f64 {
+        rounding.apply(self.pay_basis.annual_amount(self.salary, expectation) / period.per_year())
+    }
+
+    /// Pay for one hour of work by an employee working to `expectation`.
+    pub fn hourly_rate(&self, expectation: &WorkExpectation) -> f64 {
+        match self.pay_basis {
+            PayBasis::Hourly => self.salary,
+            _ if expectation.weekly_hours > 0.0 => {
+                self.pay_basis.annual_amount(self.salary, expectation)
+                    / (expectation.weekly_hours * 52.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    pub fn salary_range_status(&self) -> SalaryRangeStatus {
+        match (self.salary_min, self.salary_max) {
+            (None, None) => SalaryRangeStatus::Unset,
+            (Some(min), _) if self.salary < min => SalaryRangeStatus::Below,
+            (_, Some(max)) if self.salary > max => SalaryRangeStatus::Above,
+            _ => SalaryRangeStatus::Within,
+        }
+    }
 }
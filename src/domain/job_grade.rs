@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Pay grade within a payroll; jobs assigned to it must pay within its salary band.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct JobGrade {
+    pub id: Uuid,
+    /// Short identifier such as `G7`, unique within the payroll.
+    pub code: String,
+    /// Band the grade belongs to, e.g. `Professional`.
+    pub band: String,
+    /// Seniority within the band; higher is more senior.
+    pub level: u32,
+    pub salary_min: f64,
+    pub salary_max: f64,
+    pub payroll_id: Uuid,
+}
+
+impl JobGrade {
+    pub fn new(
+        id: Uuid,
+        code: impl Into<String>,
+        band: impl Into<String>,
+        level: u32,
+        salary_min: f64,
+        salary_max: f64,
+        payroll_id: Uuid,
+    ) -> Self {
+        Self {
+            id,
+            code: code.into(),
+            band: band.into(),
+            level,
+            salary_min,
+            salary_max,
+            payroll_id,
+        }
+    }
+
+    /// Whether `salary` falls inside the band, bounds included.
+    pub fn covers(&self, salary: f64) -> bool {
+        self.salary_min <= salary && salary <= self.salary_max
+    }
+}
@@ -0,0 +1,312 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Tolerance when comparing fractional day counts.
+pub const DAY_EPSILON: f64 = 1e-9;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveType {
+    Vacation,
+    Sick,
+    /// Drawn without a balance; approved days reduce pay instead.
+    Unpaid,
+    /// Statutory leave: no balance, bounded and paid by the payroll's policy instead.
+    Maternity,
+    Paternity,
+}
+
+impl LeaveType {
+    /// Leave types that draw down a balance, in display order.
+    pub const PAID: [LeaveType; 2] = [LeaveType::Vacation, LeaveType::Sick];
+    /// Leave types granted by law rather than from a balance.
+    pub const STATUTORY: [LeaveType; 2] = [LeaveType::Maternity, LeaveType::Paternity];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LeaveType::Vacation => "vacation",
+            LeaveType::Sick => "sick",
+            LeaveType::Unpaid => "unpaid",
+            LeaveType::Maternity => "maternity",
+            LeaveType::Paternity => "paternity",
+        }
+    }
+
+    /// Whether taking the leave draws down a balance.
+    pub fn carries_balance(self) -> bool {
+        Self::PAID.contains(&self)
+    }
+
+    pub fn is_statutory(self) -> bool {
+        Self::STATUTORY.contains(&self)
+    }
+
+    /// Whether requests may reference a medical certificate.
+    pub fn takes_certificate(self) -> bool {
+        self == LeaveType::Sick || self.is_statutory()
+    }
+
+    /// Whether the employee picks the dates, making the leave subject to blackout periods.
+    pub fn is_planned(self) -> bool {
+        matches!(self, LeaveType::Vacation | LeaveType::Unpaid)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// Days of paid leave an employee can still take.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct LeaveBalance {
+    pub employee_id: Uuid,
+    pub leave_type: LeaveType,
+    pub available_days: f64,
+    /// Calendar year the balance was granted in or last carried into.
+    pub leave_year: Option<i32>,
+    /// Part of `available_days` brought over from the previous leave year; leave taken uses
+    /// these days first.
+    pub carried_over_days: f64,
+    /// Carried-over days still unused on this date are forfeited.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub carryover_expires_on: Option<NaiveDate>,
+    /// When the employee was warned that their carried-over days are about to expire.
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub expiry_notified_at: Option<DateTime<Utc>>,
+}
+
+impl LeaveBalance {
+    /// Balance of an employee who has never been granted days of `leave_type`.
+    pub fn empty(employee_id: Uuid, leave_type: LeaveType) -> Self {
+        Self {
+            employee_id,
+            leave_type,
+            available_days: 0.0,
+            leave_year: None,
+            carried_over_days: 0.0,
+            carryover_expires_on: None,
+            expiry_notified_at: None,
+        }
+    }
+}
+
+/// What is left of a balance when its leave year ends. Leave years follow the calendar year.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct LeaveCarryoverRule {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub leave_type: LeaveType,
+    /// Most days carried into the new year; the rest are forfeited. No limit when `None`.
+    pub max_days: Option<f64>,
+    /// Carried-over days still unused this many days into the new year are forfeited. They
+    /// never expire when `None`.
+    pub expires_after_days: Option<u32>,
+}
+
+impl LeaveCarryoverRule {
+    /// Applied when a payroll has not configured the leave type: the whole balance carries
+    /// over and never expires.
+    pub fn unlimited(organization_id: Uuid, payroll_id: Uuid, leave_type: LeaveType) -> Self {
+        Self {
+            organization_id,
+            payroll_id,
+            leave_type,
+            max_days: None,
+            expires_after_days: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaveAdjustmentReason {
+    /// The leave year ended and the balance was capped to the carryover limit.
+    YearEndCarryover,
+    /// Carried-over days went unused past their expiry date.
+    CarryoverExpired,
+}
+
+/// Change made to a balance by the carryover job rather than by leave being taken.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct LeaveBalanceAdjustment {
+    pub id: Uuid,
+    pub employee_id: Uuid,
+    pub leave_type: LeaveType,
+    pub reason: LeaveAdjustmentReason,
+    /// First day of the new leave year, or the day the carried-over days expired.
+    #[schema(value_type = String, format = Date)]
+    pub effective_on: NaiveDate,
+    pub days_before: f64,
+    pub days_after: f64,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Time off asked for by an employee, covering the working days between both dates.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct LeaveRequest {
+    pub id: Uuid,
+    pub employee_id: Uuid,
+    pub leave_type: LeaveType,
+    #[schema(value_type = String, format = Date)]
+    pub start_date: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub end_date: NaiveDate,
+    /// Working days in the range, per the employee's schedule at request time.
+    pub days: f64,
+    pub status: LeaveRequestStatus,
+    pub reason: Option<String>,
+    /// Reference to the medical certificate backing a sick or statutory leave request.
+    pub certificate_reference: Option<String>,
+    /// Blackout period the request falls in; approving it needs an explicit override.
+    pub blackout_id: Option<Uuid>,
+    /// Supervisor or division manager who approved or rejected the request.
+    pub decided_by: Option<Uuid>,
+    /// Note left by whoever approved or rejected the request.
+    pub decision_note: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub requested_at: DateTime<Utc>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub decided_at: Option<DateTime<Utc>>,
+}
+
+impl LeaveRequest {
+    pub fn new(
+        id: Uuid,
+        employee_id: Uuid,
+        leave_type: LeaveType,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        days: f64,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            employee_id,
+            leave_type,
+            start_date,
+            end_date,
+            days,
+            status: LeaveRequestStatus::Pending,
+            reason,
+            certificate_reference: None,
+            blackout_id: None,
+            decided_by: None,
+            decision_note: None,
+            requested_at: Utc::now(),
+            decided_at: None,
+        }
+    }
+
+    /// Whether the request still claims its dates.
+    pub fn is_active(&self) -> bool {
+        self.status != LeaveRequestStatus::Rejected
+    }
+
+    pub fn overlaps(&self, start_date: NaiveDate, end_date: NaiveDate) -> bool {
+        self.start_date <= end_date && start_date <= self.end_date
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BlackoutEnforcement {
+    /// Requests are accepted but can only be approved with an explicit override.
+    RequireOverride,
+    /// Requests touching the period are refused outright.
+    Reject,
+}
+
+/// Dates during which a division's employees cannot freely take vacation or unpaid leave,
+/// e.g. around the year-end close.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct LeaveBlackout {
+    pub id: Uuid,
+    pub division_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub start_date: NaiveDate,
+    /// Last day of the blackout, inclusive.
+    #[schema(value_type = String, format = Date)]
+    pub end_date: NaiveDate,
+    pub enforcement: BlackoutEnforcement,
+    pub reason: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+}
+
+impl LeaveBlackout {
+    pub fn overlaps(&self, start_date: NaiveDate, end_date: NaiveDate) -> bool {
+        self.start_date <= end_date && start_date <= self.end_date
+    }
+
+    /// Explains the blackout to someone whose request collides with it.
+    pub fn describe(&self) -> String {
+        let period = format!("{} to {}", self.start_date, self.end_date);
+        match &self.reason {
+            Some(reason) => format!("{period} ({reason})"),
+            None => period,
+        }
+    }
+}
+
+/// Share of pay kept from the `from_day`-th working day of a leave spell onwards.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct LeavePayTier {
+    pub from_day: u32,
+    pub pay_percentage: f64,
+}
+
+/// How much of their pay employees of a payroll keep while on one type of leave, and for how
+/// long. Each leave request is a spell of its own; its working days are counted from 1.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct LeavePayPolicy {
+    pub payroll_id: Uuid,
+    pub leave_type: LeaveType,
+    /// Ordered by `from_day`, the first starting on day 1.
+    pub tiers: Vec<LeavePayTier>,
+    /// Longest spell a single request may cover, in working days.
+    pub max_days: Option<u32>,
+}
+
+impl LeavePayPolicy {
+    /// Leave types whose pay can be tiered.
+    pub const CONFIGURABLE: [LeaveType; 3] =
+        [LeaveType::Sick, LeaveType::Maternity, LeaveType::Paternity];
+
+    /// Applied when a payroll has not configured the leave type: full pay throughout, with no
+    /// limit on the spell.
+    pub fn full_pay(payroll_id: Uuid, leave_type: LeaveType) -> Self {
+        Self {
+            payroll_id,
+            leave_type,
+            tiers: vec![LeavePayTier {
+                from_day: 1,
+                pay_percentage: 100.0,
+            }],
+            max_days: None,
+        }
+    }
+
+    /// Pay percentage for the `day`-th working day of a spell.
+    pub fn pay_percentage(&self, day: u32) -> f64 {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.from_day <= day)
+            .map_or(100.0, |tier| tier.pay_percentage)
+    }
+}
+
+/// Working days of approved leave falling in a period that are paid at one percentage.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct LeavePayDays {
+    pub leave_type: LeaveType,
+    pub pay_percentage: f64,
+    pub days: f64,
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::organization::{Employer, LegalIdentity, Organization};
+
+/// A registered company of the organization's group. Payrolls assigned to it report under its
+/// own legal identity and pay from its own bank account.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct LegalEntity {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    #[serde(flatten)]
+    pub legal_identity: LegalIdentity,
+    /// Account salaries of the entity's payrolls are debited from.
+    pub debtor_account: Option<DebtorAccount>,
+}
+
+/// The employer's own account at one of the organization's banks.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct DebtorAccount {
+    pub bank_id: Uuid,
+    pub account_number: String,
+}
+
+impl LegalEntity {
+    pub fn new(
+        id: Uuid,
+        organization_id: Uuid,
+        name: impl Into<String>,
+        legal_identity: LegalIdentity,
+    ) -> Self {
+        Self {
+            id,
+            organization_id,
+            name: name.into(),
+            legal_identity,
+            debtor_account: None,
+        }
+    }
+
+    /// The entity as it is named on pay statements, under its organization's logo.
+    pub fn employer(&self, organization: &Organization) -> Employer {
+        let identity = &self.legal_identity;
+        Employer {
+            organization_id: self.organization_id,
+            legal_entity_id: Some(self.id),
+            name: identity
+                .legal_name
+                .clone()
+                .unwrap_or_else(|| self.name.clone()),
+            tax_id: identity.tax_id.clone(),
+            registration_number: identity.registration_number.clone(),
+            address: identity.address.clone(),
+            logo_url: organization.logo_url.clone(),
+        }
+    }
+}
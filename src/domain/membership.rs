@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// What a member may do in the organization. Ordered from most to least privileged.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberRole {
+    /// Owns the tenant; an organization with members always keeps at least one.
+    Owner,
+    /// Manages payrolls, employees and members, but cannot remove the last owner.
+    Admin,
+    /// Works with payroll data without managing membership.
+    Member,
+}
+
+/// A user's seat in an organization, identified by the email address they were invited at.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct Membership {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub email: String,
+    pub role: MemberRole,
+    #[schema(value_type = String, format = DateTime)]
+    pub invited_at: DateTime<Utc>,
+}
+
+impl Membership {
+    pub fn new(
+        id: Uuid,
+        organization_id: Uuid,
+        email: impl Into<String>,
+        role: MemberRole,
+    ) -> Self {
+        Self {
+            id,
+            organization_id,
+            email: email.into(),
+            role,
+            invited_at: Utc::now(),
+        }
+    }
+}
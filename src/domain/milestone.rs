@@ -0,0 +1,72 @@
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::employee::Employee;
+
+/// Longest window, in days, a milestone report may span.
+pub const MAX_MILESTONE_WINDOW_DAYS: i64 = 366;
+
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub enum MilestoneKind {
+    Birthday,
+    WorkAnniversary,
+}
+
+/// A birthday or work anniversary falling inside the report window.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct Milestone {
+    pub employee_id: Uuid,
+    pub id_number: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub kind: MilestoneKind,
+    /// Day it is celebrated; 29 February falls on the 28th in common years.
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    /// Age reached or years of service completed.
+    pub years: i32,
+}
+
+impl Milestone {
+    /// Birthdays and work anniversaries of `employee` between `from` and `to`, inclusive.
+    pub fn upcoming(employee: &Employee, from: NaiveDate, to: NaiveDate) -> Vec<Self> {
+        [
+            (MilestoneKind::Birthday, employee.date_of_birth),
+            (MilestoneKind::WorkAnniversary, employee.hire_date),
+        ]
+        .into_iter()
+        .flat_map(|(kind, origin)| {
+            (from.year()..=to.year()).filter_map(move |year| {
+                let date = recurrence(origin, year)?;
+                let years = year - origin.year();
+                (years > 0 && from <= date && date <= to).then_some((kind, date, years))
+            })
+        })
+        .map(|(kind, date, years)| Self {
+            employee_id: employee.id,
+            id_number: employee.id_number.clone(),
+            first_name: employee.first_name.clone(),
+            last_name: employee.last_name.clone(),
+            kind,
+            date,
+            years,
+        })
+        .collect()
+    }
+}
+
+/// Milestones of one division, soonest first.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct DivisionMilestones {
+    pub division_id: Uuid,
+    pub division_name: String,
+    pub milestones: Vec<Milestone>,
+}
+
+fn recurrence(origin: NaiveDate, year: i32) -> Option<NaiveDate> {
+    origin
+        .with_year(year)
+        .or_else(|| NaiveDate::from_ymd_opt(year, origin.month(), origin.day() - 1))
+}
@@ -1,7 +1,37 @@
+pub mod attendance;
 pub mod bank;
+pub mod benchmark;
+pub mod blob;
+pub mod calendar;
+pub mod custom_field;
+pub mod dependent;
 pub mod division;
+pub mod email;
+pub mod email_verification;
 pub mod employee;
+pub mod employee_export;
+pub mod employee_import;
+pub mod employment_history;
+pub mod expiry;
 pub mod health;
+pub mod import_profile;
 pub mod job;
+pub mod job_grade;
+pub mod leave;
+pub mod legal_entity;
+pub mod membership;
+pub mod milestone;
 pub mod organization;
+pub mod organization_deletion;
+pub mod organization_settings;
+pub mod overtime;
+pub mod pagination;
+pub mod pay_equity;
 pub mod payroll;
+pub mod position;
+pub mod profile_completeness;
+pub mod project;
+pub mod salary_adjustment;
+pub mod self_service;
+pub mod timesheet;
+pub mod work_schedule;
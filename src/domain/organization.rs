@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -6,13 +8,94 @@ use uuid::Uuid;
 pub struct Organization {
     pub id: Uuid,
     pub name: String,
+    /// Regular expression every division budget code must match in full, e.g. `CC-\d{4}`.
+    pub budget_code_pattern: Option<String>,
+    #[serde(flatten)]
+    pub legal_identity: LegalIdentity,
+    /// Where the uploaded logo is served from, if one was uploaded.
+    pub logo_url: Option<String>,
+    /// When the organization was soft-deleted; it is hidden until restored.
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// When the organization was archived; its records stay readable but cannot change until
+    /// it is reactivated.
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+/// How the organization is registered with tax authorities and company registries.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct LegalIdentity {
+    /// Registered name, when it differs from the trading `name`.
+    pub legal_name: Option<String>,
+    /// Employer tax identification number, e.g. an EIN, RFC or VAT number.
+    pub tax_id: Option<String>,
+    /// Company registry number.
+    pub registration_number: Option<String>,
+    /// Registered office address.
+    pub address: Option<String>,
+}
+
+/// The organization, or the legal entity the payroll reports under, as it is named on pay
+/// statements.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct Employer {
+    pub organization_id: Uuid,
+    pub legal_entity_id: Option<Uuid>,
+    /// Legal name, or the trading name when none is registered.
+    pub name: String,
+    pub tax_id: Option<String>,
+    pub registration_number: Option<String>,
+    pub address: Option<String>,
+    /// Logo to print on pay statements.
+    pub logo_url: Option<String>,
 }
 
 impl Organization {
-    pub fn new(id: Uuid, name: impl Into<String>) -> Self {
+    pub fn new(
+        id: Uuid,
+        name: impl Into<String>,
+        budget_code_pattern: Option<String>,
+        legal_identity: LegalIdentity,
+    ) -> Self {
         Self {
             id,
             name: name.into(),
+            budget_code_pattern,
+            legal_identity,
+            logo_url: None,
+            deleted_at: None,
+            archived_at: None,
         }
     }
+
+    pub fn employer(&self) -> Employer {
+        let identity = &self.legal_identity;
+        Employer {
+            organization_id: self.id,
+            legal_entity_id: None,
+            name: identity
+                .legal_name
+                .clone()
+                .unwrap_or_else(|| self.name.clone()),
+            tax_id: identity.tax_id.clone(),
+            registration_number: identity.registration_number.clone(),
+            address: identity.address.clone(),
+            logo_url: self.logo_url.clone(),
+        }
+    }
+
+    /// Whether `budget_code` follows the organization's format. Codes are unrestricted when no
+    /// pattern is set.
+    pub fn accepts_budget_code(&self, budget_code: &str) -> bool {
+        self.budget_code_pattern
+            .as_deref()
+            .and_then(|pattern| compile_budget_code_pattern(pattern).ok())
+            .is_none_or(|regex| regex.is_match(budget_code))
+    }
+}
+
+/// Compiles `pattern` anchored at both ends, so it has to match the whole budget code.
+pub fn compile_budget_code_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{pattern})$"))
 }
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Pending request to delete an organization once its cancellation window elapses.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct OrganizationDeletion {
+    pub organization_id: Uuid,
+    #[schema(value_type = String, format = DateTime)]
+    pub requested_at: DateTime<Utc>,
+    #[schema(value_type = String, format = DateTime)]
+    pub scheduled_for: DateTime<Utc>,
+}
+
+impl OrganizationDeletion {
+    pub fn new(
+        organization_id: Uuid,
+        requested_at: DateTime<Utc>,
+        scheduled_for: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            organization_id,
+            requested_at,
+            scheduled_for,
+        }
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.scheduled_for <= now
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::job::DEFAULT_WORKING_DAYS_PER_WEEK;
+
+/// Locale reports are formatted for when an organization has not chosen one.
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// Organization-wide defaults that payroll calculations and reports fall back on.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct OrganizationSettings {
+    pub organization_id: Uuid,
+    /// ISO 4217 operating currency, used for jobs that do not name their own.
+    pub default_currency: Option<String>,
+    /// BCP 47 language tag reports are formatted for, e.g. `es-MX`.
+    pub locale: String,
+    pub fiscal_year_start: FiscalYearStart,
+    /// Days worked per week by employees without a work schedule, Monday first.
+    pub working_days_per_week: u8,
+    /// Applied to calculated pay amounts.
+    pub rounding: RoundingRule,
+}
+
+impl OrganizationSettings {
+    /// Applied when an organization has not configured its settings.
+    pub fn standard(organization_id: Uuid) -> Self {
+        Self {
+            organization_id,
+            default_currency: None,
+            locale: DEFAULT_LOCALE.to_string(),
+            fiscal_year_start: FiscalYearStart::default(),
+            working_days_per_week: DEFAULT_WORKING_DAYS_PER_WEEK as u8,
+            rounding: RoundingRule::default(),
+        }
+    }
+}
+
+/// Month and day the organization's fiscal year begins.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct FiscalYearStart {
+    /// 1 to 12.
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Default for FiscalYearStart {
+    fn default() -> Self {
+        Self { month: 1, day: 1 }
+    }
+}
+
+impl FiscalYearStart {
+    /// Whether the month and day exist in every year; 29 February is refused.
+    pub fn is_valid(self) -> bool {
+        NaiveDate::from_ymd_opt(2001, self.month, self.day).is_some()
+    }
+
+    /// Each day between `from` and `to`, inclusive, on which a fiscal year begins.
+    pub fn occurrences(self, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        (from.year()..=to.year())
+            .filter_map(|year| NaiveDate::from_ymd_opt(year, self.month, self.day))
+            .filter(|date| (from..=to).contains(date))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Halves round away from zero.
+    #[default]
+    HalfUp,
+    /// Halves round to the even increment, as banks do.
+    HalfEven,
+    /// Always toward zero.
+    Down,
+    /// Always away from zero.
+    Up,
+}
+
+/// How calculated amounts are rounded, e.g. to the nearest 0.05 for cash payrolls.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct RoundingRule {
+    /// Amounts are rounded to a multiple of this, in whole cents; 0.01 rounds to cents.
+    pub increment: f64,
+    pub mode: RoundingMode,
+}
+
+impl Default for RoundingRule {
+    fn default() -> Self {
+        Self {
+            increment: 0.01,
+            mode: RoundingMode::HalfUp,
+        }
+    }
+}
+
+impl RoundingRule {
+    pub fn apply(self, amount: f64) -> f64 {
+        // Trims representation noise so 2.675 / 0.01 counts as the half it is meant to be.
+        let units = ((amount / self.increment) * 1e6).round() / 1e6;
+        let rounded = match self.mode {
+            RoundingMode::HalfUp => units.round(),
+            RoundingMode::HalfEven => units.round_ties_even(),
+            RoundingMode::Down => units.trunc(),
+            RoundingMode::Up => {
+                if units.fract() == 0.0 {
+                    units
+                } else {
+                    units.trunc() + units.signum()
+                }
+            }
+        };
+        ((rounded * self.increment) * 100.0).round() / 100.0
+    }
+}
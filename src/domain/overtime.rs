@@ -0,0 +1,139 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Multiplier paid on overtime when a payroll has not configured its own.
+pub const DEFAULT_OVERTIME_MULTIPLIER: f64 = 1.5;
+
+/// How a payroll pays hours beyond the daily or weekly overtime threshold.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct OvertimePolicy {
+    pub payroll_id: Uuid,
+    /// Rate applied to overtime hours, relative to the employee's hourly rate.
+    pub multiplier: f64,
+    /// When set, only overtime a manager approved beforehand earns the multiplier; the rest
+    /// is paid at the ordinary rate and reported as a warning on the run.
+    pub requires_preapproval: bool,
+}
+
+impl OvertimePolicy {
+    /// Applied when a payroll has not configured overtime: all overtime earns the default
+    /// multiplier, approved or not.
+    pub fn standard(payroll_id: Uuid) -> Self {
+        Self {
+            payroll_id,
+            multiplier: DEFAULT_OVERTIME_MULTIPLIER,
+            requires_preapproval: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OvertimeRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// Overtime an employee asks to work on a given day, up to `hours` beyond their threshold.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct OvertimeRequest {
+    pub id: Uuid,
+    pub employee_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    pub hours: f64,
+    pub reason: Option<String>,
+    pub status: OvertimeRequestStatus,
+    #[schema(value_type = String, format = DateTime)]
+    pub requested_at: DateTime<Utc>,
+    /// Supervisor or division manager who approved or rejected the request.
+    pub reviewed_by: Option<Uuid>,
+    pub review_note: Option<String>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl OvertimeRequest {
+    pub fn new(
+        id: Uuid,
+        employee_id: Uuid,
+        date: NaiveDate,
+        hours: f64,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            employee_id,
+            date,
+            hours,
+            reason,
+            status: OvertimeRequestStatus::Pending,
+            requested_at: Utc::now(),
+            reviewed_by: None,
+            review_note: None,
+            reviewed_at: None,
+        }
+    }
+}
+
+/// Overtime worked on one day and how much of it was approved beforehand.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct OvertimeDay {
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    pub hours: f64,
+    pub approved_hours: f64,
+}
+
+impl OvertimeDay {
+    pub fn unapproved_hours(&self) -> f64 {
+        (self.hours - self.approved_hours).max(0.0)
+    }
+}
+
+/// An employee's paid hours over a period, split by the rate they are paid at.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct OvertimePay {
+    pub employee_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub to: NaiveDate,
+    pub multiplier: f64,
+    pub requires_preapproval: bool,
+    /// Hours paid at the ordinary rate, including overtime that was not pre-approved.
+    pub regular_hours: f64,
+    /// Hours paid at the overtime multiplier.
+    pub overtime_hours: f64,
+    /// Overtime paid at the ordinary rate because no approved request covered it.
+    pub unapproved_overtime_hours: f64,
+    pub days: Vec<OvertimeDay>,
+}
+
+/// Overtime of one employee, on one day, that the payroll run pays without the multiplier.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct OvertimeWarning {
+    pub employee_id: Uuid,
+    pub division_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    pub unapproved_hours: f64,
+}
+
+/// Unapproved overtime a payroll run over `from`..=`to` would warn about. Always empty when
+/// the payroll does not require pre-approval.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct OvertimeWarningReport {
+    pub payroll_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub to: NaiveDate,
+    pub requires_preapproval: bool,
+    pub warnings: Vec<OvertimeWarning>,
+}
@@ -0,0 +1,67 @@
+use crate::error::{AppError, AppResult};
+
+/// Page size used when a listing does not ask for one.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+/// Largest page a listing may ask for.
+pub const MAX_PAGE_SIZE: usize = 200;
+
+/// One-based page position within a listing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PageRequest {
+    pub page: usize,
+    pub per_page: usize,
+}
+
+impl PageRequest {
+    pub fn new(page: Option<usize>, per_page: Option<usize>) -> AppResult<Self> {
+        let page = page.unwrap_or(1);
+        let per_page = per_page.unwrap_or(DEFAULT_PAGE_SIZE);
+        if page == 0 {
+            return Err(AppError::validation("page must be at least 1"));
+        }
+        if per_page == 0 || per_page > MAX_PAGE_SIZE {
+            return Err(AppError::validation(format!(
+                "per_page must be between 1 and {MAX_PAGE_SIZE}"
+            )));
+        }
+
+        Ok(Self { page, per_page })
+    }
+
+    /// Cuts the requested page out of the full, already ordered listing.
+    pub fn slice<T>(self, items: Vec<T>) -> Page<T> {
+        let total = items.len();
+        let items = items
+            .into_iter()
+            .skip((self.page - 1).saturating_mul(self.per_page))
+            .take(self.per_page)
+            .collect();
+
+        Page {
+            items,
+            page: self.page,
+            per_page: self.per_page,
+            total,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: usize,
+    pub per_page: usize,
+    /// Items across every page.
+    pub total: usize,
+}
+
+impl<T> Page<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            page: self.page,
+            per_page: self.per_page,
+            total: self.total,
+        }
+    }
+}
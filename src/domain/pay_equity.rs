@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::job_grade::JobGrade;
+
+/// Groups smaller than this are suppressed unless the caller asks otherwise.
+pub const DEFAULT_MIN_GROUP_SIZE: usize = 5;
+/// Groups of one would show an individual's pay, so no report may go below this.
+pub const SMALLEST_MIN_GROUP_SIZE: usize = 2;
+
+/// Label used when an employee has no gender or classification recorded.
+const UNSPECIFIED: &str = "Unspecified";
+
+/// One current employee's annualised pay and the attributes the report groups by.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaySample {
+    pub gender: String,
+    pub clasification: String,
+    pub annual_pay: f64,
+}
+
+/// Pay of the employees sharing one gender or classification within a grade. Figures are
+/// withheld when the group is smaller than the report's minimum.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct PayEquityGroup {
+    pub group: String,
+    pub suppressed: bool,
+    pub headcount: Option<usize>,
+    pub average_annual_pay: Option<f64>,
+    pub median_annual_pay: Option<f64>,
+    /// How far the group's median sits from the grade's median, in percent.
+    pub median_gap_percentage: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct GradePayEquity {
+    pub grade_id: Uuid,
+    pub code: String,
+    pub band: String,
+    pub level: u32,
+    /// Set when the whole grade is below the minimum group size; no figures are shown then.
+    pub suppressed: bool,
+    pub headcount: Option<usize>,
+    pub average_annual_pay: Option<f64>,
+    pub median_annual_pay: Option<f64>,
+    pub by_gender: Vec<PayEquityGroup>,
+    pub by_classification: Vec<PayEquityGroup>,
+}
+
+/// Average and median annual pay by gender and classification within each job grade.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct PayEquityReport {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub min_group_size: usize,
+    pub grades: Vec<GradePayEquity>,
+    /// Current employees left out because their job has no grade.
+    pub ungraded_employees: usize,
+}
+
+impl GradePayEquity {
+    pub fn summarize(grade: &JobGrade, samples: &[PaySample], min_group_size: usize) -> Self {
+        let suppressed = samples.len() < min_group_size;
+        let pays: Vec<f64> = samples.iter().map(|sample| sample.annual_pay).collect();
+        let median = (!suppressed).then(|| median(&pays));
+        let groups = |key: fn(&PaySample) -> &str| {
+            if suppressed {
+                return Vec::new();
+            }
+
+            let mut grouped: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+            for sample in samples {
+                grouped
+                    .entry(group_label(key(sample)))
+                    .or_default()
+                    .push(sample.annual_pay);
+            }
+            grouped
+                .into_iter()
+                .map(|(group, pays)| {
+                    PayEquityGroup::summarize(group, &pays, min_group_size, median)
+                })
+                .collect()
+        };
+
+        Self {
+            grade_id: grade.id,
+            code: grade.code.clone(),
+            band: grade.band.clone(),
+            level: grade.level,
+            suppressed,
+            headcount: (!suppressed).then_some(samples.len()),
+            average_annual_pay: (!suppressed).then(|| average(&pays)),
+            median_annual_pay: median,
+            by_gender: groups(|sample| &sample.gender),
+            by_classification: groups(|sample| &sample.clasification),
+        }
+    }
+}
+
+impl PayEquityGroup {
+    fn summarize(
+        group: String,
+        pays: &[f64],
+        min_group_size: usize,
+        grade_median: Option<f64>,
+    ) -> Self {
+        if pays.len() < min_group_size {
+            return Self {
+                group,
+                suppressed: true,
+                headcount: None,
+                average_annual_pay: None,
+                median_annual_pay: None,
+                median_gap_percentage: None,
+            };
+        }
+
+        let median = median(pays);
+        Self {
+            group,
+            suppressed: false,
+            headcount: Some(pays.len()),
+            average_annual_pay: Some(average(pays)),
+            median_annual_pay: Some(median),
+            median_gap_percentage: grade_median
+                .filter(|grade_median| *grade_median > 0.0)
+                .map(|grade_median| round_cents((median - grade_median) / grade_median * 100.0)),
+        }
+    }
+}
+
+fn group_label(value: &str) -> String {
+    let value = value.trim();
+    if value.is_empty() {
+        UNSPECIFIED.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn average(pays: &[f64]) -> f64 {
+    round_cents(pays.iter().sum::<f64>() / pays.len() as f64)
+}
+
+fn median(pays: &[f64]) -> f64 {
+    let mut sorted = pays.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let middle = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] + sorted[middle]) / 2.0
+    } else {
+        sorted[middle]
+    };
+    round_cents(median)
+}
+
+fn round_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -8,6 +9,14 @@ pub struct Payroll {
     pub name: String,
     pub description: String,
     pub organization_id: Uuid,
+    /// Holds employees out of payment batches until all their accounts are verified.
+    pub blocks_unverified_accounts: bool,
+    /// Legal entity the payroll reports and pays under; the organization itself when unset.
+    pub legal_entity_id: Option<Uuid>,
+    /// Set when the payroll's organization was soft-deleted; it and everything under it are
+    /// hidden until the organization is restored.
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 impl Payroll {
@@ -22,6 +31,9 @@ impl Payroll {
             name: name.into(),
             description: description.into(),
             organization_id,
+            blocks_unverified_accounts: false,
+            legal_entity_id: None,
+            archived_at: None,
         }
     }
 }
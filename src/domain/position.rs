@@ -0,0 +1,57 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A period during which an employee held a position.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct PositionAssignment {
+    pub employee_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub start_date: NaiveDate,
+    /// `None` while the assignment is current.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub end_date: Option<NaiveDate>,
+}
+
+/// A budgeted seat for a job within a division; vacant while no employee holds it.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct Position {
+    pub id: Uuid,
+    pub title: String,
+    pub job_id: Uuid,
+    /// Amount budgeted for the seat, independent of the job's salary.
+    pub budget: f64,
+    pub division_id: Uuid,
+    pub payroll_id: Uuid,
+    /// Current holder, if any.
+    pub employee_id: Option<Uuid>,
+    /// Past and current assignments, oldest first.
+    pub history: Vec<PositionAssignment>,
+}
+
+impl Position {
+    pub fn new(
+        id: Uuid,
+        title: impl Into<String>,
+        job_id: Uuid,
+        budget: f64,
+        division_id: Uuid,
+        payroll_id: Uuid,
+    ) -> Self {
+        Self {
+            id,
+            title: title.into(),
+            job_id,
+            budget,
+            division_id,
+            payroll_id,
+            employee_id: None,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn is_vacant(&self) -> bool {
+        self.employee_id.is_none()
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::employee::Employee;
+
+type ProfileCheck = (&'static str, fn(&Employee) -> bool);
+
+/// Profile details HR is expected to fill in before an employee is paid.
+pub const PROFILE_CHECKS: [ProfileCheck; 7] = [
+    ("address", |employee| !employee.address.trim().is_empty()),
+    ("phone", |employee| !employee.phone.trim().is_empty()),
+    ("email", |employee| employee.email.is_some()),
+    ("place_of_birth", |employee| {
+        !employee.place_of_birth.trim().is_empty()
+    }),
+    ("nationality", |employee| {
+        !employee.nationality.trim().is_empty()
+    }),
+    ("payment_instructions", |employee| {
+        !employee.payment_instructions.is_empty()
+    }),
+    ("photo", |employee| employee.photo_url.is_some()),
+];
+
+/// How much of an employee's profile is filled in.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct ProfileCompleteness {
+    pub employee_id: Uuid,
+    pub id_number: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub division_id: Uuid,
+    /// Percentage of checks passed, from 0 to 100.
+    pub score: f64,
+    /// Checks that failed; required custom fields appear as `custom_fields.<name>`.
+    pub missing: Vec<String>,
+}
+
+impl ProfileCompleteness {
+    /// Scores the employee against [`PROFILE_CHECKS`] and the organization's required
+    /// custom fields.
+    pub fn evaluate(employee: &Employee, required_custom_fields: &[String]) -> Self {
+        let mut missing: Vec<String> = PROFILE_CHECKS
+            .iter()
+            .filter(|(_, is_filled)| !is_filled(employee))
+            .map(|(name, _)| name.to_string())
+            .collect();
+        missing.extend(
+            required_custom_fields
+                .iter()
+                .filter(|name| {
+                    employee
+                        .custom_fields
+                        .get(name.as_str())
+                        .is_none_or(JsonValue::is_null)
+                })
+                .map(|name| format!("custom_fields.{name}")),
+        );
+
+        let total = PROFILE_CHECKS.len() + required_custom_fields.len();
+        let passed = total - missing.len();
+        Self {
+            employee_id: employee.id,
+            id_number: employee.id_number.clone(),
+            first_name: employee.first_name.clone(),
+            last_name: employee.last_name.clone(),
+            division_id: employee.division_id,
+            score: round_percentage(passed, total),
+            missing,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// Completeness across an organization, worst profiles first.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct OrganizationCompleteness {
+    pub organization_id: Uuid,
+    pub employees: usize,
+    pub complete: usize,
+    /// Mean of the employee scores; 100 for an organization without employees.
+    pub average_score: f64,
+    /// Number of employees failing each check.
+    pub missing_counts: BTreeMap<String, usize>,
+    /// Employees with at least one failed check.
+    pub incomplete: Vec<ProfileCompleteness>,
+}
+
+impl OrganizationCompleteness {
+    pub fn summarize(organization_id: Uuid, profiles: Vec<ProfileCompleteness>) -> Self {
+        let employees = profiles.len();
+        let average_score = if employees == 0 {
+            100.0
+        } else {
+            let sum: f64 = profiles.iter().map(|profile| profile.score).sum();
+            (sum / employees as f64 * 10.0).round() / 10.0
+        };
+
+        let mut missing_counts = BTreeMap::new();
+        for field in profiles.iter().flat_map(|profile| &profile.missing) {
+            *missing_counts.entry(field.clone()).or_insert(0) += 1;
+        }
+
+        let mut incomplete: Vec<ProfileCompleteness> = profiles
+            .into_iter()
+            .filter(|profile| !profile.is_complete())
+            .collect();
+        incomplete.sort_by(|a, b| a.score.total_cmp(&b.score));
+
+        Self {
+            organization_id,
+            employees,
+            complete: employees - incomplete.len(),
+            average_score,
+            missing_counts,
+            incomplete,
+        }
+    }
+}
+
+fn round_percentage(passed: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 100.0;
+    }
+    (passed as f64 / total as f64 * 1000.0).round() / 10.0
+}
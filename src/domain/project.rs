@@ -0,0 +1,62 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Client project or internal activity that timesheet hours are charged to.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct Project {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    /// Short identifier such as `ACME-01`, unique within the organization.
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Inactive projects keep their history but take no new hours.
+    pub active: bool,
+}
+
+impl Project {
+    pub fn new(
+        id: Uuid,
+        organization_id: Uuid,
+        code: impl Into<String>,
+        name: impl Into<String>,
+        description: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            organization_id,
+            code: code.into(),
+            name: name.into(),
+            description,
+            active: true,
+        }
+    }
+}
+
+/// Paid hours charged to one project and what they cost in one currency.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct ProjectLaborCost {
+    pub project_id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub currency: Option<String>,
+    pub hours: f64,
+    /// Hours times each employee's hourly rate, rounded to cents.
+    pub cost: f64,
+}
+
+/// Labor cost by project over `from`..=`to`, counting only hours that payroll pays: approved
+/// entries inside approved timesheets.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct ProjectLaborCostReport {
+    pub organization_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub to: NaiveDate,
+    pub projects: Vec<ProjectLaborCost>,
+    /// Paid hours not charged to any project.
+    pub untagged_hours: f64,
+}
@@ -0,0 +1,121 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::job::PayBasis;
+
+/// Largest increase, in percent, a single batch may apply.
+pub const MAX_ADJUSTMENT_PERCENTAGE: f64 = 100.0;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SalaryAdjustmentStatus {
+    Pending,
+    /// Approved but waiting for its effective date.
+    Approved,
+    Applied,
+    Rejected,
+}
+
+/// One job's salary before and after the adjustment.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct SalaryChange {
+    pub job_id: Uuid,
+    pub job_title: String,
+    pub pay_basis: PayBasis,
+    pub from_salary: f64,
+    pub to_salary: f64,
+    /// Employees holding the job when the batch was prepared.
+    pub headcount: u32,
+    /// What those employees cost per month before the change.
+    pub monthly_cost_before: f64,
+    pub monthly_cost_after: f64,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct SalaryAdjustmentTotals {
+    pub jobs: usize,
+    pub headcount: u32,
+    pub monthly_cost_before: f64,
+    pub monthly_cost_after: f64,
+    pub monthly_difference: f64,
+}
+
+impl SalaryAdjustmentTotals {
+    pub fn of(changes: &[SalaryChange]) -> Self {
+        let before: f64 = changes
+            .iter()
+            .map(|change| change.monthly_cost_before)
+            .sum();
+        let after: f64 = changes.iter().map(|change| change.monthly_cost_after).sum();
+        Self {
+            jobs: changes.len(),
+            headcount: changes.iter().map(|change| change.headcount).sum(),
+            monthly_cost_before: round_cents(before),
+            monthly_cost_after: round_cents(after),
+            monthly_difference: round_cents(after - before),
+        }
+    }
+}
+
+/// A percentage raise across a payroll's jobs, reviewed as a whole before it takes effect.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct SalaryAdjustmentBatch {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub percentage: f64,
+    #[schema(value_type = String, format = Date)]
+    pub effective_date: NaiveDate,
+    pub status: SalaryAdjustmentStatus,
+    pub changes: Vec<SalaryChange>,
+    pub totals: SalaryAdjustmentTotals,
+    /// Note left by whoever approved or rejected the batch.
+    pub decision_note: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub decided_at: Option<DateTime<Utc>>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub applied_at: Option<DateTime<Utc>>,
+}
+
+impl SalaryAdjustmentBatch {
+    pub fn new(
+        id: Uuid,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        percentage: f64,
+        effective_date: NaiveDate,
+        changes: Vec<SalaryChange>,
+    ) -> Self {
+        Self {
+            id,
+            organization_id,
+            payroll_id,
+            percentage,
+            effective_date,
+            status: SalaryAdjustmentStatus::Pending,
+            totals: SalaryAdjustmentTotals::of(&changes),
+            changes,
+            decision_note: None,
+            created_at: Utc::now(),
+            decided_at: None,
+            applied_at: None,
+        }
+    }
+
+    /// `salary` raised by `percentage`, rounded to cents.
+    pub fn adjusted(salary: f64, percentage: f64) -> f64 {
+        round_cents(salary * (1.0 + percentage / 100.0))
+    }
+
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.status == SalaryAdjustmentStatus::Approved && self.effective_date <= today
+    }
+}
+
+fn round_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Read-only credential that lets one employee see their own records through `/me` routes.
+///
+/// Only a SHA-256 digest of the secret is kept; the secret itself is shown once, at issue.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, ToSchema)]
+pub struct SelfServiceToken {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub employee_id: Uuid,
+    /// Where the token is used, e.g. the portal or device it was handed to.
+    pub label: Option<String>,
+    #[serde(skip)]
+    pub secret_hash: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub revoked_at: Option<DateTime<Utc>>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl SelfServiceToken {
+    pub fn new(
+        id: Uuid,
+        organization_id: Uuid,
+        employee_id: Uuid,
+        label: Option<String>,
+        secret_hash: String,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            organization_id,
+            employee_id,
+            label,
+            secret_hash,
+            created_at: Utc::now(),
+            expires_at,
+            revoked_at: None,
+            last_used_at: None,
+        }
+    }
+
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| now < expires_at)
+    }
+}
+
+/// A freshly issued token together with its secret.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct IssuedSelfServiceToken {
+    #[serde(flatten)]
+    pub token: SelfServiceToken,
+    /// Bearer secret for `Authorization` headers; it cannot be retrieved again.
+    pub secret: String,
+}
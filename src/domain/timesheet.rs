@@ -0,0 +1,200 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimesheetEntryStatus {
+    Submitted,
+    Approved,
+    Rejected,
+}
+
+/// Clock punches a timesheet entry was created from, as recorded by the time clock and after
+/// the payroll's rounding and grace rules. The entry's hours come from the adjusted pair, cut
+/// at midnight when the shift runs into the next day.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct ClockPunch {
+    #[schema(value_type = String, example = "08:00:00")]
+    pub clock_in: NaiveTime,
+    #[schema(value_type = String, example = "08:00:00")]
+    pub clock_out: NaiveTime,
+    #[schema(value_type = String, example = "08:00:00")]
+    pub adjusted_clock_in: NaiveTime,
+    #[schema(value_type = String, example = "08:00:00")]
+    pub adjusted_clock_out: NaiveTime,
+    /// The shift ran past midnight; its hours are split between an entry on the day it
+    /// started and one on the next day.
+    #[serde(default)]
+    pub overnight: bool,
+    /// The employee punched in more than once on the day the shift started.
+    #[serde(default)]
+    pub split: bool,
+    /// Set on the entry holding an overnight shift's hours after midnight, which starts at
+    /// midnight rather than at the punch-in.
+    #[serde(default)]
+    pub continued: bool,
+}
+
+/// Hours an employee logged on one day, optionally against a project or cost code.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct TimesheetEntry {
+    pub id: Uuid,
+    pub employee_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    pub hours: f64,
+    pub cost_code: Option<String>,
+    /// Project of the organization the hours are charged to.
+    pub project_id: Option<Uuid>,
+    pub note: Option<String>,
+    pub status: TimesheetEntryStatus,
+    /// Note left by whoever approved or rejected the entry.
+    pub review_note: Option<String>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub reviewed_at: Option<DateTime<Utc>>,
+    /// Set on entries imported from a time clock.
+    pub punch: Option<ClockPunch>,
+}
+
+impl TimesheetEntry {
+    pub fn new(
+        id: Uuid,
+        employee_id: Uuid,
+        date: NaiveDate,
+        hours: f64,
+        cost_code: Option<String>,
+        project_id: Option<Uuid>,
+        note: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            employee_id,
+            date,
+            hours,
+            cost_code,
+            project_id,
+            note,
+            status: TimesheetEntryStatus::Submitted,
+            review_note: None,
+            reviewed_at: None,
+            punch: None,
+        }
+    }
+
+    /// Orders entries by day, then by when the punched shift started on that day, so entries
+    /// sharing a day always list the same way. Entries logged by hand come first.
+    pub fn chronological_key(&self) -> (NaiveDate, Option<NaiveTime>, Uuid) {
+        let start = self.punch.map(|punch| {
+            if punch.continued {
+                NaiveTime::MIN
+            } else {
+                punch.adjusted_clock_in
+            }
+        });
+        (self.date, start, self.id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimesheetPeriodStatus {
+    Submitted,
+    Approved,
+    Rejected,
+}
+
+/// An employee's timesheet for a pay period, submitted as a whole for their manager's
+/// approval. Entries inside a submitted or approved period can no longer change, and only
+/// hours inside an approved period are paid.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct TimesheetPeriod {
+    pub id: Uuid,
+    pub employee_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub period_start: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub period_end: NaiveDate,
+    /// Hours logged in the period when it was submitted.
+    pub hours: f64,
+    pub status: TimesheetPeriodStatus,
+    #[schema(value_type = String, format = DateTime)]
+    pub submitted_at: DateTime<Utc>,
+    /// Supervisor or division manager who approved or rejected the timesheet.
+    pub reviewed_by: Option<Uuid>,
+    pub review_note: Option<String>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl TimesheetPeriod {
+    pub fn new(
+        id: Uuid,
+        employee_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        hours: f64,
+    ) -> Self {
+        Self {
+            id,
+            employee_id,
+            period_start,
+            period_end,
+            hours,
+            status: TimesheetPeriodStatus::Submitted,
+            submitted_at: Utc::now(),
+            reviewed_by: None,
+            review_note: None,
+            reviewed_at: None,
+        }
+    }
+
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        self.period_start <= date && date <= self.period_end
+    }
+
+    pub fn overlaps(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        self.period_start <= end && start <= self.period_end
+    }
+
+    /// Whether the period holds its entries: submitted and awaiting review, or approved.
+    pub fn locks_entries(&self) -> bool {
+        self.status != TimesheetPeriodStatus::Rejected
+    }
+}
+
+/// Why an employee's hours cannot be paid yet.
+#[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UnapprovedTimesheetReason {
+    NotSubmitted,
+    AwaitingApproval,
+    Rejected,
+}
+
+/// Logged hours of one employee that a payroll run would leave out.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct UnapprovedTimesheet {
+    pub employee_id: Uuid,
+    pub division_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub reason: UnapprovedTimesheetReason,
+    /// The submitted or rejected timesheet the hours belong to, if any.
+    pub period_id: Option<Uuid>,
+    pub hours: f64,
+}
+
+/// Timesheets that block a payroll run over `from`..=`to` until they are approved.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct UnapprovedTimesheetReport {
+    pub payroll_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub to: NaiveDate,
+    /// Set when every logged hour in the range is covered by an approved timesheet.
+    pub ready: bool,
+    pub blocking: Vec<UnapprovedTimesheet>,
+}
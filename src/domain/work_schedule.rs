@@ -0,0 +1,135 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Hours expected on one day of the week.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct ScheduleDay {
+    /// `Mon` through `Sun`; full day names are accepted on input.
+    #[schema(value_type = String, example = "Mon")]
+    pub weekday: Weekday,
+    pub hours: f64,
+}
+
+/// Weekly working pattern assigned to employees in place of a flat hour count.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct WorkSchedule {
+    pub id: Uuid,
+    pub name: String,
+    /// Working days, Monday first.
+    pub days: Vec<ScheduleDay>,
+    /// Hours in a day after which time counts as overtime.
+    pub daily_overtime_after: Option<f64>,
+    /// Hours in a week after which time counts as overtime; defaults to the scheduled hours.
+    pub weekly_overtime_after: Option<f64>,
+    pub organization_id: Uuid,
+}
+
+impl WorkSchedule {
+    pub fn new(
+        id: Uuid,
+        name: impl Into<String>,
+        days: Vec<ScheduleDay>,
+        daily_overtime_after: Option<f64>,
+        weekly_overtime_after: Option<f64>,
+        organization_id: Uuid,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            days,
+            daily_overtime_after,
+            weekly_overtime_after,
+            organization_id,
+        }
+    }
+
+    pub fn weekly_hours(&self) -> f64 {
+        self.days.iter().map(|day| day.hours).sum()
+    }
+
+    /// Scheduled hours on `weekday`, zero on days off.
+    pub fn hours_on(&self, weekday: Weekday) -> f64 {
+        self.days
+            .iter()
+            .find(|day| day.weekday == weekday)
+            .map_or(0.0, |day| day.hours)
+    }
+}
+
+/// Hours an employee is expected to work and where their overtime starts.
+#[derive(Clone, Debug, Serialize, PartialEq, ToSchema)]
+pub struct WorkExpectation {
+    /// Schedule the figures come from; `None` when they fall back to the employee's `hours`.
+    pub schedule_id: Option<Uuid>,
+    pub weekly_hours: f64,
+    pub days: Vec<ScheduleDay>,
+    pub daily_overtime_after: Option<f64>,
+    pub weekly_overtime_after: f64,
+    /// Days worked each week: the scheduled days, or the organization's working week,
+    /// Monday first, for employees without a schedule.
+    pub working_days_per_week: usize,
+}
+
+impl WorkExpectation {
+    pub fn from_schedule(schedule: &WorkSchedule) -> Self {
+        let weekly_hours = schedule.weekly_hours();
+        Self {
+            schedule_id: Some(schedule.id),
+            weekly_hours,
+            days: schedule.days.clone(),
+            daily_overtime_after: schedule.daily_overtime_after,
+            weekly_overtime_after: schedule.weekly_overtime_after.unwrap_or(weekly_hours),
+            working_days_per_week: schedule.days.len(),
+        }
+    }
+
+    /// Expectation for employees without a schedule: a flat weekly hour count spread over
+    /// the first `working_days_per_week` days from Monday.
+    pub fn from_weekly_hours(hours: i32, working_days_per_week: usize) -> Self {
+        let weekly_hours = f64::from(hours);
+        Self {
+            schedule_id: None,
+            weekly_hours,
+            days: Vec::new(),
+            daily_overtime_after: None,
+            weekly_overtime_after: weekly_hours,
+            working_days_per_week,
+        }
+    }
+
+    pub fn works_on(&self, weekday: Weekday) -> bool {
+        if self.days.is_empty() {
+            (weekday.num_days_from_monday() as usize) < self.working_days_per_week
+        } else {
+            self.days.iter().any(|day| day.weekday == weekday)
+        }
+    }
+
+    /// Overtime in each day of `worked`: hours beyond the daily threshold, plus hours that
+    /// push the Monday-to-Sunday week past the weekly threshold, counted on the day they
+    /// accrue. Days without overtime are left out.
+    pub fn overtime_by_day(&self, worked: &BTreeMap<NaiveDate, f64>) -> BTreeMap<NaiveDate, f64> {
+        let mut straight_time: HashMap<NaiveDate, f64> = HashMap::new();
+        let mut overtime = BTreeMap::new();
+        for (date, hours) in worked {
+            let daily = self
+                .daily_overtime_after
+                .map_or(0.0, |limit| (hours - limit).max(0.0));
+            let regular = hours - daily;
+            let week = *date - Duration::days(i64::from(date.weekday().num_days_from_monday()));
+            let week_so_far = straight_time.entry(week).or_default();
+            let room = (self.weekly_overtime_after - *week_so_far).max(0.0);
+            let weekly = (regular - room).max(0.0);
+            *week_so_far += regular - weekly;
+
+            if daily + weekly > 0.0 {
+                overtime.insert(*date, daily + weekly);
+            }
+        }
+        overtime
+    }
+}
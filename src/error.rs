@@ -1,6 +1,6 @@
 use axum::{Json, http::StatusCode, response::IntoResponse};
 use serde::Serialize;
-use surrealdb::Error as SurrealError;
+use surrealdb::{Error as SurrealError, error::Db as SurrealDbError};
 use thiserror::Error;
 
 pub type AppResult<T> = Result<T, AppError>;
@@ -9,6 +9,8 @@ pub type AppResult<T> = Result<T, AppError>;
 pub enum AppError {
     #[error("validation error: {message}")]
     Validation { message: String },
+    #[error("unauthorized: {message}")]
+    Unauthorized { message: String },
     #[error("resource not found: {message}")]
     NotFound { message: String },
     #[error("conflict: {message}")]
@@ -26,6 +28,12 @@ impl AppError {
         }
     }
 
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::Unauthorized {
+            message: message.into(),
+        }
+    }
+
     pub fn not_found(message: impl Into<String>) -> Self {
         Self::NotFound {
             message: message.into(),
@@ -51,8 +59,33 @@ impl AppError {
     }
 }
 
+/// How the key-value stores word a [`SurrealDbError::Tx`] that lost a race with a concurrent
+/// transaction: a write conflict (SurrealKV, TiKV, FoundationDB) or a busy or try-again status
+/// (RocksDB). Any other transaction error, such as a full disk, is a real failure.
+const TRANSACTION_CONFLICTS: [&str; 3] = ["conflict", "resource busy", "try again"];
+
 impl From<SurrealError> for AppError {
     fn from(value: SurrealError) -> Self {
+        // A put whose key or expected value changed underneath it always lost a race; a plain
+        // transaction error only did when the store says so.
+        let conflicted = match &value {
+            SurrealError::Db(
+                SurrealDbError::TxConditionNotMet | SurrealDbError::TxKeyAlreadyExists,
+            ) => true,
+            SurrealError::Db(SurrealDbError::Tx(message)) => {
+                let lowered = message.to_lowercase();
+                TRANSACTION_CONFLICTS
+                    .iter()
+                    .any(|fragment| lowered.contains(fragment))
+            }
+            _ => false,
+        };
+        if conflicted {
+            return Self::Conflict {
+                message: format!("a concurrent change got in the way, retry the request: {value}"),
+            };
+        }
+
         Self::Database {
             message: value.to_string(),
         }
@@ -63,6 +96,7 @@ impl IntoResponse for AppError {
     fn into_response(self) -> axum::response::Response {
         let (status, message) = match &self {
             AppError::Validation { message } => (StatusCode::UNPROCESSABLE_ENTITY, message.clone()),
+            AppError::Unauthorized { message } => (StatusCode::UNAUTHORIZED, message.clone()),
             AppError::NotFound { message } => (StatusCode::NOT_FOUND, message.clone()),
             AppError::Conflict { message } => (StatusCode::CONFLICT, message.clone()),
             AppError::Database { message } => (
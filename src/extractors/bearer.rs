@@ -0,0 +1,29 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+
+use crate::error::AppError;
+
+/// Secret from an `Authorization: Bearer <secret>` header.
+#[derive(Debug, Clone)]
+pub struct BearerToken(pub String);
+
+impl<S> FromRequestParts<S> for BearerToken
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|secret| !secret.is_empty())
+            .map(|secret| Self(secret.to_string()))
+            .ok_or_else(|| AppError::unauthorized("missing bearer token"))
+    }
+}
@@ -1,2 +1,4 @@
 #![allow(dead_code)]
 //! Request extractors (auth context, pagination, etc.) live here.
+
+pub mod bearer;
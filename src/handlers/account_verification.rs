@@ -0,0 +1,182 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::employee::{
+        AccountVerificationMethod, AccountVerificationStatus, PaymentInstruction,
+        UnverifiedAccountReport,
+    },
+    error::{AppError, AppResult},
+    server::AppState,
+    services::employee::RecordAccountVerificationParams,
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct PaymentInstructionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+    /// Priority of the payment instruction whose account is verified.
+    pub priority: u32,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct UnverifiedAccountsPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MicroDepositOutcome {
+    /// Deposits were sent; the account stays pending until the amounts are confirmed.
+    Sent,
+    /// The employee confirmed the deposited amounts.
+    Confirmed,
+    /// The amounts did not match or the deposits bounced.
+    Failed,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RecordMicroDepositRequest {
+    pub outcome: MicroDepositOutcome,
+    /// Trace number of the deposits.
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RecordDocumentVerificationRequest {
+    /// Identifier of the bank letter, voided cheque or statement that was checked.
+    pub reference: String,
+    /// Whether the document shows the employee holds the account.
+    pub accepted: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/payment-instructions/{priority}/micro-deposits",
+    params(PaymentInstructionPathParams),
+    request_body = RecordMicroDepositRequest,
+    responses(
+        (status = 200, description = "Micro-deposit outcome recorded on the account", body = PaymentInstruction),
+        (status = 404, description = "Employee or payment instruction not found"),
+        (status = 409, description = "Confirmed before the deposits were sent")
+    ),
+    tag = "Employees",
+    operation_id = "record_micro_deposit_verification"
+)]
+pub async fn record_micro_deposit(
+    State(state): State<AppState>,
+    Path(params): Path<PaymentInstructionPathParams>,
+    Json(payload): Json<RecordMicroDepositRequest>,
+) -> AppResult<Json<PaymentInstruction>> {
+    let status = match payload.outcome {
+        MicroDepositOutcome::Sent => AccountVerificationStatus::Pending,
+        MicroDepositOutcome::Confirmed => AccountVerificationStatus::Verified,
+        MicroDepositOutcome::Failed => AccountVerificationStatus::Unverified,
+    };
+
+    record(
+        state,
+        params,
+        RecordAccountVerificationParams {
+            method: AccountVerificationMethod::MicroDeposit,
+            status,
+            reference: payload.reference,
+        },
+    )
+    .await
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/payment-instructions/{priority}/document-verification",
+    params(PaymentInstructionPathParams),
+    request_body = RecordDocumentVerificationRequest,
+    responses(
+        (status = 200, description = "Document check recorded on the account", body = PaymentInstruction),
+        (status = 404, description = "Employee or payment instruction not found"),
+        (status = 422, description = "Missing document reference")
+    ),
+    tag = "Employees",
+    operation_id = "record_document_verification"
+)]
+pub async fn record_document(
+    State(state): State<AppState>,
+    Path(params): Path<PaymentInstructionPathParams>,
+    Json(payload): Json<RecordDocumentVerificationRequest>,
+) -> AppResult<Json<PaymentInstruction>> {
+    let status = if payload.accepted {
+        AccountVerificationStatus::Verified
+    } else {
+        AccountVerificationStatus::Unverified
+    };
+
+    record(
+        state,
+        params,
+        RecordAccountVerificationParams {
+            method: AccountVerificationMethod::Document,
+            status,
+            reference: Some(payload.reference),
+        },
+    )
+    .await
+}
+
+async fn record(
+    state: AppState,
+    params: PaymentInstructionPathParams,
+    verification: RecordAccountVerificationParams,
+) -> AppResult<Json<PaymentInstruction>> {
+    let instruction = state
+        .employee_service()
+        .record_account_verification(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.priority,
+            verification,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(instruction))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/unverified-accounts",
+    params(UnverifiedAccountsPathParams),
+    responses(
+        (status = 200, description = "Accounts not verified yet, whose employees payment batches hold back when the payroll blocks unverified accounts", body = UnverifiedAccountReport),
+        (status = 404, description = "Payroll not found")
+    ),
+    tag = "Employees",
+    operation_id = "list_unverified_accounts"
+)]
+pub async fn unverified_accounts(
+    State(state): State<AppState>,
+    Path(params): Path<UnverifiedAccountsPathParams>,
+) -> AppResult<Json<UnverifiedAccountReport>> {
+    let report = state
+        .employee_service()
+        .unverified_accounts(params.organization_id, params.payroll_id)
+        .await?;
+
+    Ok(Json(report))
+}
@@ -0,0 +1,47 @@
+use axum::{Json, extract::Query, http::header, response::IntoResponse};
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
+
+use crate::{
+    error::{AppError, AppResult},
+    openapi::ApiDoc,
+    services::api_collection::postman_collection,
+};
+
+const DEFAULT_BASE_URL: &str = "http://localhost:3000";
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PostmanCollectionQuery {
+    /// Value for the collection's `baseUrl` variable; defaults to `http://localhost:3000`.
+    pub base_url: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api-docs/postman.json",
+    params(PostmanCollectionQuery),
+    responses(
+        (status = 200, description = "Postman v2.1 collection generated from this document", body = Object)
+    ),
+    tag = "API Docs",
+    operation_id = "download_postman_collection"
+)]
+pub async fn postman(Query(query): Query<PostmanCollectionQuery>) -> AppResult<impl IntoResponse> {
+    let document = serde_json::to_value(ApiDoc::openapi())
+        .map_err(|err| AppError::internal(format!("failed to render OpenAPI document: {err}")))?;
+    let base_url = query
+        .base_url
+        .as_deref()
+        .map(|value| value.trim().trim_end_matches('/'))
+        .filter(|value| !value.is_empty())
+        .unwrap_or(DEFAULT_BASE_URL);
+
+    Ok((
+        [(
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"nomina.postman_collection.json\"",
+        )],
+        Json(postman_collection(&document, base_url)),
+    ))
+}
@@ -0,0 +1,167 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, header},
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::attendance::{AttendanceImportReport, ClockRecord, PunchPolicy},
+    error::AppResult,
+    server::AppState,
+    services::attendance_import::{AttendanceFormat, ImportAttendanceParams},
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct AttendanceImportPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct PunchPolicyPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetPunchPolicyRequest {
+    /// Round punches to the nearest 5, 10 or 15 minutes; `null` keeps them as recorded.
+    pub rounding_minutes: Option<u32>,
+    /// Minutes a clock-in may run past, or a clock-out fall short of, a rounding increment
+    /// and still count from it.
+    #[serde(default)]
+    pub grace_minutes: u32,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AttendanceImportQuery {
+    /// First day to reconcile; defaults to the earliest date in the file.
+    #[param(value_type = Option<String>, format = Date)]
+    pub from: Option<NaiveDate>,
+    /// Last day to reconcile; defaults to the latest date in the file.
+    #[param(value_type = Option<String>, format = Date)]
+    pub to: Option<NaiveDate>,
+}
+
+impl AttendanceImportQuery {
+    fn into_params(self) -> ImportAttendanceParams {
+        ImportAttendanceParams {
+            from: self.from,
+            to: self.to,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/attendance/import",
+    params(AttendanceImportPathParams, AttendanceImportQuery),
+    request_body(
+        description = "Clock records as a JSON array, or as CSV with `id_number,date,clock_in,clock_out` headers; punches are adjusted by the payroll's punch policy",
+        content(
+            (Vec<ClockRecord> = "application/json"),
+            (String = "text/csv")
+        )
+    ),
+    responses(
+        (status = 200, description = "Per-record import outcome and schedule anomalies", body = AttendanceImportReport),
+        (status = 404, description = "Division not found"),
+        (status = 422, description = "Malformed or empty file, or an inverted reconciliation range")
+    ),
+    tag = "Timesheets",
+    operation_id = "import_attendance"
+)]
+pub async fn import(
+    State(state): State<AppState>,
+    Path(params): Path<AttendanceImportPathParams>,
+    Query(query): Query<AttendanceImportQuery>,
+    headers: HeaderMap,
+    body: String,
+) -> AppResult<Json<AttendanceImportReport>> {
+    let format = match headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("application/json") => AttendanceFormat::Json,
+        _ => AttendanceFormat::Csv,
+    };
+
+    let report = state
+        .attendance_import_service()
+        .import(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            query.into_params(),
+            format,
+            &body,
+        )
+        .await?;
+
+    Ok(Json(report))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/punch-policy",
+    params(PunchPolicyPathParams),
+    responses(
+        (status = 200, description = "Punch rounding and grace rules; none when the payroll has not set any", body = PunchPolicy),
+        (status = 404, description = "Payroll not found")
+    ),
+    tag = "Timesheets",
+    operation_id = "get_punch_policy"
+)]
+pub async fn get_punch_policy(
+    State(state): State<AppState>,
+    Path(params): Path<PunchPolicyPathParams>,
+) -> AppResult<Json<PunchPolicy>> {
+    let policy = state
+        .attendance_import_service()
+        .punch_policy(params.organization_id, params.payroll_id)
+        .await?;
+
+    Ok(Json(policy))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/punch-policy",
+    params(PunchPolicyPathParams),
+    request_body = SetPunchPolicyRequest,
+    responses(
+        (status = 200, description = "Punch policy replaced; applies to later imports", body = PunchPolicy),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Unsupported increment, or a grace period without rounding or as long as an increment")
+    ),
+    tag = "Timesheets",
+    operation_id = "set_punch_policy"
+)]
+pub async fn set_punch_policy(
+    State(state): State<AppState>,
+    Path(params): Path<PunchPolicyPathParams>,
+    Json(payload): Json<SetPunchPolicyRequest>,
+) -> AppResult<Json<PunchPolicy>> {
+    let policy = state
+        .attendance_import_service()
+        .set_punch_policy(
+            params.organization_id,
+            params.payroll_id,
+            payload.rounding_minutes,
+            payload.grace_minutes,
+        )
+        .await?;
+
+    Ok(Json(policy))
+}
@@ -1,27 +1,99 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
-    domain::bank::Bank,
+    domain::bank::{AccountRule, Bank, CatalogBank, PaymentFormat},
     error::{AppError, AppResult},
     server::AppState,
-    services::bank::{CreateBankParams, UpdateBankParams},
+    services::bank::{
+        CreateBankParams, CreateCatalogBankParams, UpdateBankParams, UpdateCatalogBankParams,
+    },
 };
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateBankRequest {
     pub name: String,
+    /// 8 or 11 character SWIFT/BIC code.
+    pub swift_code: Option<String>,
+    /// National routing number or sort code; spaces and hyphens are dropped.
+    pub routing_number: Option<String>,
+    /// Two-letter ISO 3166-1 country code.
+    pub country: Option<String>,
+    pub address: Option<String>,
+    /// Checks employee account numbers at this bank have to pass.
+    pub account_rule: Option<AccountRule>,
+    /// Format payment batches for this bank are rendered in.
+    pub payment_format: Option<PaymentFormat>,
+    /// Catalog bank to inherit the SWIFT code, routing number and country from; those
+    /// fields must then be left out.
+    pub catalog_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateBankRequest {
     pub name: Option<String>,
+    /// `null` clears the SWIFT code.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub swift_code: Option<Option<String>>,
+    /// `null` clears the routing number.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub routing_number: Option<Option<String>>,
+    /// `null` clears the country.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub country: Option<Option<String>>,
+    /// `null` clears the address.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub address: Option<Option<String>>,
+    /// `null` removes the account checks.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<AccountRule>)]
+    pub account_rule: Option<Option<AccountRule>>,
+    /// `null` clears the payment format.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<PaymentFormat>)]
+    pub payment_format: Option<Option<PaymentFormat>>,
+    /// Links the bank to a catalog entry; `null` unlinks it and keeps the inherited details.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<Uuid>)]
+    pub catalog_id: Option<Option<Uuid>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateCatalogBankRequest {
+    pub name: String,
+    /// 8 or 11 character SWIFT/BIC code.
+    pub swift_code: Option<String>,
+    /// National routing number or sort code; spaces and hyphens are dropped.
+    pub routing_number: Option<String>,
+    /// Two-letter ISO 3166-1 country code.
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateCatalogBankRequest {
+    pub name: Option<String>,
+    /// `null` clears the SWIFT code.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub swift_code: Option<Option<String>>,
+    /// `null` clears the routing number.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub routing_number: Option<Option<String>>,
+    /// `null` clears the country.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub country: Option<Option<String>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -29,6 +101,13 @@ pub struct BankResponse {
     pub id: Uuid,
     pub name: String,
     pub organization_id: Uuid,
+    pub swift_code: Option<String>,
+    pub routing_number: Option<String>,
+    pub country: Option<String>,
+    pub address: Option<String>,
+    pub account_rule: Option<AccountRule>,
+    pub payment_format: Option<PaymentFormat>,
+    pub catalog_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, IntoParams)]
@@ -44,35 +123,83 @@ pub struct BankPathParams {
     pub bank_id: Uuid,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct CatalogBankPathParams {
+    pub catalog_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeleteBankQuery {
+    /// Bank of the same organization to move employees' payment instructions to before deleting.
+    pub reassign_to: Option<Uuid>,
+}
+
 impl From<Bank> for BankResponse {
     fn from(value: Bank) -> Self {
         Self {
             id: value.id,
             name: value.name,
             organization_id: value.organization_id,
+            swift_code: value.swift_code,
+            routing_number: value.routing_number,
+            country: value.country,
+            address: value.address,
+            account_rule: value.account_rule,
+            payment_format: value.payment_format,
+            catalog_id: value.catalog_id,
         }
     }
 }
 
 impl CreateBankRequest {
     fn into_params(self) -> CreateBankParams {
-        CreateBankParams { name: self.name }
+        CreateBankParams {
+            name: self.name,
+            swift_code: self.swift_code,
+            routing_number: self.routing_number,
+            country: self.country,
+            address: self.address,
+            account_rule: self.account_rule,
+            payment_format: self.payment_format,
+            catalog_id: self.catalog_id,
+        }
     }
 }
 
 impl UpdateBankRequest {
     fn into_params(self) -> UpdateBankParams {
-        UpdateBankParams { name: self.name }
+        UpdateBankParams {
+            name: self.name,
+            swift_code: self.swift_code,
+            routing_number: self.routing_number,
+            country: self.country,
+            address: self.address,
+            account_rule: self.account_rule,
+            payment_format: self.payment_format,
+            catalog_id: self.catalog_id,
+        }
     }
 }
 
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Some(Option::deserialize(deserializer)?))
+}
+
 #[utoipa::path(
     post,
     path = "/organizations/{organization_id}/banks",
     params(OrganizationPathParams),
     request_body = CreateBankRequest,
     responses(
-        (status = 201, description = "Bank created", body = BankResponse)
+        (status = 201, description = "Bank created", body = BankResponse),
+        (status = 404, description = "Catalog bank not found"),
+        (status = 422, description = "Empty name, malformed bank details, or details given alongside a catalog bank")
     ),
     tag = "Banks",
     operation_id = "create_bank"
@@ -145,7 +272,8 @@ pub async fn get(
     request_body = UpdateBankRequest,
     responses(
         (status = 200, description = "Bank updated", body = BankResponse),
-        (status = 404, description = "Bank not found")
+        (status = 404, description = "Bank or catalog bank not found"),
+        (status = 422, description = "Malformed bank details, or inherited details changed on a linked bank")
     ),
     tag = "Banks",
     operation_id = "update_bank"
@@ -176,10 +304,12 @@ pub async fn update(
 #[utoipa::path(
     delete,
     path = "/organizations/{organization_id}/banks/{bank_id}",
-    params(BankPathParams),
+    params(BankPathParams, DeleteBankQuery),
     responses(
         (status = 204, description = "Bank deleted"),
-        (status = 404, description = "Bank not found")
+        (status = 404, description = "Bank or reassignment target not found"),
+        (status = 409, description = "Employees are still paid through the bank"),
+        (status = 422, description = "Bank reassigned to itself, or an account fails the target bank's rule")
     ),
     tag = "Banks",
     operation_id = "delete_bank"
@@ -187,10 +317,11 @@ pub async fn update(
 pub async fn delete(
     State(state): State<AppState>,
     Path(params): Path<BankPathParams>,
+    Query(query): Query<DeleteBankQuery>,
 ) -> AppResult<StatusCode> {
     let removed = state
         .bank_service()
-        .delete(params.organization_id, params.bank_id)
+        .delete(params.organization_id, params.bank_id, query.reassign_to)
         .await?;
 
     if removed {
@@ -202,3 +333,137 @@ pub async fn delete(
         )))
     }
 }
+
+fn catalog_entry_not_found(catalog_id: Uuid) -> AppError {
+    AppError::not_found(format!("catalog bank `{catalog_id}` not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/bank-catalog",
+    request_body = CreateCatalogBankRequest,
+    responses(
+        (status = 201, description = "Bank added to the catalog shared by all organizations", body = CatalogBank),
+        (status = 422, description = "Empty name or malformed bank details")
+    ),
+    tag = "Banks",
+    operation_id = "create_catalog_bank"
+)]
+pub async fn create_catalog_entry(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateCatalogBankRequest>,
+) -> AppResult<(StatusCode, Json<CatalogBank>)> {
+    let entry = state
+        .bank_service()
+        .create_catalog_entry(CreateCatalogBankParams {
+            name: payload.name,
+            swift_code: payload.swift_code,
+            routing_number: payload.routing_number,
+            country: payload.country,
+        })
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/bank-catalog",
+    responses(
+        (status = 200, description = "Catalog banks by country and name", body = [CatalogBank])
+    ),
+    tag = "Banks",
+    operation_id = "list_catalog_banks"
+)]
+pub async fn list_catalog(State(state): State<AppState>) -> AppResult<Json<Vec<CatalogBank>>> {
+    let entries = state.bank_service().list_catalog().await?;
+    Ok(Json(entries))
+}
+
+#[utoipa::path(
+    get,
+    path = "/bank-catalog/{catalog_id}",
+    params(CatalogBankPathParams),
+    responses(
+        (status = 200, description = "Get catalog bank", body = CatalogBank),
+        (status = 404, description = "Catalog bank not found")
+    ),
+    tag = "Banks",
+    operation_id = "get_catalog_bank"
+)]
+pub async fn get_catalog_entry(
+    State(state): State<AppState>,
+    Path(params): Path<CatalogBankPathParams>,
+) -> AppResult<Json<CatalogBank>> {
+    let entry = state
+        .bank_service()
+        .get_catalog_entry(params.catalog_id)
+        .await?
+        .ok_or_else(|| catalog_entry_not_found(params.catalog_id))?;
+
+    Ok(Json(entry))
+}
+
+#[utoipa::path(
+    put,
+    path = "/bank-catalog/{catalog_id}",
+    params(CatalogBankPathParams),
+    request_body = UpdateCatalogBankRequest,
+    responses(
+        (status = 200, description = "Catalog bank updated; linked banks inherit the new details", body = CatalogBank),
+        (status = 404, description = "Catalog bank not found"),
+        (status = 409, description = "A linked bank's payment format needs the details being removed"),
+        (status = 422, description = "Malformed bank details")
+    ),
+    tag = "Banks",
+    operation_id = "update_catalog_bank"
+)]
+pub async fn update_catalog_entry(
+    State(state): State<AppState>,
+    Path(params): Path<CatalogBankPathParams>,
+    Json(payload): Json<UpdateCatalogBankRequest>,
+) -> AppResult<Json<CatalogBank>> {
+    let entry = state
+        .bank_service()
+        .update_catalog_entry(
+            params.catalog_id,
+            UpdateCatalogBankParams {
+                name: payload.name,
+                swift_code: payload.swift_code,
+                routing_number: payload.routing_number,
+                country: payload.country,
+            },
+        )
+        .await?
+        .ok_or_else(|| catalog_entry_not_found(params.catalog_id))?;
+
+    Ok(Json(entry))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/bank-catalog/{catalog_id}",
+    params(CatalogBankPathParams),
+    responses(
+        (status = 204, description = "Catalog bank deleted"),
+        (status = 404, description = "Catalog bank not found"),
+        (status = 409, description = "Organization banks are still linked to it")
+    ),
+    tag = "Banks",
+    operation_id = "delete_catalog_bank"
+)]
+pub async fn delete_catalog_entry(
+    State(state): State<AppState>,
+    Path(params): Path<CatalogBankPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .bank_service()
+        .delete_catalog_entry(params.catalog_id)
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(catalog_entry_not_found(params.catalog_id))
+    }
+}
@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::{
+    domain::employee_export::ExportFormat, error::AppResult, server::AppState,
+    services::benchmark_export::BenchmarkExportParams,
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct BenchmarkExportPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct BenchmarkExportQuery {
+    /// `csv` (default) or `xlsx`.
+    pub format: Option<ExportFormat>,
+    /// Width of the annual pay bands (default 5000).
+    pub pay_band_width: Option<u32>,
+    /// Buckets with fewer employees are left out (default 5, at least 2).
+    pub min_group_size: Option<usize>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/benchmark-export",
+    params(BenchmarkExportPathParams, BenchmarkExportQuery),
+    responses(
+        (status = 200, description = "Anonymized headcount by job grade, region, currency and annual pay band", body = Vec<u8>, content_type = "text/csv"),
+        (status = 404, description = "Organization not found"),
+        (status = 422, description = "Invalid pay band width or minimum group size")
+    ),
+    tag = "Employees",
+    operation_id = "export_compensation_benchmark"
+)]
+pub async fn export(
+    State(state): State<AppState>,
+    Path(params): Path<BenchmarkExportPathParams>,
+    Query(query): Query<BenchmarkExportQuery>,
+) -> AppResult<impl IntoResponse> {
+    let format = query.format.unwrap_or_default();
+    let document = state
+        .benchmark_export_service()
+        .export(
+            params.organization_id,
+            BenchmarkExportParams {
+                format,
+                pay_band_width: query.pay_band_width,
+                min_group_size: query.min_group_size,
+            },
+        )
+        .await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"benchmark.{}\"", format.extension()),
+            ),
+        ],
+        document,
+    ))
+}
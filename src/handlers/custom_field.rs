@@ -0,0 +1,229 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::custom_field::{CustomFieldDefinition, CustomFieldType},
+    error::{AppError, AppResult},
+    server::AppState,
+    services::custom_field::{CreateCustomFieldParams, UpdateCustomFieldParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateCustomFieldRequest {
+    pub name: String,
+    pub field_type: CustomFieldType,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateCustomFieldRequest {
+    pub name: Option<String>,
+    pub field_type: Option<CustomFieldType>,
+    pub required: Option<bool>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomFieldResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub field_type: CustomFieldType,
+    pub required: bool,
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct OrganizationPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct CustomFieldPathParams {
+    pub organization_id: Uuid,
+    pub field_id: Uuid,
+}
+
+impl From<CustomFieldDefinition> for CustomFieldResponse {
+    fn from(value: CustomFieldDefinition) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            field_type: value.field_type,
+            required: value.required,
+            organization_id: value.organization_id,
+        }
+    }
+}
+
+impl CreateCustomFieldRequest {
+    fn into_params(self) -> CreateCustomFieldParams {
+        CreateCustomFieldParams {
+            name: self.name,
+            field_type: self.field_type,
+            required: self.required,
+        }
+    }
+}
+
+impl UpdateCustomFieldRequest {
+    fn into_params(self) -> UpdateCustomFieldParams {
+        UpdateCustomFieldParams {
+            name: self.name,
+            field_type: self.field_type,
+            required: self.required,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/custom-fields",
+    params(OrganizationPathParams),
+    request_body = CreateCustomFieldRequest,
+    responses(
+        (status = 201, description = "Custom field defined", body = CustomFieldResponse),
+        (status = 409, description = "Custom field name already in use")
+    ),
+    tag = "Custom Fields",
+    operation_id = "create_custom_field"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+    Json(payload): Json<CreateCustomFieldRequest>,
+) -> AppResult<(StatusCode, Json<CustomFieldResponse>)> {
+    let definition = state
+        .custom_field_service()
+        .create(params.organization_id, payload.into_params())
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(definition.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/custom-fields",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "List custom fields", body = [CustomFieldResponse])
+    ),
+    tag = "Custom Fields",
+    operation_id = "list_custom_fields"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<Json<Vec<CustomFieldResponse>>> {
+    let definitions = state
+        .custom_field_service()
+        .list(params.organization_id)
+        .await?;
+    let response = definitions
+        .into_iter()
+        .map(CustomFieldResponse::from)
+        .collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/custom-fields/{field_id}",
+    params(CustomFieldPathParams),
+    responses(
+        (status = 200, description = "Get custom field", body = CustomFieldResponse),
+        (status = 404, description = "Custom field not found")
+    ),
+    tag = "Custom Fields",
+    operation_id = "get_custom_field"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<CustomFieldPathParams>,
+) -> AppResult<Json<CustomFieldResponse>> {
+    let definition = state
+        .custom_field_service()
+        .get(params.organization_id, params.field_id)
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "custom field `{}` not found for organization `{}`",
+                params.field_id, params.organization_id
+            ))
+        })?;
+
+    Ok(Json(definition.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/custom-fields/{field_id}",
+    params(CustomFieldPathParams),
+    request_body = UpdateCustomFieldRequest,
+    responses(
+        (status = 200, description = "Custom field updated", body = CustomFieldResponse),
+        (status = 404, description = "Custom field not found"),
+        (status = 409, description = "Custom field name already in use")
+    ),
+    tag = "Custom Fields",
+    operation_id = "update_custom_field"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<CustomFieldPathParams>,
+    Json(payload): Json<UpdateCustomFieldRequest>,
+) -> AppResult<Json<CustomFieldResponse>> {
+    let definition = state
+        .custom_field_service()
+        .update(
+            params.organization_id,
+            params.field_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "custom field `{}` not found for organization `{}`",
+                params.field_id, params.organization_id
+            ))
+        })?;
+
+    Ok(Json(definition.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/custom-fields/{field_id}",
+    params(CustomFieldPathParams),
+    responses(
+        (status = 204, description = "Custom field deleted"),
+        (status = 404, description = "Custom field not found")
+    ),
+    tag = "Custom Fields",
+    operation_id = "delete_custom_field"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<CustomFieldPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .custom_field_service()
+        .delete(params.organization_id, params.field_id)
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::not_found(format!(
+            "custom field `{}` not found for organization `{}`",
+            params.field_id, params.organization_id
+        )))
+    }
+}
@@ -0,0 +1,264 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::dependent::Dependent,
+    error::{AppError, AppResult},
+    server::AppState,
+    services::dependent::{CreateDependentParams, UpdateDependentParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateDependentRequest {
+    pub name: String,
+    pub relationship: String,
+    #[schema(value_type = String, format = Date)]
+    pub date_of_birth: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateDependentRequest {
+    pub name: Option<String>,
+    pub relationship: Option<String>,
+    #[schema(value_type = Option<String>, format = Date)]
+    pub date_of_birth: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DependentResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub relationship: String,
+    #[schema(value_type = String, format = Date)]
+    pub date_of_birth: NaiveDate,
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct DependentCollectionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct DependentPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+    pub dependent_id: Uuid,
+}
+
+impl From<Dependent> for DependentResponse {
+    fn from(value: Dependent) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            relationship: value.relationship,
+            date_of_birth: value.date_of_birth,
+            employee_id: value.employee_id,
+        }
+    }
+}
+
+impl CreateDependentRequest {
+    fn into_params(self) -> CreateDependentParams {
+        CreateDependentParams {
+            name: self.name,
+            relationship: self.relationship,
+            date_of_birth: self.date_of_birth,
+        }
+    }
+}
+
+impl UpdateDependentRequest {
+    fn into_params(self) -> UpdateDependentParams {
+        UpdateDependentParams {
+            name: self.name,
+            relationship: self.relationship,
+            date_of_birth: self.date_of_birth,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/dependents",
+    params(DependentCollectionPathParams),
+    request_body = CreateDependentRequest,
+    responses(
+        (status = 201, description = "Dependent created", body = DependentResponse),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Dependents",
+    operation_id = "create_dependent"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<DependentCollectionPathParams>,
+    Json(payload): Json<CreateDependentRequest>,
+) -> AppResult<(StatusCode, Json<DependentResponse>)> {
+    let dependent = state
+        .dependent_service()
+        .create(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            payload.into_params(),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(dependent.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/dependents",
+    params(DependentCollectionPathParams),
+    responses(
+        (status = 200, description = "List dependents", body = [DependentResponse]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Dependents",
+    operation_id = "list_dependents"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<DependentCollectionPathParams>,
+) -> AppResult<Json<Vec<DependentResponse>>> {
+    let dependents = state
+        .dependent_service()
+        .list(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?;
+    let response = dependents
+        .into_iter()
+        .map(DependentResponse::from)
+        .collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/dependents/{dependent_id}",
+    params(DependentPathParams),
+    responses(
+        (status = 200, description = "Get dependent", body = DependentResponse),
+        (status = 404, description = "Dependent not found")
+    ),
+    tag = "Dependents",
+    operation_id = "get_dependent"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<DependentPathParams>,
+) -> AppResult<Json<DependentResponse>> {
+    let dependent = state
+        .dependent_service()
+        .get(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.dependent_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "dependent `{}` not found for employee `{}`",
+                params.dependent_id, params.employee_id
+            ))
+        })?;
+
+    Ok(Json(dependent.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/dependents/{dependent_id}",
+    params(DependentPathParams),
+    request_body = UpdateDependentRequest,
+    responses(
+        (status = 200, description = "Dependent updated", body = DependentResponse),
+        (status = 404, description = "Dependent not found")
+    ),
+    tag = "Dependents",
+    operation_id = "update_dependent"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<DependentPathParams>,
+    Json(payload): Json<UpdateDependentRequest>,
+) -> AppResult<Json<DependentResponse>> {
+    let dependent = state
+        .dependent_service()
+        .update(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.dependent_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "dependent `{}` not found for employee `{}`",
+                params.dependent_id, params.employee_id
+            ))
+        })?;
+
+    Ok(Json(dependent.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/dependents/{dependent_id}",
+    params(DependentPathParams),
+    responses(
+        (status = 204, description = "Dependent deleted"),
+        (status = 404, description = "Dependent not found")
+    ),
+    tag = "Dependents",
+    operation_id = "delete_dependent"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<DependentPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .dependent_service()
+        .delete(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.dependent_id,
+        )
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::not_found(format!(
+            "dependent `{}` not found for employee `{}`",
+            params.dependent_id, params.employee_id
+        )))
+    }
+}
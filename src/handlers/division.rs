@@ -1,6 +1,6 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
 use serde::{Deserialize, Deserializer, Serialize};
@@ -11,7 +11,9 @@ use crate::{
     domain::division::Division,
     error::{AppError, AppResult},
     server::AppState,
-    services::division::{CreateDivisionParams, UpdateDivisionParams},
+    services::division::{
+        CreateDivisionParams, DivisionDeletePolicy, MoveDivisionParams, UpdateDivisionParams,
+    },
 };
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -20,6 +22,10 @@ pub struct CreateDivisionRequest {
     pub description: String,
     pub budget_code: String,
     pub parent_division_id: Option<Uuid>,
+    /// Maximum active employees and filled positions; unlimited when omitted.
+    pub headcount_budget: Option<u32>,
+    /// Work location used to bucket reports, e.g. `North` or `US-CA`.
+    pub region: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -30,6 +36,14 @@ pub struct UpdateDivisionRequest {
     #[serde(default, deserialize_with = "deserialize_option_option")]
     #[schema(value_type = Option<Uuid>)]
     pub parent_division_id: Option<Option<Uuid>>,
+    /// `null` removes the limit.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<u32>)]
+    pub headcount_budget: Option<Option<u32>>,
+    /// `null` clears the region.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub region: Option<Option<String>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -40,6 +54,23 @@ pub struct DivisionResponse {
     pub budget_code: String,
     pub payroll_id: Uuid,
     pub parent_division_id: Option<Uuid>,
+    pub manager_employee_id: Option<Uuid>,
+    pub headcount_budget: Option<u32>,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AssignDivisionManagerRequest {
+    /// Employee of the same payroll; `null` removes the manager.
+    pub employee_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveDivisionRequest {
+    /// New parent in the destination payroll; omit or `null` to make the division a root.
+    pub parent_division_id: Option<Uuid>,
+    /// Destination payroll of the same organization; defaults to the current one.
+    pub payroll_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, IntoParams)]
@@ -57,6 +88,49 @@ pub struct DivisionPathParams {
     pub division_id: Uuid,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct FlatDivisionPathParams {
+    pub division_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DivisionSearchQuery {
+    pub organization_id: Uuid,
+    /// Only divisions of this payroll.
+    pub payroll_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeleteDivisionQuery {
+    /// Division of the same payroll to move employees, positions and child divisions to.
+    pub reassign_to: Option<Uuid>,
+    /// Delete child divisions, positions and every employee below the division as well.
+    #[serde(default)]
+    pub cascade: bool,
+}
+
+impl DeleteDivisionQuery {
+    fn policy(&self) -> AppResult<DivisionDeletePolicy> {
+        match (self.reassign_to, self.cascade) {
+            (Some(_), true) => Err(AppError::validation(
+                "reassign_to and cascade cannot be combined",
+            )),
+            (Some(target_id), false) => Ok(DivisionDeletePolicy::ReassignTo(target_id)),
+            (None, true) => Ok(DivisionDeletePolicy::Cascade),
+            (None, false) => Ok(DivisionDeletePolicy::Refuse),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OrganizationScopeQuery {
+    pub organization_id: Uuid,
+}
+
 impl From<Division> for DivisionResponse {
     fn from(value: Division) -> Self {
         Self {
@@ -66,6 +140,9 @@ impl From<Division> for DivisionResponse {
             budget_code: value.budget_code,
             payroll_id: value.payroll_id,
             parent_division_id: value.parent_division_id,
+            manager_employee_id: value.manager_employee_id,
+            headcount_budget: value.headcount_budget,
+            region: value.region,
         }
     }
 }
@@ -77,6 +154,8 @@ impl CreateDivisionRequest {
             description: self.description,
             budget_code: self.budget_code,
             parent_division_id: self.parent_division_id,
+            headcount_budget: self.headcount_budget,
+            region: self.region,
         }
     }
 }
@@ -88,13 +167,22 @@ impl UpdateDivisionRequest {
             description: self.description,
             budget_code: self.budget_code,
             parent_division_id: self.parent_division_id,
+            headcount_budget: self.headcount_budget,
+            region: self.region,
         }
     }
 }
 
-fn deserialize_option_option<'de, D>(deserializer: D) -> Result<Option<Option<Uuid>>, D::Error>
+fn flat_division_not_found(division_id: Uuid, organization_id: Uuid) -> AppError {
+    AppError::not_found(format!(
+        "division `{division_id}` not found in organization `{organization_id}`"
+    ))
+}
+
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
 where
     D: Deserializer<'de>,
+    T: Deserialize<'de>,
 {
     Ok(Some(Option::deserialize(deserializer)?))
 }
@@ -221,10 +309,12 @@ pub async fn update(
 #[utoipa::path(
     delete,
     path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}",
-    params(DivisionPathParams),
+    params(DivisionPathParams, DeleteDivisionQuery),
     responses(
         (status = 204, description = "Division deleted"),
-        (status = 404, description = "Division not found")
+        (status = 404, description = "Division or reassignment target not found"),
+        (status = 409, description = "Employees, positions or child divisions remain, or the target lacks budgeted headcount"),
+        (status = 422, description = "Target is the division or one of its descendants, or both options given")
     ),
     tag = "Divisions",
     operation_id = "delete_division"
@@ -232,13 +322,15 @@ pub async fn update(
 pub async fn delete(
     State(state): State<AppState>,
     Path(params): Path<DivisionPathParams>,
+    Query(query): Query<DeleteDivisionQuery>,
 ) -> AppResult<StatusCode> {
     let removed = state
-        .division_service()
-        .delete(
+        .employee_service()
+        .delete_division(
             params.organization_id,
             params.payroll_id,
             params.division_id,
+            query.policy()?,
         )
         .await?;
 
@@ -251,3 +343,209 @@ pub async fn delete(
         )))
     }
 }
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/manager",
+    params(DivisionPathParams),
+    request_body = AssignDivisionManagerRequest,
+    responses(
+        (status = 200, description = "Division manager assigned", body = DivisionResponse),
+        (status = 404, description = "Division or employee not found")
+    ),
+    tag = "Divisions",
+    operation_id = "assign_division_manager"
+)]
+pub async fn assign_manager(
+    State(state): State<AppState>,
+    Path(params): Path<DivisionPathParams>,
+    Json(payload): Json<AssignDivisionManagerRequest>,
+) -> AppResult<Json<DivisionResponse>> {
+    let division = state
+        .employee_service()
+        .assign_division_manager(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            payload.employee_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "division `{}` not found for payroll `{}` in organization `{}`",
+                params.division_id, params.payroll_id, params.organization_id
+            ))
+        })?;
+
+    Ok(Json(division.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/move",
+    params(DivisionPathParams),
+    request_body = MoveDivisionRequest,
+    responses(
+        (status = 200, description = "The moved division followed by its descendants", body = [DivisionResponse]),
+        (status = 404, description = "Division, parent or payroll not found"),
+        (status = 409, description = "The subtree still holds employees, positions or managers of its payroll"),
+        (status = 422, description = "Parent is inside the subtree or in another payroll")
+    ),
+    tag = "Divisions",
+    operation_id = "move_division"
+)]
+pub async fn move_subtree(
+    State(state): State<AppState>,
+    Path(params): Path<DivisionPathParams>,
+    Json(payload): Json<MoveDivisionRequest>,
+) -> AppResult<Json<Vec<DivisionResponse>>> {
+    let divisions = state
+        .division_service()
+        .move_subtree(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            MoveDivisionParams {
+                parent_division_id: payload.parent_division_id,
+                payroll_id: payload.payroll_id,
+            },
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "division `{}` not found for payroll `{}` in organization `{}`",
+                params.division_id, params.payroll_id, params.organization_id
+            ))
+        })?;
+
+    Ok(Json(
+        divisions.into_iter().map(DivisionResponse::from).collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/divisions",
+    params(DivisionSearchQuery),
+    responses(
+        (status = 200, description = "List the organization's divisions", body = [DivisionResponse]),
+        (status = 404, description = "Organization or payroll not found")
+    ),
+    tag = "Divisions",
+    operation_id = "search_divisions"
+)]
+pub async fn search(
+    State(state): State<AppState>,
+    Query(query): Query<DivisionSearchQuery>,
+) -> AppResult<Json<Vec<DivisionResponse>>> {
+    let divisions = state
+        .division_service()
+        .search(query.organization_id, query.payroll_id)
+        .await?;
+    let response = divisions.into_iter().map(DivisionResponse::from).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/divisions/{division_id}",
+    params(FlatDivisionPathParams, OrganizationScopeQuery),
+    responses(
+        (status = 200, description = "Get division", body = DivisionResponse),
+        (status = 404, description = "Division not found")
+    ),
+    tag = "Divisions",
+    operation_id = "find_division"
+)]
+pub async fn find(
+    State(state): State<AppState>,
+    Path(params): Path<FlatDivisionPathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+) -> AppResult<Json<DivisionResponse>> {
+    let division = state
+        .division_service()
+        .locate(query.organization_id, params.division_id)
+        .await?
+        .ok_or_else(|| flat_division_not_found(params.division_id, query.organization_id))?;
+
+    Ok(Json(division.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/divisions/{division_id}",
+    params(FlatDivisionPathParams, OrganizationScopeQuery),
+    request_body = UpdateDivisionRequest,
+    responses(
+        (status = 200, description = "Division updated", body = DivisionResponse),
+        (status = 404, description = "Division not found")
+    ),
+    tag = "Divisions",
+    operation_id = "update_division_by_id"
+)]
+pub async fn update_by_id(
+    State(state): State<AppState>,
+    Path(params): Path<FlatDivisionPathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+    Json(payload): Json<UpdateDivisionRequest>,
+) -> AppResult<Json<DivisionResponse>> {
+    let payroll_id = locate_payroll(&state, query.organization_id, params.division_id).await?;
+
+    update(
+        State(state),
+        Path(DivisionPathParams {
+            organization_id: query.organization_id,
+            payroll_id,
+            division_id: params.division_id,
+        }),
+        Json(payload),
+    )
+    .await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/divisions/{division_id}",
+    params(FlatDivisionPathParams, OrganizationScopeQuery, DeleteDivisionQuery),
+    responses(
+        (status = 204, description = "Division deleted"),
+        (status = 404, description = "Division or reassignment target not found"),
+        (status = 409, description = "Employees, positions or child divisions remain, or the target lacks budgeted headcount"),
+        (status = 422, description = "Target is the division or one of its descendants, or both options given")
+    ),
+    tag = "Divisions",
+    operation_id = "delete_division_by_id"
+)]
+pub async fn delete_by_id(
+    State(state): State<AppState>,
+    Path(params): Path<FlatDivisionPathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+    removal: Query<DeleteDivisionQuery>,
+) -> AppResult<StatusCode> {
+    let payroll_id = locate_payroll(&state, query.organization_id, params.division_id).await?;
+
+    delete(
+        State(state),
+        Path(DivisionPathParams {
+            organization_id: query.organization_id,
+            payroll_id,
+            division_id: params.division_id,
+        }),
+        removal,
+    )
+    .await
+}
+
+/// Resolves the payroll a flat route's division lives in, so it can reuse the nested handler.
+async fn locate_payroll(
+    state: &AppState,
+    organization_id: Uuid,
+    division_id: Uuid,
+) -> AppResult<Uuid> {
+    state
+        .division_service()
+        .locate(organization_id, division_id)
+        .await?
+        .map(|division| division.payroll_id)
+        .ok_or_else(|| flat_division_not_found(division_id, organization_id))
+}
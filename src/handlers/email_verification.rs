@@ -0,0 +1,104 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{error::AppResult, handlers::employee::EmployeeResponse, server::AppState};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct EmailVerificationPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmailVerificationSentResponse {
+    /// Address the code was sent to.
+    pub email: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub expires_at: DateTime<Utc>,
+    pub attempts_left: u32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ConfirmEmailRequest {
+    pub code: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/email-verification",
+    params(EmailVerificationPathParams),
+    responses(
+        (status = 202, description = "Verification code sent; any earlier code stops working", body = EmailVerificationSentResponse),
+        (status = 404, description = "Employee not found"),
+        (status = 409, description = "Email is already verified"),
+        (status = 422, description = "Employee has no email")
+    ),
+    tag = "Employees",
+    operation_id = "send_email_verification"
+)]
+pub async fn send(
+    State(state): State<AppState>,
+    Path(params): Path<EmailVerificationPathParams>,
+) -> AppResult<(StatusCode, Json<EmailVerificationSentResponse>)> {
+    let verification = state
+        .email_verification_service()
+        .send_code(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(EmailVerificationSentResponse {
+            email: verification.email,
+            expires_at: verification.expires_at,
+            attempts_left: verification.attempts_left,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/email-verification/confirm",
+    params(EmailVerificationPathParams),
+    request_body = ConfirmEmailRequest,
+    responses(
+        (status = 200, description = "Email verified", body = EmployeeResponse),
+        (status = 404, description = "Employee not found"),
+        (status = 409, description = "Email changed after the code was sent"),
+        (status = 422, description = "Code is wrong, expired or was never requested")
+    ),
+    tag = "Employees",
+    operation_id = "confirm_email_verification"
+)]
+pub async fn confirm(
+    State(state): State<AppState>,
+    Path(params): Path<EmailVerificationPathParams>,
+    Json(payload): Json<ConfirmEmailRequest>,
+) -> AppResult<Json<EmployeeResponse>> {
+    let employee = state
+        .email_verification_service()
+        .confirm(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            &payload.code,
+        )
+        .await?;
+
+    Ok(Json(employee.into()))
+}
@@ -1,27 +1,45 @@
 use axum::{
     Json,
-    extract::{Path, State},
-    http::StatusCode,
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
 };
-use chrono::NaiveDate;
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
-    domain::employee::Employee,
+    domain::{
+        blob::Blob,
+        employee::{Employee, EmployeeStatus, PaymentInstruction, WorkPermit},
+        employment_history::{EmploymentEvent, TimelineEntry},
+        job::{PayPeriod, PeriodPay},
+        pagination::{Page, PageRequest},
+        work_schedule::WorkExpectation,
+    },
     error::{AppError, AppResult},
     server::AppState,
-    services::employee::{CreateEmployeeParams, UpdateEmployeeParams},
+    services::{
+        employee::{CreateEmployeeParams, EmployeeListFilter, UpdateEmployeeParams},
+        employment_history::TerminateEmployeeParams,
+    },
 };
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateEmployeeRequest {
     pub id_number: String,
+    pub external_id: Option<String>,
     pub last_name: String,
     pub first_name: String,
     pub address: String,
     pub phone: String,
+    /// Must be unique within the organization; starts out unverified.
+    pub email: Option<String>,
     pub place_of_birth: String,
     #[schema(value_type = String, format = Date)]
     pub date_of_birth: NaiveDate,
@@ -32,21 +50,39 @@ pub struct CreateEmployeeRequest {
     pub hire_date: NaiveDate,
     #[schema(value_type = Option<String>, format = Date)]
     pub termination_date: Option<NaiveDate>,
+    /// Last day of probation; cannot be before `hire_date`.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub probation_end_date: Option<NaiveDate>,
+    /// Last day of a fixed-term contract; cannot be before `hire_date`.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub contract_end_date: Option<NaiveDate>,
+    pub work_permit: Option<WorkPermit>,
     pub clasification: String,
     pub job_id: Uuid,
-    pub bank_id: Uuid,
-    pub bank_account: String,
-    pub status: String,
+    /// Accounts the net pay is split across; percentages must add up to 100.
+    pub payment_instructions: Vec<PaymentInstruction>,
+    pub status: EmployeeStatus,
     pub hours: i32,
+    /// Work schedule that replaces `hours` for expected hours and overtime.
+    pub schedule_id: Option<Uuid>,
+    pub supervisor_id: Option<Uuid>,
+    /// Values keyed by the organization's custom field names.
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub custom_fields: BTreeMap<String, JsonValue>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateEmployeeRequest {
     pub id_number: Option<String>,
+    pub external_id: Option<String>,
     pub last_name: Option<String>,
     pub first_name: Option<String>,
     pub address: Option<String>,
     pub phone: Option<String>,
+    /// `null` removes the email; changing it requires verifying the new address.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    pub email: Option<Option<String>>,
     pub place_of_birth: Option<String>,
     #[schema(value_type = Option<String>, format = Date)]
     pub date_of_birth: Option<NaiveDate>,
@@ -58,22 +94,65 @@ pub struct UpdateEmployeeRequest {
     #[serde(default, deserialize_with = "deserialize_option_option")]
     #[schema(value_type = Option<String>, format = Date)]
     pub termination_date: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>, format = Date)]
+    pub probation_end_date: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>, format = Date)]
+    pub contract_end_date: Option<Option<NaiveDate>>,
+    /// `null` removes the work permit.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<WorkPermit>)]
+    pub work_permit: Option<Option<WorkPermit>>,
     pub clasification: Option<String>,
     pub job_id: Option<Uuid>,
-    pub bank_id: Option<Uuid>,
-    pub bank_account: Option<String>,
-    pub status: Option<String>,
+    /// Replaces every payment instruction when supplied.
+    pub payment_instructions: Option<Vec<PaymentInstruction>>,
+    pub status: Option<EmployeeStatus>,
     pub hours: Option<i32>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<Uuid>)]
+    pub schedule_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<Uuid>)]
+    pub supervisor_id: Option<Option<Uuid>>,
+    /// Replaces every custom field value when supplied.
+    #[schema(value_type = Option<Object>)]
+    pub custom_fields: Option<BTreeMap<String, JsonValue>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TerminateEmployeeRequest {
+    #[schema(value_type = String, format = Date)]
+    pub effective_date: NaiveDate,
+    pub reason_code: String,
+    #[serde(default = "default_true")]
+    pub eligible_for_rehire: bool,
+    #[serde(default)]
+    pub eligible_for_severance: bool,
+    /// Leave final-pay items pending for the next payroll run.
+    #[serde(default)]
+    pub flag_final_pay: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RehireEmployeeRequest {
+    #[schema(value_type = String, format = Date)]
+    pub hire_date: NaiveDate,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct EmployeeResponse {
     pub id: Uuid,
     pub id_number: String,
+    pub external_id: Option<String>,
     pub last_name: String,
     pub first_name: String,
     pub address: String,
     pub phone: String,
+    pub email: Option<String>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub email_verified_at: Option<DateTime<Utc>>,
     pub place_of_birth: String,
     #[schema(value_type = String, format = Date)]
     pub date_of_birth: NaiveDate,
@@ -84,14 +163,24 @@ pub struct EmployeeResponse {
     pub hire_date: NaiveDate,
     #[schema(value_type = Option<String>, format = Date)]
     pub termination_date: Option<NaiveDate>,
+    #[schema(value_type = Option<String>, format = Date)]
+    pub probation_end_date: Option<NaiveDate>,
+    #[schema(value_type = Option<String>, format = Date)]
+    pub contract_end_date: Option<NaiveDate>,
+    pub work_permit: Option<WorkPermit>,
     pub clasification: String,
     pub job_id: Uuid,
-    pub bank_id: Uuid,
-    pub bank_account: String,
-    pub status: String,
+    /// Accounts the net pay is split across; percentages must add up to 100.
+    pub payment_instructions: Vec<PaymentInstruction>,
+    pub status: EmployeeStatus,
     pub hours: i32,
+    pub schedule_id: Option<Uuid>,
     pub division_id: Uuid,
     pub payroll_id: Uuid,
+    pub supervisor_id: Option<Uuid>,
+    pub photo_url: Option<String>,
+    #[schema(value_type = Object)]
+    pub custom_fields: BTreeMap<String, JsonValue>,
 }
 
 #[derive(Debug, Deserialize, IntoParams)]
@@ -102,6 +191,14 @@ pub struct EmployeeCollectionPathParams {
     pub division_id: Uuid,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EmployeeListQuery {
+    /// Also list employees of every division below this one.
+    #[serde(default)]
+    pub include_descendants: bool,
+}
+
 #[derive(Debug, Deserialize, IntoParams)]
 #[into_params(parameter_in = Path)]
 pub struct EmployeePathParams {
@@ -111,15 +208,90 @@ pub struct EmployeePathParams {
     pub employee_id: Uuid,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct FlatEmployeePathParams {
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EmployeeSearchQuery {
+    pub organization_id: Uuid,
+    /// Only employees of this payroll.
+    pub payroll_id: Option<Uuid>,
+    /// Only employees of this division.
+    pub division_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct OrganizationEmployeesPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OrganizationEmployeesQuery {
+    pub payroll_id: Option<Uuid>,
+    pub division_id: Option<Uuid>,
+    pub job_id: Option<Uuid>,
+    pub status: Option<EmployeeStatus>,
+    /// Case-insensitive match on name, id number, external id or email.
+    pub q: Option<String>,
+    /// One-based page number; defaults to 1.
+    pub page: Option<usize>,
+    /// Employees per page, up to 200; defaults to 50.
+    pub per_page: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmployeePageResponse {
+    pub items: Vec<EmployeeResponse>,
+    pub page: usize,
+    pub per_page: usize,
+    /// Employees matching the filters across every page.
+    pub total: usize,
+}
+
+impl From<Page<Employee>> for EmployeePageResponse {
+    fn from(value: Page<Employee>) -> Self {
+        let page = value.map(EmployeeResponse::from);
+        Self {
+            items: page.items,
+            page: page.page,
+            per_page: page.per_page,
+            total: page.total,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OrganizationScopeQuery {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PeriodPayQuery {
+    /// Defaults to `monthly`.
+    #[serde(default)]
+    pub period: PayPeriod,
+}
+
 impl From<Employee> for EmployeeResponse {
     fn from(value: Employee) -> Self {
         Self {
             id: value.id,
             id_number: value.id_number,
+            external_id: value.external_id,
             last_name: value.last_name,
             first_name: value.first_name,
             address: value.address,
             phone: value.phone,
+            email: value.email,
+            email_verified_at: value.email_verified_at,
             place_of_birth: value.place_of_birth,
             date_of_birth: value.date_of_birth,
             nationality: value.nationality,
@@ -127,14 +299,20 @@ impl From<Employee> for EmployeeResponse {
             gender: value.gender,
             hire_date: value.hire_date,
             termination_date: value.termination_date,
+            probation_end_date: value.probation_end_date,
+            contract_end_date: value.contract_end_date,
+            work_permit: value.work_permit.as_ref().map(WorkPermit::masked),
             clasification: value.clasification,
             job_id: value.job_id,
-            bank_id: value.bank_id,
-            bank_account: value.bank_account,
+            payment_instructions: value.payment_instructions,
             status: value.status,
             hours: value.hours,
             division_id: value.division_id,
             payroll_id: value.payroll_id,
+            schedule_id: value.schedule_id,
+            supervisor_id: value.supervisor_id,
+            photo_url: value.photo_url,
+            custom_fields: value.custom_fields,
         }
     }
 }
@@ -143,10 +321,12 @@ impl CreateEmployeeRequest {
     fn into_params(self) -> CreateEmployeeParams {
         CreateEmployeeParams {
             id_number: self.id_number,
+            external_id: self.external_id,
             last_name: self.last_name,
             first_name: self.first_name,
             address: self.address,
             phone: self.phone,
+            email: self.email,
             place_of_birth: self.place_of_birth,
             date_of_birth: self.date_of_birth,
             nationality: self.nationality,
@@ -154,12 +334,17 @@ impl CreateEmployeeRequest {
             gender: self.gender,
             hire_date: self.hire_date,
             termination_date: self.termination_date,
+            probation_end_date: self.probation_end_date,
+            contract_end_date: self.contract_end_date,
+            work_permit: self.work_permit,
             clasification: self.clasification,
             job_id: self.job_id,
-            bank_id: self.bank_id,
-            bank_account: self.bank_account,
+            payment_instructions: self.payment_instructions,
             status: self.status,
             hours: self.hours,
+            schedule_id: self.schedule_id,
+            supervisor_id: self.supervisor_id,
+            custom_fields: self.custom_fields,
         }
     }
 }
@@ -168,10 +353,13 @@ impl UpdateEmployeeRequest {
     fn into_params(self) -> UpdateEmployeeParams {
         UpdateEmployeeParams {
             id_number: self.id_number,
+            external_id: self.external_id,
             last_name: self.last_name,
             first_name: self.first_name,
             address: self.address,
             phone: self.phone,
+            email: self.email,
+            email_verified_at: None,
             place_of_birth: self.place_of_birth,
             date_of_birth: self.date_of_birth,
             nationality: self.nationality,
@@ -179,19 +367,35 @@ impl UpdateEmployeeRequest {
             gender: self.gender,
             hire_date: self.hire_date,
             termination_date: self.termination_date,
+            probation_end_date: self.probation_end_date,
+            contract_end_date: self.contract_end_date,
+            work_permit: self.work_permit,
             clasification: self.clasification,
             job_id: self.job_id,
-            bank_id: self.bank_id,
-            bank_account: self.bank_account,
+            payment_instructions: self.payment_instructions,
             status: self.status,
             hours: self.hours,
+            schedule_id: self.schedule_id,
+            supervisor_id: self.supervisor_id,
+            custom_fields: self.custom_fields,
         }
     }
 }
 
-fn deserialize_option_option<'de, D>(deserializer: D) -> Result<Option<Option<NaiveDate>>, D::Error>
+fn flat_employee_not_found(employee_id: Uuid, organization_id: Uuid) -> AppError {
+    AppError::not_found(format!(
+        "employee `{employee_id}` not found in organization `{organization_id}`"
+    ))
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
 where
     D: Deserializer<'de>,
+    T: Deserialize<'de>,
 {
     Ok(Some(Option::deserialize(deserializer)?))
 }
@@ -228,7 +432,7 @@ pub async fn create(
 #[utoipa::path(
     get,
     path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees",
-    params(EmployeeCollectionPathParams),
+    params(EmployeeCollectionPathParams, EmployeeListQuery),
     responses(
         (status = 200, description = "List employees", body = [EmployeeResponse])
     ),
@@ -238,15 +442,26 @@ pub async fn create(
 pub async fn list(
     State(state): State<AppState>,
     Path(params): Path<EmployeeCollectionPathParams>,
+    Query(query): Query<EmployeeListQuery>,
 ) -> AppResult<Json<Vec<EmployeeResponse>>> {
-    let employees = state
-        .employee_service()
-        .list(
-            params.organization_id,
-            params.payroll_id,
-            params.division_id,
-        )
-        .await?;
+    let service = state.employee_service();
+    let employees = if query.include_descendants {
+        service
+            .list_with_descendants(
+                params.organization_id,
+                params.payroll_id,
+                params.division_id,
+            )
+            .await?
+    } else {
+        service
+            .list(
+                params.organization_id,
+                params.payroll_id,
+                params.division_id,
+            )
+            .await?
+    };
     let response = employees.into_iter().map(EmployeeResponse::from).collect();
     Ok(Json(response))
 }
@@ -356,3 +571,487 @@ pub async fn delete(
         )))
     }
 }
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/terminate",
+    params(EmployeePathParams),
+    request_body = TerminateEmployeeRequest,
+    responses(
+        (status = 200, description = "Employee terminated", body = EmployeeResponse),
+        (status = 404, description = "Employee not found"),
+        (status = 409, description = "Employee is already terminated"),
+        (status = 422, description = "Missing reason code or effective date before hire date")
+    ),
+    tag = "Employees",
+    operation_id = "terminate_employee"
+)]
+pub async fn terminate(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+    Json(payload): Json<TerminateEmployeeRequest>,
+) -> AppResult<Json<EmployeeResponse>> {
+    let employee = state
+        .employment_history_service()
+        .terminate(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            TerminateEmployeeParams {
+                effective_date: payload.effective_date,
+                reason_code: payload.reason_code,
+                eligible_for_rehire: payload.eligible_for_rehire,
+                eligible_for_severance: payload.eligible_for_severance,
+                flag_final_pay: payload.flag_final_pay,
+            },
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(employee.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/rehire",
+    params(EmployeePathParams),
+    request_body = RehireEmployeeRequest,
+    responses(
+        (status = 200, description = "Employee rehired as Onboarding", body = EmployeeResponse),
+        (status = 404, description = "Employee not found"),
+        (status = 409, description = "Employee is not terminated or not eligible for rehire")
+    ),
+    tag = "Employees",
+    operation_id = "rehire_employee"
+)]
+pub async fn rehire(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+    Json(payload): Json<RehireEmployeeRequest>,
+) -> AppResult<Json<EmployeeResponse>> {
+    let employee = state
+        .employment_history_service()
+        .rehire(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            payload.hire_date,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(employee.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/employment-history",
+    params(EmployeePathParams),
+    responses(
+        (status = 200, description = "Terminations, rehires, status and job changes, oldest first", body = [EmploymentEvent]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Employees",
+    operation_id = "list_employment_history"
+)]
+pub async fn employment_history(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+) -> AppResult<Json<Vec<EmploymentEvent>>> {
+    let events = state
+        .employment_history_service()
+        .list(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(events))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timeline",
+    params(EmployeePathParams),
+    responses(
+        (status = 200, description = "Chronological activity feed for the employee", body = [TimelineEntry]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Employees",
+    operation_id = "get_employee_timeline"
+)]
+pub async fn timeline(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+) -> AppResult<Json<Vec<TimelineEntry>>> {
+    let entries = state
+        .employment_history_service()
+        .timeline(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(entries))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/expected-hours",
+    params(EmployeePathParams),
+    responses(
+        (status = 200, description = "Expected hours and overtime thresholds", body = WorkExpectation),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Employees",
+    operation_id = "get_employee_expected_hours"
+)]
+pub async fn expected_hours(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+) -> AppResult<Json<WorkExpectation>> {
+    let expectation = state
+        .employee_service()
+        .work_expectation(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(expectation))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/period-pay",
+    params(EmployeePathParams, PeriodPayQuery),
+    responses(
+        (status = 200, description = "Job salary converted to one pay period", body = PeriodPay),
+        (status = 404, description = "Employee or job not found")
+    ),
+    tag = "Employees",
+    operation_id = "get_employee_period_pay"
+)]
+pub async fn period_pay(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+    Query(query): Query<PeriodPayQuery>,
+) -> AppResult<Json<PeriodPay>> {
+    let pay = state
+        .employee_service()
+        .period_pay(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            query.period,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(pay))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/direct-reports",
+    params(EmployeePathParams),
+    responses(
+        (status = 200, description = "Employees reporting to this employee", body = [EmployeeResponse]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Employees",
+    operation_id = "list_employee_direct_reports"
+)]
+pub async fn direct_reports(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+) -> AppResult<Json<Vec<EmployeeResponse>>> {
+    let reports = state
+        .employee_service()
+        .direct_reports(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(
+        reports.into_iter().map(EmployeeResponse::from).collect(),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/photo",
+    params(EmployeePathParams),
+    request_body(
+        content = Vec<u8>,
+        description = "JPEG, PNG, or WebP image up to 1 MiB",
+        content_type = "image/*"
+    ),
+    responses(
+        (status = 200, description = "Photo stored", body = EmployeeResponse),
+        (status = 404, description = "Employee not found"),
+        (status = 422, description = "Unsupported content type or size")
+    ),
+    tag = "Employees",
+    operation_id = "upload_employee_photo"
+)]
+pub async fn upload_photo(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> AppResult<Json<EmployeeResponse>> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+
+    let employee = state
+        .employee_service()
+        .upload_photo(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            Blob::new(content_type, body.to_vec()),
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(employee.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/photo",
+    params(EmployeePathParams),
+    responses(
+        (status = 200, description = "Employee photo", body = Vec<u8>, content_type = "image/*"),
+        (status = 404, description = "Employee or photo not found")
+    ),
+    tag = "Employees",
+    operation_id = "get_employee_photo"
+)]
+pub async fn get_photo(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeePathParams>,
+) -> AppResult<impl IntoResponse> {
+    let photo = state
+        .employee_service()
+        .photo(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "photo not found for employee `{}`",
+                params.employee_id
+            ))
+        })?;
+
+    Ok(([(header::CONTENT_TYPE, photo.content_type)], photo.data))
+}
+
+#[utoipa::path(
+    get,
+    path = "/employees",
+    params(EmployeeSearchQuery),
+    responses(
+        (status = 200, description = "List the organization's employees", body = [EmployeeResponse]),
+        (status = 404, description = "Organization, payroll or division not found")
+    ),
+    tag = "Employees",
+    operation_id = "search_employees"
+)]
+pub async fn search(
+    State(state): State<AppState>,
+    Query(query): Query<EmployeeSearchQuery>,
+) -> AppResult<Json<Vec<EmployeeResponse>>> {
+    let employees = state
+        .employee_service()
+        .search(query.organization_id, query.payroll_id, query.division_id)
+        .await?;
+    let response = employees.into_iter().map(EmployeeResponse::from).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/employees",
+    params(OrganizationEmployeesPathParams, OrganizationEmployeesQuery),
+    responses(
+        (status = 200, description = "One page of the organization's employees across all payrolls and divisions", body = EmployeePageResponse),
+        (status = 404, description = "Organization, payroll or division not found"),
+        (status = 422, description = "Invalid page or page size")
+    ),
+    tag = "Employees",
+    operation_id = "list_organization_employees"
+)]
+pub async fn list_organization(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationEmployeesPathParams>,
+    Query(query): Query<OrganizationEmployeesQuery>,
+) -> AppResult<Json<EmployeePageResponse>> {
+    let page = PageRequest::new(query.page, query.per_page)?;
+    let filter = EmployeeListFilter {
+        payroll_id: query.payroll_id,
+        division_id: query.division_id,
+        job_id: query.job_id,
+        status: query.status,
+        query: query.q,
+    };
+    let employees = state
+        .employee_service()
+        .list_organization(params.organization_id, filter, page)
+        .await?;
+
+    Ok(Json(employees.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/employees/{employee_id}",
+    params(FlatEmployeePathParams, OrganizationScopeQuery),
+    responses(
+        (status = 200, description = "Get employee", body = EmployeeResponse),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Employees",
+    operation_id = "find_employee"
+)]
+pub async fn find(
+    State(state): State<AppState>,
+    Path(params): Path<FlatEmployeePathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+) -> AppResult<Json<EmployeeResponse>> {
+    let employee = state
+        .employee_service()
+        .locate(query.organization_id, params.employee_id)
+        .await?
+        .ok_or_else(|| flat_employee_not_found(params.employee_id, query.organization_id))?;
+
+    Ok(Json(employee.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/employees/{employee_id}",
+    params(FlatEmployeePathParams, OrganizationScopeQuery),
+    request_body = UpdateEmployeeRequest,
+    responses(
+        (status = 200, description = "Employee updated", body = EmployeeResponse),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Employees",
+    operation_id = "update_employee_by_id"
+)]
+pub async fn update_by_id(
+    State(state): State<AppState>,
+    Path(params): Path<FlatEmployeePathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+    Json(payload): Json<UpdateEmployeeRequest>,
+) -> AppResult<Json<EmployeeResponse>> {
+    let path = nested_path(&state, query.organization_id, params.employee_id).await?;
+    update(State(state), Path(path), Json(payload)).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/employees/{employee_id}",
+    params(FlatEmployeePathParams, OrganizationScopeQuery),
+    responses(
+        (status = 204, description = "Employee deleted"),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Employees",
+    operation_id = "delete_employee_by_id"
+)]
+pub async fn delete_by_id(
+    State(state): State<AppState>,
+    Path(params): Path<FlatEmployeePathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+) -> AppResult<StatusCode> {
+    let path = nested_path(&state, query.organization_id, params.employee_id).await?;
+    delete(State(state), Path(path)).await
+}
+
+/// Resolves a flat route's employee to its nested path, so it can reuse the nested handler.
+async fn nested_path(
+    state: &AppState,
+    organization_id: Uuid,
+    employee_id: Uuid,
+) -> AppResult<EmployeePathParams> {
+    let employee = state
+        .employee_service()
+        .locate(organization_id, employee_id)
+        .await?
+        .ok_or_else(|| flat_employee_not_found(employee_id, organization_id))?;
+
+    Ok(EmployeePathParams {
+        organization_id,
+        payroll_id: employee.payroll_id,
+        division_id: employee.division_id,
+        employee_id,
+    })
+}
@@ -0,0 +1,90 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::{
+    domain::{employee::EmployeeStatus, employee_export::ExportFormat},
+    error::AppResult,
+    server::AppState,
+    services::employee_export::ExportEmployeesParams,
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct EmployeeExportPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EmployeeExportQuery {
+    /// `csv` (default) or `xlsx`.
+    pub format: Option<ExportFormat>,
+    /// Comma-separated column names, in output order; every column when omitted.
+    pub columns: Option<String>,
+    /// Only export employees with this status.
+    pub status: Option<EmployeeStatus>,
+}
+
+impl EmployeeExportQuery {
+    fn into_params(self) -> ExportEmployeesParams {
+        ExportEmployeesParams {
+            format: self.format.unwrap_or_default(),
+            columns: self.columns.map(|columns| {
+                columns
+                    .split(',')
+                    .map(|column| column.trim().to_string())
+                    .filter(|column| !column.is_empty())
+                    .collect()
+            }),
+            status: self.status,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/export",
+    params(EmployeeExportPathParams, EmployeeExportQuery),
+    responses(
+        (status = 200, description = "Employee list as a file download", body = Vec<u8>, content_type = "text/csv"),
+        (status = 404, description = "Division not found"),
+        (status = 422, description = "Unknown column")
+    ),
+    tag = "Employees",
+    operation_id = "export_employees"
+)]
+pub async fn export(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeeExportPathParams>,
+    Query(query): Query<EmployeeExportQuery>,
+) -> AppResult<impl IntoResponse> {
+    let format = query.format.unwrap_or_default();
+    let document = state
+        .employee_export_service()
+        .export(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            query.into_params(),
+        )
+        .await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"employees.{}\"", format.extension()),
+            ),
+        ],
+        document,
+    ))
+}
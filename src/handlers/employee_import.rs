@@ -0,0 +1,82 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::{
+    domain::employee_import::{EmployeeImportReport, EmployeeMatchKey},
+    error::AppResult,
+    server::AppState,
+    services::employee_import::ImportEmployeesParams,
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct EmployeeImportPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EmployeeImportQuery {
+    /// Import profile describing the CSV layout; header names must match employee fields without one.
+    pub profile_id: Option<Uuid>,
+    /// Updates employees whose `id_number` or `external_id` matches a row instead of creating them.
+    pub match_on: Option<EmployeeMatchKey>,
+    /// Reports division employees missing from the file as leavers, without changing them;
+    /// requires `match_on`.
+    #[serde(default)]
+    pub flag_leavers: bool,
+}
+
+impl EmployeeImportQuery {
+    fn into_params(self) -> ImportEmployeesParams {
+        ImportEmployeesParams {
+            profile_id: self.profile_id,
+            match_key: self.match_on,
+            flag_leavers: self.flag_leavers,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/import",
+    params(EmployeeImportPathParams, EmployeeImportQuery),
+    request_body(
+        content = String,
+        description = "CSV document with a header row",
+        content_type = "text/csv"
+    ),
+    responses(
+        (status = 200, description = "Per-row import outcome", body = EmployeeImportReport),
+        (status = 404, description = "Division or import profile not found"),
+        (status = 422, description = "Malformed CSV, no importable columns, or leavers flagged without a match key")
+    ),
+    tag = "Employees",
+    operation_id = "import_employees"
+)]
+pub async fn import(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeeImportPathParams>,
+    Query(query): Query<EmployeeImportQuery>,
+    body: String,
+) -> AppResult<Json<EmployeeImportReport>> {
+    let report = state
+        .employee_import_service()
+        .import(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            query.into_params(),
+            &body,
+        )
+        .await?;
+
+    Ok(Json(report))
+}
@@ -0,0 +1,96 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use chrono::Utc;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::{
+    domain::expiry::{Expiry, PermitExpiry},
+    error::AppResult,
+    server::AppState,
+};
+
+/// Look-ahead used when the report is requested without `within_days`.
+const DEFAULT_WITHIN_DAYS: u32 = 30;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct ExpiryPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExpiryQuery {
+    /// Days ahead of today to cover, at most 366; defaults to 30.
+    pub within_days: Option<u32>,
+    /// Only employees of this payroll.
+    pub payroll_id: Option<Uuid>,
+    /// Only employees of this division.
+    pub division_id: Option<Uuid>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/expiring-soon",
+    params(ExpiryPathParams, ExpiryQuery),
+    responses(
+        (status = 200, description = "Contracts, probation periods and work permits ending soon, soonest first", body = [Expiry]),
+        (status = 404, description = "Organization, payroll or division not found"),
+        (status = 422, description = "Invalid window")
+    ),
+    tag = "Employees",
+    operation_id = "list_expiring_soon"
+)]
+pub async fn expiring_soon(
+    State(state): State<AppState>,
+    Path(params): Path<ExpiryPathParams>,
+    Query(query): Query<ExpiryQuery>,
+) -> AppResult<Json<Vec<Expiry>>> {
+    let expiries = state
+        .expiry_service()
+        .expiring(
+            params.organization_id,
+            query.payroll_id,
+            query.division_id,
+            Utc::now().date_naive(),
+            query.within_days.unwrap_or(DEFAULT_WITHIN_DAYS),
+        )
+        .await?;
+
+    Ok(Json(expiries))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/expiring-permits",
+    params(ExpiryPathParams, ExpiryQuery),
+    responses(
+        (status = 200, description = "Work permits already lapsed or lapsing soon, most overdue first", body = [PermitExpiry]),
+        (status = 404, description = "Organization, payroll or division not found"),
+        (status = 422, description = "Invalid window")
+    ),
+    tag = "Employees",
+    operation_id = "list_expiring_permits"
+)]
+pub async fn expiring_permits(
+    State(state): State<AppState>,
+    Path(params): Path<ExpiryPathParams>,
+    Query(query): Query<ExpiryQuery>,
+) -> AppResult<Json<Vec<PermitExpiry>>> {
+    let permits = state
+        .expiry_service()
+        .expiring_permits(
+            params.organization_id,
+            query.payroll_id,
+            query.division_id,
+            Utc::now().date_naive(),
+            query.within_days.unwrap_or(DEFAULT_WITHIN_DAYS),
+        )
+        .await?;
+
+    Ok(Json(permits))
+}
@@ -1,6 +1,11 @@
-use axum::Json;
+use axum::{Json, extract::State, http::StatusCode};
 
-use crate::domain::health::Health;
+use crate::{
+    domain::health::{
+        DependencyStatus, Health, IntegrationHealthReport, IntegrationStatus, ReadinessReport,
+    },
+    server::AppState,
+};
 
 #[utoipa::path(
     get,
@@ -11,3 +16,45 @@ use crate::domain::health::Health;
 pub async fn check() -> Json<Health> {
     Json(Health::current())
 }
+
+#[utoipa::path(
+    get,
+    path = "/health/integrations",
+    responses(
+        (status = 200, description = "No integration is failing", body = IntegrationHealthReport),
+        (status = 503, description = "At least one integration's latest call failed", body = IntegrationHealthReport)
+    ),
+    tag = "Health"
+)]
+pub async fn integrations(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<IntegrationHealthReport>) {
+    let report = state.integration_monitor().report();
+    let status = if report.status == IntegrationStatus::Failing {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+
+    (status, Json(report))
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    responses(
+        (status = 200, description = "Every dependency answered its ping", body = ReadinessReport),
+        (status = 503, description = "At least one dependency is down", body = ReadinessReport)
+    ),
+    tag = "Health"
+)]
+pub async fn ready(State(state): State<AppState>) -> (StatusCode, Json<ReadinessReport>) {
+    let report = state.readiness_service().check().await;
+    let status = if report.status == DependencyStatus::Down {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+
+    (status, Json(report))
+}
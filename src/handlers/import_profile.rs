@@ -0,0 +1,239 @@
+use std::collections::BTreeMap;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::import_profile::ImportProfile,
+    error::{AppError, AppResult},
+    server::AppState,
+    services::import_profile::{CreateImportProfileParams, UpdateImportProfileParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateImportProfileRequest {
+    pub name: String,
+    /// Source CSV column name mapped to the employee field it populates.
+    pub column_mappings: BTreeMap<String, String>,
+    /// `chrono` format string for date columns; defaults to `%Y-%m-%d`.
+    pub date_format: Option<String>,
+    /// Per employee field, source values replaced by the stored value.
+    #[serde(default)]
+    pub value_translations: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateImportProfileRequest {
+    pub name: Option<String>,
+    pub column_mappings: Option<BTreeMap<String, String>>,
+    pub date_format: Option<String>,
+    pub value_translations: Option<BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportProfileResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub column_mappings: BTreeMap<String, String>,
+    pub date_format: String,
+    pub value_translations: BTreeMap<String, BTreeMap<String, String>>,
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct OrganizationPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct ImportProfilePathParams {
+    pub organization_id: Uuid,
+    pub profile_id: Uuid,
+}
+
+impl From<ImportProfile> for ImportProfileResponse {
+    fn from(value: ImportProfile) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            column_mappings: value.column_mappings,
+            date_format: value.date_format,
+            value_translations: value.value_translations,
+            organization_id: value.organization_id,
+        }
+    }
+}
+
+impl CreateImportProfileRequest {
+    fn into_params(self) -> CreateImportProfileParams {
+        CreateImportProfileParams {
+            name: self.name,
+            column_mappings: self.column_mappings,
+            date_format: self.date_format,
+            value_translations: self.value_translations,
+        }
+    }
+}
+
+impl UpdateImportProfileRequest {
+    fn into_params(self) -> UpdateImportProfileParams {
+        UpdateImportProfileParams {
+            name: self.name,
+            column_mappings: self.column_mappings,
+            date_format: self.date_format,
+            value_translations: self.value_translations,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/import-profiles",
+    params(OrganizationPathParams),
+    request_body = CreateImportProfileRequest,
+    responses(
+        (status = 201, description = "Import profile created", body = ImportProfileResponse),
+        (status = 422, description = "Unknown employee field or invalid date format")
+    ),
+    tag = "Import Profiles",
+    operation_id = "create_import_profile"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+    Json(payload): Json<CreateImportProfileRequest>,
+) -> AppResult<(StatusCode, Json<ImportProfileResponse>)> {
+    let profile = state
+        .import_profile_service()
+        .create(params.organization_id, payload.into_params())
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(profile.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/import-profiles",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "List import profiles", body = [ImportProfileResponse])
+    ),
+    tag = "Import Profiles",
+    operation_id = "list_import_profiles"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<Json<Vec<ImportProfileResponse>>> {
+    let profiles = state
+        .import_profile_service()
+        .list(params.organization_id)
+        .await?;
+    let response = profiles
+        .into_iter()
+        .map(ImportProfileResponse::from)
+        .collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/import-profiles/{profile_id}",
+    params(ImportProfilePathParams),
+    responses(
+        (status = 200, description = "Get import profile", body = ImportProfileResponse),
+        (status = 404, description = "Import profile not found")
+    ),
+    tag = "Import Profiles",
+    operation_id = "get_import_profile"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<ImportProfilePathParams>,
+) -> AppResult<Json<ImportProfileResponse>> {
+    let profile = state
+        .import_profile_service()
+        .get(params.organization_id, params.profile_id)
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "import profile `{}` not found for organization `{}`",
+                params.profile_id, params.organization_id
+            ))
+        })?;
+
+    Ok(Json(profile.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/import-profiles/{profile_id}",
+    params(ImportProfilePathParams),
+    request_body = UpdateImportProfileRequest,
+    responses(
+        (status = 200, description = "Import profile updated", body = ImportProfileResponse),
+        (status = 404, description = "Import profile not found")
+    ),
+    tag = "Import Profiles",
+    operation_id = "update_import_profile"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<ImportProfilePathParams>,
+    Json(payload): Json<UpdateImportProfileRequest>,
+) -> AppResult<Json<ImportProfileResponse>> {
+    let profile = state
+        .import_profile_service()
+        .update(
+            params.organization_id,
+            params.profile_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "import profile `{}` not found for organization `{}`",
+                params.profile_id, params.organization_id
+            ))
+        })?;
+
+    Ok(Json(profile.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/import-profiles/{profile_id}",
+    params(ImportProfilePathParams),
+    responses(
+        (status = 204, description = "Import profile deleted"),
+        (status = 404, description = "Import profile not found")
+    ),
+    tag = "Import Profiles",
+    operation_id = "delete_import_profile"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<ImportProfilePathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .import_profile_service()
+        .delete(params.organization_id, params.profile_id)
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::not_found(format!(
+            "import profile `{}` not found for organization `{}`",
+            params.profile_id, params.organization_id
+        )))
+    }
+}
@@ -1,15 +1,19 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
-    domain::job::Job,
+    domain::{
+        job::{HeadcountPolicy, Job, JobHeadcount, PayBasis, SalaryRangeStatus},
+        pagination::PageRequest,
+    },
     error::{AppError, AppResult},
+    handlers::employee::EmployeePageResponse,
     server::AppState,
     services::job::{CreateJobParams, UpdateJobParams},
 };
@@ -17,20 +21,84 @@ use crate::{
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateJobRequest {
     pub job_title: String,
+    pub description: Option<String>,
+    /// Duties of the role, one per entry.
+    #[serde(default)]
+    pub responsibilities: Vec<String>,
+    /// Required qualifications, one per entry.
+    #[serde(default)]
+    pub qualifications: Vec<String>,
+    /// Rate per `pay_basis` unit.
     pub salary: f64,
+    /// Defaults to `monthly`.
+    #[serde(default)]
+    pub pay_basis: PayBasis,
+    /// ISO 4217 code; omit for the organization's operating currency.
+    pub currency: Option<String>,
+    pub salary_min: Option<f64>,
+    pub salary_max: Option<f64>,
+    /// Grade whose salary band `salary` must fall within.
+    pub grade_id: Option<Uuid>,
+    /// Seats budgeted across the payroll; omit for no limit.
+    pub budgeted_headcount: Option<u32>,
+    /// Defaults to `warn`.
+    #[serde(default)]
+    pub headcount_policy: HeadcountPolicy,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateJobRequest {
     pub job_title: Option<String>,
+    /// `null` clears the description.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub description: Option<Option<String>>,
+    /// Replaces the whole list.
+    pub responsibilities: Option<Vec<String>>,
+    /// Replaces the whole list.
+    pub qualifications: Option<Vec<String>>,
     pub salary: Option<f64>,
+    pub pay_basis: Option<PayBasis>,
+    /// `null` falls back to the organization's operating currency.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub currency: Option<Option<String>>,
+    /// `null` removes the range's floor.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<f64>)]
+    pub salary_min: Option<Option<f64>>,
+    /// `null` removes the range's ceiling.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<f64>)]
+    pub salary_max: Option<Option<f64>>,
+    /// `null` takes the job out of its grade.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<Uuid>)]
+    pub grade_id: Option<Option<Uuid>>,
+    /// `null` removes the budget.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<u32>)]
+    pub budgeted_headcount: Option<Option<u32>>,
+    pub headcount_policy: Option<HeadcountPolicy>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct JobResponse {
     pub id: Uuid,
     pub job_title: String,
+    pub description: Option<String>,
+    pub responsibilities: Vec<String>,
+    pub qualifications: Vec<String>,
     pub salary: f64,
+    pub pay_basis: PayBasis,
+    pub currency: Option<String>,
+    pub salary_min: Option<f64>,
+    pub salary_max: Option<f64>,
+    /// Whether `salary` falls inside the job's own range.
+    pub salary_range_status: SalaryRangeStatus,
+    pub grade_id: Option<Uuid>,
+    pub budgeted_headcount: Option<u32>,
+    pub headcount_policy: HeadcountPolicy,
     pub payroll_id: Uuid,
 }
 
@@ -49,12 +117,59 @@ pub struct JobPathParams {
     pub job_id: Uuid,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct FlatJobPathParams {
+    pub job_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct JobSearchQuery {
+    pub organization_id: Uuid,
+    /// Only jobs of this payroll.
+    pub payroll_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OrganizationScopeQuery {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct JobEmployeesQuery {
+    /// One-based page number; defaults to 1.
+    pub page: Option<usize>,
+    /// Employees per page, up to 200; defaults to 50.
+    pub per_page: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeleteJobQuery {
+    /// Job of the same payroll to move assigned employees to before deleting.
+    pub reassign_to: Option<Uuid>,
+}
+
 impl From<Job> for JobResponse {
     fn from(value: Job) -> Self {
         Self {
+            salary_range_status: value.salary_range_status(),
             id: value.id,
             job_title: value.job_title,
+            description: value.description,
+            responsibilities: value.responsibilities,
+            qualifications: value.qualifications,
             salary: value.salary,
+            pay_basis: value.pay_basis,
+            currency: value.currency,
+            salary_min: value.salary_min,
+            salary_max: value.salary_max,
+            grade_id: value.grade_id,
+            budgeted_headcount: value.budgeted_headcount,
+            headcount_policy: value.headcount_policy,
             payroll_id: value.payroll_id,
         }
     }
@@ -64,7 +179,17 @@ impl CreateJobRequest {
     fn into_params(self) -> CreateJobParams {
         CreateJobParams {
             job_title: self.job_title,
+            description: self.description,
+            responsibilities: self.responsibilities,
+            qualifications: self.qualifications,
             salary: self.salary,
+            pay_basis: self.pay_basis,
+            currency: self.currency,
+            salary_min: self.salary_min,
+            salary_max: self.salary_max,
+            grade_id: self.grade_id,
+            budgeted_headcount: self.budgeted_headcount,
+            headcount_policy: self.headcount_policy,
         }
     }
 }
@@ -73,18 +198,44 @@ impl UpdateJobRequest {
     fn into_params(self) -> UpdateJobParams {
         UpdateJobParams {
             job_title: self.job_title,
+            description: self.description,
+            responsibilities: self.responsibilities,
+            qualifications: self.qualifications,
             salary: self.salary,
+            pay_basis: self.pay_basis,
+            currency: self.currency,
+            salary_min: self.salary_min,
+            salary_max: self.salary_max,
+            grade_id: self.grade_id,
+            budgeted_headcount: self.budgeted_headcount,
+            headcount_policy: self.headcount_policy,
         }
     }
 }
 
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Some(Option::deserialize(deserializer)?))
+}
+
+fn flat_job_not_found(job_id: Uuid, organization_id: Uuid) -> AppError {
+    AppError::not_found(format!(
+        "job `{job_id}` not found in organization `{organization_id}`"
+    ))
+}
+
 #[utoipa::path(
     post,
     path = "/organizations/{organization_id}/payrolls/{payroll_id}/jobs",
     params(JobCollectionPathParams),
     request_body = CreateJobRequest,
     responses(
-        (status = 201, description = "Job created", body = JobResponse)
+        (status = 201, description = "Job created", body = JobResponse),
+        (status = 404, description = "Payroll or grade not found"),
+        (status = 422, description = "Salary outside the job's range or grade band")
     ),
     tag = "Jobs",
     operation_id = "create_job"
@@ -128,6 +279,65 @@ pub async fn list(
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/job-headcount",
+    params(JobCollectionPathParams),
+    responses(
+        (status = 200, description = "Budgeted versus filled seats per job", body = [JobHeadcount]),
+        (status = 404, description = "Payroll not found")
+    ),
+    tag = "Jobs",
+    operation_id = "job_headcount"
+)]
+pub async fn headcount(
+    State(state): State<AppState>,
+    Path(params): Path<JobCollectionPathParams>,
+) -> AppResult<Json<Vec<JobHeadcount>>> {
+    let headcount = state
+        .employee_service()
+        .job_headcount(params.organization_id, params.payroll_id)
+        .await?;
+    Ok(Json(headcount))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/jobs/{job_id}/employees",
+    params(JobPathParams, JobEmployeesQuery),
+    responses(
+        (status = 200, description = "One page of the employees currently holding the job", body = EmployeePageResponse),
+        (status = 404, description = "Job not found"),
+        (status = 422, description = "Invalid page or page size")
+    ),
+    tag = "Jobs",
+    operation_id = "list_job_employees"
+)]
+pub async fn employees(
+    State(state): State<AppState>,
+    Path(params): Path<JobPathParams>,
+    Query(query): Query<JobEmployeesQuery>,
+) -> AppResult<Json<EmployeePageResponse>> {
+    let page = PageRequest::new(query.page, query.per_page)?;
+    let employees = state
+        .employee_service()
+        .list_by_job(
+            params.organization_id,
+            params.payroll_id,
+            params.job_id,
+            page,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "job `{}` not found for payroll `{}`",
+                params.job_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(employees.into()))
+}
+
 #[utoipa::path(
     get,
     path = "/organizations/{organization_id}/payrolls/{payroll_id}/jobs/{job_id}",
@@ -164,7 +374,8 @@ pub async fn get(
     request_body = UpdateJobRequest,
     responses(
         (status = 200, description = "Job updated", body = JobResponse),
-        (status = 404, description = "Job not found")
+        (status = 404, description = "Job or grade not found"),
+        (status = 422, description = "Salary outside the job's range or grade band")
     ),
     tag = "Jobs",
     operation_id = "update_job"
@@ -196,10 +407,12 @@ pub async fn update(
 #[utoipa::path(
     delete,
     path = "/organizations/{organization_id}/payrolls/{payroll_id}/jobs/{job_id}",
-    params(JobPathParams),
+    params(JobPathParams, DeleteJobQuery),
     responses(
         (status = 204, description = "Job deleted"),
-        (status = 404, description = "Job not found")
+        (status = 404, description = "Job or reassignment target not found"),
+        (status = 409, description = "Employees still assigned, or target job lacks budgeted seats"),
+        (status = 422, description = "Job reassigned to itself")
     ),
     tag = "Jobs",
     operation_id = "delete_job"
@@ -207,10 +420,16 @@ pub async fn update(
 pub async fn delete(
     State(state): State<AppState>,
     Path(params): Path<JobPathParams>,
+    Query(query): Query<DeleteJobQuery>,
 ) -> AppResult<StatusCode> {
     let removed = state
         .job_service()
-        .delete(params.organization_id, params.payroll_id, params.job_id)
+        .delete(
+            params.organization_id,
+            params.payroll_id,
+            params.job_id,
+            query.reassign_to,
+        )
         .await?;
 
     if removed {
@@ -222,3 +441,126 @@ pub async fn delete(
         )))
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/jobs",
+    params(JobSearchQuery),
+    responses(
+        (status = 200, description = "List the organization's jobs", body = [JobResponse]),
+        (status = 404, description = "Organization or payroll not found")
+    ),
+    tag = "Jobs",
+    operation_id = "search_jobs"
+)]
+pub async fn search(
+    State(state): State<AppState>,
+    Query(query): Query<JobSearchQuery>,
+) -> AppResult<Json<Vec<JobResponse>>> {
+    let jobs = state
+        .job_service()
+        .search(query.organization_id, query.payroll_id)
+        .await?;
+    let response = jobs.into_iter().map(JobResponse::from).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/jobs/{job_id}",
+    params(FlatJobPathParams, OrganizationScopeQuery),
+    responses(
+        (status = 200, description = "Get job", body = JobResponse),
+        (status = 404, description = "Job not found")
+    ),
+    tag = "Jobs",
+    operation_id = "find_job"
+)]
+pub async fn find(
+    State(state): State<AppState>,
+    Path(params): Path<FlatJobPathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+) -> AppResult<Json<JobResponse>> {
+    let job = state
+        .job_service()
+        .locate(query.organization_id, params.job_id)
+        .await?
+        .ok_or_else(|| flat_job_not_found(params.job_id, query.organization_id))?;
+
+    Ok(Json(job.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/jobs/{job_id}",
+    params(FlatJobPathParams, OrganizationScopeQuery),
+    request_body = UpdateJobRequest,
+    responses(
+        (status = 200, description = "Job updated", body = JobResponse),
+        (status = 404, description = "Job not found")
+    ),
+    tag = "Jobs",
+    operation_id = "update_job_by_id"
+)]
+pub async fn update_by_id(
+    State(state): State<AppState>,
+    Path(params): Path<FlatJobPathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+    Json(payload): Json<UpdateJobRequest>,
+) -> AppResult<Json<JobResponse>> {
+    let payroll_id = locate_payroll(&state, query.organization_id, params.job_id).await?;
+
+    update(
+        State(state),
+        Path(JobPathParams {
+            organization_id: query.organization_id,
+            payroll_id,
+            job_id: params.job_id,
+        }),
+        Json(payload),
+    )
+    .await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/jobs/{job_id}",
+    params(FlatJobPathParams, OrganizationScopeQuery, DeleteJobQuery),
+    responses(
+        (status = 204, description = "Job deleted"),
+        (status = 404, description = "Job or reassignment target not found"),
+        (status = 409, description = "Employees still assigned, or target job lacks budgeted seats"),
+        (status = 422, description = "Job reassigned to itself")
+    ),
+    tag = "Jobs",
+    operation_id = "delete_job_by_id"
+)]
+pub async fn delete_by_id(
+    State(state): State<AppState>,
+    Path(params): Path<FlatJobPathParams>,
+    Query(query): Query<OrganizationScopeQuery>,
+    reassignment: Query<DeleteJobQuery>,
+) -> AppResult<StatusCode> {
+    let payroll_id = locate_payroll(&state, query.organization_id, params.job_id).await?;
+
+    delete(
+        State(state),
+        Path(JobPathParams {
+            organization_id: query.organization_id,
+            payroll_id,
+            job_id: params.job_id,
+        }),
+        reassignment,
+    )
+    .await
+}
+
+/// Resolves the payroll a flat route's job lives in, so it can reuse the nested handler.
+async fn locate_payroll(state: &AppState, organization_id: Uuid, job_id: Uuid) -> AppResult<Uuid> {
+    state
+        .job_service()
+        .locate(organization_id, job_id)
+        .await?
+        .map(|job| job.payroll_id)
+        .ok_or_else(|| flat_job_not_found(job_id, organization_id))
+}
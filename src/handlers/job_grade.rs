@@ -0,0 +1,242 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::job_grade::JobGrade,
+    error::{AppError, AppResult},
+    server::AppState,
+    services::job_grade::{CreateJobGradeParams, UpdateJobGradeParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateJobGradeRequest {
+    pub code: String,
+    pub band: String,
+    pub level: u32,
+    pub salary_min: f64,
+    pub salary_max: f64,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateJobGradeRequest {
+    pub code: Option<String>,
+    pub band: Option<String>,
+    pub level: Option<u32>,
+    pub salary_min: Option<f64>,
+    pub salary_max: Option<f64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct JobGradeResponse {
+    pub id: Uuid,
+    pub code: String,
+    pub band: String,
+    pub level: u32,
+    pub salary_min: f64,
+    pub salary_max: f64,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct JobGradeCollectionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct JobGradePathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub grade_id: Uuid,
+}
+
+impl From<JobGrade> for JobGradeResponse {
+    fn from(value: JobGrade) -> Self {
+        Self {
+            id: value.id,
+            code: value.code,
+            band: value.band,
+            level: value.level,
+            salary_min: value.salary_min,
+            salary_max: value.salary_max,
+            payroll_id: value.payroll_id,
+        }
+    }
+}
+
+impl CreateJobGradeRequest {
+    fn into_params(self) -> CreateJobGradeParams {
+        CreateJobGradeParams {
+            code: self.code,
+            band: self.band,
+            level: self.level,
+            salary_min: self.salary_min,
+            salary_max: self.salary_max,
+        }
+    }
+}
+
+impl UpdateJobGradeRequest {
+    fn into_params(self) -> UpdateJobGradeParams {
+        UpdateJobGradeParams {
+            code: self.code,
+            band: self.band,
+            level: self.level,
+            salary_min: self.salary_min,
+            salary_max: self.salary_max,
+        }
+    }
+}
+
+fn grade_not_found(params: &JobGradePathParams) -> AppError {
+    AppError::not_found(format!(
+        "grade `{}` not found for payroll `{}`",
+        params.grade_id, params.payroll_id
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/job-grades",
+    params(JobGradeCollectionPathParams),
+    request_body = CreateJobGradeRequest,
+    responses(
+        (status = 201, description = "Grade created", body = JobGradeResponse),
+        (status = 404, description = "Payroll not found"),
+        (status = 409, description = "Grade code already in use"),
+        (status = 422, description = "Invalid salary band")
+    ),
+    tag = "Jobs",
+    operation_id = "create_job_grade"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<JobGradeCollectionPathParams>,
+    Json(payload): Json<CreateJobGradeRequest>,
+) -> AppResult<(StatusCode, Json<JobGradeResponse>)> {
+    let grade = state
+        .job_grade_service()
+        .create(
+            params.organization_id,
+            params.payroll_id,
+            payload.into_params(),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(grade.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/job-grades",
+    params(JobGradeCollectionPathParams),
+    responses(
+        (status = 200, description = "List grades by band and level", body = [JobGradeResponse])
+    ),
+    tag = "Jobs",
+    operation_id = "list_job_grades"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<JobGradeCollectionPathParams>,
+) -> AppResult<Json<Vec<JobGradeResponse>>> {
+    let grades = state
+        .job_grade_service()
+        .list(params.organization_id, params.payroll_id)
+        .await?;
+    let response = grades.into_iter().map(JobGradeResponse::from).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/job-grades/{grade_id}",
+    params(JobGradePathParams),
+    responses(
+        (status = 200, description = "Get grade", body = JobGradeResponse),
+        (status = 404, description = "Grade not found")
+    ),
+    tag = "Jobs",
+    operation_id = "get_job_grade"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<JobGradePathParams>,
+) -> AppResult<Json<JobGradeResponse>> {
+    let grade = state
+        .job_grade_service()
+        .get(params.organization_id, params.payroll_id, params.grade_id)
+        .await?
+        .ok_or_else(|| grade_not_found(&params))?;
+
+    Ok(Json(grade.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/job-grades/{grade_id}",
+    params(JobGradePathParams),
+    request_body = UpdateJobGradeRequest,
+    responses(
+        (status = 200, description = "Grade updated", body = JobGradeResponse),
+        (status = 404, description = "Grade not found"),
+        (status = 409, description = "Code in use or band would exclude assigned jobs"),
+        (status = 422, description = "Invalid salary band")
+    ),
+    tag = "Jobs",
+    operation_id = "update_job_grade"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<JobGradePathParams>,
+    Json(payload): Json<UpdateJobGradeRequest>,
+) -> AppResult<Json<JobGradeResponse>> {
+    let grade = state
+        .job_grade_service()
+        .update(
+            params.organization_id,
+            params.payroll_id,
+            params.grade_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| grade_not_found(&params))?;
+
+    Ok(Json(grade.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/job-grades/{grade_id}",
+    params(JobGradePathParams),
+    responses(
+        (status = 204, description = "Grade deleted"),
+        (status = 404, description = "Grade not found"),
+        (status = 409, description = "Grade still assigned to jobs")
+    ),
+    tag = "Jobs",
+    operation_id = "delete_job_grade"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<JobGradePathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .job_grade_service()
+        .delete(params.organization_id, params.payroll_id, params.grade_id)
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(grade_not_found(&params))
+    }
+}
@@ -0,0 +1,714 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::leave::{
+        BlackoutEnforcement, LeaveBalance, LeaveBalanceAdjustment, LeaveBlackout,
+        LeaveCarryoverRule, LeavePayDays, LeavePayPolicy, LeavePayTier, LeaveRequest, LeaveType,
+    },
+    error::{AppError, AppResult},
+    server::AppState,
+    services::leave::{CreateLeaveBlackoutParams, CreateLeaveRequestParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateLeaveRequestRequest {
+    pub leave_type: LeaveType,
+    #[schema(value_type = String, format = Date)]
+    pub start_date: NaiveDate,
+    /// Last day of leave, inclusive.
+    #[schema(value_type = String, format = Date)]
+    pub end_date: NaiveDate,
+    pub reason: Option<String>,
+    /// Reference to the medical certificate; sick and statutory leave only.
+    pub certificate_reference: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DecideLeaveRequestRequest {
+    /// The employee's supervisor or the manager of their division.
+    pub approver_id: Uuid,
+    pub note: Option<String>,
+    /// Approves a request falling in a blackout period; ignored when rejecting.
+    #[serde(default)]
+    pub override_blackout: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateLeaveBlackoutRequest {
+    #[schema(value_type = String, format = Date)]
+    pub start_date: NaiveDate,
+    /// Last day of the blackout, inclusive.
+    #[schema(value_type = String, format = Date)]
+    pub end_date: NaiveDate,
+    pub enforcement: BlackoutEnforcement,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetLeaveBalanceRequest {
+    pub available_days: f64,
+    /// Date the grant takes effect, deciding its leave year; today when omitted.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetLeavePayPolicyRequest {
+    pub tiers: Vec<LeavePayTier>,
+    /// Longest spell a single request may cover, in working days; unlimited when omitted.
+    pub max_days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetLeaveCarryoverRuleRequest {
+    /// Most days carried into the new year; unlimited when omitted.
+    pub max_days: Option<f64>,
+    /// Carried-over days expire this many days into the new year; never when omitted.
+    pub expires_after_days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RunLeaveCarryoverRequest {
+    /// Date to apply the rules as of; today when omitted.
+    #[schema(value_type = Option<String>, format = Date)]
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LeaveCollectionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LeaveRequestPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+    pub request_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LeaveBalancePathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+    /// `vacation` or `sick`.
+    pub leave_type: LeaveType,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LeavePayPolicyPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    /// `sick`, `maternity` or `paternity`.
+    pub leave_type: LeaveType,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LeaveCarryoverRulePathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    /// `vacation` or `sick`.
+    pub leave_type: LeaveType,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LeaveBlackoutCollectionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LeaveBlackoutPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub blackout_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LeaveCarryoverPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LeavePayQuery {
+    /// First day of the pay period.
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    /// Last day of the pay period, inclusive.
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+}
+
+impl CreateLeaveRequestRequest {
+    fn into_params(self) -> CreateLeaveRequestParams {
+        CreateLeaveRequestParams {
+            leave_type: self.leave_type,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            reason: self.reason,
+            certificate_reference: self.certificate_reference,
+        }
+    }
+}
+
+fn request_not_found(params: &LeaveRequestPathParams) -> AppError {
+    AppError::not_found(format!(
+        "leave request `{}` not found for employee `{}`",
+        params.request_id, params.employee_id
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-balances",
+    params(LeaveCollectionPathParams),
+    responses(
+        (status = 200, description = "Available days per paid leave type", body = [LeaveBalance]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Leave",
+    operation_id = "list_leave_balances"
+)]
+pub async fn balances(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveCollectionPathParams>,
+) -> AppResult<Json<Vec<LeaveBalance>>> {
+    let balances = state
+        .leave_service()
+        .balances(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?;
+
+    Ok(Json(balances))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-balances/{leave_type}",
+    params(LeaveBalancePathParams),
+    request_body = SetLeaveBalanceRequest,
+    responses(
+        (status = 200, description = "Leave balance set", body = LeaveBalance),
+        (status = 404, description = "Employee not found"),
+        (status = 422, description = "Unpaid leave or negative days")
+    ),
+    tag = "Leave",
+    operation_id = "set_leave_balance"
+)]
+pub async fn set_balance(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveBalancePathParams>,
+    Json(payload): Json<SetLeaveBalanceRequest>,
+) -> AppResult<Json<LeaveBalance>> {
+    let balance = state
+        .leave_service()
+        .set_balance(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.leave_type,
+            payload.available_days,
+            payload
+                .as_of
+                .unwrap_or_else(|| chrono::Utc::now().date_naive()),
+        )
+        .await?;
+
+    Ok(Json(balance))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests",
+    params(LeaveCollectionPathParams),
+    request_body = CreateLeaveRequestRequest,
+    responses(
+        (status = 201, description = "Leave requested", body = LeaveRequest),
+        (status = 404, description = "Employee not found"),
+        (status = 409, description = "Overlaps another leave request"),
+        (status = 422, description = "Invalid dates or insufficient balance")
+    ),
+    tag = "Leave",
+    operation_id = "create_leave_request"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveCollectionPathParams>,
+    Json(payload): Json<CreateLeaveRequestRequest>,
+) -> AppResult<(StatusCode, Json<LeaveRequest>)> {
+    let request = state
+        .leave_service()
+        .create(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            payload.into_params(),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(request)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests",
+    params(LeaveCollectionPathParams),
+    responses(
+        (status = 200, description = "Leave requests, most recent first", body = [LeaveRequest]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Leave",
+    operation_id = "list_leave_requests"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveCollectionPathParams>,
+) -> AppResult<Json<Vec<LeaveRequest>>> {
+    let requests = state
+        .leave_service()
+        .list(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?;
+
+    Ok(Json(requests))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests/{request_id}",
+    params(LeaveRequestPathParams),
+    responses(
+        (status = 200, description = "Get leave request", body = LeaveRequest),
+        (status = 404, description = "Leave request not found")
+    ),
+    tag = "Leave",
+    operation_id = "get_leave_request"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveRequestPathParams>,
+) -> AppResult<Json<LeaveRequest>> {
+    let request = state
+        .leave_service()
+        .get(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.request_id,
+        )
+        .await?
+        .ok_or_else(|| request_not_found(&params))?;
+
+    Ok(Json(request))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests/{request_id}/approve",
+    params(LeaveRequestPathParams),
+    request_body = DecideLeaveRequestRequest,
+    responses(
+        (status = 200, description = "Leave approved and balance deducted", body = LeaveRequest),
+        (status = 404, description = "Leave request not found"),
+        (status = 409, description = "Already decided or balance no longer covers it"),
+        (status = 422, description = "Approver is not the employee's supervisor or division manager")
+    ),
+    tag = "Leave",
+    operation_id = "approve_leave_request"
+)]
+pub async fn approve(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveRequestPathParams>,
+    Json(payload): Json<DecideLeaveRequestRequest>,
+) -> AppResult<Json<LeaveRequest>> {
+    let request = state
+        .leave_service()
+        .approve(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.request_id,
+            payload.approver_id,
+            payload.note,
+            payload.override_blackout,
+        )
+        .await?
+        .ok_or_else(|| request_not_found(&params))?;
+
+    Ok(Json(request))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests/{request_id}/reject",
+    params(LeaveRequestPathParams),
+    request_body = DecideLeaveRequestRequest,
+    responses(
+        (status = 200, description = "Leave rejected", body = LeaveRequest),
+        (status = 404, description = "Leave request not found"),
+        (status = 409, description = "Already decided"),
+        (status = 422, description = "Approver is not the employee's supervisor or division manager")
+    ),
+    tag = "Leave",
+    operation_id = "reject_leave_request"
+)]
+pub async fn reject(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveRequestPathParams>,
+    Json(payload): Json<DecideLeaveRequestRequest>,
+) -> AppResult<Json<LeaveRequest>> {
+    let request = state
+        .leave_service()
+        .reject(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.request_id,
+            payload.approver_id,
+            payload.note,
+        )
+        .await?
+        .ok_or_else(|| request_not_found(&params))?;
+
+    Ok(Json(request))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/leave-pay-policies/{leave_type}",
+    params(LeavePayPolicyPathParams),
+    responses(
+        (status = 200, description = "Pay tiers and duration for the leave type; unlimited full pay when none are set", body = LeavePayPolicy),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Leave type pay cannot be tiered")
+    ),
+    tag = "Leave",
+    operation_id = "get_leave_pay_policy"
+)]
+pub async fn get_pay_policy(
+    State(state): State<AppState>,
+    Path(params): Path<LeavePayPolicyPathParams>,
+) -> AppResult<Json<LeavePayPolicy>> {
+    let policy = state
+        .leave_service()
+        .pay_policy(params.organization_id, params.payroll_id, params.leave_type)
+        .await?;
+
+    Ok(Json(policy))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/leave-pay-policies/{leave_type}",
+    params(LeavePayPolicyPathParams),
+    request_body = SetLeavePayPolicyRequest,
+    responses(
+        (status = 200, description = "Pay tiers and duration replaced", body = LeavePayPolicy),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Leave type pay cannot be tiered, or invalid tiers or duration")
+    ),
+    tag = "Leave",
+    operation_id = "set_leave_pay_policy"
+)]
+pub async fn set_pay_policy(
+    State(state): State<AppState>,
+    Path(params): Path<LeavePayPolicyPathParams>,
+    Json(payload): Json<SetLeavePayPolicyRequest>,
+) -> AppResult<Json<LeavePayPolicy>> {
+    let policy = state
+        .leave_service()
+        .set_pay_policy(
+            params.organization_id,
+            params.payroll_id,
+            params.leave_type,
+            payload.tiers,
+            payload.max_days,
+        )
+        .await?;
+
+    Ok(Json(policy))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-pay",
+    params(LeaveCollectionPathParams, LeavePayQuery),
+    responses(
+        (status = 200, description = "Approved tiered leave days in the period by pay percentage", body = [LeavePayDays]),
+        (status = 404, description = "Employee not found"),
+        (status = 422, description = "Period ends before it starts")
+    ),
+    tag = "Leave",
+    operation_id = "list_leave_pay_days"
+)]
+pub async fn pay_days(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveCollectionPathParams>,
+    Query(query): Query<LeavePayQuery>,
+) -> AppResult<Json<Vec<LeavePayDays>>> {
+    let days = state
+        .leave_service()
+        .leave_pay_days(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            query.from,
+            query.to,
+        )
+        .await?;
+
+    Ok(Json(days))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/leave-carryover-rules/{leave_type}",
+    params(LeaveCarryoverRulePathParams),
+    responses(
+        (status = 200, description = "Carryover limit and expiry for the leave type; everything carries over when none are set", body = LeaveCarryoverRule),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Leave type does not carry a balance")
+    ),
+    tag = "Leave",
+    operation_id = "get_leave_carryover_rule"
+)]
+pub async fn get_carryover_rule(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveCarryoverRulePathParams>,
+) -> AppResult<Json<LeaveCarryoverRule>> {
+    let rule = state
+        .leave_service()
+        .carryover_rule(params.organization_id, params.payroll_id, params.leave_type)
+        .await?;
+
+    Ok(Json(rule))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/leave-carryover-rules/{leave_type}",
+    params(LeaveCarryoverRulePathParams),
+    request_body = SetLeaveCarryoverRuleRequest,
+    responses(
+        (status = 200, description = "Carryover limit and expiry replaced", body = LeaveCarryoverRule),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Leave type does not carry a balance, or invalid limit or expiry")
+    ),
+    tag = "Leave",
+    operation_id = "set_leave_carryover_rule"
+)]
+pub async fn set_carryover_rule(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveCarryoverRulePathParams>,
+    Json(payload): Json<SetLeaveCarryoverRuleRequest>,
+) -> AppResult<Json<LeaveCarryoverRule>> {
+    let rule = state
+        .leave_service()
+        .set_carryover_rule(
+            params.organization_id,
+            params.payroll_id,
+            params.leave_type,
+            payload.max_days,
+            payload.expires_after_days,
+        )
+        .await?;
+
+    Ok(Json(rule))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/leave-carryover",
+    params(LeaveCarryoverPathParams),
+    request_body = RunLeaveCarryoverRequest,
+    responses(
+        (status = 200, description = "Applies the payroll's carryover rules now, as the daily sweep does, and returns the balance adjustments made", body = [LeaveBalanceAdjustment]),
+        (status = 404, description = "Payroll not found")
+    ),
+    tag = "Leave",
+    operation_id = "run_leave_carryover"
+)]
+pub async fn run_carryover(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveCarryoverPathParams>,
+    Json(payload): Json<RunLeaveCarryoverRequest>,
+) -> AppResult<Json<Vec<LeaveBalanceAdjustment>>> {
+    let adjustments = state
+        .leave_service()
+        .carry_over(
+            params.organization_id,
+            params.payroll_id,
+            payload
+                .as_of
+                .unwrap_or_else(|| chrono::Utc::now().date_naive()),
+        )
+        .await?;
+
+    Ok(Json(adjustments))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-adjustments",
+    params(LeaveCollectionPathParams),
+    responses(
+        (status = 200, description = "Year-end carryover and expiry adjustments to the employee's balances, most recent first", body = [LeaveBalanceAdjustment]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Leave",
+    operation_id = "list_leave_adjustments"
+)]
+pub async fn adjustments(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveCollectionPathParams>,
+) -> AppResult<Json<Vec<LeaveBalanceAdjustment>>> {
+    let adjustments = state
+        .leave_service()
+        .adjustments(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?;
+
+    Ok(Json(adjustments))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/leave-blackouts",
+    params(LeaveBlackoutCollectionPathParams),
+    request_body = CreateLeaveBlackoutRequest,
+    responses(
+        (status = 201, description = "Blackout period created", body = LeaveBlackout),
+        (status = 404, description = "Division not found"),
+        (status = 422, description = "Blackout ends before it starts")
+    ),
+    tag = "Leave",
+    operation_id = "create_leave_blackout"
+)]
+pub async fn create_blackout(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveBlackoutCollectionPathParams>,
+    Json(payload): Json<CreateLeaveBlackoutRequest>,
+) -> AppResult<(StatusCode, Json<LeaveBlackout>)> {
+    let blackout = state
+        .leave_service()
+        .create_blackout(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            CreateLeaveBlackoutParams {
+                start_date: payload.start_date,
+                end_date: payload.end_date,
+                enforcement: payload.enforcement,
+                reason: payload.reason,
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(blackout)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/leave-blackouts",
+    params(LeaveBlackoutCollectionPathParams),
+    responses(
+        (status = 200, description = "Blackout periods of the division, earliest first", body = [LeaveBlackout]),
+        (status = 404, description = "Division not found")
+    ),
+    tag = "Leave",
+    operation_id = "list_leave_blackouts"
+)]
+pub async fn list_blackouts(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveBlackoutCollectionPathParams>,
+) -> AppResult<Json<Vec<LeaveBlackout>>> {
+    let blackouts = state
+        .leave_service()
+        .list_blackouts(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+        )
+        .await?;
+
+    Ok(Json(blackouts))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/leave-blackouts/{blackout_id}",
+    params(LeaveBlackoutPathParams),
+    responses(
+        (status = 204, description = "Blackout period deleted"),
+        (status = 404, description = "Blackout period not found")
+    ),
+    tag = "Leave",
+    operation_id = "delete_leave_blackout"
+)]
+pub async fn delete_blackout(
+    State(state): State<AppState>,
+    Path(params): Path<LeaveBlackoutPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .leave_service()
+        .delete_blackout(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.blackout_id,
+        )
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::not_found(format!(
+            "leave blackout `{}` not found for division `{}`",
+            params.blackout_id, params.division_id
+        )))
+    }
+}
@@ -0,0 +1,244 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::{Deserialize, Deserializer};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        legal_entity::{DebtorAccount, LegalEntity},
+        organization::LegalIdentity,
+    },
+    error::{AppError, AppResult},
+    server::AppState,
+    services::legal_entity::{CreateLegalEntityParams, UpdateLegalEntityParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateLegalEntityRequest {
+    pub name: String,
+    /// Registered name, when it differs from `name`.
+    pub legal_name: Option<String>,
+    /// Tax identification number, unique among the organization's legal entities.
+    #[schema(example = "12-3456789")]
+    pub tax_id: Option<String>,
+    pub registration_number: Option<String>,
+    /// Registered office address.
+    pub address: Option<String>,
+    /// Account at one of the organization's banks that salaries are paid from.
+    pub debtor_account: Option<DebtorAccount>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateLegalEntityRequest {
+    pub name: Option<String>,
+    /// `null` clears this and each of the fields below.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub legal_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub tax_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub registration_number: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub address: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<DebtorAccount>)]
+    pub debtor_account: Option<Option<DebtorAccount>>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LegalEntityCollectionPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct LegalEntityPathParams {
+    pub organization_id: Uuid,
+    pub legal_entity_id: Uuid,
+}
+
+impl CreateLegalEntityRequest {
+    fn into_params(self) -> CreateLegalEntityParams {
+        CreateLegalEntityParams {
+            name: self.name,
+            legal_identity: LegalIdentity {
+                legal_name: self.legal_name,
+                tax_id: self.tax_id,
+                registration_number: self.registration_number,
+                address: self.address,
+            },
+            debtor_account: self.debtor_account,
+        }
+    }
+}
+
+impl UpdateLegalEntityRequest {
+    fn into_params(self) -> UpdateLegalEntityParams {
+        UpdateLegalEntityParams {
+            name: self.name,
+            legal_name: self.legal_name,
+            tax_id: self.tax_id,
+            registration_number: self.registration_number,
+            address: self.address,
+            debtor_account: self.debtor_account,
+        }
+    }
+}
+
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Some(Option::deserialize(deserializer)?))
+}
+
+fn legal_entity_not_found(params: &LegalEntityPathParams) -> AppError {
+    AppError::not_found(format!(
+        "legal entity `{}` not found for organization `{}`",
+        params.legal_entity_id, params.organization_id
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/legal-entities",
+    params(LegalEntityCollectionPathParams),
+    request_body = CreateLegalEntityRequest,
+    responses(
+        (status = 201, description = "Legal entity created", body = LegalEntity),
+        (status = 404, description = "Organization or debtor bank not found"),
+        (status = 409, description = "Tax ID registered to another legal entity"),
+        (status = 422, description = "Empty name, malformed identifiers or debtor account")
+    ),
+    tag = "Organizations",
+    operation_id = "create_legal_entity"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<LegalEntityCollectionPathParams>,
+    Json(payload): Json<CreateLegalEntityRequest>,
+) -> AppResult<(StatusCode, Json<LegalEntity>)> {
+    let legal_entity = state
+        .legal_entity_service()
+        .create(params.organization_id, payload.into_params())
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(legal_entity)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/legal-entities",
+    params(LegalEntityCollectionPathParams),
+    responses(
+        (status = 200, description = "Legal entities by name", body = [LegalEntity]),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organizations",
+    operation_id = "list_legal_entities"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<LegalEntityCollectionPathParams>,
+) -> AppResult<Json<Vec<LegalEntity>>> {
+    let legal_entities = state
+        .legal_entity_service()
+        .list(params.organization_id)
+        .await?;
+
+    Ok(Json(legal_entities))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/legal-entities/{legal_entity_id}",
+    params(LegalEntityPathParams),
+    responses(
+        (status = 200, description = "Get legal entity", body = LegalEntity),
+        (status = 404, description = "Legal entity not found")
+    ),
+    tag = "Organizations",
+    operation_id = "get_legal_entity"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<LegalEntityPathParams>,
+) -> AppResult<Json<LegalEntity>> {
+    let legal_entity = state
+        .legal_entity_service()
+        .get(params.organization_id, params.legal_entity_id)
+        .await?
+        .ok_or_else(|| legal_entity_not_found(&params))?;
+
+    Ok(Json(legal_entity))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/legal-entities/{legal_entity_id}",
+    params(LegalEntityPathParams),
+    request_body = UpdateLegalEntityRequest,
+    responses(
+        (status = 200, description = "Legal entity updated", body = LegalEntity),
+        (status = 404, description = "Legal entity or debtor bank not found"),
+        (status = 409, description = "Tax ID registered to another legal entity"),
+        (status = 422, description = "No fields supplied, malformed identifiers or debtor account")
+    ),
+    tag = "Organizations",
+    operation_id = "update_legal_entity"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<LegalEntityPathParams>,
+    Json(payload): Json<UpdateLegalEntityRequest>,
+) -> AppResult<Json<LegalEntity>> {
+    let legal_entity = state
+        .legal_entity_service()
+        .update(
+            params.organization_id,
+            params.legal_entity_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| legal_entity_not_found(&params))?;
+
+    Ok(Json(legal_entity))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/legal-entities/{legal_entity_id}",
+    params(LegalEntityPathParams),
+    responses(
+        (status = 204, description = "Legal entity deleted"),
+        (status = 404, description = "Legal entity not found"),
+        (status = 409, description = "Payrolls still report under the legal entity")
+    ),
+    tag = "Organizations",
+    operation_id = "delete_legal_entity"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<LegalEntityPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .legal_entity_service()
+        .delete(params.organization_id, params.legal_entity_id)
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(legal_entity_not_found(&params))
+    }
+}
@@ -0,0 +1,159 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::membership::{MemberRole, Membership},
+    error::{AppError, AppResult},
+    server::AppState,
+    services::membership::InviteMemberParams,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InviteMemberRequest {
+    /// Address the invitation is sent to; unique within the organization.
+    pub email: String,
+    /// The organization's first member must be an `owner`.
+    pub role: MemberRole,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateMemberRoleRequest {
+    pub role: MemberRole,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct MemberCollectionPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct MemberPathParams {
+    pub organization_id: Uuid,
+    pub member_id: Uuid,
+}
+
+fn member_not_found(params: &MemberPathParams) -> AppError {
+    AppError::not_found(format!(
+        "member `{}` not found for organization `{}`",
+        params.member_id, params.organization_id
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/members",
+    params(MemberCollectionPathParams),
+    request_body = InviteMemberRequest,
+    responses(
+        (status = 201, description = "Member added and invitation emailed", body = Membership),
+        (status = 404, description = "Organization not found"),
+        (status = 409, description = "Email is already a member"),
+        (status = 422, description = "Invalid email, or the first member is not an owner")
+    ),
+    tag = "Organizations",
+    operation_id = "invite_member"
+)]
+pub async fn invite(
+    State(state): State<AppState>,
+    Path(params): Path<MemberCollectionPathParams>,
+    Json(payload): Json<InviteMemberRequest>,
+) -> AppResult<(StatusCode, Json<Membership>)> {
+    let membership = state
+        .membership_service()
+        .invite(
+            params.organization_id,
+            InviteMemberParams {
+                email: payload.email,
+                role: payload.role,
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(membership)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/members",
+    params(MemberCollectionPathParams),
+    responses(
+        (status = 200, description = "Members by role, owners first, then by email", body = [Membership]),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organizations",
+    operation_id = "list_members"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<MemberCollectionPathParams>,
+) -> AppResult<Json<Vec<Membership>>> {
+    let members = state
+        .membership_service()
+        .list(params.organization_id)
+        .await?;
+
+    Ok(Json(members))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/members/{member_id}",
+    params(MemberPathParams),
+    request_body = UpdateMemberRoleRequest,
+    responses(
+        (status = 200, description = "Role changed", body = Membership),
+        (status = 404, description = "Member not found"),
+        (status = 409, description = "The member is the organization's last owner")
+    ),
+    tag = "Organizations",
+    operation_id = "update_member_role"
+)]
+pub async fn update_role(
+    State(state): State<AppState>,
+    Path(params): Path<MemberPathParams>,
+    Json(payload): Json<UpdateMemberRoleRequest>,
+) -> AppResult<Json<Membership>> {
+    let membership = state
+        .membership_service()
+        .update_role(params.organization_id, params.member_id, payload.role)
+        .await?
+        .ok_or_else(|| member_not_found(&params))?;
+
+    Ok(Json(membership))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/members/{member_id}",
+    params(MemberPathParams),
+    responses(
+        (status = 204, description = "Member removed"),
+        (status = 404, description = "Member not found"),
+        (status = 409, description = "The member is the organization's last owner")
+    ),
+    tag = "Organizations",
+    operation_id = "remove_member"
+)]
+pub async fn remove(
+    State(state): State<AppState>,
+    Path(params): Path<MemberPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .membership_service()
+        .remove(params.organization_id, params.member_id)
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(member_not_found(&params))
+    }
+}
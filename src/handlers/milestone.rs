@@ -0,0 +1,69 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use chrono::{Duration, NaiveDate, Utc};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::{domain::milestone::DivisionMilestones, error::AppResult, server::AppState};
+
+/// Days covered when the report is requested without an end date.
+const DEFAULT_WINDOW_DAYS: i64 = 30;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct MilestonePathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MilestoneQuery {
+    /// First day of the window; defaults to today.
+    #[param(value_type = Option<String>, format = Date)]
+    pub from: Option<NaiveDate>,
+    /// Last day of the window, at most 365 days after `from`; defaults to 30 days after it.
+    #[param(value_type = Option<String>, format = Date)]
+    pub to: Option<NaiveDate>,
+    /// Only employees of this payroll.
+    pub payroll_id: Option<Uuid>,
+    /// Only employees of this division.
+    pub division_id: Option<Uuid>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/milestones",
+    params(MilestonePathParams, MilestoneQuery),
+    responses(
+        (status = 200, description = "Upcoming birthdays and work anniversaries, grouped by division", body = [DivisionMilestones]),
+        (status = 404, description = "Organization, payroll or division not found"),
+        (status = 422, description = "Invalid window")
+    ),
+    tag = "Employees",
+    operation_id = "list_upcoming_milestones"
+)]
+pub async fn upcoming(
+    State(state): State<AppState>,
+    Path(params): Path<MilestonePathParams>,
+    Query(query): Query<MilestoneQuery>,
+) -> AppResult<Json<Vec<DivisionMilestones>>> {
+    let from = query.from.unwrap_or_else(|| Utc::now().date_naive());
+    let to = query
+        .to
+        .unwrap_or(from + Duration::days(DEFAULT_WINDOW_DAYS));
+    let milestones = state
+        .milestone_service()
+        .upcoming(
+            params.organization_id,
+            query.payroll_id,
+            query.division_id,
+            from,
+            to,
+        )
+        .await?;
+
+    Ok(Json(milestones))
+}
@@ -1,7 +1,32 @@
+pub mod account_verification;
+pub mod api_collection;
+pub mod attendance_import;
 pub mod bank;
+pub mod benchmark_export;
+pub mod custom_field;
+pub mod dependent;
 pub mod division;
+pub mod email_verification;
 pub mod employee;
+pub mod employee_export;
+pub mod employee_import;
+pub mod expiry;
 pub mod health;
+pub mod import_profile;
 pub mod job;
+pub mod job_grade;
+pub mod leave;
+pub mod legal_entity;
+pub mod membership;
+pub mod milestone;
 pub mod organization;
+pub mod overtime;
+pub mod pay_equity;
 pub mod payroll;
+pub mod position;
+pub mod profile_completeness;
+pub mod project;
+pub mod salary_adjustment;
+pub mod self_service;
+pub mod timesheet;
+pub mod work_schedule;
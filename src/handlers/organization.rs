@@ -1,33 +1,128 @@
 use axum::{
     Json,
-    extract::{Path, State},
-    http::StatusCode,
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::IntoResponse,
 };
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::{
-    domain::organization::Organization,
+    domain::{
+        blob::Blob,
+        calendar::OrganizationCalendar,
+        organization::{LegalIdentity, Organization},
+        organization_deletion::OrganizationDeletion,
+        organization_settings::{FiscalYearStart, OrganizationSettings, RoundingRule},
+    },
     error::{AppError, AppResult},
     server::AppState,
-    services::organization::{CreateOrganizationParams, UpdateOrganizationParams},
+    services::{
+        organization::{CreateOrganizationParams, UpdateOrganizationParams},
+        organization_clone::{CloneOrganizationParams, OrganizationClone},
+        organization_deletion::RequestOrganizationDeletionParams,
+        organization_settings::UpdateOrganizationSettingsParams,
+    },
 };
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateOrganizationRequest {
     pub name: String,
+    /// Regular expression every division budget code must match in full, e.g. `CC-\d{4}`.
+    pub budget_code_pattern: Option<String>,
+    /// Registered name, when it differs from `name`.
+    pub legal_name: Option<String>,
+    /// Employer tax identification number; spaces are dropped and letters upper-cased.
+    #[schema(example = "12-3456789")]
+    pub tax_id: Option<String>,
+    pub registration_number: Option<String>,
+    /// Registered office address.
+    pub address: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateOrganizationRequest {
     pub name: Option<String>,
+    /// `null` lifts the budget code restriction.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub budget_code_pattern: Option<Option<String>>,
+    /// `null` clears this and each of the legal identity fields below.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub legal_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub tax_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub registration_number: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub address: Option<Option<String>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CloneOrganizationRequest {
+    pub name: String,
+    /// Legal identity of the new entity; none of it is copied from the template.
+    pub legal_name: Option<String>,
+    #[schema(example = "12-3456789")]
+    pub tax_id: Option<String>,
+    pub registration_number: Option<String>,
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateOrganizationSettingsRequest {
+    /// ISO 4217 code; `null` clears it.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>, example = "USD")]
+    pub default_currency: Option<Option<String>>,
+    #[schema(example = "es-MX")]
+    pub locale: Option<String>,
+    pub fiscal_year_start: Option<FiscalYearStart>,
+    /// 1 to 7.
+    pub working_days_per_week: Option<u8>,
+    pub rounding: Option<RoundingRule>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct OrganizationResponse {
     pub id: Uuid,
     pub name: String,
+    pub budget_code_pattern: Option<String>,
+    pub legal_name: Option<String>,
+    pub tax_id: Option<String>,
+    pub registration_number: Option<String>,
+    pub address: Option<String>,
+    pub logo_url: Option<String>,
+    /// Set while the organization is archived and read-only.
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+/// The new organization and how many of the template's records were copied into it.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizationCloneResponse {
+    pub organization: OrganizationResponse,
+    pub payrolls: usize,
+    pub job_grades: usize,
+    pub jobs: usize,
+    pub divisions: usize,
+    pub banks: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizationDeletionResponse {
+    pub organization_id: Uuid,
+    #[schema(value_type = String, format = DateTime)]
+    pub requested_at: DateTime<Utc>,
+    #[schema(value_type = String, format = DateTime)]
+    pub scheduled_for: DateTime<Utc>,
 }
 
 #[derive(Debug, Deserialize, IntoParams)]
@@ -36,33 +131,134 @@ pub struct OrganizationPathParams {
     pub id: Uuid,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarQuery {
+    /// First day to cover.
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    /// Last day to cover, at most 366 days after `from`.
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+    /// Only events of this payroll, plus organization-wide ones.
+    pub payroll_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeleteOrganizationQuery {
+    /// Organization name, repeated to confirm the deletion.
+    pub acknowledgement: Option<String>,
+}
+
 impl From<Organization> for OrganizationResponse {
     fn from(value: Organization) -> Self {
         Self {
             id: value.id,
             name: value.name,
+            budget_code_pattern: value.budget_code_pattern,
+            legal_name: value.legal_identity.legal_name,
+            tax_id: value.legal_identity.tax_id,
+            registration_number: value.legal_identity.registration_number,
+            address: value.legal_identity.address,
+            logo_url: value.logo_url,
+            archived_at: value.archived_at,
+        }
+    }
+}
+
+impl From<OrganizationClone> for OrganizationCloneResponse {
+    fn from(value: OrganizationClone) -> Self {
+        Self {
+            organization: value.organization.into(),
+            payrolls: value.payrolls,
+            job_grades: value.job_grades,
+            jobs: value.jobs,
+            divisions: value.divisions,
+            banks: value.banks,
+        }
+    }
+}
+
+impl From<OrganizationDeletion> for OrganizationDeletionResponse {
+    fn from(value: OrganizationDeletion) -> Self {
+        Self {
+            organization_id: value.organization_id,
+            requested_at: value.requested_at,
+            scheduled_for: value.scheduled_for,
         }
     }
 }
 
 impl CreateOrganizationRequest {
     fn into_params(self) -> CreateOrganizationParams {
-        CreateOrganizationParams { name: self.name }
+        CreateOrganizationParams {
+            name: self.name,
+            budget_code_pattern: self.budget_code_pattern,
+            legal_identity: LegalIdentity {
+                legal_name: self.legal_name,
+                tax_id: self.tax_id,
+                registration_number: self.registration_number,
+                address: self.address,
+            },
+        }
+    }
+}
+
+impl CloneOrganizationRequest {
+    fn into_params(self) -> CloneOrganizationParams {
+        CloneOrganizationParams {
+            name: self.name,
+            legal_identity: LegalIdentity {
+                legal_name: self.legal_name,
+                tax_id: self.tax_id,
+                registration_number: self.registration_number,
+                address: self.address,
+            },
+        }
     }
 }
 
 impl UpdateOrganizationRequest {
     fn into_params(self) -> UpdateOrganizationParams {
-        UpdateOrganizationParams { name: self.name }
+        UpdateOrganizationParams {
+            name: self.name,
+            budget_code_pattern: self.budget_code_pattern,
+            legal_name: self.legal_name,
+            tax_id: self.tax_id,
+            registration_number: self.registration_number,
+            address: self.address,
+        }
     }
 }
 
+impl UpdateOrganizationSettingsRequest {
+    fn into_params(self) -> UpdateOrganizationSettingsParams {
+        UpdateOrganizationSettingsParams {
+            default_currency: self.default_currency,
+            locale: self.locale,
+            fiscal_year_start: self.fiscal_year_start,
+            working_days_per_week: self.working_days_per_week,
+            rounding: self.rounding,
+        }
+    }
+}
+
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Some(Option::deserialize(deserializer)?))
+}
+
 #[utoipa::path(
     post,
     path = "/organizations",
     request_body = CreateOrganizationRequest,
     responses(
-        (status = 201, description = "Organization created", body = OrganizationResponse)
+        (status = 201, description = "Organization created", body = OrganizationResponse),
+        (status = 422, description = "Empty name, invalid budget code pattern or invalid legal identity")
     ),
     tag = "Organizations",
     operation_id = "create_organization"
@@ -129,7 +325,8 @@ pub async fn get(
     request_body = UpdateOrganizationRequest,
     responses(
         (status = 200, description = "Organization updated", body = OrganizationResponse),
-        (status = 404, description = "Organization not found")
+        (status = 404, description = "Organization not found"),
+        (status = 422, description = "Empty name, invalid budget code pattern or invalid legal identity")
     ),
     tag = "Organizations",
     operation_id = "update_organization"
@@ -152,10 +349,12 @@ pub async fn update(
 #[utoipa::path(
     delete,
     path = "/organizations/{id}",
-    params(OrganizationPathParams),
+    params(OrganizationPathParams, DeleteOrganizationQuery),
     responses(
-        (status = 204, description = "Organization deleted"),
-        (status = 404, description = "Organization not found")
+        (status = 202, description = "Organization scheduled for deletion", body = OrganizationDeletionResponse),
+        (status = 404, description = "Organization not found"),
+        (status = 409, description = "Organization already scheduled for deletion"),
+        (status = 422, description = "Acknowledgement does not match the organization name")
     ),
     tag = "Organizations",
     operation_id = "delete_organization"
@@ -163,15 +362,339 @@ pub async fn update(
 pub async fn delete(
     State(state): State<AppState>,
     Path(params): Path<OrganizationPathParams>,
+    Query(query): Query<DeleteOrganizationQuery>,
+) -> AppResult<(StatusCode, Json<OrganizationDeletionResponse>)> {
+    let deletion = state
+        .organization_deletion_service()
+        .request(
+            params.id,
+            RequestOrganizationDeletionParams {
+                acknowledgement: query.acknowledgement.unwrap_or_default(),
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(deletion.into())))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{id}/clone",
+    params(OrganizationPathParams),
+    request_body = CloneOrganizationRequest,
+    responses(
+        (status = 201, description = "New organization with the template's settings, payrolls, job grades, jobs, divisions and banks, without employees", body = OrganizationCloneResponse),
+        (status = 404, description = "Template organization not found"),
+        (status = 422, description = "Empty name or invalid legal identity")
+    ),
+    tag = "Organizations",
+    operation_id = "clone_organization"
+)]
+pub async fn clone(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+    Json(payload): Json<CloneOrganizationRequest>,
+) -> AppResult<(StatusCode, Json<OrganizationCloneResponse>)> {
+    let id = params.id;
+    let clone = state
+        .organization_clone_service()
+        .clone_organization(id, payload.into_params())
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("organization `{id}` not found")))?;
+
+    Ok((StatusCode::CREATED, Json(clone.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{id}/deletion",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "Pending organization deletion", body = OrganizationDeletionResponse),
+        (status = 404, description = "No deletion pending")
+    ),
+    tag = "Organizations",
+    operation_id = "get_organization_deletion"
+)]
+pub async fn get_deletion(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<Json<OrganizationDeletionResponse>> {
+    let id = params.id;
+    let deletion = state
+        .organization_deletion_service()
+        .get(id)
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!("no deletion pending for organization `{id}`"))
+        })?;
+
+    Ok(Json(deletion.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{id}/deletion",
+    params(OrganizationPathParams),
+    responses(
+        (status = 204, description = "Organization deletion cancelled"),
+        (status = 404, description = "No deletion pending")
+    ),
+    tag = "Organizations",
+    operation_id = "cancel_organization_deletion"
+)]
+pub async fn cancel_deletion(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
 ) -> AppResult<StatusCode> {
     let id = params.id;
-    let removed = state.organization_service().delete(id).await?;
+    let cancelled = state.organization_deletion_service().cancel(id).await?;
+
+    if cancelled {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::not_found(format!(
+            "no deletion pending for organization `{id}`"
+        )))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{id}/archive",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "Organization archived; its records stay readable but every change to them returns 409", body = OrganizationResponse),
+        (status = 404, description = "Organization not found"),
+        (status = 409, description = "Organization already archived")
+    ),
+    tag = "Organizations",
+    operation_id = "archive_organization"
+)]
+pub async fn archive(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<Json<OrganizationResponse>> {
+    let id = params.id;
+    let organization = state
+        .organization_service()
+        .archive(id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("organization `{id}` not found")))?;
+
+    Ok(Json(organization.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{id}/reactivate",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "Organization writable again", body = OrganizationResponse),
+        (status = 404, description = "Organization not found"),
+        (status = 409, description = "Organization is not archived")
+    ),
+    tag = "Organizations",
+    operation_id = "reactivate_organization"
+)]
+pub async fn reactivate(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<Json<OrganizationResponse>> {
+    let id = params.id;
+    let organization = state
+        .organization_service()
+        .reactivate(id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("organization `{id}` not found")))?;
+
+    Ok(Json(organization.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{id}/restore",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "Organization restored together with its payrolls and banks", body = OrganizationResponse),
+        (status = 404, description = "No deleted organization with this id")
+    ),
+    tag = "Organizations",
+    operation_id = "restore_organization"
+)]
+pub async fn restore(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<Json<OrganizationResponse>> {
+    let id = params.id;
+    let organization = state
+        .organization_deletion_service()
+        .restore(id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("no deleted organization `{id}`")))?;
+
+    Ok(Json(organization.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{id}/settings",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "Organization settings; the standard ones until any are saved", body = OrganizationSettings),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organizations",
+    operation_id = "get_organization_settings"
+)]
+pub async fn get_settings(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<Json<OrganizationSettings>> {
+    let settings = state.organization_settings_service().get(params.id).await?;
+
+    Ok(Json(settings))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{id}/settings",
+    params(OrganizationPathParams),
+    request_body = UpdateOrganizationSettingsRequest,
+    responses(
+        (status = 200, description = "Organization settings updated", body = OrganizationSettings),
+        (status = 404, description = "Organization not found"),
+        (status = 422, description = "Invalid currency, locale, fiscal year start, working days or rounding rule")
+    ),
+    tag = "Organizations",
+    operation_id = "update_organization_settings"
+)]
+pub async fn update_settings(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+    Json(payload): Json<UpdateOrganizationSettingsRequest>,
+) -> AppResult<Json<OrganizationSettings>> {
+    let settings = state
+        .organization_settings_service()
+        .update(params.id, payload.into_params())
+        .await?;
+
+    Ok(Json(settings))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{id}/logo",
+    params(OrganizationPathParams),
+    request_body(
+        content = Vec<u8>,
+        description = "PNG, JPEG, or WebP image up to 512 KiB",
+        content_type = "image/*"
+    ),
+    responses(
+        (status = 200, description = "Logo stored", body = OrganizationResponse),
+        (status = 404, description = "Organization not found"),
+        (status = 422, description = "Unsupported content type or size")
+    ),
+    tag = "Organizations",
+    operation_id = "upload_organization_logo"
+)]
+pub async fn upload_logo(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> AppResult<Json<OrganizationResponse>> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+
+    let id = params.id;
+    let organization = state
+        .organization_service()
+        .upload_logo(id, Blob::new(content_type, body.to_vec()))
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("organization `{id}` not found")))?;
+
+    Ok(Json(organization.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{id}/logo",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "Organization logo", body = Vec<u8>, content_type = "image/*"),
+        (status = 404, description = "Organization or logo not found")
+    ),
+    tag = "Organizations",
+    operation_id = "get_organization_logo"
+)]
+pub async fn get_logo(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<impl IntoResponse> {
+    let id = params.id;
+    let logo = state
+        .organization_service()
+        .logo(id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("logo not found for organization `{id}`")))?;
+
+    Ok(([(header::CONTENT_TYPE, logo.content_type)], logo.data))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{id}/logo",
+    params(OrganizationPathParams),
+    responses(
+        (status = 204, description = "Logo removed"),
+        (status = 404, description = "Organization or logo not found")
+    ),
+    tag = "Organizations",
+    operation_id = "delete_organization_logo"
+)]
+pub async fn delete_logo(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<StatusCode> {
+    let id = params.id;
+    let removed = state.organization_service().delete_logo(id).await?;
 
     if removed {
         Ok(StatusCode::NO_CONTENT)
     } else {
         Err(AppError::not_found(format!(
-            "organization `{id}` not found"
+            "logo not found for organization `{id}`"
         )))
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{id}/calendar",
+    params(OrganizationPathParams, CalendarQuery),
+    responses(
+        (status = 200, description = "Fiscal year starts, approval deadlines, salary adjustments, leave, blackouts and contract, probation and permit ends in the range, by date", body = OrganizationCalendar),
+        (status = 404, description = "Organization or payroll not found"),
+        (status = 422, description = "Invalid range")
+    ),
+    tag = "Organizations",
+    operation_id = "get_organization_calendar"
+)]
+pub async fn calendar(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+    Query(query): Query<CalendarQuery>,
+) -> AppResult<Json<OrganizationCalendar>> {
+    let calendar = state
+        .calendar_service()
+        .events(params.id, query.payroll_id, query.from, query.to)
+        .await?;
+
+    Ok(Json(calendar))
+}
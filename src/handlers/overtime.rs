@@ -0,0 +1,335 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::overtime::{
+        OvertimePay, OvertimePolicy, OvertimeRequest, OvertimeRequestStatus, OvertimeWarningReport,
+    },
+    error::{AppError, AppResult},
+    server::AppState,
+    services::overtime::CreateOvertimeRequestParams,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetOvertimePolicyRequest {
+    /// Rate applied to overtime hours, at least 1.
+    pub multiplier: f64,
+    /// Pay the multiplier only on overtime approved beforehand.
+    pub requires_preapproval: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateOvertimeRequestRequest {
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    /// Overtime hours asked for on the day.
+    pub hours: f64,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReviewOvertimeRequestRequest {
+    /// The employee's supervisor or the manager of their division.
+    pub approver_id: Uuid,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct OvertimePayrollPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct OvertimeEmployeePathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct OvertimeRequestPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+    pub request_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OvertimePeriodQuery {
+    /// First day of the pay period.
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    /// Last day of the pay period.
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+}
+
+fn request_not_found(params: &OvertimeRequestPathParams) -> AppError {
+    AppError::not_found(format!(
+        "overtime request `{}` not found for employee `{}`",
+        params.request_id, params.employee_id
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/overtime-policy",
+    params(OvertimePayrollPathParams),
+    responses(
+        (status = 200, description = "Overtime multiplier and pre-approval rule; 1.5 without pre-approval when none is set", body = OvertimePolicy),
+        (status = 404, description = "Payroll not found")
+    ),
+    tag = "Timesheets",
+    operation_id = "get_overtime_policy"
+)]
+pub async fn get_policy(
+    State(state): State<AppState>,
+    Path(params): Path<OvertimePayrollPathParams>,
+) -> AppResult<Json<OvertimePolicy>> {
+    let policy = state
+        .overtime_service()
+        .policy(params.organization_id, params.payroll_id)
+        .await?;
+
+    Ok(Json(policy))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/overtime-policy",
+    params(OvertimePayrollPathParams),
+    request_body = SetOvertimePolicyRequest,
+    responses(
+        (status = 200, description = "Overtime policy replaced", body = OvertimePolicy),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Multiplier below 1")
+    ),
+    tag = "Timesheets",
+    operation_id = "set_overtime_policy"
+)]
+pub async fn set_policy(
+    State(state): State<AppState>,
+    Path(params): Path<OvertimePayrollPathParams>,
+    Json(payload): Json<SetOvertimePolicyRequest>,
+) -> AppResult<Json<OvertimePolicy>> {
+    let policy = state
+        .overtime_service()
+        .set_policy(
+            params.organization_id,
+            params.payroll_id,
+            payload.multiplier,
+            payload.requires_preapproval,
+        )
+        .await?;
+
+    Ok(Json(policy))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-requests",
+    params(OvertimeEmployeePathParams),
+    request_body = CreateOvertimeRequestRequest,
+    responses(
+        (status = 201, description = "Overtime request awaiting manager approval", body = OvertimeRequest),
+        (status = 404, description = "Employee not found"),
+        (status = 409, description = "Another request already covers the day"),
+        (status = 422, description = "Invalid date or hours")
+    ),
+    tag = "Timesheets",
+    operation_id = "create_overtime_request"
+)]
+pub async fn create_request(
+    State(state): State<AppState>,
+    Path(params): Path<OvertimeEmployeePathParams>,
+    Json(payload): Json<CreateOvertimeRequestRequest>,
+) -> AppResult<(StatusCode, Json<OvertimeRequest>)> {
+    let request = state
+        .overtime_service()
+        .create_request(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            CreateOvertimeRequestParams {
+                date: payload.date,
+                hours: payload.hours,
+                reason: payload.reason,
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(request)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-requests",
+    params(OvertimeEmployeePathParams),
+    responses(
+        (status = 200, description = "Overtime requests by date", body = [OvertimeRequest]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Timesheets",
+    operation_id = "list_overtime_requests"
+)]
+pub async fn list_requests(
+    State(state): State<AppState>,
+    Path(params): Path<OvertimeEmployeePathParams>,
+) -> AppResult<Json<Vec<OvertimeRequest>>> {
+    let requests = state
+        .overtime_service()
+        .list_requests(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?;
+
+    Ok(Json(requests))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-requests/{request_id}/approve",
+    params(OvertimeRequestPathParams),
+    request_body = ReviewOvertimeRequestRequest,
+    responses(
+        (status = 200, description = "Overtime request approved", body = OvertimeRequest),
+        (status = 404, description = "Overtime request not found"),
+        (status = 409, description = "Request already reviewed"),
+        (status = 422, description = "Approver is not the employee's supervisor or division manager")
+    ),
+    tag = "Timesheets",
+    operation_id = "approve_overtime_request"
+)]
+pub async fn approve_request(
+    State(state): State<AppState>,
+    Path(params): Path<OvertimeRequestPathParams>,
+    Json(payload): Json<ReviewOvertimeRequestRequest>,
+) -> AppResult<Json<OvertimeRequest>> {
+    review_request(state, params, OvertimeRequestStatus::Approved, payload).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-requests/{request_id}/reject",
+    params(OvertimeRequestPathParams),
+    request_body = ReviewOvertimeRequestRequest,
+    responses(
+        (status = 200, description = "Overtime request rejected", body = OvertimeRequest),
+        (status = 404, description = "Overtime request not found"),
+        (status = 409, description = "Request already reviewed"),
+        (status = 422, description = "Approver is not the employee's supervisor or division manager")
+    ),
+    tag = "Timesheets",
+    operation_id = "reject_overtime_request"
+)]
+pub async fn reject_request(
+    State(state): State<AppState>,
+    Path(params): Path<OvertimeRequestPathParams>,
+    Json(payload): Json<ReviewOvertimeRequestRequest>,
+) -> AppResult<Json<OvertimeRequest>> {
+    review_request(state, params, OvertimeRequestStatus::Rejected, payload).await
+}
+
+async fn review_request(
+    state: AppState,
+    params: OvertimeRequestPathParams,
+    status: OvertimeRequestStatus,
+    payload: ReviewOvertimeRequestRequest,
+) -> AppResult<Json<OvertimeRequest>> {
+    let request = state
+        .overtime_service()
+        .review_request(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.request_id,
+            payload.approver_id,
+            status,
+            payload.note,
+        )
+        .await?
+        .ok_or_else(|| request_not_found(&params))?;
+
+    Ok(Json(request))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-pay",
+    params(OvertimeEmployeePathParams, OvertimePeriodQuery),
+    responses(
+        (status = 200, description = "Paid hours split between the ordinary rate and the overtime multiplier", body = OvertimePay),
+        (status = 404, description = "Employee not found"),
+        (status = 422, description = "Invalid range")
+    ),
+    tag = "Timesheets",
+    operation_id = "get_overtime_pay"
+)]
+pub async fn pay(
+    State(state): State<AppState>,
+    Path(params): Path<OvertimeEmployeePathParams>,
+    Query(query): Query<OvertimePeriodQuery>,
+) -> AppResult<Json<OvertimePay>> {
+    let pay = state
+        .overtime_service()
+        .overtime_pay(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            query.from,
+            query.to,
+        )
+        .await?;
+
+    Ok(Json(pay))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/overtime-warnings",
+    params(OvertimePayrollPathParams, OvertimePeriodQuery),
+    responses(
+        (status = 200, description = "Overtime worked without an approved request, which the payroll run pays at the ordinary rate", body = OvertimeWarningReport),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Invalid range")
+    ),
+    tag = "Timesheets",
+    operation_id = "list_overtime_warnings"
+)]
+pub async fn warnings(
+    State(state): State<AppState>,
+    Path(params): Path<OvertimePayrollPathParams>,
+    Query(query): Query<OvertimePeriodQuery>,
+) -> AppResult<Json<OvertimeWarningReport>> {
+    let report = state
+        .overtime_service()
+        .warnings(
+            params.organization_id,
+            params.payroll_id,
+            query.from,
+            query.to,
+        )
+        .await?;
+
+    Ok(Json(report))
+}
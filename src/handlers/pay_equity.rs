@@ -0,0 +1,52 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::{domain::pay_equity::PayEquityReport, error::AppResult, server::AppState};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct PayEquityPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PayEquityQuery {
+    /// Smallest group whose figures are shown (default 5, at least 2).
+    pub min_group_size: Option<usize>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/pay-equity",
+    params(PayEquityPathParams, PayEquityQuery),
+    responses(
+        (status = 200, description = "Average and median annual pay by gender and classification within each job grade", body = PayEquityReport),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Minimum group size below 2")
+    ),
+    tag = "Jobs",
+    operation_id = "get_pay_equity_report"
+)]
+pub async fn report(
+    State(state): State<AppState>,
+    Path(params): Path<PayEquityPathParams>,
+    Query(query): Query<PayEquityQuery>,
+) -> AppResult<Json<PayEquityReport>> {
+    let report = state
+        .pay_equity_service()
+        .report(
+            params.organization_id,
+            params.payroll_id,
+            query.min_group_size,
+        )
+        .await?;
+
+    Ok(Json(report))
+}
@@ -3,7 +3,7 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
@@ -18,12 +18,22 @@ use crate::{
 pub struct CreatePayrollRequest {
     pub name: String,
     pub description: String,
+    /// Hold employees out of payment batches until all their accounts are verified.
+    #[serde(default)]
+    pub blocks_unverified_accounts: bool,
+    /// Legal entity the payroll reports and pays under; the organization itself when omitted.
+    pub legal_entity_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdatePayrollRequest {
     pub name: Option<String>,
     pub description: Option<String>,
+    pub blocks_unverified_accounts: Option<bool>,
+    /// `null` moves the payroll back under the organization itself.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<Uuid>)]
+    pub legal_entity_id: Option<Option<Uuid>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -32,6 +42,8 @@ pub struct PayrollResponse {
     pub name: String,
     pub description: String,
     pub organization_id: Uuid,
+    pub blocks_unverified_accounts: bool,
+    pub legal_entity_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, IntoParams)]
@@ -54,6 +66,8 @@ impl From<Payroll> for PayrollResponse {
             name: value.name,
             description: value.description,
             organization_id: value.organization_id,
+            blocks_unverified_accounts: value.blocks_unverified_accounts,
+            legal_entity_id: value.legal_entity_id,
         }
     }
 }
@@ -63,6 +77,8 @@ impl CreatePayrollRequest {
         CreatePayrollParams {
             name: self.name,
             description: self.description,
+            blocks_unverified_accounts: self.blocks_unverified_accounts,
+            legal_entity_id: self.legal_entity_id,
         }
     }
 }
@@ -72,10 +88,20 @@ impl UpdatePayrollRequest {
         UpdatePayrollParams {
             name: self.name,
             description: self.description,
+            blocks_unverified_accounts: self.blocks_unverified_accounts,
+            legal_entity_id: self.legal_entity_id,
         }
     }
 }
 
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Some(Option::deserialize(deserializer)?))
+}
+
 #[utoipa::path(
     post,
     path = "/organizations/{organization_id}/payrolls",
@@ -0,0 +1,347 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::position::{Position, PositionAssignment},
+    error::{AppError, AppResult},
+    server::AppState,
+    services::position::{AssignPositionParams, CreatePositionParams, UpdatePositionParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreatePositionRequest {
+    pub title: String,
+    pub job_id: Uuid,
+    pub budget: f64,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdatePositionRequest {
+    pub title: Option<String>,
+    pub job_id: Option<Uuid>,
+    pub budget: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AssignPositionRequest {
+    /// Employee of the position's division.
+    pub employee_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub start_date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UnassignPositionRequest {
+    #[schema(value_type = String, format = Date)]
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PositionResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub job_id: Uuid,
+    pub budget: f64,
+    pub division_id: Uuid,
+    pub payroll_id: Uuid,
+    pub employee_id: Option<Uuid>,
+    pub vacant: bool,
+    pub history: Vec<PositionAssignment>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct PositionCollectionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct PositionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub position_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PositionListQuery {
+    /// Only list positions without a current holder.
+    #[serde(default)]
+    pub vacant: bool,
+}
+
+impl From<Position> for PositionResponse {
+    fn from(value: Position) -> Self {
+        Self {
+            vacant: value.is_vacant(),
+            id: value.id,
+            title: value.title,
+            job_id: value.job_id,
+            budget: value.budget,
+            division_id: value.division_id,
+            payroll_id: value.payroll_id,
+            employee_id: value.employee_id,
+            history: value.history,
+        }
+    }
+}
+
+impl CreatePositionRequest {
+    fn into_params(self) -> CreatePositionParams {
+        CreatePositionParams {
+            title: self.title,
+            job_id: self.job_id,
+            budget: self.budget,
+        }
+    }
+}
+
+impl UpdatePositionRequest {
+    fn into_params(self) -> UpdatePositionParams {
+        UpdatePositionParams {
+            title: self.title,
+            job_id: self.job_id,
+            budget: self.budget,
+        }
+    }
+}
+
+fn position_not_found(params: &PositionPathParams) -> AppError {
+    AppError::not_found(format!(
+        "position `{}` not found for division `{}` in payroll `{}`",
+        params.position_id, params.division_id, params.payroll_id
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions",
+    params(PositionCollectionPathParams),
+    request_body = CreatePositionRequest,
+    responses(
+        (status = 201, description = "Position created", body = PositionResponse),
+        (status = 404, description = "Division or job not found"),
+        (status = 422, description = "Invalid title or budget")
+    ),
+    tag = "Positions",
+    operation_id = "create_position"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<PositionCollectionPathParams>,
+    Json(payload): Json<CreatePositionRequest>,
+) -> AppResult<(StatusCode, Json<PositionResponse>)> {
+    let position = state
+        .position_service()
+        .create(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            payload.into_params(),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(position.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions",
+    params(PositionCollectionPathParams, PositionListQuery),
+    responses(
+        (status = 200, description = "List positions", body = [PositionResponse])
+    ),
+    tag = "Positions",
+    operation_id = "list_positions"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<PositionCollectionPathParams>,
+    Query(query): Query<PositionListQuery>,
+) -> AppResult<Json<Vec<PositionResponse>>> {
+    let positions = state
+        .position_service()
+        .list(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            query.vacant,
+        )
+        .await?;
+    let response = positions.into_iter().map(PositionResponse::from).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions/{position_id}",
+    params(PositionPathParams),
+    responses(
+        (status = 200, description = "Get position", body = PositionResponse),
+        (status = 404, description = "Position not found")
+    ),
+    tag = "Positions",
+    operation_id = "get_position"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<PositionPathParams>,
+) -> AppResult<Json<PositionResponse>> {
+    let position = state
+        .position_service()
+        .get(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.position_id,
+        )
+        .await?
+        .ok_or_else(|| position_not_found(&params))?;
+
+    Ok(Json(position.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions/{position_id}",
+    params(PositionPathParams),
+    request_body = UpdatePositionRequest,
+    responses(
+        (status = 200, description = "Position updated", body = PositionResponse),
+        (status = 404, description = "Position not found")
+    ),
+    tag = "Positions",
+    operation_id = "update_position"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<PositionPathParams>,
+    Json(payload): Json<UpdatePositionRequest>,
+) -> AppResult<Json<PositionResponse>> {
+    let position = state
+        .position_service()
+        .update(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.position_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| position_not_found(&params))?;
+
+    Ok(Json(position.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions/{position_id}",
+    params(PositionPathParams),
+    responses(
+        (status = 204, description = "Position deleted"),
+        (status = 404, description = "Position not found"),
+        (status = 409, description = "Position is occupied")
+    ),
+    tag = "Positions",
+    operation_id = "delete_position"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<PositionPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .position_service()
+        .delete(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.position_id,
+        )
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(position_not_found(&params))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions/{position_id}/assign",
+    params(PositionPathParams),
+    request_body = AssignPositionRequest,
+    responses(
+        (status = 200, description = "Employee assigned", body = PositionResponse),
+        (status = 404, description = "Position or employee not found"),
+        (status = 409, description = "Position occupied or employee already holds a position")
+    ),
+    tag = "Positions",
+    operation_id = "assign_position"
+)]
+pub async fn assign(
+    State(state): State<AppState>,
+    Path(params): Path<PositionPathParams>,
+    Json(payload): Json<AssignPositionRequest>,
+) -> AppResult<Json<PositionResponse>> {
+    let position = state
+        .position_service()
+        .assign(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.position_id,
+            AssignPositionParams {
+                employee_id: payload.employee_id,
+                start_date: payload.start_date,
+            },
+        )
+        .await?
+        .ok_or_else(|| position_not_found(&params))?;
+
+    Ok(Json(position.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions/{position_id}/unassign",
+    params(PositionPathParams),
+    request_body = UnassignPositionRequest,
+    responses(
+        (status = 200, description = "Position vacated", body = PositionResponse),
+        (status = 404, description = "Position not found"),
+        (status = 409, description = "Position is already vacant")
+    ),
+    tag = "Positions",
+    operation_id = "unassign_position"
+)]
+pub async fn unassign(
+    State(state): State<AppState>,
+    Path(params): Path<PositionPathParams>,
+    Json(payload): Json<UnassignPositionRequest>,
+) -> AppResult<Json<PositionResponse>> {
+    let position = state
+        .position_service()
+        .unassign(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.position_id,
+            payload.end_date,
+        )
+        .await?
+        .ok_or_else(|| position_not_found(&params))?;
+
+    Ok(Json(position.into()))
+}
@@ -0,0 +1,95 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::{
+    domain::profile_completeness::{OrganizationCompleteness, ProfileCompleteness},
+    error::{AppError, AppResult},
+    server::AppState,
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct EmployeeCompletenessPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct OrganizationCompletenessPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CompletenessScopeQuery {
+    /// Only employees of this payroll.
+    pub payroll_id: Option<Uuid>,
+    /// Only employees of this division.
+    pub division_id: Option<Uuid>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/profile-completeness",
+    params(EmployeeCompletenessPathParams),
+    responses(
+        (status = 200, description = "Completeness score and missing profile details", body = ProfileCompleteness),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Employees",
+    operation_id = "get_employee_profile_completeness"
+)]
+pub async fn employee(
+    State(state): State<AppState>,
+    Path(params): Path<EmployeeCompletenessPathParams>,
+) -> AppResult<Json<ProfileCompleteness>> {
+    let completeness = state
+        .profile_completeness_service()
+        .employee(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "employee `{}` not found for division `{}` in payroll `{}`",
+                params.employee_id, params.division_id, params.payroll_id
+            ))
+        })?;
+
+    Ok(Json(completeness))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/profile-completeness",
+    params(OrganizationCompletenessPathParams, CompletenessScopeQuery),
+    responses(
+        (status = 200, description = "Completeness roll-up over employees who are not terminated", body = OrganizationCompleteness),
+        (status = 404, description = "Organization, payroll or division not found")
+    ),
+    tag = "Employees",
+    operation_id = "get_organization_profile_completeness"
+)]
+pub async fn organization(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationCompletenessPathParams>,
+    Query(query): Query<CompletenessScopeQuery>,
+) -> AppResult<Json<OrganizationCompleteness>> {
+    let completeness = state
+        .profile_completeness_service()
+        .organization(params.organization_id, query.payroll_id, query.division_id)
+        .await?;
+
+    Ok(Json(completeness))
+}
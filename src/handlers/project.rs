@@ -0,0 +1,252 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::project::{Project, ProjectLaborCostReport},
+    error::{AppError, AppResult},
+    server::AppState,
+    services::project::{CreateProjectParams, UpdateProjectParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateProjectRequest {
+    /// Unique within the organization, ignoring case.
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateProjectRequest {
+    pub code: Option<String>,
+    pub name: Option<String>,
+    /// `null` clears the description.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub description: Option<Option<String>>,
+    /// `false` stops new hours from being charged to the project.
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct ProjectCollectionPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct ProjectPathParams {
+    pub organization_id: Uuid,
+    pub project_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProjectLaborCostQuery {
+    /// First day to include.
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    /// Last day to include.
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+}
+
+impl CreateProjectRequest {
+    fn into_params(self) -> CreateProjectParams {
+        CreateProjectParams {
+            code: self.code,
+            name: self.name,
+            description: self.description,
+        }
+    }
+}
+
+impl UpdateProjectRequest {
+    fn into_params(self) -> UpdateProjectParams {
+        UpdateProjectParams {
+            code: self.code,
+            name: self.name,
+            description: self.description,
+            active: self.active,
+        }
+    }
+}
+
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Some(Option::deserialize(deserializer)?))
+}
+
+fn project_not_found(params: &ProjectPathParams) -> AppError {
+    AppError::not_found(format!(
+        "project `{}` not found for organization `{}`",
+        params.project_id, params.organization_id
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/projects",
+    params(ProjectCollectionPathParams),
+    request_body = CreateProjectRequest,
+    responses(
+        (status = 201, description = "Project created", body = Project),
+        (status = 404, description = "Organization not found"),
+        (status = 409, description = "Project code already in use"),
+        (status = 422, description = "Empty code or name")
+    ),
+    tag = "Projects",
+    operation_id = "create_project"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<ProjectCollectionPathParams>,
+    Json(payload): Json<CreateProjectRequest>,
+) -> AppResult<(StatusCode, Json<Project>)> {
+    let project = state
+        .project_service()
+        .create(params.organization_id, payload.into_params())
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(project)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/projects",
+    params(ProjectCollectionPathParams),
+    responses(
+        (status = 200, description = "Projects by code", body = [Project]),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Projects",
+    operation_id = "list_projects"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<ProjectCollectionPathParams>,
+) -> AppResult<Json<Vec<Project>>> {
+    let projects = state.project_service().list(params.organization_id).await?;
+
+    Ok(Json(projects))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/projects/{project_id}",
+    params(ProjectPathParams),
+    responses(
+        (status = 200, description = "Get project", body = Project),
+        (status = 404, description = "Project not found")
+    ),
+    tag = "Projects",
+    operation_id = "get_project"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<ProjectPathParams>,
+) -> AppResult<Json<Project>> {
+    let project = state
+        .project_service()
+        .get(params.organization_id, params.project_id)
+        .await?
+        .ok_or_else(|| project_not_found(&params))?;
+
+    Ok(Json(project))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/projects/{project_id}",
+    params(ProjectPathParams),
+    request_body = UpdateProjectRequest,
+    responses(
+        (status = 200, description = "Project updated", body = Project),
+        (status = 404, description = "Project not found"),
+        (status = 409, description = "Project code already in use"),
+        (status = 422, description = "No fields supplied or empty code or name")
+    ),
+    tag = "Projects",
+    operation_id = "update_project"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<ProjectPathParams>,
+    Json(payload): Json<UpdateProjectRequest>,
+) -> AppResult<Json<Project>> {
+    let project = state
+        .project_service()
+        .update(
+            params.organization_id,
+            params.project_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| project_not_found(&params))?;
+
+    Ok(Json(project))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/projects/{project_id}",
+    params(ProjectPathParams),
+    responses(
+        (status = 204, description = "Project deleted"),
+        (status = 404, description = "Project not found"),
+        (status = 409, description = "Timesheet entries are charged to the project")
+    ),
+    tag = "Projects",
+    operation_id = "delete_project"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<ProjectPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .project_service()
+        .delete(params.organization_id, params.project_id)
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(project_not_found(&params))
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/project-labor-costs",
+    params(ProjectCollectionPathParams, ProjectLaborCostQuery),
+    responses(
+        (status = 200, description = "Paid hours and their cost by project and currency", body = ProjectLaborCostReport),
+        (status = 404, description = "Organization not found"),
+        (status = 422, description = "Invalid range")
+    ),
+    tag = "Projects",
+    operation_id = "get_project_labor_costs"
+)]
+pub async fn labor_costs(
+    State(state): State<AppState>,
+    Path(params): Path<ProjectCollectionPathParams>,
+    Query(query): Query<ProjectLaborCostQuery>,
+) -> AppResult<Json<ProjectLaborCostReport>> {
+    let report = state
+        .timesheet_service()
+        .project_labor_costs(params.organization_id, query.from, query.to)
+        .await?;
+
+    Ok(Json(report))
+}
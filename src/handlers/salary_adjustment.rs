@@ -0,0 +1,208 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::salary_adjustment::SalaryAdjustmentBatch,
+    error::{AppError, AppResult},
+    server::AppState,
+    services::salary_adjustment::CreateSalaryAdjustmentParams,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateSalaryAdjustmentRequest {
+    /// Raise in percent, e.g. `3.5`.
+    pub percentage: f64,
+    #[schema(value_type = String, format = Date)]
+    pub effective_date: NaiveDate,
+    /// Jobs to raise. With no jobs and no divisions, every job of the payroll is raised.
+    #[serde(default)]
+    pub job_ids: Vec<Uuid>,
+    /// Raises every job held by a current employee of these divisions.
+    #[serde(default)]
+    pub division_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DecideSalaryAdjustmentRequest {
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct SalaryAdjustmentCollectionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct SalaryAdjustmentPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub adjustment_id: Uuid,
+}
+
+fn adjustment_not_found(params: &SalaryAdjustmentPathParams) -> AppError {
+    AppError::not_found(format!(
+        "salary adjustment `{}` not found for payroll `{}`",
+        params.adjustment_id, params.payroll_id
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments",
+    params(SalaryAdjustmentCollectionPathParams),
+    request_body = CreateSalaryAdjustmentRequest,
+    responses(
+        (status = 201, description = "Pending batch with per-job changes and preview totals", body = SalaryAdjustmentBatch),
+        (status = 404, description = "Payroll, job or division not found"),
+        (status = 422, description = "Invalid percentage, empty selection, or a new salary outside its range or grade band")
+    ),
+    tag = "Jobs",
+    operation_id = "create_salary_adjustment"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<SalaryAdjustmentCollectionPathParams>,
+    Json(payload): Json<CreateSalaryAdjustmentRequest>,
+) -> AppResult<(StatusCode, Json<SalaryAdjustmentBatch>)> {
+    let batch = state
+        .salary_adjustment_service()
+        .create(
+            params.organization_id,
+            params.payroll_id,
+            CreateSalaryAdjustmentParams {
+                percentage: payload.percentage,
+                effective_date: payload.effective_date,
+                job_ids: payload.job_ids,
+                division_ids: payload.division_ids,
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(batch)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments",
+    params(SalaryAdjustmentCollectionPathParams),
+    responses(
+        (status = 200, description = "Salary adjustment batches, newest first", body = [SalaryAdjustmentBatch]),
+        (status = 404, description = "Payroll not found")
+    ),
+    tag = "Jobs",
+    operation_id = "list_salary_adjustments"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<SalaryAdjustmentCollectionPathParams>,
+) -> AppResult<Json<Vec<SalaryAdjustmentBatch>>> {
+    let batches = state
+        .salary_adjustment_service()
+        .list(params.organization_id, params.payroll_id)
+        .await?;
+
+    Ok(Json(batches))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments/{adjustment_id}",
+    params(SalaryAdjustmentPathParams),
+    responses(
+        (status = 200, description = "Get salary adjustment batch", body = SalaryAdjustmentBatch),
+        (status = 404, description = "Batch not found")
+    ),
+    tag = "Jobs",
+    operation_id = "get_salary_adjustment"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<SalaryAdjustmentPathParams>,
+) -> AppResult<Json<SalaryAdjustmentBatch>> {
+    let batch = state
+        .salary_adjustment_service()
+        .get(
+            params.organization_id,
+            params.payroll_id,
+            params.adjustment_id,
+        )
+        .await?
+        .ok_or_else(|| adjustment_not_found(&params))?;
+
+    Ok(Json(batch))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments/{adjustment_id}/approve",
+    params(SalaryAdjustmentPathParams),
+    request_body = DecideSalaryAdjustmentRequest,
+    responses(
+        (status = 200, description = "Batch approved, and applied if its effective date has come", body = SalaryAdjustmentBatch),
+        (status = 404, description = "Batch not found"),
+        (status = 409, description = "Already decided"),
+        (status = 422, description = "A new salary no longer fits its job's range or grade band")
+    ),
+    tag = "Jobs",
+    operation_id = "approve_salary_adjustment"
+)]
+pub async fn approve(
+    State(state): State<AppState>,
+    Path(params): Path<SalaryAdjustmentPathParams>,
+    Json(payload): Json<DecideSalaryAdjustmentRequest>,
+) -> AppResult<Json<SalaryAdjustmentBatch>> {
+    let batch = state
+        .salary_adjustment_service()
+        .approve(
+            params.organization_id,
+            params.payroll_id,
+            params.adjustment_id,
+            payload.note,
+        )
+        .await?
+        .ok_or_else(|| adjustment_not_found(&params))?;
+
+    Ok(Json(batch))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments/{adjustment_id}/reject",
+    params(SalaryAdjustmentPathParams),
+    request_body = DecideSalaryAdjustmentRequest,
+    responses(
+        (status = 200, description = "Batch rejected", body = SalaryAdjustmentBatch),
+        (status = 404, description = "Batch not found"),
+        (status = 409, description = "Already decided")
+    ),
+    tag = "Jobs",
+    operation_id = "reject_salary_adjustment"
+)]
+pub async fn reject(
+    State(state): State<AppState>,
+    Path(params): Path<SalaryAdjustmentPathParams>,
+    Json(payload): Json<DecideSalaryAdjustmentRequest>,
+) -> AppResult<Json<SalaryAdjustmentBatch>> {
+    let batch = state
+        .salary_adjustment_service()
+        .reject(
+            params.organization_id,
+            params.payroll_id,
+            params.adjustment_id,
+            payload.note,
+        )
+        .await?
+        .ok_or_else(|| adjustment_not_found(&params))?;
+
+    Ok(Json(batch))
+}
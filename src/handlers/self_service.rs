@@ -0,0 +1,170 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::self_service::{IssuedSelfServiceToken, SelfServiceToken},
+    error::{AppError, AppResult},
+    extractors::bearer::BearerToken,
+    handlers::employee::EmployeeResponse,
+    server::AppState,
+    services::self_service::IssueSelfServiceTokenParams,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct IssueSelfServiceTokenRequest {
+    /// Where the token is used, e.g. the portal or device it was handed to.
+    pub label: Option<String>,
+    /// Days until the token stops working, up to 365; it never expires when omitted.
+    pub expires_in_days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct SelfServiceTokenCollectionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct SelfServiceTokenPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+    pub token_id: Uuid,
+}
+
+impl IssueSelfServiceTokenRequest {
+    fn into_params(self) -> IssueSelfServiceTokenParams {
+        IssueSelfServiceTokenParams {
+            label: self.label,
+            expires_in_days: self.expires_in_days,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/self-service-tokens",
+    params(SelfServiceTokenCollectionPathParams),
+    request_body = IssueSelfServiceTokenRequest,
+    responses(
+        (status = 201, description = "Token issued; the secret is only returned here", body = IssuedSelfServiceToken),
+        (status = 404, description = "Employee not found"),
+        (status = 422, description = "Invalid lifetime")
+    ),
+    tag = "Self-Service",
+    operation_id = "issue_self_service_token"
+)]
+pub async fn issue(
+    State(state): State<AppState>,
+    Path(params): Path<SelfServiceTokenCollectionPathParams>,
+    Json(payload): Json<IssueSelfServiceTokenRequest>,
+) -> AppResult<(StatusCode, Json<IssuedSelfServiceToken>)> {
+    let issued = state
+        .self_service_service()
+        .issue(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            payload.into_params(),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(issued)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/self-service-tokens",
+    params(SelfServiceTokenCollectionPathParams),
+    responses(
+        (status = 200, description = "Tokens issued to the employee, newest first", body = [SelfServiceToken]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Self-Service",
+    operation_id = "list_self_service_tokens"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<SelfServiceTokenCollectionPathParams>,
+) -> AppResult<Json<Vec<SelfServiceToken>>> {
+    let tokens = state
+        .self_service_service()
+        .list(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?;
+
+    Ok(Json(tokens))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/self-service-tokens/{token_id}",
+    params(SelfServiceTokenPathParams),
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 404, description = "Token not found")
+    ),
+    tag = "Self-Service",
+    operation_id = "revoke_self_service_token"
+)]
+pub async fn revoke(
+    State(state): State<AppState>,
+    Path(params): Path<SelfServiceTokenPathParams>,
+) -> AppResult<StatusCode> {
+    state
+        .self_service_service()
+        .revoke(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.token_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::not_found(format!(
+                "self-service token `{}` not found for employee `{}`",
+                params.token_id, params.employee_id
+            ))
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/me",
+    params(
+        ("Authorization" = String, Header, description = "`Bearer <secret>` from a self-service token")
+    ),
+    responses(
+        (status = 200, description = "The token holder's own profile", body = EmployeeResponse),
+        (status = 401, description = "Missing, invalid, expired or revoked token")
+    ),
+    tag = "Self-Service",
+    operation_id = "get_my_profile"
+)]
+pub async fn me(
+    State(state): State<AppState>,
+    BearerToken(secret): BearerToken,
+) -> AppResult<Json<EmployeeResponse>> {
+    let employee = state.self_service_service().me(&secret).await?;
+
+    Ok(Json(employee.into()))
+}
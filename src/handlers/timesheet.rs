@@ -0,0 +1,564 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::timesheet::{
+        TimesheetEntry, TimesheetEntryStatus, TimesheetPeriod, TimesheetPeriodStatus,
+        UnapprovedTimesheetReport,
+    },
+    error::{AppError, AppResult},
+    server::AppState,
+    services::timesheet::{
+        CreateTimesheetEntryParams, SubmitTimesheetParams, UpdateTimesheetEntryParams,
+    },
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTimesheetEntryRequest {
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    pub hours: f64,
+    /// Project or cost code the hours are charged to.
+    pub cost_code: Option<String>,
+    /// Active project of the organization the hours are charged to.
+    pub project_id: Option<Uuid>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTimesheetEntryRequest {
+    #[schema(value_type = Option<String>, format = Date)]
+    pub date: Option<NaiveDate>,
+    pub hours: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub cost_code: Option<Option<String>>,
+    /// `null` removes the project.
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<Uuid>)]
+    pub project_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<String>)]
+    pub note: Option<Option<String>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReviewTimesheetEntryRequest {
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SubmitTimesheetRequest {
+    #[schema(value_type = String, format = Date)]
+    pub period_start: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub period_end: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReviewTimesheetRequest {
+    /// The employee's supervisor or the manager of their division.
+    pub approver_id: Uuid,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct TimesheetCollectionPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct TimesheetEntryPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+    pub entry_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct TimesheetPeriodPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+    pub division_id: Uuid,
+    pub employee_id: Uuid,
+    pub period_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct UnapprovedTimesheetPathParams {
+    pub organization_id: Uuid,
+    pub payroll_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UnapprovedTimesheetQuery {
+    /// First day of the pay period.
+    #[param(value_type = String, format = Date)]
+    pub from: NaiveDate,
+    /// Last day of the pay period.
+    #[param(value_type = String, format = Date)]
+    pub to: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TimesheetRangeQuery {
+    /// First day to include.
+    #[param(value_type = Option<String>, format = Date)]
+    pub from: Option<NaiveDate>,
+    /// Last day to include.
+    #[param(value_type = Option<String>, format = Date)]
+    pub to: Option<NaiveDate>,
+}
+
+impl CreateTimesheetEntryRequest {
+    fn into_params(self) -> CreateTimesheetEntryParams {
+        CreateTimesheetEntryParams {
+            date: self.date,
+            hours: self.hours,
+            cost_code: self.cost_code,
+            project_id: self.project_id,
+            note: self.note,
+            punch: None,
+        }
+    }
+}
+
+impl UpdateTimesheetEntryRequest {
+    fn into_params(self) -> UpdateTimesheetEntryParams {
+        UpdateTimesheetEntryParams {
+            date: self.date,
+            hours: self.hours,
+            cost_code: self.cost_code,
+            project_id: self.project_id,
+            note: self.note,
+        }
+    }
+}
+
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Some(Option::deserialize(deserializer)?))
+}
+
+fn entry_not_found(params: &TimesheetEntryPathParams) -> AppError {
+    AppError::not_found(format!(
+        "timesheet entry `{}` not found for employee `{}`",
+        params.entry_id, params.employee_id
+    ))
+}
+
+fn period_not_found(params: &TimesheetPeriodPathParams) -> AppError {
+    AppError::not_found(format!(
+        "timesheet `{}` not found for employee `{}`",
+        params.period_id, params.employee_id
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries",
+    params(TimesheetCollectionPathParams),
+    request_body = CreateTimesheetEntryRequest,
+    responses(
+        (status = 201, description = "Timesheet entry submitted for approval", body = TimesheetEntry),
+        (status = 404, description = "Employee or project not found"),
+        (status = 409, description = "The day's timesheet is submitted or approved"),
+        (status = 422, description = "Invalid date or hours, or inactive project")
+    ),
+    tag = "Timesheets",
+    operation_id = "create_timesheet_entry"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetCollectionPathParams>,
+    Json(payload): Json<CreateTimesheetEntryRequest>,
+) -> AppResult<(StatusCode, Json<TimesheetEntry>)> {
+    let entry = state
+        .timesheet_service()
+        .create(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            payload.into_params(),
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries",
+    params(TimesheetCollectionPathParams, TimesheetRangeQuery),
+    responses(
+        (status = 200, description = "Timesheet entries by date", body = [TimesheetEntry]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Timesheets",
+    operation_id = "list_timesheet_entries"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetCollectionPathParams>,
+    Query(query): Query<TimesheetRangeQuery>,
+) -> AppResult<Json<Vec<TimesheetEntry>>> {
+    let entries = state
+        .timesheet_service()
+        .list(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            query.from,
+            query.to,
+        )
+        .await?;
+
+    Ok(Json(entries))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries/{entry_id}",
+    params(TimesheetEntryPathParams),
+    responses(
+        (status = 200, description = "Get timesheet entry", body = TimesheetEntry),
+        (status = 404, description = "Timesheet entry not found")
+    ),
+    tag = "Timesheets",
+    operation_id = "get_timesheet_entry"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetEntryPathParams>,
+) -> AppResult<Json<TimesheetEntry>> {
+    let entry = state
+        .timesheet_service()
+        .get(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.entry_id,
+        )
+        .await?
+        .ok_or_else(|| entry_not_found(&params))?;
+
+    Ok(Json(entry))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries/{entry_id}",
+    params(TimesheetEntryPathParams),
+    request_body = UpdateTimesheetEntryRequest,
+    responses(
+        (status = 200, description = "Timesheet entry updated and resubmitted", body = TimesheetEntry),
+        (status = 404, description = "Timesheet entry not found"),
+        (status = 409, description = "Entry already approved")
+    ),
+    tag = "Timesheets",
+    operation_id = "update_timesheet_entry"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetEntryPathParams>,
+    Json(payload): Json<UpdateTimesheetEntryRequest>,
+) -> AppResult<Json<TimesheetEntry>> {
+    let entry = state
+        .timesheet_service()
+        .update(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.entry_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| entry_not_found(&params))?;
+
+    Ok(Json(entry))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries/{entry_id}",
+    params(TimesheetEntryPathParams),
+    responses(
+        (status = 204, description = "Timesheet entry deleted"),
+        (status = 404, description = "Timesheet entry not found"),
+        (status = 409, description = "Entry already approved")
+    ),
+    tag = "Timesheets",
+    operation_id = "delete_timesheet_entry"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetEntryPathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .timesheet_service()
+        .delete(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.entry_id,
+        )
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(entry_not_found(&params))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries/{entry_id}/approve",
+    params(TimesheetEntryPathParams),
+    request_body = ReviewTimesheetEntryRequest,
+    responses(
+        (status = 200, description = "Timesheet entry approved", body = TimesheetEntry),
+        (status = 404, description = "Timesheet entry not found"),
+        (status = 409, description = "Entry already reviewed")
+    ),
+    tag = "Timesheets",
+    operation_id = "approve_timesheet_entry"
+)]
+pub async fn approve(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetEntryPathParams>,
+    Json(payload): Json<ReviewTimesheetEntryRequest>,
+) -> AppResult<Json<TimesheetEntry>> {
+    review(state, params, TimesheetEntryStatus::Approved, payload.note).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries/{entry_id}/reject",
+    params(TimesheetEntryPathParams),
+    request_body = ReviewTimesheetEntryRequest,
+    responses(
+        (status = 200, description = "Timesheet entry rejected", body = TimesheetEntry),
+        (status = 404, description = "Timesheet entry not found"),
+        (status = 409, description = "Entry already reviewed")
+    ),
+    tag = "Timesheets",
+    operation_id = "reject_timesheet_entry"
+)]
+pub async fn reject(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetEntryPathParams>,
+    Json(payload): Json<ReviewTimesheetEntryRequest>,
+) -> AppResult<Json<TimesheetEntry>> {
+    review(state, params, TimesheetEntryStatus::Rejected, payload.note).await
+}
+
+async fn review(
+    state: AppState,
+    params: TimesheetEntryPathParams,
+    status: TimesheetEntryStatus,
+    note: Option<String>,
+) -> AppResult<Json<TimesheetEntry>> {
+    let entry = state
+        .timesheet_service()
+        .review(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.entry_id,
+            status,
+            note,
+        )
+        .await?
+        .ok_or_else(|| entry_not_found(&params))?;
+
+    Ok(Json(entry))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheets",
+    params(TimesheetCollectionPathParams),
+    request_body = SubmitTimesheetRequest,
+    responses(
+        (status = 201, description = "Timesheet submitted for manager approval; its entries are held", body = TimesheetPeriod),
+        (status = 404, description = "Employee not found"),
+        (status = 409, description = "A timesheet overlapping the period is already submitted or approved"),
+        (status = 422, description = "Invalid period or rejected entries in it")
+    ),
+    tag = "Timesheets",
+    operation_id = "submit_timesheet"
+)]
+pub async fn submit(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetCollectionPathParams>,
+    Json(payload): Json<SubmitTimesheetRequest>,
+) -> AppResult<(StatusCode, Json<TimesheetPeriod>)> {
+    let period = state
+        .timesheet_service()
+        .submit_period(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            SubmitTimesheetParams {
+                period_start: payload.period_start,
+                period_end: payload.period_end,
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(period)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheets",
+    params(TimesheetCollectionPathParams),
+    responses(
+        (status = 200, description = "Submitted timesheets by period", body = [TimesheetPeriod]),
+        (status = 404, description = "Employee not found")
+    ),
+    tag = "Timesheets",
+    operation_id = "list_timesheets"
+)]
+pub async fn list_periods(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetCollectionPathParams>,
+) -> AppResult<Json<Vec<TimesheetPeriod>>> {
+    let periods = state
+        .timesheet_service()
+        .list_periods(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+        )
+        .await?;
+
+    Ok(Json(periods))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheets/{period_id}/approve",
+    params(TimesheetPeriodPathParams),
+    request_body = ReviewTimesheetRequest,
+    responses(
+        (status = 200, description = "Timesheet approved along with its pending entries", body = TimesheetPeriod),
+        (status = 404, description = "Timesheet not found"),
+        (status = 409, description = "Timesheet already reviewed"),
+        (status = 422, description = "Approver is not the employee's supervisor or division manager")
+    ),
+    tag = "Timesheets",
+    operation_id = "approve_timesheet"
+)]
+pub async fn approve_period(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetPeriodPathParams>,
+    Json(payload): Json<ReviewTimesheetRequest>,
+) -> AppResult<Json<TimesheetPeriod>> {
+    review_period(state, params, TimesheetPeriodStatus::Approved, payload).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheets/{period_id}/reject",
+    params(TimesheetPeriodPathParams),
+    request_body = ReviewTimesheetRequest,
+    responses(
+        (status = 200, description = "Timesheet rejected; its entries can be corrected and resubmitted", body = TimesheetPeriod),
+        (status = 404, description = "Timesheet not found"),
+        (status = 409, description = "Timesheet already reviewed"),
+        (status = 422, description = "Approver is not the employee's supervisor or division manager")
+    ),
+    tag = "Timesheets",
+    operation_id = "reject_timesheet"
+)]
+pub async fn reject_period(
+    State(state): State<AppState>,
+    Path(params): Path<TimesheetPeriodPathParams>,
+    Json(payload): Json<ReviewTimesheetRequest>,
+) -> AppResult<Json<TimesheetPeriod>> {
+    review_period(state, params, TimesheetPeriodStatus::Rejected, payload).await
+}
+
+async fn review_period(
+    state: AppState,
+    params: TimesheetPeriodPathParams,
+    status: TimesheetPeriodStatus,
+    payload: ReviewTimesheetRequest,
+) -> AppResult<Json<TimesheetPeriod>> {
+    let period = state
+        .timesheet_service()
+        .review_period(
+            params.organization_id,
+            params.payroll_id,
+            params.division_id,
+            params.employee_id,
+            params.period_id,
+            payload.approver_id,
+            status,
+            payload.note,
+        )
+        .await?
+        .ok_or_else(|| period_not_found(&params))?;
+
+    Ok(Json(period))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/payrolls/{payroll_id}/timesheets/unapproved",
+    params(UnapprovedTimesheetPathParams, UnapprovedTimesheetQuery),
+    responses(
+        (status = 200, description = "Logged hours not yet covered by an approved timesheet, which block the payroll run", body = UnapprovedTimesheetReport),
+        (status = 404, description = "Payroll not found"),
+        (status = 422, description = "Invalid range")
+    ),
+    tag = "Timesheets",
+    operation_id = "list_unapproved_timesheets"
+)]
+pub async fn unapproved(
+    State(state): State<AppState>,
+    Path(params): Path<UnapprovedTimesheetPathParams>,
+    Query(query): Query<UnapprovedTimesheetQuery>,
+) -> AppResult<Json<UnapprovedTimesheetReport>> {
+    let report = state
+        .timesheet_service()
+        .unapproved_report(
+            params.organization_id,
+            params.payroll_id,
+            query.from,
+            query.to,
+        )
+        .await?;
+
+    Ok(Json(report))
+}
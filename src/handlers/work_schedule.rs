@@ -0,0 +1,246 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    domain::work_schedule::{ScheduleDay, WorkSchedule},
+    error::{AppError, AppResult},
+    server::AppState,
+    services::work_schedule::{CreateWorkScheduleParams, UpdateWorkScheduleParams},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWorkScheduleRequest {
+    pub name: String,
+    pub days: Vec<ScheduleDay>,
+    pub daily_overtime_after: Option<f64>,
+    /// Defaults to the schedule's weekly hours.
+    pub weekly_overtime_after: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWorkScheduleRequest {
+    pub name: Option<String>,
+    /// Replaces every working day when supplied.
+    pub days: Option<Vec<ScheduleDay>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<f64>)]
+    pub daily_overtime_after: Option<Option<f64>>,
+    #[serde(default, deserialize_with = "deserialize_option_option")]
+    #[schema(value_type = Option<f64>)]
+    pub weekly_overtime_after: Option<Option<f64>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkScheduleResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub days: Vec<ScheduleDay>,
+    pub weekly_hours: f64,
+    pub daily_overtime_after: Option<f64>,
+    pub weekly_overtime_after: Option<f64>,
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct OrganizationPathParams {
+    pub organization_id: Uuid,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct WorkSchedulePathParams {
+    pub organization_id: Uuid,
+    pub schedule_id: Uuid,
+}
+
+impl From<WorkSchedule> for WorkScheduleResponse {
+    fn from(value: WorkSchedule) -> Self {
+        Self {
+            weekly_hours: value.weekly_hours(),
+            id: value.id,
+            name: value.name,
+            days: value.days,
+            daily_overtime_after: value.daily_overtime_after,
+            weekly_overtime_after: value.weekly_overtime_after,
+            organization_id: value.organization_id,
+        }
+    }
+}
+
+impl CreateWorkScheduleRequest {
+    fn into_params(self) -> CreateWorkScheduleParams {
+        CreateWorkScheduleParams {
+            name: self.name,
+            days: self.days,
+            daily_overtime_after: self.daily_overtime_after,
+            weekly_overtime_after: self.weekly_overtime_after,
+        }
+    }
+}
+
+impl UpdateWorkScheduleRequest {
+    fn into_params(self) -> UpdateWorkScheduleParams {
+        UpdateWorkScheduleParams {
+            name: self.name,
+            days: self.days,
+            daily_overtime_after: self.daily_overtime_after,
+            weekly_overtime_after: self.weekly_overtime_after,
+        }
+    }
+}
+
+fn deserialize_option_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Some(Option::deserialize(deserializer)?))
+}
+
+fn schedule_not_found(params: &WorkSchedulePathParams) -> AppError {
+    AppError::not_found(format!(
+        "work schedule `{}` not found for organization `{}`",
+        params.schedule_id, params.organization_id
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/organizations/{organization_id}/work-schedules",
+    params(OrganizationPathParams),
+    request_body = CreateWorkScheduleRequest,
+    responses(
+        (status = 201, description = "Work schedule created", body = WorkScheduleResponse),
+        (status = 404, description = "Organization not found"),
+        (status = 409, description = "Work schedule name already in use"),
+        (status = 422, description = "Invalid days or thresholds")
+    ),
+    tag = "Work Schedules",
+    operation_id = "create_work_schedule"
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+    Json(payload): Json<CreateWorkScheduleRequest>,
+) -> AppResult<(StatusCode, Json<WorkScheduleResponse>)> {
+    let schedule = state
+        .work_schedule_service()
+        .create(params.organization_id, payload.into_params())
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(schedule.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/work-schedules",
+    params(OrganizationPathParams),
+    responses(
+        (status = 200, description = "List work schedules", body = [WorkScheduleResponse])
+    ),
+    tag = "Work Schedules",
+    operation_id = "list_work_schedules"
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(params): Path<OrganizationPathParams>,
+) -> AppResult<Json<Vec<WorkScheduleResponse>>> {
+    let schedules = state
+        .work_schedule_service()
+        .list(params.organization_id)
+        .await?;
+    let response = schedules
+        .into_iter()
+        .map(WorkScheduleResponse::from)
+        .collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/organizations/{organization_id}/work-schedules/{schedule_id}",
+    params(WorkSchedulePathParams),
+    responses(
+        (status = 200, description = "Get work schedule", body = WorkScheduleResponse),
+        (status = 404, description = "Work schedule not found")
+    ),
+    tag = "Work Schedules",
+    operation_id = "get_work_schedule"
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(params): Path<WorkSchedulePathParams>,
+) -> AppResult<Json<WorkScheduleResponse>> {
+    let schedule = state
+        .work_schedule_service()
+        .get(params.organization_id, params.schedule_id)
+        .await?
+        .ok_or_else(|| schedule_not_found(&params))?;
+
+    Ok(Json(schedule.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/organizations/{organization_id}/work-schedules/{schedule_id}",
+    params(WorkSchedulePathParams),
+    request_body = UpdateWorkScheduleRequest,
+    responses(
+        (status = 200, description = "Work schedule updated", body = WorkScheduleResponse),
+        (status = 404, description = "Work schedule not found"),
+        (status = 409, description = "Work schedule name already in use")
+    ),
+    tag = "Work Schedules",
+    operation_id = "update_work_schedule"
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(params): Path<WorkSchedulePathParams>,
+    Json(payload): Json<UpdateWorkScheduleRequest>,
+) -> AppResult<Json<WorkScheduleResponse>> {
+    let schedule = state
+        .work_schedule_service()
+        .update(
+            params.organization_id,
+            params.schedule_id,
+            payload.into_params(),
+        )
+        .await?
+        .ok_or_else(|| schedule_not_found(&params))?;
+
+    Ok(Json(schedule.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/organizations/{organization_id}/work-schedules/{schedule_id}",
+    params(WorkSchedulePathParams),
+    responses(
+        (status = 204, description = "Work schedule deleted"),
+        (status = 404, description = "Work schedule not found")
+    ),
+    tag = "Work Schedules",
+    operation_id = "delete_work_schedule"
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    Path(params): Path<WorkSchedulePathParams>,
+) -> AppResult<StatusCode> {
+    let removed = state
+        .work_schedule_service()
+        .delete(params.organization_id, params.schedule_id)
+        .await?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(schedule_not_found(&params))
+    }
+}
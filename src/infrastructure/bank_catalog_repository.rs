@@ -0,0 +1,138 @@
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::bank::CatalogBank,
+    error::{AppError, AppResult},
+    services::bank::BankCatalogRepository,
+};
+
+const BANK_CATALOG_TABLE: &str = "bank_catalog";
+
+#[derive(Clone)]
+pub struct SurrealBankCatalogRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealBankCatalogRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> BankCatalogRepository for SurrealBankCatalogRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, entry: CatalogBank) -> AppResult<CatalogBank> {
+        let record: Option<CatalogBankRecord> = self
+            .client
+            .create((BANK_CATALOG_TABLE, entry.id.to_string()))
+            .content(build_payload(&entry))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created catalog bank"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<CatalogBank>> {
+        let record: Option<CatalogBankRecord> = self
+            .reader
+            .select((BANK_CATALOG_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_all(&self) -> AppResult<Vec<CatalogBank>> {
+        let records: Vec<CatalogBankRecord> = self.reader.select(BANK_CATALOG_TABLE).await?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, entry: CatalogBank) -> AppResult<Option<CatalogBank>> {
+        let record: Option<CatalogBankRecord> = self
+            .client
+            .update((BANK_CATALOG_TABLE, entry.id.to_string()))
+            .content(build_payload(&entry))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<CatalogBankRecord> = self
+            .client
+            .delete((BANK_CATALOG_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CatalogBankRecord {
+    id: Thing,
+    name: String,
+    #[serde(default)]
+    swift_code: Option<String>,
+    #[serde(default)]
+    routing_number: Option<String>,
+    #[serde(default)]
+    country: Option<String>,
+}
+
+fn record_to_domain(record: CatalogBankRecord) -> AppResult<CatalogBank> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored catalog bank id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored catalog bank identifier is not a supported format",
+            ));
+        }
+    };
+
+    Ok(CatalogBank {
+        id,
+        name: record.name,
+        swift_code: record.swift_code,
+        routing_number: record.routing_number,
+        country: record.country,
+    })
+}
+
+fn build_payload(entry: &CatalogBank) -> JsonValue {
+    json!({
+        "name": entry.name,
+        "swift_code": entry.swift_code,
+        "routing_number": entry.routing_number,
+        "country": entry.country,
+    })
+}
+
+pub type SurrealAnyBankCatalogRepository = SurrealBankCatalogRepository<Any>;
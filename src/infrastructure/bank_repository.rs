@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_json::{Map, Value as JsonValue, json};
 use surrealdb::{
@@ -8,19 +9,69 @@ use surrealdb::{
 use uuid::Uuid;
 
 use crate::{
-    domain::bank::Bank,
+    domain::bank::{AccountRule, Bank, PaymentFormat},
     error::{AppError, AppResult},
-    services::bank::BankRepository,
+    infrastructure::employee_repository::payment_instructions_content,
+    services::bank::{BankReassignment, BankRepository},
 };
 
 const BANK_TABLE: &str = "bank";
 
+const BY_ORGANIZATION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE organization_id = $organization_id";
+
+const BY_CATALOG_ENTRY_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE catalog_id = $catalog_id";
+
+/// Deletes a bank unless an employee is still paid through it, checked in the same
+/// transaction. Reassigned employees get their new payment instructions in it too; the paid
+/// employees are compared again here, so one who starts or stops being paid through the bank
+/// in between is not left pointing at a deleted record. A concurrent create conflicts with the
+/// delete on the `referenced_at` write it makes to the target. Records written before split
+/// payments keep a single `bank_id`, which a move clears.
+const DELETE_QUERY: &str = "
+BEGIN TRANSACTION;
+{
+    IF array::len((SELECT id FROM type::thing('bank', $bank_id))) = 0 {
+        RETURN { found: false };
+    };
+    LET $paid = (
+        SELECT VALUE record::id(id) FROM employee
+            WHERE payment_instructions.bank_id CONTAINS $bank_id OR bank_id = $bank_id
+    );
+    LET $moving = $moves.employee_id;
+    IF array::len(array::complement($paid, $moving)) > 0 {
+        RETURN { found: true, paid: array::len($paid) };
+    };
+    IF array::len(array::complement($moving, $paid)) > 0 {
+        RETURN { found: true, conflict: 'moved' };
+    };
+    IF $target_id AND array::len((SELECT id FROM type::thing('bank', $target_id))) = 0 {
+        RETURN { found: true, conflict: 'target' };
+    };
+
+    IF $target_id {
+        UPDATE type::thing('bank', $target_id) SET referenced_at = time::now();
+    };
+    FOR $move IN $moves {
+        UPDATE type::thing('employee', $move.employee_id) SET
+            payment_instructions = $move.payment_instructions,
+            bank_id = NONE,
+            bank_account = NONE;
+    };
+    DELETE type::thing('bank', $bank_id);
+    RETURN { found: true };
+};
+COMMIT TRANSACTION;
+";
+
 #[derive(Clone)]
 pub struct SurrealBankRepository<C>
 where
     C: Connection,
 {
     client: Surreal<C>,
+    reader: Surreal<C>,
 }
 
 impl<C> SurrealBankRepository<C>
@@ -28,7 +79,15 @@ where
     C: Connection,
 {
     pub fn new(client: Surreal<C>) -> Self {
-        Self { client }
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
     }
 }
 
@@ -37,13 +96,32 @@ impl<C> BankRepository for SurrealBankRepository<C>
 where
     C: Connection + Clone + Send + Sync + 'static,
 {
-    async fn insert(&self, id: Uuid, name: String, organization_id: Uuid) -> AppResult<Bank> {
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        organization_id: Uuid,
+        swift_code: Option<String>,
+        routing_number: Option<String>,
+        country: Option<String>,
+        address: Option<String>,
+        account_rule: Option<AccountRule>,
+        payment_format: Option<PaymentFormat>,
+        catalog_id: Option<Uuid>,
+    ) -> AppResult<Bank> {
         let record: Option<BankRecord> = self
             .client
             .create((BANK_TABLE, id.to_string()))
             .content(json!({
                 "name": name,
                 "organization_id": organization_id,
+                "swift_code": swift_code,
+                "routing_number": routing_number,
+                "country": country,
+                "address": address,
+                "account_rule": account_rule,
+                "payment_format": payment_format,
+                "catalog_id": catalog_id,
             }))
             .await?;
 
@@ -54,21 +132,57 @@ where
     }
 
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Bank>> {
-        let record: Option<BankRecord> = self.client.select((BANK_TABLE, id.to_string())).await?;
+        let record: Option<BankRecord> = self.reader.select((BANK_TABLE, id.to_string())).await?;
         record.map(record_to_domain).transpose()
     }
 
     async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Bank>> {
-        let records: Vec<BankRecord> = self.client.select(BANK_TABLE).await?;
-        records
-            .into_iter()
-            .filter(|record| record.organization_id == organization_id.to_string())
-            .map(record_to_domain)
-            .collect()
+        let mut response = self
+            .reader
+            .query(BY_ORGANIZATION_QUERY)
+            .bind(("table", BANK_TABLE))
+            .bind(("organization_id", organization_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<BankRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
     }
 
-    async fn update(&self, id: Uuid, name: Option<String>) -> AppResult<Option<Bank>> {
-        let payload = build_update_payload(name)?;
+    async fn fetch_by_catalog_entry(&self, catalog_id: Uuid) -> AppResult<Vec<Bank>> {
+        let mut response = self
+            .reader
+            .query(BY_CATALOG_ENTRY_QUERY)
+            .bind(("table", BANK_TABLE))
+            .bind(("catalog_id", catalog_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<BankRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        swift_code: Option<Option<String>>,
+        routing_number: Option<Option<String>>,
+        country: Option<Option<String>>,
+        address: Option<Option<String>>,
+        account_rule: Option<Option<AccountRule>>,
+        payment_format: Option<Option<PaymentFormat>>,
+        catalog_id: Option<Option<Uuid>>,
+    ) -> AppResult<Option<Bank>> {
+        let payload = build_update_payload(
+            name,
+            swift_code,
+            routing_number,
+            country,
+            address,
+            account_rule,
+            payment_format,
+            catalog_id,
+        )?;
         let record: Option<BankRecord> = self
             .client
             .update((BANK_TABLE, id.to_string()))
@@ -78,17 +192,95 @@ where
         record.map(record_to_domain).transpose()
     }
 
-    async fn delete(&self, id: Uuid) -> AppResult<bool> {
-        let record: Option<BankRecord> = self.client.delete((BANK_TABLE, id.to_string())).await?;
-        Ok(record.is_some())
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Bank>> {
+        let record: Option<BankRecord> = self
+            .client
+            .update((BANK_TABLE, id.to_string()))
+            .merge(json!({
+                "archived_at": archived_at.map(|timestamp| timestamp.to_rfc3339()),
+            }))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid, reassignment: Option<BankReassignment>) -> AppResult<bool> {
+        let target_id = reassignment
+            .as_ref()
+            .map(|reassignment| reassignment.to_bank_id);
+        let moves: Vec<JsonValue> = reassignment
+            .map(|reassignment| reassignment.payment_instructions)
+            .unwrap_or_default()
+            .iter()
+            .map(|(employee_id, instructions)| {
+                json!({
+                    "employee_id": employee_id.to_string(),
+                    "payment_instructions": payment_instructions_content(instructions),
+                })
+            })
+            .collect();
+
+        let mut response = self
+            .client
+            .query(DELETE_QUERY)
+            .bind(("bank_id", id.to_string()))
+            .bind(("target_id", target_id.map(|id| id.to_string())))
+            .bind(("moves", moves))
+            .await?
+            .check()?;
+        let outcome: Option<DeleteOutcome> = response.take(0)?;
+        let outcome =
+            outcome.ok_or_else(|| AppError::internal("database did not report the bank delete"))?;
+
+        match (outcome.paid, outcome.conflict.as_deref(), target_id) {
+            (0, None, _) => Ok(outcome.found),
+            (paid, None, None) => Err(AppError::conflict(format!(
+                "bank `{id}` still pays {paid} employee(s)"
+            ))),
+            (_, Some("target"), Some(target_id)) => Err(AppError::conflict(format!(
+                "bank `{target_id}` was deleted while employees were being moved to it"
+            ))),
+            _ => Err(AppError::conflict(format!(
+                "the employees paid through bank `{id}` changed while they were being reassigned"
+            ))),
+        }
     }
 }
 
+#[derive(Deserialize)]
+struct DeleteOutcome {
+    found: bool,
+    #[serde(default)]
+    paid: usize,
+    #[serde(default)]
+    conflict: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct BankRecord {
     id: Thing,
     name: String,
     organization_id: String,
+    #[serde(default)]
+    swift_code: Option<String>,
+    #[serde(default)]
+    routing_number: Option<String>,
+    #[serde(default)]
+    country: Option<String>,
+    #[serde(default)]
+    address: Option<String>,
+    #[serde(default)]
+    account_rule: Option<AccountRule>,
+    #[serde(default)]
+    payment_format: Option<PaymentFormat>,
+    #[serde(default)]
+    catalog_id: Option<String>,
+    #[serde(default)]
+    archived_at: Option<String>,
 }
 
 fn record_to_domain(record: BankRecord) -> AppResult<Bank> {
@@ -105,17 +297,80 @@ fn record_to_domain(record: BankRecord) -> AppResult<Bank> {
 
     let organization_id = Uuid::parse_str(&record.organization_id)
         .map_err(|_| AppError::internal("stored bank organization id is not a UUID"))?;
+    let catalog_id = record
+        .catalog_id
+        .as_deref()
+        .map(|value| {
+            Uuid::parse_str(value)
+                .map_err(|_| AppError::internal("stored bank catalog id is not a UUID"))
+        })
+        .transpose()?;
+    let archived_at = record
+        .archived_at
+        .as_deref()
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|_| AppError::internal("stored bank archive time is not valid"))
+        })
+        .transpose()?;
 
-    Ok(Bank::new(id, record.name, organization_id))
+    Ok(Bank {
+        swift_code: record.swift_code,
+        routing_number: record.routing_number,
+        country: record.country,
+        address: record.address,
+        account_rule: record.account_rule,
+        payment_format: record.payment_format,
+        catalog_id,
+        archived_at,
+        ..Bank::new(id, record.name, organization_id)
+    })
 }
 
-fn build_update_payload(name: Option<String>) -> AppResult<JsonValue> {
+#[allow(clippy::too_many_arguments)]
+fn build_update_payload(
+    name: Option<String>,
+    swift_code: Option<Option<String>>,
+    routing_number: Option<Option<String>>,
+    country: Option<Option<String>>,
+    address: Option<Option<String>>,
+    account_rule: Option<Option<AccountRule>>,
+    payment_format: Option<Option<PaymentFormat>>,
+    catalog_id: Option<Option<Uuid>>,
+) -> AppResult<JsonValue> {
     let mut object = Map::new();
 
     if let Some(name) = name {
         object.insert("name".to_string(), JsonValue::String(name));
     }
 
+    for (field, value) in [
+        ("swift_code", swift_code),
+        ("routing_number", routing_number),
+        ("country", country),
+        ("address", address),
+    ] {
+        if let Some(value) = value {
+            object.insert(
+                field.to_string(),
+                value.map(JsonValue::String).unwrap_or(JsonValue::Null),
+            );
+        }
+    }
+
+    if let Some(account_rule) = account_rule {
+        object.insert("account_rule".to_string(), json!(account_rule));
+    }
+
+    if let Some(payment_format) = payment_format {
+        object.insert("payment_format".to_string(), json!(payment_format));
+    }
+
+    if let Some(catalog_id) = catalog_id {
+        object.insert("catalog_id".to_string(), json!(catalog_id));
+    }
+
     if object.is_empty() {
         return Err(AppError::internal("no fields supplied for bank update"));
     }
@@ -0,0 +1,75 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde::Deserialize;
+use serde_json::json;
+use surrealdb::{Connection, Surreal, engine::any::Any};
+
+use crate::{
+    domain::blob::Blob,
+    error::{AppError, AppResult},
+    services::blob::BlobStore,
+};
+
+const BLOB_TABLE: &str = "blob";
+
+/// Stores blobs as base64 payloads in a SurrealDB table.
+#[derive(Clone)]
+pub struct SurrealBlobStore<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+}
+
+impl<C> SurrealBlobStore<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> BlobStore for SurrealBlobStore<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn put(&self, key: &str, blob: Blob) -> AppResult<()> {
+        let _: Option<BlobRecord> = self
+            .client
+            .upsert((BLOB_TABLE, key.to_string()))
+            .content(json!({
+                "content_type": blob.content_type,
+                "data": STANDARD.encode(&blob.data),
+            }))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> AppResult<Option<Blob>> {
+        let record: Option<BlobRecord> = self.client.select((BLOB_TABLE, key.to_string())).await?;
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<bool> {
+        let record: Option<BlobRecord> = self.client.delete((BLOB_TABLE, key.to_string())).await?;
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BlobRecord {
+    content_type: String,
+    data: String,
+}
+
+fn record_to_domain(record: BlobRecord) -> AppResult<Blob> {
+    let data = STANDARD
+        .decode(record.data)
+        .map_err(|_| AppError::internal("stored blob is not valid base64"))?;
+
+    Ok(Blob::new(record.content_type, data))
+}
+
+pub type SurrealAnyBlobStore = SurrealBlobStore<Any>;
@@ -0,0 +1,176 @@
+use serde::Deserialize;
+use serde_json::{Map, Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::custom_field::{CustomFieldDefinition, CustomFieldType},
+    error::{AppError, AppResult},
+    services::custom_field::{CustomFieldRepository, UpdateCustomFieldParams},
+};
+
+const CUSTOM_FIELD_TABLE: &str = "custom_field";
+
+const BY_ORGANIZATION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE organization_id = $organization_id";
+
+#[derive(Clone)]
+pub struct SurrealCustomFieldRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealCustomFieldRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> CustomFieldRepository for SurrealCustomFieldRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, definition: CustomFieldDefinition) -> AppResult<CustomFieldDefinition> {
+        let record: Option<CustomFieldRecord> = self
+            .client
+            .create((CUSTOM_FIELD_TABLE, definition.id.to_string()))
+            .content(json!({
+                "name": definition.name,
+                "field_type": definition.field_type,
+                "required": definition.required,
+                "organization_id": definition.organization_id,
+            }))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created custom field"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<CustomFieldDefinition>> {
+        let record: Option<CustomFieldRecord> = self
+            .reader
+            .select((CUSTOM_FIELD_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_organization(
+        &self,
+        organization_id: Uuid,
+    ) -> AppResult<Vec<CustomFieldDefinition>> {
+        let mut response = self
+            .reader
+            .query(BY_ORGANIZATION_QUERY)
+            .bind(("table", CUSTOM_FIELD_TABLE))
+            .bind(("organization_id", organization_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<CustomFieldRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        updates: UpdateCustomFieldParams,
+    ) -> AppResult<Option<CustomFieldDefinition>> {
+        let payload = build_update_payload(updates)?;
+        let record: Option<CustomFieldRecord> = self
+            .client
+            .update((CUSTOM_FIELD_TABLE, id.to_string()))
+            .merge(payload)
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<CustomFieldRecord> = self
+            .client
+            .delete((CUSTOM_FIELD_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CustomFieldRecord {
+    id: Thing,
+    name: String,
+    field_type: CustomFieldType,
+    required: bool,
+    organization_id: String,
+}
+
+fn record_to_domain(record: CustomFieldRecord) -> AppResult<CustomFieldDefinition> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored custom field id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored custom field identifier is not a supported format",
+            ));
+        }
+    };
+
+    let organization_id = Uuid::parse_str(&record.organization_id)
+        .map_err(|_| AppError::internal("stored custom field organization id is not a UUID"))?;
+
+    Ok(CustomFieldDefinition::new(
+        id,
+        record.name,
+        record.field_type,
+        record.required,
+        organization_id,
+    ))
+}
+
+fn build_update_payload(updates: UpdateCustomFieldParams) -> AppResult<JsonValue> {
+    let mut object = Map::new();
+
+    if let Some(name) = updates.name {
+        object.insert("name".to_string(), JsonValue::String(name));
+    }
+
+    if let Some(field_type) = updates.field_type {
+        object.insert("field_type".to_string(), json!(field_type));
+    }
+
+    if let Some(required) = updates.required {
+        object.insert("required".to_string(), JsonValue::Bool(required));
+    }
+
+    if object.is_empty() {
+        return Err(AppError::internal(
+            "no fields supplied for custom field update",
+        ));
+    }
+
+    Ok(JsonValue::Object(object))
+}
+
+pub type SurrealAnyCustomFieldRepository = SurrealCustomFieldRepository<Any>;
@@ -0,0 +1,52 @@
+use surrealdb::{Connection, Surreal, engine::any::Any};
+
+use crate::{
+    error::AppResult,
+    services::readiness::{DATABASE_DEPENDENCY, DATABASE_REPLICA_DEPENDENCY, DependencyProbe},
+};
+
+#[derive(Clone)]
+pub struct SurrealDatabaseProbe<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    name: &'static str,
+}
+
+impl<C> SurrealDatabaseProbe<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            client,
+            name: DATABASE_DEPENDENCY,
+        }
+    }
+
+    pub fn replica(client: Surreal<C>) -> Self {
+        Self {
+            client,
+            name: DATABASE_REPLICA_DEPENDENCY,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> DependencyProbe for SurrealDatabaseProbe<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    async fn ping(&self) -> AppResult<()> {
+        self.client.health().await?;
+
+        Ok(())
+    }
+}
+
+pub type SurrealAnyDatabaseProbe = SurrealDatabaseProbe<Any>;
@@ -0,0 +1,192 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_json::{Map, Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::dependent::Dependent,
+    error::{AppError, AppResult},
+    services::dependent::DependentRepository,
+};
+
+const DEPENDENT_TABLE: &str = "dependent";
+
+const BY_EMPLOYEE_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE employee_id = $employee_id";
+
+#[derive(Clone)]
+pub struct SurrealDependentRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealDependentRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> DependentRepository for SurrealDependentRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        relationship: String,
+        date_of_birth: NaiveDate,
+        employee_id: Uuid,
+    ) -> AppResult<Dependent> {
+        let record: Option<DependentRecord> = self
+            .client
+            .create((DEPENDENT_TABLE, id.to_string()))
+            .content(json!({
+                "name": name,
+                "relationship": relationship,
+                "date_of_birth": date_of_birth.to_string(),
+                "employee_id": employee_id,
+            }))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created dependent"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Dependent>> {
+        let record: Option<DependentRecord> = self
+            .reader
+            .select((DEPENDENT_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<Dependent>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", DEPENDENT_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<DependentRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        relationship: Option<String>,
+        date_of_birth: Option<NaiveDate>,
+    ) -> AppResult<Option<Dependent>> {
+        let payload = build_update_payload(name, relationship, date_of_birth)?;
+        let record: Option<DependentRecord> = self
+            .client
+            .update((DEPENDENT_TABLE, id.to_string()))
+            .merge(payload)
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<DependentRecord> = self
+            .client
+            .delete((DEPENDENT_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DependentRecord {
+    id: Thing,
+    name: String,
+    relationship: String,
+    date_of_birth: String,
+    employee_id: String,
+}
+
+fn record_to_domain(record: DependentRecord) -> AppResult<Dependent> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored dependent id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored dependent identifier is not a supported format",
+            ));
+        }
+    };
+
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored dependent employee id is not a UUID"))?;
+    let date_of_birth = NaiveDate::parse_from_str(&record.date_of_birth, "%Y-%m-%d")
+        .map_err(|_| AppError::internal("stored dependent date of birth is not a valid date"))?;
+
+    Ok(Dependent::new(
+        id,
+        record.name,
+        record.relationship,
+        date_of_birth,
+        employee_id,
+    ))
+}
+
+fn build_update_payload(
+    name: Option<String>,
+    relationship: Option<String>,
+    date_of_birth: Option<NaiveDate>,
+) -> AppResult<JsonValue> {
+    let mut object = Map::new();
+
+    if let Some(name) = name {
+        object.insert("name".to_string(), JsonValue::String(name));
+    }
+
+    if let Some(relationship) = relationship {
+        object.insert("relationship".to_string(), JsonValue::String(relationship));
+    }
+
+    if let Some(date_of_birth) = date_of_birth {
+        object.insert(
+            "date_of_birth".to_string(),
+            JsonValue::String(date_of_birth.to_string()),
+        );
+    }
+
+    if object.is_empty() {
+        return Err(AppError::internal(
+            "no fields supplied for dependent update",
+        ));
+    }
+
+    Ok(JsonValue::Object(object))
+}
+
+pub type SurrealAnyDependentRepository = SurrealDependentRepository<Any>;
@@ -10,17 +10,113 @@ use uuid::Uuid;
 use crate::{
     domain::division::Division,
     error::{AppError, AppResult},
-    services::division::DivisionRepository,
+    infrastructure::employee_repository::removing_employee_records,
+    services::division::{
+        DivisionDeletePolicy, DivisionRelocation, DivisionRemoval, DivisionRepository,
+    },
 };
 
 const DIVISION_TABLE: &str = "division";
 
+const BY_PAYROLL_QUERY: &str = "SELECT * FROM type::table($table) WHERE payroll_id = $payroll_id";
+
+/// Applies a [`DivisionRemoval`] in one transaction. What the policy depends on is checked
+/// again inside it, and a failed check returns before anything is written. A cascade also
+/// removes what is stored under the deleted employees; see [`removing_employee_records`].
+const REMOVE_QUERY: &str = "
+BEGIN TRANSACTION;
+{
+    IF array::len((SELECT id FROM type::thing('division', $division_id))) = 0 {
+        RETURN { found: false };
+    };
+    LET $scope = array::append($descendants, $division_id);
+    IF $policy = 'refuse' AND (
+        array::len((SELECT id FROM employee WHERE division_id = $division_id)) > 0
+        OR array::len((SELECT id FROM position WHERE division_id = $division_id)) > 0
+        OR array::len((SELECT id FROM division WHERE parent_division_id = $division_id)) > 0
+    ) {
+        RETURN { found: true, conflict: 'dependents' };
+    };
+    IF $policy = 'reassign'
+        AND array::len((SELECT id FROM type::thing('division', $target_id))) = 0 {
+        RETURN { found: true, conflict: 'target' };
+    };
+    IF $policy = 'cascade' AND array::len((
+        SELECT id FROM division
+            WHERE parent_division_id IN $scope AND record::id(id) NOTINSIDE $scope
+    )) > 0 {
+        RETURN { found: true, conflict: 'descendants' };
+    };
+
+    LET $removed = IF $policy = 'cascade' {
+        (SELECT VALUE record::id(id) FROM employee WHERE division_id IN $scope)
+    } ELSE {
+        []
+    };
+    IF $policy = 'reassign' {
+        UPDATE employee SET division_id = $target_id WHERE division_id = $division_id;
+        UPDATE position SET division_id = $target_id WHERE division_id = $division_id;
+        UPDATE division SET parent_division_id = $target_id
+            WHERE parent_division_id = $division_id;
+    };
+    IF $policy = 'cascade' {
+        {remove_employee_records}
+        DELETE employee WHERE division_id IN $scope;
+        DELETE position WHERE division_id IN $scope;
+        DELETE division WHERE record::id(id) IN $scope;
+    };
+    DELETE type::thing('division', $division_id);
+    RETURN { found: true, removed: $removed };
+};
+COMMIT TRANSACTION;
+";
+
+/// Applies a [`DivisionRelocation`] in one transaction. The subtree is checked again inside
+/// it, and a failed check returns before anything is written.
+const RELOCATE_QUERY: &str = "
+BEGIN TRANSACTION;
+{
+    IF array::len((SELECT id FROM type::thing('division', $division_id))) = 0 {
+        RETURN { found: false };
+    };
+    LET $scope = array::append($descendants, $division_id);
+    IF array::len((
+        SELECT id FROM division
+            WHERE parent_division_id IN $scope AND record::id(id) NOTINSIDE $scope
+    )) > 0 {
+        RETURN { found: true, conflict: 'descendants' };
+    };
+    IF $parent_id AND array::len((SELECT id FROM type::thing('division', $parent_id))) = 0 {
+        RETURN { found: true, conflict: 'parent' };
+    };
+    LET $current_payroll_id = (SELECT VALUE payroll_id FROM type::thing('division', $division_id))[0];
+    IF $current_payroll_id != $payroll_id AND (
+        array::len((SELECT id FROM employee WHERE division_id IN $scope)) > 0
+        OR array::len((SELECT id FROM position WHERE division_id IN $scope)) > 0
+        OR array::len((
+            SELECT id FROM division WHERE record::id(id) IN $scope AND manager_employee_id
+        )) > 0
+    ) {
+        RETURN { found: true, conflict: 'dependents' };
+    };
+
+    UPDATE type::thing('division', $division_id) SET parent_division_id = $parent_id;
+    UPDATE division SET payroll_id = $payroll_id WHERE record::id(id) IN $scope;
+    RETURN {
+        found: true,
+        moved: (SELECT * FROM division WHERE record::id(id) IN $scope),
+    };
+};
+COMMIT TRANSACTION;
+";
+
 #[derive(Clone)]
 pub struct SurrealDivisionRepository<C>
 where
     C: Connection,
 {
     client: Surreal<C>,
+    reader: Surreal<C>,
 }
 
 impl<C> SurrealDivisionRepository<C>
@@ -28,7 +124,15 @@ where
     C: Connection,
 {
     pub fn new(client: Surreal<C>) -> Self {
-        Self { client }
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
     }
 }
 
@@ -37,6 +141,7 @@ impl<C> DivisionRepository for SurrealDivisionRepository<C>
 where
     C: Connection + Clone + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     async fn insert(
         &self,
         id: Uuid,
@@ -45,6 +150,8 @@ where
         budget_code: String,
         payroll_id: Uuid,
         parent_division_id: Option<Uuid>,
+        headcount_budget: Option<u32>,
+        region: Option<String>,
     ) -> AppResult<Division> {
         let record: Option<DivisionRecord> = self
             .client
@@ -55,6 +162,8 @@ where
                 "budget_code": budget_code,
                 "payroll_id": payroll_id,
                 "parent_division_id": parent_division_id,
+                "headcount_budget": headcount_budget,
+                "region": region,
             }))
             .await?;
 
@@ -66,20 +175,24 @@ where
 
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Division>> {
         let record: Option<DivisionRecord> =
-            self.client.select((DIVISION_TABLE, id.to_string())).await?;
+            self.reader.select((DIVISION_TABLE, id.to_string())).await?;
 
         record.map(record_to_domain).transpose()
     }
 
     async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<Division>> {
-        let records: Vec<DivisionRecord> = self.client.select(DIVISION_TABLE).await?;
-        records
-            .into_iter()
-            .filter(|record| record.payroll_id == payroll_id.to_string())
-            .map(record_to_domain)
-            .collect()
+        let mut response = self
+            .reader
+            .query(BY_PAYROLL_QUERY)
+            .bind(("table", DIVISION_TABLE))
+            .bind(("payroll_id", payroll_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<DivisionRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn update(
         &self,
         id: Uuid,
@@ -87,8 +200,17 @@ where
         description: Option<String>,
         budget_code: Option<String>,
         parent_division_id: Option<Option<Uuid>>,
+        headcount_budget: Option<Option<u32>>,
+        region: Option<Option<String>>,
     ) -> AppResult<Option<Division>> {
-        let payload = build_update_payload(name, description, budget_code, parent_division_id)?;
+        let payload = build_update_payload(
+            name,
+            description,
+            budget_code,
+            parent_division_id,
+            headcount_budget,
+            region,
+        )?;
 
         let record: Option<DivisionRecord> = self
             .client
@@ -99,12 +221,158 @@ where
         record.map(record_to_domain).transpose()
     }
 
-    async fn delete(&self, id: Uuid) -> AppResult<bool> {
-        let record: Option<DivisionRecord> =
-            self.client.delete((DIVISION_TABLE, id.to_string())).await?;
+    async fn set_manager(
+        &self,
+        id: Uuid,
+        manager_employee_id: Option<Uuid>,
+    ) -> AppResult<Option<Division>> {
+        let record: Option<DivisionRecord> = self
+            .client
+            .update((DIVISION_TABLE, id.to_string()))
+            .merge(json!({ "manager_employee_id": manager_employee_id }))
+            .await?;
 
-        Ok(record.is_some())
+        record.map(record_to_domain).transpose()
     }
+
+    async fn relocate(&self, relocation: DivisionRelocation) -> AppResult<Option<Vec<Division>>> {
+        let division_id = relocation.division_id;
+        let descendants: Vec<String> = relocation
+            .descendants
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+
+        let mut response = self
+            .client
+            .query(RELOCATE_QUERY)
+            .bind(("division_id", division_id.to_string()))
+            .bind(("descendants", descendants))
+            .bind(("payroll_id", relocation.payroll_id.to_string()))
+            .bind((
+                "parent_id",
+                relocation.parent_division_id.map(|id| id.to_string()),
+            ))
+            .await?
+            .check()?;
+        let outcome: Option<RelocateOutcome> = response.take(0)?;
+        let outcome = outcome
+            .ok_or_else(|| AppError::internal("database did not report the division move"))?;
+
+        if !outcome.found {
+            return Ok(None);
+        }
+        match (outcome.conflict.as_deref(), relocation.parent_division_id) {
+            (None, _) => {}
+            (Some("parent"), Some(parent_id)) => {
+                return Err(AppError::conflict(format!(
+                    "parent division `{parent_id}` was deleted while `{division_id}` was being \
+                     moved under it"
+                )));
+            }
+            (Some("descendants"), _) => {
+                return Err(AppError::conflict(format!(
+                    "a division was added under `{division_id}` while it was being moved"
+                )));
+            }
+            (Some(_), _) => {
+                return Err(AppError::conflict(format!(
+                    "division `{division_id}` gained employees, positions or a manager while it \
+                     was being moved to another payroll"
+                )));
+            }
+        }
+
+        let mut moved = outcome
+            .moved
+            .into_iter()
+            .map(record_to_domain)
+            .collect::<AppResult<Vec<Division>>>()?;
+        let order: Vec<Uuid> = [division_id]
+            .into_iter()
+            .chain(relocation.descendants)
+            .collect();
+        moved.sort_by_key(|division| order.iter().position(|id| *id == division.id));
+        Ok(Some(moved))
+    }
+
+    async fn remove(&self, removal: DivisionRemoval) -> AppResult<Option<Vec<Uuid>>> {
+        let division_id = removal.division_id;
+        let (policy, target_id) = match removal.policy {
+            DivisionDeletePolicy::Refuse => ("refuse", None),
+            DivisionDeletePolicy::ReassignTo(target_id) => ("reassign", Some(target_id)),
+            DivisionDeletePolicy::Cascade => ("cascade", None),
+        };
+        let descendants: Vec<String> = removal
+            .descendants
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+
+        let mut response = self
+            .client
+            .query(removing_employee_records(REMOVE_QUERY))
+            .bind(("division_id", division_id.to_string()))
+            .bind(("descendants", descendants))
+            .bind(("policy", policy))
+            .bind(("target_id", target_id.map(|id| id.to_string())))
+            .await?
+            .check()?;
+        let outcome: Option<RemoveOutcome> = response.take(0)?;
+        let outcome = outcome
+            .ok_or_else(|| AppError::internal("database did not report the division removal"))?;
+
+        if !outcome.found {
+            return Ok(None);
+        }
+        match (outcome.conflict.as_deref(), target_id) {
+            (None, _) => {}
+            (Some("target"), Some(target_id)) => {
+                return Err(AppError::conflict(format!(
+                    "division `{target_id}` was deleted while dependents were being moved to it"
+                )));
+            }
+            (Some("descendants"), _) => {
+                return Err(AppError::conflict(format!(
+                    "a division was added under `{division_id}` while it was being deleted"
+                )));
+            }
+            (Some(_), _) => {
+                return Err(AppError::conflict(format!(
+                    "division `{division_id}` gained employees, positions or child divisions \
+                     while it was being deleted"
+                )));
+            }
+        }
+
+        outcome
+            .removed
+            .iter()
+            .map(|id| {
+                Uuid::parse_str(id)
+                    .map_err(|_| AppError::internal("stored employee id is not a UUID"))
+            })
+            .collect::<AppResult<_>>()
+            .map(Some)
+    }
+}
+
+#[derive(Deserialize)]
+struct RelocateOutcome {
+    found: bool,
+    #[serde(default)]
+    conflict: Option<String>,
+    #[serde(default)]
+    moved: Vec<DivisionRecord>,
+}
+
+#[derive(Deserialize)]
+struct RemoveOutcome {
+    found: bool,
+    #[serde(default)]
+    conflict: Option<String>,
+    #[serde(default)]
+    removed: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -115,6 +383,12 @@ struct DivisionRecord {
     budget_code: String,
     payroll_id: String,
     parent_division_id: Option<String>,
+    #[serde(default)]
+    manager_employee_id: Option<String>,
+    #[serde(default)]
+    headcount_budget: Option<u32>,
+    #[serde(default)]
+    region: Option<String>,
 }
 
 fn record_to_domain(record: DivisionRecord) -> AppResult<Division> {
@@ -138,6 +412,13 @@ fn record_to_domain(record: DivisionRecord) -> AppResult<Division> {
         ),
         None => None,
     };
+    let manager_employee_id = match record.manager_employee_id {
+        Some(value) => Some(
+            Uuid::parse_str(&value)
+                .map_err(|_| AppError::internal("stored division manager id is not a UUID"))?,
+        ),
+        None => None,
+    };
 
     Ok(Division::new(
         id,
@@ -146,6 +427,9 @@ fn record_to_domain(record: DivisionRecord) -> AppResult<Division> {
         record.budget_code,
         payroll_id,
         parent_division_id,
+        manager_employee_id,
+        record.headcount_budget,
+        record.region,
     ))
 }
 
@@ -154,6 +438,8 @@ fn build_update_payload(
     description: Option<String>,
     budget_code: Option<String>,
     parent_division_id: Option<Option<Uuid>>,
+    headcount_budget: Option<Option<u32>>,
+    region: Option<Option<String>>,
 ) -> AppResult<JsonValue> {
     let mut object = Map::new();
 
@@ -183,6 +469,22 @@ fn build_update_payload(
         }
     }
 
+    if let Some(headcount_budget) = headcount_budget {
+        object.insert(
+            "headcount_budget".to_string(),
+            headcount_budget
+                .map(JsonValue::from)
+                .unwrap_or(JsonValue::Null),
+        );
+    }
+
+    if let Some(region) = region {
+        object.insert(
+            "region".to_string(),
+            region.map(JsonValue::String).unwrap_or(JsonValue::Null),
+        );
+    }
+
     if object.is_empty() {
         return Err(AppError::internal("no fields supplied for division update"));
     }
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{Connection, Surreal, engine::any::Any};
+use uuid::Uuid;
+
+use crate::{
+    domain::email_verification::EmailVerification,
+    error::{AppError, AppResult},
+    services::email_verification::EmailVerificationRepository,
+};
+
+const EMAIL_VERIFICATION_TABLE: &str = "email_verification";
+
+/// Keeps one pending verification per employee, keyed by the employee id.
+#[derive(Clone)]
+pub struct SurrealEmailVerificationRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealEmailVerificationRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> EmailVerificationRepository for SurrealEmailVerificationRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn upsert(&self, verification: EmailVerification) -> AppResult<EmailVerification> {
+        let employee_id = verification.employee_id;
+        let record: Option<EmailVerificationRecord> = self
+            .client
+            .upsert((EMAIL_VERIFICATION_TABLE, employee_id.to_string()))
+            .content(build_payload(&verification))
+            .await?;
+
+        record
+            .map(|record| record_to_domain(employee_id, record))
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return stored email verification"))
+    }
+
+    async fn fetch(&self, employee_id: Uuid) -> AppResult<Option<EmailVerification>> {
+        let record: Option<EmailVerificationRecord> = self
+            .reader
+            .select((EMAIL_VERIFICATION_TABLE, employee_id.to_string()))
+            .await?;
+
+        record
+            .map(|record| record_to_domain(employee_id, record))
+            .transpose()
+    }
+
+    async fn delete(&self, employee_id: Uuid) -> AppResult<bool> {
+        let record: Option<EmailVerificationRecord> = self
+            .client
+            .delete((EMAIL_VERIFICATION_TABLE, employee_id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct EmailVerificationRecord {
+    email: String,
+    code_hash: String,
+    expires_at: String,
+    attempts_left: u32,
+    created_at: String,
+}
+
+fn record_to_domain(
+    employee_id: Uuid,
+    record: EmailVerificationRecord,
+) -> AppResult<EmailVerification> {
+    Ok(EmailVerification {
+        employee_id,
+        email: record.email,
+        code_hash: record.code_hash,
+        expires_at: parse_timestamp(&record.expires_at)?,
+        attempts_left: record.attempts_left,
+        created_at: parse_timestamp(&record.created_at)?,
+    })
+}
+
+fn parse_timestamp(value: &str) -> AppResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| AppError::internal("stored email verification timestamp is not valid"))
+}
+
+fn build_payload(verification: &EmailVerification) -> JsonValue {
+    json!({
+        "email": verification.email,
+        "code_hash": verification.code_hash,
+        "expires_at": verification.expires_at.to_rfc3339(),
+        "attempts_left": verification.attempts_left,
+        "created_at": verification.created_at.to_rfc3339(),
+    })
+}
+
+pub type SurrealAnyEmailVerificationRepository = SurrealEmailVerificationRepository<Any>;
@@ -1,4 +1,6 @@
-use chrono::NaiveDate;
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::Deserialize;
 use serde_json::{Map, Value as JsonValue, json};
 use surrealdb::{
@@ -9,19 +11,225 @@ use surrealdb::{
 use uuid::Uuid;
 
 use crate::{
-    domain::employee::Employee,
+    domain::{
+        employee::{
+            AccountVerification, Employee, EmployeeStatus, PaymentAmount, PaymentInstruction,
+            WorkPermit,
+        },
+        employment_history::EmploymentEvent,
+        pagination::{Page, PageRequest},
+    },
     error::{AppError, AppResult},
-    services::employee::{EmployeeRepository, UpdateEmployeeParams},
+    infrastructure::employment_history_repository::event_content,
+    services::employee::{EmployeePageFilter, EmployeeRepository, UpdateEmployeeParams},
 };
 
 const EMPLOYEE_TABLE: &str = "employee";
 
+const BY_DIVISION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE division_id = $division_id";
+
+const BY_DIVISIONS_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE division_id INSIDE $division_ids";
+
+/// Checks the referenced divisions, jobs, banks, schedules and supervisors and creates the
+/// employees in one transaction.
+///
+/// Each transaction only sees its own snapshot, so reading the references would not stop a
+/// delete committing between the check and the insert. The transaction therefore also writes
+/// `referenced_at` on every referenced record. A concurrent delete touches the same key, and
+/// the store aborts one of the two with a conflict instead of committing both and leaving a
+/// dangling link. Concurrent creates under the same division or job contend the same way and
+/// may be asked to retry.
+///
+/// `INSERT` takes neither a computed table nor a subquery position, so it names `employee`
+/// and the created records are read back afterwards. It also skips ids that already exist
+/// without failing, so those are refused up front.
+const INSERT_QUERY: &str = "
+BEGIN TRANSACTION;
+{
+    LET $references = (SELECT VALUE type::thing(tb, id) FROM $links);
+    LET $missing = array::complement($references, (SELECT VALUE id FROM $references));
+    IF array::len($missing) > 0 {
+        RETURN { missing: $missing };
+    };
+    LET $created = (SELECT VALUE type::thing($table, id) FROM $records);
+    LET $existing = (SELECT VALUE id FROM $created);
+    IF array::len($existing) > 0 {
+        RETURN { missing: [], existing: $existing };
+    };
+    UPDATE $references SET referenced_at = time::now();
+    INSERT INTO employee $records;
+    RETURN {
+        missing: [],
+        employees: (SELECT * FROM $created),
+    };
+};
+COMMIT TRANSACTION;
+";
+
+/// Clears or deletes everything that refers to the employees whose ids are bound as `$removed`:
+/// supervisor and division manager links and position assignments are cleared, and their
+/// dependents, employment history, leave, overtime, timesheets, self-service tokens, email
+/// verification and photo (stored under [`Employee::photo_key`]) are deleted. Spliced with
+/// [`removing_employee_records`] into the transactions that delete employees.
+const REMOVE_EMPLOYEE_RECORDS: &str = "
+    UPDATE employee SET supervisor_id = NULL WHERE supervisor_id IN $removed;
+    UPDATE division SET manager_employee_id = NULL WHERE manager_employee_id IN $removed;
+    UPDATE position SET employee_id = NULL WHERE employee_id IN $removed;
+    UPDATE position
+        SET history = (SELECT * FROM $parent.history WHERE employee_id NOTINSIDE $removed)
+        WHERE history.employee_id CONTAINSANY $removed;
+    DELETE dependent WHERE employee_id IN $removed;
+    DELETE employment_event WHERE employee_id IN $removed;
+    DELETE leave_adjustment WHERE employee_id IN $removed;
+    DELETE leave_balance WHERE employee_id IN $removed;
+    DELETE leave_request WHERE employee_id IN $removed;
+    DELETE overtime_request WHERE employee_id IN $removed;
+    DELETE self_service_token WHERE employee_id IN $removed;
+    DELETE timesheet_entry WHERE employee_id IN $removed;
+    DELETE timesheet_period WHERE employee_id IN $removed;
+    FOR $removed_id IN $removed {
+        DELETE type::thing('email_verification', $removed_id);
+        DELETE type::thing('blob', string::concat('employee_photo_', $removed_id));
+    };
+";
+
+/// `query` with its `{remove_employee_records}` placeholder replaced by the statements of
+/// [`REMOVE_EMPLOYEE_RECORDS`].
+pub(crate) fn removing_employee_records(query: &str) -> String {
+    query.replace("{remove_employee_records}", REMOVE_EMPLOYEE_RECORDS)
+}
+
+/// Deletes an employee and everything stored under it in one transaction.
+const DELETE_QUERY: &str = "
+BEGIN TRANSACTION;
+{
+    IF array::len((SELECT id FROM type::thing('employee', $employee_id))) = 0 {
+        RETURN { found: false };
+    };
+    LET $removed = [$employee_id];
+    {remove_employee_records}
+    DELETE type::thing('employee', $employee_id);
+    RETURN { found: true };
+};
+COMMIT TRANSACTION;
+";
+
+/// Terminates an employee in one transaction: the status and termination date are set, every
+/// position they hold is vacated with its open assignment closed, and the event is recorded.
+/// The status is checked again inside it, and a failed check returns before anything is
+/// written.
+const TERMINATE_QUERY: &str = "
+BEGIN TRANSACTION;
+{
+    LET $employee = type::thing('employee', $employee_id);
+    LET $status = (SELECT VALUE status FROM $employee)[0];
+    IF $status IS NONE {
+        RETURN { found: false };
+    };
+    IF $status = $terminated {
+        RETURN { found: true, terminated: true };
+    };
+    UPDATE position SET
+        employee_id = NULL,
+        history = (
+            SELECT
+                employee_id,
+                start_date,
+                (end_date ?? array::max([start_date, $termination_date])) AS end_date
+            FROM $parent.history
+        )
+        WHERE employee_id = $employee_id;
+    CREATE type::thing('employment_event', $event_id) CONTENT $event;
+    RETURN {
+        found: true,
+        employee: (
+            UPDATE $employee MERGE { status: $terminated, termination_date: $termination_date }
+                RETURN AFTER
+        )[0],
+    };
+};
+COMMIT TRANSACTION;
+";
+
+const BY_JOB_QUERY: &str = "SELECT * FROM type::table($table) WHERE job_id = $job_id";
+
+const ACTIVE_BY_JOB_COUNT_QUERY: &str = "
+    SELECT count() AS total FROM type::table($table)
+        WHERE job_id = $job_id AND status != $terminated
+        GROUP ALL
+";
+
+const BY_EMAIL_QUERY: &str = "
+    SELECT * FROM type::table($table) WHERE email = $email AND payroll_id INSIDE $payroll_ids
+";
+
+const BY_SUPERVISOR_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE supervisor_id = $supervisor_id";
+
+// Records written before split payments keep a single `bank_id`.
+const BY_BANK_QUERY: &str = "
+    SELECT * FROM type::table($table)
+        WHERE payment_instructions.bank_id CONTAINS $bank_id OR bank_id = $bank_id
+";
+
+// `surreal::migrate` rewrites legacy status spellings, so `status` is compared as stored and
+// the `employee_job` index narrows both the page and the count.
+const JOB_PAGE_QUERY: &str = "
+    SELECT * FROM type::table($table)
+        WHERE job_id = $job_id AND status != $terminated
+        ORDER BY last_name, first_name
+        LIMIT $limit START $start;
+    SELECT count() AS total FROM type::table($table)
+        WHERE job_id = $job_id AND status != $terminated
+        GROUP ALL;
+";
+
+/// Matches `EmployeePageFilter::matches`. Only the filters that are set are compared, since a
+/// guard such as `(!$job_id OR ...)` stops the planner from using an index; the text search
+/// cannot use one either, so it runs over what the indexed select returned.
+fn page_query(filter: &EmployeePageFilter) -> String {
+    let mut conditions = vec!["division_id INSIDE $division_ids"];
+    if filter.job_id.is_some() {
+        conditions.push("job_id = $job_id");
+    }
+    if filter.status.is_some() {
+        conditions.push("status = $status");
+    }
+    let matched = if filter.query.is_some() {
+        "(SELECT * FROM $scoped
+            WHERE string::contains(string::lowercase(first_name + ' ' + last_name), $query)
+                OR string::contains(string::lowercase(last_name + ' ' + first_name), $query)
+                OR string::contains(string::lowercase(id_number), $query)
+                OR string::contains(string::lowercase(external_id ?? ''), $query)
+                OR string::contains(string::lowercase(email ?? ''), $query))"
+    } else {
+        "$scoped"
+    };
+
+    format!(
+        "{{
+            LET $scoped = (SELECT * FROM type::table($table) WHERE {});
+            LET $matched = {matched};
+            RETURN {{
+                total: array::len($matched),
+                employees: (
+                    SELECT * FROM $matched ORDER BY last_name, first_name LIMIT $limit START $start
+                ),
+            }};
+        }}",
+        conditions.join(" AND ")
+    )
+}
+
 #[derive(Clone)]
 pub struct SurrealEmployeeRepository<C>
 where
     C: Connection,
 {
     client: Surreal<C>,
+    reader: Surreal<C>,
 }
 
 impl<C> SurrealEmployeeRepository<C>
@@ -29,7 +237,15 @@ where
     C: Connection,
 {
     pub fn new(client: Surreal<C>) -> Self {
-        Self { client }
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
     }
 }
 
@@ -43,10 +259,12 @@ where
         &self,
         id: Uuid,
         id_number: String,
+        external_id: Option<String>,
         last_name: String,
         first_name: String,
         address: String,
         phone: String,
+        email: Option<String>,
         place_of_birth: String,
         date_of_birth: NaiveDate,
         nationality: String,
@@ -54,49 +272,129 @@ where
         gender: String,
         hire_date: NaiveDate,
         termination_date: Option<NaiveDate>,
+        probation_end_date: Option<NaiveDate>,
+        contract_end_date: Option<NaiveDate>,
+        work_permit: Option<WorkPermit>,
         clasification: String,
         job_id: Uuid,
-        bank_id: Uuid,
-        bank_account: String,
-        status: String,
+        payment_instructions: Vec<PaymentInstruction>,
+        status: EmployeeStatus,
         hours: i32,
+        schedule_id: Option<Uuid>,
         division_id: Uuid,
         payroll_id: Uuid,
+        supervisor_id: Option<Uuid>,
+        custom_fields: BTreeMap<String, JsonValue>,
     ) -> AppResult<Employee> {
-        let record: Option<EmployeeRecord> = self
+        let employee = Employee::new(
+            id,
+            id_number,
+            external_id,
+            last_name,
+            first_name,
+            address,
+            phone,
+            email,
+            None,
+            place_of_birth,
+            date_of_birth,
+            nationality,
+            marital_status,
+            gender,
+            hire_date,
+            termination_date,
+            probation_end_date,
+            contract_end_date,
+            work_permit,
+            clasification,
+            job_id,
+            payment_instructions,
+            status,
+            hours,
+            schedule_id,
+            division_id,
+            payroll_id,
+            supervisor_id,
+            None,
+            custom_fields,
+        );
+
+        self.insert_many(vec![employee])
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::internal("database did not return created employee"))
+    }
+
+    async fn insert_many(&self, employees: Vec<Employee>) -> AppResult<Vec<Employee>> {
+        if employees.is_empty() {
+            return Ok(employees);
+        }
+
+        // A supervisor created in the same batch is not there yet to be checked.
+        let batch: Vec<Uuid> = employees.iter().map(|employee| employee.id).collect();
+        let links: Vec<JsonValue> = employees
+            .iter()
+            .flat_map(employee_links)
+            .filter(|link| {
+                link["tb"] != EMPLOYEE_TABLE || !batch.iter().any(|id| link["id"] == json!(id))
+            })
+            .collect();
+        let records: Vec<JsonValue> = employees.iter().map(employee_content).collect();
+        let subject = if employees.len() == 1 {
+            "the employee was"
+        } else {
+            "the employees were"
+        };
+
+        let mut response = self
             .client
-            .create((EMPLOYEE_TABLE, id.to_string()))
-            .content(json!({
-                "id_number": id_number,
-                "last_name": last_name,
-                "first_name": first_name,
-                "address": address,
-                "phone": phone,
-                "place_of_birth": place_of_birth,
-                "date_of_birth": date_of_birth.to_string(),
-                "nationality": nationality,
-                "marital_status": marital_status,
-                "gender": gender,
-                "hire_date": hire_date.to_string(),
-                "termination_date": termination_date.map(|date| date.to_string()),
-                "clasification": clasification,
-                "job_id": job_id,
-                "bank_id": bank_id,
-                "bank_account": bank_account,
-                "status": status,
-                "hours": hours,
-                "division_id": division_id,
-                "payroll_id": payroll_id,
-            }))
-            .await?;
+            .query(INSERT_QUERY)
+            .bind(("links", links))
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("records", records))
+            .await?
+            .check()?;
+        let outcome: Option<InsertOutcome> = response.take(0)?;
+        let outcome = outcome
+            .ok_or_else(|| AppError::internal("database did not return created employees"))?;
 
-        record
+        if !outcome.missing.is_empty() {
+            let mut tables: Vec<String> =
+                outcome.missing.into_iter().map(|thing| thing.tb).collect();
+            tables.sort();
+            tables.dedup();
+            return Err(AppError::conflict(format!(
+                "referenced {} deleted while {subject} being created",
+                tables.join(", ")
+            )));
+        }
+        if let Some(existing) = outcome.existing.first() {
+            return Err(AppError::conflict(format!(
+                "employee `{}` already exists",
+                existing.id.to_raw()
+            )));
+        }
+        if outcome.employees.len() != employees.len() {
+            return Err(AppError::internal(
+                "database did not return every created employee",
+            ));
+        }
+
+        outcome
+            .employees
+            .into_iter()
             .map(record_to_domain)
-            .transpose()?
-            .ok_or_else(|| AppError::internal("database did not return created employee"))
+            .collect()
     }
 
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Employee>> {
+        let record: Option<EmployeeRecord> =
+            self.reader.select((EMPLOYEE_TABLE, id.to_string())).await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_latest(&self, id: Uuid) -> AppResult<Option<Employee>> {
         let record: Option<EmployeeRecord> =
             self.client.select((EMPLOYEE_TABLE, id.to_string())).await?;
 
@@ -104,40 +402,355 @@ where
     }
 
     async fn fetch_by_division(&self, division_id: Uuid) -> AppResult<Vec<Employee>> {
-        let records: Vec<EmployeeRecord> = self.client.select(EMPLOYEE_TABLE).await?;
-        records
-            .into_iter()
-            .filter(|record| record.division_id == division_id.to_string())
-            .map(record_to_domain)
-            .collect()
+        let mut response = self
+            .reader
+            .query(BY_DIVISION_QUERY)
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("division_id", division_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<EmployeeRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_by_divisions(&self, division_ids: Vec<Uuid>) -> AppResult<Vec<Employee>> {
+        let division_ids: Vec<String> = division_ids.iter().map(ToString::to_string).collect();
+        let mut response = self
+            .reader
+            .query(BY_DIVISIONS_QUERY)
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("division_ids", division_ids))
+            .await?
+            .check()?;
+        let records: Vec<EmployeeRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_by_supervisor(&self, supervisor_id: Uuid) -> AppResult<Vec<Employee>> {
+        let mut response = self
+            .reader
+            .query(BY_SUPERVISOR_QUERY)
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("supervisor_id", supervisor_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<EmployeeRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_by_job(&self, job_id: Uuid) -> AppResult<Vec<Employee>> {
+        let mut response = self
+            .reader
+            .query(BY_JOB_QUERY)
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("job_id", job_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<EmployeeRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn count_active_by_job(&self, job_id: Uuid) -> AppResult<u32> {
+        let mut response = self
+            .client
+            .query(ACTIVE_BY_JOB_COUNT_QUERY)
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("job_id", job_id.to_string()))
+            .bind(("terminated", EmployeeStatus::Terminated.as_str()))
+            .await?
+            .check()?;
+        let count: Option<CountRecord> = response.take(0)?;
+        Ok(count.map_or(0, |count| count.total as u32))
     }
 
-    async fn update(&self, id: Uuid, updates: UpdateEmployeeParams) -> AppResult<Option<Employee>> {
-        let payload = build_update_payload(updates)?;
+    async fn fetch_by_email(
+        &self,
+        email: &str,
+        payroll_ids: Vec<Uuid>,
+    ) -> AppResult<Vec<Employee>> {
+        let payroll_ids: Vec<String> = payroll_ids.iter().map(ToString::to_string).collect();
+        let mut response = self
+            .client
+            .query(BY_EMAIL_QUERY)
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("email", email.to_string()))
+            .bind(("payroll_ids", payroll_ids))
+            .await?
+            .check()?;
+        let records: Vec<EmployeeRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_by_bank(&self, bank_id: Uuid) -> AppResult<Vec<Employee>> {
+        let mut response = self
+            .reader
+            .query(BY_BANK_QUERY)
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("bank_id", bank_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<EmployeeRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_page_by_job(
+        &self,
+        job_id: Uuid,
+        page: PageRequest,
+    ) -> AppResult<Page<Employee>> {
+        let mut response = self
+            .reader
+            .query(JOB_PAGE_QUERY)
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("job_id", job_id.to_string()))
+            .bind(("terminated", EmployeeStatus::Terminated.as_str()))
+            .bind(("limit", page.per_page))
+            .bind(("start", (page.page - 1).saturating_mul(page.per_page)))
+            .await?
+            .check()?;
+        let records: Vec<EmployeeRecord> = response.take(0)?;
+        let count: Option<CountRecord> = response.take(1)?;
+
+        Ok(Page {
+            items: records
+                .into_iter()
+                .map(record_to_domain)
+                .collect::<AppResult<_>>()?,
+            page: page.page,
+            per_page: page.per_page,
+            total: count.map_or(0, |count| count.total),
+        })
+    }
+
+    async fn fetch_page(
+        &self,
+        filter: EmployeePageFilter,
+        page: PageRequest,
+    ) -> AppResult<Page<Employee>> {
+        let division_ids: Vec<String> = filter
+            .division_ids
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let mut response = self
+            .reader
+            .query(page_query(&filter))
+            .bind(("table", EMPLOYEE_TABLE))
+            .bind(("division_ids", division_ids))
+            .bind(("job_id", filter.job_id.map(|id| id.to_string())))
+            .bind(("status", filter.status.map(EmployeeStatus::as_str)))
+            .bind(("query", filter.query))
+            .bind(("limit", page.per_page))
+            .bind(("start", (page.page - 1).saturating_mul(page.per_page)))
+            .await?
+            .check()?;
+        let outcome: Option<PageOutcome> = response.take(0)?;
+        let outcome =
+            outcome.ok_or_else(|| AppError::internal("database did not return a page"))?;
+
+        Ok(Page {
+            items: outcome
+                .employees
+                .into_iter()
+                .map(record_to_domain)
+                .collect::<AppResult<_>>()?,
+            page: page.page,
+            per_page: page.per_page,
+            total: outcome.total,
+        })
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        mut updates: UpdateEmployeeParams,
+    ) -> AppResult<Option<Employee>> {
+        // A merge would keep custom fields missing from the new map, so the map is replaced
+        // wholesale with SET before the remaining fields are merged.
+        if let Some(custom_fields) = updates.custom_fields.take() {
+            self.client
+                .query("UPDATE type::thing($table, $id) SET custom_fields = $custom_fields")
+                .bind(("table", EMPLOYEE_TABLE))
+                .bind(("id", id.to_string()))
+                .bind(("custom_fields", custom_fields))
+                .await?
+                .check()?;
+        }
+
+        let record: Option<EmployeeRecord> = match build_update_payload(updates) {
+            Some(payload) => {
+                self.client
+                    .update((EMPLOYEE_TABLE, id.to_string()))
+                    .merge(payload)
+                    .await?
+            }
+            None => self.client.select((EMPLOYEE_TABLE, id.to_string())).await?,
+        };
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn set_photo_url(
+        &self,
+        id: Uuid,
+        photo_url: Option<String>,
+    ) -> AppResult<Option<Employee>> {
         let record: Option<EmployeeRecord> = self
             .client
             .update((EMPLOYEE_TABLE, id.to_string()))
-            .merge(payload)
+            .merge(json!({ "photo_url": photo_url }))
             .await?;
 
         record.map(record_to_domain).transpose()
     }
 
+    async fn terminate(&self, id: Uuid, event: EmploymentEvent) -> AppResult<Option<Employee>> {
+        let mut response = self
+            .client
+            .query(TERMINATE_QUERY)
+            .bind(("employee_id", id.to_string()))
+            .bind(("terminated", EmployeeStatus::Terminated.as_str()))
+            .bind(("termination_date", event.effective_date.to_string()))
+            .bind(("event_id", event.id.to_string()))
+            .bind(("event", event_content(&event)))
+            .await?
+            .check()?;
+        let outcome: Option<TerminateOutcome> = response.take(0)?;
+        let outcome =
+            outcome.ok_or_else(|| AppError::internal("database did not report the termination"))?;
+
+        if !outcome.found {
+            return Ok(None);
+        }
+        if outcome.terminated {
+            return Err(AppError::conflict(format!(
+                "employee `{id}` is already terminated"
+            )));
+        }
+        outcome.employee.map(record_to_domain).transpose()
+    }
+
     async fn delete(&self, id: Uuid) -> AppResult<bool> {
-        let record: Option<EmployeeRecord> =
-            self.client.delete((EMPLOYEE_TABLE, id.to_string())).await?;
-        Ok(record.is_some())
+        let mut response = self
+            .client
+            .query(removing_employee_records(DELETE_QUERY))
+            .bind(("employee_id", id.to_string()))
+            .await?
+            .check()?;
+        let outcome: Option<DeleteOutcome> = response.take(0)?;
+        let outcome = outcome
+            .ok_or_else(|| AppError::internal("database did not report the employee delete"))?;
+        Ok(outcome.found)
     }
 }
 
+#[derive(Deserialize)]
+struct DeleteOutcome {
+    found: bool,
+}
+
+#[derive(Deserialize)]
+struct TerminateOutcome {
+    found: bool,
+    #[serde(default)]
+    terminated: bool,
+    #[serde(default)]
+    employee: Option<EmployeeRecord>,
+}
+
+/// Row of a `count() ... GROUP ALL` query, such as the second half of a page query.
+#[derive(Debug, Deserialize)]
+struct CountRecord {
+    total: usize,
+}
+
+#[derive(Deserialize)]
+struct PageOutcome {
+    total: usize,
+    employees: Vec<EmployeeRecord>,
+}
+
+#[derive(Deserialize)]
+struct InsertOutcome {
+    missing: Vec<Thing>,
+    #[serde(default)]
+    existing: Vec<Thing>,
+    #[serde(default)]
+    employees: Vec<EmployeeRecord>,
+}
+
+/// Records the employee must point at when it is created.
+fn employee_links(employee: &Employee) -> Vec<JsonValue> {
+    let mut links = vec![
+        json!({ "tb": "division", "id": employee.division_id }),
+        json!({ "tb": "job", "id": employee.job_id }),
+    ];
+    links.extend(
+        employee
+            .payment_instructions
+            .iter()
+            .map(|instruction| json!({ "tb": "bank", "id": instruction.bank_id })),
+    );
+    links.extend(
+        employee
+            .schedule_id
+            .map(|id| json!({ "tb": "work_schedule", "id": id })),
+    );
+    links.extend(
+        employee
+            .supervisor_id
+            .map(|id| json!({ "tb": EMPLOYEE_TABLE, "id": id })),
+    );
+    links
+}
+
+fn employee_content(employee: &Employee) -> JsonValue {
+    json!({
+        "id": employee.id.to_string(),
+        "id_number": employee.id_number,
+        "external_id": employee.external_id,
+        "last_name": employee.last_name,
+        "first_name": employee.first_name,
+        "address": employee.address,
+        "phone": employee.phone,
+        "email": employee.email,
+        "place_of_birth": employee.place_of_birth,
+        "date_of_birth": employee.date_of_birth.to_string(),
+        "nationality": employee.nationality,
+        "marital_status": employee.marital_status,
+        "gender": employee.gender,
+        "hire_date": employee.hire_date.to_string(),
+        "termination_date": employee.termination_date.map(|date| date.to_string()),
+        "probation_end_date": employee.probation_end_date.map(|date| date.to_string()),
+        "contract_end_date": employee.contract_end_date.map(|date| date.to_string()),
+        "work_permit": employee.work_permit,
+        "clasification": employee.clasification,
+        "job_id": employee.job_id,
+        "payment_instructions": payment_instructions_content(&employee.payment_instructions),
+        "status": employee.status.as_str(),
+        "hours": employee.hours,
+        "schedule_id": employee.schedule_id,
+        "division_id": employee.division_id,
+        "payroll_id": employee.payroll_id,
+        "supervisor_id": employee.supervisor_id,
+        "custom_fields": employee.custom_fields,
+    })
+}
+
 #[derive(Debug, Deserialize)]
 struct EmployeeRecord {
     id: Thing,
     id_number: String,
+    #[serde(default)]
+    external_id: Option<String>,
     last_name: String,
     first_name: String,
     address: String,
     phone: String,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    email_verified_at: Option<String>,
     place_of_birth: String,
     date_of_birth: String,
     nationality: String,
@@ -145,14 +758,43 @@ struct EmployeeRecord {
     gender: String,
     hire_date: String,
     termination_date: Option<String>,
+    #[serde(default)]
+    probation_end_date: Option<String>,
+    #[serde(default)]
+    contract_end_date: Option<String>,
+    #[serde(default)]
+    work_permit: Option<WorkPermit>,
     clasification: String,
     job_id: String,
-    bank_id: String,
-    bank_account: String,
+    #[serde(default)]
+    payment_instructions: Vec<PaymentInstructionRecord>,
+    /// Single account stored before payment instructions existed.
+    #[serde(default)]
+    bank_id: Option<String>,
+    #[serde(default)]
+    bank_account: Option<String>,
     status: String,
     hours: i32,
+    #[serde(default)]
+    schedule_id: Option<String>,
     division_id: String,
     payroll_id: String,
+    #[serde(default)]
+    supervisor_id: Option<String>,
+    #[serde(default)]
+    photo_url: Option<String>,
+    #[serde(default)]
+    custom_fields: BTreeMap<String, JsonValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PaymentInstructionRecord {
+    bank_id: String,
+    account: String,
+    amount: PaymentAmount,
+    priority: u32,
+    #[serde(default)]
+    verification: AccountVerification,
 }
 
 fn record_to_domain(record: EmployeeRecord) -> AppResult<Employee> {
@@ -171,24 +813,90 @@ fn record_to_domain(record: EmployeeRecord) -> AppResult<Employee> {
         .map_err(|_| AppError::internal("stored division id is not a UUID"))?;
     let payroll_id = Uuid::parse_str(&record.payroll_id)
         .map_err(|_| AppError::internal("stored payroll id is not a UUID"))?;
+    let supervisor_id = match record.supervisor_id {
+        Some(value) => Some(
+            Uuid::parse_str(&value)
+                .map_err(|_| AppError::internal("stored supervisor id is not a UUID"))?,
+        ),
+        None => None,
+    };
+    let schedule_id = match record.schedule_id {
+        Some(value) => Some(
+            Uuid::parse_str(&value)
+                .map_err(|_| AppError::internal("stored schedule id is not a UUID"))?,
+        ),
+        None => None,
+    };
+    let status = EmployeeStatus::parse(&record.status).ok_or_else(|| {
+        AppError::internal(format!(
+            "employee `{id}` has unrecognized stored status `{}`",
+            record.status
+        ))
+    })?;
     let job_id = Uuid::parse_str(&record.job_id)
         .map_err(|_| AppError::internal("stored job id is not a UUID"))?;
-    let bank_id = Uuid::parse_str(&record.bank_id)
-        .map_err(|_| AppError::internal("stored bank id is not a UUID"))?;
+    let payment_instructions = match (record.bank_id, record.bank_account) {
+        (Some(bank_id), Some(account)) if record.payment_instructions.is_empty() => {
+            vec![PaymentInstruction {
+                bank_id: Uuid::parse_str(&bank_id)
+                    .map_err(|_| AppError::internal("stored bank id is not a UUID"))?,
+                account,
+                amount: PaymentAmount::Percentage(100.0),
+                priority: 0,
+                verification: AccountVerification::default(),
+            }]
+        }
+        _ => record
+            .payment_instructions
+            .into_iter()
+            .map(|instruction| {
+                Ok(PaymentInstruction {
+                    bank_id: Uuid::parse_str(&instruction.bank_id)
+                        .map_err(|_| AppError::internal("stored bank id is not a UUID"))?,
+                    account: instruction.account,
+                    amount: instruction.amount,
+                    priority: instruction.priority,
+                    verification: instruction.verification,
+                })
+            })
+            .collect::<AppResult<_>>()?,
+    };
     let date_of_birth = parse_date(&record.date_of_birth, "date of birth")?;
     let hire_date = parse_date(&record.hire_date, "hire date")?;
     let termination_date = match record.termination_date {
         Some(value) => Some(parse_date(&value, "termination date")?),
         None => None,
     };
+    let probation_end_date = record
+        .probation_end_date
+        .as_deref()
+        .map(|value| parse_date(value, "probation end date"))
+        .transpose()?;
+    let contract_end_date = record
+        .contract_end_date
+        .as_deref()
+        .map(|value| parse_date(value, "contract end date"))
+        .transpose()?;
+    let email_verified_at = record
+        .email_verified_at
+        .as_deref()
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|_| AppError::internal("stored email verification time is not valid"))
+        })
+        .transpose()?;
 
     Ok(Employee::new(
         id,
         record.id_number,
+        record.external_id,
         record.last_name,
         record.first_name,
         record.address,
         record.phone,
+        record.email,
+        email_verified_at,
         record.place_of_birth,
         date_of_birth,
         record.nationality,
@@ -196,29 +904,55 @@ fn record_to_domain(record: EmployeeRecord) -> AppResult<Employee> {
         record.gender,
         hire_date,
         termination_date,
+        probation_end_date,
+        contract_end_date,
+        record.work_permit,
         record.clasification,
         job_id,
-        bank_id,
-        record.bank_account,
-        record.status,
+        payment_instructions,
+        status,
         record.hours,
+        schedule_id,
         division_id,
         payroll_id,
+        supervisor_id,
+        record.photo_url,
+        record.custom_fields,
     ))
 }
 
+pub(crate) fn payment_instructions_content(instructions: &[PaymentInstruction]) -> JsonValue {
+    instructions
+        .iter()
+        .map(|instruction| {
+            json!({
+                "bank_id": instruction.bank_id.to_string(),
+                "account": instruction.account,
+                "amount": instruction.amount,
+                "priority": instruction.priority,
+                "verification": instruction.verification,
+            })
+        })
+        .collect()
+}
+
 fn parse_date(value: &str, field: &str) -> AppResult<NaiveDate> {
     NaiveDate::parse_from_str(value, "%Y-%m-%d")
         .map_err(|_| AppError::internal(format!("stored {field} is not a valid date")))
 }
 
-fn build_update_payload(updates: UpdateEmployeeParams) -> AppResult<JsonValue> {
+/// Returns `None` when no mergeable field is set.
+fn build_update_payload(updates: UpdateEmployeeParams) -> Option<JsonValue> {
     let mut object = Map::new();
 
     if let Some(id_number) = updates.id_number {
         object.insert("id_number".to_string(), JsonValue::String(id_number));
     }
 
+    if let Some(external_id) = updates.external_id {
+        object.insert("external_id".to_string(), JsonValue::String(external_id));
+    }
+
     if let Some(last_name) = updates.last_name {
         object.insert("last_name".to_string(), JsonValue::String(last_name));
     }
@@ -235,6 +969,17 @@ fn build_update_payload(updates: UpdateEmployeeParams) -> AppResult<JsonValue> {
         object.insert("phone".to_string(), JsonValue::String(phone));
     }
 
+    if let Some(email) = updates.email {
+        object.insert("email".to_string(), json!(email));
+    }
+
+    if let Some(email_verified_at) = updates.email_verified_at {
+        object.insert(
+            "email_verified_at".to_string(),
+            json!(email_verified_at.map(|timestamp| timestamp.to_rfc3339())),
+        );
+    }
+
     if let Some(place_of_birth) = updates.place_of_birth {
         object.insert(
             "place_of_birth".to_string(),
@@ -285,6 +1030,24 @@ fn build_update_payload(updates: UpdateEmployeeParams) -> AppResult<JsonValue> {
         }
     }
 
+    if let Some(probation_end_date) = updates.probation_end_date {
+        object.insert(
+            "probation_end_date".to_string(),
+            json!(probation_end_date.map(|date| date.to_string())),
+        );
+    }
+
+    if let Some(contract_end_date) = updates.contract_end_date {
+        object.insert(
+            "contract_end_date".to_string(),
+            json!(contract_end_date.map(|date| date.to_string())),
+        );
+    }
+
+    if let Some(work_permit) = updates.work_permit {
+        object.insert("work_permit".to_string(), json!(work_permit));
+    }
+
     if let Some(clasification) = updates.clasification {
         object.insert(
             "clasification".to_string(),
@@ -296,30 +1059,47 @@ fn build_update_payload(updates: UpdateEmployeeParams) -> AppResult<JsonValue> {
         object.insert("job_id".to_string(), JsonValue::String(job_id.to_string()));
     }
 
-    if let Some(bank_id) = updates.bank_id {
+    if let Some(payment_instructions) = updates.payment_instructions {
         object.insert(
-            "bank_id".to_string(),
-            JsonValue::String(bank_id.to_string()),
+            "payment_instructions".to_string(),
+            payment_instructions_content(&payment_instructions),
         );
     }
 
-    if let Some(bank_account) = updates.bank_account {
-        object.insert("bank_account".to_string(), JsonValue::String(bank_account));
-    }
-
     if let Some(status) = updates.status {
-        object.insert("status".to_string(), JsonValue::String(status));
+        object.insert(
+            "status".to_string(),
+            JsonValue::String(status.as_str().to_string()),
+        );
     }
 
     if let Some(hours) = updates.hours {
         object.insert("hours".to_string(), JsonValue::from(hours));
     }
 
+    if let Some(schedule_id) = updates.schedule_id {
+        object.insert(
+            "schedule_id".to_string(),
+            schedule_id
+                .map(|value| JsonValue::String(value.to_string()))
+                .unwrap_or(JsonValue::Null),
+        );
+    }
+
+    if let Some(supervisor_id) = updates.supervisor_id {
+        object.insert(
+            "supervisor_id".to_string(),
+            supervisor_id
+                .map(|value| JsonValue::String(value.to_string()))
+                .unwrap_or(JsonValue::Null),
+        );
+    }
+
     if object.is_empty() {
-        return Err(AppError::internal("no fields supplied for employee update"));
+        return None;
     }
 
-    Ok(JsonValue::Object(object))
+    Some(JsonValue::Object(object))
 }
 
 pub type SurrealAnyEmployeeRepository = SurrealEmployeeRepository<Any>;
@@ -0,0 +1,216 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::employment_history::{
+        DivisionTransfer, EmploymentEvent, EmploymentEventKind, JobChange, SalaryAdjustmentChange,
+        StatusChange, TerminationTerms,
+    },
+    error::{AppError, AppResult},
+    services::employment_history::EmploymentHistoryRepository,
+};
+
+const EMPLOYMENT_EVENT_TABLE: &str = "employment_event";
+
+const BY_EMPLOYEE_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE employee_id = $employee_id";
+
+#[derive(Clone)]
+pub struct SurrealEmploymentHistoryRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealEmploymentHistoryRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> EmploymentHistoryRepository for SurrealEmploymentHistoryRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, event: EmploymentEvent) -> AppResult<EmploymentEvent> {
+        let record: Option<EmploymentEventRecord> = self
+            .client
+            .create((EMPLOYMENT_EVENT_TABLE, event.id.to_string()))
+            .content(event_content(&event))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created employment event"))
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<EmploymentEvent>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", EMPLOYMENT_EVENT_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<EmploymentEventRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+}
+
+/// What an event is stored as, for repositories that record events inside their own
+/// transactions.
+pub(crate) fn event_content(event: &EmploymentEvent) -> JsonValue {
+    json!({
+        "employee_id": event.employee_id,
+        "kind": event.kind,
+        "effective_date": event.effective_date.to_string(),
+        "termination": event.termination,
+        "status_change": event.status_change,
+        "job_change": event.job_change.as_ref().map(|change| json!({
+            "from_job_id": change.from_job_id.to_string(),
+            "to_job_id": change.to_job_id.to_string(),
+            "from_salary": change.from_salary,
+            "to_salary": change.to_salary,
+        })),
+        "transfer": event.transfer.as_ref().map(|transfer| json!({
+            "from_division_id": transfer.from_division_id.to_string(),
+            "to_division_id": transfer.to_division_id.to_string(),
+        })),
+        "salary_adjustment": event.salary_adjustment.as_ref().map(|change| json!({
+            "batch_id": change.batch_id.to_string(),
+            "job_id": change.job_id.to_string(),
+            "from_salary": change.from_salary,
+            "to_salary": change.to_salary,
+        })),
+        "recorded_at": event.recorded_at.to_rfc3339(),
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct EmploymentEventRecord {
+    id: Thing,
+    employee_id: String,
+    kind: EmploymentEventKind,
+    effective_date: String,
+    #[serde(default)]
+    termination: Option<TerminationTerms>,
+    #[serde(default)]
+    status_change: Option<StatusChange>,
+    #[serde(default)]
+    job_change: Option<JobChangeRecord>,
+    #[serde(default)]
+    transfer: Option<DivisionTransferRecord>,
+    #[serde(default)]
+    salary_adjustment: Option<SalaryAdjustmentChangeRecord>,
+    recorded_at: String,
+}
+
+/// Ids nested in an event are stored as strings; the database deserializer cannot read them
+/// back into [`Uuid`]s.
+#[derive(Debug, Deserialize)]
+struct JobChangeRecord {
+    from_job_id: String,
+    to_job_id: String,
+    from_salary: f64,
+    to_salary: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct DivisionTransferRecord {
+    from_division_id: String,
+    to_division_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SalaryAdjustmentChangeRecord {
+    batch_id: String,
+    job_id: String,
+    from_salary: f64,
+    to_salary: f64,
+}
+
+fn parse_id(value: &str, field: &str) -> AppResult<Uuid> {
+    Uuid::parse_str(value)
+        .map_err(|_| AppError::internal(format!("stored employment event {field} is not a UUID")))
+}
+
+fn record_to_domain(record: EmploymentEventRecord) -> AppResult<EmploymentEvent> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored employment event id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored employment event identifier is not a supported format",
+            ));
+        }
+    };
+
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored employment event employee id is not a UUID"))?;
+    let effective_date = NaiveDate::parse_from_str(&record.effective_date, "%Y-%m-%d")
+        .map_err(|_| AppError::internal("stored employment event date is not a valid date"))?;
+    let recorded_at = DateTime::parse_from_rfc3339(&record.recorded_at)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| AppError::internal("stored employment event timestamp is not valid"))?;
+
+    let mut event = EmploymentEvent::new(id, employee_id, record.kind, effective_date, recorded_at);
+    event.termination = record.termination;
+    event.status_change = record.status_change;
+    event.job_change = record
+        .job_change
+        .map(|change| {
+            Ok::<_, AppError>(JobChange {
+                from_job_id: parse_id(&change.from_job_id, "job id")?,
+                to_job_id: parse_id(&change.to_job_id, "job id")?,
+                from_salary: change.from_salary,
+                to_salary: change.to_salary,
+            })
+        })
+        .transpose()?;
+    event.transfer = record
+        .transfer
+        .map(|transfer| {
+            Ok::<_, AppError>(DivisionTransfer {
+                from_division_id: parse_id(&transfer.from_division_id, "division id")?,
+                to_division_id: parse_id(&transfer.to_division_id, "division id")?,
+            })
+        })
+        .transpose()?;
+    event.salary_adjustment = record
+        .salary_adjustment
+        .map(|change| {
+            Ok::<_, AppError>(SalaryAdjustmentChange {
+                batch_id: parse_id(&change.batch_id, "salary adjustment id")?,
+                job_id: parse_id(&change.job_id, "job id")?,
+                from_salary: change.from_salary,
+                to_salary: change.to_salary,
+            })
+        })
+        .transpose()?;
+    Ok(event)
+}
+
+pub type SurrealAnyEmploymentHistoryRepository = SurrealEmploymentHistoryRepository<Any>;
@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::import_profile::ImportProfile,
+    error::{AppError, AppResult},
+    services::import_profile::ImportProfileRepository,
+};
+
+const IMPORT_PROFILE_TABLE: &str = "import_profile";
+
+const BY_ORGANIZATION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE organization_id = $organization_id";
+
+#[derive(Clone)]
+pub struct SurrealImportProfileRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealImportProfileRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> ImportProfileRepository for SurrealImportProfileRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, profile: ImportProfile) -> AppResult<ImportProfile> {
+        let record: Option<ImportProfileRecord> = self
+            .client
+            .create((IMPORT_PROFILE_TABLE, profile.id.to_string()))
+            .content(build_payload(&profile))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created import profile"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<ImportProfile>> {
+        let record: Option<ImportProfileRecord> = self
+            .reader
+            .select((IMPORT_PROFILE_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<ImportProfile>> {
+        let mut response = self
+            .reader
+            .query(BY_ORGANIZATION_QUERY)
+            .bind(("table", IMPORT_PROFILE_TABLE))
+            .bind(("organization_id", organization_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<ImportProfileRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, profile: ImportProfile) -> AppResult<Option<ImportProfile>> {
+        let record: Option<ImportProfileRecord> = self
+            .client
+            .update((IMPORT_PROFILE_TABLE, profile.id.to_string()))
+            .content(build_payload(&profile))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<ImportProfileRecord> = self
+            .client
+            .delete((IMPORT_PROFILE_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportProfileRecord {
+    id: Thing,
+    name: String,
+    column_mappings: BTreeMap<String, String>,
+    date_format: String,
+    #[serde(default)]
+    value_translations: BTreeMap<String, BTreeMap<String, String>>,
+    organization_id: String,
+}
+
+fn record_to_domain(record: ImportProfileRecord) -> AppResult<ImportProfile> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored import profile id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored import profile identifier is not a supported format",
+            ));
+        }
+    };
+
+    let organization_id = Uuid::parse_str(&record.organization_id)
+        .map_err(|_| AppError::internal("stored import profile organization id is not a UUID"))?;
+
+    Ok(ImportProfile::new(
+        id,
+        record.name,
+        record.column_mappings,
+        record.date_format,
+        record.value_translations,
+        organization_id,
+    ))
+}
+
+fn build_payload(profile: &ImportProfile) -> JsonValue {
+    json!({
+        "name": profile.name,
+        "column_mappings": profile.column_mappings,
+        "date_format": profile.date_format,
+        "value_translations": profile.value_translations,
+        "organization_id": profile.organization_id,
+    })
+}
+
+pub type SurrealAnyImportProfileRepository = SurrealImportProfileRepository<Any>;
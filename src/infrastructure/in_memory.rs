@@ -0,0 +1,2929 @@
+//! Repository implementations backed by process memory, enabled with the `in-memory`
+//! feature. They implement the same traits as the SurrealDB repositories, so a full
+//! `AppState` can be wired for integration tests without a database.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        attendance::PunchPolicy,
+        bank::{AccountRule, Bank, CatalogBank, PaymentFormat},
+        blob::Blob,
+        custom_field::CustomFieldDefinition,
+        dependent::Dependent,
+        division::Division,
+        email::EmailMessage,
+        email_verification::EmailVerification,
+        employee::{Employee, EmployeeStatus, PaymentInstruction, WorkPermit},
+        employment_history::EmploymentEvent,
+        import_profile::ImportProfile,
+        job::Job,
+        job_grade::JobGrade,
+        leave::{
+            DAY_EPSILON, LeaveBalance, LeaveBalanceAdjustment, LeaveBlackout, LeaveCarryoverRule,
+            LeavePayPolicy, LeaveRequest, LeaveRequestStatus, LeaveType,
+        },
+        legal_entity::LegalEntity,
+        membership::Membership,
+        organization::{LegalIdentity, Organization},
+        organization_deletion::OrganizationDeletion,
+        organization_settings::OrganizationSettings,
+        overtime::{OvertimePolicy, OvertimeRequest},
+        pagination::{Page, PageRequest},
+        payroll::Payroll,
+        position::Position,
+        project::Project,
+        salary_adjustment::SalaryAdjustmentBatch,
+        self_service::SelfServiceToken,
+        timesheet::{TimesheetEntry, TimesheetPeriod},
+        work_schedule::WorkSchedule,
+    },
+    error::{AppError, AppResult},
+    server::AppState,
+    services::{
+        attendance_import::{AttendanceImportService, PunchPolicyRepository},
+        bank::{BankCatalogRepository, BankReassignment, BankRepository, BankService},
+        benchmark_export::BenchmarkExportService,
+        blob::BlobStore,
+        cache::{CacheStore, MemoryCacheStore},
+        calendar::CalendarService,
+        custom_field::{CustomFieldRepository, CustomFieldService, UpdateCustomFieldParams},
+        dependent::{DependentRepository, DependentService},
+        division::{
+            DivisionDeletePolicy, DivisionRelocation, DivisionRemoval, DivisionRepository,
+            DivisionService,
+        },
+        email::EmailSender,
+        email_verification::{EmailVerificationRepository, EmailVerificationService},
+        employee::{EmployeePageFilter, EmployeeRepository, EmployeeService, UpdateEmployeeParams},
+        employee_export::EmployeeExportService,
+        employee_import::EmployeeImportService,
+        employment_history::{EmploymentHistoryRepository, EmploymentHistoryService},
+        expiry::ExpiryService,
+        import_profile::{ImportProfileRepository, ImportProfileService},
+        integration_health::{EMAIL_INTEGRATION, IntegrationMonitor, MonitoredEmailSender},
+        job::{JobReassignment, JobRepository, JobService},
+        job_grade::{JobGradeRepository, JobGradeService},
+        lease::{LeaseRepository, LeaseService},
+        leave::{LeaveRepository, LeaveService},
+        legal_entity::{LegalEntityRepository, LegalEntityService},
+        membership::{MembershipRepository, MembershipService},
+        milestone::MilestoneService,
+        organization::{OrganizationRepository, OrganizationService},
+        organization_clone::OrganizationCloneService,
+        organization_deletion::{
+            DEFAULT_DELETION_GRACE_PERIOD_HOURS, OrganizationDeletionRepository,
+            OrganizationDeletionService,
+        },
+        organization_settings::{OrganizationSettingsRepository, OrganizationSettingsService},
+        overtime::{OvertimeRepository, OvertimeService},
+        pay_equity::PayEquityService,
+        payroll::{PayrollRepository, PayrollService},
+        position::{PositionRepository, PositionService},
+        profile_completeness::ProfileCompletenessService,
+        project::{ProjectRepository, ProjectService},
+        readiness::{DATABASE_DEPENDENCY, DependencyProbe, ReadinessService},
+        salary_adjustment::{SalaryAdjustmentRepository, SalaryAdjustmentService},
+        self_service::{SelfServiceService, SelfServiceTokenRepository},
+        timesheet::{TimesheetRepository, TimesheetService},
+        work_schedule::{WorkScheduleRepository, WorkScheduleService},
+    },
+};
+
+#[derive(Default)]
+pub struct InMemoryOrganizationRepository {
+    store: RwLock<HashMap<Uuid, Organization>>,
+}
+
+/// Shares the employee store, so a delete checks the employees paid through the bank under
+/// the same locks.
+pub struct InMemoryBankRepository {
+    store: RwLock<HashMap<Uuid, Bank>>,
+    employees: Arc<InMemoryEmployeeRepository>,
+}
+
+impl InMemoryBankRepository {
+    pub fn new(employees: Arc<InMemoryEmployeeRepository>) -> Self {
+        Self {
+            store: RwLock::new(HashMap::new()),
+            employees,
+        }
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for InMemoryOrganizationRepository {
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        budget_code_pattern: Option<String>,
+        legal_identity: LegalIdentity,
+    ) -> AppResult<Organization> {
+        let organization = Organization::new(id, name, budget_code_pattern, legal_identity);
+        self.store
+            .write()
+            .await
+            .insert(organization.id, organization.clone());
+        Ok(organization)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Organization>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_all(&self) -> AppResult<Vec<Organization>> {
+        Ok(self.store.read().await.values().cloned().collect())
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        budget_code_pattern: Option<Option<String>>,
+        legal_identity: Option<LegalIdentity>,
+    ) -> AppResult<Option<Organization>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            if let Some(name) = name {
+                existing.name = name;
+            }
+            if let Some(budget_code_pattern) = budget_code_pattern {
+                existing.budget_code_pattern = budget_code_pattern;
+            }
+            if let Some(legal_identity) = legal_identity {
+                existing.legal_identity = legal_identity;
+            }
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn set_logo_url(
+        &self,
+        id: Uuid,
+        logo_url: Option<String>,
+    ) -> AppResult<Option<Organization>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            existing.logo_url = logo_url;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn set_deleted_at(
+        &self,
+        id: Uuid,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Organization>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            existing.deleted_at = deleted_at;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Organization>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            existing.archived_at = archived_at;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl BankRepository for InMemoryBankRepository {
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        organization_id: Uuid,
+        swift_code: Option<String>,
+        routing_number: Option<String>,
+        country: Option<String>,
+        address: Option<String>,
+        account_rule: Option<AccountRule>,
+        payment_format: Option<PaymentFormat>,
+        catalog_id: Option<Uuid>,
+    ) -> AppResult<Bank> {
+        let bank = Bank {
+            swift_code,
+            routing_number,
+            country,
+            address,
+            account_rule,
+            payment_format,
+            catalog_id,
+            ..Bank::new(id, name, organization_id)
+        };
+        self.store.write().await.insert(bank.id, bank.clone());
+        Ok(bank)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Bank>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Bank>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|bank| bank.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_by_catalog_entry(&self, catalog_id: Uuid) -> AppResult<Vec<Bank>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|bank| bank.catalog_id == Some(catalog_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        swift_code: Option<Option<String>>,
+        routing_number: Option<Option<String>>,
+        country: Option<Option<String>>,
+        address: Option<Option<String>>,
+        account_rule: Option<Option<AccountRule>>,
+        payment_format: Option<Option<PaymentFormat>>,
+        catalog_id: Option<Option<Uuid>>,
+    ) -> AppResult<Option<Bank>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            if let Some(name) = name {
+                existing.name = name;
+            }
+            if let Some(swift_code) = swift_code {
+                existing.swift_code = swift_code;
+            }
+            if let Some(routing_number) = routing_number {
+                existing.routing_number = routing_number;
+            }
+            if let Some(country) = country {
+                existing.country = country;
+            }
+            if let Some(address) = address {
+                existing.address = address;
+            }
+            if let Some(account_rule) = account_rule {
+                existing.account_rule = account_rule;
+            }
+            if let Some(payment_format) = payment_format {
+                existing.payment_format = payment_format;
+            }
+            if let Some(catalog_id) = catalog_id {
+                existing.catalog_id = catalog_id;
+            }
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Bank>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            existing.archived_at = archived_at;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid, reassignment: Option<BankReassignment>) -> AppResult<bool> {
+        let mut guard = self.store.write().await;
+        if !guard.contains_key(&id) {
+            return Ok(false);
+        }
+        let mut employees = self.employees.store.write().await;
+        let paid: HashSet<Uuid> = employees
+            .values()
+            .filter(|employee| {
+                employee
+                    .payment_instructions
+                    .iter()
+                    .any(|instruction| instruction.bank_id == id)
+            })
+            .map(|employee| employee.id)
+            .collect();
+        let Some(reassignment) = reassignment else {
+            if !paid.is_empty() {
+                return Err(AppError::conflict(format!(
+                    "bank `{id}` still pays {} employee(s)",
+                    paid.len()
+                )));
+            }
+            return Ok(guard.remove(&id).is_some());
+        };
+
+        let moving: HashSet<Uuid> = reassignment
+            .payment_instructions
+            .iter()
+            .map(|(employee_id, _)| *employee_id)
+            .collect();
+        if paid != moving {
+            return Err(AppError::conflict(format!(
+                "the employees paid through bank `{id}` changed while they were being reassigned"
+            )));
+        }
+        if !guard.contains_key(&reassignment.to_bank_id) {
+            return Err(AppError::conflict(format!(
+                "bank `{}` was deleted while employees were being moved to it",
+                reassignment.to_bank_id
+            )));
+        }
+        for (employee_id, instructions) in reassignment.payment_instructions {
+            if let Some(employee) = employees.get_mut(&employee_id) {
+                employee.payment_instructions = instructions;
+            }
+        }
+
+        Ok(guard.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryBankCatalogRepository {
+    store: RwLock<HashMap<Uuid, CatalogBank>>,
+}
+
+#[async_trait]
+impl BankCatalogRepository for InMemoryBankCatalogRepository {
+    async fn insert(&self, entry: CatalogBank) -> AppResult<CatalogBank> {
+        self.store.write().await.insert(entry.id, entry.clone());
+        Ok(entry)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<CatalogBank>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_all(&self) -> AppResult<Vec<CatalogBank>> {
+        Ok(self.store.read().await.values().cloned().collect())
+    }
+
+    async fn update(&self, entry: CatalogBank) -> AppResult<Option<CatalogBank>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&entry.id) {
+            *existing = entry;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryPayrollRepository {
+    store: RwLock<HashMap<Uuid, Payroll>>,
+}
+
+#[async_trait]
+impl PayrollRepository for InMemoryPayrollRepository {
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        description: String,
+        organization_id: Uuid,
+        blocks_unverified_accounts: bool,
+        legal_entity_id: Option<Uuid>,
+    ) -> AppResult<Payroll> {
+        let mut payroll = Payroll::new(id, name, description, organization_id);
+        payroll.blocks_unverified_accounts = blocks_unverified_accounts;
+        payroll.legal_entity_id = legal_entity_id;
+        self.store.write().await.insert(payroll.id, payroll.clone());
+        Ok(payroll)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Payroll>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Payroll>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|payroll| payroll.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        description: Option<String>,
+        blocks_unverified_accounts: Option<bool>,
+        legal_entity_id: Option<Option<Uuid>>,
+    ) -> AppResult<Option<Payroll>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            if let Some(name) = name {
+                existing.name = name;
+            }
+            if let Some(description) = description {
+                existing.description = description;
+            }
+            if let Some(blocks) = blocks_unverified_accounts {
+                existing.blocks_unverified_accounts = blocks;
+            }
+            if let Some(legal_entity_id) = legal_entity_id {
+                existing.legal_entity_id = legal_entity_id;
+            }
+
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Payroll>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            existing.archived_at = archived_at;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+/// Shares the employee and position stores, so a removal changes all three under one set of
+/// locks. Its divisions live in the employee repository's map, which deleting an employee
+/// clears manager links in.
+pub struct InMemoryDivisionRepository {
+    store: Arc<RwLock<HashMap<Uuid, Division>>>,
+    employees: Arc<InMemoryEmployeeRepository>,
+    positions: Arc<InMemoryPositionRepository>,
+}
+
+impl InMemoryDivisionRepository {
+    pub fn new(
+        employees: Arc<InMemoryEmployeeRepository>,
+        positions: Arc<InMemoryPositionRepository>,
+    ) -> Self {
+        Self {
+            store: Arc::clone(&employees.divisions),
+            employees,
+            positions,
+        }
+    }
+}
+
+#[async_trait]
+impl DivisionRepository for InMemoryDivisionRepository {
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        description: String,
+        budget_code: String,
+        payroll_id: Uuid,
+        parent_division_id: Option<Uuid>,
+        headcount_budget: Option<u32>,
+        region: Option<String>,
+    ) -> AppResult<Division> {
+        let division = Division::new(
+            id,
+            name,
+            description,
+            budget_code,
+            payroll_id,
+            parent_division_id,
+            None,
+            headcount_budget,
+            region,
+        );
+        self.store
+            .write()
+            .await
+            .insert(division.id, division.clone());
+        Ok(division)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Division>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<Division>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|division| division.payroll_id == payroll_id)
+            .cloned()
+            .collect())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        description: Option<String>,
+        budget_code: Option<String>,
+        parent_division_id: Option<Option<Uuid>>,
+        headcount_budget: Option<Option<u32>>,
+        region: Option<Option<String>>,
+    ) -> AppResult<Option<Division>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            if let Some(name) = name {
+                existing.name = name;
+            }
+            if let Some(description) = description {
+                existing.description = description;
+            }
+            if let Some(budget_code) = budget_code {
+                existing.budget_code = budget_code;
+            }
+            if let Some(parent) = parent_division_id {
+                existing.parent_division_id = parent;
+            }
+            if let Some(headcount_budget) = headcount_budget {
+                existing.headcount_budget = headcount_budget;
+            }
+            if let Some(region) = region {
+                existing.region = region;
+            }
+
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn set_manager(
+        &self,
+        id: Uuid,
+        manager_employee_id: Option<Uuid>,
+    ) -> AppResult<Option<Division>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            existing.manager_employee_id = manager_employee_id;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn relocate(&self, relocation: DivisionRelocation) -> AppResult<Option<Vec<Division>>> {
+        let mut divisions = self.store.write().await;
+        let employees = self.employees.store.read().await;
+        let positions = self.positions.store.read().await;
+        let division_id = relocation.division_id;
+        let Some(current_payroll_id) = divisions
+            .get(&division_id)
+            .map(|division| division.payroll_id)
+        else {
+            return Ok(None);
+        };
+
+        let scope: Vec<Uuid> = [division_id]
+            .into_iter()
+            .chain(relocation.descendants)
+            .collect();
+        if divisions.values().any(|division| {
+            division
+                .parent_division_id
+                .is_some_and(|parent_id| scope.contains(&parent_id))
+                && !scope.contains(&division.id)
+        }) {
+            return Err(AppError::conflict(format!(
+                "a division was added under `{division_id}` while it was being moved"
+            )));
+        }
+        if let Some(parent_id) = relocation.parent_division_id
+            && !divisions.contains_key(&parent_id)
+        {
+            return Err(AppError::conflict(format!(
+                "parent division `{parent_id}` was deleted while `{division_id}` was being moved \
+                 under it"
+            )));
+        }
+        if current_payroll_id != relocation.payroll_id
+            && (employees
+                .values()
+                .any(|employee| scope.contains(&employee.division_id))
+                || positions
+                    .values()
+                    .any(|position| scope.contains(&position.division_id))
+                || scope.iter().any(|id| {
+                    divisions
+                        .get(id)
+                        .is_some_and(|division| division.manager_employee_id.is_some())
+                }))
+        {
+            return Err(AppError::conflict(format!(
+                "division `{division_id}` gained employees, positions or a manager while it was \
+                 being moved to another payroll"
+            )));
+        }
+
+        let mut moved = Vec::with_capacity(scope.len());
+        for id in &scope {
+            if let Some(division) = divisions.get_mut(id) {
+                division.payroll_id = relocation.payroll_id;
+                if *id == division_id {
+                    division.parent_division_id = relocation.parent_division_id;
+                }
+                moved.push(division.clone());
+            }
+        }
+
+        Ok(Some(moved))
+    }
+
+    async fn remove(&self, removal: DivisionRemoval) -> AppResult<Option<Vec<Uuid>>> {
+        let mut divisions = self.store.write().await;
+        let mut employees = self.employees.store.write().await;
+        let mut positions = self.positions.store.write().await;
+        let division_id = removal.division_id;
+        if !divisions.contains_key(&division_id) {
+            return Ok(None);
+        }
+
+        match removal.policy {
+            DivisionDeletePolicy::Refuse => {
+                if employees
+                    .values()
+                    .any(|employee| employee.division_id == division_id)
+                    || positions
+                        .values()
+                        .any(|position| position.division_id == division_id)
+                    || divisions
+                        .values()
+                        .any(|division| division.parent_division_id == Some(division_id))
+                {
+                    return Err(AppError::conflict(format!(
+                        "division `{division_id}` gained employees, positions or child divisions while it was \
+                         being deleted"
+                    )));
+                }
+                divisions.remove(&division_id);
+                Ok(Some(Vec::new()))
+            }
+            DivisionDeletePolicy::ReassignTo(target_id) => {
+                if !divisions.contains_key(&target_id) {
+                    return Err(AppError::conflict(format!(
+                        "division `{target_id}` was deleted while dependents were being moved to it"
+                    )));
+                }
+                for employee in employees.values_mut() {
+                    if employee.division_id == division_id {
+                        employee.division_id = target_id;
+                    }
+                }
+                for position in positions.values_mut() {
+                    if position.division_id == division_id {
+                        position.division_id = target_id;
+                    }
+                }
+                for division in divisions.values_mut() {
+                    if division.parent_division_id == Some(division_id) {
+                        division.parent_division_id = Some(target_id);
+                    }
+                }
+                divisions.remove(&division_id);
+                Ok(Some(Vec::new()))
+            }
+            DivisionDeletePolicy::Cascade => {
+                let scope: HashSet<Uuid> = removal
+                    .descendants
+                    .iter()
+                    .copied()
+                    .chain([division_id])
+                    .collect();
+                if divisions.values().any(|division| {
+                    !scope.contains(&division.id)
+                        && division
+                            .parent_division_id
+                            .is_some_and(|parent| scope.contains(&parent))
+                }) {
+                    return Err(AppError::conflict(format!(
+                        "a division was added under `{division_id}` while it was being deleted"
+                    )));
+                }
+
+                let removed: Vec<Uuid> = employees
+                    .values()
+                    .filter(|employee| scope.contains(&employee.division_id))
+                    .map(|employee| employee.id)
+                    .collect();
+                employees.retain(|_, employee| !scope.contains(&employee.division_id));
+                for employee in employees.values_mut() {
+                    if employee
+                        .supervisor_id
+                        .is_some_and(|supervisor| removed.contains(&supervisor))
+                    {
+                        employee.supervisor_id = None;
+                    }
+                }
+                positions.retain(|_, position| !scope.contains(&position.division_id));
+                forget_position_holders(&mut positions, &removed);
+                self.employees.remove_records(&removed).await;
+                divisions.retain(|id, _| !scope.contains(id));
+                for division in divisions.values_mut() {
+                    if division
+                        .manager_employee_id
+                        .is_some_and(|manager| removed.contains(&manager))
+                    {
+                        division.manager_employee_id = None;
+                    }
+                }
+                Ok(Some(removed))
+            }
+        }
+    }
+}
+
+/// Shares the employee and employment history stores, so a delete checks and moves the
+/// employees holding the job under one set of locks.
+pub struct InMemoryJobRepository {
+    store: RwLock<HashMap<Uuid, Job>>,
+    employees: Arc<InMemoryEmployeeRepository>,
+    history: Arc<InMemoryEmploymentHistoryRepository>,
+}
+
+impl InMemoryJobRepository {
+    pub fn new(
+        employees: Arc<InMemoryEmployeeRepository>,
+        history: Arc<InMemoryEmploymentHistoryRepository>,
+    ) -> Self {
+        Self {
+            store: RwLock::new(HashMap::new()),
+            employees,
+            history,
+        }
+    }
+}
+
+#[async_trait]
+impl JobRepository for InMemoryJobRepository {
+    async fn insert(&self, job: Job) -> AppResult<Job> {
+        self.store.write().await.insert(job.id, job.clone());
+        Ok(job)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Job>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<Job>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|job| job.payroll_id == payroll_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, job: Job) -> AppResult<Option<Job>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&job.id) {
+            *existing = job;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid, reassignment: Option<JobReassignment>) -> AppResult<bool> {
+        let mut guard = self.store.write().await;
+        if !guard.contains_key(&id) {
+            return Ok(false);
+        }
+        let mut employees = self.employees.store.write().await;
+        let assigned: HashSet<Uuid> = employees
+            .values()
+            .filter(|employee| employee.job_id == id)
+            .map(|employee| employee.id)
+            .collect();
+
+        let Some(reassignment) = reassignment else {
+            if !assigned.is_empty() {
+                return Err(AppError::conflict(format!(
+                    "job `{id}` is still assigned to {} employee(s)",
+                    assigned.len()
+                )));
+            }
+            return Ok(guard.remove(&id).is_some());
+        };
+        let moving: HashSet<Uuid> = reassignment
+            .events
+            .iter()
+            .map(|event| event.employee_id)
+            .collect();
+        if moving != assigned {
+            return Err(AppError::conflict(format!(
+                "the employees of job `{id}` changed while they were being reassigned"
+            )));
+        }
+        if !guard.contains_key(&reassignment.to_job_id) {
+            return Err(AppError::conflict(format!(
+                "job `{}` was deleted while employees were being moved to it",
+                reassignment.to_job_id
+            )));
+        }
+
+        for employee_id in moving {
+            if let Some(employee) = employees.get_mut(&employee_id) {
+                employee.job_id = reassignment.to_job_id;
+            }
+        }
+        let mut history = self.history.store.write().await;
+        for event in reassignment.events {
+            history.insert(event.id, event);
+        }
+        Ok(guard.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryJobGradeRepository {
+    store: RwLock<HashMap<Uuid, JobGrade>>,
+}
+
+#[async_trait]
+impl JobGradeRepository for InMemoryJobGradeRepository {
+    async fn insert(&self, grade: JobGrade) -> AppResult<JobGrade> {
+        self.store.write().await.insert(grade.id, grade.clone());
+        Ok(grade)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<JobGrade>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<JobGrade>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|grade| grade.payroll_id == payroll_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, grade: JobGrade) -> AppResult<Option<JobGrade>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&grade.id) {
+            *existing = grade;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryProjectRepository {
+    store: RwLock<HashMap<Uuid, Project>>,
+}
+
+#[async_trait]
+impl ProjectRepository for InMemoryProjectRepository {
+    async fn insert(&self, project: Project) -> AppResult<Project> {
+        self.store.write().await.insert(project.id, project.clone());
+        Ok(project)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Project>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Project>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|project| project.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, project: Project) -> AppResult<Option<Project>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&project.id) {
+            *existing = project;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryLegalEntityRepository {
+    store: RwLock<HashMap<Uuid, LegalEntity>>,
+}
+
+#[async_trait]
+impl LegalEntityRepository for InMemoryLegalEntityRepository {
+    async fn insert(&self, legal_entity: LegalEntity) -> AppResult<LegalEntity> {
+        self.store
+            .write()
+            .await
+            .insert(legal_entity.id, legal_entity.clone());
+        Ok(legal_entity)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<LegalEntity>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<LegalEntity>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|legal_entity| legal_entity.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, legal_entity: LegalEntity) -> AppResult<Option<LegalEntity>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&legal_entity.id) {
+            *existing = legal_entity;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryMembershipRepository {
+    store: RwLock<HashMap<Uuid, Membership>>,
+}
+
+#[async_trait]
+impl MembershipRepository for InMemoryMembershipRepository {
+    async fn insert(&self, membership: Membership) -> AppResult<Membership> {
+        self.store
+            .write()
+            .await
+            .insert(membership.id, membership.clone());
+        Ok(membership)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Membership>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Membership>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|membership| membership.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, membership: Membership) -> AppResult<Option<Membership>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&membership.id) {
+            *existing = membership;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+/// The stores holding what is kept under an employee, which deleting the employee removes too.
+#[derive(Clone, Default)]
+pub struct InMemoryEmployeeRecords {
+    pub dependents: Arc<InMemoryDependentRepository>,
+    pub leave: Arc<InMemoryLeaveRepository>,
+    pub timesheets: Arc<InMemoryTimesheetRepository>,
+    pub overtime: Arc<InMemoryOvertimeRepository>,
+    pub self_service_tokens: Arc<InMemorySelfServiceTokenRepository>,
+    pub email_verifications: Arc<InMemoryEmailVerificationRepository>,
+    pub blobs: Arc<InMemoryBlobStore>,
+}
+
+impl InMemoryEmployeeRecords {
+    async fn remove(&self, removed: &[Uuid]) {
+        let held = |employee_id: &Uuid| removed.contains(employee_id);
+        self.dependents
+            .store
+            .write()
+            .await
+            .retain(|_, dependent| !held(&dependent.employee_id));
+        self.leave
+            .requests
+            .write()
+            .await
+            .retain(|_, request| !held(&request.employee_id));
+        self.leave
+            .balances
+            .write()
+            .await
+            .retain(|(employee_id, _), _| !held(employee_id));
+        self.leave
+            .adjustments
+            .write()
+            .await
+            .retain(|adjustment| !held(&adjustment.employee_id));
+        self.timesheets
+            .store
+            .write()
+            .await
+            .retain(|_, entry| !held(&entry.employee_id));
+        self.timesheets
+            .periods
+            .write()
+            .await
+            .retain(|_, period| !held(&period.employee_id));
+        self.overtime
+            .requests
+            .write()
+            .await
+            .retain(|_, request| !held(&request.employee_id));
+        self.self_service_tokens
+            .store
+            .write()
+            .await
+            .retain(|_, token| !held(&token.employee_id));
+        self.email_verifications
+            .store
+            .write()
+            .await
+            .retain(|employee_id, _| !held(employee_id));
+        let mut blobs = self.blobs.store.write().await;
+        for employee_id in removed {
+            blobs.remove(&Employee::photo_key(*employee_id));
+        }
+    }
+}
+
+/// Clears the positions held by the removed employees and drops them from every position's
+/// assignment history.
+fn forget_position_holders(positions: &mut HashMap<Uuid, Position>, removed: &[Uuid]) {
+    for position in positions.values_mut() {
+        if position
+            .employee_id
+            .is_some_and(|employee_id| removed.contains(&employee_id))
+        {
+            position.employee_id = None;
+        }
+        position
+            .history
+            .retain(|assignment| !removed.contains(&assignment.employee_id));
+    }
+}
+
+/// Shares the position and employment history stores, so a termination changes all three
+/// under one set of locks, and the division map and [`InMemoryEmployeeRecords`], so a delete
+/// clears everything kept under the employee along with it. [`Default`] gives it stores of its
+/// own.
+#[derive(Default)]
+pub struct InMemoryEmployeeRepository {
+    store: RwLock<HashMap<Uuid, Employee>>,
+    divisions: Arc<RwLock<HashMap<Uuid, Division>>>,
+    positions: Arc<InMemoryPositionRepository>,
+    history: Arc<InMemoryEmploymentHistoryRepository>,
+    records: InMemoryEmployeeRecords,
+}
+
+impl InMemoryEmployeeRepository {
+    pub fn new(
+        positions: Arc<InMemoryPositionRepository>,
+        history: Arc<InMemoryEmploymentHistoryRepository>,
+        records: InMemoryEmployeeRecords,
+    ) -> Self {
+        Self {
+            store: RwLock::new(HashMap::new()),
+            divisions: Arc::default(),
+            positions,
+            history,
+            records,
+        }
+    }
+
+    /// Removes everything kept under the removed employees outside the employee, division and
+    /// position maps, whose locks the caller holds.
+    async fn remove_records(&self, removed: &[Uuid]) {
+        self.history
+            .store
+            .write()
+            .await
+            .retain(|_, event| !removed.contains(&event.employee_id));
+        self.records.remove(removed).await;
+    }
+}
+
+#[async_trait]
+impl EmployeeRepository for InMemoryEmployeeRepository {
+    async fn insert(
+        &self,
+        id: Uuid,
+        id_number: String,
+        external_id: Option<String>,
+        last_name: String,
+        first_name: String,
+        address: String,
+        phone: String,
+        email: Option<String>,
+        place_of_birth: String,
+        date_of_birth: chrono::NaiveDate,
+        nationality: String,
+        marital_status: String,
+        gender: String,
+        hire_date: chrono::NaiveDate,
+        termination_date: Option<chrono::NaiveDate>,
+        probation_end_date: Option<chrono::NaiveDate>,
+        contract_end_date: Option<chrono::NaiveDate>,
+        work_permit: Option<WorkPermit>,
+        clasification: String,
+        job_id: Uuid,
+        payment_instructions: Vec<PaymentInstruction>,
+        status: EmployeeStatus,
+        hours: i32,
+        schedule_id: Option<Uuid>,
+        division_id: Uuid,
+        payroll_id: Uuid,
+        supervisor_id: Option<Uuid>,
+        custom_fields: BTreeMap<String, serde_json::Value>,
+    ) -> AppResult<Employee> {
+        let employee = Employee::new(
+            id,
+            id_number,
+            external_id,
+            last_name,
+            first_name,
+            address,
+            phone,
+            email,
+            None,
+            place_of_birth,
+            date_of_birth,
+            nationality,
+            marital_status,
+            gender,
+            hire_date,
+            termination_date,
+            probation_end_date,
+            contract_end_date,
+            work_permit,
+            clasification,
+            job_id,
+            payment_instructions,
+            status,
+            hours,
+            schedule_id,
+            division_id,
+            payroll_id,
+            supervisor_id,
+            None,
+            custom_fields,
+        );
+        self.store
+            .write()
+            .await
+            .insert(employee.id, employee.clone());
+        Ok(employee)
+    }
+
+    async fn insert_many(&self, employees: Vec<Employee>) -> AppResult<Vec<Employee>> {
+        let mut store = self.store.write().await;
+        for employee in &employees {
+            store.insert(employee.id, employee.clone());
+        }
+        Ok(employees)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Employee>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_latest(&self, id: Uuid) -> AppResult<Option<Employee>> {
+        self.fetch(id).await
+    }
+
+    async fn fetch_by_division(&self, division_id: Uuid) -> AppResult<Vec<Employee>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| employee.division_id == division_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_by_divisions(&self, division_ids: Vec<Uuid>) -> AppResult<Vec<Employee>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| division_ids.contains(&employee.division_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_by_supervisor(&self, supervisor_id: Uuid) -> AppResult<Vec<Employee>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| employee.supervisor_id == Some(supervisor_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_by_job(&self, job_id: Uuid) -> AppResult<Vec<Employee>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| employee.job_id == job_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn count_active_by_job(&self, job_id: Uuid) -> AppResult<u32> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| {
+                employee.job_id == job_id && employee.status != EmployeeStatus::Terminated
+            })
+            .count() as u32)
+    }
+
+    async fn fetch_by_email(
+        &self,
+        email: &str,
+        payroll_ids: Vec<Uuid>,
+    ) -> AppResult<Vec<Employee>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| {
+                employee.email.as_deref() == Some(email)
+                    && payroll_ids.contains(&employee.payroll_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_by_bank(&self, bank_id: Uuid) -> AppResult<Vec<Employee>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| {
+                employee
+                    .payment_instructions
+                    .iter()
+                    .any(|instruction| instruction.bank_id == bank_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_page_by_job(
+        &self,
+        job_id: Uuid,
+        page: PageRequest,
+    ) -> AppResult<Page<Employee>> {
+        let mut employees: Vec<Employee> = self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| {
+                employee.job_id == job_id && employee.status != EmployeeStatus::Terminated
+            })
+            .cloned()
+            .collect();
+        employees.sort_by(|a, b| {
+            a.last_name
+                .cmp(&b.last_name)
+                .then_with(|| a.first_name.cmp(&b.first_name))
+        });
+        Ok(page.slice(employees))
+    }
+
+    async fn fetch_page(
+        &self,
+        filter: EmployeePageFilter,
+        page: PageRequest,
+    ) -> AppResult<Page<Employee>> {
+        let mut employees: Vec<Employee> = self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|employee| filter.matches(employee))
+            .cloned()
+            .collect();
+        employees.sort_by(|a, b| {
+            a.last_name
+                .cmp(&b.last_name)
+                .then_with(|| a.first_name.cmp(&b.first_name))
+        });
+        Ok(page.slice(employees))
+    }
+
+    async fn update(&self, id: Uuid, updates: UpdateEmployeeParams) -> AppResult<Option<Employee>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            if let Some(id_number) = updates.id_number {
+                existing.id_number = id_number;
+            }
+            if let Some(external_id) = updates.external_id {
+                existing.external_id = Some(external_id);
+            }
+            if let Some(last_name) = updates.last_name {
+                existing.last_name = last_name;
+            }
+            if let Some(first_name) = updates.first_name {
+                existing.first_name = first_name;
+            }
+            if let Some(address) = updates.address {
+                existing.address = address;
+            }
+            if let Some(phone) = updates.phone {
+                existing.phone = phone;
+            }
+            if let Some(email) = updates.email {
+                existing.email = email;
+            }
+            if let Some(email_verified_at) = updates.email_verified_at {
+                existing.email_verified_at = email_verified_at;
+            }
+            if let Some(place_of_birth) = updates.place_of_birth {
+                existing.place_of_birth = place_of_birth;
+            }
+            if let Some(date_of_birth) = updates.date_of_birth {
+                existing.date_of_birth = date_of_birth;
+            }
+            if let Some(nationality) = updates.nationality {
+                existing.nationality = nationality;
+            }
+            if let Some(marital_status) = updates.marital_status {
+                existing.marital_status = marital_status;
+            }
+            if let Some(gender) = updates.gender {
+                existing.gender = gender;
+            }
+            if let Some(hire_date) = updates.hire_date {
+                existing.hire_date = hire_date;
+            }
+            if let Some(termination_date) = updates.termination_date {
+                existing.termination_date = termination_date;
+            }
+            if let Some(probation_end_date) = updates.probation_end_date {
+                existing.probation_end_date = probation_end_date;
+            }
+            if let Some(contract_end_date) = updates.contract_end_date {
+                existing.contract_end_date = contract_end_date;
+            }
+            if let Some(work_permit) = updates.work_permit {
+                existing.work_permit = work_permit;
+            }
+            if let Some(clasification) = updates.clasification {
+                existing.clasification = clasification;
+            }
+            if let Some(job_id) = updates.job_id {
+                existing.job_id = job_id;
+            }
+            if let Some(payment_instructions) = updates.payment_instructions {
+                existing.payment_instructions = payment_instructions;
+            }
+            if let Some(status) = updates.status {
+                existing.status = status;
+            }
+            if let Some(hours) = updates.hours {
+                existing.hours = hours;
+            }
+            if let Some(schedule_id) = updates.schedule_id {
+                existing.schedule_id = schedule_id;
+            }
+            if let Some(supervisor_id) = updates.supervisor_id {
+                existing.supervisor_id = supervisor_id;
+            }
+            if let Some(custom_fields) = updates.custom_fields {
+                existing.custom_fields = custom_fields;
+            }
+
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn set_photo_url(
+        &self,
+        id: Uuid,
+        photo_url: Option<String>,
+    ) -> AppResult<Option<Employee>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            existing.photo_url = photo_url;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn terminate(&self, id: Uuid, event: EmploymentEvent) -> AppResult<Option<Employee>> {
+        let mut guard = self.store.write().await;
+        let Some(existing) = guard.get_mut(&id) else {
+            return Ok(None);
+        };
+        if existing.status == EmployeeStatus::Terminated {
+            return Err(AppError::conflict(format!(
+                "employee `{id}` is already terminated"
+            )));
+        }
+
+        let end_date = event.effective_date;
+        for position in self
+            .positions
+            .store
+            .write()
+            .await
+            .values_mut()
+            .filter(|position| position.employee_id == Some(id))
+        {
+            for entry in position
+                .history
+                .iter_mut()
+                .filter(|entry| entry.end_date.is_none())
+            {
+                entry.end_date = Some(end_date.max(entry.start_date));
+            }
+            position.employee_id = None;
+        }
+        self.history.store.write().await.insert(event.id, event);
+
+        existing.status = EmployeeStatus::Terminated;
+        existing.termination_date = Some(end_date);
+        Ok(Some(existing.clone()))
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let mut divisions = self.divisions.write().await;
+        let mut employees = self.store.write().await;
+        let mut positions = self.positions.store.write().await;
+        if employees.remove(&id).is_none() {
+            return Ok(false);
+        }
+
+        for employee in employees.values_mut() {
+            if employee.supervisor_id == Some(id) {
+                employee.supervisor_id = None;
+            }
+        }
+        for division in divisions.values_mut() {
+            if division.manager_employee_id == Some(id) {
+                division.manager_employee_id = None;
+            }
+        }
+        forget_position_holders(&mut positions, &[id]);
+        self.remove_records(&[id]).await;
+        Ok(true)
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryDependentRepository {
+    store: RwLock<HashMap<Uuid, Dependent>>,
+}
+
+#[async_trait]
+impl DependentRepository for InMemoryDependentRepository {
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        relationship: String,
+        date_of_birth: chrono::NaiveDate,
+        employee_id: Uuid,
+    ) -> AppResult<Dependent> {
+        let dependent = Dependent::new(id, name, relationship, date_of_birth, employee_id);
+        self.store
+            .write()
+            .await
+            .insert(dependent.id, dependent.clone());
+        Ok(dependent)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Dependent>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<Dependent>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|dependent| dependent.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        relationship: Option<String>,
+        date_of_birth: Option<chrono::NaiveDate>,
+    ) -> AppResult<Option<Dependent>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            if let Some(name) = name {
+                existing.name = name;
+            }
+            if let Some(relationship) = relationship {
+                existing.relationship = relationship;
+            }
+            if let Some(date_of_birth) = date_of_birth {
+                existing.date_of_birth = date_of_birth;
+            }
+
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryOrganizationDeletionRepository {
+    store: RwLock<HashMap<Uuid, OrganizationDeletion>>,
+}
+
+#[async_trait]
+impl OrganizationDeletionRepository for InMemoryOrganizationDeletionRepository {
+    async fn insert(
+        &self,
+        organization_id: Uuid,
+        requested_at: DateTime<Utc>,
+        scheduled_for: DateTime<Utc>,
+    ) -> AppResult<OrganizationDeletion> {
+        let deletion = OrganizationDeletion::new(organization_id, requested_at, scheduled_for);
+        self.store
+            .write()
+            .await
+            .insert(organization_id, deletion.clone());
+        Ok(deletion)
+    }
+
+    async fn fetch(&self, organization_id: Uuid) -> AppResult<Option<OrganizationDeletion>> {
+        Ok(self.store.read().await.get(&organization_id).cloned())
+    }
+
+    async fn fetch_due(&self, now: DateTime<Utc>) -> AppResult<Vec<OrganizationDeletion>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|deletion| deletion.is_due(now))
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, organization_id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&organization_id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryBlobStore {
+    store: RwLock<HashMap<String, Blob>>,
+}
+
+#[async_trait]
+impl BlobStore for InMemoryBlobStore {
+    async fn put(&self, key: &str, blob: Blob) -> AppResult<()> {
+        self.store.write().await.insert(key.to_string(), blob);
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> AppResult<Option<Blob>> {
+        Ok(self.store.read().await.get(key).cloned())
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(key).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryImportProfileRepository {
+    store: RwLock<HashMap<Uuid, ImportProfile>>,
+}
+
+#[async_trait]
+impl ImportProfileRepository for InMemoryImportProfileRepository {
+    async fn insert(&self, profile: ImportProfile) -> AppResult<ImportProfile> {
+        self.store.write().await.insert(profile.id, profile.clone());
+        Ok(profile)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<ImportProfile>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<ImportProfile>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|profile| profile.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, profile: ImportProfile) -> AppResult<Option<ImportProfile>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&profile.id) {
+            *existing = profile;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryCustomFieldRepository {
+    store: RwLock<HashMap<Uuid, CustomFieldDefinition>>,
+}
+
+#[async_trait]
+impl CustomFieldRepository for InMemoryCustomFieldRepository {
+    async fn insert(&self, definition: CustomFieldDefinition) -> AppResult<CustomFieldDefinition> {
+        self.store
+            .write()
+            .await
+            .insert(definition.id, definition.clone());
+        Ok(definition)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<CustomFieldDefinition>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_organization(
+        &self,
+        organization_id: Uuid,
+    ) -> AppResult<Vec<CustomFieldDefinition>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|definition| definition.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        updates: UpdateCustomFieldParams,
+    ) -> AppResult<Option<CustomFieldDefinition>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&id) {
+            if let Some(name) = updates.name {
+                existing.name = name;
+            }
+            if let Some(field_type) = updates.field_type {
+                existing.field_type = field_type;
+            }
+            if let Some(required) = updates.required {
+                existing.required = required;
+            }
+
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryPositionRepository {
+    store: RwLock<HashMap<Uuid, Position>>,
+}
+
+#[async_trait]
+impl PositionRepository for InMemoryPositionRepository {
+    async fn insert(&self, position: Position) -> AppResult<Position> {
+        self.store
+            .write()
+            .await
+            .insert(position.id, position.clone());
+        Ok(position)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Position>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_division(&self, division_id: Uuid) -> AppResult<Vec<Position>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|position| position.division_id == division_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<Position>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|position| position.employee_id == Some(employee_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_by_assignee(&self, employee_id: Uuid) -> AppResult<Vec<Position>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|position| {
+                position
+                    .history
+                    .iter()
+                    .any(|entry| entry.employee_id == employee_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, position: Position) -> AppResult<Option<Position>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&position.id) {
+            *existing = position;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryEmploymentHistoryRepository {
+    store: RwLock<HashMap<Uuid, EmploymentEvent>>,
+}
+
+#[async_trait]
+impl EmploymentHistoryRepository for InMemoryEmploymentHistoryRepository {
+    async fn insert(&self, event: EmploymentEvent) -> AppResult<EmploymentEvent> {
+        self.store.write().await.insert(event.id, event.clone());
+        Ok(event)
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<EmploymentEvent>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|event| event.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryWorkScheduleRepository {
+    store: RwLock<HashMap<Uuid, WorkSchedule>>,
+}
+
+#[async_trait]
+impl WorkScheduleRepository for InMemoryWorkScheduleRepository {
+    async fn insert(&self, schedule: WorkSchedule) -> AppResult<WorkSchedule> {
+        self.store
+            .write()
+            .await
+            .insert(schedule.id, schedule.clone());
+        Ok(schedule)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<WorkSchedule>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<WorkSchedule>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|schedule| schedule.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, schedule: WorkSchedule) -> AppResult<Option<WorkSchedule>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&schedule.id) {
+            *existing = schedule;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryLeaveRepository {
+    requests: RwLock<HashMap<Uuid, LeaveRequest>>,
+    balances: RwLock<HashMap<(Uuid, LeaveType), LeaveBalance>>,
+    pay_policies: RwLock<HashMap<(Uuid, LeaveType), LeavePayPolicy>>,
+    carryover_rules: RwLock<HashMap<(Uuid, LeaveType), LeaveCarryoverRule>>,
+    adjustments: RwLock<Vec<LeaveBalanceAdjustment>>,
+    blackouts: RwLock<HashMap<Uuid, LeaveBlackout>>,
+}
+
+#[async_trait]
+impl LeaveRepository for InMemoryLeaveRepository {
+    async fn insert_request(&self, request: LeaveRequest) -> AppResult<LeaveRequest> {
+        self.requests
+            .write()
+            .await
+            .insert(request.id, request.clone());
+        Ok(request)
+    }
+
+    async fn fetch_request(&self, id: Uuid) -> AppResult<Option<LeaveRequest>> {
+        Ok(self.requests.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_latest_request(&self, id: Uuid) -> AppResult<Option<LeaveRequest>> {
+        self.fetch_request(id).await
+    }
+
+    async fn fetch_requests_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<LeaveRequest>> {
+        Ok(self
+            .requests
+            .read()
+            .await
+            .values()
+            .filter(|request| request.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_request(&self, request: LeaveRequest) -> AppResult<Option<LeaveRequest>> {
+        let mut guard = self.requests.write().await;
+        if let Some(existing) = guard.get_mut(&request.id) {
+            *existing = request;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn decide_request(&self, request: LeaveRequest) -> AppResult<Option<LeaveRequest>> {
+        let mut requests = self.requests.write().await;
+        let Some(existing) = requests
+            .get_mut(&request.id)
+            .filter(|existing| existing.status == LeaveRequestStatus::Pending)
+        else {
+            return Ok(None);
+        };
+
+        if request.status == LeaveRequestStatus::Approved && request.leave_type.carries_balance() {
+            let mut balances = self.balances.write().await;
+            let balance = balances.get_mut(&(request.employee_id, request.leave_type));
+            let available = balance
+                .as_ref()
+                .map_or(0.0, |balance| balance.available_days);
+            if request.days > available + DAY_EPSILON {
+                return Err(AppError::conflict(format!(
+                    "only {available} {} days remain; the request needs {}",
+                    request.leave_type.as_str(),
+                    request.days
+                )));
+            }
+            if let Some(balance) = balance {
+                balance.available_days = (available - request.days).max(0.0);
+                balance.carried_over_days = (balance.carried_over_days - request.days).max(0.0);
+            }
+        }
+
+        *existing = request;
+        Ok(Some(existing.clone()))
+    }
+
+    async fn fetch_balances(&self, employee_id: Uuid) -> AppResult<Vec<LeaveBalance>> {
+        Ok(self
+            .balances
+            .read()
+            .await
+            .values()
+            .filter(|balance| balance.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_latest_balance(
+        &self,
+        employee_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeaveBalance>> {
+        Ok(self
+            .balances
+            .read()
+            .await
+            .values()
+            .find(|balance| balance.employee_id == employee_id && balance.leave_type == leave_type)
+            .cloned())
+    }
+
+    async fn upsert_balance(&self, balance: LeaveBalance) -> AppResult<LeaveBalance> {
+        self.balances
+            .write()
+            .await
+            .insert((balance.employee_id, balance.leave_type), balance.clone());
+        Ok(balance)
+    }
+
+    async fn fetch_pay_policy(
+        &self,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeavePayPolicy>> {
+        Ok(self
+            .pay_policies
+            .read()
+            .await
+            .get(&(payroll_id, leave_type))
+            .cloned())
+    }
+
+    async fn upsert_pay_policy(&self, policy: LeavePayPolicy) -> AppResult<LeavePayPolicy> {
+        self.pay_policies
+            .write()
+            .await
+            .insert((policy.payroll_id, policy.leave_type), policy.clone());
+        Ok(policy)
+    }
+
+    async fn fetch_carryover_rule(
+        &self,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeaveCarryoverRule>> {
+        Ok(self
+            .carryover_rules
+            .read()
+            .await
+            .get(&(payroll_id, leave_type))
+            .cloned())
+    }
+
+    async fn fetch_carryover_rules(&self) -> AppResult<Vec<LeaveCarryoverRule>> {
+        Ok(self
+            .carryover_rules
+            .read()
+            .await
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    async fn upsert_carryover_rule(
+        &self,
+        rule: LeaveCarryoverRule,
+    ) -> AppResult<LeaveCarryoverRule> {
+        self.carryover_rules
+            .write()
+            .await
+            .insert((rule.payroll_id, rule.leave_type), rule.clone());
+        Ok(rule)
+    }
+
+    async fn insert_adjustment(
+        &self,
+        adjustment: LeaveBalanceAdjustment,
+    ) -> AppResult<LeaveBalanceAdjustment> {
+        self.adjustments.write().await.push(adjustment.clone());
+        Ok(adjustment)
+    }
+
+    async fn fetch_adjustments_by_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<LeaveBalanceAdjustment>> {
+        Ok(self
+            .adjustments
+            .read()
+            .await
+            .iter()
+            .filter(|adjustment| adjustment.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn insert_blackout(&self, blackout: LeaveBlackout) -> AppResult<LeaveBlackout> {
+        self.blackouts
+            .write()
+            .await
+            .insert(blackout.id, blackout.clone());
+        Ok(blackout)
+    }
+
+    async fn fetch_blackouts_by_division(
+        &self,
+        division_id: Uuid,
+    ) -> AppResult<Vec<LeaveBlackout>> {
+        Ok(self
+            .blackouts
+            .read()
+            .await
+            .values()
+            .filter(|blackout| blackout.division_id == division_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_blackout(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.blackouts.write().await.remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryTimesheetRepository {
+    store: RwLock<HashMap<Uuid, TimesheetEntry>>,
+    periods: RwLock<HashMap<Uuid, TimesheetPeriod>>,
+}
+
+#[async_trait]
+impl TimesheetRepository for InMemoryTimesheetRepository {
+    async fn insert(&self, entry: TimesheetEntry) -> AppResult<TimesheetEntry> {
+        self.store.write().await.insert(entry.id, entry.clone());
+        Ok(entry)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<TimesheetEntry>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<TimesheetEntry>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|entry| entry.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_by_project(&self, project_id: Uuid) -> AppResult<Vec<TimesheetEntry>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|entry| entry.project_id == Some(project_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, entry: TimesheetEntry) -> AppResult<Option<TimesheetEntry>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&entry.id) {
+            *existing = entry;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&id).is_some())
+    }
+
+    async fn insert_period(&self, period: TimesheetPeriod) -> AppResult<TimesheetPeriod> {
+        self.periods.write().await.insert(period.id, period.clone());
+        Ok(period)
+    }
+
+    async fn fetch_period(&self, id: Uuid) -> AppResult<Option<TimesheetPeriod>> {
+        Ok(self.periods.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_periods_by_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<TimesheetPeriod>> {
+        Ok(self
+            .periods
+            .read()
+            .await
+            .values()
+            .filter(|period| period.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_period(&self, period: TimesheetPeriod) -> AppResult<Option<TimesheetPeriod>> {
+        let mut guard = self.periods.write().await;
+        if let Some(existing) = guard.get_mut(&period.id) {
+            *existing = period;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryPunchPolicyRepository {
+    store: RwLock<HashMap<Uuid, PunchPolicy>>,
+}
+
+#[async_trait]
+impl PunchPolicyRepository for InMemoryPunchPolicyRepository {
+    async fn fetch(&self, payroll_id: Uuid) -> AppResult<Option<PunchPolicy>> {
+        Ok(self.store.read().await.get(&payroll_id).cloned())
+    }
+
+    async fn upsert(&self, policy: PunchPolicy) -> AppResult<PunchPolicy> {
+        self.store
+            .write()
+            .await
+            .insert(policy.payroll_id, policy.clone());
+        Ok(policy)
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryOrganizationSettingsRepository {
+    store: RwLock<HashMap<Uuid, OrganizationSettings>>,
+}
+
+#[async_trait]
+impl OrganizationSettingsRepository for InMemoryOrganizationSettingsRepository {
+    async fn fetch(&self, organization_id: Uuid) -> AppResult<Option<OrganizationSettings>> {
+        Ok(self.store.read().await.get(&organization_id).cloned())
+    }
+
+    async fn upsert(&self, settings: OrganizationSettings) -> AppResult<OrganizationSettings> {
+        self.store
+            .write()
+            .await
+            .insert(settings.organization_id, settings.clone());
+        Ok(settings)
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryOvertimeRepository {
+    policies: RwLock<HashMap<Uuid, OvertimePolicy>>,
+    requests: RwLock<HashMap<Uuid, OvertimeRequest>>,
+}
+
+#[async_trait]
+impl OvertimeRepository for InMemoryOvertimeRepository {
+    async fn fetch_policy(&self, payroll_id: Uuid) -> AppResult<Option<OvertimePolicy>> {
+        Ok(self.policies.read().await.get(&payroll_id).cloned())
+    }
+
+    async fn upsert_policy(&self, policy: OvertimePolicy) -> AppResult<OvertimePolicy> {
+        self.policies
+            .write()
+            .await
+            .insert(policy.payroll_id, policy.clone());
+        Ok(policy)
+    }
+
+    async fn insert_request(&self, request: OvertimeRequest) -> AppResult<OvertimeRequest> {
+        self.requests
+            .write()
+            .await
+            .insert(request.id, request.clone());
+        Ok(request)
+    }
+
+    async fn fetch_request(&self, id: Uuid) -> AppResult<Option<OvertimeRequest>> {
+        Ok(self.requests.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_requests_by_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<OvertimeRequest>> {
+        Ok(self
+            .requests
+            .read()
+            .await
+            .values()
+            .filter(|request| request.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_request(&self, request: OvertimeRequest) -> AppResult<Option<OvertimeRequest>> {
+        let mut guard = self.requests.write().await;
+        if let Some(existing) = guard.get_mut(&request.id) {
+            *existing = request;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+}
+
+#[derive(Default)]
+pub struct InMemorySelfServiceTokenRepository {
+    store: RwLock<HashMap<Uuid, SelfServiceToken>>,
+}
+
+#[async_trait]
+impl SelfServiceTokenRepository for InMemorySelfServiceTokenRepository {
+    async fn insert(&self, token: SelfServiceToken) -> AppResult<SelfServiceToken> {
+        self.store.write().await.insert(token.id, token.clone());
+        Ok(token)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<SelfServiceToken>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_secret_hash(&self, secret_hash: &str) -> AppResult<Option<SelfServiceToken>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .find(|token| token.secret_hash == secret_hash)
+            .cloned())
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<SelfServiceToken>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|token| token.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, token: SelfServiceToken) -> AppResult<Option<SelfServiceToken>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&token.id) {
+            *existing = token;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> AppResult<Option<SelfServiceToken>> {
+        let mut guard = self.store.write().await;
+        match guard.get_mut(&id) {
+            Some(existing) if existing.revoked_at.is_none() => {
+                existing.last_used_at = Some(used_at);
+                Ok(Some(existing.clone()))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryEmailVerificationRepository {
+    store: RwLock<HashMap<Uuid, EmailVerification>>,
+}
+
+#[async_trait]
+impl EmailVerificationRepository for InMemoryEmailVerificationRepository {
+    async fn upsert(&self, verification: EmailVerification) -> AppResult<EmailVerification> {
+        self.store
+            .write()
+            .await
+            .insert(verification.employee_id, verification.clone());
+        Ok(verification)
+    }
+
+    async fn fetch(&self, employee_id: Uuid) -> AppResult<Option<EmailVerification>> {
+        Ok(self.store.read().await.get(&employee_id).cloned())
+    }
+
+    async fn delete(&self, employee_id: Uuid) -> AppResult<bool> {
+        Ok(self.store.write().await.remove(&employee_id).is_some())
+    }
+}
+
+/// Email sender that keeps every message so tests can inspect it.
+#[derive(Default)]
+pub struct InMemoryOutbox {
+    sent: RwLock<Vec<EmailMessage>>,
+    failing: AtomicBool,
+}
+
+impl InMemoryOutbox {
+    pub async fn sent(&self) -> Vec<EmailMessage> {
+        self.sent.read().await.clone()
+    }
+
+    /// Makes every send fail, as an unreachable mail relay would, until reset.
+    pub fn set_failing(&self, failing: bool) {
+        self.failing.store(failing, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl EmailSender for InMemoryOutbox {
+    async fn send(&self, message: EmailMessage) -> AppResult<()> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(AppError::internal("mail relay unreachable"));
+        }
+        self.sent.write().await.push(message);
+        Ok(())
+    }
+}
+
+/// Stands in for the database in readiness checks; always up unless told otherwise.
+#[derive(Default)]
+pub struct InMemoryDatabaseProbe {
+    failing: AtomicBool,
+}
+
+impl InMemoryDatabaseProbe {
+    /// Makes every ping fail, as an unreachable database would, until reset.
+    pub fn set_failing(&self, failing: bool) {
+        self.failing.store(failing, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl DependencyProbe for InMemoryDatabaseProbe {
+    fn name(&self) -> &str {
+        DATABASE_DEPENDENCY
+    }
+
+    async fn ping(&self) -> AppResult<()> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(AppError::internal("database unreachable"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryLeaseRepository {
+    store: RwLock<HashMap<String, (String, DateTime<Utc>)>>,
+}
+
+#[async_trait]
+impl LeaseRepository for InMemoryLeaseRepository {
+    async fn acquire(
+        &self,
+        name: &str,
+        holder: &str,
+        now: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> AppResult<bool> {
+        let mut guard = self.store.write().await;
+        if let Some((current, expires_at)) = guard.get(name)
+            && current != holder
+            && *expires_at > now
+        {
+            return Ok(false);
+        }
+
+        guard.insert(name.to_string(), (holder.to_string(), until));
+        Ok(true)
+    }
+
+    async fn release(&self, name: &str, holder: &str) -> AppResult<()> {
+        let mut guard = self.store.write().await;
+        if guard
+            .get(name)
+            .is_some_and(|(current, _)| current == holder)
+        {
+            guard.remove(name);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemorySalaryAdjustmentRepository {
+    store: RwLock<HashMap<Uuid, SalaryAdjustmentBatch>>,
+}
+
+#[async_trait]
+impl SalaryAdjustmentRepository for InMemorySalaryAdjustmentRepository {
+    async fn insert(&self, batch: SalaryAdjustmentBatch) -> AppResult<SalaryAdjustmentBatch> {
+        self.store.write().await.insert(batch.id, batch.clone());
+        Ok(batch)
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<SalaryAdjustmentBatch>> {
+        Ok(self.store.read().await.get(&id).cloned())
+    }
+
+    async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<SalaryAdjustmentBatch>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|batch| batch.payroll_id == payroll_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn fetch_due(&self, today: NaiveDate) -> AppResult<Vec<SalaryAdjustmentBatch>> {
+        Ok(self
+            .store
+            .read()
+            .await
+            .values()
+            .filter(|batch| batch.is_due(today))
+            .cloned()
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        batch: SalaryAdjustmentBatch,
+    ) -> AppResult<Option<SalaryAdjustmentBatch>> {
+        let mut guard = self.store.write().await;
+        if let Some(existing) = guard.get_mut(&batch.id) {
+            *existing = batch;
+            return Ok(Some(existing.clone()));
+        }
+
+        Ok(None)
+    }
+}
+
+impl AppState {
+    /// Wires every service over fresh in-memory repositories, sending email to `outbox` and
+    /// checking readiness against `database_probe`.
+    pub fn in_memory(
+        outbox: Arc<InMemoryOutbox>,
+        database_probe: Arc<InMemoryDatabaseProbe>,
+    ) -> Self {
+        let integration_monitor = Arc::new(IntegrationMonitor::default());
+        integration_monitor.register(EMAIL_INTEGRATION, None);
+        let email_sender: Arc<dyn EmailSender> = Arc::new(MonitoredEmailSender::new(
+            Arc::clone(&outbox) as Arc<dyn EmailSender>,
+            Arc::clone(&integration_monitor),
+        ));
+
+        let organization_repository: Arc<dyn OrganizationRepository> =
+            Arc::new(InMemoryOrganizationRepository::default());
+        let records = InMemoryEmployeeRecords::default();
+        let blob_store: Arc<dyn BlobStore> = Arc::clone(&records.blobs) as _;
+        let cache_store: Arc<dyn CacheStore> = Arc::new(MemoryCacheStore::default());
+        let organization_service = Arc::new(OrganizationService::new(
+            organization_repository,
+            Arc::clone(&blob_store),
+            Arc::clone(&cache_store),
+        ));
+        let organization_settings_repository: Arc<dyn OrganizationSettingsRepository> =
+            Arc::new(InMemoryOrganizationSettingsRepository::default());
+        let organization_settings_service = Arc::new(OrganizationSettingsService::new(
+            organization_settings_repository,
+            Arc::clone(&organization_service),
+        ));
+
+        let payroll_repository: Arc<dyn PayrollRepository> =
+            Arc::new(InMemoryPayrollRepository::default());
+        let legal_entity_repository: Arc<dyn LegalEntityRepository> =
+            Arc::new(InMemoryLegalEntityRepository::default());
+        let payroll_service = Arc::new(PayrollService::new(
+            payroll_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&legal_entity_repository),
+            cache_store,
+        ));
+
+        let positions = Arc::new(InMemoryPositionRepository::default());
+        let history = Arc::new(InMemoryEmploymentHistoryRepository::default());
+        let employees = Arc::new(InMemoryEmployeeRepository::new(
+            Arc::clone(&positions),
+            Arc::clone(&history),
+            records.clone(),
+        ));
+        let employment_history_repository: Arc<dyn EmploymentHistoryRepository> =
+            Arc::clone(&history) as _;
+        let employee_repository: Arc<dyn EmployeeRepository> = Arc::clone(&employees) as _;
+        let position_repository: Arc<dyn PositionRepository> = Arc::clone(&positions) as _;
+
+        let division_repository: Arc<dyn DivisionRepository> = Arc::new(
+            InMemoryDivisionRepository::new(Arc::clone(&employees), positions),
+        );
+        let division_service = Arc::new(DivisionService::new(
+            division_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&organization_service),
+            Arc::clone(&employee_repository),
+            Arc::clone(&position_repository),
+        ));
+
+        let job_repository: Arc<dyn JobRepository> = Arc::new(InMemoryJobRepository::new(
+            Arc::clone(&employees),
+            Arc::clone(&history),
+        ));
+        let job_grade_repository: Arc<dyn JobGradeRepository> =
+            Arc::new(InMemoryJobGradeRepository::default());
+        let job_grade_service = Arc::new(JobGradeService::new(
+            job_grade_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&job_repository),
+        ));
+
+        let job_service = Arc::new(JobService::new(
+            job_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&job_grade_service),
+            Arc::clone(&employee_repository),
+            Arc::clone(&employment_history_repository),
+        ));
+
+        let bank_repository: Arc<dyn BankRepository> =
+            Arc::new(InMemoryBankRepository::new(Arc::clone(&employees)));
+        let bank_catalog_repository: Arc<dyn BankCatalogRepository> =
+            Arc::new(InMemoryBankCatalogRepository::default());
+        let bank_service = Arc::new(BankService::new(
+            bank_repository,
+            bank_catalog_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&employee_repository),
+        ));
+
+        let custom_field_repository: Arc<dyn CustomFieldRepository> =
+            Arc::new(InMemoryCustomFieldRepository::default());
+        let custom_field_service = Arc::new(CustomFieldService::new(
+            custom_field_repository,
+            Arc::clone(&organization_service),
+        ));
+
+        let work_schedule_repository: Arc<dyn WorkScheduleRepository> =
+            Arc::new(InMemoryWorkScheduleRepository::default());
+        let work_schedule_service = Arc::new(WorkScheduleService::new(
+            work_schedule_repository,
+            Arc::clone(&organization_service),
+        ));
+
+        let employee_service = Arc::new(EmployeeService::new(
+            Arc::clone(&employee_repository),
+            Arc::clone(&division_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&job_service),
+            Arc::clone(&bank_service),
+            Arc::clone(&blob_store),
+            Arc::clone(&custom_field_service),
+            Arc::clone(&employment_history_repository),
+            Arc::clone(&work_schedule_service),
+            Arc::clone(&organization_settings_service),
+        ));
+
+        let dependent_repository: Arc<dyn DependentRepository> =
+            Arc::clone(&records.dependents) as _;
+        let dependent_service = Arc::new(DependentService::new(
+            dependent_repository,
+            Arc::clone(&employee_service),
+        ));
+
+        let timesheet_repository: Arc<dyn TimesheetRepository> =
+            Arc::clone(&records.timesheets) as _;
+        let project_repository: Arc<dyn ProjectRepository> =
+            Arc::new(InMemoryProjectRepository::default());
+        let project_service = Arc::new(ProjectService::new(
+            project_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&timesheet_repository),
+        ));
+        let timesheet_service = Arc::new(TimesheetService::new(
+            timesheet_repository,
+            Arc::clone(&division_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&job_service),
+            Arc::clone(&project_service),
+        ));
+
+        let leave_repository: Arc<dyn LeaveRepository> = Arc::clone(&records.leave) as _;
+        let leave_service = Arc::new(LeaveService::new(
+            leave_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&division_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&timesheet_service),
+            Arc::clone(&email_sender),
+        ));
+
+        let overtime_repository: Arc<dyn OvertimeRepository> = Arc::clone(&records.overtime) as _;
+        let overtime_service = Arc::new(OvertimeService::new(
+            overtime_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&timesheet_service),
+        ));
+        let punch_policy_repository: Arc<dyn PunchPolicyRepository> =
+            Arc::new(InMemoryPunchPolicyRepository::default());
+        let attendance_import_service = Arc::new(AttendanceImportService::new(
+            punch_policy_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&timesheet_service),
+            Arc::clone(&leave_service),
+        ));
+        let profile_completeness_service = Arc::new(ProfileCompletenessService::new(
+            Arc::clone(&employee_service),
+            Arc::clone(&custom_field_service),
+        ));
+
+        let self_service_token_repository: Arc<dyn SelfServiceTokenRepository> =
+            Arc::clone(&records.self_service_tokens) as _;
+        let self_service_service = Arc::new(SelfServiceService::new(
+            self_service_token_repository,
+            Arc::clone(&employee_service),
+        ));
+
+        let import_profile_repository: Arc<dyn ImportProfileRepository> =
+            Arc::new(InMemoryImportProfileRepository::default());
+        let import_profile_service = Arc::new(ImportProfileService::new(
+            import_profile_repository,
+            Arc::clone(&organization_service),
+        ));
+        let employee_import_service = Arc::new(EmployeeImportService::new(
+            Arc::clone(&employee_service),
+            Arc::clone(&division_service),
+            Arc::clone(&import_profile_service),
+        ));
+        let employee_export_service =
+            Arc::new(EmployeeExportService::new(Arc::clone(&employee_service)));
+
+        let position_service = Arc::new(PositionService::new(
+            Arc::clone(&position_repository),
+            Arc::clone(&division_service),
+            Arc::clone(&job_service),
+            Arc::clone(&employee_service),
+        ));
+
+        let employment_history_service = Arc::new(EmploymentHistoryService::new(
+            employment_history_repository,
+            Arc::clone(&employee_service),
+            Arc::clone(&position_service),
+        ));
+
+        let organization_deletion_repository: Arc<dyn OrganizationDeletionRepository> =
+            Arc::new(InMemoryOrganizationDeletionRepository::default());
+        let organization_deletion_service = Arc::new(OrganizationDeletionService::new(
+            organization_deletion_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&bank_service),
+            chrono::Duration::hours(DEFAULT_DELETION_GRACE_PERIOD_HOURS),
+        ));
+
+        let email_verification_repository: Arc<dyn EmailVerificationRepository> =
+            Arc::clone(&records.email_verifications) as _;
+        let email_verification_service = Arc::new(EmailVerificationService::new(
+            email_verification_repository,
+            Arc::clone(&employee_service),
+            Arc::clone(&email_sender),
+        ));
+
+        let milestone_service = Arc::new(MilestoneService::new(
+            Arc::clone(&employee_service),
+            Arc::clone(&division_service),
+        ));
+
+        let lease_repository: Arc<dyn LeaseRepository> =
+            Arc::new(InMemoryLeaseRepository::default());
+        let lease_service = Arc::new(LeaseService::new(
+            lease_repository,
+            chrono::Duration::minutes(30),
+        ));
+
+        let expiry_service = Arc::new(ExpiryService::new(Arc::clone(&employee_service)));
+
+        let salary_adjustment_repository: Arc<dyn SalaryAdjustmentRepository> =
+            Arc::new(InMemorySalaryAdjustmentRepository::default());
+        let salary_adjustment_service = Arc::new(SalaryAdjustmentService::new(
+            salary_adjustment_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&job_service),
+            Arc::clone(&employee_service),
+        ));
+
+        let pay_equity_service = Arc::new(PayEquityService::new(
+            Arc::clone(&employee_service),
+            Arc::clone(&job_service),
+            Arc::clone(&job_grade_service),
+        ));
+
+        let benchmark_export_service = Arc::new(BenchmarkExportService::new(
+            Arc::clone(&payroll_service),
+            Arc::clone(&division_service),
+            Arc::clone(&job_service),
+            Arc::clone(&job_grade_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&organization_settings_service),
+        ));
+
+        let calendar_service = Arc::new(CalendarService::new(
+            Arc::clone(&organization_settings_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&division_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&leave_service),
+            Arc::clone(&salary_adjustment_service),
+        ));
+
+        let organization_clone_service = Arc::new(OrganizationCloneService::new(
+            Arc::clone(&organization_service),
+            Arc::clone(&organization_settings_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&job_grade_service),
+            Arc::clone(&job_service),
+            Arc::clone(&division_service),
+            Arc::clone(&bank_service),
+        ));
+
+        let membership_repository: Arc<dyn MembershipRepository> =
+            Arc::new(InMemoryMembershipRepository::default());
+        let membership_service = Arc::new(MembershipService::new(
+            membership_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&email_sender),
+        ));
+
+        let legal_entity_service = Arc::new(LegalEntityService::new(
+            legal_entity_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&bank_service),
+        ));
+
+        let readiness_service = Arc::new(ReadinessService::new(vec![
+            Arc::clone(&database_probe) as Arc<dyn DependencyProbe>
+        ]));
+
+        Self::new(
+            organization_service,
+            payroll_service,
+            division_service,
+            job_service,
+            bank_service,
+            employee_service,
+            dependent_service,
+            organization_deletion_service,
+            import_profile_service,
+            employee_import_service,
+            custom_field_service,
+            employee_export_service,
+            position_service,
+            employment_history_service,
+            work_schedule_service,
+            leave_service,
+            timesheet_service,
+            attendance_import_service,
+            profile_completeness_service,
+            self_service_service,
+            email_verification_service,
+            milestone_service,
+            lease_service,
+            job_grade_service,
+            expiry_service,
+            salary_adjustment_service,
+            pay_equity_service,
+            benchmark_export_service,
+            project_service,
+            overtime_service,
+            organization_settings_service,
+            calendar_service,
+            integration_monitor,
+            organization_clone_service,
+            membership_service,
+            legal_entity_service,
+            readiness_service,
+        )
+    }
+}
@@ -0,0 +1,152 @@
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::job_grade::JobGrade,
+    error::{AppError, AppResult},
+    services::job_grade::JobGradeRepository,
+};
+
+const JOB_GRADE_TABLE: &str = "job_grade";
+
+const BY_PAYROLL_QUERY: &str = "SELECT * FROM type::table($table) WHERE payroll_id = $payroll_id";
+
+#[derive(Clone)]
+pub struct SurrealJobGradeRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealJobGradeRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> JobGradeRepository for SurrealJobGradeRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, grade: JobGrade) -> AppResult<JobGrade> {
+        let record: Option<JobGradeRecord> = self
+            .client
+            .create((JOB_GRADE_TABLE, grade.id.to_string()))
+            .content(build_payload(&grade))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created job grade"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<JobGrade>> {
+        let record: Option<JobGradeRecord> = self
+            .reader
+            .select((JOB_GRADE_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<JobGrade>> {
+        let mut response = self
+            .reader
+            .query(BY_PAYROLL_QUERY)
+            .bind(("table", JOB_GRADE_TABLE))
+            .bind(("payroll_id", payroll_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<JobGradeRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, grade: JobGrade) -> AppResult<Option<JobGrade>> {
+        let record: Option<JobGradeRecord> = self
+            .client
+            .update((JOB_GRADE_TABLE, grade.id.to_string()))
+            .content(build_payload(&grade))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<JobGradeRecord> = self
+            .client
+            .delete((JOB_GRADE_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JobGradeRecord {
+    id: Thing,
+    code: String,
+    band: String,
+    level: u32,
+    salary_min: f64,
+    salary_max: f64,
+    payroll_id: String,
+}
+
+fn record_to_domain(record: JobGradeRecord) -> AppResult<JobGrade> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored job grade id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored job grade identifier is not a supported format",
+            ));
+        }
+    };
+    let payroll_id = Uuid::parse_str(&record.payroll_id)
+        .map_err(|_| AppError::internal("stored payroll id is not a UUID"))?;
+
+    Ok(JobGrade::new(
+        id,
+        record.code,
+        record.band,
+        record.level,
+        record.salary_min,
+        record.salary_max,
+        payroll_id,
+    ))
+}
+
+fn build_payload(grade: &JobGrade) -> JsonValue {
+    json!({
+        "code": grade.code,
+        "band": grade.band,
+        "level": grade.level,
+        "salary_min": grade.salary_min,
+        "salary_max": grade.salary_max,
+        "payroll_id": grade.payroll_id,
+    })
+}
+
+pub type SurrealAnyJobGradeRepository = SurrealJobGradeRepository<Any>;
@@ -1,5 +1,5 @@
 use serde::Deserialize;
-use serde_json::{Map, Value as JsonValue, json};
+use serde_json::{Value as JsonValue, json};
 use surrealdb::{
     Connection, Surreal,
     engine::any::Any,
@@ -8,19 +8,58 @@ use surrealdb::{
 use uuid::Uuid;
 
 use crate::{
-    domain::job::Job,
+    domain::job::{HeadcountPolicy, Job, PayBasis},
     error::{AppError, AppResult},
-    services::job::JobRepository,
+    infrastructure::employment_history_repository::event_content,
+    services::job::{JobReassignment, JobRepository},
 };
 
 const JOB_TABLE: &str = "job";
 
+const BY_PAYROLL_QUERY: &str = "SELECT * FROM type::table($table) WHERE payroll_id = $payroll_id";
+
+/// Applies a job delete in one transaction: the employees of a [`JobReassignment`] move to
+/// its target with their events recorded, and the job is deleted. The service checks the
+/// assignments first; they are compared again here, so an employee moved onto or off the job
+/// in between is not left pointing at a deleted record or given a wrong event. A concurrent
+/// create conflicts with the delete on the `referenced_at` write it makes to the job.
+const DELETE_QUERY: &str = "
+BEGIN TRANSACTION;
+{
+    IF array::len((SELECT id FROM type::thing('job', $job_id))) = 0 {
+        RETURN { found: false };
+    };
+    LET $assigned = (SELECT VALUE record::id(id) FROM employee WHERE job_id = $job_id);
+    IF array::len(array::complement($assigned, $moving)) > 0 {
+        RETURN { found: true, assigned: array::len($assigned) };
+    };
+    IF array::len(array::complement($moving, $assigned)) > 0 {
+        RETURN { found: true, conflict: 'moved' };
+    };
+    IF $target_id AND array::len((SELECT id FROM type::thing('job', $target_id))) = 0 {
+        RETURN { found: true, conflict: 'target' };
+    };
+
+    IF $target_id {
+        UPDATE type::thing('job', $target_id) SET referenced_at = time::now();
+        UPDATE employee SET job_id = $target_id WHERE job_id = $job_id;
+    };
+    FOR $event IN $events {
+        CREATE type::thing('employment_event', $event.id) CONTENT $event.content;
+    };
+    DELETE type::thing('job', $job_id);
+    RETURN { found: true };
+};
+COMMIT TRANSACTION;
+";
+
 #[derive(Clone)]
 pub struct SurrealJobRepository<C>
 where
     C: Connection,
 {
     client: Surreal<C>,
+    reader: Surreal<C>,
 }
 
 impl<C> SurrealJobRepository<C>
@@ -28,7 +67,15 @@ where
     C: Connection,
 {
     pub fn new(client: Surreal<C>) -> Self {
-        Self { client }
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
     }
 }
 
@@ -37,21 +84,11 @@ impl<C> JobRepository for SurrealJobRepository<C>
 where
     C: Connection + Clone + Send + Sync + 'static,
 {
-    async fn insert(
-        &self,
-        id: Uuid,
-        job_title: String,
-        salary: f64,
-        payroll_id: Uuid,
-    ) -> AppResult<Job> {
+    async fn insert(&self, job: Job) -> AppResult<Job> {
         let record: Option<JobRecord> = self
             .client
-            .create((JOB_TABLE, id.to_string()))
-            .content(json!({
-                "job_title": job_title,
-                "salary": salary,
-                "payroll_id": payroll_id,
-            }))
+            .create((JOB_TABLE, job.id.to_string()))
+            .content(build_payload(&job))
             .await?;
 
         record
@@ -61,46 +98,110 @@ where
     }
 
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Job>> {
-        let record: Option<JobRecord> = self.client.select((JOB_TABLE, id.to_string())).await?;
+        let record: Option<JobRecord> = self.reader.select((JOB_TABLE, id.to_string())).await?;
         record.map(record_to_domain).transpose()
     }
 
     async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<Job>> {
-        let records: Vec<JobRecord> = self.client.select(JOB_TABLE).await?;
-        records
-            .into_iter()
-            .filter(|record| record.payroll_id == payroll_id.to_string())
-            .map(record_to_domain)
-            .collect()
+        let mut response = self
+            .reader
+            .query(BY_PAYROLL_QUERY)
+            .bind(("table", JOB_TABLE))
+            .bind(("payroll_id", payroll_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<JobRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
     }
 
-    async fn update(
-        &self,
-        id: Uuid,
-        job_title: Option<String>,
-        salary: Option<f64>,
-    ) -> AppResult<Option<Job>> {
-        let payload = build_update_payload(job_title, salary)?;
+    async fn update(&self, job: Job) -> AppResult<Option<Job>> {
         let record: Option<JobRecord> = self
             .client
-            .update((JOB_TABLE, id.to_string()))
-            .merge(payload)
+            .update((JOB_TABLE, job.id.to_string()))
+            .content(build_payload(&job))
             .await?;
 
         record.map(record_to_domain).transpose()
     }
 
-    async fn delete(&self, id: Uuid) -> AppResult<bool> {
-        let record: Option<JobRecord> = self.client.delete((JOB_TABLE, id.to_string())).await?;
-        Ok(record.is_some())
+    async fn delete(&self, id: Uuid, reassignment: Option<JobReassignment>) -> AppResult<bool> {
+        let target_id = reassignment
+            .as_ref()
+            .map(|reassignment| reassignment.to_job_id);
+        let events = reassignment
+            .map(|reassignment| reassignment.events)
+            .unwrap_or_default();
+        let moving: Vec<String> = events
+            .iter()
+            .map(|event| event.employee_id.to_string())
+            .collect();
+        let events: Vec<JsonValue> = events
+            .iter()
+            .map(|event| json!({ "id": event.id.to_string(), "content": event_content(event) }))
+            .collect();
+
+        let mut response = self
+            .client
+            .query(DELETE_QUERY)
+            .bind(("job_id", id.to_string()))
+            .bind(("target_id", target_id.map(|id| id.to_string())))
+            .bind(("moving", moving))
+            .bind(("events", events))
+            .await?
+            .check()?;
+        let outcome: Option<DeleteOutcome> = response.take(0)?;
+        let outcome =
+            outcome.ok_or_else(|| AppError::internal("database did not report the job delete"))?;
+
+        match (outcome.assigned, outcome.conflict.as_deref(), target_id) {
+            (0, None, _) => Ok(outcome.found),
+            (assigned, None, None) => Err(AppError::conflict(format!(
+                "job `{id}` is still assigned to {assigned} employee(s)"
+            ))),
+            (_, Some("target"), Some(target_id)) => Err(AppError::conflict(format!(
+                "job `{target_id}` was deleted while employees were being moved to it"
+            ))),
+            _ => Err(AppError::conflict(format!(
+                "the employees of job `{id}` changed while they were being reassigned"
+            ))),
+        }
     }
 }
 
+#[derive(Deserialize)]
+struct DeleteOutcome {
+    found: bool,
+    #[serde(default)]
+    assigned: usize,
+    #[serde(default)]
+    conflict: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct JobRecord {
     id: Thing,
     job_title: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    responsibilities: Vec<String>,
+    #[serde(default)]
+    qualifications: Vec<String>,
     salary: f64,
+    #[serde(default)]
+    pay_basis: PayBasis,
+    #[serde(default)]
+    currency: Option<String>,
+    #[serde(default)]
+    salary_min: Option<f64>,
+    #[serde(default)]
+    salary_max: Option<f64>,
+    #[serde(default)]
+    grade_id: Option<String>,
+    #[serde(default)]
+    budgeted_headcount: Option<u32>,
+    #[serde(default)]
+    headcount_policy: HeadcountPolicy,
     payroll_id: String,
 }
 
@@ -119,25 +220,47 @@ fn record_to_domain(record: JobRecord) -> AppResult<Job> {
     let payroll_id = Uuid::parse_str(&record.payroll_id)
         .map_err(|_| AppError::internal("stored job payroll id is not a UUID"))?;
 
-    Ok(Job::new(id, record.job_title, record.salary, payroll_id))
-}
-
-fn build_update_payload(job_title: Option<String>, salary: Option<f64>) -> AppResult<JsonValue> {
-    let mut object = Map::new();
-
-    if let Some(job_title) = job_title {
-        object.insert("job_title".to_string(), JsonValue::String(job_title));
-    }
+    let grade_id = record
+        .grade_id
+        .as_deref()
+        .map(Uuid::parse_str)
+        .transpose()
+        .map_err(|_| AppError::internal("stored job grade id is not a UUID"))?;
 
-    if let Some(salary) = salary {
-        object.insert("salary".to_string(), JsonValue::from(salary));
-    }
-
-    if object.is_empty() {
-        return Err(AppError::internal("no fields supplied for job update"));
-    }
+    Ok(Job::new(
+        id,
+        record.job_title,
+        record.description,
+        record.responsibilities,
+        record.qualifications,
+        record.salary,
+        record.pay_basis,
+        record.currency,
+        record.salary_min,
+        record.salary_max,
+        grade_id,
+        record.budgeted_headcount,
+        record.headcount_policy,
+        payroll_id,
+    ))
+}
 
-    Ok(JsonValue::Object(object))
+fn build_payload(job: &Job) -> JsonValue {
+    json!({
+        "job_title": job.job_title,
+        "description": job.description,
+        "responsibilities": job.responsibilities,
+        "qualifications": job.qualifications,
+        "salary": job.salary,
+        "pay_basis": job.pay_basis,
+        "currency": job.currency,
+        "salary_min": job.salary_min,
+        "salary_max": job.salary_max,
+        "grade_id": job.grade_id,
+        "budgeted_headcount": job.budgeted_headcount,
+        "headcount_policy": job.headcount_policy,
+        "payroll_id": job.payroll_id,
+    })
 }
 
 pub type SurrealAnyJobRepository = SurrealJobRepository<Any>;
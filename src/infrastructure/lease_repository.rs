@@ -0,0 +1,80 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use surrealdb::{Connection, Surreal, engine::any::Any};
+
+use crate::{error::AppResult, services::lease::LeaseRepository};
+
+const LEASE_TABLE: &str = "lease";
+
+/// Reads and writes the lease in a single statement, so two instances cannot both win it.
+const ACQUIRE_QUERY: &str = "{
+    LET $lease = (SELECT * FROM ONLY type::thing($table, $name));
+    IF $lease = NONE OR $lease.holder = $holder OR $lease.expires_at <= type::datetime($now) {
+        UPSERT type::thing($table, $name) CONTENT {
+            holder: $holder,
+            expires_at: type::datetime($until),
+        };
+        RETURN true;
+    };
+    RETURN false;
+}";
+
+const RELEASE_QUERY: &str = "DELETE type::thing($table, $name) WHERE holder = $holder RETURN NONE";
+
+#[derive(Clone)]
+pub struct SurrealLeaseRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+}
+
+impl<C> SurrealLeaseRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> LeaseRepository for SurrealLeaseRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn acquire(
+        &self,
+        name: &str,
+        holder: &str,
+        now: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> AppResult<bool> {
+        let mut response = self
+            .client
+            .query(ACQUIRE_QUERY)
+            .bind(("table", LEASE_TABLE))
+            .bind(("name", name.to_string()))
+            .bind(("holder", holder.to_string()))
+            .bind(("now", now.to_rfc3339_opts(SecondsFormat::Millis, true)))
+            .bind(("until", until.to_rfc3339_opts(SecondsFormat::Millis, true)))
+            .await?
+            .check()?;
+        let acquired: Option<bool> = response.take(0)?;
+
+        Ok(acquired.unwrap_or(false))
+    }
+
+    async fn release(&self, name: &str, holder: &str) -> AppResult<()> {
+        self.client
+            .query(RELEASE_QUERY)
+            .bind(("table", LEASE_TABLE))
+            .bind(("name", name.to_string()))
+            .bind(("holder", holder.to_string()))
+            .await?
+            .check()?;
+
+        Ok(())
+    }
+}
+
+pub type SurrealAnyLeaseRepository = SurrealLeaseRepository<Any>;
@@ -0,0 +1,697 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::leave::{
+        BlackoutEnforcement, DAY_EPSILON, LeaveAdjustmentReason, LeaveBalance,
+        LeaveBalanceAdjustment, LeaveBlackout, LeaveCarryoverRule, LeavePayPolicy, LeavePayTier,
+        LeaveRequest, LeaveRequestStatus, LeaveType,
+    },
+    error::{AppError, AppResult},
+    services::leave::LeaveRepository,
+};
+
+const LEAVE_REQUEST_TABLE: &str = "leave_request";
+const LEAVE_BALANCE_TABLE: &str = "leave_balance";
+const LEAVE_PAY_POLICY_TABLE: &str = "leave_pay_policy";
+const LEAVE_CARRYOVER_RULE_TABLE: &str = "leave_carryover_rule";
+const LEAVE_ADJUSTMENT_TABLE: &str = "leave_adjustment";
+const LEAVE_BLACKOUT_TABLE: &str = "leave_blackout";
+
+const BY_EMPLOYEE_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE employee_id = $employee_id";
+
+const BY_DIVISION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE division_id = $division_id";
+
+/// Decides a pending request in one transaction. The status is checked again inside it, and an
+/// approval's days come off the balance relative to what is stored, so two decisions racing
+/// each other cannot both apply. A failed check returns before anything is written.
+const DECIDE_QUERY: &str = "
+BEGIN TRANSACTION;
+{
+    LET $request = type::thing('leave_request', $request_id);
+    IF array::len((SELECT id FROM $request WHERE status = $pending)) = 0 {
+        RETURN { decided: false };
+    };
+    LET $balance = type::thing('leave_balance', $balance_key);
+    LET $available = ((SELECT VALUE available_days FROM $balance)[0]) ?? 0;
+    IF $deduct AND $days > $available + $epsilon {
+        RETURN { decided: false, available: $available };
+    };
+    IF $deduct {
+        UPDATE $balance SET
+            available_days = math::max([available_days - $days, 0]),
+            carried_over_days = math::max([(carried_over_days ?? 0) - $days, 0]);
+    };
+    RETURN {
+        decided: true,
+        request: (UPDATE $request MERGE $decision RETURN AFTER)[0],
+    };
+};
+COMMIT TRANSACTION;
+";
+
+#[derive(Clone)]
+pub struct SurrealLeaveRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealLeaveRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> LeaveRepository for SurrealLeaveRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert_request(&self, request: LeaveRequest) -> AppResult<LeaveRequest> {
+        let record: Option<LeaveRequestRecord> = self
+            .client
+            .create((LEAVE_REQUEST_TABLE, request.id.to_string()))
+            .content(build_request_payload(&request))
+            .await?;
+
+        record
+            .map(request_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created leave request"))
+    }
+
+    async fn fetch_request(&self, id: Uuid) -> AppResult<Option<LeaveRequest>> {
+        let record: Option<LeaveRequestRecord> = self
+            .reader
+            .select((LEAVE_REQUEST_TABLE, id.to_string()))
+            .await?;
+
+        record.map(request_record_to_domain).transpose()
+    }
+
+    async fn fetch_latest_request(&self, id: Uuid) -> AppResult<Option<LeaveRequest>> {
+        let record: Option<LeaveRequestRecord> = self
+            .client
+            .select((LEAVE_REQUEST_TABLE, id.to_string()))
+            .await?;
+
+        record.map(request_record_to_domain).transpose()
+    }
+
+    async fn fetch_requests_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<LeaveRequest>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", LEAVE_REQUEST_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<LeaveRequestRecord> = response.take(0)?;
+        records.into_iter().map(request_record_to_domain).collect()
+    }
+
+    async fn update_request(&self, request: LeaveRequest) -> AppResult<Option<LeaveRequest>> {
+        let record: Option<LeaveRequestRecord> = self
+            .client
+            .update((LEAVE_REQUEST_TABLE, request.id.to_string()))
+            .content(build_request_payload(&request))
+            .await?;
+
+        record.map(request_record_to_domain).transpose()
+    }
+
+    async fn decide_request(&self, request: LeaveRequest) -> AppResult<Option<LeaveRequest>> {
+        let deduct =
+            request.status == LeaveRequestStatus::Approved && request.leave_type.carries_balance();
+        let mut response = self
+            .client
+            .query(DECIDE_QUERY)
+            .bind(("request_id", request.id.to_string()))
+            .bind(("pending", LeaveRequestStatus::Pending))
+            .bind((
+                "balance_key",
+                balance_key(request.employee_id, request.leave_type),
+            ))
+            .bind(("deduct", deduct))
+            .bind(("days", request.days))
+            .bind(("epsilon", DAY_EPSILON))
+            .bind((
+                "decision",
+                json!({
+                    "status": request.status,
+                    "decided_by": request.decided_by,
+                    "decision_note": request.decision_note,
+                    "decided_at": request.decided_at.map(|timestamp| timestamp.to_rfc3339()),
+                }),
+            ))
+            .await?
+            .check()?;
+        let outcome: Option<DecideOutcome> = response.take(0)?;
+        let outcome = outcome
+            .ok_or_else(|| AppError::internal("database did not report the leave decision"))?;
+
+        if let Some(available) = outcome.available {
+            return Err(AppError::conflict(format!(
+                "only {available} {} days remain; the request needs {}",
+                request.leave_type.as_str(),
+                request.days
+            )));
+        }
+        if !outcome.decided {
+            return Ok(None);
+        }
+        outcome.request.map(request_record_to_domain).transpose()
+    }
+
+    async fn fetch_balances(&self, employee_id: Uuid) -> AppResult<Vec<LeaveBalance>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", LEAVE_BALANCE_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<LeaveBalanceRecord> = response.take(0)?;
+        records.into_iter().map(balance_record_to_domain).collect()
+    }
+
+    async fn fetch_latest_balance(
+        &self,
+        employee_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeaveBalance>> {
+        let record: Option<LeaveBalanceRecord> = self
+            .client
+            .select((LEAVE_BALANCE_TABLE, balance_key(employee_id, leave_type)))
+            .await?;
+
+        record.map(balance_record_to_domain).transpose()
+    }
+
+    async fn upsert_balance(&self, balance: LeaveBalance) -> AppResult<LeaveBalance> {
+        let key = balance_key(balance.employee_id, balance.leave_type);
+        let record: Option<LeaveBalanceRecord> = self
+            .client
+            .upsert((LEAVE_BALANCE_TABLE, key))
+            .content(json!({
+                "employee_id": balance.employee_id,
+                "leave_type": balance.leave_type,
+                "available_days": balance.available_days,
+                "leave_year": balance.leave_year,
+                "carried_over_days": balance.carried_over_days,
+                "carryover_expires_on": balance.carryover_expires_on.map(|date| date.to_string()),
+                "expiry_notified_at": balance
+                    .expiry_notified_at
+                    .map(|timestamp| timestamp.to_rfc3339()),
+            }))
+            .await?;
+
+        record
+            .map(balance_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return stored leave balance"))
+    }
+
+    async fn fetch_pay_policy(
+        &self,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeavePayPolicy>> {
+        let record: Option<LeavePayPolicyRecord> = self
+            .reader
+            .select((LEAVE_PAY_POLICY_TABLE, policy_key(payroll_id, leave_type)))
+            .await?;
+
+        record.map(policy_record_to_domain).transpose()
+    }
+
+    async fn upsert_pay_policy(&self, policy: LeavePayPolicy) -> AppResult<LeavePayPolicy> {
+        let record: Option<LeavePayPolicyRecord> = self
+            .client
+            .upsert((
+                LEAVE_PAY_POLICY_TABLE,
+                policy_key(policy.payroll_id, policy.leave_type),
+            ))
+            .content(json!({
+                "payroll_id": policy.payroll_id,
+                "leave_type": policy.leave_type,
+                "tiers": policy.tiers,
+                "max_days": policy.max_days,
+            }))
+            .await?;
+
+        record
+            .map(policy_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return stored leave pay policy"))
+    }
+
+    async fn fetch_carryover_rule(
+        &self,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeaveCarryoverRule>> {
+        let record: Option<LeaveCarryoverRuleRecord> = self
+            .reader
+            .select((
+                LEAVE_CARRYOVER_RULE_TABLE,
+                policy_key(payroll_id, leave_type),
+            ))
+            .await?;
+
+        record.map(carryover_rule_record_to_domain).transpose()
+    }
+
+    async fn fetch_carryover_rules(&self) -> AppResult<Vec<LeaveCarryoverRule>> {
+        let records: Vec<LeaveCarryoverRuleRecord> =
+            self.reader.select(LEAVE_CARRYOVER_RULE_TABLE).await?;
+        records
+            .into_iter()
+            .map(carryover_rule_record_to_domain)
+            .collect()
+    }
+
+    async fn upsert_carryover_rule(
+        &self,
+        rule: LeaveCarryoverRule,
+    ) -> AppResult<LeaveCarryoverRule> {
+        let record: Option<LeaveCarryoverRuleRecord> = self
+            .client
+            .upsert((
+                LEAVE_CARRYOVER_RULE_TABLE,
+                policy_key(rule.payroll_id, rule.leave_type),
+            ))
+            .content(json!({
+                "organization_id": rule.organization_id,
+                "payroll_id": rule.payroll_id,
+                "leave_type": rule.leave_type,
+                "max_days": rule.max_days,
+                "expires_after_days": rule.expires_after_days,
+            }))
+            .await?;
+
+        record
+            .map(carryover_rule_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return stored carryover rule"))
+    }
+
+    async fn insert_adjustment(
+        &self,
+        adjustment: LeaveBalanceAdjustment,
+    ) -> AppResult<LeaveBalanceAdjustment> {
+        let record: Option<LeaveAdjustmentRecord> = self
+            .client
+            .create((LEAVE_ADJUSTMENT_TABLE, adjustment.id.to_string()))
+            .content(json!({
+                "employee_id": adjustment.employee_id,
+                "leave_type": adjustment.leave_type,
+                "reason": adjustment.reason,
+                "effective_on": adjustment.effective_on.to_string(),
+                "days_before": adjustment.days_before,
+                "days_after": adjustment.days_after,
+                "created_at": adjustment.created_at.to_rfc3339(),
+            }))
+            .await?;
+
+        record
+            .map(adjustment_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created leave adjustment"))
+    }
+
+    async fn fetch_adjustments_by_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<LeaveBalanceAdjustment>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", LEAVE_ADJUSTMENT_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<LeaveAdjustmentRecord> = response.take(0)?;
+        records
+            .into_iter()
+            .map(adjustment_record_to_domain)
+            .collect()
+    }
+
+    async fn insert_blackout(&self, blackout: LeaveBlackout) -> AppResult<LeaveBlackout> {
+        let record: Option<LeaveBlackoutRecord> = self
+            .client
+            .create((LEAVE_BLACKOUT_TABLE, blackout.id.to_string()))
+            .content(json!({
+                "division_id": blackout.division_id,
+                "start_date": blackout.start_date.to_string(),
+                "end_date": blackout.end_date.to_string(),
+                "enforcement": blackout.enforcement,
+                "reason": blackout.reason,
+                "created_at": blackout.created_at.to_rfc3339(),
+            }))
+            .await?;
+
+        record
+            .map(blackout_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created leave blackout"))
+    }
+
+    async fn fetch_blackouts_by_division(
+        &self,
+        division_id: Uuid,
+    ) -> AppResult<Vec<LeaveBlackout>> {
+        let mut response = self
+            .reader
+            .query(BY_DIVISION_QUERY)
+            .bind(("table", LEAVE_BLACKOUT_TABLE))
+            .bind(("division_id", division_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<LeaveBlackoutRecord> = response.take(0)?;
+        records.into_iter().map(blackout_record_to_domain).collect()
+    }
+
+    async fn delete_blackout(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<LeaveBlackoutRecord> = self
+            .client
+            .delete((LEAVE_BLACKOUT_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Deserialize)]
+struct DecideOutcome {
+    decided: bool,
+    #[serde(default)]
+    available: Option<f64>,
+    #[serde(default)]
+    request: Option<LeaveRequestRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeaveRequestRecord {
+    id: Thing,
+    employee_id: String,
+    leave_type: LeaveType,
+    start_date: String,
+    end_date: String,
+    days: f64,
+    status: LeaveRequestStatus,
+    #[serde(default)]
+    reason: Option<String>,
+    #[serde(default)]
+    certificate_reference: Option<String>,
+    #[serde(default)]
+    blackout_id: Option<String>,
+    #[serde(default)]
+    decided_by: Option<String>,
+    #[serde(default)]
+    decision_note: Option<String>,
+    requested_at: String,
+    #[serde(default)]
+    decided_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeaveBalanceRecord {
+    employee_id: String,
+    leave_type: LeaveType,
+    available_days: f64,
+    #[serde(default)]
+    leave_year: Option<i32>,
+    #[serde(default)]
+    carried_over_days: f64,
+    #[serde(default)]
+    carryover_expires_on: Option<String>,
+    #[serde(default)]
+    expiry_notified_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeavePayPolicyRecord {
+    payroll_id: String,
+    leave_type: LeaveType,
+    tiers: Vec<LeavePayTier>,
+    #[serde(default)]
+    max_days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeaveCarryoverRuleRecord {
+    organization_id: String,
+    payroll_id: String,
+    leave_type: LeaveType,
+    #[serde(default)]
+    max_days: Option<f64>,
+    #[serde(default)]
+    expires_after_days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeaveAdjustmentRecord {
+    id: Thing,
+    employee_id: String,
+    leave_type: LeaveType,
+    reason: LeaveAdjustmentReason,
+    effective_on: String,
+    days_before: f64,
+    days_after: f64,
+    created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeaveBlackoutRecord {
+    id: Thing,
+    division_id: String,
+    start_date: String,
+    end_date: String,
+    enforcement: BlackoutEnforcement,
+    #[serde(default)]
+    reason: Option<String>,
+    created_at: String,
+}
+
+fn request_record_to_domain(record: LeaveRequestRecord) -> AppResult<LeaveRequest> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored leave request id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored leave request identifier is not a supported format",
+            ));
+        }
+    };
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored leave request employee id is not a UUID"))?;
+    let start_date = parse_date(&record.start_date)?;
+    let end_date = parse_date(&record.end_date)?;
+    let blackout_id = record
+        .blackout_id
+        .as_deref()
+        .map(Uuid::parse_str)
+        .transpose()
+        .map_err(|_| AppError::internal("stored leave request blackout id is not a UUID"))?;
+    let decided_by = record
+        .decided_by
+        .as_deref()
+        .map(Uuid::parse_str)
+        .transpose()
+        .map_err(|_| AppError::internal("stored leave request approver id is not a UUID"))?;
+    let requested_at = parse_timestamp(&record.requested_at)?;
+    let decided_at = record
+        .decided_at
+        .as_deref()
+        .map(parse_timestamp)
+        .transpose()?;
+
+    Ok(LeaveRequest {
+        id,
+        employee_id,
+        leave_type: record.leave_type,
+        start_date,
+        end_date,
+        days: record.days,
+        status: record.status,
+        reason: record.reason,
+        certificate_reference: record.certificate_reference,
+        blackout_id,
+        decided_by,
+        decision_note: record.decision_note,
+        requested_at,
+        decided_at,
+    })
+}
+
+fn parse_date(value: &str) -> AppResult<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| AppError::internal("stored leave request date is not a valid date"))
+}
+
+fn parse_timestamp(value: &str) -> AppResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| AppError::internal("stored leave request timestamp is not valid"))
+}
+
+fn balance_record_to_domain(record: LeaveBalanceRecord) -> AppResult<LeaveBalance> {
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored leave balance employee id is not a UUID"))?;
+
+    let carryover_expires_on = record
+        .carryover_expires_on
+        .as_deref()
+        .map(parse_date)
+        .transpose()?;
+    let expiry_notified_at = record
+        .expiry_notified_at
+        .as_deref()
+        .map(parse_timestamp)
+        .transpose()?;
+
+    Ok(LeaveBalance {
+        employee_id,
+        leave_type: record.leave_type,
+        available_days: record.available_days,
+        leave_year: record.leave_year,
+        carried_over_days: record.carried_over_days,
+        carryover_expires_on,
+        expiry_notified_at,
+    })
+}
+
+fn carryover_rule_record_to_domain(
+    record: LeaveCarryoverRuleRecord,
+) -> AppResult<LeaveCarryoverRule> {
+    let organization_id = Uuid::parse_str(&record.organization_id)
+        .map_err(|_| AppError::internal("stored carryover rule organization id is not a UUID"))?;
+    let payroll_id = Uuid::parse_str(&record.payroll_id)
+        .map_err(|_| AppError::internal("stored carryover rule payroll id is not a UUID"))?;
+
+    Ok(LeaveCarryoverRule {
+        organization_id,
+        payroll_id,
+        leave_type: record.leave_type,
+        max_days: record.max_days,
+        expires_after_days: record.expires_after_days,
+    })
+}
+
+fn adjustment_record_to_domain(record: LeaveAdjustmentRecord) -> AppResult<LeaveBalanceAdjustment> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored leave adjustment id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored leave adjustment identifier is not a supported format",
+            ));
+        }
+    };
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored leave adjustment employee id is not a UUID"))?;
+
+    Ok(LeaveBalanceAdjustment {
+        id,
+        employee_id,
+        leave_type: record.leave_type,
+        reason: record.reason,
+        effective_on: parse_date(&record.effective_on)?,
+        days_before: record.days_before,
+        days_after: record.days_after,
+        created_at: parse_timestamp(&record.created_at)?,
+    })
+}
+
+fn policy_record_to_domain(record: LeavePayPolicyRecord) -> AppResult<LeavePayPolicy> {
+    let payroll_id = Uuid::parse_str(&record.payroll_id)
+        .map_err(|_| AppError::internal("stored leave pay policy payroll id is not a UUID"))?;
+
+    Ok(LeavePayPolicy {
+        payroll_id,
+        leave_type: record.leave_type,
+        tiers: record.tiers,
+        max_days: record.max_days,
+    })
+}
+
+fn blackout_record_to_domain(record: LeaveBlackoutRecord) -> AppResult<LeaveBlackout> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored leave blackout id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored leave blackout identifier is not a supported format",
+            ));
+        }
+    };
+    let division_id = Uuid::parse_str(&record.division_id)
+        .map_err(|_| AppError::internal("stored leave blackout division id is not a UUID"))?;
+
+    Ok(LeaveBlackout {
+        id,
+        division_id,
+        start_date: parse_date(&record.start_date)?,
+        end_date: parse_date(&record.end_date)?,
+        enforcement: record.enforcement,
+        reason: record.reason,
+        created_at: parse_timestamp(&record.created_at)?,
+    })
+}
+
+fn policy_key(payroll_id: Uuid, leave_type: LeaveType) -> String {
+    format!("{payroll_id}_{}", leave_type.as_str())
+}
+
+/// A balance is keyed by its employee and leave type, so each pair has one record.
+fn balance_key(employee_id: Uuid, leave_type: LeaveType) -> String {
+    format!("{employee_id}_{}", leave_type.as_str())
+}
+
+fn build_request_payload(request: &LeaveRequest) -> JsonValue {
+    json!({
+        "employee_id": request.employee_id,
+        "leave_type": request.leave_type,
+        "start_date": request.start_date.to_string(),
+        "end_date": request.end_date.to_string(),
+        "days": request.days,
+        "status": request.status,
+        "reason": request.reason,
+        "certificate_reference": request.certificate_reference,
+        "blackout_id": request.blackout_id,
+        "decided_by": request.decided_by,
+        "decision_note": request.decision_note,
+        "requested_at": request.requested_at.to_rfc3339(),
+        "decided_at": request.decided_at.map(|timestamp| timestamp.to_rfc3339()),
+    })
+}
+
+pub type SurrealAnyLeaveRepository = SurrealLeaveRepository<Any>;
@@ -0,0 +1,188 @@
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        legal_entity::{DebtorAccount, LegalEntity},
+        organization::LegalIdentity,
+    },
+    error::{AppError, AppResult},
+    services::legal_entity::LegalEntityRepository,
+};
+
+const LEGAL_ENTITY_TABLE: &str = "legal_entity";
+
+const BY_ORGANIZATION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE organization_id = $organization_id";
+
+#[derive(Clone)]
+pub struct SurrealLegalEntityRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealLegalEntityRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> LegalEntityRepository for SurrealLegalEntityRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, legal_entity: LegalEntity) -> AppResult<LegalEntity> {
+        let record: Option<LegalEntityRecord> = self
+            .client
+            .create((LEGAL_ENTITY_TABLE, legal_entity.id.to_string()))
+            .content(build_payload(&legal_entity))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created legal entity"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<LegalEntity>> {
+        let record: Option<LegalEntityRecord> = self
+            .reader
+            .select((LEGAL_ENTITY_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<LegalEntity>> {
+        let mut response = self
+            .reader
+            .query(BY_ORGANIZATION_QUERY)
+            .bind(("table", LEGAL_ENTITY_TABLE))
+            .bind(("organization_id", organization_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<LegalEntityRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, legal_entity: LegalEntity) -> AppResult<Option<LegalEntity>> {
+        let record: Option<LegalEntityRecord> = self
+            .client
+            .update((LEGAL_ENTITY_TABLE, legal_entity.id.to_string()))
+            .content(build_payload(&legal_entity))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<LegalEntityRecord> = self
+            .client
+            .delete((LEGAL_ENTITY_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LegalEntityRecord {
+    id: Thing,
+    organization_id: String,
+    name: String,
+    #[serde(default)]
+    legal_name: Option<String>,
+    #[serde(default)]
+    tax_id: Option<String>,
+    #[serde(default)]
+    registration_number: Option<String>,
+    #[serde(default)]
+    address: Option<String>,
+    #[serde(default)]
+    debtor_account: Option<DebtorAccountRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DebtorAccountRecord {
+    bank_id: String,
+    account_number: String,
+}
+
+fn record_to_domain(record: LegalEntityRecord) -> AppResult<LegalEntity> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored legal entity id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored legal entity identifier is not a supported format",
+            ));
+        }
+    };
+    let organization_id = Uuid::parse_str(&record.organization_id)
+        .map_err(|_| AppError::internal("stored legal entity organization id is not a UUID"))?;
+    let debtor_account = record
+        .debtor_account
+        .map(|account| {
+            Uuid::parse_str(&account.bank_id)
+                .map(|bank_id| DebtorAccount {
+                    bank_id,
+                    account_number: account.account_number,
+                })
+                .map_err(|_| AppError::internal("stored debtor account bank id is not a UUID"))
+        })
+        .transpose()?;
+
+    let mut legal_entity = LegalEntity::new(
+        id,
+        organization_id,
+        record.name,
+        LegalIdentity {
+            legal_name: record.legal_name,
+            tax_id: record.tax_id,
+            registration_number: record.registration_number,
+            address: record.address,
+        },
+    );
+    legal_entity.debtor_account = debtor_account;
+    Ok(legal_entity)
+}
+
+fn build_payload(legal_entity: &LegalEntity) -> JsonValue {
+    let identity = &legal_entity.legal_identity;
+    json!({
+        "organization_id": legal_entity.organization_id,
+        "name": legal_entity.name,
+        "legal_name": identity.legal_name,
+        "tax_id": identity.tax_id,
+        "registration_number": identity.registration_number,
+        "address": identity.address,
+        "debtor_account": legal_entity.debtor_account.as_ref().map(|account| json!({
+            "bank_id": account.bank_id,
+            "account_number": account.account_number,
+        })),
+    })
+}
+
+pub type SurrealAnyLegalEntityRepository = SurrealLegalEntityRepository<Any>;
@@ -0,0 +1,16 @@
+use tracing::{debug, info};
+
+use crate::{domain::email::EmailMessage, error::AppResult, services::email::EmailSender};
+
+/// Writes emails to the log instead of delivering them, for deployments without a mail relay.
+#[derive(Clone, Debug, Default)]
+pub struct LogEmailSender;
+
+#[async_trait::async_trait]
+impl EmailSender for LogEmailSender {
+    async fn send(&self, message: EmailMessage) -> AppResult<()> {
+        info!(to = %message.to, subject = %message.subject, "email not delivered; no mail relay configured");
+        debug!(body = %message.body, "undelivered email body");
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::membership::{MemberRole, Membership},
+    error::{AppError, AppResult},
+    services::membership::MembershipRepository,
+};
+
+const MEMBERSHIP_TABLE: &str = "membership";
+
+const BY_ORGANIZATION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE organization_id = $organization_id";
+
+#[derive(Clone)]
+pub struct SurrealMembershipRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealMembershipRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> MembershipRepository for SurrealMembershipRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, membership: Membership) -> AppResult<Membership> {
+        let record: Option<MembershipRecord> = self
+            .client
+            .create((MEMBERSHIP_TABLE, membership.id.to_string()))
+            .content(build_payload(&membership))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created membership"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Membership>> {
+        let record: Option<MembershipRecord> = self
+            .reader
+            .select((MEMBERSHIP_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Membership>> {
+        let mut response = self
+            .reader
+            .query(BY_ORGANIZATION_QUERY)
+            .bind(("table", MEMBERSHIP_TABLE))
+            .bind(("organization_id", organization_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<MembershipRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, membership: Membership) -> AppResult<Option<Membership>> {
+        let record: Option<MembershipRecord> = self
+            .client
+            .update((MEMBERSHIP_TABLE, membership.id.to_string()))
+            .content(build_payload(&membership))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<MembershipRecord> = self
+            .client
+            .delete((MEMBERSHIP_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MembershipRecord {
+    id: Thing,
+    organization_id: String,
+    email: String,
+    role: MemberRole,
+    invited_at: String,
+}
+
+fn record_to_domain(record: MembershipRecord) -> AppResult<Membership> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored membership id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored membership identifier is not a supported format",
+            ));
+        }
+    };
+    let organization_id = Uuid::parse_str(&record.organization_id)
+        .map_err(|_| AppError::internal("stored membership organization id is not a UUID"))?;
+    let invited_at = DateTime::parse_from_rfc3339(&record.invited_at)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| AppError::internal("stored membership timestamp is not valid"))?;
+
+    Ok(Membership {
+        id,
+        organization_id,
+        email: record.email,
+        role: record.role,
+        invited_at,
+    })
+}
+
+fn build_payload(membership: &Membership) -> JsonValue {
+    json!({
+        "organization_id": membership.organization_id,
+        "email": membership.email,
+        "role": membership.role,
+        "invited_at": membership.invited_at.to_rfc3339(),
+    })
+}
+
+pub type SurrealAnyMembershipRepository = SurrealMembershipRepository<Any>;
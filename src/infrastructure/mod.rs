@@ -1,7 +1,35 @@
+pub mod bank_catalog_repository;
 pub mod bank_repository;
+pub mod blob_store;
+pub mod custom_field_repository;
+pub mod database_probe;
+pub mod dependent_repository;
 pub mod division_repository;
+pub mod email_verification_repository;
 pub mod employee_repository;
+pub mod employment_history_repository;
+pub mod import_profile_repository;
+#[cfg(feature = "in-memory")]
+pub mod in_memory;
+pub mod job_grade_repository;
 pub mod job_repository;
+pub mod lease_repository;
+pub mod leave_repository;
+pub mod legal_entity_repository;
+pub mod log_email_sender;
+pub mod membership_repository;
+pub mod organization_deletion_repository;
 pub mod organization_repository;
+pub mod organization_settings_repository;
+pub mod overtime_repository;
 pub mod payroll_repository;
+pub mod position_repository;
+pub mod project_repository;
+pub mod punch_policy_repository;
+#[cfg(feature = "redis-cache")]
+pub mod redis_cache;
+pub mod salary_adjustment_repository;
+pub mod self_service_repository;
 pub mod surreal;
+pub mod timesheet_repository;
+pub mod work_schedule_repository;
@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::organization_deletion::OrganizationDeletion,
+    error::{AppError, AppResult},
+    services::organization_deletion::OrganizationDeletionRepository,
+};
+
+const ORGANIZATION_DELETION_TABLE: &str = "organization_deletion";
+
+// `scheduled_for` is stored as RFC 3339 in UTC, which sorts like the instants themselves.
+const DUE_QUERY: &str = "SELECT * FROM type::table($table) WHERE scheduled_for <= $now";
+
+#[derive(Clone)]
+pub struct SurrealOrganizationDeletionRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealOrganizationDeletionRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> OrganizationDeletionRepository for SurrealOrganizationDeletionRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(
+        &self,
+        organization_id: Uuid,
+        requested_at: DateTime<Utc>,
+        scheduled_for: DateTime<Utc>,
+    ) -> AppResult<OrganizationDeletion> {
+        let record: Option<OrganizationDeletionRecord> = self
+            .client
+            .create((ORGANIZATION_DELETION_TABLE, organization_id.to_string()))
+            .content(json!({
+                "requested_at": requested_at.to_rfc3339(),
+                "scheduled_for": scheduled_for.to_rfc3339(),
+            }))
+            .await?;
+
+        record.map(record_to_domain).transpose()?.ok_or_else(|| {
+            AppError::internal("database did not return created organization deletion")
+        })
+    }
+
+    async fn fetch(&self, organization_id: Uuid) -> AppResult<Option<OrganizationDeletion>> {
+        let record: Option<OrganizationDeletionRecord> = self
+            .reader
+            .select((ORGANIZATION_DELETION_TABLE, organization_id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_due(&self, now: DateTime<Utc>) -> AppResult<Vec<OrganizationDeletion>> {
+        let mut response = self
+            .reader
+            .query(DUE_QUERY)
+            .bind(("table", ORGANIZATION_DELETION_TABLE))
+            .bind(("now", now.to_rfc3339()))
+            .await?
+            .check()?;
+        let records: Vec<OrganizationDeletionRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn delete(&self, organization_id: Uuid) -> AppResult<bool> {
+        let record: Option<OrganizationDeletionRecord> = self
+            .client
+            .delete((ORGANIZATION_DELETION_TABLE, organization_id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OrganizationDeletionRecord {
+    id: Thing,
+    requested_at: String,
+    scheduled_for: String,
+}
+
+fn record_to_domain(record: OrganizationDeletionRecord) -> AppResult<OrganizationDeletion> {
+    let organization_id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored organization deletion id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored organization deletion identifier is not a supported format",
+            ));
+        }
+    };
+
+    Ok(OrganizationDeletion::new(
+        organization_id,
+        parse_timestamp(&record.requested_at, "requested at")?,
+        parse_timestamp(&record.scheduled_for, "scheduled for")?,
+    ))
+}
+
+fn parse_timestamp(value: &str, field: &str) -> AppResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| AppError::internal(format!("stored {field} is not a valid timestamp")))
+}
+
+pub type SurrealAnyOrganizationDeletionRepository = SurrealOrganizationDeletionRepository<Any>;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_json::{Map, Value as JsonValue, json};
 use surrealdb::{
@@ -8,7 +9,7 @@ use surrealdb::{
 use uuid::Uuid;
 
 use crate::{
-    domain::organization::Organization,
+    domain::organization::{LegalIdentity, Organization},
     error::{AppError, AppResult},
     services::organization::OrganizationRepository,
 };
@@ -21,6 +22,7 @@ where
     C: Connection,
 {
     client: Surreal<C>,
+    reader: Surreal<C>,
 }
 
 impl<C> SurrealOrganizationRepository<C>
@@ -28,7 +30,15 @@ where
     C: Connection,
 {
     pub fn new(client: Surreal<C>) -> Self {
-        Self { client }
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
     }
 }
 
@@ -37,11 +47,24 @@ impl<C> OrganizationRepository for SurrealOrganizationRepository<C>
 where
     C: Connection + Clone + Send + Sync + 'static,
 {
-    async fn insert(&self, id: Uuid, name: String) -> AppResult<Organization> {
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        budget_code_pattern: Option<String>,
+        legal_identity: LegalIdentity,
+    ) -> AppResult<Organization> {
         let record: Option<OrganizationRecord> = self
             .client
             .create((ORGANIZATION_TABLE, id.to_string()))
-            .content(json!({"name": name}))
+            .content(json!({
+                "name": name,
+                "budget_code_pattern": budget_code_pattern,
+                "legal_name": legal_identity.legal_name,
+                "tax_id": legal_identity.tax_id,
+                "registration_number": legal_identity.registration_number,
+                "address": legal_identity.address,
+            }))
             .await?;
 
         record
@@ -60,12 +83,18 @@ where
     }
 
     async fn fetch_all(&self) -> AppResult<Vec<Organization>> {
-        let records: Vec<OrganizationRecord> = self.client.select(ORGANIZATION_TABLE).await?;
+        let records: Vec<OrganizationRecord> = self.reader.select(ORGANIZATION_TABLE).await?;
         records.into_iter().map(record_to_domain).collect()
     }
 
-    async fn update(&self, id: Uuid, name: Option<String>) -> AppResult<Option<Organization>> {
-        let payload = build_update_payload(name)?;
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        budget_code_pattern: Option<Option<String>>,
+        legal_identity: Option<LegalIdentity>,
+    ) -> AppResult<Option<Organization>> {
+        let payload = build_update_payload(name, budget_code_pattern, legal_identity)?;
 
         let record: Option<OrganizationRecord> = self
             .client
@@ -76,13 +105,50 @@ where
         record.map(record_to_domain).transpose()
     }
 
-    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+    async fn set_logo_url(
+        &self,
+        id: Uuid,
+        logo_url: Option<String>,
+    ) -> AppResult<Option<Organization>> {
+        let record: Option<OrganizationRecord> = self
+            .client
+            .update((ORGANIZATION_TABLE, id.to_string()))
+            .merge(json!({ "logo_url": logo_url }))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn set_deleted_at(
+        &self,
+        id: Uuid,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Organization>> {
+        let record: Option<OrganizationRecord> = self
+            .client
+            .update((ORGANIZATION_TABLE, id.to_string()))
+            .merge(json!({
+                "deleted_at": deleted_at.map(|timestamp| timestamp.to_rfc3339()),
+            }))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Organization>> {
         let record: Option<OrganizationRecord> = self
             .client
-            .delete((ORGANIZATION_TABLE, id.to_string()))
+            .update((ORGANIZATION_TABLE, id.to_string()))
+            .merge(json!({
+                "archived_at": archived_at.map(|timestamp| timestamp.to_rfc3339()),
+            }))
             .await?;
 
-        Ok(record.is_some())
+        record.map(record_to_domain).transpose()
     }
 }
 
@@ -90,6 +156,22 @@ where
 struct OrganizationRecord {
     id: Thing,
     name: String,
+    #[serde(default)]
+    budget_code_pattern: Option<String>,
+    #[serde(default)]
+    legal_name: Option<String>,
+    #[serde(default)]
+    tax_id: Option<String>,
+    #[serde(default)]
+    registration_number: Option<String>,
+    #[serde(default)]
+    address: Option<String>,
+    #[serde(default)]
+    logo_url: Option<String>,
+    #[serde(default)]
+    deleted_at: Option<String>,
+    #[serde(default)]
+    archived_at: Option<String>,
 }
 
 fn record_to_domain(record: OrganizationRecord) -> AppResult<Organization> {
@@ -104,18 +186,74 @@ fn record_to_domain(record: OrganizationRecord) -> AppResult<Organization> {
         }
     };
 
-    Ok(Organization::new(id, record.name))
+    let mut organization = Organization::new(
+        id,
+        record.name,
+        record.budget_code_pattern,
+        LegalIdentity {
+            legal_name: record.legal_name,
+            tax_id: record.tax_id,
+            registration_number: record.registration_number,
+            address: record.address,
+        },
+    );
+    organization.logo_url = record.logo_url;
+    organization.deleted_at = record
+        .deleted_at
+        .as_deref()
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|_| AppError::internal("stored organization deletion time is not valid"))
+        })
+        .transpose()?;
+    organization.archived_at = record
+        .archived_at
+        .as_deref()
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|_| AppError::internal("stored organization archive time is not valid"))
+        })
+        .transpose()?;
+
+    Ok(organization)
 }
 
 pub type SurrealAnyOrganizationRepository = SurrealOrganizationRepository<Any>;
 
-fn build_update_payload(name: Option<String>) -> AppResult<JsonValue> {
+fn build_update_payload(
+    name: Option<String>,
+    budget_code_pattern: Option<Option<String>>,
+    legal_identity: Option<LegalIdentity>,
+) -> AppResult<JsonValue> {
     let mut object = Map::new();
 
     if let Some(name) = name {
         object.insert("name".to_string(), JsonValue::String(name));
     }
 
+    if let Some(budget_code_pattern) = budget_code_pattern {
+        object.insert(
+            "budget_code_pattern".to_string(),
+            budget_code_pattern
+                .map(JsonValue::String)
+                .unwrap_or(JsonValue::Null),
+        );
+    }
+
+    if let Some(identity) = legal_identity {
+        let optional =
+            |value: Option<String>| value.map(JsonValue::String).unwrap_or(JsonValue::Null);
+        object.insert("legal_name".to_string(), optional(identity.legal_name));
+        object.insert("tax_id".to_string(), optional(identity.tax_id));
+        object.insert(
+            "registration_number".to_string(),
+            optional(identity.registration_number),
+        );
+        object.insert("address".to_string(), optional(identity.address));
+    }
+
     if object.is_empty() {
         return Err(AppError::internal(
             "no fields supplied for organization update",
@@ -0,0 +1,112 @@
+use serde::Deserialize;
+use serde_json::json;
+use surrealdb::{Connection, Surreal, engine::any::Any};
+use uuid::Uuid;
+
+use crate::{
+    domain::organization_settings::{
+        DEFAULT_LOCALE, FiscalYearStart, OrganizationSettings, RoundingRule,
+    },
+    error::{AppError, AppResult},
+    services::organization_settings::OrganizationSettingsRepository,
+};
+
+const ORGANIZATION_SETTINGS_TABLE: &str = "organization_settings";
+
+#[derive(Clone)]
+pub struct SurrealOrganizationSettingsRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealOrganizationSettingsRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> OrganizationSettingsRepository for SurrealOrganizationSettingsRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn fetch(&self, organization_id: Uuid) -> AppResult<Option<OrganizationSettings>> {
+        let record: Option<OrganizationSettingsRecord> = self
+            .reader
+            .select((ORGANIZATION_SETTINGS_TABLE, organization_id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn upsert(&self, settings: OrganizationSettings) -> AppResult<OrganizationSettings> {
+        let record: Option<OrganizationSettingsRecord> = self
+            .client
+            .upsert((
+                ORGANIZATION_SETTINGS_TABLE,
+                settings.organization_id.to_string(),
+            ))
+            .content(json!({
+                "organization_id": settings.organization_id,
+                "default_currency": settings.default_currency,
+                "locale": settings.locale,
+                "fiscal_year_start": settings.fiscal_year_start,
+                "working_days_per_week": settings.working_days_per_week,
+                "rounding": settings.rounding,
+            }))
+            .await?;
+
+        record.map(record_to_domain).transpose()?.ok_or_else(|| {
+            AppError::internal("database did not return stored organization settings")
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OrganizationSettingsRecord {
+    organization_id: String,
+    #[serde(default)]
+    default_currency: Option<String>,
+    #[serde(default)]
+    locale: Option<String>,
+    #[serde(default)]
+    fiscal_year_start: FiscalYearStart,
+    #[serde(default)]
+    working_days_per_week: Option<u8>,
+    #[serde(default)]
+    rounding: RoundingRule,
+}
+
+fn record_to_domain(record: OrganizationSettingsRecord) -> AppResult<OrganizationSettings> {
+    let organization_id = Uuid::parse_str(&record.organization_id).map_err(|_| {
+        AppError::internal("stored organization settings organization id is not a UUID")
+    })?;
+    let standard = OrganizationSettings::standard(organization_id);
+
+    Ok(OrganizationSettings {
+        organization_id,
+        default_currency: record.default_currency,
+        locale: record.locale.unwrap_or_else(|| DEFAULT_LOCALE.to_string()),
+        fiscal_year_start: record.fiscal_year_start,
+        working_days_per_week: record
+            .working_days_per_week
+            .unwrap_or(standard.working_days_per_week),
+        rounding: record.rounding,
+    })
+}
+
+pub type SurrealAnyOrganizationSettingsRepository = SurrealOrganizationSettingsRepository<Any>;
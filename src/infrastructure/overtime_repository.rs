@@ -0,0 +1,226 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::overtime::{OvertimePolicy, OvertimeRequest, OvertimeRequestStatus},
+    error::{AppError, AppResult},
+    services::overtime::OvertimeRepository,
+};
+
+const OVERTIME_POLICY_TABLE: &str = "overtime_policy";
+const OVERTIME_REQUEST_TABLE: &str = "overtime_request";
+
+const BY_EMPLOYEE_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE employee_id = $employee_id";
+
+#[derive(Clone)]
+pub struct SurrealOvertimeRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealOvertimeRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> OvertimeRepository for SurrealOvertimeRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn fetch_policy(&self, payroll_id: Uuid) -> AppResult<Option<OvertimePolicy>> {
+        let record: Option<OvertimePolicyRecord> = self
+            .reader
+            .select((OVERTIME_POLICY_TABLE, payroll_id.to_string()))
+            .await?;
+
+        record.map(policy_record_to_domain).transpose()
+    }
+
+    async fn upsert_policy(&self, policy: OvertimePolicy) -> AppResult<OvertimePolicy> {
+        let record: Option<OvertimePolicyRecord> = self
+            .client
+            .upsert((OVERTIME_POLICY_TABLE, policy.payroll_id.to_string()))
+            .content(json!({
+                "payroll_id": policy.payroll_id,
+                "multiplier": policy.multiplier,
+                "requires_preapproval": policy.requires_preapproval,
+            }))
+            .await?;
+
+        record
+            .map(policy_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return stored overtime policy"))
+    }
+
+    async fn insert_request(&self, request: OvertimeRequest) -> AppResult<OvertimeRequest> {
+        let record: Option<OvertimeRequestRecord> = self
+            .client
+            .create((OVERTIME_REQUEST_TABLE, request.id.to_string()))
+            .content(build_request_payload(&request))
+            .await?;
+
+        record
+            .map(request_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created overtime request"))
+    }
+
+    async fn fetch_request(&self, id: Uuid) -> AppResult<Option<OvertimeRequest>> {
+        let record: Option<OvertimeRequestRecord> = self
+            .reader
+            .select((OVERTIME_REQUEST_TABLE, id.to_string()))
+            .await?;
+
+        record.map(request_record_to_domain).transpose()
+    }
+
+    async fn fetch_requests_by_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<OvertimeRequest>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", OVERTIME_REQUEST_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<OvertimeRequestRecord> = response.take(0)?;
+        records.into_iter().map(request_record_to_domain).collect()
+    }
+
+    async fn update_request(&self, request: OvertimeRequest) -> AppResult<Option<OvertimeRequest>> {
+        let record: Option<OvertimeRequestRecord> = self
+            .client
+            .update((OVERTIME_REQUEST_TABLE, request.id.to_string()))
+            .content(build_request_payload(&request))
+            .await?;
+
+        record.map(request_record_to_domain).transpose()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OvertimePolicyRecord {
+    payroll_id: String,
+    multiplier: f64,
+    #[serde(default)]
+    requires_preapproval: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct OvertimeRequestRecord {
+    id: Thing,
+    employee_id: String,
+    date: String,
+    hours: f64,
+    #[serde(default)]
+    reason: Option<String>,
+    status: OvertimeRequestStatus,
+    requested_at: String,
+    #[serde(default)]
+    reviewed_by: Option<String>,
+    #[serde(default)]
+    review_note: Option<String>,
+    #[serde(default)]
+    reviewed_at: Option<String>,
+}
+
+fn policy_record_to_domain(record: OvertimePolicyRecord) -> AppResult<OvertimePolicy> {
+    let payroll_id = Uuid::parse_str(&record.payroll_id)
+        .map_err(|_| AppError::internal("stored overtime policy payroll id is not a UUID"))?;
+
+    Ok(OvertimePolicy {
+        payroll_id,
+        multiplier: record.multiplier,
+        requires_preapproval: record.requires_preapproval,
+    })
+}
+
+fn request_record_to_domain(record: OvertimeRequestRecord) -> AppResult<OvertimeRequest> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored overtime request id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored overtime request identifier is not a supported format",
+            ));
+        }
+    };
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored overtime request employee id is not a UUID"))?;
+    let date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d")
+        .map_err(|_| AppError::internal("stored overtime request date is not a valid date"))?;
+    let parse_timestamp = |value: &str| {
+        DateTime::parse_from_rfc3339(value)
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+            .map_err(|_| AppError::internal("stored overtime request timestamp is not valid"))
+    };
+    let reviewed_by = record
+        .reviewed_by
+        .as_deref()
+        .map(|value| {
+            Uuid::parse_str(value)
+                .map_err(|_| AppError::internal("stored overtime reviewer id is not a UUID"))
+        })
+        .transpose()?;
+
+    Ok(OvertimeRequest {
+        id,
+        employee_id,
+        date,
+        hours: record.hours,
+        reason: record.reason,
+        status: record.status,
+        requested_at: parse_timestamp(&record.requested_at)?,
+        reviewed_by,
+        review_note: record.review_note,
+        reviewed_at: record
+            .reviewed_at
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+    })
+}
+
+fn build_request_payload(request: &OvertimeRequest) -> JsonValue {
+    json!({
+        "employee_id": request.employee_id,
+        "date": request.date.to_string(),
+        "hours": request.hours,
+        "reason": request.reason,
+        "status": request.status,
+        "requested_at": request.requested_at.to_rfc3339(),
+        "reviewed_by": request.reviewed_by,
+        "review_note": request.review_note,
+        "reviewed_at": request.reviewed_at.map(|timestamp| timestamp.to_rfc3339()),
+    })
+}
+
+pub type SurrealAnyOvertimeRepository = SurrealOvertimeRepository<Any>;
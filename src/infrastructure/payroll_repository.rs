@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_json::{Map, Value as JsonValue, json};
 use surrealdb::{
@@ -15,12 +16,16 @@ use crate::{
 
 const PAYROLL_TABLE: &str = "payroll";
 
+const BY_ORGANIZATION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE organization_id = $organization_id";
+
 #[derive(Clone)]
 pub struct SurrealPayrollRepository<C>
 where
     C: Connection,
 {
     client: Surreal<C>,
+    reader: Surreal<C>,
 }
 
 impl<C> SurrealPayrollRepository<C>
@@ -28,7 +33,15 @@ where
     C: Connection,
 {
     pub fn new(client: Surreal<C>) -> Self {
-        Self { client }
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
     }
 }
 
@@ -43,6 +56,8 @@ where
         name: String,
         description: String,
         organization_id: Uuid,
+        blocks_unverified_accounts: bool,
+        legal_entity_id: Option<Uuid>,
     ) -> AppResult<Payroll> {
         let record: Option<PayrollRecord> = self
             .client
@@ -51,6 +66,8 @@ where
                 "name": name,
                 "description": description,
                 "organization_id": organization_id,
+                "blocks_unverified_accounts": blocks_unverified_accounts,
+                "legal_entity_id": legal_entity_id,
             }))
             .await?;
 
@@ -68,12 +85,15 @@ where
     }
 
     async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Payroll>> {
-        let records: Vec<PayrollRecord> = self.client.select(PAYROLL_TABLE).await?;
-        records
-            .into_iter()
-            .filter(|record| record.organization_id == organization_id.to_string())
-            .map(record_to_domain)
-            .collect()
+        let mut response = self
+            .reader
+            .query(BY_ORGANIZATION_QUERY)
+            .bind(("table", PAYROLL_TABLE))
+            .bind(("organization_id", organization_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<PayrollRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
     }
 
     async fn update(
@@ -81,8 +101,15 @@ where
         id: Uuid,
         name: Option<String>,
         description: Option<String>,
+        blocks_unverified_accounts: Option<bool>,
+        legal_entity_id: Option<Option<Uuid>>,
     ) -> AppResult<Option<Payroll>> {
-        let payload = build_update_payload(name, description)?;
+        let payload = build_update_payload(
+            name,
+            description,
+            blocks_unverified_accounts,
+            legal_entity_id,
+        )?;
         let record: Option<PayrollRecord> = self
             .client
             .update((PAYROLL_TABLE, id.to_string()))
@@ -92,6 +119,22 @@ where
         record.map(record_to_domain).transpose()
     }
 
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Payroll>> {
+        let record: Option<PayrollRecord> = self
+            .client
+            .update((PAYROLL_TABLE, id.to_string()))
+            .merge(json!({
+                "archived_at": archived_at.map(|timestamp| timestamp.to_rfc3339()),
+            }))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
     async fn delete(&self, id: Uuid) -> AppResult<bool> {
         let record: Option<PayrollRecord> =
             self.client.delete((PAYROLL_TABLE, id.to_string())).await?;
@@ -106,6 +149,12 @@ struct PayrollRecord {
     name: String,
     description: String,
     organization_id: String,
+    #[serde(default)]
+    blocks_unverified_accounts: bool,
+    #[serde(default)]
+    legal_entity_id: Option<String>,
+    #[serde(default)]
+    archived_at: Option<String>,
 }
 
 fn record_to_domain(record: PayrollRecord) -> AppResult<Payroll> {
@@ -123,15 +172,34 @@ fn record_to_domain(record: PayrollRecord) -> AppResult<Payroll> {
     let organization_id = Uuid::parse_str(&record.organization_id)
         .map_err(|_| AppError::internal("stored payroll organization id is not a UUID"))?;
 
-    Ok(Payroll::new(
-        id,
-        record.name,
-        record.description,
-        organization_id,
-    ))
+    let mut payroll = Payroll::new(id, record.name, record.description, organization_id);
+    payroll.blocks_unverified_accounts = record.blocks_unverified_accounts;
+    payroll.legal_entity_id = record
+        .legal_entity_id
+        .as_deref()
+        .map(|value| {
+            Uuid::parse_str(value)
+                .map_err(|_| AppError::internal("stored payroll legal entity id is not a UUID"))
+        })
+        .transpose()?;
+    payroll.archived_at = record
+        .archived_at
+        .as_deref()
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|_| AppError::internal("stored payroll archive time is not valid"))
+        })
+        .transpose()?;
+    Ok(payroll)
 }
 
-fn build_update_payload(name: Option<String>, description: Option<String>) -> AppResult<JsonValue> {
+fn build_update_payload(
+    name: Option<String>,
+    description: Option<String>,
+    blocks_unverified_accounts: Option<bool>,
+    legal_entity_id: Option<Option<Uuid>>,
+) -> AppResult<JsonValue> {
     let mut object = Map::new();
 
     if let Some(name) = name {
@@ -142,6 +210,20 @@ fn build_update_payload(name: Option<String>, description: Option<String>) -> Ap
         object.insert("description".to_string(), JsonValue::String(description));
     }
 
+    if let Some(blocks) = blocks_unverified_accounts {
+        object.insert(
+            "blocks_unverified_accounts".to_string(),
+            JsonValue::Bool(blocks),
+        );
+    }
+
+    if let Some(legal_entity_id) = legal_entity_id {
+        object.insert(
+            "legal_entity_id".to_string(),
+            legal_entity_id.map_or(JsonValue::Null, |id| JsonValue::String(id.to_string())),
+        );
+    }
+
     if object.is_empty() {
         return Err(AppError::internal("no fields supplied for payroll update"));
     }
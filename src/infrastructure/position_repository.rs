@@ -0,0 +1,221 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::position::{Position, PositionAssignment},
+    error::{AppError, AppResult},
+    services::position::PositionRepository,
+};
+
+const POSITION_TABLE: &str = "position";
+
+const BY_DIVISION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE division_id = $division_id";
+const BY_ASSIGNEE_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE history.employee_id CONTAINS $employee_id";
+const BY_EMPLOYEE_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE employee_id = $employee_id";
+
+#[derive(Clone)]
+pub struct SurrealPositionRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealPositionRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> PositionRepository for SurrealPositionRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, position: Position) -> AppResult<Position> {
+        let record: Option<PositionRecord> = self
+            .client
+            .create((POSITION_TABLE, position.id.to_string()))
+            .content(build_payload(&position))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created position"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Position>> {
+        let record: Option<PositionRecord> =
+            self.reader.select((POSITION_TABLE, id.to_string())).await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_division(&self, division_id: Uuid) -> AppResult<Vec<Position>> {
+        let mut response = self
+            .reader
+            .query(BY_DIVISION_QUERY)
+            .bind(("table", POSITION_TABLE))
+            .bind(("division_id", division_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<PositionRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<Position>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", POSITION_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<PositionRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_by_assignee(&self, employee_id: Uuid) -> AppResult<Vec<Position>> {
+        let mut response = self
+            .reader
+            .query(BY_ASSIGNEE_QUERY)
+            .bind(("table", POSITION_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<PositionRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, position: Position) -> AppResult<Option<Position>> {
+        let record: Option<PositionRecord> = self
+            .client
+            .update((POSITION_TABLE, position.id.to_string()))
+            .content(build_payload(&position))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<PositionRecord> =
+            self.client.delete((POSITION_TABLE, id.to_string())).await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PositionRecord {
+    id: Thing,
+    title: String,
+    job_id: String,
+    budget: f64,
+    division_id: String,
+    payroll_id: String,
+    #[serde(default)]
+    employee_id: Option<String>,
+    #[serde(default)]
+    history: Vec<PositionAssignmentRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PositionAssignmentRecord {
+    employee_id: String,
+    start_date: NaiveDate,
+    #[serde(default)]
+    end_date: Option<NaiveDate>,
+}
+
+fn record_to_domain(record: PositionRecord) -> AppResult<Position> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored position id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored position identifier is not a supported format",
+            ));
+        }
+    };
+
+    let parse = |value: &str, field: &str| {
+        Uuid::parse_str(value)
+            .map_err(|_| AppError::internal(format!("stored position {field} is not a UUID")))
+    };
+    let job_id = parse(&record.job_id, "job id")?;
+    let division_id = parse(&record.division_id, "division id")?;
+    let payroll_id = parse(&record.payroll_id, "payroll id")?;
+    let employee_id = record
+        .employee_id
+        .as_deref()
+        .map(|value| parse(value, "employee id"))
+        .transpose()?;
+
+    let mut position = Position::new(
+        id,
+        record.title,
+        job_id,
+        record.budget,
+        division_id,
+        payroll_id,
+    );
+    position.employee_id = employee_id;
+    position.history = record
+        .history
+        .into_iter()
+        .map(|assignment| {
+            Ok(PositionAssignment {
+                employee_id: parse(&assignment.employee_id, "assignment employee id")?,
+                start_date: assignment.start_date,
+                end_date: assignment.end_date,
+            })
+        })
+        .collect::<AppResult<_>>()?;
+    Ok(position)
+}
+
+fn build_payload(position: &Position) -> JsonValue {
+    json!({
+        "title": position.title,
+        "job_id": position.job_id,
+        "budget": position.budget,
+        "division_id": position.division_id,
+        "payroll_id": position.payroll_id,
+        "employee_id": position.employee_id,
+        "history": position
+            .history
+            .iter()
+            .map(|assignment| json!({
+                "employee_id": assignment.employee_id.to_string(),
+                "start_date": assignment.start_date,
+                "end_date": assignment.end_date,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+pub type SurrealAnyPositionRepository = SurrealPositionRepository<Any>;
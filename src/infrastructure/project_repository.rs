@@ -0,0 +1,149 @@
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::project::Project,
+    error::{AppError, AppResult},
+    services::project::ProjectRepository,
+};
+
+const PROJECT_TABLE: &str = "project";
+
+const BY_ORGANIZATION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE organization_id = $organization_id";
+
+#[derive(Clone)]
+pub struct SurrealProjectRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealProjectRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> ProjectRepository for SurrealProjectRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, project: Project) -> AppResult<Project> {
+        let record: Option<ProjectRecord> = self
+            .client
+            .create((PROJECT_TABLE, project.id.to_string()))
+            .content(build_payload(&project))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created project"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Project>> {
+        let record: Option<ProjectRecord> =
+            self.reader.select((PROJECT_TABLE, id.to_string())).await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Project>> {
+        let mut response = self
+            .reader
+            .query(BY_ORGANIZATION_QUERY)
+            .bind(("table", PROJECT_TABLE))
+            .bind(("organization_id", organization_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<ProjectRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, project: Project) -> AppResult<Option<Project>> {
+        let record: Option<ProjectRecord> = self
+            .client
+            .update((PROJECT_TABLE, project.id.to_string()))
+            .content(build_payload(&project))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<ProjectRecord> =
+            self.client.delete((PROJECT_TABLE, id.to_string())).await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ProjectRecord {
+    id: Thing,
+    organization_id: String,
+    code: String,
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    active: bool,
+}
+
+fn record_to_domain(record: ProjectRecord) -> AppResult<Project> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored project id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored project identifier is not a supported format",
+            ));
+        }
+    };
+    let organization_id = Uuid::parse_str(&record.organization_id)
+        .map_err(|_| AppError::internal("stored project organization id is not a UUID"))?;
+
+    Ok(Project {
+        active: record.active,
+        ..Project::new(
+            id,
+            organization_id,
+            record.code,
+            record.name,
+            record.description,
+        )
+    })
+}
+
+fn build_payload(project: &Project) -> JsonValue {
+    json!({
+        "organization_id": project.organization_id,
+        "code": project.code,
+        "name": project.name,
+        "description": project.description,
+        "active": project.active,
+    })
+}
+
+pub type SurrealAnyProjectRepository = SurrealProjectRepository<Any>;
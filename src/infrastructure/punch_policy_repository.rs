@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use serde_json::json;
+use surrealdb::{Connection, Surreal, engine::any::Any};
+use uuid::Uuid;
+
+use crate::{
+    domain::attendance::PunchPolicy,
+    error::{AppError, AppResult},
+    services::attendance_import::PunchPolicyRepository,
+};
+
+const PUNCH_POLICY_TABLE: &str = "punch_policy";
+
+#[derive(Clone)]
+pub struct SurrealPunchPolicyRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealPunchPolicyRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> PunchPolicyRepository for SurrealPunchPolicyRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn fetch(&self, payroll_id: Uuid) -> AppResult<Option<PunchPolicy>> {
+        let record: Option<PunchPolicyRecord> = self
+            .reader
+            .select((PUNCH_POLICY_TABLE, payroll_id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn upsert(&self, policy: PunchPolicy) -> AppResult<PunchPolicy> {
+        let record: Option<PunchPolicyRecord> = self
+            .client
+            .upsert((PUNCH_POLICY_TABLE, policy.payroll_id.to_string()))
+            .content(json!({
+                "payroll_id": policy.payroll_id,
+                "rounding_minutes": policy.rounding_minutes,
+                "grace_minutes": policy.grace_minutes,
+            }))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return stored punch policy"))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PunchPolicyRecord {
+    payroll_id: String,
+    #[serde(default)]
+    rounding_minutes: Option<u32>,
+    #[serde(default)]
+    grace_minutes: u32,
+}
+
+fn record_to_domain(record: PunchPolicyRecord) -> AppResult<PunchPolicy> {
+    let payroll_id = Uuid::parse_str(&record.payroll_id)
+        .map_err(|_| AppError::internal("stored punch policy payroll id is not a UUID"))?;
+
+    Ok(PunchPolicy {
+        payroll_id,
+        rounding_minutes: record.rounding_minutes,
+        grace_minutes: record.grace_minutes,
+    })
+}
+
+pub type SurrealAnyPunchPolicyRepository = SurrealPunchPolicyRepository<Any>;
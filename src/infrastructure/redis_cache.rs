@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use redis::{AsyncCommands, Client, RedisError, aio::ConnectionManager};
+
+use crate::{
+    error::{AppError, AppResult},
+    services::cache::CacheStore,
+};
+
+/// Cache store shared by every instance of the API, so an invalidation made by one of them is
+/// seen by all the others.
+#[derive(Clone)]
+pub struct RedisCacheStore {
+    connection: ConnectionManager,
+}
+
+impl RedisCacheStore {
+    /// Connects to `url`, e.g. `redis://cache:6379/0`; the connection is re-established on its
+    /// own after a failure.
+    pub async fn connect(url: &str) -> Result<Self, RedisError> {
+        let connection = ConnectionManager::new(Client::open(url)?).await?;
+        Ok(Self { connection })
+    }
+}
+
+#[async_trait]
+impl CacheStore for RedisCacheStore {
+    async fn get(&self, key: &str) -> AppResult<Option<String>> {
+        let mut connection = self.connection.clone();
+        connection.get(key).await.map_err(cache_error)
+    }
+
+    async fn set(&self, key: &str, value: String, ttl: Duration) -> AppResult<()> {
+        let mut connection = self.connection.clone();
+        let ttl_ms = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+        connection
+            .pset_ex(key, value, ttl_ms)
+            .await
+            .map_err(cache_error)
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        let mut connection = self.connection.clone();
+        connection.del(key).await.map_err(cache_error)
+    }
+}
+
+fn cache_error(err: RedisError) -> AppError {
+    AppError::internal(format!("cache error: {err}"))
+}
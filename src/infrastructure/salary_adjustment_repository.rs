@@ -0,0 +1,250 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        job::PayBasis,
+        salary_adjustment::{
+            SalaryAdjustmentBatch, SalaryAdjustmentStatus, SalaryAdjustmentTotals, SalaryChange,
+        },
+    },
+    error::{AppError, AppResult},
+    services::salary_adjustment::SalaryAdjustmentRepository,
+};
+
+const SALARY_ADJUSTMENT_TABLE: &str = "salary_adjustment";
+
+const BY_PAYROLL_QUERY: &str = "SELECT * FROM type::table($table) WHERE payroll_id = $payroll_id";
+
+// Effective dates are stored as `YYYY-MM-DD`, which sorts like the dates themselves.
+const DUE_QUERY: &str = "
+    SELECT * FROM type::table($table)
+        WHERE status = $approved AND effective_date <= $today
+";
+
+#[derive(Clone)]
+pub struct SurrealSalaryAdjustmentRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealSalaryAdjustmentRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> SalaryAdjustmentRepository for SurrealSalaryAdjustmentRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, batch: SalaryAdjustmentBatch) -> AppResult<SalaryAdjustmentBatch> {
+        let record: Option<SalaryAdjustmentRecord> = self
+            .client
+            .create((SALARY_ADJUSTMENT_TABLE, batch.id.to_string()))
+            .content(build_payload(&batch))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created salary adjustment"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<SalaryAdjustmentBatch>> {
+        let record: Option<SalaryAdjustmentRecord> = self
+            .reader
+            .select((SALARY_ADJUSTMENT_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<SalaryAdjustmentBatch>> {
+        let mut response = self
+            .reader
+            .query(BY_PAYROLL_QUERY)
+            .bind(("table", SALARY_ADJUSTMENT_TABLE))
+            .bind(("payroll_id", payroll_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<SalaryAdjustmentRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_due(&self, today: NaiveDate) -> AppResult<Vec<SalaryAdjustmentBatch>> {
+        let mut response = self
+            .reader
+            .query(DUE_QUERY)
+            .bind(("table", SALARY_ADJUSTMENT_TABLE))
+            .bind(("approved", SalaryAdjustmentStatus::Approved))
+            .bind(("today", today.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<SalaryAdjustmentRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(
+        &self,
+        batch: SalaryAdjustmentBatch,
+    ) -> AppResult<Option<SalaryAdjustmentBatch>> {
+        let record: Option<SalaryAdjustmentRecord> = self
+            .client
+            .update((SALARY_ADJUSTMENT_TABLE, batch.id.to_string()))
+            .content(build_payload(&batch))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SalaryAdjustmentRecord {
+    id: Thing,
+    organization_id: String,
+    payroll_id: String,
+    percentage: f64,
+    effective_date: String,
+    status: SalaryAdjustmentStatus,
+    changes: Vec<SalaryChangeRecord>,
+    #[serde(default)]
+    decision_note: Option<String>,
+    created_at: String,
+    #[serde(default)]
+    decided_at: Option<String>,
+    #[serde(default)]
+    applied_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SalaryChangeRecord {
+    job_id: String,
+    job_title: String,
+    pay_basis: PayBasis,
+    from_salary: f64,
+    to_salary: f64,
+    headcount: u32,
+    monthly_cost_before: f64,
+    monthly_cost_after: f64,
+}
+
+fn record_to_domain(record: SalaryAdjustmentRecord) -> AppResult<SalaryAdjustmentBatch> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored salary adjustment id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored salary adjustment identifier is not a supported format",
+            ));
+        }
+    };
+    let organization_id = Uuid::parse_str(&record.organization_id)
+        .map_err(|_| AppError::internal("stored organization id is not a UUID"))?;
+    let payroll_id = Uuid::parse_str(&record.payroll_id)
+        .map_err(|_| AppError::internal("stored payroll id is not a UUID"))?;
+    let effective_date = NaiveDate::parse_from_str(&record.effective_date, "%Y-%m-%d")
+        .map_err(|_| AppError::internal("stored effective date is not a valid date"))?;
+    let changes = record
+        .changes
+        .into_iter()
+        .map(|change| {
+            Ok(SalaryChange {
+                job_id: Uuid::parse_str(&change.job_id)
+                    .map_err(|_| AppError::internal("stored salary change job id is not a UUID"))?,
+                job_title: change.job_title,
+                pay_basis: change.pay_basis,
+                from_salary: change.from_salary,
+                to_salary: change.to_salary,
+                headcount: change.headcount,
+                monthly_cost_before: change.monthly_cost_before,
+                monthly_cost_after: change.monthly_cost_after,
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+    let created_at = parse_timestamp(&record.created_at)?;
+    let decided_at = record
+        .decided_at
+        .as_deref()
+        .map(parse_timestamp)
+        .transpose()?;
+    let applied_at = record
+        .applied_at
+        .as_deref()
+        .map(parse_timestamp)
+        .transpose()?;
+
+    Ok(SalaryAdjustmentBatch {
+        id,
+        organization_id,
+        payroll_id,
+        percentage: record.percentage,
+        effective_date,
+        status: record.status,
+        totals: SalaryAdjustmentTotals::of(&changes),
+        changes,
+        decision_note: record.decision_note,
+        created_at,
+        decided_at,
+        applied_at,
+    })
+}
+
+fn parse_timestamp(value: &str) -> AppResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| AppError::internal("stored salary adjustment timestamp is not valid"))
+}
+
+fn build_payload(batch: &SalaryAdjustmentBatch) -> JsonValue {
+    json!({
+        "organization_id": batch.organization_id,
+        "payroll_id": batch.payroll_id,
+        "percentage": batch.percentage,
+        "effective_date": batch.effective_date.to_string(),
+        "status": batch.status,
+        "changes": batch
+            .changes
+            .iter()
+            .map(|change| json!({
+                "job_id": change.job_id.to_string(),
+                "job_title": change.job_title,
+                "pay_basis": change.pay_basis,
+                "from_salary": change.from_salary,
+                "to_salary": change.to_salary,
+                "headcount": change.headcount,
+                "monthly_cost_before": change.monthly_cost_before,
+                "monthly_cost_after": change.monthly_cost_after,
+            }))
+            .collect::<Vec<_>>(),
+        "decision_note": batch.decision_note,
+        "created_at": batch.created_at.to_rfc3339(),
+        "decided_at": batch.decided_at.map(|timestamp| timestamp.to_rfc3339()),
+        "applied_at": batch.applied_at.map(|timestamp| timestamp.to_rfc3339()),
+    })
+}
+
+pub type SurrealAnySalaryAdjustmentRepository = SurrealSalaryAdjustmentRepository<Any>;
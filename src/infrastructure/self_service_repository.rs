@@ -0,0 +1,211 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::self_service::SelfServiceToken,
+    error::{AppError, AppResult},
+    services::self_service::SelfServiceTokenRepository,
+};
+
+const SELF_SERVICE_TOKEN_TABLE: &str = "self_service_token";
+
+const BY_EMPLOYEE_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE employee_id = $employee_id";
+const BY_SECRET_HASH_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE secret_hash = $secret_hash LIMIT 1";
+
+// Tokens are stored with a `null` revocation until revoked.
+const TOUCH_QUERY: &str = "
+    UPDATE type::thing($table, $id) MERGE { last_used_at: $used_at }
+        WHERE revoked_at IS NONE OR revoked_at IS NULL
+";
+
+#[derive(Clone)]
+pub struct SurrealSelfServiceTokenRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealSelfServiceTokenRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> SelfServiceTokenRepository for SurrealSelfServiceTokenRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, token: SelfServiceToken) -> AppResult<SelfServiceToken> {
+        let record: Option<SelfServiceTokenRecord> = self
+            .client
+            .create((SELF_SERVICE_TOKEN_TABLE, token.id.to_string()))
+            .content(build_payload(&token))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created self-service token"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<SelfServiceToken>> {
+        let record: Option<SelfServiceTokenRecord> = self
+            .reader
+            .select((SELF_SERVICE_TOKEN_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    /// Read from the primary: a replica that has not caught up with a revocation would let
+    /// the token in.
+    async fn fetch_by_secret_hash(&self, secret_hash: &str) -> AppResult<Option<SelfServiceToken>> {
+        let mut response = self
+            .client
+            .query(BY_SECRET_HASH_QUERY)
+            .bind(("table", SELF_SERVICE_TOKEN_TABLE))
+            .bind(("secret_hash", secret_hash.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<SelfServiceTokenRecord> = response.take(0)?;
+        records.into_iter().next().map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<SelfServiceToken>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", SELF_SERVICE_TOKEN_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<SelfServiceTokenRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, token: SelfServiceToken) -> AppResult<Option<SelfServiceToken>> {
+        let record: Option<SelfServiceTokenRecord> = self
+            .client
+            .update((SELF_SERVICE_TOKEN_TABLE, token.id.to_string()))
+            .content(build_payload(&token))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> AppResult<Option<SelfServiceToken>> {
+        let mut response = self
+            .client
+            .query(TOUCH_QUERY)
+            .bind(("table", SELF_SERVICE_TOKEN_TABLE))
+            .bind(("id", id.to_string()))
+            .bind(("used_at", used_at.to_rfc3339()))
+            .await?
+            .check()?;
+        let records: Vec<SelfServiceTokenRecord> = response.take(0)?;
+        records.into_iter().next().map(record_to_domain).transpose()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SelfServiceTokenRecord {
+    id: Thing,
+    organization_id: String,
+    employee_id: String,
+    #[serde(default)]
+    label: Option<String>,
+    secret_hash: String,
+    created_at: String,
+    #[serde(default)]
+    expires_at: Option<String>,
+    #[serde(default)]
+    revoked_at: Option<String>,
+    #[serde(default)]
+    last_used_at: Option<String>,
+}
+
+fn record_to_domain(record: SelfServiceTokenRecord) -> AppResult<SelfServiceToken> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored self-service token id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored self-service token identifier is not a supported format",
+            ));
+        }
+    };
+    let organization_id = Uuid::parse_str(&record.organization_id).map_err(|_| {
+        AppError::internal("stored self-service token organization id is not a UUID")
+    })?;
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored self-service token employee id is not a UUID"))?;
+
+    Ok(SelfServiceToken {
+        id,
+        organization_id,
+        employee_id,
+        label: record.label,
+        secret_hash: record.secret_hash,
+        created_at: parse_timestamp(&record.created_at)?,
+        expires_at: record
+            .expires_at
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+        revoked_at: record
+            .revoked_at
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+        last_used_at: record
+            .last_used_at
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+    })
+}
+
+fn parse_timestamp(value: &str) -> AppResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| AppError::internal("stored self-service token timestamp is not valid"))
+}
+
+fn build_payload(token: &SelfServiceToken) -> JsonValue {
+    json!({
+        "organization_id": token.organization_id,
+        "employee_id": token.employee_id,
+        "label": token.label,
+        "secret_hash": token.secret_hash,
+        "created_at": token.created_at.to_rfc3339(),
+        "expires_at": token.expires_at.map(|timestamp| timestamp.to_rfc3339()),
+        "revoked_at": token.revoked_at.map(|timestamp| timestamp.to_rfc3339()),
+        "last_used_at": token.last_used_at.map(|timestamp| timestamp.to_rfc3339()),
+    })
+}
+
+pub type SurrealAnySelfServiceTokenRepository = SurrealSelfServiceTokenRepository<Any>;
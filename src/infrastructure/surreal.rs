@@ -1,33 +1,189 @@
-use std::env;
+use std::{env, time::Duration};
 
+use serde_json::json;
 use surrealdb::{
     Surreal,
     engine::any::{self, Any},
     opt::auth::Root,
 };
 use thiserror::Error;
+use tokio::time::{Instant, sleep};
+use tracing::warn;
+
+use crate::domain::employee::EmployeeStatus;
+
+/// In-process, in-memory engine used when no SurrealDB server is configured.
+pub const EMBEDDED_URL: &str = "mem://";
+const EMBEDDED_NAMESPACE: &str = "nomina";
+const EMBEDDED_DATABASE: &str = "nomina";
+
+/// Indexes backing the parent, per-employee and due-date lookups the repositories run as `WHERE`
+/// queries.
+const INDEXES: &str = "
+    DEFINE INDEX IF NOT EXISTS bank_catalog ON TABLE bank FIELDS catalog_id;
+    DEFINE INDEX IF NOT EXISTS bank_organization ON TABLE bank FIELDS organization_id;
+    DEFINE INDEX IF NOT EXISTS custom_field_organization ON TABLE custom_field FIELDS organization_id;
+    DEFINE INDEX IF NOT EXISTS dependent_employee ON TABLE dependent FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS division_payroll ON TABLE division FIELDS payroll_id;
+    DEFINE INDEX IF NOT EXISTS employee_division ON TABLE employee FIELDS division_id;
+    DEFINE INDEX IF NOT EXISTS employee_email ON TABLE employee FIELDS email;
+    DEFINE INDEX IF NOT EXISTS employee_job ON TABLE employee FIELDS job_id;
+    DEFINE INDEX IF NOT EXISTS employee_supervisor ON TABLE employee FIELDS supervisor_id;
+    DEFINE INDEX IF NOT EXISTS employment_event_employee ON TABLE employment_event FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS import_profile_organization ON TABLE import_profile FIELDS organization_id;
+    DEFINE INDEX IF NOT EXISTS job_payroll ON TABLE job FIELDS payroll_id;
+    DEFINE INDEX IF NOT EXISTS job_grade_payroll ON TABLE job_grade FIELDS payroll_id;
+    DEFINE INDEX IF NOT EXISTS leave_adjustment_employee ON TABLE leave_adjustment FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS leave_balance_employee ON TABLE leave_balance FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS leave_blackout_division ON TABLE leave_blackout FIELDS division_id;
+    DEFINE INDEX IF NOT EXISTS leave_request_employee ON TABLE leave_request FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS legal_entity_organization ON TABLE legal_entity FIELDS organization_id;
+    DEFINE INDEX IF NOT EXISTS membership_organization ON TABLE membership FIELDS organization_id;
+    DEFINE INDEX IF NOT EXISTS organization_deletion_scheduled ON TABLE organization_deletion FIELDS scheduled_for;
+    DEFINE INDEX IF NOT EXISTS overtime_request_employee ON TABLE overtime_request FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS payroll_organization ON TABLE payroll FIELDS organization_id;
+    DEFINE INDEX IF NOT EXISTS position_division ON TABLE position FIELDS division_id;
+    DEFINE INDEX IF NOT EXISTS position_employee ON TABLE position FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS project_organization ON TABLE project FIELDS organization_id;
+    DEFINE INDEX IF NOT EXISTS salary_adjustment_payroll ON TABLE salary_adjustment FIELDS payroll_id;
+    DEFINE INDEX IF NOT EXISTS salary_adjustment_status ON TABLE salary_adjustment FIELDS status;
+    DEFINE INDEX IF NOT EXISTS self_service_token_employee ON TABLE self_service_token FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS self_service_token_secret_hash ON TABLE self_service_token FIELDS secret_hash UNIQUE;
+    DEFINE INDEX IF NOT EXISTS timesheet_entry_employee ON TABLE timesheet_entry FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS timesheet_entry_project ON TABLE timesheet_entry FIELDS project_id;
+    DEFINE INDEX IF NOT EXISTS timesheet_period_employee ON TABLE timesheet_period FIELDS employee_id;
+    DEFINE INDEX IF NOT EXISTS work_schedule_organization ON TABLE work_schedule FIELDS organization_id;
+";
+
+/// Rewrites employee statuses stored as free text before the enum existed (`On Leave`,
+/// `Inactive`, `Leaver`) to the spelling [`EmployeeStatus::as_str`] writes, so queries compare
+/// `status` directly. Returns the ids of employees whose status matches no spelling at all.
+const STATUS_MIGRATION: &str = "
+FOR $status IN $statuses {
+    UPDATE employee SET status = $status.canonical
+        WHERE status != $status.canonical
+            AND string::replace(string::replace(string::replace(
+                string::lowercase(<string> status), ' ', ''), '_', ''), '-', '') INSIDE $status.aliases;
+};
+SELECT VALUE record::id(id) FROM employee WHERE status NOTINSIDE $canonical;
+";
 
 #[derive(Debug, Clone)]
 pub struct SurrealConfig {
     pub url: String,
+    /// Read replica serving the repositories' `fetch*` lookups; writes always go to `url`.
+    pub read_url: Option<String>,
     pub namespace: String,
     pub database: String,
+    /// Root user and password; the embedded engine runs without authentication.
+    pub credentials: Option<SurrealCredentials>,
+    pub retry: RetryPolicy,
+}
+
+#[derive(Debug, Clone)]
+pub struct SurrealCredentials {
     pub username: String,
     pub password: String,
 }
 
 impl SurrealConfig {
+    /// Reads the server connection from the environment, falling back on the embedded engine
+    /// when `SURREALDB_URL` is not set so a plain `cargo run` works without a database server.
     pub fn from_env() -> Result<Self, SurrealConfigError> {
+        if env::var_os("SURREALDB_URL").is_none() {
+            return Ok(Self::embedded());
+        }
+
         Ok(Self {
             url: read_env("SURREALDB_URL")?,
+            read_url: env::var("SURREALDB_READ_URL")
+                .ok()
+                .filter(|url| !url.trim().is_empty()),
             namespace: read_env("SURREALDB_NAMESPACE")?,
             database: read_env("SURREALDB_DATABASE")?,
-            username: read_env("SURREALDB_USERNAME")?,
-            password: read_env("SURREALDB_PASSWORD")?,
+            credentials: Some(SurrealCredentials {
+                username: read_env("SURREALDB_USERNAME")?,
+                password: read_env("SURREALDB_PASSWORD")?,
+            }),
+            retry: RetryPolicy::from_env(),
+        })
+    }
+
+    /// An in-memory database living inside the process; everything is lost on restart.
+    pub fn embedded() -> Self {
+        Self {
+            url: EMBEDDED_URL.to_string(),
+            read_url: None,
+            namespace: EMBEDDED_NAMESPACE.to_string(),
+            database: EMBEDDED_DATABASE.to_string(),
+            credentials: None,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    pub fn is_embedded(&self) -> bool {
+        self.url == EMBEDDED_URL
+    }
+
+    /// Connection settings for the read replica: same namespace, database and credentials as
+    /// the primary, at `read_url`.
+    pub fn replica(&self) -> Option<Self> {
+        self.read_url.as_ref().map(|url| Self {
+            url: url.clone(),
+            read_url: None,
+            ..self.clone()
         })
     }
 }
 
+/// How long startup keeps trying to reach a database server that is not up yet, doubling the
+/// pause between attempts from `initial_delay` up to `max_delay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    /// Startup gives up once this much time has passed since the first attempt.
+    pub timeout: Duration,
+}
+
+impl RetryPolicy {
+    /// Reads `SURREALDB_RETRY_INITIAL_DELAY_MS`, `SURREALDB_RETRY_MAX_DELAY_MS` and
+    /// `SURREALDB_CONNECT_TIMEOUT_SECS`, keeping the defaults for unset or unparsable values.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            initial_delay: read_duration("SURREALDB_RETRY_INITIAL_DELAY_MS", Duration::from_millis)
+                .unwrap_or(defaults.initial_delay),
+            max_delay: read_duration("SURREALDB_RETRY_MAX_DELAY_MS", Duration::from_millis)
+                .unwrap_or(defaults.max_delay),
+            timeout: read_duration("SURREALDB_CONNECT_TIMEOUT_SECS", Duration::from_secs)
+                .unwrap_or(defaults.timeout),
+        }
+    }
+
+    /// The pause after one that lasted `previous`.
+    pub fn next_delay(&self, previous: Duration) -> Duration {
+        previous.saturating_mul(2).min(self.max_delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+fn read_duration(key: &str, unit: fn(u64) -> Duration) -> Option<Duration> {
+    env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .map(unit)
+}
+
 #[derive(Debug, Error)]
 pub enum SurrealConfigError {
     #[error("missing `{0}` environment variable")]
@@ -38,15 +194,110 @@ fn read_env(key: &'static str) -> Result<String, SurrealConfigError> {
     env::var(key).map_err(|_| SurrealConfigError::MissingEnv(key))
 }
 
+/// Connects like [`connect`], retrying failed attempts according to `config.retry` so the
+/// service can start before the database server is ready. Only reaching the server is retried;
+/// a failed migration fails at once.
+pub async fn connect_with_retry(config: &SurrealConfig) -> Result<Surreal<Any>, surrealdb::Error> {
+    let client = retry(config, || connect_reader(config)).await?;
+    prepare(&client).await?;
+
+    Ok(client)
+}
+
+/// Connects like [`connect_reader`], retrying like [`connect_with_retry`].
+pub async fn connect_reader_with_retry(
+    config: &SurrealConfig,
+) -> Result<Surreal<Any>, surrealdb::Error> {
+    retry(config, || connect_reader(config)).await
+}
+
+async fn retry<F, Fut>(config: &SurrealConfig, connect: F) -> Result<Surreal<Any>, surrealdb::Error>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<Surreal<Any>, surrealdb::Error>>,
+{
+    let deadline = Instant::now() + config.retry.timeout;
+    let mut delay = config.retry.initial_delay;
+    let mut attempt = 1u32;
+
+    loop {
+        match connect().await {
+            Ok(client) => return Ok(client),
+            Err(err) if Instant::now() + delay < deadline => {
+                warn!(
+                    "SurrealDB connection attempt {attempt} to `{}` failed: {err}; retrying in {}ms",
+                    config.url,
+                    delay.as_millis()
+                );
+                sleep(delay).await;
+                delay = config.retry.next_delay(delay);
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Connects to the primary and makes sure the schema the repositories rely on is defined.
 pub async fn connect(config: &SurrealConfig) -> Result<Surreal<Any>, surrealdb::Error> {
+    let client = connect_reader(config).await?;
+    prepare(&client).await?;
+
+    Ok(client)
+}
+
+async fn prepare(client: &Surreal<Any>) -> Result<(), surrealdb::Error> {
+    client.query(INDEXES).await?.check()?;
+    migrate(client).await
+}
+
+/// Brings rows written by older releases up to what the repositories read.
+///
+/// Fails, after migrating every row it can, when an employee's stored status matches no known
+/// spelling: such an employee could not be read, so the service refuses to start until the
+/// listed rows are corrected.
+pub async fn migrate(client: &Surreal<Any>) -> Result<(), surrealdb::Error> {
+    let statuses: Vec<_> = EmployeeStatus::ALL
+        .into_iter()
+        .map(|status| json!({ "canonical": status.as_str(), "aliases": status.aliases() }))
+        .collect();
+    let canonical: Vec<_> = EmployeeStatus::ALL
+        .into_iter()
+        .map(EmployeeStatus::as_str)
+        .collect();
+    let mut response = client
+        .query(STATUS_MIGRATION)
+        .bind(("statuses", statuses))
+        .bind(("canonical", canonical))
+        .await?
+        .check()?;
+    let unrecognized: Vec<String> = response.take(1)?;
+    if !unrecognized.is_empty() {
+        return Err(surrealdb::error::Api::Query(format!(
+            "{} employee(s) have a stored status that is not a known spelling; correct them \
+             before starting: {}",
+            unrecognized.len(),
+            unrecognized.join(", ")
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Connects without defining anything, since a read replica refuses writes and receives the
+/// primary's schema anyway.
+pub async fn connect_reader(config: &SurrealConfig) -> Result<Surreal<Any>, surrealdb::Error> {
     let client = any::connect(&config.url).await?;
 
-    client
-        .signin(Root {
-            username: &config.username,
-            password: &config.password,
-        })
-        .await?;
+    if let Some(credentials) = &config.credentials {
+        client
+            .signin(Root {
+                username: &credentials.username,
+                password: &credentials.password,
+            })
+            .await?;
+    }
 
     client
         .use_ns(&config.namespace)
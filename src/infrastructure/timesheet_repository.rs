@@ -0,0 +1,335 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::timesheet::{
+        ClockPunch, TimesheetEntry, TimesheetEntryStatus, TimesheetPeriod, TimesheetPeriodStatus,
+    },
+    error::{AppError, AppResult},
+    services::timesheet::TimesheetRepository,
+};
+
+const TIMESHEET_ENTRY_TABLE: &str = "timesheet_entry";
+const TIMESHEET_PERIOD_TABLE: &str = "timesheet_period";
+
+const BY_EMPLOYEE_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE employee_id = $employee_id";
+
+const BY_PROJECT_QUERY: &str = "SELECT * FROM type::table($table) WHERE project_id = $project_id";
+
+#[derive(Clone)]
+pub struct SurrealTimesheetRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealTimesheetRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> TimesheetRepository for SurrealTimesheetRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, entry: TimesheetEntry) -> AppResult<TimesheetEntry> {
+        let record: Option<TimesheetEntryRecord> = self
+            .client
+            .create((TIMESHEET_ENTRY_TABLE, entry.id.to_string()))
+            .content(build_payload(&entry))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created timesheet entry"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<TimesheetEntry>> {
+        let record: Option<TimesheetEntryRecord> = self
+            .reader
+            .select((TIMESHEET_ENTRY_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<TimesheetEntry>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", TIMESHEET_ENTRY_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<TimesheetEntryRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn fetch_by_project(&self, project_id: Uuid) -> AppResult<Vec<TimesheetEntry>> {
+        let mut response = self
+            .reader
+            .query(BY_PROJECT_QUERY)
+            .bind(("table", TIMESHEET_ENTRY_TABLE))
+            .bind(("project_id", project_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<TimesheetEntryRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, entry: TimesheetEntry) -> AppResult<Option<TimesheetEntry>> {
+        let record: Option<TimesheetEntryRecord> = self
+            .client
+            .update((TIMESHEET_ENTRY_TABLE, entry.id.to_string()))
+            .content(build_payload(&entry))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<TimesheetEntryRecord> = self
+            .client
+            .delete((TIMESHEET_ENTRY_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+
+    async fn insert_period(&self, period: TimesheetPeriod) -> AppResult<TimesheetPeriod> {
+        let record: Option<TimesheetPeriodRecord> = self
+            .client
+            .create((TIMESHEET_PERIOD_TABLE, period.id.to_string()))
+            .content(build_period_payload(&period))
+            .await?;
+
+        record
+            .map(period_record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created timesheet"))
+    }
+
+    async fn fetch_period(&self, id: Uuid) -> AppResult<Option<TimesheetPeriod>> {
+        let record: Option<TimesheetPeriodRecord> = self
+            .reader
+            .select((TIMESHEET_PERIOD_TABLE, id.to_string()))
+            .await?;
+
+        record.map(period_record_to_domain).transpose()
+    }
+
+    async fn fetch_periods_by_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<TimesheetPeriod>> {
+        let mut response = self
+            .reader
+            .query(BY_EMPLOYEE_QUERY)
+            .bind(("table", TIMESHEET_PERIOD_TABLE))
+            .bind(("employee_id", employee_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<TimesheetPeriodRecord> = response.take(0)?;
+        records.into_iter().map(period_record_to_domain).collect()
+    }
+
+    async fn update_period(&self, period: TimesheetPeriod) -> AppResult<Option<TimesheetPeriod>> {
+        let record: Option<TimesheetPeriodRecord> = self
+            .client
+            .update((TIMESHEET_PERIOD_TABLE, period.id.to_string()))
+            .content(build_period_payload(&period))
+            .await?;
+
+        record.map(period_record_to_domain).transpose()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TimesheetEntryRecord {
+    id: Thing,
+    employee_id: String,
+    date: String,
+    hours: f64,
+    #[serde(default)]
+    cost_code: Option<String>,
+    #[serde(default)]
+    project_id: Option<String>,
+    #[serde(default)]
+    note: Option<String>,
+    status: TimesheetEntryStatus,
+    #[serde(default)]
+    review_note: Option<String>,
+    #[serde(default)]
+    reviewed_at: Option<String>,
+    #[serde(default)]
+    punch: Option<ClockPunch>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TimesheetPeriodRecord {
+    id: Thing,
+    employee_id: String,
+    period_start: String,
+    period_end: String,
+    hours: f64,
+    status: TimesheetPeriodStatus,
+    submitted_at: String,
+    #[serde(default)]
+    reviewed_by: Option<String>,
+    #[serde(default)]
+    review_note: Option<String>,
+    #[serde(default)]
+    reviewed_at: Option<String>,
+}
+
+fn record_to_domain(record: TimesheetEntryRecord) -> AppResult<TimesheetEntry> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored timesheet entry id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored timesheet entry identifier is not a supported format",
+            ));
+        }
+    };
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored timesheet entry employee id is not a UUID"))?;
+    let date = NaiveDate::parse_from_str(&record.date, "%Y-%m-%d")
+        .map_err(|_| AppError::internal("stored timesheet entry date is not a valid date"))?;
+    let project_id = record
+        .project_id
+        .as_deref()
+        .map(|value| {
+            Uuid::parse_str(value)
+                .map_err(|_| AppError::internal("stored timesheet entry project id is not a UUID"))
+        })
+        .transpose()?;
+    let reviewed_at = record
+        .reviewed_at
+        .as_deref()
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|_| AppError::internal("stored timesheet review timestamp is not valid"))
+        })
+        .transpose()?;
+
+    Ok(TimesheetEntry {
+        id,
+        employee_id,
+        date,
+        hours: record.hours,
+        cost_code: record.cost_code,
+        project_id,
+        note: record.note,
+        status: record.status,
+        review_note: record.review_note,
+        reviewed_at,
+        punch: record.punch,
+    })
+}
+
+fn build_payload(entry: &TimesheetEntry) -> JsonValue {
+    json!({
+        "employee_id": entry.employee_id,
+        "date": entry.date.to_string(),
+        "hours": entry.hours,
+        "cost_code": entry.cost_code,
+        "project_id": entry.project_id,
+        "note": entry.note,
+        "status": entry.status,
+        "review_note": entry.review_note,
+        "reviewed_at": entry.reviewed_at.map(|timestamp| timestamp.to_rfc3339()),
+        "punch": entry.punch,
+    })
+}
+
+fn period_record_to_domain(record: TimesheetPeriodRecord) -> AppResult<TimesheetPeriod> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored timesheet id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored timesheet identifier is not a supported format",
+            ));
+        }
+    };
+    let employee_id = Uuid::parse_str(&record.employee_id)
+        .map_err(|_| AppError::internal("stored timesheet employee id is not a UUID"))?;
+    let parse_date = |value: &str| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| AppError::internal("stored timesheet period is not a valid date"))
+    };
+    let parse_timestamp = |value: &str| {
+        DateTime::parse_from_rfc3339(value)
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+            .map_err(|_| AppError::internal("stored timesheet timestamp is not valid"))
+    };
+    let reviewed_by = record
+        .reviewed_by
+        .as_deref()
+        .map(|value| {
+            Uuid::parse_str(value)
+                .map_err(|_| AppError::internal("stored timesheet reviewer id is not a UUID"))
+        })
+        .transpose()?;
+
+    Ok(TimesheetPeriod {
+        id,
+        employee_id,
+        period_start: parse_date(&record.period_start)?,
+        period_end: parse_date(&record.period_end)?,
+        hours: record.hours,
+        status: record.status,
+        submitted_at: parse_timestamp(&record.submitted_at)?,
+        reviewed_by,
+        review_note: record.review_note,
+        reviewed_at: record
+            .reviewed_at
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+    })
+}
+
+fn build_period_payload(period: &TimesheetPeriod) -> JsonValue {
+    json!({
+        "employee_id": period.employee_id,
+        "period_start": period.period_start.to_string(),
+        "period_end": period.period_end.to_string(),
+        "hours": period.hours,
+        "status": period.status,
+        "submitted_at": period.submitted_at.to_rfc3339(),
+        "reviewed_by": period.reviewed_by,
+        "review_note": period.review_note,
+        "reviewed_at": period.reviewed_at.map(|timestamp| timestamp.to_rfc3339()),
+    })
+}
+
+pub type SurrealAnyTimesheetRepository = SurrealTimesheetRepository<Any>;
@@ -0,0 +1,152 @@
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use surrealdb::{
+    Connection, Surreal,
+    engine::any::Any,
+    sql::{Id, Thing},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::work_schedule::{ScheduleDay, WorkSchedule},
+    error::{AppError, AppResult},
+    services::work_schedule::WorkScheduleRepository,
+};
+
+const WORK_SCHEDULE_TABLE: &str = "work_schedule";
+
+const BY_ORGANIZATION_QUERY: &str =
+    "SELECT * FROM type::table($table) WHERE organization_id = $organization_id";
+
+#[derive(Clone)]
+pub struct SurrealWorkScheduleRepository<C>
+where
+    C: Connection,
+{
+    client: Surreal<C>,
+    reader: Surreal<C>,
+}
+
+impl<C> SurrealWorkScheduleRepository<C>
+where
+    C: Connection,
+{
+    pub fn new(client: Surreal<C>) -> Self {
+        Self {
+            reader: client.clone(),
+            client,
+        }
+    }
+
+    pub fn with_reader(mut self, reader: Surreal<C>) -> Self {
+        self.reader = reader;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> WorkScheduleRepository for SurrealWorkScheduleRepository<C>
+where
+    C: Connection + Clone + Send + Sync + 'static,
+{
+    async fn insert(&self, schedule: WorkSchedule) -> AppResult<WorkSchedule> {
+        let record: Option<WorkScheduleRecord> = self
+            .client
+            .create((WORK_SCHEDULE_TABLE, schedule.id.to_string()))
+            .content(build_payload(&schedule))
+            .await?;
+
+        record
+            .map(record_to_domain)
+            .transpose()?
+            .ok_or_else(|| AppError::internal("database did not return created work schedule"))
+    }
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<WorkSchedule>> {
+        let record: Option<WorkScheduleRecord> = self
+            .reader
+            .select((WORK_SCHEDULE_TABLE, id.to_string()))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<WorkSchedule>> {
+        let mut response = self
+            .reader
+            .query(BY_ORGANIZATION_QUERY)
+            .bind(("table", WORK_SCHEDULE_TABLE))
+            .bind(("organization_id", organization_id.to_string()))
+            .await?
+            .check()?;
+        let records: Vec<WorkScheduleRecord> = response.take(0)?;
+        records.into_iter().map(record_to_domain).collect()
+    }
+
+    async fn update(&self, schedule: WorkSchedule) -> AppResult<Option<WorkSchedule>> {
+        let record: Option<WorkScheduleRecord> = self
+            .client
+            .update((WORK_SCHEDULE_TABLE, schedule.id.to_string()))
+            .content(build_payload(&schedule))
+            .await?;
+
+        record.map(record_to_domain).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        let record: Option<WorkScheduleRecord> = self
+            .client
+            .delete((WORK_SCHEDULE_TABLE, id.to_string()))
+            .await?;
+
+        Ok(record.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkScheduleRecord {
+    id: Thing,
+    name: String,
+    days: Vec<ScheduleDay>,
+    #[serde(default)]
+    daily_overtime_after: Option<f64>,
+    #[serde(default)]
+    weekly_overtime_after: Option<f64>,
+    organization_id: String,
+}
+
+fn record_to_domain(record: WorkScheduleRecord) -> AppResult<WorkSchedule> {
+    let id = match record.id.id {
+        Id::String(value) => Uuid::parse_str(&value)
+            .map_err(|_| AppError::internal("stored work schedule id is not a UUID"))?,
+        Id::Uuid(value) => uuid::Uuid::from(value),
+        _ => {
+            return Err(AppError::internal(
+                "stored work schedule identifier is not a supported format",
+            ));
+        }
+    };
+    let organization_id = Uuid::parse_str(&record.organization_id)
+        .map_err(|_| AppError::internal("stored organization id is not a UUID"))?;
+
+    Ok(WorkSchedule::new(
+        id,
+        record.name,
+        record.days,
+        record.daily_overtime_after,
+        record.weekly_overtime_after,
+        organization_id,
+    ))
+}
+
+fn build_payload(schedule: &WorkSchedule) -> JsonValue {
+    json!({
+        "name": schedule.name,
+        "days": schedule.days,
+        "daily_overtime_after": schedule.daily_overtime_after,
+        "weekly_overtime_after": schedule.weekly_overtime_after,
+        "organization_id": schedule.organization_id,
+    })
+}
+
+pub type SurrealAnyWorkScheduleRepository = SurrealWorkScheduleRepository<Any>;
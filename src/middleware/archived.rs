@@ -0,0 +1,71 @@
+//! Keeps archived organizations read-only across every route nested under them, and across
+//! the flat routes that name the organization in their query string.
+
+use axum::{
+    extract::{Query, Request, State},
+    http::{Method, Uri},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::server::AppState;
+
+/// Routes that still accept writes while the organization is archived: reactivating it,
+/// scheduling or cancelling its deletion, and cloning it into a new organization.
+const WRITABLE_WHEN_ARCHIVED: [&str; 3] = ["reactivate", "deletion", "clone"];
+
+/// Top-level routes that address a record by id alone, with `?organization_id=` saying which
+/// organization it belongs to.
+const FLAT_ROUTES: [&str; 3] = ["divisions", "jobs", "employees"];
+
+#[derive(Deserialize)]
+struct OrganizationQuery {
+    organization_id: Uuid,
+}
+
+/// Answers 409 to any change under `/organizations/{id}`, or to a flat route of that
+/// organization, while it is archived; reads go through untouched.
+pub async fn reject_archived_writes(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(organization_id) = guarded_organization(request.method(), request.uri())
+        && let Err(error) = state
+            .organization_service()
+            .ensure_writable(organization_id)
+            .await
+    {
+        return error.into_response();
+    }
+
+    next.run(request).await
+}
+
+/// The organization a write to `uri` would change, unless the route stays writable.
+fn guarded_organization(method: &Method, uri: &Uri) -> Option<Uuid> {
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return None;
+    }
+
+    let mut segments = uri.path().trim_matches('/').split('/');
+    match segments.next() {
+        Some("organizations") => {}
+        // The handler rejects a flat route without the query itself.
+        Some(flat) if FLAT_ROUTES.contains(&flat) => {
+            return Query::<OrganizationQuery>::try_from_uri(uri)
+                .ok()
+                .map(|Query(query)| query.organization_id);
+        }
+        _ => return None,
+    }
+    let organization_id = segments.next().and_then(|id| Uuid::parse_str(id).ok())?;
+    match segments.next() {
+        // Scheduling the deletion of the organization itself.
+        None if *method == Method::DELETE => None,
+        Some(nested) if WRITABLE_WHEN_ARCHIVED.contains(&nested) => None,
+        _ => Some(organization_id),
+    }
+}
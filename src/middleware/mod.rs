@@ -1,2 +1,4 @@
 #![allow(dead_code)]
 //! Custom Tower middleware layers are defined in this module.
+
+pub mod archived;
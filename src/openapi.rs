@@ -5,11 +5,86 @@ use utoipa::OpenApi;
 #[openapi(
     paths(
         crate::handlers::health::check,
+        crate::handlers::health::integrations,
+        crate::handlers::health::ready,
+        crate::handlers::api_collection::postman,
+        crate::handlers::work_schedule::create,
+        crate::handlers::work_schedule::list,
+        crate::handlers::work_schedule::get,
+        crate::handlers::work_schedule::update,
+        crate::handlers::work_schedule::delete,
+        crate::handlers::leave::balances,
+        crate::handlers::leave::set_balance,
+        crate::handlers::leave::create,
+        crate::handlers::leave::list,
+        crate::handlers::leave::get,
+        crate::handlers::leave::approve,
+        crate::handlers::leave::reject,
+        crate::handlers::leave::get_pay_policy,
+        crate::handlers::leave::set_pay_policy,
+        crate::handlers::leave::pay_days,
+        crate::handlers::leave::get_carryover_rule,
+        crate::handlers::leave::set_carryover_rule,
+        crate::handlers::leave::run_carryover,
+        crate::handlers::leave::adjustments,
+        crate::handlers::leave::create_blackout,
+        crate::handlers::leave::list_blackouts,
+        crate::handlers::leave::delete_blackout,
+        crate::handlers::timesheet::create,
+        crate::handlers::timesheet::list,
+        crate::handlers::timesheet::get,
+        crate::handlers::timesheet::update,
+        crate::handlers::timesheet::delete,
+        crate::handlers::timesheet::approve,
+        crate::handlers::timesheet::reject,
+        crate::handlers::timesheet::submit,
+        crate::handlers::timesheet::list_periods,
+        crate::handlers::timesheet::approve_period,
+        crate::handlers::timesheet::reject_period,
+        crate::handlers::timesheet::unapproved,
+        crate::handlers::overtime::get_policy,
+        crate::handlers::overtime::set_policy,
+        crate::handlers::overtime::create_request,
+        crate::handlers::overtime::list_requests,
+        crate::handlers::overtime::approve_request,
+        crate::handlers::overtime::reject_request,
+        crate::handlers::overtime::pay,
+        crate::handlers::overtime::warnings,
+        crate::handlers::project::create,
+        crate::handlers::project::list,
+        crate::handlers::project::get,
+        crate::handlers::project::update,
+        crate::handlers::project::delete,
+        crate::handlers::project::labor_costs,
+        crate::handlers::attendance_import::import,
+        crate::handlers::attendance_import::get_punch_policy,
+        crate::handlers::attendance_import::set_punch_policy,
         crate::handlers::organization::create,
         crate::handlers::organization::list,
         crate::handlers::organization::get,
         crate::handlers::organization::update,
         crate::handlers::organization::delete,
+        crate::handlers::organization::clone,
+        crate::handlers::organization::get_deletion,
+        crate::handlers::organization::cancel_deletion,
+        crate::handlers::organization::archive,
+        crate::handlers::organization::reactivate,
+        crate::handlers::organization::restore,
+        crate::handlers::organization::get_settings,
+        crate::handlers::organization::update_settings,
+        crate::handlers::organization::upload_logo,
+        crate::handlers::organization::get_logo,
+        crate::handlers::organization::delete_logo,
+        crate::handlers::organization::calendar,
+        crate::handlers::membership::invite,
+        crate::handlers::membership::list,
+        crate::handlers::membership::update_role,
+        crate::handlers::membership::remove,
+        crate::handlers::legal_entity::create,
+        crate::handlers::legal_entity::list,
+        crate::handlers::legal_entity::get,
+        crate::handlers::legal_entity::update,
+        crate::handlers::legal_entity::delete,
         crate::handlers::payroll::create,
         crate::handlers::payroll::list,
         crate::handlers::payroll::get,
@@ -17,62 +92,330 @@ use utoipa::OpenApi;
         crate::handlers::payroll::delete,
         crate::handlers::job::create,
         crate::handlers::job::list,
+        crate::handlers::job::headcount,
+        crate::handlers::job::employees,
         crate::handlers::job::get,
         crate::handlers::job::update,
         crate::handlers::job::delete,
+        crate::handlers::job::search,
+        crate::handlers::job::find,
+        crate::handlers::job::update_by_id,
+        crate::handlers::job::delete_by_id,
+        crate::handlers::job_grade::create,
+        crate::handlers::job_grade::list,
+        crate::handlers::job_grade::get,
+        crate::handlers::job_grade::update,
+        crate::handlers::job_grade::delete,
+        crate::handlers::salary_adjustment::create,
+        crate::handlers::salary_adjustment::list,
+        crate::handlers::salary_adjustment::get,
+        crate::handlers::salary_adjustment::approve,
+        crate::handlers::salary_adjustment::reject,
+        crate::handlers::pay_equity::report,
         crate::handlers::division::create,
         crate::handlers::division::list,
         crate::handlers::division::get,
         crate::handlers::division::update,
         crate::handlers::division::delete,
+        crate::handlers::division::search,
+        crate::handlers::division::find,
+        crate::handlers::division::update_by_id,
+        crate::handlers::division::delete_by_id,
+        crate::handlers::division::assign_manager,
+        crate::handlers::division::move_subtree,
         crate::handlers::bank::create,
         crate::handlers::bank::list,
         crate::handlers::bank::get,
         crate::handlers::bank::update,
         crate::handlers::bank::delete,
+        crate::handlers::bank::create_catalog_entry,
+        crate::handlers::bank::list_catalog,
+        crate::handlers::bank::get_catalog_entry,
+        crate::handlers::bank::update_catalog_entry,
+        crate::handlers::bank::delete_catalog_entry,
         crate::handlers::employee::create,
         crate::handlers::employee::list,
         crate::handlers::employee::get,
         crate::handlers::employee::update,
         crate::handlers::employee::delete,
+        crate::handlers::employee::search,
+        crate::handlers::employee::list_organization,
+        crate::handlers::employee::find,
+        crate::handlers::employee::update_by_id,
+        crate::handlers::employee::delete_by_id,
+        crate::handlers::employee::terminate,
+        crate::handlers::employee::rehire,
+        crate::handlers::employee::employment_history,
+        crate::handlers::employee::timeline,
+        crate::handlers::employee::expected_hours,
+        crate::handlers::employee::period_pay,
+        crate::handlers::profile_completeness::employee,
+        crate::handlers::profile_completeness::organization,
+        crate::handlers::milestone::upcoming,
+        crate::handlers::expiry::expiring_soon,
+        crate::handlers::expiry::expiring_permits,
+        crate::handlers::email_verification::send,
+        crate::handlers::email_verification::confirm,
+        crate::handlers::account_verification::record_micro_deposit,
+        crate::handlers::account_verification::record_document,
+        crate::handlers::account_verification::unverified_accounts,
+        crate::handlers::self_service::issue,
+        crate::handlers::self_service::list,
+        crate::handlers::self_service::revoke,
+        crate::handlers::self_service::me,
+        crate::handlers::employee::direct_reports,
+        crate::handlers::employee::upload_photo,
+        crate::handlers::employee::get_photo,
+        crate::handlers::employee_import::import,
+        crate::handlers::employee_export::export,
+        crate::handlers::benchmark_export::export,
+        crate::handlers::dependent::create,
+        crate::handlers::dependent::list,
+        crate::handlers::dependent::get,
+        crate::handlers::dependent::update,
+        crate::handlers::dependent::delete,
+        crate::handlers::import_profile::create,
+        crate::handlers::import_profile::list,
+        crate::handlers::import_profile::get,
+        crate::handlers::import_profile::update,
+        crate::handlers::import_profile::delete,
+        crate::handlers::custom_field::create,
+        crate::handlers::custom_field::list,
+        crate::handlers::custom_field::get,
+        crate::handlers::custom_field::update,
+        crate::handlers::custom_field::delete,
+        crate::handlers::position::create,
+        crate::handlers::position::list,
+        crate::handlers::position::get,
+        crate::handlers::position::update,
+        crate::handlers::position::delete,
+        crate::handlers::position::assign,
+        crate::handlers::position::unassign,
     ),
     components(
         schemas(
             crate::domain::health::Health,
+            crate::domain::health::IntegrationHealthReport,
+            crate::domain::health::IntegrationHealth,
+            crate::domain::health::IntegrationStatus,
+            crate::domain::health::ReadinessReport,
+            crate::domain::health::DependencyHealth,
+            crate::domain::health::DependencyStatus,
             crate::domain::organization::Organization,
+            crate::domain::organization::LegalIdentity,
+            crate::domain::organization::Employer,
+            crate::domain::membership::MemberRole,
+            crate::domain::membership::Membership,
+            crate::domain::legal_entity::LegalEntity,
+            crate::domain::legal_entity::DebtorAccount,
+            crate::domain::organization_deletion::OrganizationDeletion,
+            crate::domain::organization_settings::OrganizationSettings,
+            crate::domain::organization_settings::FiscalYearStart,
+            crate::domain::organization_settings::RoundingRule,
+            crate::domain::organization_settings::RoundingMode,
+            crate::domain::calendar::OrganizationCalendar,
+            crate::domain::calendar::CalendarEvent,
+            crate::domain::calendar::CalendarEventKind,
             crate::domain::payroll::Payroll,
             crate::domain::job::Job,
+            crate::domain::job::SalaryRangeStatus,
+            crate::domain::job::HeadcountPolicy,
+            crate::domain::job::PayBasis,
+            crate::domain::job::PayPeriod,
+            crate::domain::job::PeriodPay,
+            crate::domain::job::JobHeadcount,
+            crate::domain::job_grade::JobGrade,
+            crate::domain::salary_adjustment::SalaryAdjustmentStatus,
+            crate::domain::salary_adjustment::SalaryChange,
+            crate::domain::salary_adjustment::SalaryAdjustmentTotals,
+            crate::domain::salary_adjustment::SalaryAdjustmentBatch,
+            crate::domain::pay_equity::PayEquityGroup,
+            crate::domain::pay_equity::GradePayEquity,
+            crate::domain::pay_equity::PayEquityReport,
             crate::domain::division::Division,
             crate::domain::bank::Bank,
+            crate::domain::bank::AccountRule,
+            crate::domain::bank::AccountChecksum,
+            crate::domain::bank::PaymentFormat,
+            crate::domain::bank::CatalogBank,
             crate::domain::employee::Employee,
+            crate::domain::employee::EmployeeStatus,
+            crate::domain::employee::PaymentInstruction,
+            crate::domain::employee::WorkPermit,
+            crate::domain::employee::PaymentAmount,
+            crate::domain::employee::PaymentAllocation,
+            crate::domain::employee::AccountVerification,
+            crate::domain::employee::AccountVerificationStatus,
+            crate::domain::employee::AccountVerificationMethod,
+            crate::domain::employee::UnverifiedAccount,
+            crate::domain::employee::UnverifiedAccountReport,
+            crate::domain::employment_history::EmploymentEvent,
+            crate::domain::employment_history::EmploymentEventKind,
+            crate::domain::employment_history::TerminationTerms,
+            crate::domain::employment_history::StatusChange,
+            crate::domain::employment_history::JobChange,
+            crate::domain::employment_history::DivisionTransfer,
+            crate::domain::employment_history::SalaryAdjustmentChange,
+            crate::domain::employment_history::TimelineEntry,
+            crate::domain::employment_history::TimelineEntryKind,
+            crate::domain::dependent::Dependent,
+            crate::domain::position::Position,
+            crate::domain::position::PositionAssignment,
+            crate::domain::work_schedule::ScheduleDay,
+            crate::domain::work_schedule::WorkExpectation,
+            crate::domain::leave::LeaveType,
+            crate::domain::leave::LeaveRequestStatus,
+            crate::domain::leave::LeaveBalance,
+            crate::domain::leave::LeaveRequest,
+            crate::domain::leave::LeavePayTier,
+            crate::domain::leave::LeavePayPolicy,
+            crate::domain::leave::LeavePayDays,
+            crate::domain::leave::LeaveCarryoverRule,
+            crate::domain::leave::LeaveAdjustmentReason,
+            crate::domain::leave::LeaveBalanceAdjustment,
+            crate::domain::leave::BlackoutEnforcement,
+            crate::domain::leave::LeaveBlackout,
+            crate::domain::timesheet::TimesheetEntry,
+            crate::domain::timesheet::TimesheetEntryStatus,
+            crate::domain::timesheet::ClockPunch,
+            crate::domain::timesheet::TimesheetPeriod,
+            crate::domain::timesheet::TimesheetPeriodStatus,
+            crate::domain::timesheet::UnapprovedTimesheet,
+            crate::domain::timesheet::UnapprovedTimesheetReason,
+            crate::domain::timesheet::UnapprovedTimesheetReport,
+            crate::domain::overtime::OvertimePolicy,
+            crate::domain::overtime::OvertimeRequest,
+            crate::domain::overtime::OvertimeRequestStatus,
+            crate::domain::overtime::OvertimeDay,
+            crate::domain::overtime::OvertimePay,
+            crate::domain::overtime::OvertimeWarning,
+            crate::domain::overtime::OvertimeWarningReport,
+            crate::domain::project::Project,
+            crate::domain::project::ProjectLaborCost,
+            crate::domain::project::ProjectLaborCostReport,
+            crate::domain::attendance::ClockRecord,
+            crate::domain::attendance::AttendanceAnomalyKind,
+            crate::domain::attendance::AttendanceAnomaly,
+            crate::domain::attendance::AttendanceImportRow,
+            crate::domain::attendance::AttendanceImportReport,
+            crate::domain::attendance::PunchPolicy,
+            crate::handlers::attendance_import::SetPunchPolicyRequest,
+            crate::domain::import_profile::ImportProfile,
+            crate::domain::custom_field::CustomFieldDefinition,
+            crate::domain::custom_field::CustomFieldType,
+            crate::domain::employee_import::EmployeeImportReport,
+            crate::domain::employee_import::EmployeeImportRow,
+            crate::domain::employee_import::ImportRowStatus,
+            crate::domain::employee_import::EmployeeMatchKey,
+            crate::domain::employee_export::ExportFormat,
+            crate::domain::profile_completeness::ProfileCompleteness,
+            crate::domain::profile_completeness::OrganizationCompleteness,
+            crate::domain::milestone::MilestoneKind,
+            crate::domain::milestone::Milestone,
+            crate::domain::milestone::DivisionMilestones,
+            crate::domain::expiry::ExpiryKind,
+            crate::domain::expiry::Expiry,
+            crate::domain::expiry::PermitExpiry,
+            crate::domain::self_service::SelfServiceToken,
+            crate::domain::self_service::IssuedSelfServiceToken,
             crate::handlers::organization::CreateOrganizationRequest,
             crate::handlers::organization::UpdateOrganizationRequest,
+            crate::handlers::organization::UpdateOrganizationSettingsRequest,
             crate::handlers::organization::OrganizationResponse,
+            crate::handlers::organization::OrganizationDeletionResponse,
+            crate::handlers::organization::CloneOrganizationRequest,
+            crate::handlers::organization::OrganizationCloneResponse,
+            crate::handlers::membership::InviteMemberRequest,
+            crate::handlers::membership::UpdateMemberRoleRequest,
+            crate::handlers::legal_entity::CreateLegalEntityRequest,
+            crate::handlers::legal_entity::UpdateLegalEntityRequest,
             crate::handlers::payroll::CreatePayrollRequest,
             crate::handlers::payroll::UpdatePayrollRequest,
             crate::handlers::payroll::PayrollResponse,
             crate::handlers::job::CreateJobRequest,
             crate::handlers::job::UpdateJobRequest,
             crate::handlers::job::JobResponse,
+            crate::handlers::job_grade::CreateJobGradeRequest,
+            crate::handlers::job_grade::UpdateJobGradeRequest,
+            crate::handlers::job_grade::JobGradeResponse,
+            crate::handlers::salary_adjustment::CreateSalaryAdjustmentRequest,
+            crate::handlers::salary_adjustment::DecideSalaryAdjustmentRequest,
             crate::handlers::division::CreateDivisionRequest,
             crate::handlers::division::UpdateDivisionRequest,
             crate::handlers::division::DivisionResponse,
+            crate::handlers::division::AssignDivisionManagerRequest,
+            crate::handlers::division::MoveDivisionRequest,
             crate::handlers::bank::CreateBankRequest,
             crate::handlers::bank::UpdateBankRequest,
             crate::handlers::bank::BankResponse,
+            crate::handlers::bank::CreateCatalogBankRequest,
+            crate::handlers::bank::UpdateCatalogBankRequest,
             crate::handlers::employee::CreateEmployeeRequest,
             crate::handlers::employee::UpdateEmployeeRequest,
+            crate::handlers::employee::TerminateEmployeeRequest,
+            crate::handlers::employee::RehireEmployeeRequest,
             crate::handlers::employee::EmployeeResponse,
+            crate::handlers::employee::EmployeePageResponse,
+            crate::handlers::dependent::CreateDependentRequest,
+            crate::handlers::dependent::UpdateDependentRequest,
+            crate::handlers::dependent::DependentResponse,
+            crate::handlers::import_profile::CreateImportProfileRequest,
+            crate::handlers::import_profile::UpdateImportProfileRequest,
+            crate::handlers::import_profile::ImportProfileResponse,
+            crate::handlers::custom_field::CreateCustomFieldRequest,
+            crate::handlers::custom_field::UpdateCustomFieldRequest,
+            crate::handlers::custom_field::CustomFieldResponse,
+            crate::handlers::position::CreatePositionRequest,
+            crate::handlers::position::UpdatePositionRequest,
+            crate::handlers::position::AssignPositionRequest,
+            crate::handlers::position::UnassignPositionRequest,
+            crate::handlers::position::PositionResponse,
+            crate::handlers::work_schedule::CreateWorkScheduleRequest,
+            crate::handlers::work_schedule::UpdateWorkScheduleRequest,
+            crate::handlers::work_schedule::WorkScheduleResponse,
+            crate::handlers::leave::CreateLeaveRequestRequest,
+            crate::handlers::leave::DecideLeaveRequestRequest,
+            crate::handlers::leave::SetLeaveBalanceRequest,
+            crate::handlers::leave::SetLeavePayPolicyRequest,
+            crate::handlers::leave::SetLeaveCarryoverRuleRequest,
+            crate::handlers::leave::RunLeaveCarryoverRequest,
+            crate::handlers::leave::CreateLeaveBlackoutRequest,
+            crate::handlers::timesheet::CreateTimesheetEntryRequest,
+            crate::handlers::timesheet::UpdateTimesheetEntryRequest,
+            crate::handlers::timesheet::ReviewTimesheetEntryRequest,
+            crate::handlers::timesheet::SubmitTimesheetRequest,
+            crate::handlers::timesheet::ReviewTimesheetRequest,
+            crate::handlers::overtime::SetOvertimePolicyRequest,
+            crate::handlers::overtime::CreateOvertimeRequestRequest,
+            crate::handlers::overtime::ReviewOvertimeRequestRequest,
+            crate::handlers::project::CreateProjectRequest,
+            crate::handlers::project::UpdateProjectRequest,
+            crate::handlers::self_service::IssueSelfServiceTokenRequest,
+            crate::handlers::email_verification::EmailVerificationSentResponse,
+            crate::handlers::email_verification::ConfirmEmailRequest,
+            crate::handlers::account_verification::MicroDepositOutcome,
+            crate::handlers::account_verification::RecordMicroDepositRequest,
+            crate::handlers::account_verification::RecordDocumentVerificationRequest,
         )
     ),
     tags(
         (name = "Health", description = "Service health endpoints"),
+        (name = "API Docs", description = "Client collections generated from this document"),
         (name = "Organizations", description = "Organization management"),
         (name = "Payrolls", description = "Payroll management"),
         (name = "Jobs", description = "Job management"),
         (name = "Divisions", description = "Division management"),
-        (name = "Banks", description = "Bank management"),
+        (name = "Banks", description = "Bank management and the shared bank catalog"),
         (name = "Employees", description = "Employee management"),
+        (name = "Dependents", description = "Employee dependent management"),
+        (name = "Positions", description = "Division seats, vacancies, and assignment history"),
+        (name = "Work Schedules", description = "Weekly working patterns and overtime thresholds"),
+        (name = "Leave", description = "Leave balances and time-off requests"),
+        (name = "Timesheets", description = "Logged hours, overtime and their approval"),
+        (name = "Projects", description = "Projects timesheet hours are charged to, and their labor cost"),
+        (name = "Self-Service", description = "Read-only employee access through scoped tokens"),
+        (name = "Custom Fields", description = "Organization-defined employee attributes"),
+        (name = "Import Profiles", description = "CSV column mapping profiles for employee imports"),
     )
 )]
 pub struct ApiDoc;
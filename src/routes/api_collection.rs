@@ -0,0 +1,10 @@
+use axum::{Router, routing::get};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new().route(
+        "/api-docs/postman.json",
+        get(handlers::api_collection::postman),
+    )
+}
@@ -17,4 +17,14 @@ pub fn router() -> Router<AppState> {
                 .put(handlers::bank::update)
                 .delete(handlers::bank::delete),
         )
+        .route(
+            "/bank-catalog",
+            post(handlers::bank::create_catalog_entry).get(handlers::bank::list_catalog),
+        )
+        .route(
+            "/bank-catalog/{catalog_id}",
+            get(handlers::bank::get_catalog_entry)
+                .put(handlers::bank::update_catalog_entry)
+                .delete(handlers::bank::delete_catalog_entry),
+        )
 }
@@ -0,0 +1,20 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/custom-fields",
+            post(handlers::custom_field::create).get(handlers::custom_field::list),
+        )
+        .route(
+            "/organizations/{organization_id}/custom-fields/{field_id}",
+            get(handlers::custom_field::get)
+                .put(handlers::custom_field::update)
+                .delete(handlers::custom_field::delete),
+        )
+}
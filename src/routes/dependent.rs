@@ -0,0 +1,20 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/dependents",
+            post(handlers::dependent::create).get(handlers::dependent::list),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/dependents/{dependent_id}",
+            get(handlers::dependent::get)
+                .put(handlers::dependent::update)
+                .delete(handlers::dependent::delete),
+        )
+}
@@ -1,12 +1,19 @@
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 
 use crate::{handlers, server::AppState};
 
 pub fn router() -> Router<AppState> {
     Router::<AppState>::new()
+        .route("/divisions", get(handlers::division::search))
+        .route(
+            "/divisions/{division_id}",
+            get(handlers::division::find)
+                .put(handlers::division::update_by_id)
+                .delete(handlers::division::delete_by_id),
+        )
         .route(
             "/organizations/{organization_id}/payrolls/{payroll_id}/divisions",
             post(handlers::division::create).get(handlers::division::list),
@@ -17,4 +24,12 @@ pub fn router() -> Router<AppState> {
                 .put(handlers::division::update)
                 .delete(handlers::division::delete),
         )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/manager",
+            put(handlers::division::assign_manager),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/move",
+            post(handlers::division::move_subtree),
+        )
 }
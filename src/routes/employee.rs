@@ -7,14 +7,109 @@ use crate::{handlers, server::AppState};
 
 pub fn router() -> Router<AppState> {
     Router::<AppState>::new()
+        .route("/employees", get(handlers::employee::search))
+        .route(
+            "/employees/{employee_id}",
+            get(handlers::employee::find)
+                .put(handlers::employee::update_by_id)
+                .delete(handlers::employee::delete_by_id),
+        )
+        .route(
+            "/organizations/{organization_id}/employees",
+            get(handlers::employee::list_organization),
+        )
         .route(
             "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees",
             post(handlers::employee::create).get(handlers::employee::list),
         )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/import",
+            post(handlers::employee_import::import),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/export",
+            get(handlers::employee_export::export),
+        )
         .route(
             "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}",
             get(handlers::employee::get)
                 .put(handlers::employee::update)
                 .delete(handlers::employee::delete),
         )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/terminate",
+            post(handlers::employee::terminate),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/rehire",
+            post(handlers::employee::rehire),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/employment-history",
+            get(handlers::employee::employment_history),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timeline",
+            get(handlers::employee::timeline),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/expected-hours",
+            get(handlers::employee::expected_hours),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/period-pay",
+            get(handlers::employee::period_pay),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/direct-reports",
+            get(handlers::employee::direct_reports),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/profile-completeness",
+            get(handlers::profile_completeness::employee),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/email-verification",
+            post(handlers::email_verification::send),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/email-verification/confirm",
+            post(handlers::email_verification::confirm),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/payment-instructions/{priority}/micro-deposits",
+            post(handlers::account_verification::record_micro_deposit),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/payment-instructions/{priority}/document-verification",
+            post(handlers::account_verification::record_document),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/unverified-accounts",
+            get(handlers::account_verification::unverified_accounts),
+        )
+        .route(
+            "/organizations/{organization_id}/profile-completeness",
+            get(handlers::profile_completeness::organization),
+        )
+        .route(
+            "/organizations/{organization_id}/benchmark-export",
+            get(handlers::benchmark_export::export),
+        )
+        .route(
+            "/organizations/{organization_id}/milestones",
+            get(handlers::milestone::upcoming),
+        )
+        .route(
+            "/organizations/{organization_id}/expiring-soon",
+            get(handlers::expiry::expiring_soon),
+        )
+        .route(
+            "/organizations/{organization_id}/expiring-permits",
+            get(handlers::expiry::expiring_permits),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/photo",
+            get(handlers::employee::get_photo).put(handlers::employee::upload_photo),
+        )
 }
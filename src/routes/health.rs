@@ -3,5 +3,8 @@ use axum::{Router, routing::get};
 use crate::{handlers, server::AppState};
 
 pub fn router() -> Router<AppState> {
-    Router::<AppState>::new().route("/health", get(handlers::health::check))
+    Router::<AppState>::new()
+        .route("/health", get(handlers::health::check))
+        .route("/health/integrations", get(handlers::health::integrations))
+        .route("/health/ready", get(handlers::health::ready))
 }
@@ -0,0 +1,20 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/import-profiles",
+            post(handlers::import_profile::create).get(handlers::import_profile::list),
+        )
+        .route(
+            "/organizations/{organization_id}/import-profiles/{profile_id}",
+            get(handlers::import_profile::get)
+                .put(handlers::import_profile::update)
+                .delete(handlers::import_profile::delete),
+        )
+}
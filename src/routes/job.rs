@@ -7,6 +7,13 @@ use crate::{handlers, server::AppState};
 
 pub fn router() -> Router<AppState> {
     Router::<AppState>::new()
+        .route("/jobs", get(handlers::job::search))
+        .route(
+            "/jobs/{job_id}",
+            get(handlers::job::find)
+                .put(handlers::job::update_by_id)
+                .delete(handlers::job::delete_by_id),
+        )
         .route(
             "/organizations/{organization_id}/payrolls/{payroll_id}/jobs",
             post(handlers::job::create).get(handlers::job::list),
@@ -17,4 +24,42 @@ pub fn router() -> Router<AppState> {
                 .put(handlers::job::update)
                 .delete(handlers::job::delete),
         )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/jobs/{job_id}/employees",
+            get(handlers::job::employees),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/job-headcount",
+            get(handlers::job::headcount),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments",
+            post(handlers::salary_adjustment::create).get(handlers::salary_adjustment::list),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments/{adjustment_id}",
+            get(handlers::salary_adjustment::get),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments/{adjustment_id}/approve",
+            post(handlers::salary_adjustment::approve),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/salary-adjustments/{adjustment_id}/reject",
+            post(handlers::salary_adjustment::reject),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/pay-equity",
+            get(handlers::pay_equity::report),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/job-grades",
+            post(handlers::job_grade::create).get(handlers::job_grade::list),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/job-grades/{grade_id}",
+            get(handlers::job_grade::get)
+                .put(handlers::job_grade::update)
+                .delete(handlers::job_grade::delete),
+        )
 }
@@ -0,0 +1,62 @@
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-balances",
+            get(handlers::leave::balances),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-balances/{leave_type}",
+            put(handlers::leave::set_balance),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-pay",
+            get(handlers::leave::pay_days),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/leave-pay-policies/{leave_type}",
+            get(handlers::leave::get_pay_policy).put(handlers::leave::set_pay_policy),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-adjustments",
+            get(handlers::leave::adjustments),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/leave-carryover-rules/{leave_type}",
+            get(handlers::leave::get_carryover_rule).put(handlers::leave::set_carryover_rule),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/leave-blackouts",
+            post(handlers::leave::create_blackout).get(handlers::leave::list_blackouts),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/leave-blackouts/{blackout_id}",
+            delete(handlers::leave::delete_blackout),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/leave-carryover",
+            post(handlers::leave::run_carryover),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests",
+            post(handlers::leave::create).get(handlers::leave::list),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests/{request_id}",
+            get(handlers::leave::get),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests/{request_id}/approve",
+            post(handlers::leave::approve),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/leave-requests/{request_id}/reject",
+            post(handlers::leave::reject),
+        )
+}
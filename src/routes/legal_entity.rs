@@ -0,0 +1,20 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/legal-entities",
+            post(handlers::legal_entity::create).get(handlers::legal_entity::list),
+        )
+        .route(
+            "/organizations/{organization_id}/legal-entities/{legal_entity_id}",
+            get(handlers::legal_entity::get)
+                .put(handlers::legal_entity::update)
+                .delete(handlers::legal_entity::delete),
+        )
+}
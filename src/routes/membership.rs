@@ -0,0 +1,18 @@
+use axum::{
+    Router,
+    routing::{post, put},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/members",
+            post(handlers::membership::invite).get(handlers::membership::list),
+        )
+        .route(
+            "/organizations/{organization_id}/members/{member_id}",
+            put(handlers::membership::update_role).delete(handlers::membership::remove),
+        )
+}
@@ -4,15 +4,27 @@ use tracing::Level;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::{openapi::ApiDoc, server::AppState};
+use crate::{middleware::archived::reject_archived_writes, openapi::ApiDoc, server::AppState};
 
+pub mod api_collection;
 pub mod bank;
+pub mod custom_field;
+pub mod dependent;
 pub mod division;
 pub mod employee;
 pub mod health;
+pub mod import_profile;
 pub mod job;
+pub mod leave;
+pub mod legal_entity;
+pub mod membership;
 pub mod organization;
 pub mod payroll;
+pub mod position;
+pub mod project;
+pub mod self_service;
+pub mod timesheet;
+pub mod work_schedule;
 
 pub fn app_router(state: AppState) -> Router {
     let openapi = ApiDoc::openapi();
@@ -20,12 +32,28 @@ pub fn app_router(state: AppState) -> Router {
     Router::<AppState>::new()
         .merge(health::router())
         .merge(organization::router())
+        .merge(membership::router())
+        .merge(legal_entity::router())
         .merge(payroll::router())
         .merge(job::router())
         .merge(division::router())
         .merge(bank::router())
         .merge(employee::router())
+        .merge(dependent::router())
+        .merge(import_profile::router())
+        .merge(custom_field::router())
+        .merge(position::router())
+        .merge(work_schedule::router())
+        .merge(leave::router())
+        .merge(timesheet::router())
+        .merge(project::router())
+        .merge(self_service::router())
+        .merge(api_collection::router())
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", openapi))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            reject_archived_writes,
+        ))
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
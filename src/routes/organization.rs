@@ -17,4 +17,39 @@ pub fn router() -> Router<AppState> {
                 .put(handlers::organization::update)
                 .delete(handlers::organization::delete),
         )
+        .route(
+            "/organizations/{id}/clone",
+            post(handlers::organization::clone),
+        )
+        .route(
+            "/organizations/{id}/deletion",
+            get(handlers::organization::get_deletion)
+                .delete(handlers::organization::cancel_deletion),
+        )
+        .route(
+            "/organizations/{id}/archive",
+            post(handlers::organization::archive),
+        )
+        .route(
+            "/organizations/{id}/reactivate",
+            post(handlers::organization::reactivate),
+        )
+        .route(
+            "/organizations/{id}/restore",
+            post(handlers::organization::restore),
+        )
+        .route(
+            "/organizations/{id}/settings",
+            get(handlers::organization::get_settings).put(handlers::organization::update_settings),
+        )
+        .route(
+            "/organizations/{id}/logo",
+            get(handlers::organization::get_logo)
+                .put(handlers::organization::upload_logo)
+                .delete(handlers::organization::delete_logo),
+        )
+        .route(
+            "/organizations/{id}/calendar",
+            get(handlers::organization::calendar),
+        )
 }
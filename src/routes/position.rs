@@ -0,0 +1,28 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions",
+            post(handlers::position::create).get(handlers::position::list),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions/{position_id}",
+            get(handlers::position::get)
+                .put(handlers::position::update)
+                .delete(handlers::position::delete),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions/{position_id}/assign",
+            post(handlers::position::assign),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/positions/{position_id}/unassign",
+            post(handlers::position::unassign),
+        )
+}
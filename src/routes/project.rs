@@ -0,0 +1,24 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/projects",
+            post(handlers::project::create).get(handlers::project::list),
+        )
+        .route(
+            "/organizations/{organization_id}/projects/{project_id}",
+            get(handlers::project::get)
+                .put(handlers::project::update)
+                .delete(handlers::project::delete),
+        )
+        .route(
+            "/organizations/{organization_id}/project-labor-costs",
+            get(handlers::project::labor_costs),
+        )
+}
@@ -0,0 +1,19 @@
+use axum::{
+    Router,
+    routing::{delete, get},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route("/me", get(handlers::self_service::me))
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/self-service-tokens",
+            get(handlers::self_service::list).post(handlers::self_service::issue),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/self-service-tokens/{token_id}",
+            delete(handlers::self_service::revoke),
+        )
+}
@@ -0,0 +1,77 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/attendance/import",
+            post(handlers::attendance_import::import),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/punch-policy",
+            get(handlers::attendance_import::get_punch_policy)
+                .put(handlers::attendance_import::set_punch_policy),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries",
+            post(handlers::timesheet::create).get(handlers::timesheet::list),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries/{entry_id}",
+            get(handlers::timesheet::get)
+                .put(handlers::timesheet::update)
+                .delete(handlers::timesheet::delete),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries/{entry_id}/approve",
+            post(handlers::timesheet::approve),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheet-entries/{entry_id}/reject",
+            post(handlers::timesheet::reject),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheets",
+            post(handlers::timesheet::submit).get(handlers::timesheet::list_periods),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheets/{period_id}/approve",
+            post(handlers::timesheet::approve_period),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/timesheets/{period_id}/reject",
+            post(handlers::timesheet::reject_period),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/timesheets/unapproved",
+            get(handlers::timesheet::unapproved),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/overtime-policy",
+            get(handlers::overtime::get_policy).put(handlers::overtime::set_policy),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/overtime-warnings",
+            get(handlers::overtime::warnings),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-requests",
+            post(handlers::overtime::create_request).get(handlers::overtime::list_requests),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-requests/{request_id}/approve",
+            post(handlers::overtime::approve_request),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-requests/{request_id}/reject",
+            post(handlers::overtime::reject_request),
+        )
+        .route(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/overtime-pay",
+            get(handlers::overtime::pay),
+        )
+}
@@ -0,0 +1,20 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{handlers, server::AppState};
+
+pub fn router() -> Router<AppState> {
+    Router::<AppState>::new()
+        .route(
+            "/organizations/{organization_id}/work-schedules",
+            post(handlers::work_schedule::create).get(handlers::work_schedule::list),
+        )
+        .route(
+            "/organizations/{organization_id}/work-schedules/{schedule_id}",
+            get(handlers::work_schedule::get)
+                .put(handlers::work_schedule::update)
+                .delete(handlers::work_schedule::delete),
+        )
+}
@@ -1,27 +1,90 @@
-use std::{io, sync::Arc};
+use std::{env, io, sync::Arc, time::Duration};
 
 use axum::Router;
+use chrono::Utc;
 use thiserror::Error;
 use tokio::net::TcpListener;
+use tracing::{error, info, warn};
 
+#[cfg(feature = "redis-cache")]
+use crate::infrastructure::redis_cache::RedisCacheStore;
 use crate::{
     infrastructure::{
+        bank_catalog_repository::SurrealAnyBankCatalogRepository,
         bank_repository::SurrealAnyBankRepository,
+        blob_store::SurrealAnyBlobStore,
+        custom_field_repository::SurrealAnyCustomFieldRepository,
+        database_probe::SurrealAnyDatabaseProbe,
+        dependent_repository::SurrealAnyDependentRepository,
         division_repository::SurrealAnyDivisionRepository,
+        email_verification_repository::SurrealAnyEmailVerificationRepository,
         employee_repository::SurrealAnyEmployeeRepository,
+        employment_history_repository::SurrealAnyEmploymentHistoryRepository,
+        import_profile_repository::SurrealAnyImportProfileRepository,
+        job_grade_repository::SurrealAnyJobGradeRepository,
         job_repository::SurrealAnyJobRepository,
+        lease_repository::SurrealAnyLeaseRepository,
+        leave_repository::SurrealAnyLeaveRepository,
+        legal_entity_repository::SurrealAnyLegalEntityRepository,
+        log_email_sender::LogEmailSender,
+        membership_repository::SurrealAnyMembershipRepository,
+        organization_deletion_repository::SurrealAnyOrganizationDeletionRepository,
         organization_repository::SurrealAnyOrganizationRepository,
+        organization_settings_repository::SurrealAnyOrganizationSettingsRepository,
+        overtime_repository::SurrealAnyOvertimeRepository,
         payroll_repository::SurrealAnyPayrollRepository,
+        position_repository::SurrealAnyPositionRepository,
+        project_repository::SurrealAnyProjectRepository,
+        punch_policy_repository::SurrealAnyPunchPolicyRepository,
+        salary_adjustment_repository::SurrealAnySalaryAdjustmentRepository,
+        self_service_repository::SurrealAnySelfServiceTokenRepository,
         surreal::{self, SurrealConfig, SurrealConfigError},
+        timesheet_repository::SurrealAnyTimesheetRepository,
+        work_schedule_repository::SurrealAnyWorkScheduleRepository,
     },
     routes,
     services::{
+        attendance_import::AttendanceImportService,
         bank::BankService,
+        benchmark_export::BenchmarkExportService,
+        cache::{CacheStore, MemoryCacheStore},
+        calendar::CalendarService,
+        custom_field::CustomFieldService,
+        dependent::DependentService,
         division::DivisionService,
+        email_verification::EmailVerificationService,
         employee::EmployeeService,
+        employee_export::EmployeeExportService,
+        employee_import::EmployeeImportService,
+        employment_history::EmploymentHistoryService,
+        expiry::ExpiryService,
+        import_profile::ImportProfileService,
+        integration_health::{EMAIL_INTEGRATION, IntegrationMonitor, MonitoredEmailSender},
         job::JobService,
+        job_grade::JobGradeService,
+        lease::{
+            LEAVE_CARRYOVER_SWEEP_LEASE, LeaseService, ORGANIZATION_DELETION_SWEEP_LEASE,
+            SALARY_ADJUSTMENT_SWEEP_LEASE,
+        },
+        leave::LeaveService,
+        legal_entity::LegalEntityService,
+        membership::MembershipService,
+        milestone::MilestoneService,
         organization::{self, OrganizationService},
+        organization_clone::OrganizationCloneService,
+        organization_deletion::{self, OrganizationDeletionService},
+        organization_settings::OrganizationSettingsService,
+        overtime::OvertimeService,
+        pay_equity::PayEquityService,
         payroll::PayrollService,
+        position::PositionService,
+        profile_completeness::ProfileCompletenessService,
+        project::ProjectService,
+        readiness::{DependencyProbe, ReadinessService},
+        salary_adjustment::SalaryAdjustmentService,
+        self_service::SelfServiceService,
+        timesheet::TimesheetService,
+        work_schedule::WorkScheduleService,
     },
 };
 
@@ -30,10 +93,107 @@ pub async fn run(listener: TcpListener) -> Result<(), io::Error> {
         .await
         .map_err(|err| io::Error::other(err.to_string()))?;
 
+    spawn_deletion_sweeper(state.organization_deletion_service(), state.lease_service());
+    spawn_salary_adjustment_sweeper(state.salary_adjustment_service(), state.lease_service());
+    spawn_leave_carryover_sweeper(state.leave_service(), state.lease_service());
     let app = router(state);
     axum::serve(listener, app).await
 }
 
+const DELETION_SWEEP_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Soft-deletes organizations whose deletion came due, cascading to their payrolls and banks,
+/// on whichever instance holds the sweep lease.
+fn spawn_deletion_sweeper(service: Arc<OrganizationDeletionService>, leases: Arc<LeaseService>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DELETION_SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            match leases.try_acquire(ORGANIZATION_DELETION_SWEEP_LEASE).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(err) => {
+                    error!("failed to acquire the organization deletion sweep lease: {err}");
+                    continue;
+                }
+            }
+            match service.purge_due(Utc::now()).await {
+                Ok(purged) => {
+                    for organization_id in purged {
+                        info!(
+                            "archived organization `{organization_id}` after its cancellation window"
+                        );
+                    }
+                }
+                Err(err) => error!("failed to purge scheduled organization deletions: {err}"),
+            }
+        }
+    });
+}
+
+/// Applies approved salary adjustments once their effective date comes, on whichever instance
+/// holds the sweep lease. Runs on the deletion sweep's interval, which the lease TTL is sized for.
+fn spawn_salary_adjustment_sweeper(
+    service: Arc<SalaryAdjustmentService>,
+    leases: Arc<LeaseService>,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DELETION_SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            match leases.try_acquire(SALARY_ADJUSTMENT_SWEEP_LEASE).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(err) => {
+                    error!("failed to acquire the salary adjustment sweep lease: {err}");
+                    continue;
+                }
+            }
+            match service.apply_due(Utc::now().date_naive()).await {
+                Ok(applied) => {
+                    for adjustment_id in applied {
+                        info!("applied salary adjustment `{adjustment_id}` on its effective date");
+                    }
+                }
+                Err(err) => error!("failed to apply due salary adjustments: {err}"),
+            }
+        }
+    });
+}
+
+/// Applies leave carryover rules (year-end caps, expiry of carried-over days and expiry
+/// warnings) on whichever instance holds the sweep lease. Runs on the deletion sweep's interval.
+fn spawn_leave_carryover_sweeper(service: Arc<LeaveService>, leases: Arc<LeaseService>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DELETION_SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            match leases.try_acquire(LEAVE_CARRYOVER_SWEEP_LEASE).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(err) => {
+                    error!("failed to acquire the leave carryover sweep lease: {err}");
+                    continue;
+                }
+            }
+            match service.carry_over_due(Utc::now().date_naive()).await {
+                Ok(adjustments) => {
+                    for adjustment in adjustments {
+                        info!(
+                            "adjusted {} leave of employee `{}` from {} to {} days",
+                            adjustment.leave_type.as_str(),
+                            adjustment.employee_id,
+                            adjustment.days_before,
+                            adjustment.days_after
+                        );
+                    }
+                }
+                Err(err) => error!("failed to apply leave carryover rules: {err}"),
+            }
+        }
+    });
+}
+
 pub fn router(state: AppState) -> Router {
     routes::app_router(state)
 }
@@ -46,9 +206,41 @@ pub struct AppState {
     job_service: Arc<JobService>,
     bank_service: Arc<BankService>,
     employee_service: Arc<EmployeeService>,
+    dependent_service: Arc<DependentService>,
+    organization_deletion_service: Arc<OrganizationDeletionService>,
+    import_profile_service: Arc<ImportProfileService>,
+    employee_import_service: Arc<EmployeeImportService>,
+    custom_field_service: Arc<CustomFieldService>,
+    employee_export_service: Arc<EmployeeExportService>,
+    position_service: Arc<PositionService>,
+    employment_history_service: Arc<EmploymentHistoryService>,
+    work_schedule_service: Arc<WorkScheduleService>,
+    leave_service: Arc<LeaveService>,
+    timesheet_service: Arc<TimesheetService>,
+    attendance_import_service: Arc<AttendanceImportService>,
+    profile_completeness_service: Arc<ProfileCompletenessService>,
+    self_service_service: Arc<SelfServiceService>,
+    email_verification_service: Arc<EmailVerificationService>,
+    milestone_service: Arc<MilestoneService>,
+    lease_service: Arc<LeaseService>,
+    job_grade_service: Arc<JobGradeService>,
+    expiry_service: Arc<ExpiryService>,
+    salary_adjustment_service: Arc<SalaryAdjustmentService>,
+    pay_equity_service: Arc<PayEquityService>,
+    benchmark_export_service: Arc<BenchmarkExportService>,
+    project_service: Arc<ProjectService>,
+    overtime_service: Arc<OvertimeService>,
+    organization_settings_service: Arc<OrganizationSettingsService>,
+    calendar_service: Arc<CalendarService>,
+    integration_monitor: Arc<IntegrationMonitor>,
+    organization_clone_service: Arc<OrganizationCloneService>,
+    membership_service: Arc<MembershipService>,
+    legal_entity_service: Arc<LegalEntityService>,
+    readiness_service: Arc<ReadinessService>,
 }
 
 impl AppState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         organization_service: Arc<OrganizationService>,
         payroll_service: Arc<PayrollService>,
@@ -56,6 +248,37 @@ impl AppState {
         job_service: Arc<JobService>,
         bank_service: Arc<BankService>,
         employee_service: Arc<EmployeeService>,
+        dependent_service: Arc<DependentService>,
+        organization_deletion_service: Arc<OrganizationDeletionService>,
+        import_profile_service: Arc<ImportProfileService>,
+        employee_import_service: Arc<EmployeeImportService>,
+        custom_field_service: Arc<CustomFieldService>,
+        employee_export_service: Arc<EmployeeExportService>,
+        position_service: Arc<PositionService>,
+        employment_history_service: Arc<EmploymentHistoryService>,
+        work_schedule_service: Arc<WorkScheduleService>,
+        leave_service: Arc<LeaveService>,
+        timesheet_service: Arc<TimesheetService>,
+        attendance_import_service: Arc<AttendanceImportService>,
+        profile_completeness_service: Arc<ProfileCompletenessService>,
+        self_service_service: Arc<SelfServiceService>,
+        email_verification_service: Arc<EmailVerificationService>,
+        milestone_service: Arc<MilestoneService>,
+        lease_service: Arc<LeaseService>,
+        job_grade_service: Arc<JobGradeService>,
+        expiry_service: Arc<ExpiryService>,
+        salary_adjustment_service: Arc<SalaryAdjustmentService>,
+        pay_equity_service: Arc<PayEquityService>,
+        benchmark_export_service: Arc<BenchmarkExportService>,
+        project_service: Arc<ProjectService>,
+        overtime_service: Arc<OvertimeService>,
+        organization_settings_service: Arc<OrganizationSettingsService>,
+        calendar_service: Arc<CalendarService>,
+        integration_monitor: Arc<IntegrationMonitor>,
+        organization_clone_service: Arc<OrganizationCloneService>,
+        membership_service: Arc<MembershipService>,
+        legal_entity_service: Arc<LegalEntityService>,
+        readiness_service: Arc<ReadinessService>,
     ) -> Self {
         Self {
             organization_service,
@@ -64,6 +287,37 @@ impl AppState {
             job_service,
             bank_service,
             employee_service,
+            dependent_service,
+            organization_deletion_service,
+            import_profile_service,
+            employee_import_service,
+            custom_field_service,
+            employee_export_service,
+            position_service,
+            employment_history_service,
+            work_schedule_service,
+            leave_service,
+            timesheet_service,
+            attendance_import_service,
+            profile_completeness_service,
+            self_service_service,
+            email_verification_service,
+            milestone_service,
+            lease_service,
+            job_grade_service,
+            expiry_service,
+            salary_adjustment_service,
+            pay_equity_service,
+            benchmark_export_service,
+            project_service,
+            overtime_service,
+            organization_settings_service,
+            calendar_service,
+            integration_monitor,
+            organization_clone_service,
+            membership_service,
+            legal_entity_service,
+            readiness_service,
         }
     }
 
@@ -91,52 +345,486 @@ impl AppState {
         Arc::clone(&self.employee_service)
     }
 
+    pub fn dependent_service(&self) -> Arc<DependentService> {
+        Arc::clone(&self.dependent_service)
+    }
+
+    pub fn organization_deletion_service(&self) -> Arc<OrganizationDeletionService> {
+        Arc::clone(&self.organization_deletion_service)
+    }
+
+    pub fn import_profile_service(&self) -> Arc<ImportProfileService> {
+        Arc::clone(&self.import_profile_service)
+    }
+
+    pub fn employee_import_service(&self) -> Arc<EmployeeImportService> {
+        Arc::clone(&self.employee_import_service)
+    }
+
+    pub fn custom_field_service(&self) -> Arc<CustomFieldService> {
+        Arc::clone(&self.custom_field_service)
+    }
+
+    pub fn employee_export_service(&self) -> Arc<EmployeeExportService> {
+        Arc::clone(&self.employee_export_service)
+    }
+
+    pub fn position_service(&self) -> Arc<PositionService> {
+        Arc::clone(&self.position_service)
+    }
+
+    pub fn employment_history_service(&self) -> Arc<EmploymentHistoryService> {
+        Arc::clone(&self.employment_history_service)
+    }
+
+    pub fn work_schedule_service(&self) -> Arc<WorkScheduleService> {
+        Arc::clone(&self.work_schedule_service)
+    }
+
+    pub fn leave_service(&self) -> Arc<LeaveService> {
+        Arc::clone(&self.leave_service)
+    }
+
+    pub fn timesheet_service(&self) -> Arc<TimesheetService> {
+        Arc::clone(&self.timesheet_service)
+    }
+
+    pub fn attendance_import_service(&self) -> Arc<AttendanceImportService> {
+        Arc::clone(&self.attendance_import_service)
+    }
+
+    pub fn profile_completeness_service(&self) -> Arc<ProfileCompletenessService> {
+        Arc::clone(&self.profile_completeness_service)
+    }
+
+    pub fn self_service_service(&self) -> Arc<SelfServiceService> {
+        Arc::clone(&self.self_service_service)
+    }
+
+    pub fn email_verification_service(&self) -> Arc<EmailVerificationService> {
+        Arc::clone(&self.email_verification_service)
+    }
+
+    pub fn milestone_service(&self) -> Arc<MilestoneService> {
+        Arc::clone(&self.milestone_service)
+    }
+
+    pub fn lease_service(&self) -> Arc<LeaseService> {
+        Arc::clone(&self.lease_service)
+    }
+
+    pub fn job_grade_service(&self) -> Arc<JobGradeService> {
+        Arc::clone(&self.job_grade_service)
+    }
+
+    pub fn expiry_service(&self) -> Arc<ExpiryService> {
+        Arc::clone(&self.expiry_service)
+    }
+
+    pub fn salary_adjustment_service(&self) -> Arc<SalaryAdjustmentService> {
+        Arc::clone(&self.salary_adjustment_service)
+    }
+
+    pub fn pay_equity_service(&self) -> Arc<PayEquityService> {
+        Arc::clone(&self.pay_equity_service)
+    }
+
+    pub fn benchmark_export_service(&self) -> Arc<BenchmarkExportService> {
+        Arc::clone(&self.benchmark_export_service)
+    }
+
+    pub fn project_service(&self) -> Arc<ProjectService> {
+        Arc::clone(&self.project_service)
+    }
+
+    pub fn overtime_service(&self) -> Arc<OvertimeService> {
+        Arc::clone(&self.overtime_service)
+    }
+
+    pub fn organization_settings_service(&self) -> Arc<OrganizationSettingsService> {
+        Arc::clone(&self.organization_settings_service)
+    }
+
+    pub fn calendar_service(&self) -> Arc<CalendarService> {
+        Arc::clone(&self.calendar_service)
+    }
+
+    pub fn integration_monitor(&self) -> Arc<IntegrationMonitor> {
+        Arc::clone(&self.integration_monitor)
+    }
+
+    pub fn organization_clone_service(&self) -> Arc<OrganizationCloneService> {
+        Arc::clone(&self.organization_clone_service)
+    }
+
+    pub fn membership_service(&self) -> Arc<MembershipService> {
+        Arc::clone(&self.membership_service)
+    }
+
+    pub fn legal_entity_service(&self) -> Arc<LegalEntityService> {
+        Arc::clone(&self.legal_entity_service)
+    }
+
+    pub fn readiness_service(&self) -> Arc<ReadinessService> {
+        Arc::clone(&self.readiness_service)
+    }
+
     pub async fn initialize() -> Result<Self, ServerSetupError> {
         let config = SurrealConfig::from_env()?;
-        let client = surreal::connect(&config).await?;
+        if config.is_embedded() {
+            warn!(
+                "SURREALDB_URL is not set; using an embedded in-memory database whose data is lost on restart"
+            );
+        }
+        let client = surreal::connect_with_retry(&config).await?;
+        let replica = config.replica();
+        let reader = match &replica {
+            Some(replica) => surreal::connect_reader_with_retry(replica).await?,
+            None => client.clone(),
+        };
 
-        let organization_repository: Arc<dyn organization::OrganizationRepository> =
-            Arc::new(SurrealAnyOrganizationRepository::new(client.clone()));
-        let organization_service = Arc::new(OrganizationService::new(organization_repository));
+        let organization_repository: Arc<dyn organization::OrganizationRepository> = Arc::new(
+            SurrealAnyOrganizationRepository::new(client.clone()).with_reader(reader.clone()),
+        );
+        let blob_store: Arc<dyn crate::services::blob::BlobStore> =
+            Arc::new(SurrealAnyBlobStore::new(client.clone()));
+        let cache_store = lookup_cache_store().await?;
+        let organization_service = Arc::new(OrganizationService::new(
+            organization_repository,
+            Arc::clone(&blob_store),
+            Arc::clone(&cache_store),
+        ));
+        let organization_settings_repository: Arc<
+            dyn crate::services::organization_settings::OrganizationSettingsRepository,
+        > = Arc::new(
+            SurrealAnyOrganizationSettingsRepository::new(client.clone())
+                .with_reader(reader.clone()),
+        );
+        let organization_settings_service = Arc::new(OrganizationSettingsService::new(
+            organization_settings_repository,
+            Arc::clone(&organization_service),
+        ));
 
         let payroll_repository: Arc<dyn crate::services::payroll::PayrollRepository> =
-            Arc::new(SurrealAnyPayrollRepository::new(client.clone()));
+            Arc::new(SurrealAnyPayrollRepository::new(client.clone()).with_reader(reader.clone()));
+        let legal_entity_repository: Arc<dyn crate::services::legal_entity::LegalEntityRepository> =
+            Arc::new(
+                SurrealAnyLegalEntityRepository::new(client.clone()).with_reader(reader.clone()),
+            );
         let payroll_service = Arc::new(PayrollService::new(
             payroll_repository,
             Arc::clone(&organization_service),
+            Arc::clone(&legal_entity_repository),
+            cache_store,
         ));
 
+        let employee_repository: Arc<dyn crate::services::employee::EmployeeRepository> =
+            Arc::new(SurrealAnyEmployeeRepository::new(client.clone()).with_reader(reader.clone()));
+
+        let position_repository: Arc<dyn crate::services::position::PositionRepository> =
+            Arc::new(SurrealAnyPositionRepository::new(client.clone()).with_reader(reader.clone()));
+
         let division_repository: Arc<dyn crate::services::division::DivisionRepository> =
-            Arc::new(SurrealAnyDivisionRepository::new(client.clone()));
+            Arc::new(SurrealAnyDivisionRepository::new(client.clone()).with_reader(reader.clone()));
         let division_service = Arc::new(DivisionService::new(
             division_repository,
             Arc::clone(&payroll_service),
+            Arc::clone(&organization_service),
+            Arc::clone(&employee_repository),
+            Arc::clone(&position_repository),
         ));
 
         let job_repository: Arc<dyn crate::services::job::JobRepository> =
-            Arc::new(SurrealAnyJobRepository::new(client.clone()));
+            Arc::new(SurrealAnyJobRepository::new(client.clone()).with_reader(reader.clone()));
+        let job_grade_repository: Arc<dyn crate::services::job_grade::JobGradeRepository> =
+            Arc::new(SurrealAnyJobGradeRepository::new(client.clone()).with_reader(reader.clone()));
+        let job_grade_service = Arc::new(JobGradeService::new(
+            job_grade_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&job_repository),
+        ));
+        let employment_history_repository: Arc<
+            dyn crate::services::employment_history::EmploymentHistoryRepository,
+        > = Arc::new(
+            SurrealAnyEmploymentHistoryRepository::new(client.clone()).with_reader(reader.clone()),
+        );
+
         let job_service = Arc::new(JobService::new(
             job_repository,
             Arc::clone(&payroll_service),
+            Arc::clone(&job_grade_service),
+            Arc::clone(&employee_repository),
+            Arc::clone(&employment_history_repository),
         ));
 
         let bank_repository: Arc<dyn crate::services::bank::BankRepository> =
-            Arc::new(SurrealAnyBankRepository::new(client.clone()));
+            Arc::new(SurrealAnyBankRepository::new(client.clone()).with_reader(reader.clone()));
+        let bank_catalog_repository: Arc<dyn crate::services::bank::BankCatalogRepository> =
+            Arc::new(
+                SurrealAnyBankCatalogRepository::new(client.clone()).with_reader(reader.clone()),
+            );
         let bank_service = Arc::new(BankService::new(
             bank_repository,
+            bank_catalog_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&employee_repository),
+        ));
+
+        let custom_field_repository: Arc<dyn crate::services::custom_field::CustomFieldRepository> =
+            Arc::new(
+                SurrealAnyCustomFieldRepository::new(client.clone()).with_reader(reader.clone()),
+            );
+        let custom_field_service = Arc::new(CustomFieldService::new(
+            custom_field_repository,
+            Arc::clone(&organization_service),
+        ));
+
+        let work_schedule_repository: Arc<
+            dyn crate::services::work_schedule::WorkScheduleRepository,
+        > = Arc::new(
+            SurrealAnyWorkScheduleRepository::new(client.clone()).with_reader(reader.clone()),
+        );
+        let work_schedule_service = Arc::new(WorkScheduleService::new(
+            work_schedule_repository,
             Arc::clone(&organization_service),
         ));
 
-        let employee_repository: Arc<dyn crate::services::employee::EmployeeRepository> =
-            Arc::new(SurrealAnyEmployeeRepository::new(client));
         let employee_service = Arc::new(EmployeeService::new(
-            employee_repository,
+            Arc::clone(&employee_repository),
             Arc::clone(&division_service),
             Arc::clone(&payroll_service),
             Arc::clone(&job_service),
             Arc::clone(&bank_service),
+            Arc::clone(&blob_store),
+            Arc::clone(&custom_field_service),
+            Arc::clone(&employment_history_repository),
+            Arc::clone(&work_schedule_service),
+            Arc::clone(&organization_settings_service),
         ));
 
+        let dependent_repository: Arc<dyn crate::services::dependent::DependentRepository> =
+            Arc::new(
+                SurrealAnyDependentRepository::new(client.clone()).with_reader(reader.clone()),
+            );
+        let dependent_service = Arc::new(DependentService::new(
+            dependent_repository,
+            Arc::clone(&employee_service),
+        ));
+
+        let integration_monitor = Arc::new(IntegrationMonitor::default());
+        integration_monitor.register(
+            EMAIL_INTEGRATION,
+            Some("no mail relay configured; emails are written to the log".to_string()),
+        );
+        let email_sender: Arc<dyn crate::services::email::EmailSender> = Arc::new(
+            MonitoredEmailSender::new(Arc::new(LogEmailSender), Arc::clone(&integration_monitor)),
+        );
+
+        let timesheet_repository: Arc<dyn crate::services::timesheet::TimesheetRepository> =
+            Arc::new(
+                SurrealAnyTimesheetRepository::new(client.clone()).with_reader(reader.clone()),
+            );
+        let project_repository: Arc<dyn crate::services::project::ProjectRepository> =
+            Arc::new(SurrealAnyProjectRepository::new(client.clone()).with_reader(reader.clone()));
+        let project_service = Arc::new(ProjectService::new(
+            project_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&timesheet_repository),
+        ));
+        let timesheet_service = Arc::new(TimesheetService::new(
+            timesheet_repository,
+            Arc::clone(&division_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&job_service),
+            Arc::clone(&project_service),
+        ));
+
+        let leave_repository: Arc<dyn crate::services::leave::LeaveRepository> =
+            Arc::new(SurrealAnyLeaveRepository::new(client.clone()).with_reader(reader.clone()));
+        let leave_service = Arc::new(LeaveService::new(
+            leave_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&division_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&timesheet_service),
+            Arc::clone(&email_sender),
+        ));
+
+        let overtime_repository: Arc<dyn crate::services::overtime::OvertimeRepository> =
+            Arc::new(SurrealAnyOvertimeRepository::new(client.clone()).with_reader(reader.clone()));
+        let overtime_service = Arc::new(OvertimeService::new(
+            overtime_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&timesheet_service),
+        ));
+        let punch_policy_repository: Arc<
+            dyn crate::services::attendance_import::PunchPolicyRepository,
+        > = Arc::new(
+            SurrealAnyPunchPolicyRepository::new(client.clone()).with_reader(reader.clone()),
+        );
+        let attendance_import_service = Arc::new(AttendanceImportService::new(
+            punch_policy_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&timesheet_service),
+            Arc::clone(&leave_service),
+        ));
+        let profile_completeness_service = Arc::new(ProfileCompletenessService::new(
+            Arc::clone(&employee_service),
+            Arc::clone(&custom_field_service),
+        ));
+
+        let self_service_token_repository: Arc<
+            dyn crate::services::self_service::SelfServiceTokenRepository,
+        > = Arc::new(
+            SurrealAnySelfServiceTokenRepository::new(client.clone()).with_reader(reader.clone()),
+        );
+        let self_service_service = Arc::new(SelfServiceService::new(
+            self_service_token_repository,
+            Arc::clone(&employee_service),
+        ));
+
+        let import_profile_repository: Arc<
+            dyn crate::services::import_profile::ImportProfileRepository,
+        > = Arc::new(
+            SurrealAnyImportProfileRepository::new(client.clone()).with_reader(reader.clone()),
+        );
+        let import_profile_service = Arc::new(ImportProfileService::new(
+            import_profile_repository,
+            Arc::clone(&organization_service),
+        ));
+        let employee_import_service = Arc::new(EmployeeImportService::new(
+            Arc::clone(&employee_service),
+            Arc::clone(&division_service),
+            Arc::clone(&import_profile_service),
+        ));
+        let employee_export_service =
+            Arc::new(EmployeeExportService::new(Arc::clone(&employee_service)));
+
+        let position_service = Arc::new(PositionService::new(
+            Arc::clone(&position_repository),
+            Arc::clone(&division_service),
+            Arc::clone(&job_service),
+            Arc::clone(&employee_service),
+        ));
+
+        let employment_history_service = Arc::new(EmploymentHistoryService::new(
+            employment_history_repository,
+            Arc::clone(&employee_service),
+            Arc::clone(&position_service),
+        ));
+
+        let organization_deletion_repository: Arc<
+            dyn organization_deletion::OrganizationDeletionRepository,
+        > = Arc::new(
+            SurrealAnyOrganizationDeletionRepository::new(client.clone())
+                .with_reader(reader.clone()),
+        );
+        let organization_deletion_service = Arc::new(OrganizationDeletionService::new(
+            organization_deletion_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&bank_service),
+            deletion_grace_period(),
+        ));
+
+        let email_verification_repository: Arc<
+            dyn crate::services::email_verification::EmailVerificationRepository,
+        > = Arc::new(
+            SurrealAnyEmailVerificationRepository::new(client.clone()).with_reader(reader.clone()),
+        );
+        let email_verification_service = Arc::new(EmailVerificationService::new(
+            email_verification_repository,
+            Arc::clone(&employee_service),
+            Arc::clone(&email_sender),
+        ));
+
+        let milestone_service = Arc::new(MilestoneService::new(
+            Arc::clone(&employee_service),
+            Arc::clone(&division_service),
+        ));
+
+        let lease_repository: Arc<dyn crate::services::lease::LeaseRepository> =
+            Arc::new(SurrealAnyLeaseRepository::new(client.clone()));
+        let lease_service = Arc::new(LeaseService::new(
+            lease_repository,
+            // Renewed every sweep, so the lease only lapses when its holder misses one.
+            chrono::Duration::seconds(2 * DELETION_SWEEP_INTERVAL.as_secs() as i64),
+        ));
+
+        let expiry_service = Arc::new(ExpiryService::new(Arc::clone(&employee_service)));
+
+        let salary_adjustment_repository: Arc<
+            dyn crate::services::salary_adjustment::SalaryAdjustmentRepository,
+        > = Arc::new(
+            SurrealAnySalaryAdjustmentRepository::new(client.clone()).with_reader(reader.clone()),
+        );
+        let salary_adjustment_service = Arc::new(SalaryAdjustmentService::new(
+            salary_adjustment_repository,
+            Arc::clone(&payroll_service),
+            Arc::clone(&job_service),
+            Arc::clone(&employee_service),
+        ));
+
+        let pay_equity_service = Arc::new(PayEquityService::new(
+            Arc::clone(&employee_service),
+            Arc::clone(&job_service),
+            Arc::clone(&job_grade_service),
+        ));
+
+        let benchmark_export_service = Arc::new(BenchmarkExportService::new(
+            Arc::clone(&payroll_service),
+            Arc::clone(&division_service),
+            Arc::clone(&job_service),
+            Arc::clone(&job_grade_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&organization_settings_service),
+        ));
+
+        let calendar_service = Arc::new(CalendarService::new(
+            Arc::clone(&organization_settings_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&division_service),
+            Arc::clone(&employee_service),
+            Arc::clone(&leave_service),
+            Arc::clone(&salary_adjustment_service),
+        ));
+
+        let organization_clone_service = Arc::new(OrganizationCloneService::new(
+            Arc::clone(&organization_service),
+            Arc::clone(&organization_settings_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&job_grade_service),
+            Arc::clone(&job_service),
+            Arc::clone(&division_service),
+            Arc::clone(&bank_service),
+        ));
+
+        let membership_repository: Arc<dyn crate::services::membership::MembershipRepository> =
+            Arc::new(
+                SurrealAnyMembershipRepository::new(client.clone()).with_reader(reader.clone()),
+            );
+        let membership_service = Arc::new(MembershipService::new(
+            membership_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&email_sender),
+        ));
+
+        let legal_entity_service = Arc::new(LegalEntityService::new(
+            legal_entity_repository,
+            Arc::clone(&organization_service),
+            Arc::clone(&payroll_service),
+            Arc::clone(&bank_service),
+        ));
+
+        let mut dependency_probes: Vec<Arc<dyn DependencyProbe>> =
+            vec![Arc::new(SurrealAnyDatabaseProbe::new(client))];
+        if replica.is_some() {
+            dependency_probes.push(Arc::new(SurrealAnyDatabaseProbe::replica(reader)));
+        }
+        let readiness_service = Arc::new(ReadinessService::new(dependency_probes));
+
         Ok(Self::new(
             organization_service,
             payroll_service,
@@ -144,14 +832,82 @@ impl AppState {
             job_service,
             bank_service,
             employee_service,
+            dependent_service,
+            organization_deletion_service,
+            import_profile_service,
+            employee_import_service,
+            custom_field_service,
+            employee_export_service,
+            position_service,
+            employment_history_service,
+            work_schedule_service,
+            leave_service,
+            timesheet_service,
+            attendance_import_service,
+            profile_completeness_service,
+            self_service_service,
+            email_verification_service,
+            milestone_service,
+            lease_service,
+            job_grade_service,
+            expiry_service,
+            salary_adjustment_service,
+            pay_equity_service,
+            benchmark_export_service,
+            project_service,
+            overtime_service,
+            organization_settings_service,
+            calendar_service,
+            integration_monitor,
+            organization_clone_service,
+            membership_service,
+            legal_entity_service,
+            readiness_service,
         ))
     }
 }
 
+fn deletion_grace_period() -> chrono::Duration {
+    let hours = env::var("ORGANIZATION_DELETION_GRACE_HOURS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(organization_deletion::DEFAULT_DELETION_GRACE_PERIOD_HOURS);
+    chrono::Duration::hours(hours)
+}
+
+/// Redis when `REDIS_URL` is set, so every instance sees the others' invalidations; this
+/// process otherwise.
+#[cfg(feature = "redis-cache")]
+async fn lookup_cache_store() -> Result<Arc<dyn CacheStore>, ServerSetupError> {
+    match redis_url() {
+        Some(url) => Ok(Arc::new(RedisCacheStore::connect(&url).await?)),
+        None => Ok(Arc::new(MemoryCacheStore::default())),
+    }
+}
+
+#[cfg(not(feature = "redis-cache"))]
+async fn lookup_cache_store() -> Result<Arc<dyn CacheStore>, ServerSetupError> {
+    if redis_url().is_some() {
+        return Err(ServerSetupError::RedisCacheDisabled);
+    }
+    Ok(Arc::new(MemoryCacheStore::default()))
+}
+
+fn redis_url() -> Option<String> {
+    env::var("REDIS_URL")
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+}
+
 #[derive(Debug, Error)]
 pub enum ServerSetupError {
     #[error(transparent)]
     Config(#[from] SurrealConfigError),
     #[error(transparent)]
     Database(#[from] surrealdb::Error),
+    #[cfg(feature = "redis-cache")]
+    #[error(transparent)]
+    Cache(#[from] redis::RedisError),
+    #[error("`REDIS_URL` is set but this build lacks the `redis-cache` feature")]
+    RedisCacheDisabled,
 }
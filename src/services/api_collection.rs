@@ -0,0 +1,232 @@
+use std::collections::BTreeSet;
+
+use serde_json::{Map, Value as JsonValue, json};
+
+/// Postman collection format version produced by [`postman_collection`].
+pub const POSTMAN_SCHEMA: &str =
+    "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+/// Deepest schema nesting expanded into example payloads.
+const MAX_EXAMPLE_DEPTH: usize = 8;
+
+const HTTP_METHODS: [&str; 7] = ["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// Converts an OpenAPI document into a Postman v2.1 collection.
+///
+/// Operations are grouped into one folder per tag. Path parameters and
+/// `*_id` body fields are bound to collection variables, so a partner only has
+/// to fill in `baseUrl` and the ids they work with. Insomnia imports the same
+/// format.
+pub fn postman_collection(document: &JsonValue, base_url: &str) -> JsonValue {
+    let mut variables = BTreeSet::new();
+    let mut folders: Vec<(String, Vec<JsonValue>)> = document["tags"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|tag| tag["name"].as_str())
+        .map(|name| (name.to_string(), Vec::new()))
+        .collect();
+
+    for (path, item) in document["paths"].as_object().into_iter().flatten() {
+        for method in HTTP_METHODS {
+            let Some(operation) = item.get(method) else {
+                continue;
+            };
+            let request = request_item(document, path, method, operation, &mut variables);
+            let tag = operation["tags"][0].as_str().unwrap_or("Other");
+            match folders.iter_mut().find(|(name, _)| name == tag) {
+                Some((_, items)) => items.push(request),
+                None => folders.push((tag.to_string(), vec![request])),
+            }
+        }
+    }
+
+    let mut variable = vec![json!({ "key": "baseUrl", "value": base_url })];
+    variable.extend(
+        variables
+            .into_iter()
+            .map(|key| json!({ "key": key, "value": "" })),
+    );
+
+    json!({
+        "info": {
+            "name": document["info"]["title"],
+            "description": document["info"]["description"],
+            "version": document["info"]["version"],
+            "schema": POSTMAN_SCHEMA,
+        },
+        "item": folders
+            .into_iter()
+            .filter(|(_, items)| !items.is_empty())
+            .map(|(name, items)| json!({ "name": name, "item": items }))
+            .collect::<Vec<_>>(),
+        "variable": variable,
+    })
+}
+
+fn request_item(
+    document: &JsonValue,
+    path: &str,
+    method: &str,
+    operation: &JsonValue,
+    variables: &mut BTreeSet<String>,
+) -> JsonValue {
+    let segments: Vec<String> = path
+        .trim_start_matches('/')
+        .split('/')
+        .map(|segment| match segment.strip_prefix('{') {
+            Some(name) => format!(":{}", name.trim_end_matches('}')),
+            None => segment.to_string(),
+        })
+        .collect();
+
+    let mut path_variables = Vec::new();
+    let mut query = Vec::new();
+    for parameter in operation["parameters"].as_array().into_iter().flatten() {
+        let Some(name) = parameter["name"].as_str() else {
+            continue;
+        };
+        match parameter["in"].as_str() {
+            Some("path") => {
+                variables.insert(name.to_string());
+                path_variables.push(json!({ "key": name, "value": format!("{{{{{name}}}}}") }));
+            }
+            Some("query") => query.push(json!({
+                "key": name,
+                "value": "",
+                "description": parameter["description"],
+                "disabled": true,
+            })),
+            _ => {}
+        }
+    }
+
+    let mut request = Map::new();
+    request.insert("method".to_string(), json!(method.to_uppercase()));
+    request.insert(
+        "url".to_string(),
+        json!({
+            "raw": format!("{{{{baseUrl}}}}/{}", segments.join("/")),
+            "host": ["{{baseUrl}}"],
+            "path": segments,
+            "variable": path_variables,
+            "query": query,
+        }),
+    );
+    if let Some(description) = operation["description"].as_str() {
+        request.insert("description".to_string(), json!(description));
+    }
+
+    if let Some(content) = operation["requestBody"]["content"].as_object() {
+        if let Some(media) = content.get("application/json") {
+            let example = example_for(document, &media["schema"], 0, variables);
+            request.insert(
+                "header".to_string(),
+                json!([{ "key": "Content-Type", "value": "application/json" }]),
+            );
+            request.insert(
+                "body".to_string(),
+                json!({
+                    "mode": "raw",
+                    "raw": serde_json::to_string_pretty(&example).unwrap_or_default(),
+                    "options": { "raw": { "language": "json" } },
+                }),
+            );
+        } else if let Some(content_type) = content.keys().next() {
+            request.insert(
+                "header".to_string(),
+                json!([{ "key": "Content-Type", "value": content_type }]),
+            );
+            request.insert("body".to_string(), json!({ "mode": "file", "file": {} }));
+        }
+    }
+
+    let name = operation["summary"]
+        .as_str()
+        .or_else(|| operation["operationId"].as_str())
+        .unwrap_or(path);
+
+    json!({ "name": name, "request": request })
+}
+
+/// Builds a sample value for `schema`, following `$ref`s into the components.
+fn example_for(
+    document: &JsonValue,
+    schema: &JsonValue,
+    depth: usize,
+    variables: &mut BTreeSet<String>,
+) -> JsonValue {
+    if depth > MAX_EXAMPLE_DEPTH {
+        return JsonValue::Null;
+    }
+
+    if let Some(reference) = schema["$ref"].as_str() {
+        let target = reference
+            .strip_prefix("#/")
+            .map(|pointer| format!("/{pointer}"))
+            .and_then(|pointer| document.pointer(&pointer).cloned())
+            .unwrap_or(JsonValue::Null);
+        return example_for(document, &target, depth + 1, variables);
+    }
+    if let Some(example) = schema.get("example") {
+        return example.clone();
+    }
+    if let Some(value) = schema["enum"].as_array().and_then(|values| values.first()) {
+        return value.clone();
+    }
+    for combinator in ["oneOf", "anyOf", "allOf"] {
+        if let Some(variant) = schema[combinator]
+            .as_array()
+            .and_then(|variants| variants.iter().find(|variant| variant["type"] != "null"))
+        {
+            return example_for(document, variant, depth + 1, variables);
+        }
+    }
+
+    let schema_type = match &schema["type"] {
+        JsonValue::String(value) => value.as_str(),
+        JsonValue::Array(values) => values
+            .iter()
+            .filter_map(JsonValue::as_str)
+            .find(|value| *value != "null")
+            .unwrap_or("null"),
+        _ if schema.get("properties").is_some() => "object",
+        _ => "null",
+    };
+
+    match schema_type {
+        "object" => {
+            let mut object = Map::new();
+            for (name, property) in schema["properties"].as_object().into_iter().flatten() {
+                let value = if name.ends_with("_id") && property_is_uuid(property) {
+                    variables.insert(name.clone());
+                    json!(format!("{{{{{name}}}}}"))
+                } else {
+                    example_for(document, property, depth + 1, variables)
+                };
+                object.insert(name.clone(), value);
+            }
+            JsonValue::Object(object)
+        }
+        "array" => json!([example_for(
+            document,
+            &schema["items"],
+            depth + 1,
+            variables
+        )]),
+        "string" => match schema["format"].as_str() {
+            Some("date") => json!("2024-01-31"),
+            Some("date-time") => json!("2024-01-31T00:00:00Z"),
+            Some("uuid") => json!("00000000-0000-0000-0000-000000000000"),
+            _ => json!("string"),
+        },
+        "integer" => json!(0),
+        "number" => json!(0.0),
+        "boolean" => json!(false),
+        _ => JsonValue::Null,
+    }
+}
+
+fn property_is_uuid(property: &JsonValue) -> bool {
+    property["format"] == "uuid"
+}
@@ -0,0 +1,562 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        attendance::{
+            AttendanceAnomaly, AttendanceAnomalyKind, AttendanceImportReport, ClockRecord,
+            PUNCH_ROUNDING_INCREMENTS, PunchPolicy,
+        },
+        employee::{Employee, EmployeeStatus},
+        leave::LeaveRequestStatus,
+        timesheet::{ClockPunch, TimesheetEntry, TimesheetEntryStatus},
+    },
+    error::{AppError, AppResult},
+    services::{
+        employee::EmployeeService,
+        leave::LeaveService,
+        payroll::PayrollService,
+        timesheet::{CreateTimesheetEntryParams, TimesheetService},
+    },
+};
+
+/// Prefix of the note on timesheet entries created from clock records.
+const CLOCK_NOTE_PREFIX: &str = "Time clock";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttendanceFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportAttendanceParams {
+    /// First day to reconcile; defaults to the earliest date in the file.
+    pub from: Option<NaiveDate>,
+    /// Last day to reconcile; defaults to the latest date in the file.
+    pub to: Option<NaiveDate>,
+}
+
+#[async_trait]
+pub trait PunchPolicyRepository: Send + Sync {
+    async fn fetch(&self, payroll_id: Uuid) -> AppResult<Option<PunchPolicy>>;
+    async fn upsert(&self, policy: PunchPolicy) -> AppResult<PunchPolicy>;
+}
+
+#[derive(Clone)]
+pub struct AttendanceImportService {
+    policy_repository: Arc<dyn PunchPolicyRepository>,
+    payroll_service: Arc<PayrollService>,
+    employee_service: Arc<EmployeeService>,
+    timesheet_service: Arc<TimesheetService>,
+    leave_service: Arc<LeaveService>,
+}
+
+impl AttendanceImportService {
+    pub fn new(
+        policy_repository: Arc<dyn PunchPolicyRepository>,
+        payroll_service: Arc<PayrollService>,
+        employee_service: Arc<EmployeeService>,
+        timesheet_service: Arc<TimesheetService>,
+        leave_service: Arc<LeaveService>,
+    ) -> Self {
+        Self {
+            policy_repository,
+            payroll_service,
+            employee_service,
+            timesheet_service,
+            leave_service,
+        }
+    }
+
+    /// The payroll's punch rounding and grace rules; punches are used as recorded when none
+    /// have been set.
+    pub async fn punch_policy(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+    ) -> AppResult<PunchPolicy> {
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        Ok(self
+            .policy_repository
+            .fetch(payroll_id)
+            .await?
+            .unwrap_or_else(|| PunchPolicy::standard(payroll_id)))
+    }
+
+    /// Replaces the payroll's punch rules. Grace periods are measured from the rounding
+    /// increments, so they need rounding and must be shorter than one increment.
+    pub async fn set_punch_policy(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        rounding_minutes: Option<u32>,
+        grace_minutes: u32,
+    ) -> AppResult<PunchPolicy> {
+        match rounding_minutes {
+            Some(minutes) if !PUNCH_ROUNDING_INCREMENTS.contains(&minutes) => {
+                return Err(AppError::validation(
+                    "punches can only be rounded to 5, 10 or 15 minutes",
+                ));
+            }
+            Some(minutes) if grace_minutes >= minutes => {
+                return Err(AppError::validation(
+                    "the grace period must be shorter than the rounding increment",
+                ));
+            }
+            None if grace_minutes > 0 => {
+                return Err(AppError::validation(
+                    "a grace period needs a rounding increment",
+                ));
+            }
+            _ => {}
+        }
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        self.policy_repository
+            .upsert(PunchPolicy {
+                payroll_id,
+                rounding_minutes,
+                grace_minutes,
+            })
+            .await
+    }
+
+    /// Turns each clock record into a submitted timesheet entry, with hours worked out from
+    /// the punches after the payroll's rounding and grace rules, then reconciles the period
+    /// against every active employee's schedule. Shifts running past midnight become two
+    /// entries, one per calendar day, and repeated punch pairs on a day are marked as a
+    /// split shift.
+    ///
+    /// Records already imported are reported as failed rather than logged twice.
+    pub async fn import(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        params: ImportAttendanceParams,
+        format: AttendanceFormat,
+        body: &str,
+    ) -> AppResult<AttendanceImportReport> {
+        if let (Some(from), Some(to)) = (params.from, params.to)
+            && to < from
+        {
+            return Err(AppError::validation(
+                "reconciliation end date cannot be before its start date",
+            ));
+        }
+
+        let records = parse_records(format, body)?;
+        let policy = self.punch_policy(organization_id, payroll_id).await?;
+        let employees = self
+            .employee_service
+            .list(organization_id, payroll_id, division_id)
+            .await?;
+        let by_id_number: HashMap<&str, &Employee> = employees
+            .iter()
+            .map(|employee| (employee.id_number.as_str(), employee))
+            .collect();
+
+        let mut parsed: Vec<ParsedRecord> = Vec::with_capacity(records.len());
+        for (line, record) in records {
+            let Some(employee) = by_id_number.get(record.id_number.trim()).copied() else {
+                parsed.push((
+                    line,
+                    Err((
+                        None,
+                        format!(
+                            "no employee with id number `{}` in the division",
+                            record.id_number.trim()
+                        ),
+                    )),
+                ));
+                continue;
+            };
+            let shift = Shift::parse(&record, &policy).map_err(|err| (Some(employee.id), err));
+            parsed.push((line, shift.map(|shift| (employee, shift))));
+        }
+        mark_split_shifts(&mut parsed);
+
+        let mut report = AttendanceImportReport::default();
+        let mut existing: HashMap<Uuid, Vec<TimesheetEntry>> = HashMap::new();
+        let mut first_date: Option<NaiveDate> = None;
+        let mut last_date: Option<NaiveDate> = None;
+
+        for (line, shift) in parsed {
+            let (employee, shift) = match shift {
+                Ok(shift) => shift,
+                Err((employee_id, err)) => {
+                    report.record_failed(line, employee_id, err);
+                    continue;
+                }
+            };
+            let (hours, next_day_hours) = shift.hours();
+            let end_date = if next_day_hours > 0.0 {
+                shift.next_date()
+            } else {
+                shift.date
+            };
+            first_date = Some(first_date.map_or(shift.date, |date| date.min(shift.date)));
+            last_date = Some(last_date.map_or(end_date, |date| date.max(end_date)));
+
+            let entries = match existing.get_mut(&employee.id) {
+                Some(entries) => entries,
+                None => {
+                    let entries = self
+                        .timesheet_service
+                        .list(
+                            organization_id,
+                            payroll_id,
+                            division_id,
+                            employee.id,
+                            None,
+                            None,
+                        )
+                        .await?;
+                    existing.entry(employee.id).or_insert(entries)
+                }
+            };
+            let note = shift.note();
+            if entries
+                .iter()
+                .any(|entry| entry.date == shift.date && entry.note.as_deref() == Some(&note))
+            {
+                report.record_failed(line, Some(employee.id), "shift was already imported");
+                continue;
+            }
+
+            let entry = match self
+                .timesheet_service
+                .create(
+                    organization_id,
+                    payroll_id,
+                    division_id,
+                    employee.id,
+                    CreateTimesheetEntryParams {
+                        date: shift.date,
+                        hours,
+                        cost_code: None,
+                        project_id: None,
+                        note: Some(note.clone()),
+                        punch: Some(shift.punch),
+                    },
+                )
+                .await
+            {
+                Ok(entry) => entry,
+                Err(err) => {
+                    report.record_failed(line, Some(employee.id), err.to_string());
+                    continue;
+                }
+            };
+            if next_day_hours == 0.0 {
+                report.record_imported(line, employee.id, entry.id, None);
+                entries.push(entry);
+                continue;
+            }
+
+            // Hours after midnight belong to the next calendar day, and so possibly to the
+            // next pay period. Both halves are imported or neither is.
+            match self
+                .timesheet_service
+                .create(
+                    organization_id,
+                    payroll_id,
+                    division_id,
+                    employee.id,
+                    CreateTimesheetEntryParams {
+                        date: shift.next_date(),
+                        hours: next_day_hours,
+                        cost_code: None,
+                        project_id: None,
+                        note: Some(format!("{note}, continued from {}", shift.date)),
+                        punch: Some(ClockPunch {
+                            continued: true,
+                            ..shift.punch
+                        }),
+                    },
+                )
+                .await
+            {
+                Ok(next_day) => {
+                    report.record_imported(line, employee.id, entry.id, Some(next_day.id));
+                    entries.extend([entry, next_day]);
+                }
+                Err(err) => {
+                    self.timesheet_service
+                        .delete(
+                            organization_id,
+                            payroll_id,
+                            division_id,
+                            employee.id,
+                            entry.id,
+                        )
+                        .await?;
+                    report.record_failed(
+                        line,
+                        Some(employee.id),
+                        format!("hours after midnight: {err}"),
+                    );
+                }
+            }
+        }
+
+        let (Some(from), Some(to)) = (params.from.or(first_date), params.to.or(last_date)) else {
+            return Ok(report);
+        };
+        for employee in employees
+            .iter()
+            .filter(|employee| employee.status == EmployeeStatus::Active)
+        {
+            let anomalies = self
+                .reconcile(organization_id, payroll_id, division_id, employee, from, to)
+                .await?;
+            report.anomalies.extend(anomalies);
+        }
+
+        Ok(report)
+    }
+
+    async fn reconcile(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee: &Employee,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<AttendanceAnomaly>> {
+        let Some(expectation) = self
+            .employee_service
+            .work_expectation(organization_id, payroll_id, division_id, employee.id)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+
+        let mut worked: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+        for entry in self
+            .timesheet_service
+            .list(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee.id,
+                Some(from),
+                Some(to),
+            )
+            .await?
+            .into_iter()
+            .filter(|entry| entry.status != TimesheetEntryStatus::Rejected)
+        {
+            *worked.entry(entry.date).or_default() += entry.hours;
+        }
+
+        let leave: Vec<(NaiveDate, NaiveDate)> = self
+            .leave_service
+            .list(organization_id, payroll_id, division_id, employee.id)
+            .await?
+            .into_iter()
+            .filter(|request| request.status == LeaveRequestStatus::Approved)
+            .map(|request| (request.start_date, request.end_date))
+            .collect();
+
+        let mut anomalies = Vec::new();
+        let mut weeks: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+        let start = from.max(employee.hire_date);
+        for date in start.iter_days().take_while(|date| *date <= to) {
+            let hours = worked.get(&date).copied().unwrap_or(0.0);
+            let scheduled = expectation.works_on(date.weekday());
+            let on_leave = leave
+                .iter()
+                .any(|(start, end)| *start <= date && date <= *end);
+            *weeks.entry(week_start(date)).or_default() += hours;
+
+            if scheduled && hours == 0.0 && !on_leave {
+                anomalies.push(AttendanceAnomaly {
+                    employee_id: employee.id,
+                    kind: AttendanceAnomalyKind::MissingDay,
+                    date,
+                    hours: None,
+                    detail: "scheduled working day without punches".to_string(),
+                });
+            }
+            if !scheduled && hours > 0.0 {
+                anomalies.push(AttendanceAnomaly {
+                    employee_id: employee.id,
+                    kind: AttendanceAnomalyKind::UnscheduledDay,
+                    date,
+                    hours: Some(hours),
+                    detail: format!("{hours} hours worked on a day off"),
+                });
+            }
+            if let Some(limit) = expectation.daily_overtime_after
+                && hours > limit
+            {
+                anomalies.push(AttendanceAnomaly {
+                    employee_id: employee.id,
+                    kind: AttendanceAnomalyKind::ExcessiveOvertime,
+                    date,
+                    hours: Some(hours),
+                    detail: format!("{hours} hours exceed the daily threshold of {limit}"),
+                });
+            }
+        }
+
+        let weekly_limit = expectation.weekly_overtime_after;
+        for (monday, hours) in weeks {
+            if hours > weekly_limit {
+                anomalies.push(AttendanceAnomaly {
+                    employee_id: employee.id,
+                    kind: AttendanceAnomalyKind::ExcessiveOvertime,
+                    date: monday,
+                    hours: Some(hours),
+                    detail: format!(
+                        "{hours} hours in the week exceed the weekly threshold of {weekly_limit}"
+                    ),
+                });
+            }
+        }
+
+        Ok(anomalies)
+    }
+}
+
+/// A clock record by line, matched to its employee, or the reason it cannot be imported.
+type ParsedRecord<'a> = (u64, Result<(&'a Employee, Shift), (Option<Uuid>, String)>);
+
+/// A parsed clock record.
+struct Shift {
+    date: NaiveDate,
+    punch: ClockPunch,
+}
+
+impl Shift {
+    fn parse(record: &ClockRecord, policy: &PunchPolicy) -> Result<Self, String> {
+        let date = NaiveDate::parse_from_str(record.date.trim(), "%Y-%m-%d")
+            .map_err(|_| format!("`{}` is not a YYYY-MM-DD date", record.date.trim()))?;
+        let clock_in = parse_time(&record.clock_in)?;
+        let clock_out = parse_time(&record.clock_out)?;
+        if clock_in == clock_out {
+            return Err("clock-in and clock-out are the same time".to_string());
+        }
+        let adjusted_clock_in = policy.adjust_clock_in(clock_in);
+        let adjusted_clock_out = policy.adjust_clock_out(clock_out);
+        if adjusted_clock_in == adjusted_clock_out {
+            return Err("the shift is shorter than the punch rounding".to_string());
+        }
+
+        Ok(Self {
+            date,
+            punch: ClockPunch {
+                clock_in,
+                clock_out,
+                adjusted_clock_in,
+                adjusted_clock_out,
+                overnight: adjusted_clock_out < adjusted_clock_in
+                    && adjusted_clock_out != NaiveTime::MIN,
+                split: false,
+                continued: false,
+            },
+        })
+    }
+
+    /// Hours between the adjusted punches, rounded to the minute: those on the day the shift
+    /// started, and those worked after midnight.
+    fn hours(&self) -> (f64, f64) {
+        let to_hours =
+            |duration: Duration| (duration.num_minutes() as f64 / 60.0 * 100.0).round() / 100.0;
+        let start = self.punch.adjusted_clock_in;
+        let end = self.punch.adjusted_clock_out;
+        if end > start {
+            return (to_hours(end - start), 0.0);
+        }
+
+        (
+            to_hours(Duration::days(1) - (start - NaiveTime::MIN)),
+            to_hours(end - NaiveTime::MIN),
+        )
+    }
+
+    fn next_date(&self) -> NaiveDate {
+        self.date + Duration::days(1)
+    }
+
+    /// Names the punches as recorded, so re-imports match regardless of the rules in force.
+    fn note(&self) -> String {
+        format!(
+            "{CLOCK_NOTE_PREFIX} {}-{}",
+            self.punch.clock_in.format("%H:%M"),
+            self.punch.clock_out.format("%H:%M")
+        )
+    }
+}
+
+/// Flags shifts of employees with more than one punch pair on the same day of the file.
+fn mark_split_shifts(parsed: &mut [ParsedRecord<'_>]) {
+    let mut per_day: HashMap<(Uuid, NaiveDate), HashSet<(NaiveTime, NaiveTime)>> = HashMap::new();
+    for (_, shift) in parsed.iter() {
+        if let Ok((employee, shift)) = shift {
+            per_day
+                .entry((employee.id, shift.date))
+                .or_default()
+                .insert((shift.punch.clock_in, shift.punch.clock_out));
+        }
+    }
+    for (_, shift) in parsed.iter_mut() {
+        if let Ok((employee, shift)) = shift {
+            shift.punch.split = per_day[&(employee.id, shift.date)].len() > 1;
+        }
+    }
+}
+
+fn parse_time(value: &str) -> Result<NaiveTime, String> {
+    let value = value.trim();
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M:%S"))
+        .map_err(|_| format!("`{value}` is not an HH:MM time"))
+}
+
+fn parse_records(format: AttendanceFormat, body: &str) -> AppResult<Vec<(u64, ClockRecord)>> {
+    let records = match format {
+        AttendanceFormat::Json => serde_json::from_str::<Vec<ClockRecord>>(body)
+            .map_err(|err| AppError::validation(format!("invalid attendance JSON: {err}")))?
+            .into_iter()
+            .zip(1..)
+            .map(|(record, line)| (line, record))
+            .collect(),
+        AttendanceFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(body.as_bytes());
+            let mut records = Vec::new();
+            for record in reader.deserialize::<ClockRecord>() {
+                let record = record
+                    .map_err(|err| AppError::validation(format!("invalid CSV row: {err}")))?;
+                records.push((records.len() as u64 + 2, record));
+            }
+            records
+        }
+    };
+
+    if records.is_empty() {
+        return Err(AppError::validation("the file contains no clock records"));
+    }
+
+    Ok(records)
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
@@ -1,60 +1,196 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::{
-    domain::bank::Bank,
+    domain::{
+        bank::{AccountRule, Bank, CatalogBank, PaymentFormat},
+        employee::{AccountVerification, PaymentInstruction},
+    },
     error::{AppError, AppResult},
-    services::organization::OrganizationService,
+    services::{employee::EmployeeRepository, organization::OrganizationService},
 };
 
 #[derive(Debug, Clone)]
 pub struct CreateBankParams {
     pub name: String,
+    pub swift_code: Option<String>,
+    pub routing_number: Option<String>,
+    pub country: Option<String>,
+    pub address: Option<String>,
+    pub account_rule: Option<AccountRule>,
+    pub payment_format: Option<PaymentFormat>,
+    /// Catalog entry to inherit the SWIFT code, routing number and country from.
+    pub catalog_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct UpdateBankParams {
     pub name: Option<String>,
+    pub swift_code: Option<Option<String>>,
+    pub routing_number: Option<Option<String>>,
+    pub country: Option<Option<String>>,
+    pub address: Option<Option<String>>,
+    pub account_rule: Option<Option<AccountRule>>,
+    pub payment_format: Option<Option<PaymentFormat>>,
+    /// `Some(None)` unlinks the bank, keeping the details it inherited.
+    pub catalog_id: Option<Option<Uuid>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateCatalogBankParams {
+    pub name: String,
+    pub swift_code: Option<String>,
+    pub routing_number: Option<String>,
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateCatalogBankParams {
+    pub name: Option<String>,
+    pub swift_code: Option<Option<String>>,
+    pub routing_number: Option<Option<String>>,
+    pub country: Option<Option<String>>,
+}
+
+/// Payment details of a bank, validated against each other.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct BankDetails {
+    swift_code: Option<String>,
+    routing_number: Option<String>,
+    country: Option<String>,
+    address: Option<String>,
 }
 
 #[async_trait]
 pub trait BankRepository: Send + Sync {
-    async fn insert(&self, id: Uuid, name: String, organization_id: Uuid) -> AppResult<Bank>;
+    #[allow(clippy::too_many_arguments)]
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        organization_id: Uuid,
+        swift_code: Option<String>,
+        routing_number: Option<String>,
+        country: Option<String>,
+        address: Option<String>,
+        account_rule: Option<AccountRule>,
+        payment_format: Option<PaymentFormat>,
+        catalog_id: Option<Uuid>,
+    ) -> AppResult<Bank>;
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Bank>>;
     async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Bank>>;
-    async fn update(&self, id: Uuid, name: Option<String>) -> AppResult<Option<Bank>>;
+    async fn fetch_by_catalog_entry(&self, catalog_id: Uuid) -> AppResult<Vec<Bank>>;
+    #[allow(clippy::too_many_arguments)]
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        swift_code: Option<Option<String>>,
+        routing_number: Option<Option<String>>,
+        country: Option<Option<String>>,
+        address: Option<Option<String>>,
+        account_rule: Option<Option<AccountRule>>,
+        payment_format: Option<Option<PaymentFormat>>,
+        catalog_id: Option<Option<Uuid>>,
+    ) -> AppResult<Option<Bank>>;
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Bank>>;
+    async fn delete(&self, id: Uuid, reassignment: Option<BankReassignment>) -> AppResult<bool>;
+}
+
+/// Employees moved off a bank being deleted, each with the payment instructions they keep.
+#[derive(Debug, Clone)]
+pub struct BankReassignment {
+    pub to_bank_id: Uuid,
+    pub payment_instructions: Vec<(Uuid, Vec<PaymentInstruction>)>,
+}
+
+#[async_trait]
+pub trait BankCatalogRepository: Send + Sync {
+    async fn insert(&self, entry: CatalogBank) -> AppResult<CatalogBank>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<CatalogBank>>;
+    async fn fetch_all(&self) -> AppResult<Vec<CatalogBank>>;
+    async fn update(&self, entry: CatalogBank) -> AppResult<Option<CatalogBank>>;
     async fn delete(&self, id: Uuid) -> AppResult<bool>;
 }
 
 #[derive(Clone)]
 pub struct BankService {
     repository: Arc<dyn BankRepository>,
+    catalog_repository: Arc<dyn BankCatalogRepository>,
     organization_service: Arc<OrganizationService>,
+    employee_repository: Arc<dyn EmployeeRepository>,
 }
 
 impl BankService {
     pub fn new(
         repository: Arc<dyn BankRepository>,
+        catalog_repository: Arc<dyn BankCatalogRepository>,
         organization_service: Arc<OrganizationService>,
+        employee_repository: Arc<dyn EmployeeRepository>,
     ) -> Self {
         Self {
             repository,
+            catalog_repository,
             organization_service,
+            employee_repository,
         }
     }
 
+    /// Creates a bank, inheriting its SWIFT code, routing number and country when it is
+    /// linked to a catalog entry.
     pub async fn create(&self, organization_id: Uuid, params: CreateBankParams) -> AppResult<Bank> {
         let name = Self::normalize_name(&params.name)?;
+        let mut details = BankDetails {
+            swift_code: params.swift_code,
+            routing_number: params.routing_number,
+            country: params.country,
+            address: params.address,
+        };
+        if let Some(catalog_id) = params.catalog_id {
+            Self::ensure_not_inherited(
+                details.swift_code.is_some()
+                    || details.routing_number.is_some()
+                    || details.country.is_some(),
+            )?;
+            details = details.inherit(&self.catalog_entry(catalog_id).await?);
+        }
+        let details = details.normalize()?;
+        if let Some(format) = &params.payment_format {
+            details.ensure_supports(format)?;
+        }
+        let account_rule = params
+            .account_rule
+            .map(normalize_account_rule)
+            .transpose()?;
         self.ensure_organization_exists(organization_id).await?;
         let id = Uuid::new_v4();
-        self.repository.insert(id, name, organization_id).await
+        self.repository
+            .insert(
+                id,
+                name,
+                organization_id,
+                details.swift_code,
+                details.routing_number,
+                details.country,
+                details.address,
+                account_rule,
+                params.payment_format,
+                params.catalog_id,
+            )
+            .await
     }
 
     pub async fn get(&self, organization_id: Uuid, bank_id: Uuid) -> AppResult<Option<Bank>> {
         let bank = self.repository.fetch(bank_id).await?;
-        Ok(bank.filter(|bank| bank.organization_id == organization_id))
+        Ok(bank
+            .filter(|bank| bank.organization_id == organization_id && bank.archived_at.is_none()))
     }
 
     pub async fn list(&self, organization_id: Uuid) -> AppResult<Vec<Bank>> {
@@ -63,22 +199,79 @@ impl BankService {
             .repository
             .fetch_by_organization(organization_id)
             .await?;
+        banks.retain(|bank| bank.archived_at.is_none());
         banks.sort_by(|a, b| a.name.cmp(&b.name));
         Ok(banks)
     }
 
+    /// Archives the banks of a soft-deleted organization.
+    pub(crate) async fn archive_organization(
+        &self,
+        organization_id: Uuid,
+        archived_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        for bank in self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?
+        {
+            if bank.archived_at.is_none() {
+                self.repository
+                    .set_archived_at(bank.id, Some(archived_at))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn restore_organization(&self, organization_id: Uuid) -> AppResult<()> {
+        for bank in self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?
+        {
+            if bank.archived_at.is_some() {
+                self.repository.set_archived_at(bank.id, None).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn update(
         &self,
         organization_id: Uuid,
         bank_id: Uuid,
-        params: UpdateBankParams,
+        mut params: UpdateBankParams,
     ) -> AppResult<Option<Bank>> {
-        if params.name.is_none() {
+        if params.name.is_none()
+            && params.swift_code.is_none()
+            && params.routing_number.is_none()
+            && params.country.is_none()
+            && params.address.is_none()
+            && params.account_rule.is_none()
+            && params.payment_format.is_none()
+            && params.catalog_id.is_none()
+        {
             return Err(AppError::validation("no fields supplied for update"));
         }
 
-        if self.get(organization_id, bank_id).await?.is_none() {
+        let Some(existing) = self.get(organization_id, bank_id).await? else {
             return Ok(None);
+        };
+        if params.catalog_id.unwrap_or(existing.catalog_id).is_some() {
+            Self::ensure_not_inherited(
+                params.swift_code.is_some()
+                    || params.routing_number.is_some()
+                    || params.country.is_some(),
+            )?;
+        }
+        if let Some(Some(catalog_id)) = params.catalog_id {
+            let entry = self.catalog_entry(catalog_id).await?;
+            params.swift_code = Some(entry.swift_code);
+            params.routing_number = Some(entry.routing_number);
+            params.country = Some(entry.country);
         }
 
         let name = params
@@ -86,16 +279,242 @@ impl BankService {
             .as_deref()
             .map(Self::normalize_name)
             .transpose()?;
+        // Details depend on one another, so the merged result is validated as a whole.
+        let details = BankDetails {
+            swift_code: params.swift_code.clone().unwrap_or(existing.swift_code),
+            routing_number: params
+                .routing_number
+                .clone()
+                .unwrap_or(existing.routing_number),
+            country: params.country.clone().unwrap_or(existing.country),
+            address: params.address.clone().unwrap_or(existing.address),
+        }
+        .normalize()?;
+        // A detail change must not leave the bank without what its payment format needs.
+        if let Some(format) = params
+            .payment_format
+            .clone()
+            .unwrap_or(existing.payment_format)
+        {
+            details.ensure_supports(&format)?;
+        }
+        let account_rule = params
+            .account_rule
+            .map(|rule| rule.map(normalize_account_rule).transpose())
+            .transpose()?;
 
-        self.repository.update(bank_id, name).await
+        self.repository
+            .update(
+                bank_id,
+                name,
+                params.swift_code.map(|_| details.swift_code),
+                params.routing_number.map(|_| details.routing_number),
+                params.country.map(|_| details.country),
+                params.address.map(|_| details.address),
+                account_rule,
+                params.payment_format,
+                params.catalog_id,
+            )
+            .await
     }
 
-    pub async fn delete(&self, organization_id: Uuid, bank_id: Uuid) -> AppResult<bool> {
+    /// Refused while employees are paid through the bank, unless `reassign_to` names another
+    /// bank of the organization to move their payment instructions to first. Moved accounts
+    /// start over as unverified, since the new bank never checked them.
+    pub async fn delete(
+        &self,
+        organization_id: Uuid,
+        bank_id: Uuid,
+        reassign_to: Option<Uuid>,
+    ) -> AppResult<bool> {
         if self.get(organization_id, bank_id).await?.is_none() {
             return Ok(false);
         }
 
-        self.repository.delete(bank_id).await
+        let paid = self.employee_repository.fetch_by_bank(bank_id).await?;
+        let mut reassignment = None;
+        if !paid.is_empty() {
+            let Some(target_id) = reassign_to else {
+                return Err(AppError::conflict(format!(
+                    "bank `{bank_id}` still pays {} employee(s)",
+                    paid.len()
+                )));
+            };
+            if target_id == bank_id {
+                return Err(AppError::validation(
+                    "reassign_to must name a different bank",
+                ));
+            }
+            let target = self.get(organization_id, target_id).await?.ok_or_else(|| {
+                AppError::not_found(format!(
+                    "bank `{target_id}` not found for organization `{organization_id}`"
+                ))
+            })?;
+
+            // Every account is checked against the target, then all of them move with the delete.
+            let mut payment_instructions = Vec::with_capacity(paid.len());
+            for employee in paid {
+                let mut instructions = employee.payment_instructions;
+                for instruction in instructions
+                    .iter_mut()
+                    .filter(|instruction| instruction.bank_id == bank_id)
+                {
+                    if let Some(rule) = &target.account_rule {
+                        instruction.account =
+                            rule.check(&instruction.account).map_err(|reason| {
+                                AppError::validation(format!(
+                                    "bank account `{}` of employee `{}` {reason} for bank `{}`",
+                                    instruction.account, employee.id, target.name
+                                ))
+                            })?;
+                    }
+                    instruction.bank_id = target_id;
+                    instruction.verification = AccountVerification::default();
+                }
+                payment_instructions.push((employee.id, instructions));
+            }
+            reassignment = Some(BankReassignment {
+                to_bank_id: target_id,
+                payment_instructions,
+            });
+        }
+
+        self.repository.delete(bank_id, reassignment).await
+    }
+
+    /// Adds a bank to the catalog shared by all organizations.
+    pub async fn create_catalog_entry(
+        &self,
+        params: CreateCatalogBankParams,
+    ) -> AppResult<CatalogBank> {
+        let name = Self::normalize_name(&params.name)?;
+        let details = BankDetails {
+            swift_code: params.swift_code,
+            routing_number: params.routing_number,
+            country: params.country,
+            address: None,
+        }
+        .normalize()?;
+
+        self.catalog_repository
+            .insert(CatalogBank {
+                id: Uuid::new_v4(),
+                name,
+                swift_code: details.swift_code,
+                routing_number: details.routing_number,
+                country: details.country,
+            })
+            .await
+    }
+
+    pub async fn get_catalog_entry(&self, catalog_id: Uuid) -> AppResult<Option<CatalogBank>> {
+        self.catalog_repository.fetch(catalog_id).await
+    }
+
+    /// Catalog entries by country, then name.
+    pub async fn list_catalog(&self) -> AppResult<Vec<CatalogBank>> {
+        let mut entries = self.catalog_repository.fetch_all().await?;
+        entries.sort_by(|a, b| (&a.country, &a.name).cmp(&(&b.country, &b.name)));
+        Ok(entries)
+    }
+
+    /// Updates a catalog entry and copies its SWIFT code, routing number and country to every
+    /// bank linked to it. Refused when a linked bank's payment format would lose what it needs.
+    pub async fn update_catalog_entry(
+        &self,
+        catalog_id: Uuid,
+        params: UpdateCatalogBankParams,
+    ) -> AppResult<Option<CatalogBank>> {
+        if params.name.is_none()
+            && params.swift_code.is_none()
+            && params.routing_number.is_none()
+            && params.country.is_none()
+        {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+        let Some(existing) = self.catalog_repository.fetch(catalog_id).await? else {
+            return Ok(None);
+        };
+
+        let name = params
+            .name
+            .as_deref()
+            .map(Self::normalize_name)
+            .transpose()?
+            .unwrap_or(existing.name);
+        let details = BankDetails {
+            swift_code: params.swift_code.unwrap_or(existing.swift_code),
+            routing_number: params.routing_number.unwrap_or(existing.routing_number),
+            country: params.country.unwrap_or(existing.country),
+            address: None,
+        }
+        .normalize()?;
+
+        let linked = self.repository.fetch_by_catalog_entry(catalog_id).await?;
+        for bank in &linked {
+            if let Some(format) = &bank.payment_format {
+                details.ensure_supports(format).map_err(|_| {
+                    AppError::conflict(format!(
+                        "bank `{}` of organization `{}` pays in a format the updated details no longer support",
+                        bank.id, bank.organization_id
+                    ))
+                })?;
+            }
+        }
+
+        let entry = CatalogBank {
+            id: catalog_id,
+            name,
+            swift_code: details.swift_code,
+            routing_number: details.routing_number,
+            country: details.country,
+        };
+        for bank in linked {
+            self.repository
+                .update(
+                    bank.id,
+                    None,
+                    Some(entry.swift_code.clone()),
+                    Some(entry.routing_number.clone()),
+                    Some(entry.country.clone()),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+        }
+        self.catalog_repository.update(entry).await
+    }
+
+    /// Refused while organization banks are still linked to the entry.
+    pub async fn delete_catalog_entry(&self, catalog_id: Uuid) -> AppResult<bool> {
+        let linked = self.repository.fetch_by_catalog_entry(catalog_id).await?;
+        if !linked.is_empty() {
+            return Err(AppError::conflict(format!(
+                "catalog bank `{catalog_id}` is still linked to {} bank(s)",
+                linked.len()
+            )));
+        }
+
+        self.catalog_repository.delete(catalog_id).await
+    }
+
+    async fn catalog_entry(&self, catalog_id: Uuid) -> AppResult<CatalogBank> {
+        self.catalog_repository
+            .fetch(catalog_id)
+            .await?
+            .ok_or_else(|| AppError::not_found(format!("catalog bank `{catalog_id}` not found")))
+    }
+
+    fn ensure_not_inherited(details_supplied: bool) -> AppResult<()> {
+        if details_supplied {
+            Err(AppError::validation(
+                "SWIFT code, routing number and country are inherited from the bank catalog; unlink the bank to set them",
+            ))
+        } else {
+            Ok(())
+        }
     }
 
     async fn ensure_organization_exists(&self, organization_id: Uuid) -> AppResult<()> {
@@ -123,3 +542,177 @@ impl BankService {
         Ok(name.to_string())
     }
 }
+
+impl BankDetails {
+    fn inherit(self, entry: &CatalogBank) -> Self {
+        Self {
+            swift_code: entry.swift_code.clone(),
+            routing_number: entry.routing_number.clone(),
+            country: entry.country.clone(),
+            ..self
+        }
+    }
+
+    fn normalize(self) -> AppResult<Self> {
+        let country = self.country.as_deref().map(normalize_country).transpose()?;
+        let swift_code = self
+            .swift_code
+            .as_deref()
+            .map(normalize_swift_code)
+            .transpose()?;
+        if let (Some(swift_code), Some(country)) = (&swift_code, &country)
+            && swift_code[4..6] != *country
+        {
+            return Err(AppError::validation(format!(
+                "SWIFT code `{swift_code}` belongs to country `{}`, not `{country}`",
+                &swift_code[4..6]
+            )));
+        }
+        let routing_number = self
+            .routing_number
+            .as_deref()
+            .map(|value| normalize_routing_number(value, country.as_deref()))
+            .transpose()?;
+        let address = self
+            .address
+            .map(|address| {
+                let address = address.trim().to_string();
+                if address.is_empty() {
+                    Err(AppError::validation("bank address cannot be empty"))
+                } else {
+                    Ok(address)
+                }
+            })
+            .transpose()?;
+
+        Ok(Self {
+            swift_code,
+            routing_number,
+            country,
+            address,
+        })
+    }
+
+    /// Checks the details carry what `format` puts in the payment file.
+    fn ensure_supports(&self, format: &PaymentFormat) -> AppResult<()> {
+        match format {
+            PaymentFormat::Sepa if self.swift_code.is_none() => Err(AppError::validation(
+                "SEPA payment files need the bank's SWIFT code",
+            )),
+            PaymentFormat::Nacha
+                if self.routing_number.is_none() || self.country.as_deref() != Some("US") =>
+            {
+                Err(AppError::validation(
+                    "NACHA payment files need a US bank with an ABA routing number",
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks the lengths are positive and in order, and compacts the prefixes the way accounts
+/// are compacted before being matched against them.
+fn normalize_account_rule(rule: AccountRule) -> AppResult<AccountRule> {
+    if rule.min_length == Some(0) || rule.max_length == Some(0) {
+        return Err(AppError::validation("account lengths must be at least 1"));
+    }
+    if let (Some(min_length), Some(max_length)) = (rule.min_length, rule.max_length)
+        && min_length > max_length
+    {
+        return Err(AppError::validation(format!(
+            "account min length {min_length} exceeds max length {max_length}"
+        )));
+    }
+    let prefixes = rule
+        .prefixes
+        .iter()
+        .map(|prefix| {
+            let compact = AccountRule::compact(prefix);
+            if compact.is_empty() || !compact.chars().all(|c| c.is_ascii_alphanumeric()) {
+                Err(AppError::validation(format!(
+                    "account prefix `{}` must be letters or digits",
+                    prefix.trim()
+                )))
+            } else {
+                Ok(compact)
+            }
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+
+    Ok(AccountRule { prefixes, ..rule })
+}
+
+/// Upper-cases a SWIFT/BIC code, rejecting anything but a four-letter bank code, a two-letter
+/// country, a two-character location and an optional three-character branch.
+fn normalize_swift_code(value: &str) -> AppResult<String> {
+    let code = value.trim().to_ascii_uppercase();
+    let bytes = code.as_bytes();
+    let valid = matches!(bytes.len(), 8 | 11)
+        && bytes[..6].iter().all(u8::is_ascii_alphabetic)
+        && bytes[6..].iter().all(u8::is_ascii_alphanumeric);
+    if !valid {
+        return Err(AppError::validation(format!(
+            "SWIFT code `{}` must be 8 or 11 characters: bank, country, location and optional branch",
+            value.trim()
+        )));
+    }
+
+    Ok(code)
+}
+
+/// Upper-cases a country code, rejecting anything but two ASCII letters.
+fn normalize_country(value: &str) -> AppResult<String> {
+    let code = value.trim().to_ascii_uppercase();
+    if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(AppError::validation(format!(
+            "bank country `{}` is not a two-letter ISO 3166-1 code",
+            value.trim()
+        )));
+    }
+
+    Ok(code)
+}
+
+/// Drops spaces and hyphens from a routing number or sort code. US ABA numbers need nine
+/// digits with a valid checksum and UK sort codes six digits; other countries take 3 to 20
+/// letters or digits.
+fn normalize_routing_number(value: &str, country: Option<&str>) -> AppResult<String> {
+    let number: String = value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-'))
+        .collect::<String>()
+        .to_ascii_uppercase();
+    let digits = !number.is_empty() && number.chars().all(|c| c.is_ascii_digit());
+    let error = match country {
+        Some("US") if !(digits && number.len() == 9 && aba_checksum_matches(&number)) => {
+            Some("is not a valid nine-digit ABA routing number")
+        }
+        Some("GB") if !(digits && number.len() == 6) => Some("is not a six-digit sort code"),
+        Some("US" | "GB") => None,
+        _ if !(3..=20).contains(&number.len())
+            || !number.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            Some("must be 3 to 20 letters or digits")
+        }
+        _ => None,
+    };
+    if let Some(error) = error {
+        return Err(AppError::validation(format!(
+            "routing number `{}` {error}",
+            value.trim()
+        )));
+    }
+
+    Ok(number)
+}
+
+/// ABA check: digits weighted 3, 7, 1 in turn must add up to a multiple of ten.
+fn aba_checksum_matches(number: &str) -> bool {
+    number
+        .bytes()
+        .zip([3, 7, 1].into_iter().cycle())
+        .map(|(digit, weight)| u32::from(digit - b'0') * weight)
+        .sum::<u32>()
+        .is_multiple_of(10)
+}
@@ -0,0 +1,167 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        benchmark::{BENCHMARK_COLUMNS, BenchmarkBucket, DEFAULT_PAY_BAND_WIDTH},
+        employee::EmployeeStatus,
+        employee_export::ExportFormat,
+        pay_equity::{DEFAULT_MIN_GROUP_SIZE, SMALLEST_MIN_GROUP_SIZE},
+    },
+    error::{AppError, AppResult},
+    services::{
+        division::DivisionService, employee::EmployeeService,
+        employee_export::EmployeeExportService, job::JobService, job_grade::JobGradeService,
+        organization_settings::OrganizationSettingsService, payroll::PayrollService,
+    },
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct BenchmarkExportParams {
+    pub format: ExportFormat,
+    /// Width of the annual pay bands; [`DEFAULT_PAY_BAND_WIDTH`] when `None`.
+    pub pay_band_width: Option<u32>,
+    /// Buckets with fewer employees are left out; [`DEFAULT_MIN_GROUP_SIZE`] when `None`.
+    pub min_group_size: Option<usize>,
+}
+
+/// Counts current employees by job grade, region, currency and annual pay band across the
+/// organization, for sharing with compensation benchmarking providers.
+#[derive(Clone)]
+pub struct BenchmarkExportService {
+    payroll_service: Arc<PayrollService>,
+    division_service: Arc<DivisionService>,
+    job_service: Arc<JobService>,
+    job_grade_service: Arc<JobGradeService>,
+    employee_service: Arc<EmployeeService>,
+    settings_service: Arc<OrganizationSettingsService>,
+}
+
+impl BenchmarkExportService {
+    pub fn new(
+        payroll_service: Arc<PayrollService>,
+        division_service: Arc<DivisionService>,
+        job_service: Arc<JobService>,
+        job_grade_service: Arc<JobGradeService>,
+        employee_service: Arc<EmployeeService>,
+        settings_service: Arc<OrganizationSettingsService>,
+    ) -> Self {
+        Self {
+            payroll_service,
+            division_service,
+            job_service,
+            job_grade_service,
+            employee_service,
+            settings_service,
+        }
+    }
+
+    /// Renders the buckets as CSV or XLSX. No names, identifiers or exact salaries are
+    /// included, and buckets smaller than the minimum group size are dropped.
+    pub async fn export(
+        &self,
+        organization_id: Uuid,
+        params: BenchmarkExportParams,
+    ) -> AppResult<Vec<u8>> {
+        let width = params.pay_band_width.unwrap_or(DEFAULT_PAY_BAND_WIDTH);
+        if width == 0 {
+            return Err(AppError::validation(
+                "pay_band_width must be greater than 0",
+            ));
+        }
+        let min_group_size = params.min_group_size.unwrap_or(DEFAULT_MIN_GROUP_SIZE);
+        if min_group_size < SMALLEST_MIN_GROUP_SIZE {
+            return Err(AppError::validation(format!(
+                "min_group_size must be at least {SMALLEST_MIN_GROUP_SIZE}"
+            )));
+        }
+
+        let settings = self.settings_service.current(organization_id).await?;
+        let mut buckets: BTreeMap<BenchmarkBucket, usize> = BTreeMap::new();
+        for payroll in self.payroll_service.list(organization_id).await? {
+            let grades: HashMap<Uuid, _> = self
+                .job_grade_service
+                .list(organization_id, payroll.id)
+                .await?
+                .into_iter()
+                .map(|grade| (grade.id, grade))
+                .collect();
+            let jobs: HashMap<Uuid, _> = self
+                .job_service
+                .list(organization_id, payroll.id)
+                .await?
+                .into_iter()
+                .map(|job| (job.id, job))
+                .collect();
+            let regions: HashMap<Uuid, Option<String>> = self
+                .division_service
+                .list(organization_id, payroll.id)
+                .await?
+                .into_iter()
+                .map(|division| (division.id, division.region))
+                .collect();
+            let employees = self
+                .employee_service
+                .search(organization_id, Some(payroll.id), None)
+                .await?;
+
+            for employee in employees
+                .iter()
+                .filter(|employee| employee.status != EmployeeStatus::Terminated)
+            {
+                let Some(job) = jobs.get(&employee.job_id) else {
+                    continue;
+                };
+                let Some(expectation) = self
+                    .employee_service
+                    .work_expectation(
+                        organization_id,
+                        payroll.id,
+                        employee.division_id,
+                        employee.id,
+                    )
+                    .await?
+                else {
+                    continue;
+                };
+                let grade = job.grade_id.and_then(|grade_id| grades.get(&grade_id));
+                let annual_pay = job.pay_basis.annual_amount(job.salary, &expectation);
+
+                let bucket = BenchmarkBucket {
+                    grade_band: grade.map(|grade| grade.band.clone()).unwrap_or_default(),
+                    grade_level: grade.map(|grade| grade.level),
+                    grade_code: grade.map(|grade| grade.code.clone()).unwrap_or_default(),
+                    region: regions
+                        .get(&employee.division_id)
+                        .cloned()
+                        .flatten()
+                        .unwrap_or_default(),
+                    currency: job
+                        .currency
+                        .clone()
+                        .or_else(|| settings.default_currency.clone())
+                        .unwrap_or_default(),
+                    pay_band_from: BenchmarkBucket::band_floor(annual_pay, width),
+                };
+                *buckets.entry(bucket).or_default() += 1;
+            }
+        }
+
+        let rows: Vec<Vec<String>> = buckets
+            .iter()
+            .filter(|(_, headcount)| **headcount >= min_group_size)
+            .map(|(bucket, headcount)| bucket.row(width, *headcount))
+            .collect();
+
+        match params.format {
+            ExportFormat::Csv => EmployeeExportService::write_csv(&BENCHMARK_COLUMNS, &rows),
+            ExportFormat::Xlsx => {
+                EmployeeExportService::write_xlsx("Benchmark", &BENCHMARK_COLUMNS, &rows)
+            }
+        }
+    }
+}
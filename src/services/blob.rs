@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::{domain::blob::Blob, error::AppResult};
+
+/// Storage backend for binary objects addressed by a caller-chosen key.
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    async fn put(&self, key: &str, blob: Blob) -> AppResult<()>;
+    async fn get(&self, key: &str) -> AppResult<Option<Blob>>;
+    async fn delete(&self, key: &str) -> AppResult<bool>;
+}
@@ -0,0 +1,207 @@
+use std::{
+    fmt::Display,
+    future::Future,
+    marker::PhantomData,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use moka::{Expiry, future::Cache};
+use serde::{Serialize, de::DeserializeOwned};
+use tracing::warn;
+
+use crate::error::AppResult;
+
+/// How long services keep a looked-up record before reading it from the database again.
+pub const LOOKUP_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Records the in-process store holds before it starts evicting.
+pub const LOOKUP_CACHE_CAPACITY: usize = 1024;
+
+/// Prefix of every key the services write, so a shared store can hold other data too.
+const KEY_PREFIX: &str = "nomina";
+
+/// Where cached lookups live: this process, or a server shared by every instance.
+#[async_trait]
+pub trait CacheStore: Send + Sync {
+    async fn get(&self, key: &str) -> AppResult<Option<String>>;
+    async fn set(&self, key: &str, value: String, ttl: Duration) -> AppResult<()>;
+    async fn delete(&self, key: &str) -> AppResult<()>;
+}
+
+/// Cache store kept in this process, evicting the least useful entries past `capacity`.
+/// Another instance of the API writing a record does not reach it, so the TTL bounds how
+/// stale a read can be.
+pub struct MemoryCacheStore {
+    entries: Cache<String, CachedValue>,
+}
+
+#[derive(Clone)]
+struct CachedValue {
+    value: String,
+    ttl: Duration,
+}
+
+/// Expires each entry after the TTL it was stored with.
+struct StoredTtl;
+
+impl Expiry<String, CachedValue> for StoredTtl {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        value: &CachedValue,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(value.ttl)
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &String,
+        value: &CachedValue,
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        Some(value.ttl)
+    }
+}
+
+impl MemoryCacheStore {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Cache::builder()
+                .max_capacity(capacity as u64)
+                .expire_after(StoredTtl)
+                .build(),
+        }
+    }
+}
+
+impl Default for MemoryCacheStore {
+    fn default() -> Self {
+        Self::new(LOOKUP_CACHE_CAPACITY)
+    }
+}
+
+#[async_trait]
+impl CacheStore for MemoryCacheStore {
+    async fn get(&self, key: &str) -> AppResult<Option<String>> {
+        Ok(self.entries.get(key).await.map(|entry| entry.value))
+    }
+
+    async fn set(&self, key: &str, value: String, ttl: Duration) -> AppResult<()> {
+        self.entries
+            .insert(key.to_string(), CachedValue { value, ttl })
+            .await;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        self.entries.invalidate(key).await;
+        Ok(())
+    }
+}
+
+/// Typed read-through cache for hot lookups, entries expiring `ttl` after they were loaded.
+///
+/// Services invalidate an entry whenever they write the record it holds. The store failing
+/// only costs the round-trip it was meant to save: reads fall back on `load`.
+///
+/// A load that raced with a write in this process is not cached. Across instances sharing a
+/// store, a load can still finish after another instance's invalidation and cache what it
+/// read before the write; that entry then lives until `ttl` runs out.
+pub struct TtlCache<K, V> {
+    store: Arc<dyn CacheStore>,
+    namespace: &'static str,
+    ttl: Duration,
+    /// Bumped on every invalidation made through this cache, so a load that raced with a write
+    /// in this process is not kept. Other instances have their own counter.
+    generation: AtomicU64,
+    _entries: PhantomData<fn(K) -> V>,
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Display,
+    V: Serialize + DeserializeOwned,
+{
+    pub fn new(store: Arc<dyn CacheStore>, namespace: &'static str, ttl: Duration) -> Self {
+        Self {
+            store,
+            namespace,
+            ttl,
+            generation: AtomicU64::new(0),
+            _entries: PhantomData,
+        }
+    }
+
+    /// Returns the cached value for `key`, or calls `load` and caches what it finds. Misses
+    /// (`None`) are not cached, so a record created afterwards is found right away.
+    pub async fn get_or_load<F, Fut>(&self, key: K, load: F) -> AppResult<Option<V>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = AppResult<Option<V>>>,
+    {
+        let key = self.key(&key);
+        match self.store.get(&key).await {
+            Ok(Some(cached)) => match serde_json::from_str(&cached) {
+                Ok(value) => return Ok(Some(value)),
+                Err(err) => warn!("discarding unreadable cache entry `{key}`: {err}"),
+            },
+            Ok(None) => {}
+            Err(err) => warn!("cache lookup of `{key}` failed: {err}"),
+        }
+
+        let generation = self.generation.load(Ordering::SeqCst);
+        let value = load().await?;
+        if let Some(value) = &value
+            && self.generation.load(Ordering::SeqCst) == generation
+        {
+            self.store_value(&key, value).await;
+            if self.generation.load(Ordering::SeqCst) != generation {
+                self.delete(&key).await;
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Runs a write to the record under `key`, then drops its cached copy.
+    pub async fn invalidating<T>(&self, key: K, write: impl Future<Output = T>) -> T {
+        let result = write.await;
+        self.invalidate(&key).await;
+        result
+    }
+
+    pub async fn invalidate(&self, key: &K) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.delete(&self.key(key)).await;
+    }
+
+    fn key(&self, key: &K) -> String {
+        format!("{KEY_PREFIX}:{}:{key}", self.namespace)
+    }
+
+    async fn store_value(&self, key: &str, value: &V) {
+        let serialized = match serde_json::to_string(value) {
+            Ok(serialized) => serialized,
+            Err(err) => {
+                warn!("cannot cache `{key}`: {err}");
+                return;
+            }
+        };
+        if let Err(err) = self.store.set(key, serialized, self.ttl).await {
+            warn!("caching `{key}` failed: {err}");
+        }
+    }
+
+    async fn delete(&self, key: &str) {
+        if let Err(err) = self.store.delete(key).await {
+            warn!("dropping cache entry `{key}` failed: {err}");
+        }
+    }
+}
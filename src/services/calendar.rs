@@ -0,0 +1,268 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        calendar::{
+            CalendarEvent, CalendarEventKind, MAX_CALENDAR_RANGE_DAYS, OrganizationCalendar,
+        },
+        employee::{Employee, EmployeeStatus},
+        expiry::{Expiry, ExpiryKind},
+        leave::LeaveRequestStatus,
+        salary_adjustment::SalaryAdjustmentStatus,
+    },
+    error::{AppError, AppResult},
+    services::{
+        division::DivisionService, employee::EmployeeService, leave::LeaveService,
+        organization_settings::OrganizationSettingsService, payroll::PayrollService,
+        salary_adjustment::SalaryAdjustmentService,
+    },
+};
+
+/// Gathers the dated events other services keep into one organization-wide calendar.
+#[derive(Clone)]
+pub struct CalendarService {
+    settings_service: Arc<OrganizationSettingsService>,
+    payroll_service: Arc<PayrollService>,
+    division_service: Arc<DivisionService>,
+    employee_service: Arc<EmployeeService>,
+    leave_service: Arc<LeaveService>,
+    salary_adjustment_service: Arc<SalaryAdjustmentService>,
+}
+
+impl CalendarService {
+    pub fn new(
+        settings_service: Arc<OrganizationSettingsService>,
+        payroll_service: Arc<PayrollService>,
+        division_service: Arc<DivisionService>,
+        employee_service: Arc<EmployeeService>,
+        leave_service: Arc<LeaveService>,
+        salary_adjustment_service: Arc<SalaryAdjustmentService>,
+    ) -> Self {
+        Self {
+            settings_service,
+            payroll_service,
+            division_service,
+            employee_service,
+            leave_service,
+            salary_adjustment_service,
+        }
+    }
+
+    /// Events touching any day from `from` to `to`, inclusive, across the organization or only
+    /// `payroll_id`. Ordered by date, then kind.
+    pub async fn events(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<OrganizationCalendar> {
+        if to < from {
+            return Err(AppError::validation("`to` cannot be before `from`"));
+        }
+        if (to - from).num_days() >= MAX_CALENDAR_RANGE_DAYS {
+            return Err(AppError::validation(format!(
+                "the calendar covers at most {MAX_CALENDAR_RANGE_DAYS} days"
+            )));
+        }
+        let settings = self.settings_service.get(organization_id).await?;
+
+        let mut events: Vec<CalendarEvent> = settings
+            .fiscal_year_start
+            .occurrences(from, to)
+            .into_iter()
+            .map(|date| {
+                CalendarEvent::on(
+                    CalendarEventKind::FiscalYearStart,
+                    date,
+                    "Fiscal year starts",
+                )
+            })
+            .collect();
+
+        let payrolls = match payroll_id {
+            Some(payroll_id) => vec![
+                self.payroll_service
+                    .get(organization_id, payroll_id)
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::not_found(format!(
+                            "payroll `{payroll_id}` not found for organization `{organization_id}`"
+                        ))
+                    })?,
+            ],
+            None => self.payroll_service.list(organization_id).await?,
+        };
+        for payroll in &payrolls {
+            events.extend(self.payroll_events(organization_id, payroll.id).await?);
+        }
+
+        let employees = self
+            .employee_service
+            .search(organization_id, payroll_id, None)
+            .await?;
+        for employee in employees
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+        {
+            events.extend(expiry_events(employee, from, to));
+            events.extend(self.leave_events(organization_id, employee).await?);
+        }
+
+        events.retain(|event| event.overlaps(from, to));
+        events.sort_by(|a, b| {
+            a.date
+                .cmp(&b.date)
+                .then_with(|| a.kind.cmp(&b.kind))
+                .then_with(|| a.title.cmp(&b.title))
+        });
+
+        Ok(OrganizationCalendar {
+            organization_id,
+            from,
+            to,
+            events,
+        })
+    }
+
+    /// Salary adjustment effective dates and the payroll's leave blackouts.
+    async fn payroll_events(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+    ) -> AppResult<Vec<CalendarEvent>> {
+        let mut events = Vec::new();
+        for batch in self
+            .salary_adjustment_service
+            .list(organization_id, payroll_id)
+            .await?
+        {
+            let (kind, title) = match batch.status {
+                SalaryAdjustmentStatus::Pending => (
+                    CalendarEventKind::ApprovalDeadline,
+                    format!("Decide the {}% salary adjustment", batch.percentage),
+                ),
+                SalaryAdjustmentStatus::Approved | SalaryAdjustmentStatus::Applied => (
+                    CalendarEventKind::SalaryAdjustment,
+                    format!("{}% salary adjustment takes effect", batch.percentage),
+                ),
+                SalaryAdjustmentStatus::Rejected => continue,
+            };
+            events.push(CalendarEvent {
+                payroll_id: Some(payroll_id),
+                source_id: Some(batch.id),
+                ..CalendarEvent::on(kind, batch.effective_date, title)
+            });
+        }
+
+        for division in self
+            .division_service
+            .list(organization_id, payroll_id)
+            .await?
+        {
+            for blackout in self
+                .leave_service
+                .list_blackouts(organization_id, payroll_id, division.id)
+                .await?
+            {
+                let title = match &blackout.reason {
+                    Some(reason) => format!("Leave blackout in {}: {reason}", division.name),
+                    None => format!("Leave blackout in {}", division.name),
+                };
+                events.push(CalendarEvent {
+                    end_date: Some(blackout.end_date),
+                    payroll_id: Some(payroll_id),
+                    division_id: Some(division.id),
+                    source_id: Some(blackout.id),
+                    ..CalendarEvent::on(
+                        CalendarEventKind::LeaveBlackout,
+                        blackout.start_date,
+                        title,
+                    )
+                });
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Approved leave, and pending requests as deadlines on the day the leave would start.
+    async fn leave_events(
+        &self,
+        organization_id: Uuid,
+        employee: &Employee,
+    ) -> AppResult<Vec<CalendarEvent>> {
+        let name = format!("{} {}", employee.first_name, employee.last_name);
+        let requests = self
+            .leave_service
+            .list(
+                organization_id,
+                employee.payroll_id,
+                employee.division_id,
+                employee.id,
+            )
+            .await?;
+
+        Ok(requests
+            .into_iter()
+            .filter_map(|request| {
+                let leave_type = request.leave_type.as_str();
+                let event = match request.status {
+                    LeaveRequestStatus::Pending => CalendarEvent::on(
+                        CalendarEventKind::ApprovalDeadline,
+                        request.start_date,
+                        format!("Decide {name}'s {leave_type} leave request"),
+                    ),
+                    LeaveRequestStatus::Approved => CalendarEvent {
+                        end_date: Some(request.end_date),
+                        ..CalendarEvent::on(
+                            CalendarEventKind::Leave,
+                            request.start_date,
+                            format!("{name} on {leave_type} leave"),
+                        )
+                    },
+                    LeaveRequestStatus::Rejected => return None,
+                };
+                Some(CalendarEvent {
+                    payroll_id: Some(employee.payroll_id),
+                    division_id: Some(employee.division_id),
+                    employee_id: Some(employee.id),
+                    source_id: Some(request.id),
+                    ..event
+                })
+            })
+            .collect())
+    }
+}
+
+fn expiry_events(employee: &Employee, from: NaiveDate, to: NaiveDate) -> Vec<CalendarEvent> {
+    Expiry::due(employee, from, to)
+        .into_iter()
+        .map(|expiry| {
+            let name = format!("{} {}", expiry.first_name, expiry.last_name);
+            let (kind, title) = match expiry.kind {
+                ExpiryKind::ContractEnd => (
+                    CalendarEventKind::ContractEnd,
+                    format!("{name}'s contract ends"),
+                ),
+                ExpiryKind::ProbationEnd => (
+                    CalendarEventKind::ProbationEnd,
+                    format!("{name}'s probation ends"),
+                ),
+                ExpiryKind::WorkPermit => (
+                    CalendarEventKind::WorkPermitExpiry,
+                    format!("{name}'s work permit expires"),
+                ),
+            };
+            CalendarEvent {
+                payroll_id: Some(expiry.payroll_id),
+                division_id: Some(expiry.division_id),
+                employee_id: Some(expiry.employee_id),
+                ..CalendarEvent::on(kind, expiry.date, title)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,255 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::{
+    domain::custom_field::{CustomFieldDefinition, CustomFieldType},
+    error::{AppError, AppResult},
+    services::organization::OrganizationService,
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateCustomFieldParams {
+    pub name: String,
+    pub field_type: CustomFieldType,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateCustomFieldParams {
+    pub name: Option<String>,
+    pub field_type: Option<CustomFieldType>,
+    pub required: Option<bool>,
+}
+
+#[async_trait]
+pub trait CustomFieldRepository: Send + Sync {
+    async fn insert(&self, definition: CustomFieldDefinition) -> AppResult<CustomFieldDefinition>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<CustomFieldDefinition>>;
+    async fn fetch_by_organization(
+        &self,
+        organization_id: Uuid,
+    ) -> AppResult<Vec<CustomFieldDefinition>>;
+    async fn update(
+        &self,
+        id: Uuid,
+        updates: UpdateCustomFieldParams,
+    ) -> AppResult<Option<CustomFieldDefinition>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct CustomFieldService {
+    repository: Arc<dyn CustomFieldRepository>,
+    organization_service: Arc<OrganizationService>,
+}
+
+impl CustomFieldService {
+    pub fn new(
+        repository: Arc<dyn CustomFieldRepository>,
+        organization_service: Arc<OrganizationService>,
+    ) -> Self {
+        Self {
+            repository,
+            organization_service,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        params: CreateCustomFieldParams,
+    ) -> AppResult<CustomFieldDefinition> {
+        let name = Self::normalize_name(&params.name)?;
+        self.ensure_organization_exists(organization_id).await?;
+        self.ensure_name_available(organization_id, &name, None)
+            .await?;
+
+        let definition = CustomFieldDefinition::new(
+            Uuid::new_v4(),
+            name,
+            params.field_type,
+            params.required,
+            organization_id,
+        );
+        self.repository.insert(definition).await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        field_id: Uuid,
+    ) -> AppResult<Option<CustomFieldDefinition>> {
+        let definition = self.repository.fetch(field_id).await?;
+        Ok(definition.filter(|definition| definition.organization_id == organization_id))
+    }
+
+    pub async fn list(&self, organization_id: Uuid) -> AppResult<Vec<CustomFieldDefinition>> {
+        self.ensure_organization_exists(organization_id).await?;
+        let mut definitions = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?;
+        definitions.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(definitions)
+    }
+
+    /// Existing employee values are not migrated when a definition is renamed or retyped.
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        field_id: Uuid,
+        params: UpdateCustomFieldParams,
+    ) -> AppResult<Option<CustomFieldDefinition>> {
+        if params.name.is_none() && params.field_type.is_none() && params.required.is_none() {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        if self.get(organization_id, field_id).await?.is_none() {
+            return Ok(None);
+        }
+
+        let name = params
+            .name
+            .as_deref()
+            .map(Self::normalize_name)
+            .transpose()?;
+        if let Some(name) = name.as_deref() {
+            self.ensure_name_available(organization_id, name, Some(field_id))
+                .await?;
+        }
+
+        self.repository
+            .update(
+                field_id,
+                UpdateCustomFieldParams {
+                    name,
+                    field_type: params.field_type,
+                    required: params.required,
+                },
+            )
+            .await
+    }
+
+    pub async fn delete(&self, organization_id: Uuid, field_id: Uuid) -> AppResult<bool> {
+        if self.get(organization_id, field_id).await?.is_none() {
+            return Ok(false);
+        }
+
+        self.repository.delete(field_id).await
+    }
+
+    /// Checks employee custom field values against the organization's definitions.
+    ///
+    /// Null values are treated as absent and dropped from the returned map.
+    pub async fn validate_values(
+        &self,
+        organization_id: Uuid,
+        values: BTreeMap<String, JsonValue>,
+    ) -> AppResult<BTreeMap<String, JsonValue>> {
+        let definitions = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?;
+
+        let mut validated = BTreeMap::new();
+        for (name, value) in values {
+            if value.is_null() {
+                continue;
+            }
+
+            let definition = definitions
+                .iter()
+                .find(|definition| definition.name == name)
+                .ok_or_else(|| AppError::validation(format!("unknown custom field `{name}`")))?;
+
+            let matches_type = match definition.field_type {
+                CustomFieldType::Text => value.is_string(),
+                CustomFieldType::Number => value.is_number(),
+                CustomFieldType::Boolean => value.is_boolean(),
+                CustomFieldType::Date => value
+                    .as_str()
+                    .is_some_and(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok()),
+            };
+            if !matches_type {
+                return Err(AppError::validation(format!(
+                    "custom field `{name}` must be a {} value",
+                    Self::type_label(definition.field_type)
+                )));
+            }
+
+            validated.insert(name, value);
+        }
+
+        if let Some(missing) = definitions
+            .iter()
+            .find(|definition| definition.required && !validated.contains_key(&definition.name))
+        {
+            return Err(AppError::validation(format!(
+                "custom field `{}` is required",
+                missing.name
+            )));
+        }
+
+        Ok(validated)
+    }
+
+    async fn ensure_name_available(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+        except: Option<Uuid>,
+    ) -> AppResult<()> {
+        let taken = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?
+            .into_iter()
+            .any(|definition| Some(definition.id) != except && definition.name == name);
+
+        if taken {
+            Err(AppError::conflict(format!(
+                "custom field `{name}` already exists"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn ensure_organization_exists(&self, organization_id: Uuid) -> AppResult<()> {
+        let exists = self
+            .organization_service
+            .get(organization_id)
+            .await?
+            .is_some();
+
+        if exists {
+            Ok(())
+        } else {
+            Err(AppError::not_found(format!(
+                "organization `{organization_id}` not found"
+            )))
+        }
+    }
+
+    fn normalize_name(value: &str) -> AppResult<String> {
+        let name = value.trim();
+        if name.is_empty() {
+            return Err(AppError::validation("custom field name cannot be empty"));
+        }
+
+        Ok(name.to_string())
+    }
+
+    fn type_label(field_type: CustomFieldType) -> &'static str {
+        match field_type {
+            CustomFieldType::Text => "text",
+            CustomFieldType::Number => "number",
+            CustomFieldType::Boolean => "boolean",
+            CustomFieldType::Date => "date (YYYY-MM-DD)",
+        }
+    }
+}
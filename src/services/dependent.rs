@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::dependent::Dependent,
+    error::{AppError, AppResult},
+    services::employee::EmployeeService,
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateDependentParams {
+    pub name: String,
+    pub relationship: String,
+    pub date_of_birth: NaiveDate,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateDependentParams {
+    pub name: Option<String>,
+    pub relationship: Option<String>,
+    pub date_of_birth: Option<NaiveDate>,
+}
+
+#[async_trait]
+pub trait DependentRepository: Send + Sync {
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        relationship: String,
+        date_of_birth: NaiveDate,
+        employee_id: Uuid,
+    ) -> AppResult<Dependent>;
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Dependent>>;
+
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<Dependent>>;
+
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        relationship: Option<String>,
+        date_of_birth: Option<NaiveDate>,
+    ) -> AppResult<Option<Dependent>>;
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct DependentService {
+    repository: Arc<dyn DependentRepository>,
+    employee_service: Arc<EmployeeService>,
+}
+
+impl DependentService {
+    pub fn new(
+        repository: Arc<dyn DependentRepository>,
+        employee_service: Arc<EmployeeService>,
+    ) -> Self {
+        Self {
+            repository,
+            employee_service,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        params: CreateDependentParams,
+    ) -> AppResult<Dependent> {
+        let name = Self::normalize_field(&params.name, "dependent name")?;
+        let relationship = Self::normalize_field(&params.relationship, "relationship")?;
+        let date_of_birth = Self::validate_date_of_birth(params.date_of_birth)?;
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+
+        let id = Uuid::new_v4();
+        self.repository
+            .insert(id, name, relationship, date_of_birth, employee_id)
+            .await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        dependent_id: Uuid,
+    ) -> AppResult<Option<Dependent>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let dependent = self.repository.fetch(dependent_id).await?;
+        Ok(dependent.filter(|dependent| dependent.employee_id == employee_id))
+    }
+
+    pub async fn list(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<Dependent>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let mut dependents = self.repository.fetch_by_employee(employee_id).await?;
+        dependents.sort_by(|a, b| {
+            a.date_of_birth
+                .cmp(&b.date_of_birth)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        Ok(dependents)
+    }
+
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        dependent_id: Uuid,
+        params: UpdateDependentParams,
+    ) -> AppResult<Option<Dependent>> {
+        if params.name.is_none() && params.relationship.is_none() && params.date_of_birth.is_none()
+        {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        if self
+            .get(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                dependent_id,
+            )
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let name = params
+            .name
+            .as_deref()
+            .map(|value| Self::normalize_field(value, "dependent name"))
+            .transpose()?;
+        let relationship = params
+            .relationship
+            .as_deref()
+            .map(|value| Self::normalize_field(value, "relationship"))
+            .transpose()?;
+        let date_of_birth = params
+            .date_of_birth
+            .map(Self::validate_date_of_birth)
+            .transpose()?;
+
+        self.repository
+            .update(dependent_id, name, relationship, date_of_birth)
+            .await
+    }
+
+    pub async fn delete(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        dependent_id: Uuid,
+    ) -> AppResult<bool> {
+        if self
+            .get(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                dependent_id,
+            )
+            .await?
+            .is_none()
+        {
+            return Ok(false);
+        }
+
+        self.repository.delete(dependent_id).await
+    }
+
+    async fn ensure_employee_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<()> {
+        match self
+            .employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        {
+            Some(_) => Ok(()),
+            None => Err(AppError::not_found(format!(
+                "employee `{employee_id}` not found for division `{division_id}` in payroll `{payroll_id}`"
+            ))),
+        }
+    }
+
+    fn normalize_field(value: &str, field: &str) -> AppResult<String> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return Err(AppError::validation(format!("{field} cannot be empty")));
+        }
+
+        Ok(trimmed.to_string())
+    }
+
+    fn validate_date_of_birth(value: NaiveDate) -> AppResult<NaiveDate> {
+        if value > Utc::now().date_naive() {
+            return Err(AppError::validation(
+                "dependent date of birth cannot be in the future",
+            ));
+        }
+
+        Ok(value)
+    }
+}
@@ -1,12 +1,15 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::{
-    domain::division::Division,
+    domain::{division::Division, employee::EmployeeStatus},
     error::{AppError, AppResult},
-    services::payroll::PayrollService,
+    services::{
+        employee::EmployeeRepository, organization::OrganizationService, payroll::PayrollService,
+        position::PositionRepository,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -15,6 +18,8 @@ pub struct CreateDivisionParams {
     pub description: String,
     pub budget_code: String,
     pub parent_division_id: Option<Uuid>,
+    pub headcount_budget: Option<u32>,
+    pub region: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -23,10 +28,55 @@ pub struct UpdateDivisionParams {
     pub description: Option<String>,
     pub budget_code: Option<String>,
     pub parent_division_id: Option<Option<Uuid>>,
+    pub headcount_budget: Option<Option<u32>>,
+    pub region: Option<Option<String>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MoveDivisionParams {
+    /// New parent within the destination payroll; `None` makes the division a root.
+    pub parent_division_id: Option<Uuid>,
+    /// Destination payroll of the same organization; the current one when `None`.
+    pub payroll_id: Option<Uuid>,
+}
+
+/// What happens to a division's employees, positions and child divisions when it is deleted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DivisionDeletePolicy {
+    /// Refuse while anything still depends on the division.
+    #[default]
+    Refuse,
+    /// Move employees, positions and child divisions to another division of the payroll.
+    ReassignTo(Uuid),
+    /// Delete child divisions, positions and employees along with the division.
+    Cascade,
+}
+
+/// A division moved with its descendants, applied by [`DivisionRepository::relocate`] in one
+/// transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DivisionRelocation {
+    pub division_id: Uuid,
+    /// Every division below `division_id`; they keep their parents and follow it to `payroll_id`.
+    pub descendants: Vec<Uuid>,
+    pub payroll_id: Uuid,
+    pub parent_division_id: Option<Uuid>,
+}
+
+/// Everything deleting a division changes, applied by [`DivisionRepository::remove`] in one
+/// transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DivisionRemoval {
+    pub division_id: Uuid,
+    /// Every division below `division_id`, deleted with it under
+    /// [`DivisionDeletePolicy::Cascade`].
+    pub descendants: Vec<Uuid>,
+    pub policy: DivisionDeletePolicy,
 }
 
 #[async_trait]
 pub trait DivisionRepository: Send + Sync {
+    #[allow(clippy::too_many_arguments)]
     async fn insert(
         &self,
         id: Uuid,
@@ -35,12 +85,15 @@ pub trait DivisionRepository: Send + Sync {
         budget_code: String,
         payroll_id: Uuid,
         parent_division_id: Option<Uuid>,
+        headcount_budget: Option<u32>,
+        region: Option<String>,
     ) -> AppResult<Division>;
 
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Division>>;
 
     async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<Division>>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn update(
         &self,
         id: Uuid,
@@ -48,25 +101,53 @@ pub trait DivisionRepository: Send + Sync {
         description: Option<String>,
         budget_code: Option<String>,
         parent_division_id: Option<Option<Uuid>>,
+        headcount_budget: Option<Option<u32>>,
+        region: Option<Option<String>>,
+    ) -> AppResult<Option<Division>>;
+
+    async fn set_manager(
+        &self,
+        id: Uuid,
+        manager_employee_id: Option<Uuid>,
     ) -> AppResult<Option<Division>>;
 
-    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+    /// Moves the division under its new parent and the whole subtree to its payroll, all or
+    /// nothing. The subtree is checked again in the same transaction, so a division added under
+    /// it or, across payrolls, an employee, position or manager added to it fails the move
+    /// instead of splitting it. `None` when the division is gone; otherwise the moved
+    /// divisions, the given one first.
+    async fn relocate(&self, relocation: DivisionRelocation) -> AppResult<Option<Vec<Division>>>;
+
+    /// Deletes the division and moves or deletes its employees, positions and child divisions
+    /// as the policy says, all or nothing. The checks those records depend on run again in the
+    /// same transaction, so a concurrent write fails the removal instead of leaving it half
+    /// done. `None` when the division is gone; otherwise the employees deleted with it.
+    async fn remove(&self, removal: DivisionRemoval) -> AppResult<Option<Vec<Uuid>>>;
 }
 
 #[derive(Clone)]
 pub struct DivisionService {
     repository: Arc<dyn DivisionRepository>,
     payroll_service: Arc<PayrollService>,
+    organization_service: Arc<OrganizationService>,
+    employee_repository: Arc<dyn EmployeeRepository>,
+    position_repository: Arc<dyn PositionRepository>,
 }
 
 impl DivisionService {
     pub fn new(
         repository: Arc<dyn DivisionRepository>,
         payroll_service: Arc<PayrollService>,
+        organization_service: Arc<OrganizationService>,
+        employee_repository: Arc<dyn EmployeeRepository>,
+        position_repository: Arc<dyn PositionRepository>,
     ) -> Self {
         Self {
             repository,
             payroll_service,
+            organization_service,
+            employee_repository,
+            position_repository,
         }
     }
 
@@ -79,8 +160,15 @@ impl DivisionService {
         let name = Self::normalize_field(&params.name, "division name")?;
         let description = Self::normalize_field(&params.description, "division description")?;
         let budget_code = Self::normalize_field(&params.budget_code, "division budget code")?;
+        let region = params
+            .region
+            .as_deref()
+            .map(|value| Self::normalize_field(value, "division region"))
+            .transpose()?;
         self.ensure_payroll_accessible(organization_id, payroll_id)
             .await?;
+        self.ensure_budget_code_format(organization_id, &budget_code)
+            .await?;
         let parent_division_id = self
             .validate_parent(params.parent_division_id, payroll_id, None)
             .await?;
@@ -94,6 +182,8 @@ impl DivisionService {
                 budget_code,
                 payroll_id,
                 parent_division_id,
+                params.headcount_budget,
+                region,
             )
             .await
     }
@@ -118,6 +208,42 @@ impl DivisionService {
         Ok(divisions)
     }
 
+    /// Divisions across the organization, or only `payroll_id`'s when given.
+    pub async fn search(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+    ) -> AppResult<Vec<Division>> {
+        if let Some(payroll_id) = payroll_id {
+            return self.list(organization_id, payroll_id).await;
+        }
+
+        let mut divisions = Vec::new();
+        for payroll in self.payroll_service.list(organization_id).await? {
+            divisions.extend(self.repository.fetch_by_payroll(payroll.id).await?);
+        }
+        divisions.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(divisions)
+    }
+
+    /// Looks a division up by id alone, as long as its payroll belongs to the organization.
+    pub async fn locate(
+        &self,
+        organization_id: Uuid,
+        division_id: Uuid,
+    ) -> AppResult<Option<Division>> {
+        let Some(division) = self.repository.fetch(division_id).await? else {
+            return Ok(None);
+        };
+        let in_organization = self
+            .payroll_service
+            .get(organization_id, division.payroll_id)
+            .await?
+            .is_some();
+
+        Ok(in_organization.then_some(division))
+    }
+
     pub async fn update(
         &self,
         organization_id: Uuid,
@@ -129,6 +255,8 @@ impl DivisionService {
             && params.description.is_none()
             && params.budget_code.is_none()
             && params.parent_division_id.is_none()
+            && params.headcount_budget.is_none()
+            && params.region.is_none()
         {
             return Err(AppError::validation("no fields supplied for update"));
         }
@@ -164,27 +292,225 @@ impl DivisionService {
             .as_deref()
             .map(|value| Self::normalize_field(value, "division budget code"))
             .transpose()?;
+        if let Some(budget_code) = &budget_code {
+            self.ensure_budget_code_format(organization_id, budget_code)
+                .await?;
+        }
+        let region = params
+            .region
+            .map(|region| {
+                region
+                    .as_deref()
+                    .map(|value| Self::normalize_field(value, "division region"))
+                    .transpose()
+            })
+            .transpose()?;
 
         self.repository
-            .update(division_id, name, description, budget_code, parent_update)
+            .update(
+                division_id,
+                name,
+                description,
+                budget_code,
+                parent_update,
+                params.headcount_budget,
+                region,
+            )
             .await
     }
 
-    pub async fn delete(
+    /// Reparents a division together with all of its descendants, optionally into another
+    /// payroll of the organization. Everything is validated first, then the whole subtree moves
+    /// in one step and the moved divisions are returned, the given one first.
+    ///
+    /// Employees and positions are bound to jobs of their payroll, so a subtree only changes
+    /// payroll once it holds neither and none of its divisions has a manager.
+    pub async fn move_subtree(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        params: MoveDivisionParams,
+    ) -> AppResult<Option<Vec<Division>>> {
+        let Some(root) = self.get(organization_id, payroll_id, division_id).await? else {
+            return Ok(None);
+        };
+        let target_payroll_id = params.payroll_id.unwrap_or(payroll_id);
+        if target_payroll_id != payroll_id {
+            self.ensure_payroll_accessible(organization_id, target_payroll_id)
+                .await?;
+        }
+
+        let subtree = self.collect_subtree(root).await?;
+        if let Some(parent_id) = params.parent_division_id {
+            if subtree.iter().any(|division| division.id == parent_id) {
+                return Err(AppError::validation(
+                    "a division cannot be moved under itself or one of its descendants",
+                ));
+            }
+            let parent = self.repository.fetch(parent_id).await?.ok_or_else(|| {
+                AppError::not_found(format!("parent division `{parent_id}` not found"))
+            })?;
+            if parent.payroll_id != target_payroll_id {
+                return Err(AppError::validation(
+                    "parent division must belong to the destination payroll",
+                ));
+            }
+        }
+
+        if target_payroll_id != payroll_id {
+            self.ensure_movable_across_payrolls(&subtree).await?;
+        }
+
+        self.repository
+            .relocate(DivisionRelocation {
+                division_id,
+                descendants: subtree.iter().skip(1).map(|division| division.id).collect(),
+                payroll_id: target_payroll_id,
+                parent_division_id: params.parent_division_id,
+            })
+            .await
+    }
+
+    /// Fails with a conflict reporting utilization when `in_use` seats already fill the
+    /// division's headcount budget. Divisions without a budget are never limited.
+    pub fn ensure_headcount_available(division: &Division, in_use: usize) -> AppResult<()> {
+        match division.headcount_budget {
+            Some(budget) if in_use >= budget as usize => Err(AppError::conflict(format!(
+                "division `{}` has no budgeted headcount available ({in_use} of {budget} in use)",
+                division.id
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Records the division manager without checking the employee; use
+    /// `EmployeeService::assign_division_manager` to validate the assignment.
+    pub async fn set_manager(
         &self,
         organization_id: Uuid,
         payroll_id: Uuid,
         division_id: Uuid,
-    ) -> AppResult<bool> {
+        manager_employee_id: Option<Uuid>,
+    ) -> AppResult<Option<Division>> {
         if self
             .get(organization_id, payroll_id, division_id)
             .await?
             .is_none()
         {
-            return Ok(false);
+            return Ok(None);
         }
 
-        self.repository.delete(division_id).await
+        self.repository
+            .set_manager(division_id, manager_employee_id)
+            .await
+    }
+
+    /// The division followed by its descendants, parents before children.
+    pub async fn subtree(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+    ) -> AppResult<Option<Vec<Division>>> {
+        match self.get(organization_id, payroll_id, division_id).await? {
+            Some(division) => Ok(Some(self.collect_subtree(division).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Deletes the division once `policy` has dealt with whatever depends on it, returning the
+    /// employees deleted with it, or `None` when there is no such division. Every check runs
+    /// before anything is moved or removed, and the changes are applied in one transaction.
+    pub async fn delete(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        policy: DivisionDeletePolicy,
+    ) -> AppResult<Option<Vec<Uuid>>> {
+        let Some(subtree) = self
+            .subtree(organization_id, payroll_id, division_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let employees = self
+            .employee_repository
+            .fetch_by_division(division_id)
+            .await?;
+        let positions = self
+            .position_repository
+            .fetch_by_division(division_id)
+            .await?;
+        let children: Vec<&Division> = subtree
+            .iter()
+            .filter(|division| division.parent_division_id == Some(division_id))
+            .collect();
+
+        match policy {
+            DivisionDeletePolicy::Refuse => {
+                if !employees.is_empty() || !positions.is_empty() || !children.is_empty() {
+                    return Err(AppError::conflict(format!(
+                        "division `{division_id}` still has {} employee(s), {} position(s) and \
+                         {} child division(s); reassign or cascade them",
+                        employees.len(),
+                        positions.len(),
+                        children.len()
+                    )));
+                }
+            }
+            DivisionDeletePolicy::ReassignTo(target_id) => {
+                if subtree.iter().any(|division| division.id == target_id) {
+                    return Err(AppError::validation(
+                        "reassign_to must name a division outside the one being deleted",
+                    ));
+                }
+                let target = self
+                    .get(organization_id, payroll_id, target_id)
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::not_found(format!(
+                            "division `{target_id}` not found for payroll `{payroll_id}`"
+                        ))
+                    })?;
+                let moving = employees
+                    .iter()
+                    .filter(|employee| employee.status != EmployeeStatus::Terminated)
+                    .count();
+                if let Some(budget) = target.headcount_budget
+                    && moving > 0
+                {
+                    let in_use = self
+                        .employee_repository
+                        .fetch_by_division(target_id)
+                        .await?
+                        .iter()
+                        .filter(|employee| employee.status != EmployeeStatus::Terminated)
+                        .count();
+                    if in_use + moving > budget as usize {
+                        return Err(AppError::conflict(format!(
+                            "division `{target_id}` has {} of {budget} budgeted seats free, \
+                             {moving} needed",
+                            (budget as usize).saturating_sub(in_use)
+                        )));
+                    }
+                }
+            }
+            DivisionDeletePolicy::Cascade => {}
+        }
+
+        self.repository
+            .remove(DivisionRemoval {
+                division_id,
+                descendants: subtree
+                    .iter()
+                    .map(|division| division.id)
+                    .filter(|id| *id != division_id)
+                    .collect(),
+                policy,
+            })
+            .await
     }
 
     async fn ensure_payroll_accessible(
@@ -197,6 +523,24 @@ impl DivisionService {
             .await
     }
 
+    async fn ensure_budget_code_format(
+        &self,
+        organization_id: Uuid,
+        budget_code: &str,
+    ) -> AppResult<()> {
+        let Some(organization) = self.organization_service.get(organization_id).await? else {
+            return Ok(());
+        };
+        if organization.accepts_budget_code(budget_code) {
+            return Ok(());
+        }
+
+        Err(AppError::validation(format!(
+            "division budget code `{budget_code}` does not match the organization's format `{}`",
+            organization.budget_code_pattern.unwrap_or_default()
+        )))
+    }
+
     async fn validate_parent(
         &self,
         parent_division_id: Option<Uuid>,
@@ -218,12 +562,95 @@ impl DivisionService {
                 ));
             }
 
+            if let Some(division_id) = division_id {
+                self.ensure_not_descendant(division_id, parent).await?;
+            }
+
             Ok(Some(parent_id))
         } else {
             Ok(None)
         }
     }
 
+    /// `root` followed by its descendants, parents before children.
+    async fn collect_subtree(&self, root: Division) -> AppResult<Vec<Division>> {
+        let divisions = self.repository.fetch_by_payroll(root.payroll_id).await?;
+        let mut subtree = vec![root];
+        let mut index = 0;
+        while index < subtree.len() {
+            let parent_id = subtree[index].id;
+            let children: Vec<Division> = divisions
+                .iter()
+                .filter(|division| {
+                    division.parent_division_id == Some(parent_id)
+                        && !subtree.iter().any(|known| known.id == division.id)
+                })
+                .cloned()
+                .collect();
+            subtree.extend(children);
+            index += 1;
+        }
+
+        Ok(subtree)
+    }
+
+    async fn ensure_movable_across_payrolls(&self, subtree: &[Division]) -> AppResult<()> {
+        let mut employees = 0;
+        let mut positions = 0;
+        for division in subtree {
+            if division.manager_employee_id.is_some() {
+                return Err(AppError::conflict(format!(
+                    "division `{}` has a manager; clear it before moving to another payroll",
+                    division.id
+                )));
+            }
+            employees += self
+                .employee_repository
+                .fetch_by_division(division.id)
+                .await?
+                .len();
+            positions += self
+                .position_repository
+                .fetch_by_division(division.id)
+                .await?
+                .len();
+        }
+
+        if employees > 0 || positions > 0 {
+            return Err(AppError::conflict(format!(
+                "the divisions still hold {employees} employee(s) and {positions} position(s) \
+                 tied to jobs of their payroll; move them before changing payroll"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Walks up from `parent` and fails when `division_id` is one of its ancestors, since
+    /// reparenting onto a descendant would close a cycle.
+    async fn ensure_not_descendant(&self, division_id: Uuid, parent: Division) -> AppResult<()> {
+        let mut visited = HashSet::from([parent.id]);
+        let mut next = parent.parent_division_id;
+        while let Some(ancestor_id) = next {
+            if ancestor_id == division_id {
+                return Err(AppError::validation(
+                    "parent division cannot be one of the division's descendants",
+                ));
+            }
+            if !visited.insert(ancestor_id) {
+                break;
+            }
+
+            next = self
+                .repository
+                .fetch(ancestor_id)
+                .await?
+                .and_then(|ancestor| ancestor.parent_division_id);
+        }
+
+        Ok(())
+    }
+
     fn normalize_field(value: &str, field: &str) -> AppResult<String> {
         let trimmed = value.trim();
         if trimmed.is_empty() {
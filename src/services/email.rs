@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::{domain::email::EmailMessage, error::AppResult};
+
+/// Outbound email transport.
+#[async_trait]
+pub trait EmailSender: Send + Sync {
+    async fn send(&self, message: EmailMessage) -> AppResult<()>;
+}
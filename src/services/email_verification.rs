@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{
+    domain::{email::EmailMessage, email_verification::EmailVerification, employee::Employee},
+    error::{AppError, AppResult},
+    services::{email::EmailSender, employee::EmployeeService},
+};
+
+/// Minutes a verification code stays valid.
+pub const VERIFICATION_CODE_TTL_MINUTES: i64 = 15;
+/// Wrong guesses allowed before a code is discarded.
+pub const VERIFICATION_MAX_ATTEMPTS: u32 = 5;
+
+#[async_trait]
+pub trait EmailVerificationRepository: Send + Sync {
+    /// Stores the verification, replacing any earlier one for the same employee.
+    async fn upsert(&self, verification: EmailVerification) -> AppResult<EmailVerification>;
+    async fn fetch(&self, employee_id: Uuid) -> AppResult<Option<EmailVerification>>;
+    async fn delete(&self, employee_id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct EmailVerificationService {
+    repository: Arc<dyn EmailVerificationRepository>,
+    employee_service: Arc<EmployeeService>,
+    sender: Arc<dyn EmailSender>,
+}
+
+impl EmailVerificationService {
+    pub fn new(
+        repository: Arc<dyn EmailVerificationRepository>,
+        employee_service: Arc<EmployeeService>,
+        sender: Arc<dyn EmailSender>,
+    ) -> Self {
+        Self {
+            repository,
+            employee_service,
+            sender,
+        }
+    }
+
+    /// Emails a fresh code to the employee, invalidating any code sent before.
+    pub async fn send_code(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<EmailVerification> {
+        let employee = self
+            .ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let Some(email) = employee.email else {
+            return Err(AppError::validation(
+                "employee has no email address to verify",
+            ));
+        };
+        if employee.email_verified_at.is_some() {
+            return Err(AppError::conflict(format!(
+                "email `{email}` is already verified"
+            )));
+        }
+
+        let code = generate_code();
+        let now = Utc::now();
+        let verification = self
+            .repository
+            .upsert(EmailVerification {
+                employee_id,
+                email: email.clone(),
+                code_hash: hash_code(employee_id, &code),
+                expires_at: now + Duration::minutes(VERIFICATION_CODE_TTL_MINUTES),
+                attempts_left: VERIFICATION_MAX_ATTEMPTS,
+                created_at: now,
+            })
+            .await?;
+
+        self.sender
+            .send(EmailMessage::new(
+                email,
+                "Confirm your email address",
+                format!(
+                    "Hello {},\n\nYour verification code is {code}. It expires in {VERIFICATION_CODE_TTL_MINUTES} minutes.\n",
+                    employee.first_name
+                ),
+            ))
+            .await?;
+
+        Ok(verification)
+    }
+
+    /// Checks the code and marks the employee's email as verified.
+    pub async fn confirm(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        code: &str,
+    ) -> AppResult<Employee> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let Some(mut verification) = self.repository.fetch(employee_id).await? else {
+            return Err(AppError::validation(
+                "no verification code is pending; request a new one",
+            ));
+        };
+        if verification.is_expired(Utc::now()) {
+            self.repository.delete(employee_id).await?;
+            return Err(AppError::validation(
+                "verification code has expired; request a new one",
+            ));
+        }
+
+        if verification.code_hash != hash_code(employee_id, code.trim()) {
+            verification.attempts_left = verification.attempts_left.saturating_sub(1);
+            if verification.attempts_left == 0 {
+                self.repository.delete(employee_id).await?;
+                return Err(AppError::validation(
+                    "verification code is incorrect and no attempts are left; request a new one",
+                ));
+            }
+            let attempts_left = verification.attempts_left;
+            self.repository.upsert(verification).await?;
+            return Err(AppError::validation(format!(
+                "verification code is incorrect; {attempts_left} attempt(s) left"
+            )));
+        }
+
+        self.repository.delete(employee_id).await?;
+        self.employee_service
+            .mark_email_verified(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                &verification.email,
+            )
+            .await?
+            .ok_or_else(|| employee_not_found(employee_id, division_id, payroll_id))
+    }
+
+    async fn ensure_employee_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Employee> {
+        self.employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .ok_or_else(|| employee_not_found(employee_id, division_id, payroll_id))
+    }
+}
+
+fn employee_not_found(employee_id: Uuid, division_id: Uuid, payroll_id: Uuid) -> AppError {
+    AppError::not_found(format!(
+        "employee `{employee_id}` not found for division `{division_id}` in payroll `{payroll_id}`"
+    ))
+}
+
+/// Six random digits, zero-padded.
+fn generate_code() -> String {
+    let bytes = Uuid::new_v4().into_bytes();
+    let value = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    format!("{:06}", value % 1_000_000)
+}
+
+/// Salted with the employee id so equal codes for different employees hash differently.
+fn hash_code(employee_id: Uuid, code: &str) -> String {
+    format!(
+        "{:x}",
+        Sha256::digest(format!("{employee_id}:{code}").as_bytes())
+    )
+}
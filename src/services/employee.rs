@@ -1,24 +1,64 @@
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
 
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value as JsonValue;
+use tracing::warn;
 use uuid::Uuid;
 
 use crate::{
-    domain::employee::Employee,
+    domain::{
+        bank::Bank,
+        blob::Blob,
+        division::Division,
+        employee::{
+            AccountVerification, AccountVerificationMethod, AccountVerificationStatus, Employee,
+            EmployeeStatus, PaymentAmount, PaymentInstruction, UnverifiedAccount,
+            UnverifiedAccountReport, WorkPermit,
+        },
+        employment_history::{
+            DivisionTransfer, EmploymentEvent, EmploymentEventKind, JobChange, StatusChange,
+            TerminationTerms,
+        },
+        job::{HeadcountPolicy, Job, JobHeadcount, PayPeriod, PeriodPay},
+        pagination::{Page, PageRequest},
+        work_schedule::WorkExpectation,
+    },
     error::{AppError, AppResult},
     services::{
-        bank::BankService, division::DivisionService, job::JobService, payroll::PayrollService,
+        bank::BankService,
+        blob::BlobStore,
+        custom_field::CustomFieldService,
+        division::{DivisionDeletePolicy, DivisionService},
+        employment_history::EmploymentHistoryRepository,
+        job::JobService,
+        organization_settings::OrganizationSettingsService,
+        payroll::PayrollService,
+        work_schedule::WorkScheduleService,
     },
 };
 
+/// Largest accepted employee photo, in bytes.
+pub const MAX_PHOTO_BYTES: usize = 1024 * 1024;
+
+/// Media types accepted for employee photos.
+pub const PHOTO_CONTENT_TYPES: [&str; 3] = ["image/jpeg", "image/png", "image/webp"];
+
+/// Longest accepted work permit number.
+pub const MAX_WORK_PERMIT_NUMBER_CHARS: usize = 30;
+
 #[derive(Debug, Clone)]
 pub struct CreateEmployeeParams {
     pub id_number: String,
+    pub external_id: Option<String>,
     pub last_name: String,
     pub first_name: String,
     pub address: String,
     pub phone: String,
+    pub email: Option<String>,
     pub place_of_birth: String,
     pub date_of_birth: NaiveDate,
     pub nationality: String,
@@ -26,21 +66,31 @@ pub struct CreateEmployeeParams {
     pub gender: String,
     pub hire_date: NaiveDate,
     pub termination_date: Option<NaiveDate>,
+    pub probation_end_date: Option<NaiveDate>,
+    pub contract_end_date: Option<NaiveDate>,
+    pub work_permit: Option<WorkPermit>,
     pub clasification: String,
     pub job_id: Uuid,
-    pub bank_id: Uuid,
-    pub bank_account: String,
-    pub status: String,
+    pub payment_instructions: Vec<PaymentInstruction>,
+    pub status: EmployeeStatus,
     pub hours: i32,
+    pub schedule_id: Option<Uuid>,
+    pub supervisor_id: Option<Uuid>,
+    pub custom_fields: BTreeMap<String, JsonValue>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct UpdateEmployeeParams {
     pub id_number: Option<String>,
+    pub external_id: Option<String>,
     pub last_name: Option<String>,
     pub first_name: Option<String>,
     pub address: Option<String>,
     pub phone: Option<String>,
+    /// `Some(None)` clears the email; any change resets its verification.
+    pub email: Option<Option<String>>,
+    /// Set by the verification flow rather than by callers.
+    pub email_verified_at: Option<Option<DateTime<Utc>>>,
     pub place_of_birth: Option<String>,
     pub date_of_birth: Option<NaiveDate>,
     pub nationality: Option<String>,
@@ -48,12 +98,68 @@ pub struct UpdateEmployeeParams {
     pub gender: Option<String>,
     pub hire_date: Option<NaiveDate>,
     pub termination_date: Option<Option<NaiveDate>>,
+    pub probation_end_date: Option<Option<NaiveDate>>,
+    pub contract_end_date: Option<Option<NaiveDate>>,
+    pub work_permit: Option<Option<WorkPermit>>,
     pub clasification: Option<String>,
     pub job_id: Option<Uuid>,
-    pub bank_id: Option<Uuid>,
-    pub bank_account: Option<String>,
-    pub status: Option<String>,
+    /// Replaces the employee's whole list of payment instructions.
+    pub payment_instructions: Option<Vec<PaymentInstruction>>,
+    pub status: Option<EmployeeStatus>,
     pub hours: Option<i32>,
+    pub schedule_id: Option<Option<Uuid>>,
+    pub supervisor_id: Option<Option<Uuid>>,
+    /// Replaces the employee's whole custom field map.
+    pub custom_fields: Option<BTreeMap<String, JsonValue>>,
+}
+
+/// Outcome of checking that a payment account belongs to the employee.
+#[derive(Debug, Clone)]
+pub struct RecordAccountVerificationParams {
+    pub method: AccountVerificationMethod,
+    pub status: AccountVerificationStatus,
+    pub reference: Option<String>,
+}
+
+/// Narrows an organization-wide employee listing; unset fields match everyone.
+#[derive(Debug, Clone, Default)]
+pub struct EmployeeListFilter {
+    pub payroll_id: Option<Uuid>,
+    pub division_id: Option<Uuid>,
+    pub job_id: Option<Uuid>,
+    pub status: Option<EmployeeStatus>,
+    /// Case-insensitive match on name, id number, external id or email.
+    pub query: Option<String>,
+}
+
+/// What [`EmployeeRepository::fetch_page`] narrows on; unset fields match everyone.
+#[derive(Debug, Clone, Default)]
+pub struct EmployeePageFilter {
+    pub division_ids: Vec<Uuid>,
+    pub job_id: Option<Uuid>,
+    pub status: Option<EmployeeStatus>,
+    /// Lowercase text contained in the name, id number, external id or email.
+    pub query: Option<String>,
+}
+
+impl EmployeePageFilter {
+    pub fn matches(&self, employee: &Employee) -> bool {
+        self.division_ids.contains(&employee.division_id)
+            && self.job_id.is_none_or(|id| id == employee.job_id)
+            && self.status.is_none_or(|status| status == employee.status)
+            && self.query.as_deref().is_none_or(|query| {
+                [
+                    Some(format!("{} {}", employee.first_name, employee.last_name)),
+                    Some(format!("{} {}", employee.last_name, employee.first_name)),
+                    Some(employee.id_number.clone()),
+                    employee.external_id.clone(),
+                    employee.email.clone(),
+                ]
+                .into_iter()
+                .flatten()
+                .any(|value| value.to_lowercase().contains(query))
+            })
+    }
 }
 
 #[async_trait]
@@ -63,10 +169,12 @@ pub trait EmployeeRepository: Send + Sync {
         &self,
         id: Uuid,
         id_number: String,
+        external_id: Option<String>,
         last_name: String,
         first_name: String,
         address: String,
         phone: String,
+        email: Option<String>,
         place_of_birth: String,
         date_of_birth: NaiveDate,
         nationality: String,
@@ -74,22 +182,76 @@ pub trait EmployeeRepository: Send + Sync {
         gender: String,
         hire_date: NaiveDate,
         termination_date: Option<NaiveDate>,
+        probation_end_date: Option<NaiveDate>,
+        contract_end_date: Option<NaiveDate>,
+        work_permit: Option<WorkPermit>,
         clasification: String,
         job_id: Uuid,
-        bank_id: Uuid,
-        bank_account: String,
-        status: String,
+        payment_instructions: Vec<PaymentInstruction>,
+        status: EmployeeStatus,
         hours: i32,
+        schedule_id: Option<Uuid>,
         division_id: Uuid,
         payroll_id: Uuid,
+        supervisor_id: Option<Uuid>,
+        custom_fields: BTreeMap<String, JsonValue>,
     ) -> AppResult<Employee>;
 
+    /// Stores all the employees or none of them.
+    async fn insert_many(&self, employees: Vec<Employee>) -> AppResult<Vec<Employee>>;
+
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Employee>>;
 
+    /// Like `fetch`, but never served by a read replica, since it guards the reporting chain.
+    async fn fetch_latest(&self, id: Uuid) -> AppResult<Option<Employee>>;
+
     async fn fetch_by_division(&self, division_id: Uuid) -> AppResult<Vec<Employee>>;
 
+    /// Employees of any of the divisions, read in one query.
+    async fn fetch_by_divisions(&self, division_ids: Vec<Uuid>) -> AppResult<Vec<Employee>>;
+
+    async fn fetch_by_supervisor(&self, supervisor_id: Uuid) -> AppResult<Vec<Employee>>;
+
+    async fn fetch_by_job(&self, job_id: Uuid) -> AppResult<Vec<Employee>>;
+
+    /// How many employees hold the job without being terminated, the filled seats of its
+    /// headcount budget. Never served by a read replica, since it guards that budget.
+    async fn count_active_by_job(&self, job_id: Uuid) -> AppResult<u32>;
+
+    /// Employees of the payrolls whose email is `email`. Never served by a read replica, since
+    /// it guards email uniqueness.
+    async fn fetch_by_email(&self, email: &str, payroll_ids: Vec<Uuid>)
+    -> AppResult<Vec<Employee>>;
+
+    /// Employees with at least one payment instruction at the bank.
+    async fn fetch_by_bank(&self, bank_id: Uuid) -> AppResult<Vec<Employee>>;
+
+    /// One page of the job's employees other than terminated ones, by last then first name.
+    async fn fetch_page_by_job(&self, job_id: Uuid, page: PageRequest)
+    -> AppResult<Page<Employee>>;
+
+    /// One page of the employees matching the filter, by last then first name.
+    async fn fetch_page(
+        &self,
+        filter: EmployeePageFilter,
+        page: PageRequest,
+    ) -> AppResult<Page<Employee>>;
+
     async fn update(&self, id: Uuid, updates: UpdateEmployeeParams) -> AppResult<Option<Employee>>;
 
+    async fn set_photo_url(
+        &self,
+        id: Uuid,
+        photo_url: Option<String>,
+    ) -> AppResult<Option<Employee>>;
+
+    /// Marks the employee terminated as of the event's effective date, vacates the positions
+    /// they hold and records the event, all in one step. `None` when the employee is gone; a
+    /// conflict when they were already terminated.
+    async fn terminate(&self, id: Uuid, event: EmploymentEvent) -> AppResult<Option<Employee>>;
+
+    /// Deletes the employee together with everything stored under it, clearing links to it from
+    /// other employees, divisions and positions, all in one step.
     async fn delete(&self, id: Uuid) -> AppResult<bool>;
 }
 
@@ -100,15 +262,26 @@ pub struct EmployeeService {
     payroll_service: Arc<PayrollService>,
     job_service: Arc<JobService>,
     bank_service: Arc<BankService>,
+    blob_store: Arc<dyn BlobStore>,
+    custom_field_service: Arc<CustomFieldService>,
+    history: Arc<dyn EmploymentHistoryRepository>,
+    work_schedule_service: Arc<WorkScheduleService>,
+    settings_service: Arc<OrganizationSettingsService>,
 }
 
 impl EmployeeService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         repository: Arc<dyn EmployeeRepository>,
         division_service: Arc<DivisionService>,
         payroll_service: Arc<PayrollService>,
         job_service: Arc<JobService>,
         bank_service: Arc<BankService>,
+        blob_store: Arc<dyn BlobStore>,
+        custom_field_service: Arc<CustomFieldService>,
+        history: Arc<dyn EmploymentHistoryRepository>,
+        work_schedule_service: Arc<WorkScheduleService>,
+        settings_service: Arc<OrganizationSettingsService>,
     ) -> Self {
         Self {
             repository,
@@ -116,6 +289,11 @@ impl EmployeeService {
             payroll_service,
             job_service,
             bank_service,
+            blob_store,
+            custom_field_service,
+            history,
+            work_schedule_service,
+            settings_service,
         }
     }
 
@@ -125,6 +303,56 @@ impl EmployeeService {
         payroll_id: Uuid,
         division_id: Uuid,
         params: CreateEmployeeParams,
+    ) -> AppResult<Employee> {
+        let employee = self
+            .prepare_create(organization_id, payroll_id, division_id, params, &[])
+            .await?;
+
+        self.repository
+            .insert(
+                employee.id,
+                employee.id_number,
+                employee.external_id,
+                employee.last_name,
+                employee.first_name,
+                employee.address,
+                employee.phone,
+                employee.email,
+                employee.place_of_birth,
+                employee.date_of_birth,
+                employee.nationality,
+                employee.marital_status,
+                employee.gender,
+                employee.hire_date,
+                employee.termination_date,
+                employee.probation_end_date,
+                employee.contract_end_date,
+                employee.work_permit,
+                employee.clasification,
+                employee.job_id,
+                employee.payment_instructions,
+                employee.status,
+                employee.hours,
+                employee.schedule_id,
+                employee.division_id,
+                employee.payroll_id,
+                employee.supervisor_id,
+                employee.custom_fields,
+            )
+            .await
+    }
+
+    /// Validates a new employee without storing it, so a batch can be written with
+    /// [`Self::insert_prepared`]. `pending` holds the batch's earlier employees, which count
+    /// against email uniqueness and headcount budgets and may be named as supervisors as if
+    /// they were already stored.
+    pub(crate) async fn prepare_create(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        params: CreateEmployeeParams,
+        pending: &[Employee],
     ) -> AppResult<Employee> {
         let division = self
             .division_service
@@ -136,53 +364,121 @@ impl EmployeeService {
                 ))
             })?;
 
-        self.ensure_job_belongs(organization_id, payroll_id, params.job_id)
+        let job = self
+            .ensure_job_belongs(organization_id, payroll_id, params.job_id)
             .await?;
-        self.ensure_bank_belongs(organization_id, params.bank_id)
+        let payment_instructions = self
+            .validate_payment_instructions(organization_id, params.payment_instructions, &[])
             .await?;
+        if let Some(schedule_id) = params.schedule_id {
+            self.ensure_schedule_belongs(organization_id, schedule_id)
+                .await?;
+        }
 
         let id_number = Self::normalize_field(&params.id_number, "id number")?;
+        let external_id = params
+            .external_id
+            .as_deref()
+            .map(|value| Self::normalize_field(value, "external id"))
+            .transpose()?;
         let last_name = Self::normalize_field(&params.last_name, "last name")?;
         let first_name = Self::normalize_field(&params.first_name, "first name")?;
         let address = Self::normalize_field(&params.address, "address")?;
         let phone = Self::normalize_field(&params.phone, "phone")?;
+        let email = params.email.as_deref().map(normalize_email).transpose()?;
+        if let Some(email) = &email {
+            self.ensure_unique_email(organization_id, email, None)
+                .await?;
+            if pending
+                .iter()
+                .any(|employee| employee.email.as_deref() == Some(email.as_str()))
+            {
+                return Err(AppError::conflict(format!(
+                    "email `{email}` is already used by another employee in this batch"
+                )));
+            }
+        }
         let place_of_birth = Self::normalize_field(&params.place_of_birth, "place of birth")?;
         let nationality = Self::normalize_field(&params.nationality, "nationality")?;
         let marital_status = Self::normalize_field(&params.marital_status, "marital status")?;
         let gender = Self::normalize_field(&params.gender, "gender")?;
         let clasification = Self::normalize_field(&params.clasification, "clasification")?;
-        let bank_account = Self::normalize_field(&params.bank_account, "bank account")?;
-        let status = Self::normalize_field(&params.status, "status")?;
         let hours = Self::validate_hours(params.hours)?;
         let hire_date = params.hire_date;
         let termination_date = Self::validate_termination_date(hire_date, params.termination_date)?;
+        let probation_end_date =
+            Self::validate_end_date(hire_date, params.probation_end_date, "probation end date")?;
+        let contract_end_date =
+            Self::validate_end_date(hire_date, params.contract_end_date, "contract end date")?;
+        let work_permit = params.work_permit.map(normalize_work_permit).transpose()?;
+        let custom_fields = self
+            .custom_field_service
+            .validate_values(organization_id, params.custom_fields)
+            .await?;
 
         let id = Uuid::new_v4();
-        self.repository
-            .insert(
-                id,
-                id_number,
-                last_name,
-                first_name,
-                address,
-                phone,
-                place_of_birth,
-                params.date_of_birth,
-                nationality,
-                marital_status,
-                gender,
-                hire_date,
-                termination_date,
-                clasification,
-                params.job_id,
-                params.bank_id,
-                bank_account,
-                status,
-                hours,
-                division.id,
-                payroll_id,
-            )
-            .await
+        if let Some(supervisor_id) = params.supervisor_id {
+            self.validate_supervisor(id, payroll_id, supervisor_id, pending)
+                .await?;
+        }
+        if params.status != EmployeeStatus::Terminated {
+            let active = pending
+                .iter()
+                .filter(|employee| employee.status != EmployeeStatus::Terminated);
+            let in_division = active
+                .clone()
+                .filter(|employee| employee.division_id == division.id)
+                .count();
+            let in_job = active.filter(|employee| employee.job_id == job.id).count();
+            self.ensure_headcount_available(&division, in_division)
+                .await?;
+            self.check_job_headcount(&job, in_job).await?;
+        }
+
+        Ok(Employee::new(
+            id,
+            id_number,
+            external_id,
+            last_name,
+            first_name,
+            address,
+            phone,
+            email,
+            None,
+            place_of_birth,
+            params.date_of_birth,
+            nationality,
+            marital_status,
+            gender,
+            hire_date,
+            termination_date,
+            probation_end_date,
+            contract_end_date,
+            work_permit,
+            clasification,
+            params.job_id,
+            payment_instructions,
+            params.status,
+            hours,
+            params.schedule_id,
+            division.id,
+            payroll_id,
+            params.supervisor_id,
+            None,
+            custom_fields,
+        ))
+    }
+
+    /// Stores employees checked by [`Self::prepare_create`] in a single batched write.
+    pub(crate) async fn insert_prepared(
+        &self,
+        employees: Vec<Employee>,
+    ) -> AppResult<Vec<Employee>> {
+        if employees.is_empty() {
+            return Ok(employees);
+        }
+
+        self.repository.insert_many(employees).await
     }
 
     pub async fn get(
@@ -217,6 +513,169 @@ impl EmployeeService {
         Ok(employees)
     }
 
+    /// Employees of the division and of every division below it, ordered like [`Self::list`].
+    pub async fn list_with_descendants(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+    ) -> AppResult<Vec<Employee>> {
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+        let divisions = self
+            .division_service
+            .subtree(organization_id, payroll_id, division_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "division `{division_id}` not found for payroll `{payroll_id}` in organization `{organization_id}`"
+                ))
+            })?;
+
+        let mut employees = self
+            .repository
+            .fetch_by_divisions(divisions.into_iter().map(|division| division.id).collect())
+            .await?;
+        employees.sort_by(|a, b| {
+            a.last_name
+                .cmp(&b.last_name)
+                .then_with(|| a.first_name.cmp(&b.first_name))
+        });
+        Ok(employees)
+    }
+
+    /// Employees across the organization, narrowed to a payroll and/or division when given.
+    pub async fn search(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+        division_id: Option<Uuid>,
+    ) -> AppResult<Vec<Employee>> {
+        let divisions = self
+            .divisions_in_scope(organization_id, payroll_id, division_id)
+            .await?;
+
+        let mut employees = self
+            .repository
+            .fetch_by_divisions(divisions.into_iter().map(|division| division.id).collect())
+            .await?;
+        employees.sort_by(|a, b| {
+            a.last_name
+                .cmp(&b.last_name)
+                .then_with(|| a.first_name.cmp(&b.first_name))
+        });
+        Ok(employees)
+    }
+
+    async fn divisions_in_scope(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+        division_id: Option<Uuid>,
+    ) -> AppResult<Vec<Division>> {
+        match division_id {
+            Some(division_id) => {
+                let division = self
+                    .division_service
+                    .locate(organization_id, division_id)
+                    .await?
+                    .filter(|division| payroll_id.is_none_or(|id| id == division.payroll_id))
+                    .ok_or_else(|| {
+                        AppError::not_found(format!(
+                            "division `{division_id}` not found in organization `{organization_id}`"
+                        ))
+                    })?;
+                Ok(vec![division])
+            }
+            None => {
+                self.division_service
+                    .search(organization_id, payroll_id)
+                    .await
+            }
+        }
+    }
+
+    /// One page of the organization's workforce, ordered by last then first name.
+    pub async fn list_organization(
+        &self,
+        organization_id: Uuid,
+        filter: EmployeeListFilter,
+        page: PageRequest,
+    ) -> AppResult<Page<Employee>> {
+        let divisions = self
+            .divisions_in_scope(organization_id, filter.payroll_id, filter.division_id)
+            .await?;
+        let filter = EmployeePageFilter {
+            division_ids: divisions.into_iter().map(|division| division.id).collect(),
+            job_id: filter.job_id,
+            status: filter.status,
+            query: filter
+                .query
+                .map(|query| query.trim().to_lowercase())
+                .filter(|query| !query.is_empty()),
+        };
+
+        self.repository.fetch_page(filter, page).await
+    }
+
+    /// Budgeted versus filled seats for every job of the payroll, ordered by job title.
+    pub async fn job_headcount(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+    ) -> AppResult<Vec<JobHeadcount>> {
+        let jobs = self.job_service.list(organization_id, payroll_id).await?;
+
+        let mut headcounts = Vec::with_capacity(jobs.len());
+        for job in &jobs {
+            let filled = self.repository.count_active_by_job(job.id).await?;
+            headcounts.push(JobHeadcount::new(job, filled));
+        }
+        Ok(headcounts)
+    }
+
+    /// One page of the employees currently holding the job, or `None` if the job is unknown.
+    pub async fn list_by_job(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        job_id: Uuid,
+        page: PageRequest,
+    ) -> AppResult<Option<Page<Employee>>> {
+        if self
+            .job_service
+            .get(organization_id, payroll_id, job_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        self.repository
+            .fetch_page_by_job(job_id, page)
+            .await
+            .map(Some)
+    }
+
+    /// Looks an employee up by id alone, as long as their payroll belongs to the organization.
+    pub async fn locate(
+        &self,
+        organization_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Option<Employee>> {
+        let Some(employee) = self.repository.fetch(employee_id).await? else {
+            return Ok(None);
+        };
+        let in_organization = self
+            .payroll_service
+            .get(organization_id, employee.payroll_id)
+            .await?
+            .is_some();
+
+        Ok(in_organization.then_some(employee))
+    }
+
     pub async fn update(
         &self,
         organization_id: Uuid,
@@ -226,10 +685,12 @@ impl EmployeeService {
         params: UpdateEmployeeParams,
     ) -> AppResult<Option<Employee>> {
         if params.id_number.is_none()
+            && params.external_id.is_none()
             && params.last_name.is_none()
             && params.first_name.is_none()
             && params.address.is_none()
             && params.phone.is_none()
+            && params.email.is_none()
             && params.place_of_birth.is_none()
             && params.date_of_birth.is_none()
             && params.nationality.is_none()
@@ -237,12 +698,17 @@ impl EmployeeService {
             && params.gender.is_none()
             && params.hire_date.is_none()
             && params.termination_date.is_none()
+            && params.probation_end_date.is_none()
+            && params.contract_end_date.is_none()
+            && params.work_permit.is_none()
             && params.clasification.is_none()
             && params.job_id.is_none()
-            && params.bank_id.is_none()
-            && params.bank_account.is_none()
+            && params.payment_instructions.is_none()
             && params.status.is_none()
             && params.hours.is_none()
+            && params.schedule_id.is_none()
+            && params.supervisor_id.is_none()
+            && params.custom_fields.is_none()
         {
             return Err(AppError::validation("no fields supplied for update"));
         }
@@ -255,20 +721,120 @@ impl EmployeeService {
             None => return Ok(None),
         };
 
-        if let Some(job_id) = params.job_id {
-            self.ensure_job_belongs(organization_id, payroll_id, job_id)
-                .await?;
-        }
+        let job_change = match params.job_id {
+            Some(job_id) => {
+                let job = self
+                    .ensure_job_belongs(organization_id, payroll_id, job_id)
+                    .await?;
+                if job_id == employee.job_id {
+                    None
+                } else {
+                    if employee.status != EmployeeStatus::Terminated {
+                        self.check_job_headcount(&job, 0).await?;
+                    }
+                    let from_salary = self
+                        .job_service
+                        .get(organization_id, payroll_id, employee.job_id)
+                        .await?
+                        .map_or(0.0, |previous| previous.salary);
+                    Some(JobChange {
+                        from_job_id: employee.job_id,
+                        to_job_id: job_id,
+                        from_salary,
+                        to_salary: job.salary,
+                    })
+                }
+            }
+            None => None,
+        };
 
-        if let Some(bank_id) = params.bank_id {
-            self.ensure_bank_belongs(organization_id, bank_id).await?;
+        if let Some(Some(schedule_id)) = params.schedule_id {
+            self.ensure_schedule_belongs(organization_id, schedule_id)
+                .await?;
         }
+        let payment_instructions = match params.payment_instructions {
+            Some(instructions) => Some(
+                self.validate_payment_instructions(
+                    organization_id,
+                    instructions,
+                    &employee.payment_instructions,
+                )
+                .await?,
+            ),
+            None => None,
+        };
 
         let hire_date = params.hire_date.unwrap_or(employee.hire_date);
         let termination_date = match params.termination_date {
             Some(value) => Some(Self::validate_termination_date(hire_date, value)?),
             None => None,
         };
+        let probation_end_date = match params.probation_end_date {
+            Some(value) => Some(Self::validate_end_date(
+                hire_date,
+                value,
+                "probation end date",
+            )?),
+            None => None,
+        };
+        let contract_end_date = match params.contract_end_date {
+            Some(value) => Some(Self::validate_end_date(
+                hire_date,
+                value,
+                "contract end date",
+            )?),
+            None => None,
+        };
+        let work_permit = match params.work_permit {
+            Some(value) => Some(value.map(normalize_work_permit).transpose()?),
+            None => None,
+        };
+        if let Some(Some(supervisor_id)) = params.supervisor_id {
+            self.validate_supervisor(employee_id, payroll_id, supervisor_id, &[])
+                .await?;
+        }
+        // Terminating also vacates positions and records the event, which only `terminate`
+        // does, so an update may not reach `Terminated` on its own.
+        if let Some(status) = params.status
+            && (!employee.status.can_transition_to(status)
+                || (status == EmployeeStatus::Terminated && employee.status != status))
+        {
+            return Err(AppError::validation(format!(
+                "employee status cannot change from {} to {status}{}",
+                employee.status,
+                if employee.status == EmployeeStatus::Terminated {
+                    "; rehire the employee instead"
+                } else if status == EmployeeStatus::Terminated {
+                    "; terminate the employee instead"
+                } else {
+                    ""
+                }
+            )));
+        }
+
+        let custom_fields = match params.custom_fields {
+            Some(values) => Some(
+                self.custom_field_service
+                    .validate_values(organization_id, values)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        let email = match params.email {
+            Some(Some(value)) => {
+                let email = normalize_email(&value)?;
+                self.ensure_unique_email(organization_id, &email, Some(employee_id))
+                    .await?;
+                Some(Some(email))
+            }
+            Some(None) => Some(None),
+            None => None,
+        };
+        let email_verified_at = match &email {
+            Some(email) if *email != employee.email => Some(None),
+            _ => params.email_verified_at,
+        };
 
         let updates = UpdateEmployeeParams {
             id_number: params
@@ -276,6 +842,11 @@ impl EmployeeService {
                 .as_deref()
                 .map(|value| Self::normalize_field(value, "id number"))
                 .transpose()?,
+            external_id: params
+                .external_id
+                .as_deref()
+                .map(|value| Self::normalize_field(value, "external id"))
+                .transpose()?,
             last_name: params
                 .last_name
                 .as_deref()
@@ -296,6 +867,8 @@ impl EmployeeService {
                 .as_deref()
                 .map(|value| Self::normalize_field(value, "phone"))
                 .transpose()?,
+            email,
+            email_verified_at,
             place_of_birth: params
                 .place_of_birth
                 .as_deref()
@@ -319,96 +892,835 @@ impl EmployeeService {
                 .transpose()?,
             hire_date: params.hire_date,
             termination_date,
+            probation_end_date,
+            contract_end_date,
+            work_permit,
             clasification: params
                 .clasification
                 .as_deref()
                 .map(|value| Self::normalize_field(value, "clasification"))
                 .transpose()?,
             job_id: params.job_id,
-            bank_id: params.bank_id,
-            bank_account: params
-                .bank_account
-                .as_deref()
-                .map(|value| Self::normalize_field(value, "bank account"))
-                .transpose()?,
-            status: params
-                .status
-                .as_deref()
-                .map(|value| Self::normalize_field(value, "status"))
-                .transpose()?,
+            payment_instructions,
+            status: params.status,
             hours: params.hours.map(Self::validate_hours).transpose()?,
+            schedule_id: params.schedule_id,
+            supervisor_id: params.supervisor_id,
+            custom_fields,
         };
 
-        self.repository.update(employee_id, updates).await
+        let updated = self.repository.update(employee_id, updates).await?;
+        if updated.is_some() {
+            let today = Utc::now().date_naive();
+            if let Some(status) = params.status.filter(|status| *status != employee.status) {
+                let mut event = EmploymentEvent::new(
+                    Uuid::new_v4(),
+                    employee_id,
+                    EmploymentEventKind::StatusChange,
+                    today,
+                    Utc::now(),
+                );
+                event.status_change = Some(StatusChange {
+                    from: employee.status,
+                    to: status,
+                });
+                self.history.insert(event).await?;
+            }
+            if let Some(job_change) = job_change {
+                let mut event = EmploymentEvent::new(
+                    Uuid::new_v4(),
+                    employee_id,
+                    EmploymentEventKind::JobChange,
+                    today,
+                    Utc::now(),
+                );
+                event.job_change = Some(job_change);
+                self.history.insert(event).await?;
+            }
+        }
+
+        Ok(updated)
     }
 
-    pub async fn delete(
+    /// Sets `Terminated` and the termination date, vacates the employee's positions and records
+    /// the terms, all in one step.
+    pub async fn terminate(
         &self,
         organization_id: Uuid,
         payroll_id: Uuid,
         division_id: Uuid,
         employee_id: Uuid,
-    ) -> AppResult<bool> {
-        if self
+        effective_date: NaiveDate,
+        terms: TerminationTerms,
+    ) -> AppResult<Option<Employee>> {
+        let employee = match self
             .get(organization_id, payroll_id, division_id, employee_id)
             .await?
-            .is_none()
         {
-            return Ok(false);
+            Some(employee) => employee,
+            None => return Ok(None),
+        };
+
+        if employee.status == EmployeeStatus::Terminated {
+            return Err(AppError::conflict(format!(
+                "employee `{employee_id}` is already terminated"
+            )));
         }
+        Self::validate_termination_date(employee.hire_date, Some(effective_date))?;
 
-        self.repository.delete(employee_id).await
+        let mut event = EmploymentEvent::new(
+            Uuid::new_v4(),
+            employee_id,
+            EmploymentEventKind::Termination,
+            effective_date,
+            Utc::now(),
+        );
+        event.termination = Some(terms);
+        self.repository.terminate(employee_id, event).await
     }
 
-    async fn ensure_division_accessible(
+    /// Brings a terminated employee back as `Onboarding` with a new hire date.
+    pub async fn rehire(
         &self,
         organization_id: Uuid,
         payroll_id: Uuid,
         division_id: Uuid,
-    ) -> AppResult<()> {
-        self.payroll_service
-            .ensure_belongs_to_organization(organization_id, payroll_id)
-            .await?;
-        match self
+        employee_id: Uuid,
+        hire_date: NaiveDate,
+    ) -> AppResult<Option<Employee>> {
+        let employee = match self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        {
+            Some(employee) => employee,
+            None => return Ok(None),
+        };
+
+        if employee.status != EmployeeStatus::Terminated {
+            return Err(AppError::conflict(format!(
+                "employee `{employee_id}` is {} and cannot be rehired",
+                employee.status
+            )));
+        }
+        if let Some(division) = self
             .division_service
             .get(organization_id, payroll_id, division_id)
             .await?
         {
-            Some(_) => Ok(()),
-            None => Err(AppError::not_found(format!(
-                "division `{division_id}` not found for payroll `{payroll_id}` in organization `{organization_id}`"
-            ))),
+            self.ensure_headcount_available(&division, 0).await?;
         }
-    }
-
-    async fn ensure_job_belongs(
-        &self,
-        organization_id: Uuid,
-        payroll_id: Uuid,
-        job_id: Uuid,
-    ) -> AppResult<()> {
-        match self
+        if let Some(job) = self
             .job_service
-            .get(organization_id, payroll_id, job_id)
+            .get(organization_id, payroll_id, employee.job_id)
             .await?
         {
-            Some(job) if job.payroll_id == payroll_id => Ok(()),
-            _ => Err(AppError::not_found(format!(
-                "job `{job_id}` not found for payroll `{payroll_id}`"
-            ))),
+            self.check_job_headcount(&job, 0).await?;
         }
-    }
-
-    async fn ensure_bank_belongs(&self, organization_id: Uuid, bank_id: Uuid) -> AppResult<()> {
-        match self.bank_service.get(organization_id, bank_id).await? {
-            Some(bank) if bank.organization_id == organization_id => Ok(()),
-            _ => Err(AppError::not_found(format!(
-                "bank `{bank_id}` not found for organization `{organization_id}`"
-            ))),
+        if let Some(termination_date) = employee.termination_date
+            && hire_date < termination_date
+        {
+            return Err(AppError::validation(
+                "rehire date cannot be before the termination date",
+            ));
         }
+
+        let updates = UpdateEmployeeParams {
+            hire_date: Some(hire_date),
+            termination_date: Some(None),
+            status: Some(EmployeeStatus::Onboarding),
+            ..UpdateEmployeeParams::default()
+        };
+        self.repository.update(employee_id, updates).await
     }
 
-    fn normalize_field(value: &str, field: &str) -> AppResult<String> {
+    pub async fn delete(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<bool> {
+        if self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .is_none()
+        {
+            return Ok(false);
+        }
+
+        self.repository.delete(employee_id).await
+    }
+
+    /// Employees whose supervisor is `employee_id`, across every division of the payroll.
+    pub async fn direct_reports(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Option<Vec<Employee>>> {
+        if self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let mut reports = self.repository.fetch_by_supervisor(employee_id).await?;
+        reports.sort_by(|a, b| {
+            a.last_name
+                .cmp(&b.last_name)
+                .then_with(|| a.first_name.cmp(&b.first_name))
+        });
+        Ok(Some(reports))
+    }
+
+    /// Expected hours and overtime thresholds, from the assigned schedule or else `hours`
+    /// over the organization's working week.
+    pub async fn work_expectation(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Option<WorkExpectation>> {
+        let Some(employee) = self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let schedule = match employee.schedule_id {
+            Some(schedule_id) => {
+                self.work_schedule_service
+                    .get(organization_id, schedule_id)
+                    .await?
+            }
+            None => None,
+        };
+        Ok(Some(match schedule {
+            Some(schedule) => WorkExpectation::from_schedule(&schedule),
+            None => {
+                let settings = self.settings_service.current(organization_id).await?;
+                WorkExpectation::from_weekly_hours(
+                    employee.hours,
+                    usize::from(settings.working_days_per_week),
+                )
+            }
+        }))
+    }
+
+    /// The employee's job salary converted to one `period`'s gross amount, rounded and, for
+    /// jobs without a currency, priced in the organization's default currency.
+    pub async fn period_pay(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        period: PayPeriod,
+    ) -> AppResult<Option<PeriodPay>> {
+        let Some(employee) = self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let Some(expectation) = self
+            .work_expectation(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let job = self
+            .job_service
+            .get(organization_id, payroll_id, employee.job_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "job `{}` not found for payroll `{payroll_id}`",
+                    employee.job_id
+                ))
+            })?;
+
+        let settings = self.settings_service.current(organization_id).await?;
+        let employer = self
+            .payroll_service
+            .employer(organization_id, payroll_id)
+            .await?;
+
+        Ok(Some(PeriodPay {
+            employer,
+            employee_id,
+            job_id: job.id,
+            pay_basis: job.pay_basis,
+            rate: job.salary,
+            amount: job.period_amount(period, &expectation, settings.rounding),
+            currency: job.currency.or(settings.default_currency),
+            period,
+            weekly_hours: expectation.weekly_hours,
+        }))
+    }
+
+    /// Makes an employee of the same payroll the division's manager, or clears it with `None`.
+    pub async fn assign_division_manager(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        manager_employee_id: Option<Uuid>,
+    ) -> AppResult<Option<Division>> {
+        if let Some(manager_employee_id) = manager_employee_id {
+            let belongs = self
+                .repository
+                .fetch(manager_employee_id)
+                .await?
+                .is_some_and(|employee| employee.payroll_id == payroll_id);
+            if !belongs {
+                return Err(AppError::not_found(format!(
+                    "employee `{manager_employee_id}` not found for payroll `{payroll_id}`"
+                )));
+            }
+        }
+
+        self.division_service
+            .set_manager(
+                organization_id,
+                payroll_id,
+                division_id,
+                manager_employee_id,
+            )
+            .await
+    }
+
+    /// Deletes a division under `policy`. With [`DivisionDeletePolicy::Cascade`] the employees of
+    /// the division and its descendants go with it, in the same transaction; their photos are
+    /// removed once it has committed. With [`DivisionDeletePolicy::ReassignTo`] each moved employee
+    /// gets a transfer event.
+    pub async fn delete_division(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        policy: DivisionDeletePolicy,
+    ) -> AppResult<bool> {
+        let moving = match policy {
+            DivisionDeletePolicy::ReassignTo(_) => {
+                self.repository.fetch_by_division(division_id).await?
+            }
+            DivisionDeletePolicy::Refuse | DivisionDeletePolicy::Cascade => Vec::new(),
+        };
+        if self
+            .division_service
+            .delete(organization_id, payroll_id, division_id, policy)
+            .await?
+            .is_none()
+        {
+            return Ok(false);
+        }
+
+        if let DivisionDeletePolicy::ReassignTo(target_id) = policy {
+            let today = Utc::now().date_naive();
+            for employee in moving {
+                let mut event = EmploymentEvent::new(
+                    Uuid::new_v4(),
+                    employee.id,
+                    EmploymentEventKind::Transfer,
+                    today,
+                    Utc::now(),
+                );
+                event.transfer = Some(DivisionTransfer {
+                    from_division_id: division_id,
+                    to_division_id: target_id,
+                });
+                self.history.insert(event).await?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    pub async fn upload_photo(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        photo: Blob,
+    ) -> AppResult<Option<Employee>> {
+        let photo = Self::validate_photo(photo)?;
+        if self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        self.blob_store
+            .put(&Employee::photo_key(employee_id), photo)
+            .await?;
+        let photo_url = format!(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/photo"
+        );
+        self.repository
+            .set_photo_url(employee_id, Some(photo_url))
+            .await
+    }
+
+    pub async fn photo(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Option<Blob>> {
+        if self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        self.blob_store.get(&Employee::photo_key(employee_id)).await
+    }
+
+    fn validate_photo(photo: Blob) -> AppResult<Blob> {
+        if !PHOTO_CONTENT_TYPES.contains(&photo.content_type.as_str()) {
+            return Err(AppError::validation(format!(
+                "photo content type must be one of {}",
+                PHOTO_CONTENT_TYPES.join(", ")
+            )));
+        }
+
+        if photo.data.is_empty() {
+            return Err(AppError::validation("photo cannot be empty"));
+        }
+
+        if photo.data.len() > MAX_PHOTO_BYTES {
+            return Err(AppError::validation(format!(
+                "photo cannot exceed {MAX_PHOTO_BYTES} bytes"
+            )));
+        }
+
+        Ok(photo)
+    }
+
+    /// Records that the employee proved they own `email`, as long as it is still their address.
+    pub async fn mark_email_verified(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        email: &str,
+    ) -> AppResult<Option<Employee>> {
+        let Some(employee) = self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        if employee.email.as_deref() != Some(email) {
+            return Err(AppError::conflict(
+                "email changed after the verification code was sent",
+            ));
+        }
+
+        self.repository
+            .update(
+                employee_id,
+                UpdateEmployeeParams {
+                    email_verified_at: Some(Some(Utc::now())),
+                    ..Default::default()
+                },
+            )
+            .await
+    }
+
+    /// Records a micro-deposit or document check on the account paid at `priority`. A
+    /// micro-deposit can only be confirmed after it was sent, and a document that verifies
+    /// the account needs a reference to it.
+    pub async fn record_account_verification(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        priority: u32,
+        params: RecordAccountVerificationParams,
+    ) -> AppResult<Option<PaymentInstruction>> {
+        let Some(employee) = self
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let mut instructions = employee.payment_instructions;
+        let instruction = instructions
+            .iter_mut()
+            .find(|instruction| instruction.priority == priority)
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "payment instruction with priority {priority} not found for employee `{employee_id}`"
+                ))
+            })?;
+
+        let reference = params
+            .reference
+            .as_deref()
+            .map(|value| Self::normalize_field(value, "verification reference"))
+            .transpose()?;
+        match (params.method, params.status) {
+            (AccountVerificationMethod::MicroDeposit, AccountVerificationStatus::Verified)
+                if instruction.verification.status != AccountVerificationStatus::Pending
+                    || instruction.verification.method
+                        != Some(AccountVerificationMethod::MicroDeposit) =>
+            {
+                return Err(AppError::conflict(
+                    "micro-deposits must be sent before they can be confirmed",
+                ));
+            }
+            (AccountVerificationMethod::Document, AccountVerificationStatus::Verified)
+                if reference.is_none() =>
+            {
+                return Err(AppError::validation(
+                    "a document verification needs a reference to the document",
+                ));
+            }
+            _ => {}
+        }
+
+        instruction.verification = AccountVerification {
+            status: params.status,
+            method: Some(params.method),
+            reference: reference.or_else(|| instruction.verification.reference.take()),
+            updated_at: Some(Utc::now()),
+        };
+        let recorded = instruction.clone();
+
+        let updated = self
+            .repository
+            .update(
+                employee_id,
+                UpdateEmployeeParams {
+                    payment_instructions: Some(instructions),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        Ok(updated.map(|_| recorded))
+    }
+
+    /// Accounts in the payroll that are not verified yet, by employee name and priority.
+    /// Payroll runs use this to hold employees out of payment batches when the payroll
+    /// blocks unverified accounts.
+    pub async fn unverified_accounts(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+    ) -> AppResult<UnverifiedAccountReport> {
+        let payroll = self
+            .payroll_service
+            .get(organization_id, payroll_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "payroll `{payroll_id}` not found for organization `{organization_id}`"
+                ))
+            })?;
+
+        let mut employees = self.search(organization_id, Some(payroll_id), None).await?;
+        employees.sort_by(|a, b| {
+            (&a.last_name, &a.first_name, a.id).cmp(&(&b.last_name, &b.first_name, b.id))
+        });
+
+        let accounts = employees
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+            .flat_map(|employee| {
+                employee
+                    .payment_instructions
+                    .iter()
+                    .filter(|instruction| !instruction.is_verified())
+                    .map(|instruction| UnverifiedAccount {
+                        employee_id: employee.id,
+                        division_id: employee.division_id,
+                        first_name: employee.first_name.clone(),
+                        last_name: employee.last_name.clone(),
+                        bank_id: instruction.bank_id,
+                        account: instruction.account.clone(),
+                        priority: instruction.priority,
+                        status: instruction.verification.status,
+                    })
+            })
+            .collect();
+
+        Ok(UnverifiedAccountReport {
+            payroll_id,
+            blocks_unverified_accounts: payroll.blocks_unverified_accounts,
+            accounts,
+        })
+    }
+
+    async fn ensure_unique_email(
+        &self,
+        organization_id: Uuid,
+        email: &str,
+        except: Option<Uuid>,
+    ) -> AppResult<()> {
+        let payroll_ids = self
+            .payroll_service
+            .list(organization_id)
+            .await?
+            .into_iter()
+            .map(|payroll| payroll.id)
+            .collect();
+        let taken = self
+            .repository
+            .fetch_by_email(email, payroll_ids)
+            .await?
+            .into_iter()
+            .any(|employee| Some(employee.id) != except);
+        if taken {
+            return Err(AppError::conflict(format!(
+                "email `{email}` is already used by another employee in organization `{organization_id}`"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the supervisor is in the same payroll and that the reporting line stays acyclic.
+    /// Supervisors among `pending`, the batch's employees not stored yet, count as existing.
+    async fn validate_supervisor(
+        &self,
+        employee_id: Uuid,
+        payroll_id: Uuid,
+        supervisor_id: Uuid,
+        pending: &[Employee],
+    ) -> AppResult<()> {
+        let mut current = Some(supervisor_id);
+        let mut visited = HashSet::new();
+        while let Some(id) = current {
+            if id == employee_id {
+                return Err(AppError::validation(
+                    "supervisor assignment would create a reporting cycle",
+                ));
+            }
+            if !visited.insert(id) {
+                break;
+            }
+
+            let supervisor = match pending.iter().find(|employee| employee.id == id) {
+                Some(employee) => Some(employee.clone()),
+                None => self.repository.fetch_latest(id).await?,
+            };
+            let supervisor = supervisor
+                .filter(|supervisor| supervisor.payroll_id == payroll_id)
+                .ok_or_else(|| {
+                    AppError::not_found(format!(
+                        "supervisor `{id}` not found for payroll `{payroll_id}`"
+                    ))
+                })?;
+            current = supervisor.supervisor_id;
+        }
+
+        Ok(())
+    }
+
+    /// Counts every employee of the division that is not `Terminated`, plus `pending` ones not
+    /// stored yet, against its budget.
+    async fn ensure_headcount_available(
+        &self,
+        division: &Division,
+        pending: usize,
+    ) -> AppResult<()> {
+        if division.headcount_budget.is_none() {
+            return Ok(());
+        }
+
+        let in_use = self
+            .repository
+            .fetch_by_division(division.id)
+            .await?
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+            .count();
+        DivisionService::ensure_headcount_available(division, in_use + pending)
+    }
+
+    /// Checks one more seat against the job's budget, on top of `pending` seats not stored yet,
+    /// failing or only warning as the job's [`HeadcountPolicy`] says.
+    async fn check_job_headcount(&self, job: &Job, pending: usize) -> AppResult<()> {
+        let Some(budget) = job.budgeted_headcount else {
+            return Ok(());
+        };
+        let filled = self.repository.count_active_by_job(job.id).await? + pending as u32;
+        if filled < budget {
+            return Ok(());
+        }
+
+        match job.headcount_policy {
+            HeadcountPolicy::Enforce => Err(AppError::conflict(format!(
+                "job `{}` has no budgeted headcount available ({filled} of {budget} in use)",
+                job.id
+            ))),
+            HeadcountPolicy::Warn => {
+                warn!(
+                    job_id = %job.id,
+                    filled,
+                    budget,
+                    "assignment exceeds the job's budgeted headcount"
+                );
+                Ok(())
+            }
+        }
+    }
+
+    async fn ensure_division_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+    ) -> AppResult<()> {
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+        match self
+            .division_service
+            .get(organization_id, payroll_id, division_id)
+            .await?
+        {
+            Some(_) => Ok(()),
+            None => Err(AppError::not_found(format!(
+                "division `{division_id}` not found for payroll `{payroll_id}` in organization `{organization_id}`"
+            ))),
+        }
+    }
+
+    async fn ensure_job_belongs(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        job_id: Uuid,
+    ) -> AppResult<Job> {
+        match self
+            .job_service
+            .get(organization_id, payroll_id, job_id)
+            .await?
+        {
+            Some(job) if job.payroll_id == payroll_id => Ok(job),
+            _ => Err(AppError::not_found(format!(
+                "job `{job_id}` not found for payroll `{payroll_id}`"
+            ))),
+        }
+    }
+
+    async fn ensure_bank_belongs(&self, organization_id: Uuid, bank_id: Uuid) -> AppResult<Bank> {
+        match self.bank_service.get(organization_id, bank_id).await? {
+            Some(bank) if bank.organization_id == organization_id => Ok(bank),
+            _ => Err(AppError::not_found(format!(
+                "bank `{bank_id}` not found for organization `{organization_id}`"
+            ))),
+        }
+    }
+
+    async fn ensure_schedule_belongs(
+        &self,
+        organization_id: Uuid,
+        schedule_id: Uuid,
+    ) -> AppResult<()> {
+        match self
+            .work_schedule_service
+            .get(organization_id, schedule_id)
+            .await?
+        {
+            Some(_) => Ok(()),
+            None => Err(AppError::not_found(format!(
+                "work schedule `{schedule_id}` not found for organization `{organization_id}`"
+            ))),
+        }
+    }
+
+    /// Checks banks, accounts and amounts, requiring percentages to total 100. Accounts at a
+    /// bank with an account rule are checked against it and stored in compact form. An
+    /// account keeps its verification from `existing` only if its bank and number are unchanged.
+    async fn validate_payment_instructions(
+        &self,
+        organization_id: Uuid,
+        instructions: Vec<PaymentInstruction>,
+        existing: &[PaymentInstruction],
+    ) -> AppResult<Vec<PaymentInstruction>> {
+        if instructions.is_empty() {
+            return Err(AppError::validation(
+                "at least one payment instruction is required",
+            ));
+        }
+
+        let mut priorities = HashSet::new();
+        let mut percentage_total = 0.0;
+        let mut validated = Vec::with_capacity(instructions.len());
+        for mut instruction in instructions {
+            let bank = self
+                .ensure_bank_belongs(organization_id, instruction.bank_id)
+                .await?;
+            instruction.account = Self::normalize_field(&instruction.account, "bank account")?;
+            if let Some(rule) = &bank.account_rule {
+                instruction.account = rule.check(&instruction.account).map_err(|reason| {
+                    AppError::validation(format!(
+                        "bank account `{}` {reason} for bank `{}`",
+                        instruction.account, bank.name
+                    ))
+                })?;
+            }
+            instruction.verification = existing
+                .iter()
+                .find(|current| {
+                    current.bank_id == instruction.bank_id && current.account == instruction.account
+                })
+                .map(|current| current.verification.clone())
+                .unwrap_or_default();
+            if !priorities.insert(instruction.priority) {
+                return Err(AppError::validation(format!(
+                    "payment instruction priority {} is used more than once",
+                    instruction.priority
+                )));
+            }
+            match instruction.amount {
+                PaymentAmount::Fixed(amount) if !amount.is_finite() || amount <= 0.0 => {
+                    return Err(AppError::validation(
+                        "fixed payment amounts must be positive",
+                    ));
+                }
+                PaymentAmount::Percentage(percentage)
+                    if !percentage.is_finite() || percentage <= 0.0 || percentage > 100.0 =>
+                {
+                    return Err(AppError::validation(
+                        "payment percentages must be greater than 0 and at most 100",
+                    ));
+                }
+                PaymentAmount::Percentage(percentage) => percentage_total += percentage,
+                PaymentAmount::Fixed(_) => {}
+            }
+            validated.push(instruction);
+        }
+
+        if (percentage_total - 100.0).abs() > 0.001 {
+            return Err(AppError::validation(format!(
+                "payment percentages must add up to 100, got {percentage_total}"
+            )));
+        }
+
+        validated.sort_by_key(|instruction| instruction.priority);
+        Ok(validated)
+    }
+
+    fn normalize_field(value: &str, field: &str) -> AppResult<String> {
         let trimmed = value.trim();
         if trimmed.is_empty() {
             return Err(AppError::validation(format!("{field} cannot be empty")));
@@ -425,6 +1737,21 @@ impl EmployeeService {
         Ok(value)
     }
 
+    /// Probation and contract end dates cannot come before the hire date.
+    fn validate_end_date(
+        hire_date: NaiveDate,
+        date: Option<NaiveDate>,
+        field: &str,
+    ) -> AppResult<Option<NaiveDate>> {
+        if date.is_some_and(|date| date < hire_date) {
+            return Err(AppError::validation(format!(
+                "{field} cannot be before hire date"
+            )));
+        }
+
+        Ok(date)
+    }
+
     fn validate_termination_date(
         hire_date: NaiveDate,
         termination_date: Option<NaiveDate>,
@@ -441,3 +1768,54 @@ impl EmployeeService {
         }
     }
 }
+
+/// Trims and lowercases an email, rejecting anything not shaped like `local@domain.tld`.
+pub(crate) fn normalize_email(value: &str) -> AppResult<String> {
+    let email = value.trim().to_lowercase();
+    let valid = email.len() <= 254
+        && !email.contains(char::is_whitespace)
+        && email.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.split('.').all(|label| !label.is_empty())
+                && domain.contains('.')
+        });
+    if !valid {
+        return Err(AppError::validation(format!(
+            "email `{}` is not a valid address",
+            value.trim()
+        )));
+    }
+
+    Ok(email)
+}
+
+/// Trims the permit's number and upper-cases its country, rejecting malformed values.
+fn normalize_work_permit(permit: WorkPermit) -> AppResult<WorkPermit> {
+    let number = permit.number.trim().to_string();
+    let country = permit.country.trim().to_uppercase();
+    if number.is_empty() {
+        return Err(AppError::validation("work permit number cannot be empty"));
+    }
+    if number.chars().count() > MAX_WORK_PERMIT_NUMBER_CHARS
+        || !number
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '/' | ' '))
+    {
+        return Err(AppError::validation(format!(
+            "work permit number must be at most {MAX_WORK_PERMIT_NUMBER_CHARS} letters, digits, `-`, `/` or spaces"
+        )));
+    }
+    if country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(AppError::validation(format!(
+            "work permit country `{}` is not a two-letter ISO 3166-1 code",
+            permit.country.trim()
+        )));
+    }
+
+    Ok(WorkPermit {
+        number,
+        country,
+        expires_on: permit.expires_on,
+    })
+}
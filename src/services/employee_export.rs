@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use rust_xlsxwriter::Workbook;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::{Employee, EmployeeStatus},
+        employee_export::{EMPLOYEE_EXPORT_COLUMNS, ExportFormat},
+    },
+    error::{AppError, AppResult},
+    services::employee::EmployeeService,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportEmployeesParams {
+    pub format: ExportFormat,
+    /// Columns to include, in order; every column when `None`.
+    pub columns: Option<Vec<String>>,
+    /// Only employees with this status.
+    pub status: Option<EmployeeStatus>,
+}
+
+#[derive(Clone)]
+pub struct EmployeeExportService {
+    employee_service: Arc<EmployeeService>,
+}
+
+impl EmployeeExportService {
+    pub fn new(employee_service: Arc<EmployeeService>) -> Self {
+        Self { employee_service }
+    }
+
+    /// Renders the division's employees, in list order, as a CSV or XLSX document.
+    pub async fn export(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        params: ExportEmployeesParams,
+    ) -> AppResult<Vec<u8>> {
+        let columns = Self::resolve_columns(params.columns)?;
+
+        let employees: Vec<Employee> = self
+            .employee_service
+            .list(organization_id, payroll_id, division_id)
+            .await?
+            .into_iter()
+            .filter(|employee| params.status.is_none_or(|status| employee.status == status))
+            .collect();
+
+        let rows = employees
+            .iter()
+            .map(|employee| {
+                columns
+                    .iter()
+                    .map(|column| Self::cell(employee, column))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        match params.format {
+            ExportFormat::Csv => Self::write_csv(&columns, &rows),
+            ExportFormat::Xlsx => Self::write_xlsx("Employees", &columns, &rows),
+        }
+    }
+
+    fn resolve_columns(columns: Option<Vec<String>>) -> AppResult<Vec<&'static str>> {
+        let Some(columns) = columns else {
+            return Ok(EMPLOYEE_EXPORT_COLUMNS.to_vec());
+        };
+
+        let mut resolved = Vec::with_capacity(columns.len());
+        for column in &columns {
+            let column = column.trim();
+            let known = EMPLOYEE_EXPORT_COLUMNS
+                .iter()
+                .copied()
+                .find(|known| *known == column)
+                .ok_or_else(|| {
+                    AppError::validation(format!("`{column}` is not an exportable column"))
+                })?;
+            if !resolved.contains(&known) {
+                resolved.push(known);
+            }
+        }
+
+        if resolved.is_empty() {
+            return Err(AppError::validation("at least one column must be selected"));
+        }
+
+        Ok(resolved)
+    }
+
+    fn cell(employee: &Employee, column: &str) -> String {
+        match column {
+            "id" => employee.id.to_string(),
+            "id_number" => employee.id_number.clone(),
+            "external_id" => employee.external_id.clone().unwrap_or_default(),
+            "last_name" => employee.last_name.clone(),
+            "first_name" => employee.first_name.clone(),
+            "address" => employee.address.clone(),
+            "phone" => employee.phone.clone(),
+            "email" => employee.email.clone().unwrap_or_default(),
+            "place_of_birth" => employee.place_of_birth.clone(),
+            "date_of_birth" => employee.date_of_birth.to_string(),
+            "nationality" => employee.nationality.clone(),
+            "marital_status" => employee.marital_status.clone(),
+            "gender" => employee.gender.clone(),
+            "hire_date" => employee.hire_date.to_string(),
+            "termination_date" => employee
+                .termination_date
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            "probation_end_date" => employee
+                .probation_end_date
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            "contract_end_date" => employee
+                .contract_end_date
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            "clasification" => employee.clasification.clone(),
+            "job_id" => employee.job_id.to_string(),
+            "bank_id" => employee
+                .payment_instructions
+                .first()
+                .map(|instruction| instruction.bank_id.to_string())
+                .unwrap_or_default(),
+            "bank_account" => employee
+                .payment_instructions
+                .first()
+                .map(|instruction| instruction.account.clone())
+                .unwrap_or_default(),
+            "status" => employee.status.to_string(),
+            "hours" => employee.hours.to_string(),
+            "schedule_id" => employee
+                .schedule_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            "division_id" => employee.division_id.to_string(),
+            "payroll_id" => employee.payroll_id.to_string(),
+            "supervisor_id" => employee
+                .supervisor_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    pub(crate) fn write_csv(columns: &[&str], rows: &[Vec<String>]) -> AppResult<Vec<u8>> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record(columns)
+            .map_err(|err| AppError::internal(format!("failed to write CSV export: {err}")))?;
+        for row in rows {
+            writer
+                .write_record(row)
+                .map_err(|err| AppError::internal(format!("failed to write CSV export: {err}")))?;
+        }
+
+        writer
+            .into_inner()
+            .map_err(|err| AppError::internal(format!("failed to write CSV export: {err}")))
+    }
+
+    pub(crate) fn write_xlsx(
+        sheet: &str,
+        columns: &[&str],
+        rows: &[Vec<String>],
+    ) -> AppResult<Vec<u8>> {
+        let to_internal = |err: rust_xlsxwriter::XlsxError| {
+            AppError::internal(format!("failed to write XLSX export: {err}"))
+        };
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(sheet).map_err(to_internal)?;
+
+        for (index, column) in columns.iter().enumerate() {
+            worksheet
+                .write_string(0, index as u16, *column)
+                .map_err(to_internal)?;
+        }
+        for (row_index, row) in rows.iter().enumerate() {
+            for (index, value) in row.iter().enumerate() {
+                worksheet
+                    .write_string(row_index as u32 + 1, index as u16, value)
+                    .map_err(to_internal)?;
+            }
+        }
+
+        workbook.save_to_buffer().map_err(to_internal)
+    }
+}
@@ -0,0 +1,471 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::{
+            AccountVerification, Employee, EmployeeStatus, PaymentAmount, PaymentInstruction,
+        },
+        employee_import::{EmployeeImportReport, EmployeeMatchKey},
+        import_profile::{DEFAULT_IMPORT_DATE_FORMAT, EMPLOYEE_IMPORT_FIELDS, ImportProfile},
+    },
+    error::{AppError, AppResult},
+    services::{
+        division::DivisionService,
+        employee::{CreateEmployeeParams, EmployeeService, UpdateEmployeeParams},
+        import_profile::ImportProfileService,
+    },
+};
+
+/// New employees an import writes to the database in one batch.
+const IMPORT_BATCH_SIZE: usize = 500;
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportEmployeesParams {
+    pub profile_id: Option<Uuid>,
+    /// Updates employees whose key matches a row instead of creating duplicates.
+    pub match_key: Option<EmployeeMatchKey>,
+    /// Reports division employees whose key is absent from the file. They are left unchanged,
+    /// since a termination needs terms the file does not carry.
+    pub flag_leavers: bool,
+}
+
+#[derive(Clone)]
+pub struct EmployeeImportService {
+    employee_service: Arc<EmployeeService>,
+    division_service: Arc<DivisionService>,
+    import_profile_service: Arc<ImportProfileService>,
+}
+
+impl EmployeeImportService {
+    pub fn new(
+        employee_service: Arc<EmployeeService>,
+        division_service: Arc<DivisionService>,
+        import_profile_service: Arc<ImportProfileService>,
+    ) -> Self {
+        Self {
+            employee_service,
+            division_service,
+            import_profile_service,
+        }
+    }
+
+    /// Creates or updates one employee per CSV row in the division and reports the outcome of
+    /// every row.
+    ///
+    /// Without a profile, header names must match employee field names and dates use
+    /// `DEFAULT_IMPORT_DATE_FORMAT`. Without a match key every row creates a new employee.
+    ///
+    /// New employees are validated row by row but stored in batches; a batch the database
+    /// rejects fails all of its rows.
+    pub async fn import(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        params: ImportEmployeesParams,
+        csv_data: &str,
+    ) -> AppResult<EmployeeImportReport> {
+        if params.flag_leavers && params.match_key.is_none() {
+            return Err(AppError::validation(
+                "flagging leavers requires a match key",
+            ));
+        }
+
+        self.division_service
+            .get(organization_id, payroll_id, division_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "division `{division_id}` not found for payroll `{payroll_id}` in organization `{organization_id}`"
+                ))
+            })?;
+
+        let profile = match params.profile_id {
+            Some(profile_id) => Some(
+                self.import_profile_service
+                    .get(organization_id, profile_id)
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::not_found(format!(
+                            "import profile `{profile_id}` not found for organization `{organization_id}`"
+                        ))
+                    })?,
+            ),
+            None => None,
+        };
+
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(csv_data.as_bytes());
+        let headers = reader
+            .headers()
+            .map_err(|err| AppError::validation(format!("invalid CSV header: {err}")))?
+            .clone();
+        let targets = Self::resolve_targets(&headers, profile.as_ref())?;
+
+        let date_format = profile
+            .as_ref()
+            .map(|profile| profile.date_format.as_str())
+            .unwrap_or(DEFAULT_IMPORT_DATE_FORMAT);
+
+        let mut existing = match params.match_key {
+            Some(_) => {
+                self.employee_service
+                    .list(organization_id, payroll_id, division_id)
+                    .await?
+            }
+            None => Vec::new(),
+        };
+        let mut seen_keys = HashSet::new();
+        let mut pending = Vec::new();
+        let mut pending_lines = Vec::new();
+
+        let mut report = EmployeeImportReport::default();
+        for record in reader.records() {
+            let record =
+                record.map_err(|err| AppError::validation(format!("invalid CSV row: {err}")))?;
+            let line = record
+                .position()
+                .map(|position| position.line())
+                .unwrap_or(0);
+
+            let mut values = HashMap::new();
+            for (target, value) in targets.iter().zip(record.iter()) {
+                if let Some(field) = target {
+                    let value = match profile.as_ref() {
+                        Some(profile) => profile.translate(field, value),
+                        None => value,
+                    };
+                    values.insert(*field, value.to_string());
+                }
+            }
+
+            let key = params.match_key.and_then(|match_key| {
+                values
+                    .get(match_key.field())
+                    .filter(|value| !value.is_empty())
+                    .map(|value| (match_key, value.clone()))
+            });
+            if let Some((_, value)) = &key {
+                seen_keys.insert(value.clone());
+            }
+
+            // A row updating an employee created earlier in the file needs it stored first.
+            if let Some((match_key, value)) = &key
+                && pending
+                    .iter()
+                    .any(|employee| Self::key_of(employee, *match_key) == Some(value.as_str()))
+            {
+                self.flush(&mut pending, &mut pending_lines, &mut existing, &mut report)
+                    .await?;
+            }
+
+            let outcome = match Self::build_params(&values, date_format) {
+                Ok(create) => {
+                    self.upsert(
+                        organization_id,
+                        payroll_id,
+                        division_id,
+                        &existing,
+                        &pending,
+                        key.as_ref(),
+                        create,
+                    )
+                    .await
+                }
+                Err(err) => Err(err),
+            };
+
+            match outcome {
+                Ok((employee, false)) => {
+                    pending.push(employee);
+                    pending_lines.push(line);
+                    if pending.len() >= IMPORT_BATCH_SIZE {
+                        self.flush(&mut pending, &mut pending_lines, &mut existing, &mut report)
+                            .await?;
+                    }
+                }
+                Ok((employee, true)) => {
+                    report.record_updated(line, employee.id);
+                    if let Some(slot) = existing.iter_mut().find(|item| item.id == employee.id) {
+                        *slot = employee;
+                    }
+                }
+                Err(
+                    err @ (AppError::Validation { .. }
+                    | AppError::NotFound { .. }
+                    | AppError::Conflict { .. }),
+                ) => report.record_failed(line, err.to_string()),
+                Err(err) => return Err(err),
+            }
+        }
+        self.flush(&mut pending, &mut pending_lines, &mut existing, &mut report)
+            .await?;
+        report.rows.sort_by_key(|row| row.line);
+
+        if let (Some(match_key), true) = (params.match_key, params.flag_leavers) {
+            for employee in &existing {
+                let Some(key) = Self::key_of(employee, match_key) else {
+                    continue;
+                };
+                if !seen_keys.contains(key) && employee.status != EmployeeStatus::Terminated {
+                    report.flagged_leavers.push(employee.id);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Updates the employee matching `key`, or prepares a new one for the next batch; the flag
+    /// reports whether it existed.
+    #[allow(clippy::too_many_arguments)]
+    async fn upsert(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        existing: &[Employee],
+        pending: &[Employee],
+        key: Option<&(EmployeeMatchKey, String)>,
+        params: CreateEmployeeParams,
+    ) -> AppResult<(Employee, bool)> {
+        let matches: Vec<&Employee> = match key {
+            Some((match_key, value)) => existing
+                .iter()
+                .filter(|employee| Self::key_of(employee, *match_key) == Some(value.as_str()))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        match matches.as_slice() {
+            [] => {
+                let employee = self
+                    .employee_service
+                    .prepare_create(organization_id, payroll_id, division_id, params, pending)
+                    .await?;
+                Ok((employee, false))
+            }
+            [employee] => {
+                // A file row carries one account; keep an existing split that already pays into it.
+                let keeps_split = params.payment_instructions.iter().all(|imported| {
+                    employee.payment_instructions.iter().any(|current| {
+                        current.bank_id == imported.bank_id && current.account == imported.account
+                    })
+                });
+                let updates = UpdateEmployeeParams {
+                    id_number: Some(params.id_number),
+                    external_id: params.external_id,
+                    last_name: Some(params.last_name),
+                    first_name: Some(params.first_name),
+                    address: Some(params.address),
+                    phone: Some(params.phone),
+                    // A blank email column leaves the current address alone.
+                    email: params.email.map(Some),
+                    email_verified_at: None,
+                    place_of_birth: Some(params.place_of_birth),
+                    date_of_birth: Some(params.date_of_birth),
+                    nationality: Some(params.nationality),
+                    marital_status: Some(params.marital_status),
+                    gender: Some(params.gender),
+                    hire_date: Some(params.hire_date),
+                    termination_date: Some(params.termination_date),
+                    // Blank columns leave the current dates alone.
+                    probation_end_date: params.probation_end_date.map(Some),
+                    contract_end_date: params.contract_end_date.map(Some),
+                    work_permit: None,
+                    clasification: Some(params.clasification),
+                    job_id: Some(params.job_id),
+                    payment_instructions: (!keeps_split).then_some(params.payment_instructions),
+                    status: Some(params.status),
+                    hours: Some(params.hours),
+                    schedule_id: None,
+                    supervisor_id: None,
+                    custom_fields: None,
+                };
+                let employee = self
+                    .employee_service
+                    .update(
+                        organization_id,
+                        payroll_id,
+                        division_id,
+                        employee.id,
+                        updates,
+                    )
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::not_found(format!("employee `{}` not found", employee.id))
+                    })?;
+                Ok((employee, true))
+            }
+            _ => Err(AppError::conflict(
+                "match key is shared by more than one employee in the division",
+            )),
+        }
+    }
+
+    /// Stores the employees prepared so far and records their rows.
+    async fn flush(
+        &self,
+        pending: &mut Vec<Employee>,
+        pending_lines: &mut Vec<u64>,
+        existing: &mut Vec<Employee>,
+        report: &mut EmployeeImportReport,
+    ) -> AppResult<()> {
+        let lines = std::mem::take(pending_lines);
+        match self
+            .employee_service
+            .insert_prepared(std::mem::take(pending))
+            .await
+        {
+            Ok(created) => {
+                for (line, employee) in lines.into_iter().zip(created) {
+                    report.record_created(line, employee.id);
+                    existing.push(employee);
+                }
+            }
+            Err(
+                err @ (AppError::Validation { .. }
+                | AppError::NotFound { .. }
+                | AppError::Conflict { .. }),
+            ) => {
+                for line in lines {
+                    report.record_failed(line, err.to_string());
+                }
+            }
+            Err(err) => return Err(err),
+        }
+
+        Ok(())
+    }
+
+    fn key_of(employee: &Employee, match_key: EmployeeMatchKey) -> Option<&str> {
+        match match_key {
+            EmployeeMatchKey::IdNumber => Some(employee.id_number.as_str()),
+            EmployeeMatchKey::ExternalId => employee.external_id.as_deref(),
+        }
+    }
+
+    /// Resolves the employee field fed by each CSV column, in header order.
+    fn resolve_targets(
+        headers: &csv::StringRecord,
+        profile: Option<&ImportProfile>,
+    ) -> AppResult<Vec<Option<&'static str>>> {
+        let targets: Vec<Option<&'static str>> = headers
+            .iter()
+            .map(|column| {
+                let field = match profile {
+                    Some(profile) => profile.target_field(column),
+                    None => Some(column),
+                };
+                field.and_then(|field| {
+                    EMPLOYEE_IMPORT_FIELDS
+                        .iter()
+                        .copied()
+                        .find(|known| *known == field)
+                })
+            })
+            .collect();
+
+        if targets.iter().all(Option::is_none) {
+            return Err(AppError::validation(
+                "CSV header does not contain any importable employee column",
+            ));
+        }
+
+        Ok(targets)
+    }
+
+    fn build_params(
+        values: &HashMap<&str, String>,
+        date_format: &str,
+    ) -> AppResult<CreateEmployeeParams> {
+        let text = |field: &str| -> AppResult<String> {
+            values
+                .get(field)
+                .filter(|value| !value.is_empty())
+                .cloned()
+                .ok_or_else(|| AppError::validation(format!("{field} is required")))
+        };
+        let date = |field: &str| -> AppResult<NaiveDate> {
+            let value = text(field)?;
+            NaiveDate::parse_from_str(&value, date_format).map_err(|_| {
+                AppError::validation(format!(
+                    "{field} `{value}` does not match date format `{date_format}`"
+                ))
+            })
+        };
+        let uuid = |field: &str| -> AppResult<Uuid> {
+            let value = text(field)?;
+            Uuid::parse_str(&value)
+                .map_err(|_| AppError::validation(format!("{field} `{value}` is not a UUID")))
+        };
+
+        let optional_date = |field: &str| -> AppResult<Option<NaiveDate>> {
+            match values.get(field) {
+                Some(value) if !value.is_empty() => Ok(Some(date(field)?)),
+                _ => Ok(None),
+            }
+        };
+        let termination_date = optional_date("termination_date")?;
+        let probation_end_date = optional_date("probation_end_date")?;
+        let contract_end_date = optional_date("contract_end_date")?;
+        let status = text("status")?;
+        let status = EmployeeStatus::parse(&status).ok_or_else(|| {
+            AppError::validation(format!("status `{status}` is not a known employee status"))
+        })?;
+        let hours = text("hours")?;
+        let hours = hours
+            .parse()
+            .map_err(|_| AppError::validation(format!("hours `{hours}` is not a number")))?;
+
+        let external_id = values
+            .get("external_id")
+            .filter(|value| !value.is_empty())
+            .cloned();
+        let email = values
+            .get("email")
+            .filter(|value| !value.is_empty())
+            .cloned();
+
+        Ok(CreateEmployeeParams {
+            id_number: text("id_number")?,
+            external_id,
+            last_name: text("last_name")?,
+            first_name: text("first_name")?,
+            address: text("address")?,
+            phone: text("phone")?,
+            email,
+            place_of_birth: text("place_of_birth")?,
+            date_of_birth: date("date_of_birth")?,
+            nationality: text("nationality")?,
+            marital_status: text("marital_status")?,
+            gender: text("gender")?,
+            hire_date: date("hire_date")?,
+            termination_date,
+            probation_end_date,
+            contract_end_date,
+            work_permit: None,
+            clasification: text("clasification")?,
+            job_id: uuid("job_id")?,
+            payment_instructions: vec![PaymentInstruction {
+                bank_id: uuid("bank_id")?,
+                account: text("bank_account")?,
+                amount: PaymentAmount::Percentage(100.0),
+                priority: 0,
+                verification: AccountVerification::default(),
+            }],
+            status,
+            hours,
+            schedule_id: None,
+            supervisor_id: None,
+            custom_fields: BTreeMap::new(),
+        })
+    }
+}
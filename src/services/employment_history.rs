@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::Employee,
+        employment_history::{
+            EmploymentEvent, EmploymentEventKind, TerminationTerms, TimelineEntry,
+            TimelineEntryKind,
+        },
+    },
+    error::{AppError, AppResult},
+    services::{employee::EmployeeService, position::PositionService},
+};
+
+#[derive(Debug, Clone)]
+pub struct TerminateEmployeeParams {
+    pub effective_date: NaiveDate,
+    pub reason_code: String,
+    pub eligible_for_rehire: bool,
+    pub eligible_for_severance: bool,
+    /// Leaves final-pay items pending for the next payroll run.
+    pub flag_final_pay: bool,
+}
+
+#[async_trait]
+pub trait EmploymentHistoryRepository: Send + Sync {
+    async fn insert(&self, event: EmploymentEvent) -> AppResult<EmploymentEvent>;
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<EmploymentEvent>>;
+}
+
+/// Records terminations and rehires alongside the employee changes they cause.
+#[derive(Clone)]
+pub struct EmploymentHistoryService {
+    repository: Arc<dyn EmploymentHistoryRepository>,
+    employee_service: Arc<EmployeeService>,
+    position_service: Arc<PositionService>,
+}
+
+impl EmploymentHistoryService {
+    pub fn new(
+        repository: Arc<dyn EmploymentHistoryRepository>,
+        employee_service: Arc<EmployeeService>,
+        position_service: Arc<PositionService>,
+    ) -> Self {
+        Self {
+            repository,
+            employee_service,
+            position_service,
+        }
+    }
+
+    /// Terminates the employee, vacates their position and records the terms.
+    pub async fn terminate(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        params: TerminateEmployeeParams,
+    ) -> AppResult<Option<Employee>> {
+        let reason_code = params.reason_code.trim();
+        if reason_code.is_empty() {
+            return Err(AppError::validation("reason code cannot be empty"));
+        }
+
+        let terms = TerminationTerms {
+            reason_code: reason_code.to_string(),
+            eligible_for_rehire: params.eligible_for_rehire,
+            eligible_for_severance: params.eligible_for_severance,
+            final_pay_pending: params.flag_final_pay,
+        };
+        self.employee_service
+            .terminate(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                params.effective_date,
+                terms,
+            )
+            .await
+    }
+
+    /// Rehires a terminated employee unless their last termination ruled it out.
+    pub async fn rehire(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        hire_date: NaiveDate,
+    ) -> AppResult<Option<Employee>> {
+        let last_termination = self
+            .repository
+            .fetch_by_employee(employee_id)
+            .await?
+            .into_iter()
+            .filter_map(|event| event.termination.map(|terms| (event.recorded_at, terms)))
+            .max_by_key(|(recorded_at, _)| *recorded_at);
+        if let Some((_, terms)) = last_termination
+            && !terms.eligible_for_rehire
+        {
+            return Err(AppError::conflict(format!(
+                "employee `{employee_id}` was terminated as not eligible for rehire"
+            )));
+        }
+
+        let Some(employee) = self
+            .employee_service
+            .rehire(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                hire_date,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        self.record(employee_id, EmploymentEventKind::Rehire, hire_date)
+            .await?;
+
+        Ok(Some(employee))
+    }
+
+    /// Events for the employee, oldest first.
+    pub async fn list(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Option<Vec<EmploymentEvent>>> {
+        if self
+            .employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let mut events = self.repository.fetch_by_employee(employee_id).await?;
+        events.sort_by(|a, b| {
+            a.effective_date
+                .cmp(&b.effective_date)
+                .then_with(|| a.recorded_at.cmp(&b.recorded_at))
+        });
+        Ok(Some(events))
+    }
+
+    /// Hire, employment events and position assignments for the employee, oldest first. Positions
+    /// held in divisions the employee has since left are included.
+    pub async fn timeline(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Option<Vec<TimelineEntry>>> {
+        let Some(employee) = self
+            .employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let mut events = self.repository.fetch_by_employee(employee_id).await?;
+        events.sort_by_key(|event| (event.effective_date, event.recorded_at));
+
+        let mut entries = Vec::new();
+        let rehired_on_hire_date = events.iter().any(|event| {
+            event.kind == EmploymentEventKind::Rehire && event.effective_date == employee.hire_date
+        });
+        if !rehired_on_hire_date {
+            entries.push(TimelineEntry {
+                date: employee.hire_date,
+                kind: TimelineEntryKind::Hire,
+                description: "hired".to_string(),
+            });
+        }
+
+        entries.extend(events.iter().map(describe_event));
+
+        for (title, assignment) in self
+            .position_service
+            .assignments_for_employee(employee_id)
+            .await?
+        {
+            entries.push(TimelineEntry {
+                date: assignment.start_date,
+                kind: TimelineEntryKind::PositionAssigned,
+                description: format!("assigned to position `{title}`"),
+            });
+            if let Some(end_date) = assignment.end_date {
+                entries.push(TimelineEntry {
+                    date: end_date,
+                    kind: TimelineEntryKind::PositionVacated,
+                    description: format!("left position `{title}`"),
+                });
+            }
+        }
+
+        // Stable sort keeps same-day entries in the order they were gathered.
+        entries.sort_by_key(|entry| entry.date);
+        Ok(Some(entries))
+    }
+
+    async fn record(
+        &self,
+        employee_id: Uuid,
+        kind: EmploymentEventKind,
+        effective_date: NaiveDate,
+    ) -> AppResult<EmploymentEvent> {
+        let event = EmploymentEvent::new(
+            Uuid::new_v4(),
+            employee_id,
+            kind,
+            effective_date,
+            Utc::now(),
+        );
+        self.repository.insert(event).await
+    }
+}
+
+fn describe_event(event: &EmploymentEvent) -> TimelineEntry {
+    let (kind, description) = match event.kind {
+        EmploymentEventKind::Termination => (
+            TimelineEntryKind::Termination,
+            match &event.termination {
+                Some(terms) => format!("terminated ({})", terms.reason_code),
+                None => "terminated".to_string(),
+            },
+        ),
+        EmploymentEventKind::Rehire => (TimelineEntryKind::Rehire, "rehired".to_string()),
+        EmploymentEventKind::StatusChange => (
+            TimelineEntryKind::StatusChange,
+            match &event.status_change {
+                Some(change) => format!("status changed from {} to {}", change.from, change.to),
+                None => "status changed".to_string(),
+            },
+        ),
+        EmploymentEventKind::JobChange => (
+            TimelineEntryKind::JobChange,
+            match &event.job_change {
+                Some(change) => format!(
+                    "moved from job `{}` to job `{}` (salary {:.2} -> {:.2})",
+                    change.from_job_id, change.to_job_id, change.from_salary, change.to_salary
+                ),
+                None => "job changed".to_string(),
+            },
+        ),
+        EmploymentEventKind::Transfer => (
+            TimelineEntryKind::Transfer,
+            match &event.transfer {
+                Some(transfer) => format!(
+                    "transferred from division `{}` to division `{}`",
+                    transfer.from_division_id, transfer.to_division_id
+                ),
+                None => "transferred".to_string(),
+            },
+        ),
+        EmploymentEventKind::SalaryAdjustment => (
+            TimelineEntryKind::SalaryAdjustment,
+            match &event.salary_adjustment {
+                Some(change) => format!(
+                    "salary adjusted from {:.2} to {:.2} by adjustment `{}`",
+                    change.from_salary, change.to_salary, change.batch_id
+                ),
+                None => "salary adjusted".to_string(),
+            },
+        ),
+    };
+
+    TimelineEntry {
+        date: event.effective_date,
+        kind,
+        description,
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDate};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::EmployeeStatus,
+        expiry::{Expiry, MAX_EXPIRY_WINDOW_DAYS, PermitExpiry},
+    },
+    error::{AppError, AppResult},
+    services::employee::EmployeeService,
+};
+
+#[derive(Clone)]
+pub struct ExpiryService {
+    employee_service: Arc<EmployeeService>,
+}
+
+impl ExpiryService {
+    pub fn new(employee_service: Arc<EmployeeService>) -> Self {
+        Self { employee_service }
+    }
+
+    /// Contracts, probation periods and work permits of employees still on the books that
+    /// end within `within_days` of `today`, soonest first.
+    pub async fn expiring(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+        division_id: Option<Uuid>,
+        today: NaiveDate,
+        within_days: u32,
+    ) -> AppResult<Vec<Expiry>> {
+        Self::validate_window(within_days)?;
+
+        let until = today + Duration::days(i64::from(within_days));
+        let mut expiries: Vec<Expiry> = self
+            .employee_service
+            .search(organization_id, payroll_id, division_id)
+            .await?
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+            .flat_map(|employee| Expiry::due(employee, today, until))
+            .collect();
+        expiries.sort_by(|a, b| {
+            a.date
+                .cmp(&b.date)
+                .then_with(|| a.last_name.cmp(&b.last_name))
+                .then_with(|| a.first_name.cmp(&b.first_name))
+        });
+
+        Ok(expiries)
+    }
+
+    /// Work permits of employees still on the books that have lapsed or lapse within
+    /// `within_days` of `today`, soonest first.
+    pub async fn expiring_permits(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+        division_id: Option<Uuid>,
+        today: NaiveDate,
+        within_days: u32,
+    ) -> AppResult<Vec<PermitExpiry>> {
+        Self::validate_window(within_days)?;
+
+        let until = today + Duration::days(i64::from(within_days));
+        let mut permits: Vec<PermitExpiry> = self
+            .employee_service
+            .search(organization_id, payroll_id, division_id)
+            .await?
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+            .filter_map(|employee| PermitExpiry::due(employee, today, until))
+            .collect();
+        permits.sort_by(|a, b| {
+            a.days_remaining
+                .cmp(&b.days_remaining)
+                .then_with(|| a.last_name.cmp(&b.last_name))
+                .then_with(|| a.first_name.cmp(&b.first_name))
+        });
+
+        Ok(permits)
+    }
+
+    fn validate_window(within_days: u32) -> AppResult<()> {
+        if within_days > MAX_EXPIRY_WINDOW_DAYS {
+            return Err(AppError::validation(format!(
+                "within_days cannot exceed {MAX_EXPIRY_WINDOW_DAYS}"
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::format::{Item, StrftimeItems};
+use uuid::Uuid;
+
+use crate::{
+    domain::import_profile::{DEFAULT_IMPORT_DATE_FORMAT, EMPLOYEE_IMPORT_FIELDS, ImportProfile},
+    error::{AppError, AppResult},
+    services::organization::OrganizationService,
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateImportProfileParams {
+    pub name: String,
+    pub column_mappings: BTreeMap<String, String>,
+    pub date_format: Option<String>,
+    pub value_translations: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateImportProfileParams {
+    pub name: Option<String>,
+    pub column_mappings: Option<BTreeMap<String, String>>,
+    pub date_format: Option<String>,
+    pub value_translations: Option<BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+#[async_trait]
+pub trait ImportProfileRepository: Send + Sync {
+    async fn insert(&self, profile: ImportProfile) -> AppResult<ImportProfile>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<ImportProfile>>;
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<ImportProfile>>;
+    async fn update(&self, profile: ImportProfile) -> AppResult<Option<ImportProfile>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct ImportProfileService {
+    repository: Arc<dyn ImportProfileRepository>,
+    organization_service: Arc<OrganizationService>,
+}
+
+impl ImportProfileService {
+    pub fn new(
+        repository: Arc<dyn ImportProfileRepository>,
+        organization_service: Arc<OrganizationService>,
+    ) -> Self {
+        Self {
+            repository,
+            organization_service,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        params: CreateImportProfileParams,
+    ) -> AppResult<ImportProfile> {
+        let name = Self::normalize_name(&params.name)?;
+        let column_mappings = Self::normalize_mappings(params.column_mappings)?;
+        let date_format = Self::normalize_date_format(
+            params
+                .date_format
+                .as_deref()
+                .unwrap_or(DEFAULT_IMPORT_DATE_FORMAT),
+        )?;
+        let value_translations = Self::normalize_translations(params.value_translations)?;
+        self.ensure_organization_exists(organization_id).await?;
+
+        let profile = ImportProfile::new(
+            Uuid::new_v4(),
+            name,
+            column_mappings,
+            date_format,
+            value_translations,
+            organization_id,
+        );
+        self.repository.insert(profile).await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        profile_id: Uuid,
+    ) -> AppResult<Option<ImportProfile>> {
+        let profile = self.repository.fetch(profile_id).await?;
+        Ok(profile.filter(|profile| profile.organization_id == organization_id))
+    }
+
+    pub async fn list(&self, organization_id: Uuid) -> AppResult<Vec<ImportProfile>> {
+        self.ensure_organization_exists(organization_id).await?;
+        let mut profiles = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?;
+        profiles.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(profiles)
+    }
+
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        profile_id: Uuid,
+        params: UpdateImportProfileParams,
+    ) -> AppResult<Option<ImportProfile>> {
+        if params.name.is_none()
+            && params.column_mappings.is_none()
+            && params.date_format.is_none()
+            && params.value_translations.is_none()
+        {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        let Some(mut profile) = self.get(organization_id, profile_id).await? else {
+            return Ok(None);
+        };
+
+        if let Some(name) = params.name.as_deref() {
+            profile.name = Self::normalize_name(name)?;
+        }
+        if let Some(column_mappings) = params.column_mappings {
+            profile.column_mappings = Self::normalize_mappings(column_mappings)?;
+        }
+        if let Some(date_format) = params.date_format.as_deref() {
+            profile.date_format = Self::normalize_date_format(date_format)?;
+        }
+        if let Some(value_translations) = params.value_translations {
+            profile.value_translations = Self::normalize_translations(value_translations)?;
+        }
+
+        self.repository.update(profile).await
+    }
+
+    pub async fn delete(&self, organization_id: Uuid, profile_id: Uuid) -> AppResult<bool> {
+        if self.get(organization_id, profile_id).await?.is_none() {
+            return Ok(false);
+        }
+
+        self.repository.delete(profile_id).await
+    }
+
+    async fn ensure_organization_exists(&self, organization_id: Uuid) -> AppResult<()> {
+        let exists = self
+            .organization_service
+            .get(organization_id)
+            .await?
+            .is_some();
+
+        if exists {
+            Ok(())
+        } else {
+            Err(AppError::not_found(format!(
+                "organization `{organization_id}` not found"
+            )))
+        }
+    }
+
+    fn normalize_name(value: &str) -> AppResult<String> {
+        let name = value.trim();
+        if name.is_empty() {
+            return Err(AppError::validation("import profile name cannot be empty"));
+        }
+
+        Ok(name.to_string())
+    }
+
+    fn normalize_mappings(
+        mappings: BTreeMap<String, String>,
+    ) -> AppResult<BTreeMap<String, String>> {
+        if mappings.is_empty() {
+            return Err(AppError::validation(
+                "import profile must map at least one column",
+            ));
+        }
+
+        let mut normalized = BTreeMap::new();
+        for (column, field) in mappings {
+            let column = column.trim();
+            if column.is_empty() {
+                return Err(AppError::validation("source column cannot be empty"));
+            }
+
+            let field = Self::normalize_field_name(&field)?;
+            if normalized.values().any(|existing| existing == &field) {
+                return Err(AppError::validation(format!(
+                    "employee field `{field}` is mapped by more than one column"
+                )));
+            }
+
+            normalized.insert(column.to_string(), field);
+        }
+
+        Ok(normalized)
+    }
+
+    fn normalize_translations(
+        translations: BTreeMap<String, BTreeMap<String, String>>,
+    ) -> AppResult<BTreeMap<String, BTreeMap<String, String>>> {
+        translations
+            .into_iter()
+            .map(|(field, values)| Ok((Self::normalize_field_name(&field)?, values)))
+            .collect()
+    }
+
+    fn normalize_field_name(value: &str) -> AppResult<String> {
+        let field = value.trim();
+        if !EMPLOYEE_IMPORT_FIELDS.contains(&field) {
+            return Err(AppError::validation(format!(
+                "`{field}` is not an importable employee field"
+            )));
+        }
+
+        Ok(field.to_string())
+    }
+
+    fn normalize_date_format(value: &str) -> AppResult<String> {
+        let format = value.trim();
+        if format.is_empty() {
+            return Err(AppError::validation("date format cannot be empty"));
+        }
+
+        if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+            return Err(AppError::validation(format!(
+                "`{format}` is not a valid date format"
+            )));
+        }
+
+        Ok(format.to_string())
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::{
+    domain::{
+        email::EmailMessage,
+        health::{IntegrationHealth, IntegrationHealthReport},
+    },
+    error::AppResult,
+    services::email::EmailSender,
+};
+
+/// Name outbound email is reported under.
+pub const EMAIL_INTEGRATION: &str = "email";
+
+/// Remembers, per integration, how the latest calls from this process went. Nothing is
+/// persisted: a restart reports every integration as unknown until it is used again.
+#[derive(Debug, Default)]
+pub struct IntegrationMonitor {
+    integrations: RwLock<BTreeMap<String, IntegrationHealth>>,
+}
+
+impl IntegrationMonitor {
+    /// Lists `name` in reports before it is first called.
+    pub fn register(&self, name: &str, detail: Option<String>) {
+        self.integrations
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(name.to_string())
+            .or_insert_with(|| IntegrationHealth::new(name, None))
+            .detail = detail;
+    }
+
+    pub fn record_success(&self, name: &str) {
+        self.integrations
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(name.to_string())
+            .or_insert_with(|| IntegrationHealth::new(name, None))
+            .record_success(Utc::now());
+    }
+
+    pub fn record_failure(&self, name: &str, error: impl Into<String>) {
+        self.integrations
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(name.to_string())
+            .or_insert_with(|| IntegrationHealth::new(name, None))
+            .record_failure(Utc::now(), error);
+    }
+
+    /// Integrations by name.
+    pub fn report(&self) -> IntegrationHealthReport {
+        IntegrationHealthReport::new(
+            self.integrations
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .values()
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+/// Records the outcome of every email `inner` sends.
+pub struct MonitoredEmailSender {
+    inner: Arc<dyn EmailSender>,
+    monitor: Arc<IntegrationMonitor>,
+}
+
+impl MonitoredEmailSender {
+    pub fn new(inner: Arc<dyn EmailSender>, monitor: Arc<IntegrationMonitor>) -> Self {
+        Self { inner, monitor }
+    }
+}
+
+#[async_trait]
+impl EmailSender for MonitoredEmailSender {
+    async fn send(&self, message: EmailMessage) -> AppResult<()> {
+        let result = self.inner.send(message).await;
+        match &result {
+            Ok(()) => self.monitor.record_success(EMAIL_INTEGRATION),
+            Err(error) => self
+                .monitor
+                .record_failure(EMAIL_INTEGRATION, error.to_string()),
+        }
+        result
+    }
+}
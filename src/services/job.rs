@@ -1,61 +1,114 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
 use uuid::Uuid;
 
 use crate::{
-    domain::job::Job,
+    domain::{
+        employee::EmployeeStatus,
+        employment_history::{
+            EmploymentEvent, EmploymentEventKind, JobChange, SalaryAdjustmentChange,
+        },
+        job::{HeadcountPolicy, Job, PayBasis},
+    },
     error::{AppError, AppResult},
-    services::payroll::PayrollService,
+    services::{
+        employee::EmployeeRepository, employment_history::EmploymentHistoryRepository,
+        job_grade::JobGradeService, payroll::PayrollService,
+    },
 };
 
+pub const MAX_DESCRIPTION_CHARS: usize = 4000;
+/// Limit on responsibilities and on qualifications, each.
+pub const MAX_LIST_ITEMS: usize = 30;
+pub const MAX_LIST_ITEM_CHARS: usize = 500;
+
 #[derive(Debug, Clone)]
 pub struct CreateJobParams {
     pub job_title: String,
+    pub description: Option<String>,
+    pub responsibilities: Vec<String>,
+    pub qualifications: Vec<String>,
     pub salary: f64,
+    pub pay_basis: PayBasis,
+    pub currency: Option<String>,
+    pub salary_min: Option<f64>,
+    pub salary_max: Option<f64>,
+    pub grade_id: Option<Uuid>,
+    pub budgeted_headcount: Option<u32>,
+    pub headcount_policy: HeadcountPolicy,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct UpdateJobParams {
     pub job_title: Option<String>,
+    /// `Some(None)` clears the description.
+    pub description: Option<Option<String>>,
+    /// Replaces the whole list; an empty list clears it.
+    pub responsibilities: Option<Vec<String>>,
+    /// Replaces the whole list; an empty list clears it.
+    pub qualifications: Option<Vec<String>>,
     pub salary: Option<f64>,
+    pub pay_basis: Option<PayBasis>,
+    /// `Some(None)` falls back to the organization's operating currency.
+    pub currency: Option<Option<String>>,
+    /// `Some(None)` removes the range's floor.
+    pub salary_min: Option<Option<f64>>,
+    /// `Some(None)` removes the range's ceiling.
+    pub salary_max: Option<Option<f64>>,
+    /// `Some(None)` takes the job out of its grade.
+    pub grade_id: Option<Option<Uuid>>,
+    /// `Some(None)` removes the budget.
+    pub budgeted_headcount: Option<Option<u32>>,
+    pub headcount_policy: Option<HeadcountPolicy>,
 }
 
 #[async_trait]
 pub trait JobRepository: Send + Sync {
-    async fn insert(
-        &self,
-        id: Uuid,
-        job_title: String,
-        salary: f64,
-        payroll_id: Uuid,
-    ) -> AppResult<Job>;
+    async fn insert(&self, job: Job) -> AppResult<Job>;
 
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Job>>;
 
     async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<Job>>;
 
-    async fn update(
-        &self,
-        id: Uuid,
-        job_title: Option<String>,
-        salary: Option<f64>,
-    ) -> AppResult<Option<Job>>;
+    async fn update(&self, job: Job) -> AppResult<Option<Job>>;
 
-    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+    /// Moves the employees named by `reassignment` and deletes the job in one step. Refused
+    /// with a conflict while any other employee still holds the job.
+    async fn delete(&self, id: Uuid, reassignment: Option<JobReassignment>) -> AppResult<bool>;
+}
+
+/// Employees moved off a job being deleted, with the job change recorded for each of them.
+#[derive(Debug, Clone)]
+pub struct JobReassignment {
+    pub to_job_id: Uuid,
+    pub events: Vec<EmploymentEvent>,
 }
 
 #[derive(Clone)]
 pub struct JobService {
     repository: Arc<dyn JobRepository>,
     payroll_service: Arc<PayrollService>,
+    grade_service: Arc<JobGradeService>,
+    employee_repository: Arc<dyn EmployeeRepository>,
+    history: Arc<dyn EmploymentHistoryRepository>,
 }
 
 impl JobService {
-    pub fn new(repository: Arc<dyn JobRepository>, payroll_service: Arc<PayrollService>) -> Self {
+    pub fn new(
+        repository: Arc<dyn JobRepository>,
+        payroll_service: Arc<PayrollService>,
+        grade_service: Arc<JobGradeService>,
+        employee_repository: Arc<dyn EmployeeRepository>,
+        history: Arc<dyn EmploymentHistoryRepository>,
+    ) -> Self {
         Self {
             repository,
             payroll_service,
+            grade_service,
+            employee_repository,
+            history,
         }
     }
 
@@ -68,12 +121,38 @@ impl JobService {
         self.ensure_payroll_accessible(organization_id, payroll_id)
             .await?;
         let job_title = Self::normalize_title(&params.job_title)?;
+        let description = Self::normalize_description(params.description)?;
+        let responsibilities = Self::normalize_items("responsibilities", params.responsibilities)?;
+        let qualifications = Self::normalize_items("qualifications", params.qualifications)?;
         let salary = Self::validate_salary(params.salary)?;
-        let id = Uuid::new_v4();
+        Self::validate_range(salary, params.salary_min, params.salary_max)?;
+        let currency = params
+            .currency
+            .as_deref()
+            .map(normalize_currency)
+            .transpose()?;
+        if let Some(grade_id) = params.grade_id {
+            self.ensure_within_grade(organization_id, payroll_id, grade_id, salary)
+                .await?;
+        }
 
-        self.repository
-            .insert(id, job_title, salary, payroll_id)
-            .await
+        let job = Job::new(
+            Uuid::new_v4(),
+            job_title,
+            description,
+            responsibilities,
+            qualifications,
+            salary,
+            params.pay_basis,
+            currency,
+            params.salary_min,
+            params.salary_max,
+            params.grade_id,
+            params.budgeted_headcount,
+            params.headcount_policy,
+            payroll_id,
+        );
+        self.repository.insert(job).await
     }
 
     pub async fn get(
@@ -96,6 +175,38 @@ impl JobService {
         Ok(jobs)
     }
 
+    /// Jobs across the organization, or only `payroll_id`'s when given.
+    pub async fn search(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+    ) -> AppResult<Vec<Job>> {
+        if let Some(payroll_id) = payroll_id {
+            return self.list(organization_id, payroll_id).await;
+        }
+
+        let mut jobs = Vec::new();
+        for payroll in self.payroll_service.list(organization_id).await? {
+            jobs.extend(self.repository.fetch_by_payroll(payroll.id).await?);
+        }
+        jobs.sort_by(|a, b| a.job_title.cmp(&b.job_title));
+        Ok(jobs)
+    }
+
+    /// Looks a job up by id alone, as long as its payroll belongs to the organization.
+    pub async fn locate(&self, organization_id: Uuid, job_id: Uuid) -> AppResult<Option<Job>> {
+        let Some(job) = self.repository.fetch(job_id).await? else {
+            return Ok(None);
+        };
+        let in_organization = self
+            .payroll_service
+            .get(organization_id, job.payroll_id)
+            .await?
+            .is_some();
+
+        Ok(in_organization.then_some(job))
+    }
+
     pub async fn update(
         &self,
         organization_id: Uuid,
@@ -103,43 +214,223 @@ impl JobService {
         job_id: Uuid,
         params: UpdateJobParams,
     ) -> AppResult<Option<Job>> {
-        if params.job_title.is_none() && params.salary.is_none() {
+        if params.job_title.is_none()
+            && params.description.is_none()
+            && params.responsibilities.is_none()
+            && params.qualifications.is_none()
+            && params.salary.is_none()
+            && params.pay_basis.is_none()
+            && params.currency.is_none()
+            && params.salary_min.is_none()
+            && params.salary_max.is_none()
+            && params.grade_id.is_none()
+            && params.budgeted_headcount.is_none()
+            && params.headcount_policy.is_none()
+        {
             return Err(AppError::validation("no fields supplied for update"));
         }
 
-        if self
-            .get(organization_id, payroll_id, job_id)
-            .await?
-            .is_none()
-        {
+        let Some(mut job) = self.get(organization_id, payroll_id, job_id).await? else {
             return Ok(None);
+        };
+
+        if let Some(job_title) = params.job_title.as_deref() {
+            job.job_title = Self::normalize_title(job_title)?;
+        }
+        if let Some(description) = params.description {
+            job.description = Self::normalize_description(description)?;
+        }
+        if let Some(responsibilities) = params.responsibilities {
+            job.responsibilities = Self::normalize_items("responsibilities", responsibilities)?;
+        }
+        if let Some(qualifications) = params.qualifications {
+            job.qualifications = Self::normalize_items("qualifications", qualifications)?;
+        }
+        if let Some(salary) = params.salary {
+            job.salary = Self::validate_salary(salary)?;
+        }
+        if let Some(pay_basis) = params.pay_basis {
+            job.pay_basis = pay_basis;
+        }
+        if let Some(currency) = params.currency {
+            job.currency = currency.as_deref().map(normalize_currency).transpose()?;
+        }
+        if let Some(salary_min) = params.salary_min {
+            job.salary_min = salary_min;
+        }
+        if let Some(salary_max) = params.salary_max {
+            job.salary_max = salary_max;
+        }
+        Self::validate_range(job.salary, job.salary_min, job.salary_max)?;
+        if let Some(grade_id) = params.grade_id {
+            job.grade_id = grade_id;
+        }
+        if let Some(budgeted_headcount) = params.budgeted_headcount {
+            job.budgeted_headcount = budgeted_headcount;
+        }
+        if let Some(headcount_policy) = params.headcount_policy {
+            job.headcount_policy = headcount_policy;
+        }
+        if let Some(grade_id) = job.grade_id {
+            self.ensure_within_grade(organization_id, payroll_id, grade_id, job.salary)
+                .await?;
         }
 
-        let job_title = params
-            .job_title
-            .as_deref()
-            .map(Self::normalize_title)
-            .transpose()?;
-        let salary = params.salary.map(Self::validate_salary).transpose()?;
+        self.repository.update(job).await
+    }
 
-        self.repository.update(job_id, job_title, salary).await
+    /// Checks `salary` against the job's own range and its grade's band without saving it.
+    pub async fn check_salary(
+        &self,
+        organization_id: Uuid,
+        job: &Job,
+        salary: f64,
+    ) -> AppResult<()> {
+        Self::validate_range(salary, job.salary_min, job.salary_max)?;
+        if let Some(grade_id) = job.grade_id {
+            self.ensure_within_grade(organization_id, job.payroll_id, grade_id, salary)
+                .await?;
+        }
+
+        Ok(())
     }
 
-    pub async fn delete(
+    /// Sets the salary agreed in salary adjustment `batch_id` and records the raise for every
+    /// employee holding the job.
+    pub async fn apply_salary_adjustment(
         &self,
         organization_id: Uuid,
         payroll_id: Uuid,
         job_id: Uuid,
-    ) -> AppResult<bool> {
-        if self
-            .get(organization_id, payroll_id, job_id)
+        batch_id: Uuid,
+        salary: f64,
+        effective_date: NaiveDate,
+    ) -> AppResult<Option<Job>> {
+        let Some(job) = self.get(organization_id, payroll_id, job_id).await? else {
+            return Ok(None);
+        };
+        let updates = UpdateJobParams {
+            salary: Some(salary),
+            ..UpdateJobParams::default()
+        };
+        let Some(updated) = self
+            .update(organization_id, payroll_id, job_id, updates)
             .await?
-            .is_none()
+        else {
+            return Ok(None);
+        };
+
+        let holders = self.employee_repository.fetch_by_job(job_id).await?;
+        for employee in holders
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
         {
+            let mut event = EmploymentEvent::new(
+                Uuid::new_v4(),
+                employee.id,
+                EmploymentEventKind::SalaryAdjustment,
+                effective_date,
+                Utc::now(),
+            );
+            event.salary_adjustment = Some(SalaryAdjustmentChange {
+                batch_id,
+                job_id,
+                from_salary: job.salary,
+                to_salary: updated.salary,
+            });
+            self.history.insert(event).await?;
+        }
+
+        Ok(Some(updated))
+    }
+
+    /// Refused while employees hold the job, unless `reassign_to` names another job of the
+    /// payroll to move them to first.
+    pub async fn delete(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        job_id: Uuid,
+        reassign_to: Option<Uuid>,
+    ) -> AppResult<bool> {
+        let Some(job) = self.get(organization_id, payroll_id, job_id).await? else {
             return Ok(false);
+        };
+
+        let assigned = self.employee_repository.fetch_by_job(job_id).await?;
+        if !assigned.is_empty() {
+            let Some(target_id) = reassign_to else {
+                return Err(AppError::conflict(format!(
+                    "job `{job_id}` is still assigned to {} employee(s)",
+                    assigned.len()
+                )));
+            };
+            if target_id == job_id {
+                return Err(AppError::validation(
+                    "reassign_to must name a different job",
+                ));
+            }
+            let target = self
+                .get(organization_id, payroll_id, target_id)
+                .await?
+                .ok_or_else(|| {
+                    AppError::not_found(format!(
+                        "job `{target_id}` not found for payroll `{payroll_id}`"
+                    ))
+                })?;
+
+            let moving = assigned
+                .iter()
+                .filter(|employee| employee.status != EmployeeStatus::Terminated)
+                .count() as u32;
+            if let Some(budget) = target.budgeted_headcount
+                && target.headcount_policy == HeadcountPolicy::Enforce
+            {
+                let filled = self
+                    .employee_repository
+                    .count_active_by_job(target_id)
+                    .await?;
+                if filled + moving > budget {
+                    return Err(AppError::conflict(format!(
+                        "job `{target_id}` has {} of {budget} budgeted seats free, {moving} needed",
+                        budget.saturating_sub(filled)
+                    )));
+                }
+            }
+
+            let today = Utc::now().date_naive();
+            let events = assigned
+                .iter()
+                .map(|employee| {
+                    let mut event = EmploymentEvent::new(
+                        Uuid::new_v4(),
+                        employee.id,
+                        EmploymentEventKind::JobChange,
+                        today,
+                        Utc::now(),
+                    );
+                    event.job_change = Some(JobChange {
+                        from_job_id: job_id,
+                        to_job_id: target_id,
+                        from_salary: job.salary,
+                        to_salary: target.salary,
+                    });
+                    event
+                })
+                .collect();
+            return self
+                .repository
+                .delete(
+                    job_id,
+                    Some(JobReassignment {
+                        to_job_id: target_id,
+                        events,
+                    }),
+                )
+                .await;
         }
 
-        self.repository.delete(job_id).await
+        self.repository.delete(job_id, None).await
     }
 
     async fn ensure_payroll_accessible(
@@ -152,6 +443,32 @@ impl JobService {
             .await
     }
 
+    async fn ensure_within_grade(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        grade_id: Uuid,
+        salary: f64,
+    ) -> AppResult<()> {
+        let grade = self
+            .grade_service
+            .get(organization_id, payroll_id, grade_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "grade `{grade_id}` not found for payroll `{payroll_id}`"
+                ))
+            })?;
+        if !grade.covers(salary) {
+            return Err(AppError::validation(format!(
+                "salary {salary} is outside grade `{}` band of {} to {}",
+                grade.code, grade.salary_min, grade.salary_max
+            )));
+        }
+
+        Ok(())
+    }
+
     fn normalize_title(value: &str) -> AppResult<String> {
         let trimmed = value.trim();
         if trimmed.is_empty() {
@@ -161,6 +478,49 @@ impl JobService {
         Ok(trimmed.to_string())
     }
 
+    /// Blank descriptions are stored as absent.
+    fn normalize_description(value: Option<String>) -> AppResult<Option<String>> {
+        let Some(description) = value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+        else {
+            return Ok(None);
+        };
+        if description.chars().count() > MAX_DESCRIPTION_CHARS {
+            return Err(AppError::validation(format!(
+                "description cannot exceed {MAX_DESCRIPTION_CHARS} characters"
+            )));
+        }
+
+        Ok(Some(description))
+    }
+
+    fn normalize_items(field: &str, items: Vec<String>) -> AppResult<Vec<String>> {
+        if items.len() > MAX_LIST_ITEMS {
+            return Err(AppError::validation(format!(
+                "{field} cannot have more than {MAX_LIST_ITEMS} entries"
+            )));
+        }
+
+        items
+            .into_iter()
+            .map(|item| {
+                let item = item.trim();
+                if item.is_empty() {
+                    return Err(AppError::validation(format!(
+                        "{field} entries cannot be empty"
+                    )));
+                }
+                if item.chars().count() > MAX_LIST_ITEM_CHARS {
+                    return Err(AppError::validation(format!(
+                        "{field} entries cannot exceed {MAX_LIST_ITEM_CHARS} characters"
+                    )));
+                }
+                Ok(item.to_string())
+            })
+            .collect()
+    }
+
     fn validate_salary(value: f64) -> AppResult<f64> {
         if value <= 0.0 {
             return Err(AppError::validation("salary must be greater than zero"));
@@ -168,4 +528,44 @@ impl JobService {
 
         Ok(value)
     }
+
+    /// Either bound may be left open, but `salary` must sit inside whichever are set.
+    fn validate_range(
+        salary: f64,
+        salary_min: Option<f64>,
+        salary_max: Option<f64>,
+    ) -> AppResult<()> {
+        if salary_min.is_some_and(|min| min <= 0.0) || salary_max.is_some_and(|max| max <= 0.0) {
+            return Err(AppError::validation(
+                "salary range bounds must be greater than zero",
+            ));
+        }
+        if let (Some(min), Some(max)) = (salary_min, salary_max)
+            && max < min
+        {
+            return Err(AppError::validation(
+                "salary_max cannot be below salary_min",
+            ));
+        }
+        if salary_min.is_some_and(|min| salary < min) || salary_max.is_some_and(|max| salary > max)
+        {
+            return Err(AppError::validation(format!(
+                "salary {salary} is outside the job's salary range"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Upper-cases a currency code, rejecting anything but three ASCII letters.
+pub(crate) fn normalize_currency(value: &str) -> AppResult<String> {
+    let code = value.trim().to_ascii_uppercase();
+    if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(AppError::validation(format!(
+            "currency `{value}` is not a three-letter ISO 4217 code"
+        )));
+    }
+
+    Ok(code)
 }
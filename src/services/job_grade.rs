@@ -0,0 +1,245 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    domain::job_grade::JobGrade,
+    error::{AppError, AppResult},
+    services::{job::JobRepository, payroll::PayrollService},
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateJobGradeParams {
+    pub code: String,
+    pub band: String,
+    pub level: u32,
+    pub salary_min: f64,
+    pub salary_max: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateJobGradeParams {
+    pub code: Option<String>,
+    pub band: Option<String>,
+    pub level: Option<u32>,
+    pub salary_min: Option<f64>,
+    pub salary_max: Option<f64>,
+}
+
+#[async_trait]
+pub trait JobGradeRepository: Send + Sync {
+    async fn insert(&self, grade: JobGrade) -> AppResult<JobGrade>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<JobGrade>>;
+    async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<JobGrade>>;
+    async fn update(&self, grade: JobGrade) -> AppResult<Option<JobGrade>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct JobGradeService {
+    repository: Arc<dyn JobGradeRepository>,
+    payroll_service: Arc<PayrollService>,
+    job_repository: Arc<dyn JobRepository>,
+}
+
+impl JobGradeService {
+    pub fn new(
+        repository: Arc<dyn JobGradeRepository>,
+        payroll_service: Arc<PayrollService>,
+        job_repository: Arc<dyn JobRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            payroll_service,
+            job_repository,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        params: CreateJobGradeParams,
+    ) -> AppResult<JobGrade> {
+        self.ensure_payroll_accessible(organization_id, payroll_id)
+            .await?;
+        let code = Self::normalize_field(&params.code, "grade code")?;
+        let band = Self::normalize_field(&params.band, "band")?;
+        Self::validate_band(params.salary_min, params.salary_max)?;
+        self.ensure_code_available(payroll_id, &code, None).await?;
+
+        let grade = JobGrade::new(
+            Uuid::new_v4(),
+            code,
+            band,
+            params.level,
+            params.salary_min,
+            params.salary_max,
+            payroll_id,
+        );
+        self.repository.insert(grade).await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        grade_id: Uuid,
+    ) -> AppResult<Option<JobGrade>> {
+        self.ensure_payroll_accessible(organization_id, payroll_id)
+            .await?;
+        let grade = self.repository.fetch(grade_id).await?;
+        Ok(grade.filter(|grade| grade.payroll_id == payroll_id))
+    }
+
+    /// Grades ordered by band, then level, then code.
+    pub async fn list(&self, organization_id: Uuid, payroll_id: Uuid) -> AppResult<Vec<JobGrade>> {
+        self.ensure_payroll_accessible(organization_id, payroll_id)
+            .await?;
+        let mut grades = self.repository.fetch_by_payroll(payroll_id).await?;
+        grades.sort_by(|a, b| {
+            a.band
+                .cmp(&b.band)
+                .then_with(|| a.level.cmp(&b.level))
+                .then_with(|| a.code.cmp(&b.code))
+        });
+        Ok(grades)
+    }
+
+    /// Narrowing the band is refused while a job assigned to the grade would fall outside it.
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        grade_id: Uuid,
+        params: UpdateJobGradeParams,
+    ) -> AppResult<Option<JobGrade>> {
+        if params.code.is_none()
+            && params.band.is_none()
+            && params.level.is_none()
+            && params.salary_min.is_none()
+            && params.salary_max.is_none()
+        {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        let Some(mut grade) = self.get(organization_id, payroll_id, grade_id).await? else {
+            return Ok(None);
+        };
+
+        if let Some(code) = params.code.as_deref() {
+            let code = Self::normalize_field(code, "grade code")?;
+            self.ensure_code_available(payroll_id, &code, Some(grade_id))
+                .await?;
+            grade.code = code;
+        }
+        if let Some(band) = params.band.as_deref() {
+            grade.band = Self::normalize_field(band, "band")?;
+        }
+        if let Some(level) = params.level {
+            grade.level = level;
+        }
+        grade.salary_min = params.salary_min.unwrap_or(grade.salary_min);
+        grade.salary_max = params.salary_max.unwrap_or(grade.salary_max);
+        Self::validate_band(grade.salary_min, grade.salary_max)?;
+
+        let outside: Vec<String> = self
+            .job_repository
+            .fetch_by_payroll(payroll_id)
+            .await?
+            .into_iter()
+            .filter(|job| job.grade_id == Some(grade_id) && !grade.covers(job.salary))
+            .map(|job| job.job_title)
+            .collect();
+        if !outside.is_empty() {
+            return Err(AppError::conflict(format!(
+                "salary band would exclude jobs assigned to grade `{}`: {}",
+                grade.code,
+                outside.join(", ")
+            )));
+        }
+
+        self.repository.update(grade).await
+    }
+
+    /// Refused while any job is still assigned to the grade.
+    pub async fn delete(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        grade_id: Uuid,
+    ) -> AppResult<bool> {
+        let Some(grade) = self.get(organization_id, payroll_id, grade_id).await? else {
+            return Ok(false);
+        };
+        let assigned = self
+            .job_repository
+            .fetch_by_payroll(payroll_id)
+            .await?
+            .iter()
+            .filter(|job| job.grade_id == Some(grade_id))
+            .count();
+        if assigned > 0 {
+            return Err(AppError::conflict(format!(
+                "grade `{}` is still assigned to {assigned} job(s)",
+                grade.code
+            )));
+        }
+
+        self.repository.delete(grade_id).await
+    }
+
+    async fn ensure_payroll_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+    ) -> AppResult<()> {
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await
+    }
+
+    async fn ensure_code_available(
+        &self,
+        payroll_id: Uuid,
+        code: &str,
+        except: Option<Uuid>,
+    ) -> AppResult<()> {
+        let taken = self
+            .repository
+            .fetch_by_payroll(payroll_id)
+            .await?
+            .iter()
+            .any(|grade| Some(grade.id) != except && grade.code.eq_ignore_ascii_case(code));
+        if taken {
+            return Err(AppError::conflict(format!(
+                "grade code `{code}` already exists in payroll `{payroll_id}`"
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn normalize_field(value: &str, field: &str) -> AppResult<String> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return Err(AppError::validation(format!("{field} cannot be empty")));
+        }
+
+        Ok(trimmed.to_string())
+    }
+
+    fn validate_band(salary_min: f64, salary_max: f64) -> AppResult<()> {
+        if salary_min <= 0.0 {
+            return Err(AppError::validation("salary_min must be greater than zero"));
+        }
+        if salary_max < salary_min {
+            return Err(AppError::validation(
+                "salary_max cannot be below salary_min",
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::error::AppResult;
+
+/// Lease guarding the scheduled purge of organizations whose deletion window has passed.
+pub const ORGANIZATION_DELETION_SWEEP_LEASE: &str = "organization-deletion-sweep";
+
+/// Lease guarding the scheduled application of approved salary adjustments.
+pub const SALARY_ADJUSTMENT_SWEEP_LEASE: &str = "salary-adjustment-sweep";
+
+/// Lease guarding the scheduled year-end carryover and expiry of leave balances.
+pub const LEAVE_CARRYOVER_SWEEP_LEASE: &str = "leave-carryover-sweep";
+
+#[async_trait]
+pub trait LeaseRepository: Send + Sync {
+    /// Atomically grants or renews `name` for `holder` until `until`, unless another holder's
+    /// lease is still running at `now`. Returns whether `holder` now holds it.
+    async fn acquire(
+        &self,
+        name: &str,
+        holder: &str,
+        now: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> AppResult<bool>;
+
+    /// Gives the lease up early; does nothing when `holder` does not hold it.
+    async fn release(&self, name: &str, holder: &str) -> AppResult<()>;
+}
+
+/// Coordinates background work across server instances so only one of them runs it at a time.
+///
+/// Each instance gets its own holder id. A holder keeps a lease by renewing it before `ttl`
+/// runs out; when it stops, another instance takes over once the lease expires.
+#[derive(Clone)]
+pub struct LeaseService {
+    repository: Arc<dyn LeaseRepository>,
+    holder: String,
+    ttl: Duration,
+}
+
+impl LeaseService {
+    pub fn new(repository: Arc<dyn LeaseRepository>, ttl: Duration) -> Self {
+        Self {
+            repository,
+            holder: Uuid::new_v4().to_string(),
+            ttl,
+        }
+    }
+
+    pub fn holder(&self) -> &str {
+        &self.holder
+    }
+
+    /// Takes or renews the lease; `false` means another instance holds it.
+    pub async fn try_acquire(&self, name: &str) -> AppResult<bool> {
+        let now = Utc::now();
+        self.repository
+            .acquire(name, &self.holder, now, now + self.ttl)
+            .await
+    }
+
+    pub async fn release(&self, name: &str) -> AppResult<()> {
+        self.repository.release(name, &self.holder).await
+    }
+}
@@ -0,0 +1,1081 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Datelike, Days, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        email::EmailMessage,
+        employee::{Employee, EmployeeStatus},
+        leave::{
+            BlackoutEnforcement, DAY_EPSILON, LeaveAdjustmentReason, LeaveBalance,
+            LeaveBalanceAdjustment, LeaveBlackout, LeaveCarryoverRule, LeavePayDays,
+            LeavePayPolicy, LeavePayTier, LeaveRequest, LeaveRequestStatus, LeaveType,
+        },
+        work_schedule::WorkExpectation,
+    },
+    error::{AppError, AppResult},
+    services::{
+        division::DivisionService, email::EmailSender, employee::EmployeeService,
+        payroll::PayrollService, timesheet::TimesheetService,
+    },
+};
+
+/// Employees are warned this many days before their carried-over days expire.
+pub const CARRYOVER_EXPIRY_NOTICE_DAYS: u64 = 30;
+
+#[derive(Debug, Clone)]
+pub struct CreateLeaveRequestParams {
+    pub leave_type: LeaveType,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub reason: Option<String>,
+    pub certificate_reference: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateLeaveBlackoutParams {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub enforcement: BlackoutEnforcement,
+    pub reason: Option<String>,
+}
+
+#[async_trait]
+pub trait LeaveRepository: Send + Sync {
+    async fn insert_request(&self, request: LeaveRequest) -> AppResult<LeaveRequest>;
+    async fn fetch_request(&self, id: Uuid) -> AppResult<Option<LeaveRequest>>;
+    /// Like `fetch_request`, but never served by a read replica, since it guards decisions.
+    async fn fetch_latest_request(&self, id: Uuid) -> AppResult<Option<LeaveRequest>>;
+    async fn fetch_requests_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<LeaveRequest>>;
+    async fn update_request(&self, request: LeaveRequest) -> AppResult<Option<LeaveRequest>>;
+    async fn fetch_balances(&self, employee_id: Uuid) -> AppResult<Vec<LeaveBalance>>;
+    /// One balance, never served by a read replica, since balances are updated from it.
+    async fn fetch_latest_balance(
+        &self,
+        employee_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeaveBalance>>;
+    /// Creates or replaces the employee's balance for `balance.leave_type`.
+    async fn upsert_balance(&self, balance: LeaveBalance) -> AppResult<LeaveBalance>;
+    /// Stores the decision on a request that is still pending. An approval of leave that
+    /// carries a balance takes its days off that balance, carried-over days first, in the same
+    /// step, and is refused with a conflict when the balance no longer covers them. `None`
+    /// when the request was decided meanwhile.
+    async fn decide_request(&self, request: LeaveRequest) -> AppResult<Option<LeaveRequest>>;
+    async fn fetch_pay_policy(
+        &self,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeavePayPolicy>>;
+    /// Creates or replaces the payroll's policy for `policy.leave_type`.
+    async fn upsert_pay_policy(&self, policy: LeavePayPolicy) -> AppResult<LeavePayPolicy>;
+    async fn fetch_carryover_rule(
+        &self,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeaveCarryoverRule>>;
+    /// Every configured carryover rule, across organizations.
+    async fn fetch_carryover_rules(&self) -> AppResult<Vec<LeaveCarryoverRule>>;
+    /// Creates or replaces the payroll's carryover rule for `rule.leave_type`.
+    async fn upsert_carryover_rule(
+        &self,
+        rule: LeaveCarryoverRule,
+    ) -> AppResult<LeaveCarryoverRule>;
+    async fn insert_adjustment(
+        &self,
+        adjustment: LeaveBalanceAdjustment,
+    ) -> AppResult<LeaveBalanceAdjustment>;
+    async fn fetch_adjustments_by_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<LeaveBalanceAdjustment>>;
+    async fn insert_blackout(&self, blackout: LeaveBlackout) -> AppResult<LeaveBlackout>;
+    async fn fetch_blackouts_by_division(&self, division_id: Uuid)
+    -> AppResult<Vec<LeaveBlackout>>;
+    async fn delete_blackout(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct LeaveService {
+    repository: Arc<dyn LeaveRepository>,
+    payroll_service: Arc<PayrollService>,
+    division_service: Arc<DivisionService>,
+    employee_service: Arc<EmployeeService>,
+    timesheet_service: Arc<TimesheetService>,
+    sender: Arc<dyn EmailSender>,
+}
+
+impl LeaveService {
+    pub fn new(
+        repository: Arc<dyn LeaveRepository>,
+        payroll_service: Arc<PayrollService>,
+        division_service: Arc<DivisionService>,
+        employee_service: Arc<EmployeeService>,
+        timesheet_service: Arc<TimesheetService>,
+        sender: Arc<dyn EmailSender>,
+    ) -> Self {
+        Self {
+            repository,
+            payroll_service,
+            division_service,
+            employee_service,
+            timesheet_service,
+            sender,
+        }
+    }
+
+    /// Balances for every paid leave type, zero where none has been granted.
+    pub async fn balances(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<LeaveBalance>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let stored = self.repository.fetch_balances(employee_id).await?;
+
+        Ok(LeaveType::PAID
+            .into_iter()
+            .map(|leave_type| {
+                stored
+                    .iter()
+                    .find(|balance| balance.leave_type == leave_type)
+                    .cloned()
+                    .unwrap_or_else(|| LeaveBalance::empty(employee_id, leave_type))
+            })
+            .collect())
+    }
+
+    /// Sets the days available for a paid leave type as of `today`, e.g. on the yearly grant. A
+    /// balance still in an earlier leave year is carried over first, so a grant made before the
+    /// carryover sweep runs is not capped by it; days carried over are kept, up to the new total.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn set_balance(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        leave_type: LeaveType,
+        available_days: f64,
+        today: NaiveDate,
+    ) -> AppResult<LeaveBalance> {
+        Self::ensure_carries_balance(leave_type)?;
+        if !available_days.is_finite() || available_days < 0.0 {
+            return Err(AppError::validation(
+                "available days must be a non-negative number",
+            ));
+        }
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+
+        let mut balance = match self.stored_balance(employee_id, leave_type).await? {
+            Some(mut balance) => {
+                if let Some(rule) = self
+                    .repository
+                    .fetch_carryover_rule(payroll_id, leave_type)
+                    .await?
+                {
+                    self.carry_into_year(&mut balance, &rule, today).await?;
+                }
+                balance
+            }
+            None => LeaveBalance::empty(employee_id, leave_type),
+        };
+        balance.available_days = available_days;
+        balance.carried_over_days = balance.carried_over_days.min(available_days);
+        balance.leave_year = Some(balance.leave_year.unwrap_or(today.year()).max(today.year()));
+        self.repository.upsert_balance(balance).await
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        params: CreateLeaveRequestParams,
+    ) -> AppResult<LeaveRequest> {
+        if params.end_date < params.start_date {
+            return Err(AppError::validation(
+                "leave end date cannot be before its start date",
+            ));
+        }
+        let reason = params
+            .reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+        let certificate_reference = params
+            .certificate_reference
+            .map(|reference| reference.trim().to_string())
+            .filter(|reference| !reference.is_empty());
+        if certificate_reference.is_some() && !params.leave_type.takes_certificate() {
+            return Err(AppError::validation(
+                "only sick and statutory leave requests take a certificate reference",
+            ));
+        }
+
+        let employee = self
+            .ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        if employee.status == EmployeeStatus::Terminated {
+            return Err(AppError::validation(
+                "terminated employees cannot request leave",
+            ));
+        }
+
+        let expectation = self
+            .expectation(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let days = working_days(&expectation, params.start_date, params.end_date);
+        if days <= 0.0 {
+            return Err(AppError::validation(
+                "the requested range contains no working days",
+            ));
+        }
+        if LeavePayPolicy::CONFIGURABLE.contains(&params.leave_type)
+            && let Some(max_days) = self
+                .pay_policy(organization_id, payroll_id, params.leave_type)
+                .await?
+                .max_days
+            && days > f64::from(max_days)
+        {
+            return Err(AppError::validation(format!(
+                "{} leave is limited to {max_days} working days per request; {days} requested",
+                params.leave_type.as_str()
+            )));
+        }
+
+        let requests = self
+            .repository
+            .fetch_requests_by_employee(employee_id)
+            .await?;
+        if let Some(existing) = requests.iter().find(|request| {
+            request.is_active() && request.overlaps(params.start_date, params.end_date)
+        }) {
+            return Err(AppError::conflict(format!(
+                "leave request `{}` already covers part of this range",
+                existing.id
+            )));
+        }
+
+        if params.leave_type.carries_balance() {
+            let available = self.available_days(employee_id, params.leave_type).await?;
+            if days > available + DAY_EPSILON {
+                return Err(AppError::validation(format!(
+                    "{days} {} days requested but only {available} available",
+                    params.leave_type.as_str()
+                )));
+            }
+        }
+
+        let mut blackout_id = None;
+        if params.leave_type.is_planned() {
+            let blackouts = self
+                .repository
+                .fetch_blackouts_by_division(division_id)
+                .await?;
+            let colliding: Vec<&LeaveBlackout> = blackouts
+                .iter()
+                .filter(|blackout| blackout.overlaps(params.start_date, params.end_date))
+                .collect();
+            if let Some(blackout) = colliding
+                .iter()
+                .find(|blackout| blackout.enforcement == BlackoutEnforcement::Reject)
+            {
+                return Err(AppError::validation(format!(
+                    "{} leave cannot be taken during the blackout from {}",
+                    params.leave_type.as_str(),
+                    blackout.describe()
+                )));
+            }
+            blackout_id = colliding.first().map(|blackout| blackout.id);
+        }
+
+        let mut request = LeaveRequest::new(
+            Uuid::new_v4(),
+            employee_id,
+            params.leave_type,
+            params.start_date,
+            params.end_date,
+            days,
+            reason,
+        );
+        request.certificate_reference = certificate_reference;
+        request.blackout_id = blackout_id;
+        self.repository.insert_request(request).await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        request_id: Uuid,
+    ) -> AppResult<Option<LeaveRequest>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let request = self.repository.fetch_request(request_id).await?;
+        Ok(request.filter(|request| request.employee_id == employee_id))
+    }
+
+    /// Lists the employee's leave requests, most recent start date first.
+    pub async fn list(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<LeaveRequest>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let mut requests = self
+            .repository
+            .fetch_requests_by_employee(employee_id)
+            .await?;
+        requests.sort_by(|a, b| {
+            b.start_date
+                .cmp(&a.start_date)
+                .then_with(|| b.requested_at.cmp(&a.requested_at))
+        });
+        Ok(requests)
+    }
+
+    /// Approves a pending request, deducting its days from the matching balance, carried-over
+    /// days first. Requests falling in a blackout period need `override_blackout`. Only the
+    /// employee's supervisor or the manager of their division may approve.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn approve(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        request_id: Uuid,
+        approver_id: Uuid,
+        note: Option<String>,
+        override_blackout: bool,
+    ) -> AppResult<Option<LeaveRequest>> {
+        let Some(request) = self
+            .reviewable(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                request_id,
+                approver_id,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+        if let Some(blackout_id) = request.blackout_id
+            && !override_blackout
+        {
+            return Err(AppError::conflict(format!(
+                "leave request `{}` falls in blackout `{blackout_id}`; approve it with override_blackout to proceed",
+                request.id
+            )));
+        }
+
+        if request.leave_type.carries_balance() {
+            let available = self.available_days(employee_id, request.leave_type).await?;
+            if request.days > available + DAY_EPSILON {
+                return Err(AppError::conflict(format!(
+                    "only {available} {} days remain; the request needs {}",
+                    request.leave_type.as_str(),
+                    request.days
+                )));
+            }
+        }
+
+        self.decide(request, LeaveRequestStatus::Approved, approver_id, note)
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reject(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        request_id: Uuid,
+        approver_id: Uuid,
+        note: Option<String>,
+    ) -> AppResult<Option<LeaveRequest>> {
+        let Some(request) = self
+            .reviewable(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                request_id,
+                approver_id,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        self.decide(request, LeaveRequestStatus::Rejected, approver_id, note)
+            .await
+    }
+
+    /// Approved unpaid working days falling between `from` and `to`, inclusive.
+    ///
+    /// Payroll runs use this to prorate pay for the period.
+    pub async fn unpaid_leave_days(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<f64> {
+        let requests = self
+            .list(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let expectation = self
+            .expectation(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+
+        Ok(requests
+            .iter()
+            .filter(|request| {
+                request.leave_type == LeaveType::Unpaid
+                    && request.status == LeaveRequestStatus::Approved
+                    && request.overlaps(from, to)
+            })
+            .map(|request| {
+                working_days(
+                    &expectation,
+                    request.start_date.max(from),
+                    request.end_date.min(to),
+                )
+            })
+            .sum())
+    }
+
+    /// The payroll's pay policy for `leave_type`, unlimited full pay when none has been set.
+    pub async fn pay_policy(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<LeavePayPolicy> {
+        Self::ensure_pay_configurable(leave_type)?;
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        Ok(self
+            .repository
+            .fetch_pay_policy(payroll_id, leave_type)
+            .await?
+            .unwrap_or_else(|| LeavePayPolicy::full_pay(payroll_id, leave_type)))
+    }
+
+    /// Replaces the payroll's pay tiers for `leave_type`, e.g. 100% from day 1 and 70% from
+    /// day 4, and the longest spell a request may cover. The first tier has to start on day 1.
+    pub async fn set_pay_policy(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+        mut tiers: Vec<LeavePayTier>,
+        max_days: Option<u32>,
+    ) -> AppResult<LeavePayPolicy> {
+        Self::ensure_pay_configurable(leave_type)?;
+        if max_days == Some(0) {
+            return Err(AppError::validation("max days must be at least 1"));
+        }
+        tiers.sort_by_key(|tier| tier.from_day);
+        if tiers.first().is_none_or(|tier| tier.from_day != 1) {
+            return Err(AppError::validation(
+                "the first pay tier must start on day 1",
+            ));
+        }
+        if tiers
+            .windows(2)
+            .any(|pair| pair[0].from_day == pair[1].from_day)
+        {
+            return Err(AppError::validation(
+                "pay tiers cannot start on the same day",
+            ));
+        }
+        if tiers.iter().any(|tier| {
+            !tier.pay_percentage.is_finite() || !(0.0..=100.0).contains(&tier.pay_percentage)
+        }) {
+            return Err(AppError::validation(
+                "pay percentages must be between 0 and 100",
+            ));
+        }
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        self.repository
+            .upsert_pay_policy(LeavePayPolicy {
+                payroll_id,
+                leave_type,
+                tiers,
+                max_days,
+            })
+            .await
+    }
+
+    /// Approved working days of tiered leave falling between `from` and `to`, inclusive,
+    /// grouped by the pay percentage the payroll's policy gives them.
+    ///
+    /// Payroll runs use this to pay leave spanning the period.
+    pub async fn leave_pay_days(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<LeavePayDays>> {
+        if to < from {
+            return Err(AppError::validation(
+                "period end date cannot be before its start date",
+            ));
+        }
+        let requests = self
+            .list(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let expectation = self
+            .expectation(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+
+        let mut summary: Vec<LeavePayDays> = Vec::new();
+        for leave_type in LeavePayPolicy::CONFIGURABLE {
+            let policy = self
+                .pay_policy(organization_id, payroll_id, leave_type)
+                .await?;
+            for request in requests.iter().filter(|request| {
+                request.leave_type == leave_type
+                    && request.status == LeaveRequestStatus::Approved
+                    && request.overlaps(from, to)
+            }) {
+                let spell = working_dates(&expectation, request.start_date, request.end_date);
+                for (day, date) in (1..).zip(spell) {
+                    if !(from..=to).contains(&date) {
+                        continue;
+                    }
+                    let pay_percentage = policy.pay_percentage(day);
+                    match summary.iter_mut().find(|entry| {
+                        entry.leave_type == leave_type && entry.pay_percentage == pay_percentage
+                    }) {
+                        Some(entry) => entry.days += 1.0,
+                        None => summary.push(LeavePayDays {
+                            leave_type,
+                            pay_percentage,
+                            days: 1.0,
+                        }),
+                    }
+                }
+            }
+        }
+
+        summary.sort_by(|a, b| {
+            a.leave_type
+                .as_str()
+                .cmp(b.leave_type.as_str())
+                .then_with(|| b.pay_percentage.total_cmp(&a.pay_percentage))
+        });
+        Ok(summary)
+    }
+
+    /// Declares dates during which the division's employees cannot freely take vacation or
+    /// unpaid leave. Requests made earlier are left as they are.
+    pub async fn create_blackout(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        params: CreateLeaveBlackoutParams,
+    ) -> AppResult<LeaveBlackout> {
+        if params.end_date < params.start_date {
+            return Err(AppError::validation(
+                "blackout end date cannot be before its start date",
+            ));
+        }
+        self.ensure_division_accessible(organization_id, payroll_id, division_id)
+            .await?;
+
+        self.repository
+            .insert_blackout(LeaveBlackout {
+                id: Uuid::new_v4(),
+                division_id,
+                start_date: params.start_date,
+                end_date: params.end_date,
+                enforcement: params.enforcement,
+                reason: params
+                    .reason
+                    .map(|reason| reason.trim().to_string())
+                    .filter(|reason| !reason.is_empty()),
+                created_at: Utc::now(),
+            })
+            .await
+    }
+
+    /// Lists the division's blackout periods, earliest first.
+    pub async fn list_blackouts(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+    ) -> AppResult<Vec<LeaveBlackout>> {
+        self.ensure_division_accessible(organization_id, payroll_id, division_id)
+            .await?;
+        let mut blackouts = self
+            .repository
+            .fetch_blackouts_by_division(division_id)
+            .await?;
+        blackouts.sort_by_key(|blackout| (blackout.start_date, blackout.end_date));
+        Ok(blackouts)
+    }
+
+    pub async fn delete_blackout(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        blackout_id: Uuid,
+    ) -> AppResult<bool> {
+        let blackouts = self
+            .list_blackouts(organization_id, payroll_id, division_id)
+            .await?;
+        if !blackouts.iter().any(|blackout| blackout.id == blackout_id) {
+            return Ok(false);
+        }
+
+        self.repository.delete_blackout(blackout_id).await
+    }
+
+    /// The payroll's carryover rule for `leave_type`, carrying everything over with no expiry
+    /// when none has been set.
+    pub async fn carryover_rule(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<LeaveCarryoverRule> {
+        Self::ensure_carries_balance(leave_type)?;
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        Ok(self
+            .repository
+            .fetch_carryover_rule(payroll_id, leave_type)
+            .await?
+            .unwrap_or_else(|| {
+                LeaveCarryoverRule::unlimited(organization_id, payroll_id, leave_type)
+            }))
+    }
+
+    /// Replaces the payroll's carryover limit and expiry for `leave_type`.
+    pub async fn set_carryover_rule(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        leave_type: LeaveType,
+        max_days: Option<f64>,
+        expires_after_days: Option<u32>,
+    ) -> AppResult<LeaveCarryoverRule> {
+        Self::ensure_carries_balance(leave_type)?;
+        if max_days.is_some_and(|days| !days.is_finite() || days < 0.0) {
+            return Err(AppError::validation(
+                "max days must be a non-negative number",
+            ));
+        }
+        if expires_after_days == Some(0) {
+            return Err(AppError::validation(
+                "expires after days must be at least 1",
+            ));
+        }
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        self.repository
+            .upsert_carryover_rule(LeaveCarryoverRule {
+                organization_id,
+                payroll_id,
+                leave_type,
+                max_days,
+                expires_after_days,
+            })
+            .await
+    }
+
+    /// Changes the carryover job made to the employee's balances, most recent first.
+    pub async fn adjustments(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<LeaveBalanceAdjustment>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let mut adjustments = self
+            .repository
+            .fetch_adjustments_by_employee(employee_id)
+            .await?;
+        adjustments.sort_by(|a, b| {
+            b.effective_on
+                .cmp(&a.effective_on)
+                .then_with(|| b.created_at.cmp(&a.created_at))
+        });
+        Ok(adjustments)
+    }
+
+    /// Applies the payroll's carryover rules as of `today`; see [`Self::carry_over_due`].
+    pub async fn carry_over(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        today: NaiveDate,
+    ) -> AppResult<Vec<LeaveBalanceAdjustment>> {
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        let mut adjustments = Vec::new();
+        for leave_type in LeaveType::PAID {
+            if let Some(rule) = self
+                .repository
+                .fetch_carryover_rule(payroll_id, leave_type)
+                .await?
+            {
+                adjustments.extend(self.apply_carryover_rule(&rule, today).await?);
+            }
+        }
+        Ok(adjustments)
+    }
+
+    /// Runs every configured carryover rule as of `today`: balances still in an earlier leave
+    /// year are capped to the carryover limit, carried-over days past their expiry are
+    /// forfeited, and employees whose carried-over days expire within
+    /// [`CARRYOVER_EXPIRY_NOTICE_DAYS`] are emailed once. Each change to a balance is recorded
+    /// as an adjustment, and running it again the same day changes nothing.
+    ///
+    /// The scheduled carryover sweep calls this daily.
+    pub async fn carry_over_due(&self, today: NaiveDate) -> AppResult<Vec<LeaveBalanceAdjustment>> {
+        let mut adjustments = Vec::new();
+        for rule in self.repository.fetch_carryover_rules().await? {
+            adjustments.extend(self.apply_carryover_rule(&rule, today).await?);
+        }
+        Ok(adjustments)
+    }
+
+    async fn apply_carryover_rule(
+        &self,
+        rule: &LeaveCarryoverRule,
+        today: NaiveDate,
+    ) -> AppResult<Vec<LeaveBalanceAdjustment>> {
+        let employees = self
+            .employee_service
+            .search(rule.organization_id, Some(rule.payroll_id), None)
+            .await?;
+
+        let mut adjustments = Vec::new();
+        for employee in employees
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+        {
+            let Some(mut balance) = self.stored_balance(employee.id, rule.leave_type).await? else {
+                continue;
+            };
+            let before = balance.clone();
+
+            adjustments.extend(self.carry_into_year(&mut balance, rule, today).await?);
+
+            match balance.carryover_expires_on {
+                Some(expires_on) if expires_on <= today => {
+                    let days_before = balance.available_days;
+                    balance.available_days = (days_before - balance.carried_over_days).max(0.0);
+                    balance.carried_over_days = 0.0;
+                    balance.carryover_expires_on = None;
+                    adjustments.push(
+                        self.record_adjustment(
+                            &balance,
+                            LeaveAdjustmentReason::CarryoverExpired,
+                            expires_on,
+                            days_before,
+                        )
+                        .await?,
+                    );
+                }
+                Some(expires_on)
+                    if balance.expiry_notified_at.is_none()
+                        && balance.carried_over_days > DAY_EPSILON
+                        && today
+                            .checked_add_days(Days::new(CARRYOVER_EXPIRY_NOTICE_DAYS))
+                            .is_some_and(|notice_from| expires_on <= notice_from) =>
+                {
+                    if let Some(email) = &employee.email {
+                        self.sender
+                            .send(EmailMessage::new(
+                                email,
+                                "Carried-over leave about to expire",
+                                format!(
+                                    "Hello {},\n\n{} of your carried-over {} days expire on {expires_on}. Take them before then or they will be forfeited.\n",
+                                    employee.first_name,
+                                    balance.carried_over_days,
+                                    rule.leave_type.as_str()
+                                ),
+                            ))
+                            .await?;
+                        balance.expiry_notified_at = Some(Utc::now());
+                    }
+                }
+                _ => {}
+            }
+
+            if balance != before {
+                self.repository.upsert_balance(balance).await?;
+            }
+        }
+        Ok(adjustments)
+    }
+
+    /// Caps a balance still in an earlier leave year than `today`'s to the rule's carryover
+    /// limit and moves it into the current year, recording the change. Balances already in the
+    /// current year are left alone.
+    async fn carry_into_year(
+        &self,
+        balance: &mut LeaveBalance,
+        rule: &LeaveCarryoverRule,
+        today: NaiveDate,
+    ) -> AppResult<Option<LeaveBalanceAdjustment>> {
+        if balance.leave_year.is_some_and(|year| year >= today.year()) {
+            return Ok(None);
+        }
+        let year_start = NaiveDate::from_ymd_opt(today.year(), 1, 1)
+            .ok_or_else(|| AppError::internal("leave year does not start on a valid date"))?;
+
+        let days_before = balance.available_days;
+        let carried = rule
+            .max_days
+            .map_or(days_before, |max_days| days_before.min(max_days));
+        balance.available_days = carried;
+        balance.carried_over_days = carried;
+        balance.leave_year = Some(today.year());
+        balance.carryover_expires_on = rule
+            .expires_after_days
+            .filter(|_| carried > DAY_EPSILON)
+            .and_then(|days| year_start.checked_add_days(Days::new(u64::from(days))));
+        balance.expiry_notified_at = None;
+        self.record_adjustment(
+            balance,
+            LeaveAdjustmentReason::YearEndCarryover,
+            year_start,
+            days_before,
+        )
+        .await
+        .map(Some)
+    }
+
+    async fn record_adjustment(
+        &self,
+        balance: &LeaveBalance,
+        reason: LeaveAdjustmentReason,
+        effective_on: NaiveDate,
+        days_before: f64,
+    ) -> AppResult<LeaveBalanceAdjustment> {
+        self.repository
+            .insert_adjustment(LeaveBalanceAdjustment {
+                id: Uuid::new_v4(),
+                employee_id: balance.employee_id,
+                leave_type: balance.leave_type,
+                reason,
+                effective_on,
+                days_before,
+                days_after: balance.available_days,
+                created_at: Utc::now(),
+            })
+            .await
+    }
+
+    /// The pending request, once `approver_id` is confirmed as someone who may decide it.
+    async fn reviewable(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        request_id: Uuid,
+        approver_id: Uuid,
+    ) -> AppResult<Option<LeaveRequest>> {
+        let employee = self
+            .ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let Some(request) = self
+            .repository
+            .fetch_latest_request(request_id)
+            .await?
+            .filter(|request| request.employee_id == employee_id)
+        else {
+            return Ok(None);
+        };
+        self.timesheet_service
+            .ensure_manager_of(organization_id, payroll_id, &employee, approver_id)
+            .await?;
+        Self::ensure_pending(&request)?;
+
+        Ok(Some(request))
+    }
+
+    async fn decide(
+        &self,
+        mut request: LeaveRequest,
+        status: LeaveRequestStatus,
+        approver_id: Uuid,
+        note: Option<String>,
+    ) -> AppResult<Option<LeaveRequest>> {
+        request.status = status;
+        request.decided_by = Some(approver_id);
+        request.decision_note = note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        request.decided_at = Some(Utc::now());
+
+        // Only a request still pending is decided, with an approval's days deducted in the
+        // same step, so two reviewers racing each other cannot both decide it.
+        let request_id = request.id;
+        match self.repository.decide_request(request).await? {
+            Some(decided) => Ok(Some(decided)),
+            None => Err(AppError::conflict(format!(
+                "leave request `{request_id}` has already been decided"
+            ))),
+        }
+    }
+
+    async fn available_days(&self, employee_id: Uuid, leave_type: LeaveType) -> AppResult<f64> {
+        Ok(self
+            .stored_balance(employee_id, leave_type)
+            .await?
+            .map_or(0.0, |balance| balance.available_days))
+    }
+
+    async fn stored_balance(
+        &self,
+        employee_id: Uuid,
+        leave_type: LeaveType,
+    ) -> AppResult<Option<LeaveBalance>> {
+        self.repository
+            .fetch_latest_balance(employee_id, leave_type)
+            .await
+    }
+
+    async fn expectation(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<WorkExpectation> {
+        self.employee_service
+            .work_expectation(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .ok_or_else(|| employee_not_found(division_id, payroll_id, employee_id))
+    }
+
+    async fn ensure_division_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+    ) -> AppResult<()> {
+        self.division_service
+            .get(organization_id, payroll_id, division_id)
+            .await?
+            .map(|_| ())
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "division `{division_id}` not found for payroll `{payroll_id}`"
+                ))
+            })
+    }
+
+    async fn ensure_employee_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Employee> {
+        self.employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .ok_or_else(|| employee_not_found(division_id, payroll_id, employee_id))
+    }
+
+    fn ensure_carries_balance(leave_type: LeaveType) -> AppResult<()> {
+        if leave_type.carries_balance() {
+            Ok(())
+        } else {
+            Err(AppError::validation(format!(
+                "{} leave does not carry a balance",
+                leave_type.as_str()
+            )))
+        }
+    }
+
+    fn ensure_pay_configurable(leave_type: LeaveType) -> AppResult<()> {
+        if LeavePayPolicy::CONFIGURABLE.contains(&leave_type) {
+            Ok(())
+        } else {
+            Err(AppError::validation(format!(
+                "{} leave pay cannot be tiered",
+                leave_type.as_str()
+            )))
+        }
+    }
+
+    fn ensure_pending(request: &LeaveRequest) -> AppResult<()> {
+        if request.status == LeaveRequestStatus::Pending {
+            Ok(())
+        } else {
+            Err(AppError::conflict(format!(
+                "leave request `{}` has already been decided",
+                request.id
+            )))
+        }
+    }
+}
+
+fn employee_not_found(division_id: Uuid, payroll_id: Uuid, employee_id: Uuid) -> AppError {
+    AppError::not_found(format!(
+        "employee `{employee_id}` not found for division `{division_id}` in payroll `{payroll_id}`"
+    ))
+}
+
+/// Working days between both dates, inclusive. Employees without a schedule
+/// work the organization's working week from Monday.
+fn working_days(expectation: &WorkExpectation, start: NaiveDate, end: NaiveDate) -> f64 {
+    working_dates(expectation, start, end).count() as f64
+}
+
+fn working_dates(
+    expectation: &WorkExpectation,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> impl Iterator<Item = NaiveDate> + '_ {
+    start
+        .iter_days()
+        .take_while(move |date| *date <= end)
+        .filter(|date| expectation.works_on(date.weekday()))
+}
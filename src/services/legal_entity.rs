@@ -0,0 +1,274 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        legal_entity::{DebtorAccount, LegalEntity},
+        organization::LegalIdentity,
+    },
+    error::{AppError, AppResult},
+    services::{bank::BankService, organization::OrganizationService, payroll::PayrollService},
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateLegalEntityParams {
+    pub name: String,
+    pub legal_identity: LegalIdentity,
+    pub debtor_account: Option<DebtorAccount>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateLegalEntityParams {
+    pub name: Option<String>,
+    /// For each of these, `Some(None)` clears the stored value.
+    pub legal_name: Option<Option<String>>,
+    pub tax_id: Option<Option<String>>,
+    pub registration_number: Option<Option<String>>,
+    pub address: Option<Option<String>>,
+    pub debtor_account: Option<Option<DebtorAccount>>,
+}
+
+#[async_trait]
+pub trait LegalEntityRepository: Send + Sync {
+    async fn insert(&self, legal_entity: LegalEntity) -> AppResult<LegalEntity>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<LegalEntity>>;
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<LegalEntity>>;
+    async fn update(&self, legal_entity: LegalEntity) -> AppResult<Option<LegalEntity>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+/// The registered companies of an organization's group, each reporting its payrolls under its
+/// own tax identity.
+#[derive(Clone)]
+pub struct LegalEntityService {
+    repository: Arc<dyn LegalEntityRepository>,
+    organization_service: Arc<OrganizationService>,
+    payroll_service: Arc<PayrollService>,
+    bank_service: Arc<BankService>,
+}
+
+impl LegalEntityService {
+    pub fn new(
+        repository: Arc<dyn LegalEntityRepository>,
+        organization_service: Arc<OrganizationService>,
+        payroll_service: Arc<PayrollService>,
+        bank_service: Arc<BankService>,
+    ) -> Self {
+        Self {
+            repository,
+            organization_service,
+            payroll_service,
+            bank_service,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        params: CreateLegalEntityParams,
+    ) -> AppResult<LegalEntity> {
+        let name = normalize_name(&params.name)?;
+        let legal_identity = OrganizationService::normalize_legal_identity(params.legal_identity)?;
+        self.ensure_organization_exists(organization_id).await?;
+        self.ensure_tax_id_available(organization_id, &legal_identity, None)
+            .await?;
+
+        let mut legal_entity =
+            LegalEntity::new(Uuid::new_v4(), organization_id, name, legal_identity);
+        legal_entity.debtor_account = match params.debtor_account {
+            Some(account) => Some(
+                self.normalize_debtor_account(organization_id, account)
+                    .await?,
+            ),
+            None => None,
+        };
+        self.repository.insert(legal_entity).await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        legal_entity_id: Uuid,
+    ) -> AppResult<Option<LegalEntity>> {
+        let legal_entity = self.repository.fetch(legal_entity_id).await?;
+        Ok(legal_entity.filter(|legal_entity| legal_entity.organization_id == organization_id))
+    }
+
+    /// Legal entities ordered by name.
+    pub async fn list(&self, organization_id: Uuid) -> AppResult<Vec<LegalEntity>> {
+        self.ensure_organization_exists(organization_id).await?;
+        let mut legal_entities = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?;
+        legal_entities.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(legal_entities)
+    }
+
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        legal_entity_id: Uuid,
+        params: UpdateLegalEntityParams,
+    ) -> AppResult<Option<LegalEntity>> {
+        let touches_legal_identity = params.legal_name.is_some()
+            || params.tax_id.is_some()
+            || params.registration_number.is_some()
+            || params.address.is_some();
+        if params.name.is_none() && !touches_legal_identity && params.debtor_account.is_none() {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        let Some(mut legal_entity) = self.get(organization_id, legal_entity_id).await? else {
+            return Ok(None);
+        };
+
+        if let Some(name) = params.name.as_deref() {
+            legal_entity.name = normalize_name(name)?;
+        }
+        if touches_legal_identity {
+            let current = legal_entity.legal_identity;
+            legal_entity.legal_identity =
+                OrganizationService::normalize_legal_identity(LegalIdentity {
+                    legal_name: params.legal_name.unwrap_or(current.legal_name),
+                    tax_id: params.tax_id.unwrap_or(current.tax_id),
+                    registration_number: params
+                        .registration_number
+                        .unwrap_or(current.registration_number),
+                    address: params.address.unwrap_or(current.address),
+                })?;
+            self.ensure_tax_id_available(
+                organization_id,
+                &legal_entity.legal_identity,
+                Some(legal_entity_id),
+            )
+            .await?;
+        }
+        if let Some(debtor_account) = params.debtor_account {
+            legal_entity.debtor_account = match debtor_account {
+                Some(account) => Some(
+                    self.normalize_debtor_account(organization_id, account)
+                        .await?,
+                ),
+                None => None,
+            };
+        }
+
+        self.repository.update(legal_entity).await
+    }
+
+    /// Refused while payrolls report under the legal entity; move them first.
+    pub async fn delete(&self, organization_id: Uuid, legal_entity_id: Uuid) -> AppResult<bool> {
+        let Some(legal_entity) = self.get(organization_id, legal_entity_id).await? else {
+            return Ok(false);
+        };
+        let assigned = self
+            .payroll_service
+            .list(organization_id)
+            .await?
+            .iter()
+            .filter(|payroll| payroll.legal_entity_id == Some(legal_entity_id))
+            .count();
+        if assigned > 0 {
+            return Err(AppError::conflict(format!(
+                "legal entity `{}` still has {assigned} payroll{} reporting under it",
+                legal_entity.name,
+                if assigned == 1 { "" } else { "s" }
+            )));
+        }
+
+        self.repository.delete(legal_entity_id).await
+    }
+
+    /// Two entities of a group cannot be registered under the same tax ID.
+    async fn ensure_tax_id_available(
+        &self,
+        organization_id: Uuid,
+        legal_identity: &LegalIdentity,
+        except: Option<Uuid>,
+    ) -> AppResult<()> {
+        let Some(tax_id) = legal_identity.tax_id.as_deref() else {
+            return Ok(());
+        };
+        let taken = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?
+            .iter()
+            .any(|legal_entity| {
+                Some(legal_entity.id) != except
+                    && legal_entity.legal_identity.tax_id.as_deref() == Some(tax_id)
+            });
+        if taken {
+            return Err(AppError::conflict(format!(
+                "tax ID `{tax_id}` is already registered to another legal entity of organization `{organization_id}`"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the account against the rule of the organization bank it is held at.
+    async fn normalize_debtor_account(
+        &self,
+        organization_id: Uuid,
+        account: DebtorAccount,
+    ) -> AppResult<DebtorAccount> {
+        let bank = self
+            .bank_service
+            .get(organization_id, account.bank_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "bank `{}` not found for organization `{organization_id}`",
+                    account.bank_id
+                ))
+            })?;
+        let mut account_number = account.account_number.trim().to_string();
+        if account_number.is_empty() {
+            return Err(AppError::validation(
+                "debtor account number cannot be empty",
+            ));
+        }
+        if let Some(rule) = &bank.account_rule {
+            account_number = rule.check(&account_number).map_err(|reason| {
+                AppError::validation(format!(
+                    "debtor account `{account_number}` {reason} for bank `{}`",
+                    bank.name
+                ))
+            })?;
+        }
+
+        Ok(DebtorAccount {
+            bank_id: bank.id,
+            account_number,
+        })
+    }
+
+    async fn ensure_organization_exists(&self, organization_id: Uuid) -> AppResult<()> {
+        let exists = self
+            .organization_service
+            .get(organization_id)
+            .await?
+            .is_some();
+
+        if exists {
+            Ok(())
+        } else {
+            Err(AppError::not_found(format!(
+                "organization `{organization_id}` not found"
+            )))
+        }
+    }
+}
+
+fn normalize_name(value: &str) -> AppResult<String> {
+    let name = value.trim();
+    if name.is_empty() {
+        return Err(AppError::validation("legal entity name cannot be empty"));
+    }
+
+    Ok(name.to_string())
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        email::EmailMessage,
+        membership::{MemberRole, Membership},
+    },
+    error::{AppError, AppResult},
+    services::{email::EmailSender, employee::normalize_email, organization::OrganizationService},
+};
+
+#[derive(Debug, Clone)]
+pub struct InviteMemberParams {
+    pub email: String,
+    pub role: MemberRole,
+}
+
+#[async_trait]
+pub trait MembershipRepository: Send + Sync {
+    async fn insert(&self, membership: Membership) -> AppResult<Membership>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Membership>>;
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Membership>>;
+    async fn update(&self, membership: Membership) -> AppResult<Option<Membership>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+/// Who belongs to an organization and with which role. Keeps the tenant's ownership
+/// explicit: once an organization has members, at least one of them is an owner.
+///
+/// The roles are recorded here for the authorization layer to check against; requests are
+/// not yet authenticated as a member, so nothing is enforced on the other endpoints.
+#[derive(Clone)]
+pub struct MembershipService {
+    repository: Arc<dyn MembershipRepository>,
+    organization_service: Arc<OrganizationService>,
+    sender: Arc<dyn EmailSender>,
+}
+
+impl MembershipService {
+    pub fn new(
+        repository: Arc<dyn MembershipRepository>,
+        organization_service: Arc<OrganizationService>,
+        sender: Arc<dyn EmailSender>,
+    ) -> Self {
+        Self {
+            repository,
+            organization_service,
+            sender,
+        }
+    }
+
+    /// Adds the address to the organization and emails it an invitation. The first member
+    /// of an organization has to be its owner.
+    pub async fn invite(
+        &self,
+        organization_id: Uuid,
+        params: InviteMemberParams,
+    ) -> AppResult<Membership> {
+        let email = normalize_email(&params.email)?;
+        let organization = self
+            .organization_service
+            .get(organization_id)
+            .await?
+            .ok_or_else(|| organization_not_found(organization_id))?;
+
+        let members = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?;
+        if members.iter().any(|member| member.email == email) {
+            return Err(AppError::conflict(format!(
+                "`{email}` is already a member of organization `{organization_id}`"
+            )));
+        }
+        if members.is_empty() && params.role != MemberRole::Owner {
+            return Err(AppError::validation(
+                "the first member of an organization must be its owner",
+            ));
+        }
+
+        let membership = self
+            .repository
+            .insert(Membership::new(
+                Uuid::new_v4(),
+                organization_id,
+                email,
+                params.role,
+            ))
+            .await?;
+
+        self.sender
+            .send(EmailMessage::new(
+                membership.email.clone(),
+                format!("You have been invited to {}", organization.name),
+                format!(
+                    "Hello,\n\nYou have been added to {} as {}.\n",
+                    organization.name,
+                    role_label(membership.role)
+                ),
+            ))
+            .await?;
+
+        Ok(membership)
+    }
+
+    /// Members ordered by role, owners first, then by email.
+    pub async fn list(&self, organization_id: Uuid) -> AppResult<Vec<Membership>> {
+        self.ensure_organization_exists(organization_id).await?;
+        let mut members = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?;
+        members.sort_by(|a, b| a.role.cmp(&b.role).then_with(|| a.email.cmp(&b.email)));
+        Ok(members)
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        member_id: Uuid,
+    ) -> AppResult<Option<Membership>> {
+        let membership = self.repository.fetch(member_id).await?;
+        Ok(membership.filter(|membership| membership.organization_id == organization_id))
+    }
+
+    /// Changes the member's role; the last owner cannot be demoted.
+    pub async fn update_role(
+        &self,
+        organization_id: Uuid,
+        member_id: Uuid,
+        role: MemberRole,
+    ) -> AppResult<Option<Membership>> {
+        let Some(mut membership) = self.get(organization_id, member_id).await? else {
+            return Ok(None);
+        };
+        if membership.role == role {
+            return Ok(Some(membership));
+        }
+        if membership.role == MemberRole::Owner {
+            self.ensure_other_owner(&membership, "demoted").await?;
+        }
+
+        membership.role = role;
+        self.repository.update(membership).await
+    }
+
+    /// Removes the member; the last owner has to hand ownership over first.
+    pub async fn remove(&self, organization_id: Uuid, member_id: Uuid) -> AppResult<bool> {
+        let Some(membership) = self.get(organization_id, member_id).await? else {
+            return Ok(false);
+        };
+        if membership.role == MemberRole::Owner {
+            self.ensure_other_owner(&membership, "removed").await?;
+        }
+
+        self.repository.delete(member_id).await
+    }
+
+    async fn ensure_other_owner(&self, owner: &Membership, action: &str) -> AppResult<()> {
+        let other_owner = self
+            .repository
+            .fetch_by_organization(owner.organization_id)
+            .await?
+            .iter()
+            .any(|member| member.id != owner.id && member.role == MemberRole::Owner);
+        if !other_owner {
+            return Err(AppError::conflict(format!(
+                "`{}` is the last owner of organization `{}` and cannot be {action}",
+                owner.email, owner.organization_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_organization_exists(&self, organization_id: Uuid) -> AppResult<()> {
+        self.organization_service
+            .get(organization_id)
+            .await?
+            .map(|_| ())
+            .ok_or_else(|| organization_not_found(organization_id))
+    }
+}
+
+fn organization_not_found(organization_id: Uuid) -> AppError {
+    AppError::not_found(format!("organization `{organization_id}` not found"))
+}
+
+fn role_label(role: MemberRole) -> &'static str {
+    match role {
+        MemberRole::Owner => "an owner",
+        MemberRole::Admin => "an administrator",
+        MemberRole::Member => "a member",
+    }
+}
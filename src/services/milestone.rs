@@ -0,0 +1,93 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::EmployeeStatus,
+        milestone::{DivisionMilestones, MAX_MILESTONE_WINDOW_DAYS, Milestone},
+    },
+    error::{AppError, AppResult},
+    services::{division::DivisionService, employee::EmployeeService},
+};
+
+#[derive(Clone)]
+pub struct MilestoneService {
+    employee_service: Arc<EmployeeService>,
+    division_service: Arc<DivisionService>,
+}
+
+impl MilestoneService {
+    pub fn new(
+        employee_service: Arc<EmployeeService>,
+        division_service: Arc<DivisionService>,
+    ) -> Self {
+        Self {
+            employee_service,
+            division_service,
+        }
+    }
+
+    /// Birthdays and work anniversaries of employees still on the books between `from` and
+    /// `to`, grouped by division. Divisions without any are left out.
+    pub async fn upcoming(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+        division_id: Option<Uuid>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<DivisionMilestones>> {
+        if to < from {
+            return Err(AppError::validation("to cannot be before from"));
+        }
+        if (to - from).num_days() >= MAX_MILESTONE_WINDOW_DAYS {
+            return Err(AppError::validation(format!(
+                "the window cannot span more than {MAX_MILESTONE_WINDOW_DAYS} days"
+            )));
+        }
+
+        let employees = self
+            .employee_service
+            .search(organization_id, payroll_id, division_id)
+            .await?;
+        let mut by_division: HashMap<Uuid, Vec<Milestone>> = HashMap::new();
+        for employee in employees
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+        {
+            by_division
+                .entry(employee.division_id)
+                .or_default()
+                .extend(Milestone::upcoming(employee, from, to));
+        }
+
+        let mut groups: Vec<DivisionMilestones> = self
+            .division_service
+            .search(organization_id, payroll_id)
+            .await?
+            .into_iter()
+            .filter_map(|division| {
+                let mut milestones = by_division.remove(&division.id)?;
+                if milestones.is_empty() {
+                    return None;
+                }
+                milestones.sort_by(|a, b| {
+                    a.date
+                        .cmp(&b.date)
+                        .then_with(|| a.last_name.cmp(&b.last_name))
+                        .then_with(|| a.first_name.cmp(&b.first_name))
+                });
+                Some(DivisionMilestones {
+                    division_id: division.id,
+                    division_name: division.name,
+                    milestones,
+                })
+            })
+            .collect();
+        groups.sort_by(|a, b| a.division_name.cmp(&b.division_name));
+
+        Ok(groups)
+    }
+}
@@ -1,54 +1,137 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::{
-    domain::organization::Organization,
+    domain::{
+        blob::Blob,
+        organization::{LegalIdentity, Organization, compile_budget_code_pattern},
+    },
     error::{AppError, AppResult},
+    services::{
+        blob::BlobStore,
+        cache::{CacheStore, LOOKUP_CACHE_TTL, TtlCache},
+    },
 };
 
+/// Largest accepted organization logo, in bytes.
+pub const MAX_LOGO_BYTES: usize = 512 * 1024;
+
+/// Media types accepted for organization logos.
+pub const LOGO_CONTENT_TYPES: [&str; 3] = ["image/png", "image/jpeg", "image/webp"];
+
 #[derive(Debug, Clone)]
 pub struct CreateOrganizationParams {
     pub name: String,
+    pub budget_code_pattern: Option<String>,
+    pub legal_identity: LegalIdentity,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct UpdateOrganizationParams {
     pub name: Option<String>,
+    pub budget_code_pattern: Option<Option<String>>,
+    /// For each of these, `Some(None)` clears the stored value.
+    pub legal_name: Option<Option<String>>,
+    pub tax_id: Option<Option<String>>,
+    pub registration_number: Option<Option<String>>,
+    pub address: Option<Option<String>>,
+}
+
+impl UpdateOrganizationParams {
+    fn touches_legal_identity(&self) -> bool {
+        self.legal_name.is_some()
+            || self.tax_id.is_some()
+            || self.registration_number.is_some()
+            || self.address.is_some()
+    }
 }
 
 #[async_trait]
 pub trait OrganizationRepository: Send + Sync {
-    async fn insert(&self, id: Uuid, name: String) -> AppResult<Organization>;
+    async fn insert(
+        &self,
+        id: Uuid,
+        name: String,
+        budget_code_pattern: Option<String>,
+        legal_identity: LegalIdentity,
+    ) -> AppResult<Organization>;
+    /// Never served by a read replica, since the lookup cache is filled from it.
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Organization>>;
     async fn fetch_all(&self) -> AppResult<Vec<Organization>>;
-    async fn update(&self, id: Uuid, name: Option<String>) -> AppResult<Option<Organization>>;
-    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+    async fn update(
+        &self,
+        id: Uuid,
+        name: Option<String>,
+        budget_code_pattern: Option<Option<String>>,
+        legal_identity: Option<LegalIdentity>,
+    ) -> AppResult<Option<Organization>>;
+    async fn set_logo_url(
+        &self,
+        id: Uuid,
+        logo_url: Option<String>,
+    ) -> AppResult<Option<Organization>>;
+    async fn set_deleted_at(
+        &self,
+        id: Uuid,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Organization>>;
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Organization>>;
 }
 
 #[derive(Clone)]
 pub struct OrganizationService {
     repository: Arc<dyn OrganizationRepository>,
+    blob_store: Arc<dyn BlobStore>,
+    /// Organizations by id, as every nested route looks its organization up first.
+    cache: Arc<TtlCache<Uuid, Organization>>,
 }
 
 impl OrganizationService {
-    pub fn new(repository: Arc<dyn OrganizationRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<dyn OrganizationRepository>,
+        blob_store: Arc<dyn BlobStore>,
+        cache_store: Arc<dyn CacheStore>,
+    ) -> Self {
+        Self {
+            repository,
+            blob_store,
+            cache: Arc::new(TtlCache::new(cache_store, "organization", LOOKUP_CACHE_TTL)),
+        }
     }
 
     pub async fn create(&self, params: CreateOrganizationParams) -> AppResult<Organization> {
         let name = Self::normalize_name(&params.name)?;
+        let budget_code_pattern = params
+            .budget_code_pattern
+            .as_deref()
+            .map(Self::normalize_budget_code_pattern)
+            .transpose()?;
+        let legal_identity = Self::normalize_legal_identity(params.legal_identity)?;
         let id = Uuid::new_v4();
-        self.repository.insert(id, name).await
+        self.repository
+            .insert(id, name, budget_code_pattern, legal_identity)
+            .await
     }
 
+    /// Soft-deleted organizations are not found.
     pub async fn get(&self, id: Uuid) -> AppResult<Option<Organization>> {
-        self.repository.fetch(id).await
+        let organization = self
+            .cache
+            .get_or_load(id, || self.repository.fetch(id))
+            .await?;
+        Ok(organization.filter(|organization| organization.deleted_at.is_none()))
     }
 
     pub async fn list(&self) -> AppResult<Vec<Organization>> {
         let mut organizations = self.repository.fetch_all().await?;
+        organizations.retain(|organization| organization.deleted_at.is_none());
         organizations.sort_by(|a, b| a.name.cmp(&b.name));
         Ok(organizations)
     }
@@ -58,7 +141,9 @@ impl OrganizationService {
         id: Uuid,
         params: UpdateOrganizationParams,
     ) -> AppResult<Option<Organization>> {
-        if params.name.is_none() {
+        let touches_legal_identity = params.touches_legal_identity();
+        if params.name.is_none() && params.budget_code_pattern.is_none() && !touches_legal_identity
+        {
             return Err(AppError::validation("no fields supplied for update"));
         }
 
@@ -67,12 +152,176 @@ impl OrganizationService {
             .as_deref()
             .map(Self::normalize_name)
             .transpose()?;
+        let budget_code_pattern = params
+            .budget_code_pattern
+            .map(|pattern| {
+                pattern
+                    .as_deref()
+                    .map(Self::normalize_budget_code_pattern)
+                    .transpose()
+            })
+            .transpose()?;
+        let Some(existing) = self.get(id).await? else {
+            return Ok(None);
+        };
+        let legal_identity = if touches_legal_identity {
+            let current = existing.legal_identity;
+            Some(Self::normalize_legal_identity(LegalIdentity {
+                legal_name: params.legal_name.unwrap_or(current.legal_name),
+                tax_id: params.tax_id.unwrap_or(current.tax_id),
+                registration_number: params
+                    .registration_number
+                    .unwrap_or(current.registration_number),
+                address: params.address.unwrap_or(current.address),
+            })?)
+        } else {
+            None
+        };
+
+        self.cache
+            .invalidating(
+                id,
+                self.repository
+                    .update(id, name, budget_code_pattern, legal_identity),
+            )
+            .await
+    }
+
+    /// Tombstones the organization, keeping the time it was first deleted at.
+    pub(crate) async fn soft_delete(
+        &self,
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    ) -> AppResult<Option<Organization>> {
+        let Some(organization) = self.repository.fetch(id).await? else {
+            return Ok(None);
+        };
+        if organization.deleted_at.is_some() {
+            return Ok(Some(organization));
+        }
+
+        self.cache
+            .invalidating(id, self.repository.set_deleted_at(id, Some(deleted_at)))
+            .await
+    }
+
+    /// Looks up a soft-deleted organization; `None` when it does not exist or is not deleted.
+    pub async fn get_deleted(&self, id: Uuid) -> AppResult<Option<Organization>> {
+        let organization = self.repository.fetch(id).await?;
+        Ok(organization.filter(|organization| organization.deleted_at.is_some()))
+    }
+
+    pub(crate) async fn restore(&self, id: Uuid) -> AppResult<Option<Organization>> {
+        if self.get_deleted(id).await?.is_none() {
+            return Ok(None);
+        }
+
+        self.cache
+            .invalidating(id, self.repository.set_deleted_at(id, None))
+            .await
+    }
+
+    /// Makes the organization read-only while keeping all of its records.
+    pub async fn archive(&self, id: Uuid) -> AppResult<Option<Organization>> {
+        let Some(organization) = self.get(id).await? else {
+            return Ok(None);
+        };
+        if organization.archived_at.is_some() {
+            return Err(archived(id));
+        }
+
+        self.cache
+            .invalidating(id, self.repository.set_archived_at(id, Some(Utc::now())))
+            .await
+    }
 
-        self.repository.update(id, name).await
+    pub async fn reactivate(&self, id: Uuid) -> AppResult<Option<Organization>> {
+        let Some(organization) = self.get(id).await? else {
+            return Ok(None);
+        };
+        if organization.archived_at.is_none() {
+            return Err(AppError::conflict(format!(
+                "organization `{id}` is not archived"
+            )));
+        }
+
+        self.cache
+            .invalidating(id, self.repository.set_archived_at(id, None))
+            .await
+    }
+
+    /// Fails with a conflict when the organization is archived; unknown organizations pass so
+    /// the caller can report them as not found.
+    pub async fn ensure_writable(&self, id: Uuid) -> AppResult<()> {
+        match self.get(id).await? {
+            Some(organization) if organization.archived_at.is_some() => Err(archived(id)),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn upload_logo(&self, id: Uuid, logo: Blob) -> AppResult<Option<Organization>> {
+        let logo = Self::validate_logo(logo)?;
+        if self.get(id).await?.is_none() {
+            return Ok(None);
+        }
+
+        self.blob_store.put(&Self::logo_key(id), logo).await?;
+        self.cache
+            .invalidating(
+                id,
+                self.repository
+                    .set_logo_url(id, Some(format!("/organizations/{id}/logo"))),
+            )
+            .await
+    }
+
+    pub async fn logo(&self, id: Uuid) -> AppResult<Option<Blob>> {
+        if self.get(id).await?.is_none() {
+            return Ok(None);
+        }
+
+        self.blob_store.get(&Self::logo_key(id)).await
+    }
+
+    /// Removes the logo; `false` when the organization had none.
+    pub async fn delete_logo(&self, id: Uuid) -> AppResult<bool> {
+        let Some(organization) = self.get(id).await? else {
+            return Ok(false);
+        };
+        if organization.logo_url.is_none() {
+            return Ok(false);
+        }
+
+        self.blob_store.delete(&Self::logo_key(id)).await?;
+        self.cache
+            .invalidating(id, self.repository.set_logo_url(id, None))
+            .await?;
+        Ok(true)
+    }
+
+    fn logo_key(id: Uuid) -> String {
+        format!("organization_logo_{id}")
     }
 
-    pub async fn delete(&self, id: Uuid) -> AppResult<bool> {
-        self.repository.delete(id).await
+    fn validate_logo(logo: Blob) -> AppResult<Blob> {
+        if !LOGO_CONTENT_TYPES.contains(&logo.content_type.as_str()) {
+            return Err(AppError::validation(format!(
+                "logo content type must be one of {}",
+                LOGO_CONTENT_TYPES.join(", ")
+            )));
+        }
+
+        if logo.data.is_empty() {
+            return Err(AppError::validation("logo cannot be empty"));
+        }
+
+        if logo.data.len() > MAX_LOGO_BYTES {
+            return Err(AppError::validation(format!(
+                "logo cannot exceed {MAX_LOGO_BYTES} bytes"
+            )));
+        }
+
+        Ok(logo)
     }
 
     fn normalize_name(value: &str) -> AppResult<String> {
@@ -83,4 +332,85 @@ impl OrganizationService {
 
         Ok(name.to_string())
     }
+
+    pub(crate) fn normalize_legal_identity(identity: LegalIdentity) -> AppResult<LegalIdentity> {
+        let required = |value: Option<String>, field: &str| {
+            value
+                .map(|value| {
+                    let value = value.trim().to_string();
+                    if value.is_empty() {
+                        Err(AppError::validation(format!("{field} cannot be empty")))
+                    } else {
+                        Ok(value)
+                    }
+                })
+                .transpose()
+        };
+
+        Ok(LegalIdentity {
+            legal_name: required(identity.legal_name, "legal name")?,
+            tax_id: identity
+                .tax_id
+                .as_deref()
+                .map(|value| Self::normalize_identifier(value, "tax ID", 5..=20))
+                .transpose()?,
+            registration_number: identity
+                .registration_number
+                .as_deref()
+                .map(|value| Self::normalize_identifier(value, "registration number", 1..=30))
+                .transpose()?,
+            address: required(identity.address, "address")?,
+        })
+    }
+
+    /// Upper-cases a registry identifier and drops its spaces. Letters and digits may be
+    /// grouped with `-`, `.` or `/`, and the letters and digits are counted against `length`.
+    fn normalize_identifier(
+        value: &str,
+        field: &str,
+        length: std::ops::RangeInclusive<usize>,
+    ) -> AppResult<String> {
+        let identifier: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let significant = identifier
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .count();
+        let valid = identifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '/'));
+        if !valid || !length.contains(&significant) {
+            return Err(AppError::validation(format!(
+                "{field} `{}` must be {} to {} letters or digits, optionally separated by `-`, `.` or `/`",
+                value.trim(),
+                length.start(),
+                length.end()
+            )));
+        }
+
+        Ok(identifier)
+    }
+
+    fn normalize_budget_code_pattern(value: &str) -> AppResult<String> {
+        let pattern = value.trim();
+        if pattern.is_empty() {
+            return Err(AppError::validation("budget code pattern cannot be empty"));
+        }
+        compile_budget_code_pattern(pattern).map_err(|error| {
+            AppError::validation(format!(
+                "budget code pattern `{pattern}` is not a valid regular expression: {error}"
+            ))
+        })?;
+
+        Ok(pattern.to_string())
+    }
+}
+
+fn archived(id: Uuid) -> AppError {
+    AppError::conflict(format!(
+        "organization `{id}` is archived and read-only; reactivate it first"
+    ))
 }
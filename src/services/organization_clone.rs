@@ -0,0 +1,242 @@
+use std::{collections::HashMap, sync::Arc};
+
+use uuid::Uuid;
+
+use crate::{
+    domain::organization::{LegalIdentity, Organization},
+    error::{AppError, AppResult},
+    services::{
+        bank::{BankService, CreateBankParams},
+        division::{CreateDivisionParams, DivisionService},
+        job::{CreateJobParams, JobService},
+        job_grade::{CreateJobGradeParams, JobGradeService},
+        organization::{CreateOrganizationParams, OrganizationService},
+        organization_settings::{OrganizationSettingsService, UpdateOrganizationSettingsParams},
+        payroll::{CreatePayrollParams, PayrollService},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct CloneOrganizationParams {
+    pub name: String,
+    /// Legal identity of the new entity; nothing is copied from the template.
+    pub legal_identity: LegalIdentity,
+}
+
+/// The new organization and how much of the template's structure went into it.
+#[derive(Debug, Clone)]
+pub struct OrganizationClone {
+    pub organization: Organization,
+    pub payrolls: usize,
+    pub job_grades: usize,
+    pub jobs: usize,
+    pub divisions: usize,
+    pub banks: usize,
+}
+
+/// Bootstraps an organization from another one's structure: settings, payrolls with their
+/// job grades, jobs and division tree, and banks. Employees and everything attached to them
+/// stay behind.
+#[derive(Clone)]
+pub struct OrganizationCloneService {
+    organization_service: Arc<OrganizationService>,
+    settings_service: Arc<OrganizationSettingsService>,
+    payroll_service: Arc<PayrollService>,
+    job_grade_service: Arc<JobGradeService>,
+    job_service: Arc<JobService>,
+    division_service: Arc<DivisionService>,
+    bank_service: Arc<BankService>,
+}
+
+impl OrganizationCloneService {
+    pub fn new(
+        organization_service: Arc<OrganizationService>,
+        settings_service: Arc<OrganizationSettingsService>,
+        payroll_service: Arc<PayrollService>,
+        job_grade_service: Arc<JobGradeService>,
+        job_service: Arc<JobService>,
+        division_service: Arc<DivisionService>,
+        bank_service: Arc<BankService>,
+    ) -> Self {
+        Self {
+            organization_service,
+            settings_service,
+            payroll_service,
+            job_grade_service,
+            job_service,
+            division_service,
+            bank_service,
+        }
+    }
+
+    /// Copies `template_id` into a new organization; `None` when the template does not exist.
+    /// Every record goes through the same validation as when it is created by hand.
+    pub async fn clone_organization(
+        &self,
+        template_id: Uuid,
+        params: CloneOrganizationParams,
+    ) -> AppResult<Option<OrganizationClone>> {
+        let Some(template) = self.organization_service.get(template_id).await? else {
+            return Ok(None);
+        };
+
+        let organization = self
+            .organization_service
+            .create(CreateOrganizationParams {
+                name: params.name,
+                budget_code_pattern: template.budget_code_pattern.clone(),
+                legal_identity: params.legal_identity,
+            })
+            .await?;
+        let organization_id = organization.id;
+
+        let settings = self.settings_service.get(template_id).await?;
+        self.settings_service
+            .update(
+                organization_id,
+                UpdateOrganizationSettingsParams {
+                    default_currency: Some(settings.default_currency),
+                    locale: Some(settings.locale),
+                    fiscal_year_start: Some(settings.fiscal_year_start),
+                    working_days_per_week: Some(settings.working_days_per_week),
+                    rounding: Some(settings.rounding),
+                },
+            )
+            .await?;
+
+        let mut summary = OrganizationClone {
+            organization,
+            payrolls: 0,
+            job_grades: 0,
+            jobs: 0,
+            divisions: 0,
+            banks: 0,
+        };
+
+        for payroll in self.payroll_service.list(template_id).await? {
+            let copy = self
+                .payroll_service
+                .create(
+                    organization_id,
+                    CreatePayrollParams {
+                        name: payroll.name,
+                        description: payroll.description,
+                        blocks_unverified_accounts: payroll.blocks_unverified_accounts,
+                        // Legal entities carry their own registrations and are not copied.
+                        legal_entity_id: None,
+                    },
+                )
+                .await?;
+            summary.payrolls += 1;
+
+            let mut grade_ids = HashMap::new();
+            for grade in self.job_grade_service.list(template_id, payroll.id).await? {
+                let grade_copy = self
+                    .job_grade_service
+                    .create(
+                        organization_id,
+                        copy.id,
+                        CreateJobGradeParams {
+                            code: grade.code,
+                            band: grade.band,
+                            level: grade.level,
+                            salary_min: grade.salary_min,
+                            salary_max: grade.salary_max,
+                        },
+                    )
+                    .await?;
+                grade_ids.insert(grade.id, grade_copy.id);
+                summary.job_grades += 1;
+            }
+
+            for job in self.job_service.list(template_id, payroll.id).await? {
+                self.job_service
+                    .create(
+                        organization_id,
+                        copy.id,
+                        CreateJobParams {
+                            job_title: job.job_title,
+                            description: job.description,
+                            responsibilities: job.responsibilities,
+                            qualifications: job.qualifications,
+                            salary: job.salary,
+                            pay_basis: job.pay_basis,
+                            currency: job.currency,
+                            salary_min: job.salary_min,
+                            salary_max: job.salary_max,
+                            grade_id: job.grade_id.and_then(|id| grade_ids.get(&id).copied()),
+                            budgeted_headcount: job.budgeted_headcount,
+                            headcount_policy: job.headcount_policy,
+                        },
+                    )
+                    .await?;
+                summary.jobs += 1;
+            }
+
+            // Parents have to exist before their children, so keep passing over the divisions
+            // left until every one found its copied parent.
+            let mut pending = self.division_service.list(template_id, payroll.id).await?;
+            let mut division_ids = HashMap::new();
+            while !pending.is_empty() {
+                let (ready, waiting): (Vec<_>, Vec<_>) =
+                    pending.into_iter().partition(|division| {
+                        division
+                            .parent_division_id
+                            .is_none_or(|parent| division_ids.contains_key(&parent))
+                    });
+                if ready.is_empty() {
+                    return Err(AppError::internal(format!(
+                        "divisions of payroll `{}` do not form a tree",
+                        payroll.id
+                    )));
+                }
+
+                for division in ready {
+                    let division_copy = self
+                        .division_service
+                        .create(
+                            organization_id,
+                            copy.id,
+                            CreateDivisionParams {
+                                name: division.name,
+                                description: division.description,
+                                budget_code: division.budget_code,
+                                parent_division_id: division
+                                    .parent_division_id
+                                    .and_then(|id| division_ids.get(&id).copied()),
+                                headcount_budget: division.headcount_budget,
+                                region: division.region,
+                            },
+                        )
+                        .await?;
+                    division_ids.insert(division.id, division_copy.id);
+                    summary.divisions += 1;
+                }
+                pending = waiting;
+            }
+        }
+
+        for bank in self.bank_service.list(template_id).await? {
+            // Linked banks inherit their codes from the catalog entry again.
+            let inherited = bank.catalog_id.is_some();
+            self.bank_service
+                .create(
+                    organization_id,
+                    CreateBankParams {
+                        name: bank.name,
+                        swift_code: bank.swift_code.filter(|_| !inherited),
+                        routing_number: bank.routing_number.filter(|_| !inherited),
+                        country: bank.country.filter(|_| !inherited),
+                        address: bank.address,
+                        account_rule: bank.account_rule,
+                        payment_format: bank.payment_format,
+                        catalog_id: bank.catalog_id,
+                    },
+                )
+                .await?;
+            summary.banks += 1;
+        }
+
+        Ok(Some(summary))
+    }
+}
@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{organization::Organization, organization_deletion::OrganizationDeletion},
+    error::{AppError, AppResult},
+    services::{bank::BankService, organization::OrganizationService, payroll::PayrollService},
+};
+
+/// Default time an organization deletion can still be cancelled.
+pub const DEFAULT_DELETION_GRACE_PERIOD_HOURS: i64 = 72;
+
+#[derive(Debug, Clone)]
+pub struct RequestOrganizationDeletionParams {
+    /// Must repeat the organization's name to confirm the deletion is intentional.
+    pub acknowledgement: String,
+}
+
+#[async_trait]
+pub trait OrganizationDeletionRepository: Send + Sync {
+    async fn insert(
+        &self,
+        organization_id: Uuid,
+        requested_at: DateTime<Utc>,
+        scheduled_for: DateTime<Utc>,
+    ) -> AppResult<OrganizationDeletion>;
+    async fn fetch(&self, organization_id: Uuid) -> AppResult<Option<OrganizationDeletion>>;
+    async fn fetch_due(&self, now: DateTime<Utc>) -> AppResult<Vec<OrganizationDeletion>>;
+    async fn delete(&self, organization_id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct OrganizationDeletionService {
+    repository: Arc<dyn OrganizationDeletionRepository>,
+    organization_service: Arc<OrganizationService>,
+    payroll_service: Arc<PayrollService>,
+    bank_service: Arc<BankService>,
+    grace_period: Duration,
+}
+
+impl OrganizationDeletionService {
+    pub fn new(
+        repository: Arc<dyn OrganizationDeletionRepository>,
+        organization_service: Arc<OrganizationService>,
+        payroll_service: Arc<PayrollService>,
+        bank_service: Arc<BankService>,
+        grace_period: Duration,
+    ) -> Self {
+        Self {
+            repository,
+            organization_service,
+            payroll_service,
+            bank_service,
+            grace_period,
+        }
+    }
+
+    pub async fn request(
+        &self,
+        organization_id: Uuid,
+        params: RequestOrganizationDeletionParams,
+    ) -> AppResult<OrganizationDeletion> {
+        let organization = self
+            .organization_service
+            .get(organization_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!("organization `{organization_id}` not found"))
+            })?;
+
+        if params.acknowledgement.trim() != organization.name {
+            return Err(AppError::validation(
+                "acknowledgement must match the organization name",
+            ));
+        }
+
+        if let Some(pending) = self.repository.fetch(organization_id).await? {
+            return Err(AppError::conflict(format!(
+                "organization `{organization_id}` is already scheduled for deletion at {}",
+                pending.scheduled_for.to_rfc3339()
+            )));
+        }
+
+        let requested_at = Utc::now();
+        self.repository
+            .insert(
+                organization_id,
+                requested_at,
+                requested_at + self.grace_period,
+            )
+            .await
+    }
+
+    pub async fn get(&self, organization_id: Uuid) -> AppResult<Option<OrganizationDeletion>> {
+        self.repository.fetch(organization_id).await
+    }
+
+    pub async fn cancel(&self, organization_id: Uuid) -> AppResult<bool> {
+        self.repository.delete(organization_id).await
+    }
+
+    /// Soft-deletes every organization whose cancellation window has elapsed by `now`, then
+    /// archives its payrolls and banks. The pending deletion is only cleared once the cascade
+    /// finished, so an interrupted sweep picks it up again on the next run.
+    pub async fn purge_due(&self, now: DateTime<Utc>) -> AppResult<Vec<Uuid>> {
+        let mut purged = Vec::new();
+        for deletion in self.repository.fetch_due(now).await? {
+            if !deletion.is_due(now) {
+                continue;
+            }
+
+            let organization_id = deletion.organization_id;
+            let Some(organization) = self
+                .organization_service
+                .soft_delete(organization_id, now)
+                .await?
+            else {
+                self.repository.delete(organization_id).await?;
+                continue;
+            };
+            let deleted_at = organization.deleted_at.unwrap_or(now);
+            self.payroll_service
+                .archive_organization(organization_id, deleted_at)
+                .await?;
+            self.bank_service
+                .archive_organization(organization_id, deleted_at)
+                .await?;
+            self.repository.delete(organization_id).await?;
+            purged.push(organization_id);
+        }
+
+        Ok(purged)
+    }
+
+    /// Brings a soft-deleted organization back together with its payrolls and banks.
+    pub async fn restore(&self, organization_id: Uuid) -> AppResult<Option<Organization>> {
+        if self
+            .organization_service
+            .get_deleted(organization_id)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        self.payroll_service
+            .restore_organization(organization_id)
+            .await?;
+        self.bank_service
+            .restore_organization(organization_id)
+            .await?;
+        self.organization_service.restore(organization_id).await
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    domain::organization_settings::{FiscalYearStart, OrganizationSettings, RoundingRule},
+    error::{AppError, AppResult},
+    services::{job::normalize_currency, organization::OrganizationService},
+};
+
+/// Largest accepted rounding increment.
+const MAX_ROUNDING_INCREMENT: f64 = 1000.0;
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateOrganizationSettingsParams {
+    /// `Some(None)` clears the operating currency.
+    pub default_currency: Option<Option<String>>,
+    pub locale: Option<String>,
+    pub fiscal_year_start: Option<FiscalYearStart>,
+    pub working_days_per_week: Option<u8>,
+    pub rounding: Option<RoundingRule>,
+}
+
+#[async_trait]
+pub trait OrganizationSettingsRepository: Send + Sync {
+    async fn fetch(&self, organization_id: Uuid) -> AppResult<Option<OrganizationSettings>>;
+    async fn upsert(&self, settings: OrganizationSettings) -> AppResult<OrganizationSettings>;
+}
+
+#[derive(Clone)]
+pub struct OrganizationSettingsService {
+    repository: Arc<dyn OrganizationSettingsRepository>,
+    organization_service: Arc<OrganizationService>,
+}
+
+impl OrganizationSettingsService {
+    pub fn new(
+        repository: Arc<dyn OrganizationSettingsRepository>,
+        organization_service: Arc<OrganizationService>,
+    ) -> Self {
+        Self {
+            repository,
+            organization_service,
+        }
+    }
+
+    /// The organization's settings, the standard ones until any have been saved.
+    pub async fn get(&self, organization_id: Uuid) -> AppResult<OrganizationSettings> {
+        self.ensure_organization_exists(organization_id).await?;
+        self.current(organization_id).await
+    }
+
+    /// Changes the supplied settings and keeps the rest.
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        params: UpdateOrganizationSettingsParams,
+    ) -> AppResult<OrganizationSettings> {
+        if params.default_currency.is_none()
+            && params.locale.is_none()
+            && params.fiscal_year_start.is_none()
+            && params.working_days_per_week.is_none()
+            && params.rounding.is_none()
+        {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+        self.ensure_organization_exists(organization_id).await?;
+
+        let mut settings = self.current(organization_id).await?;
+        if let Some(currency) = params.default_currency {
+            settings.default_currency = currency.as_deref().map(normalize_currency).transpose()?;
+        }
+        if let Some(locale) = params.locale {
+            settings.locale = normalize_locale(&locale)?;
+        }
+        if let Some(start) = params.fiscal_year_start {
+            if !start.is_valid() {
+                return Err(AppError::validation(format!(
+                    "fiscal year cannot start on {:02}-{:02}",
+                    start.month, start.day
+                )));
+            }
+            settings.fiscal_year_start = start;
+        }
+        if let Some(days) = params.working_days_per_week {
+            if !(1..=7).contains(&days) {
+                return Err(AppError::validation(
+                    "working days per week must be between 1 and 7",
+                ));
+            }
+            settings.working_days_per_week = days;
+        }
+        if let Some(rounding) = params.rounding {
+            let cents = rounding.increment * 100.0;
+            if !rounding.increment.is_finite()
+                || rounding.increment < 0.01
+                || rounding.increment > MAX_ROUNDING_INCREMENT
+                || (cents - cents.round()).abs() > 1e-9
+            {
+                return Err(AppError::validation(format!(
+                    "rounding increment must be a whole number of cents between 0.01 and {MAX_ROUNDING_INCREMENT}"
+                )));
+            }
+            settings.rounding = rounding;
+        }
+
+        self.repository.upsert(settings).await
+    }
+
+    /// Settings for calculations on data already known to belong to the organization.
+    pub(crate) async fn current(&self, organization_id: Uuid) -> AppResult<OrganizationSettings> {
+        Ok(self
+            .repository
+            .fetch(organization_id)
+            .await?
+            .unwrap_or_else(|| OrganizationSettings::standard(organization_id)))
+    }
+
+    async fn ensure_organization_exists(&self, organization_id: Uuid) -> AppResult<()> {
+        match self.organization_service.get(organization_id).await? {
+            Some(_) => Ok(()),
+            None => Err(AppError::not_found(format!(
+                "organization `{organization_id}` not found"
+            ))),
+        }
+    }
+}
+
+/// Accepts `language` or `language-REGION` tags, e.g. `es` or `es-mx`, and returns them in
+/// canonical case.
+fn normalize_locale(value: &str) -> AppResult<String> {
+    let invalid = || AppError::validation(format!("locale `{value}` is not a language tag"));
+    let mut parts = value.trim().split(['-', '_']);
+    let language = parts.next().unwrap_or_default();
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(invalid());
+    }
+    let region = parts.next();
+    if parts.next().is_some() {
+        return Err(invalid());
+    }
+
+    match region {
+        None => Ok(language.to_ascii_lowercase()),
+        Some(region) if region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()) => {
+            Ok(format!(
+                "{}-{}",
+                language.to_ascii_lowercase(),
+                region.to_ascii_uppercase()
+            ))
+        }
+        Some(_) => Err(invalid()),
+    }
+}
@@ -0,0 +1,404 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::{Employee, EmployeeStatus},
+        overtime::{
+            OvertimeDay, OvertimePay, OvertimePolicy, OvertimeRequest, OvertimeRequestStatus,
+            OvertimeWarning, OvertimeWarningReport,
+        },
+    },
+    error::{AppError, AppResult},
+    services::{employee::EmployeeService, payroll::PayrollService, timesheet::TimesheetService},
+};
+
+const MAX_DAILY_HOURS: f64 = 24.0;
+
+#[derive(Debug, Clone)]
+pub struct CreateOvertimeRequestParams {
+    pub date: NaiveDate,
+    pub hours: f64,
+    pub reason: Option<String>,
+}
+
+#[async_trait]
+pub trait OvertimeRepository: Send + Sync {
+    async fn fetch_policy(&self, payroll_id: Uuid) -> AppResult<Option<OvertimePolicy>>;
+    async fn upsert_policy(&self, policy: OvertimePolicy) -> AppResult<OvertimePolicy>;
+    async fn insert_request(&self, request: OvertimeRequest) -> AppResult<OvertimeRequest>;
+    async fn fetch_request(&self, id: Uuid) -> AppResult<Option<OvertimeRequest>>;
+    async fn fetch_requests_by_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<OvertimeRequest>>;
+    async fn update_request(&self, request: OvertimeRequest) -> AppResult<Option<OvertimeRequest>>;
+}
+
+/// Overtime requests, their approval, and the split of paid hours between the ordinary rate
+/// and the payroll's overtime multiplier.
+#[derive(Clone)]
+pub struct OvertimeService {
+    repository: Arc<dyn OvertimeRepository>,
+    payroll_service: Arc<PayrollService>,
+    employee_service: Arc<EmployeeService>,
+    timesheet_service: Arc<TimesheetService>,
+}
+
+impl OvertimeService {
+    pub fn new(
+        repository: Arc<dyn OvertimeRepository>,
+        payroll_service: Arc<PayrollService>,
+        employee_service: Arc<EmployeeService>,
+        timesheet_service: Arc<TimesheetService>,
+    ) -> Self {
+        Self {
+            repository,
+            payroll_service,
+            employee_service,
+            timesheet_service,
+        }
+    }
+
+    /// The payroll's overtime policy, the default multiplier without pre-approval when none
+    /// has been set.
+    pub async fn policy(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+    ) -> AppResult<OvertimePolicy> {
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        Ok(self
+            .repository
+            .fetch_policy(payroll_id)
+            .await?
+            .unwrap_or_else(|| OvertimePolicy::standard(payroll_id)))
+    }
+
+    /// Replaces the payroll's overtime multiplier and whether overtime needs pre-approval.
+    pub async fn set_policy(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        multiplier: f64,
+        requires_preapproval: bool,
+    ) -> AppResult<OvertimePolicy> {
+        if !multiplier.is_finite() || multiplier < 1.0 {
+            return Err(AppError::validation(
+                "the overtime multiplier must be at least 1",
+            ));
+        }
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await?;
+
+        self.repository
+            .upsert_policy(OvertimePolicy {
+                payroll_id,
+                multiplier,
+                requires_preapproval,
+            })
+            .await
+    }
+
+    /// Asks the employee's supervisor or division manager to approve up to `hours` of
+    /// overtime on a day. Only one open or approved request may cover a day.
+    pub async fn create_request(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        params: CreateOvertimeRequestParams,
+    ) -> AppResult<OvertimeRequest> {
+        if !params.hours.is_finite() || params.hours <= 0.0 || params.hours > MAX_DAILY_HOURS {
+            return Err(AppError::validation(
+                "hours must be greater than 0 and at most 24",
+            ));
+        }
+        let employee = self
+            .ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        if employee.status == EmployeeStatus::Terminated {
+            return Err(AppError::validation(
+                "terminated employees cannot request overtime",
+            ));
+        }
+        if params.date < employee.hire_date {
+            return Err(AppError::validation(
+                "overtime cannot be requested before the employee's hire date",
+            ));
+        }
+        if let Some(existing) = self
+            .repository
+            .fetch_requests_by_employee(employee_id)
+            .await?
+            .into_iter()
+            .find(|request| {
+                request.date == params.date && request.status != OvertimeRequestStatus::Rejected
+            })
+        {
+            return Err(AppError::conflict(format!(
+                "overtime on {} is already covered by request `{}`",
+                params.date, existing.id
+            )));
+        }
+
+        let request = OvertimeRequest::new(
+            Uuid::new_v4(),
+            employee_id,
+            params.date,
+            params.hours,
+            normalize_optional(params.reason),
+        );
+        self.repository.insert_request(request).await
+    }
+
+    /// The employee's overtime requests by date.
+    pub async fn list_requests(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<OvertimeRequest>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let mut requests = self
+            .repository
+            .fetch_requests_by_employee(employee_id)
+            .await?;
+        requests.sort_by_key(|request| (request.date, request.requested_at));
+        Ok(requests)
+    }
+
+    /// Approves or rejects a pending request on behalf of `approver_id`, who must be the
+    /// employee's supervisor or the manager of their division.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn review_request(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        request_id: Uuid,
+        approver_id: Uuid,
+        status: OvertimeRequestStatus,
+        note: Option<String>,
+    ) -> AppResult<Option<OvertimeRequest>> {
+        if status == OvertimeRequestStatus::Pending {
+            return Err(AppError::validation(
+                "a review must approve or reject the request",
+            ));
+        }
+        let employee = self
+            .ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let Some(mut request) = self
+            .repository
+            .fetch_request(request_id)
+            .await?
+            .filter(|request| request.employee_id == employee_id)
+        else {
+            return Ok(None);
+        };
+        self.timesheet_service
+            .ensure_manager_of(organization_id, payroll_id, &employee, approver_id)
+            .await?;
+        if request.status != OvertimeRequestStatus::Pending {
+            return Err(AppError::conflict(format!(
+                "overtime request `{request_id}` has already been reviewed"
+            )));
+        }
+
+        request.status = status;
+        request.reviewed_by = Some(approver_id);
+        request.review_note = normalize_optional(note);
+        request.reviewed_at = Some(Utc::now());
+        self.repository.update_request(request).await
+    }
+
+    /// Paid hours between `from` and `to`, inclusive, split into those paid at the ordinary
+    /// rate and those paid at the overtime multiplier. Overtime is measured against the
+    /// employee's daily and weekly thresholds; when the payroll requires pre-approval, only
+    /// the part an approved request covers earns the multiplier.
+    ///
+    /// Payroll runs use this to pay overtime.
+    pub async fn overtime_pay(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<OvertimePay> {
+        if from > to {
+            return Err(AppError::validation("from must not be after to"));
+        }
+        let policy = self.policy(organization_id, payroll_id).await?;
+        let expectation = self
+            .employee_service
+            .work_expectation(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "employee `{employee_id}` not found for division `{division_id}` in payroll `{payroll_id}`"
+                ))
+            })?;
+
+        // Weekly overtime depends on the hours worked earlier in the week, so the week
+        // `from` falls in is read from its Monday.
+        let week_start = from - Duration::days(i64::from(from.weekday().num_days_from_monday()));
+        let mut worked: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+        for entry in self
+            .timesheet_service
+            .approved_entries(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                week_start,
+                to,
+            )
+            .await?
+        {
+            *worked.entry(entry.date).or_default() += entry.hours;
+        }
+        let approved = self
+            .repository
+            .fetch_requests_by_employee(employee_id)
+            .await?
+            .into_iter()
+            .filter(|request| request.status == OvertimeRequestStatus::Approved)
+            .collect::<Vec<_>>();
+
+        let days: Vec<OvertimeDay> = expectation
+            .overtime_by_day(&worked)
+            .into_iter()
+            .filter(|(date, _)| from <= *date)
+            .map(|(date, hours)| {
+                let requested: f64 = approved
+                    .iter()
+                    .filter(|request| request.date == date)
+                    .map(|request| request.hours)
+                    .sum();
+                OvertimeDay {
+                    date,
+                    hours,
+                    approved_hours: hours.min(requested),
+                }
+            })
+            .collect();
+
+        let total: f64 = worked.range(from..=to).map(|(_, hours)| hours).sum();
+        let overtime: f64 = days.iter().map(|day| day.hours).sum();
+        let unapproved: f64 = if policy.requires_preapproval {
+            days.iter().map(OvertimeDay::unapproved_hours).sum()
+        } else {
+            0.0
+        };
+
+        Ok(OvertimePay {
+            employee_id,
+            from,
+            to,
+            multiplier: policy.multiplier,
+            requires_preapproval: policy.requires_preapproval,
+            regular_hours: total - overtime + unapproved,
+            overtime_hours: overtime - unapproved,
+            unapproved_overtime_hours: unapproved,
+            days,
+        })
+    }
+
+    /// Overtime worked between `from` and `to` that no approved request covers, for every
+    /// employee of the payroll. A payroll run over the range pays it at the ordinary rate
+    /// and shows these as warnings.
+    pub async fn warnings(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<OvertimeWarningReport> {
+        if from > to {
+            return Err(AppError::validation("from must not be after to"));
+        }
+        let policy = self.policy(organization_id, payroll_id).await?;
+
+        let mut warnings = Vec::new();
+        if policy.requires_preapproval {
+            let mut employees = self
+                .employee_service
+                .search(organization_id, Some(payroll_id), None)
+                .await?;
+            employees.sort_by(|a, b| {
+                (&a.last_name, &a.first_name, a.id).cmp(&(&b.last_name, &b.first_name, b.id))
+            });
+
+            for employee in employees {
+                let pay = self
+                    .overtime_pay(
+                        organization_id,
+                        payroll_id,
+                        employee.division_id,
+                        employee.id,
+                        from,
+                        to,
+                    )
+                    .await?;
+                warnings.extend(
+                    pay.days
+                        .iter()
+                        .filter(|day| day.unapproved_hours() > 0.0)
+                        .map(|day| OvertimeWarning {
+                            employee_id: employee.id,
+                            division_id: employee.division_id,
+                            first_name: employee.first_name.clone(),
+                            last_name: employee.last_name.clone(),
+                            date: day.date,
+                            unapproved_hours: day.unapproved_hours(),
+                        }),
+                );
+            }
+        }
+
+        Ok(OvertimeWarningReport {
+            payroll_id,
+            from,
+            to,
+            requires_preapproval: policy.requires_preapproval,
+            warnings,
+        })
+    }
+
+    async fn ensure_employee_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Employee> {
+        self.employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "employee `{employee_id}` not found for division `{division_id}` in payroll `{payroll_id}`"
+                ))
+            })
+    }
+}
+
+fn normalize_optional(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
@@ -0,0 +1,118 @@
+use std::{collections::HashMap, sync::Arc};
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::EmployeeStatus,
+        pay_equity::{
+            DEFAULT_MIN_GROUP_SIZE, GradePayEquity, PayEquityReport, PaySample,
+            SMALLEST_MIN_GROUP_SIZE,
+        },
+    },
+    error::{AppError, AppResult},
+    services::{employee::EmployeeService, job::JobService, job_grade::JobGradeService},
+};
+
+#[derive(Clone)]
+pub struct PayEquityService {
+    employee_service: Arc<EmployeeService>,
+    job_service: Arc<JobService>,
+    job_grade_service: Arc<JobGradeService>,
+}
+
+impl PayEquityService {
+    pub fn new(
+        employee_service: Arc<EmployeeService>,
+        job_service: Arc<JobService>,
+        job_grade_service: Arc<JobGradeService>,
+    ) -> Self {
+        Self {
+            employee_service,
+            job_service,
+            job_grade_service,
+        }
+    }
+
+    /// Compares the annualised pay of the payroll's current employees within each job grade.
+    /// Groups smaller than `min_group_size` (default [`DEFAULT_MIN_GROUP_SIZE`]) are suppressed
+    /// so nobody's pay can be singled out.
+    pub async fn report(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        min_group_size: Option<usize>,
+    ) -> AppResult<PayEquityReport> {
+        let min_group_size = min_group_size.unwrap_or(DEFAULT_MIN_GROUP_SIZE);
+        if min_group_size < SMALLEST_MIN_GROUP_SIZE {
+            return Err(AppError::validation(format!(
+                "min_group_size must be at least {SMALLEST_MIN_GROUP_SIZE}"
+            )));
+        }
+
+        let grades = self
+            .job_grade_service
+            .list(organization_id, payroll_id)
+            .await?;
+        let jobs: HashMap<Uuid, _> = self
+            .job_service
+            .list(organization_id, payroll_id)
+            .await?
+            .into_iter()
+            .map(|job| (job.id, job))
+            .collect();
+        let employees = self
+            .employee_service
+            .search(organization_id, Some(payroll_id), None)
+            .await?;
+
+        let mut samples: HashMap<Uuid, Vec<PaySample>> = HashMap::new();
+        let mut ungraded_employees = 0;
+        for employee in employees
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+        {
+            let Some(job) = jobs.get(&employee.job_id) else {
+                continue;
+            };
+            let Some(grade_id) = job.grade_id else {
+                ungraded_employees += 1;
+                continue;
+            };
+            let Some(expectation) = self
+                .employee_service
+                .work_expectation(
+                    organization_id,
+                    payroll_id,
+                    employee.division_id,
+                    employee.id,
+                )
+                .await?
+            else {
+                continue;
+            };
+
+            samples.entry(grade_id).or_default().push(PaySample {
+                gender: employee.gender.clone(),
+                clasification: employee.clasification.clone(),
+                annual_pay: job.pay_basis.annual_amount(job.salary, &expectation),
+            });
+        }
+
+        let grades = grades
+            .iter()
+            .map(|grade| {
+                let samples = samples.remove(&grade.id).unwrap_or_default();
+                GradePayEquity::summarize(grade, &samples, min_group_size)
+            })
+            .collect();
+
+        Ok(PayEquityReport {
+            organization_id,
+            payroll_id,
+            min_group_size,
+            grades,
+            ungraded_employees,
+        })
+    }
+}
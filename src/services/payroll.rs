@@ -1,24 +1,34 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::{
-    domain::payroll::Payroll,
+    domain::{organization::Employer, payroll::Payroll},
     error::{AppError, AppResult},
-    services::organization::OrganizationService,
+    services::{
+        cache::{CacheStore, LOOKUP_CACHE_TTL, TtlCache},
+        legal_entity::LegalEntityRepository,
+        organization::OrganizationService,
+    },
 };
 
 #[derive(Debug, Clone)]
 pub struct CreatePayrollParams {
     pub name: String,
     pub description: String,
+    pub blocks_unverified_accounts: bool,
+    pub legal_entity_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct UpdatePayrollParams {
     pub name: Option<String>,
     pub description: Option<String>,
+    pub blocks_unverified_accounts: Option<bool>,
+    /// `Some(None)` moves the payroll back under the organization itself.
+    pub legal_entity_id: Option<Option<Uuid>>,
 }
 
 #[async_trait]
@@ -29,8 +39,11 @@ pub trait PayrollRepository: Send + Sync {
         name: String,
         description: String,
         organization_id: Uuid,
+        blocks_unverified_accounts: bool,
+        legal_entity_id: Option<Uuid>,
     ) -> AppResult<Payroll>;
 
+    /// Never served by a read replica, since the lookup cache is filled from it.
     async fn fetch(&self, id: Uuid) -> AppResult<Option<Payroll>>;
 
     async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Payroll>>;
@@ -40,6 +53,14 @@ pub trait PayrollRepository: Send + Sync {
         id: Uuid,
         name: Option<String>,
         description: Option<String>,
+        blocks_unverified_accounts: Option<bool>,
+        legal_entity_id: Option<Option<Uuid>>,
+    ) -> AppResult<Option<Payroll>>;
+
+    async fn set_archived_at(
+        &self,
+        id: Uuid,
+        archived_at: Option<DateTime<Utc>>,
     ) -> AppResult<Option<Payroll>>;
 
     async fn delete(&self, id: Uuid) -> AppResult<bool>;
@@ -49,16 +70,23 @@ pub trait PayrollRepository: Send + Sync {
 pub struct PayrollService {
     repository: Arc<dyn PayrollRepository>,
     organization_service: Arc<OrganizationService>,
+    legal_entity_repository: Arc<dyn LegalEntityRepository>,
+    /// Payrolls by id, looked up by every route nested under a payroll.
+    cache: Arc<TtlCache<Uuid, Payroll>>,
 }
 
 impl PayrollService {
     pub fn new(
         repository: Arc<dyn PayrollRepository>,
         organization_service: Arc<OrganizationService>,
+        legal_entity_repository: Arc<dyn LegalEntityRepository>,
+        cache_store: Arc<dyn CacheStore>,
     ) -> Self {
         Self {
             repository,
             organization_service,
+            legal_entity_repository,
+            cache: Arc::new(TtlCache::new(cache_store, "payroll", LOOKUP_CACHE_TTL)),
         }
     }
 
@@ -70,15 +98,31 @@ impl PayrollService {
         let name = Self::normalize_name(&params.name)?;
         let description = Self::normalize_description(&params.description)?;
         self.ensure_organization_exists(organization_id).await?;
+        if let Some(legal_entity_id) = params.legal_entity_id {
+            self.ensure_legal_entity_exists(organization_id, legal_entity_id)
+                .await?;
+        }
         let id = Uuid::new_v4();
         self.repository
-            .insert(id, name, description, organization_id)
+            .insert(
+                id,
+                name,
+                description,
+                organization_id,
+                params.blocks_unverified_accounts,
+                params.legal_entity_id,
+            )
             .await
     }
 
     pub async fn get(&self, organization_id: Uuid, payroll_id: Uuid) -> AppResult<Option<Payroll>> {
-        let payroll = self.repository.fetch(payroll_id).await?;
-        Ok(payroll.filter(|payroll| payroll.organization_id == organization_id))
+        let payroll = self
+            .cache
+            .get_or_load(payroll_id, || self.repository.fetch(payroll_id))
+            .await?;
+        Ok(payroll.filter(|payroll| {
+            payroll.organization_id == organization_id && payroll.archived_at.is_none()
+        }))
     }
 
     pub async fn list(&self, organization_id: Uuid) -> AppResult<Vec<Payroll>> {
@@ -87,6 +131,7 @@ impl PayrollService {
             .repository
             .fetch_by_organization(organization_id)
             .await?;
+        payrolls.retain(|payroll| payroll.archived_at.is_none());
         payrolls.sort_by(|a, b| a.name.cmp(&b.name));
         Ok(payrolls)
     }
@@ -97,7 +142,11 @@ impl PayrollService {
         payroll_id: Uuid,
         params: UpdatePayrollParams,
     ) -> AppResult<Option<Payroll>> {
-        if params.name.is_none() && params.description.is_none() {
+        if params.name.is_none()
+            && params.description.is_none()
+            && params.blocks_unverified_accounts.is_none()
+            && params.legal_entity_id.is_none()
+        {
             return Err(AppError::validation("no fields supplied for update"));
         }
 
@@ -115,8 +164,53 @@ impl PayrollService {
             .as_deref()
             .map(Self::normalize_description)
             .transpose()?;
+        if let Some(Some(legal_entity_id)) = params.legal_entity_id {
+            self.ensure_legal_entity_exists(organization_id, legal_entity_id)
+                .await?;
+        }
+
+        self.cache
+            .invalidating(
+                payroll_id,
+                self.repository.update(
+                    payroll_id,
+                    name,
+                    description,
+                    params.blocks_unverified_accounts,
+                    params.legal_entity_id,
+                ),
+            )
+            .await
+    }
+
+    /// Who pays the payroll's employees: its legal entity, or the organization when it has
+    /// none.
+    pub async fn employer(&self, organization_id: Uuid, payroll_id: Uuid) -> AppResult<Employer> {
+        let organization = self
+            .organization_service
+            .get(organization_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!("organization `{organization_id}` not found"))
+            })?;
+        let legal_entity_id = self
+            .get(organization_id, payroll_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "payroll `{payroll_id}` not found for organization `{organization_id}`"
+                ))
+            })?
+            .legal_entity_id;
+        let legal_entity = match legal_entity_id {
+            Some(id) => self.legal_entity_repository.fetch(id).await?,
+            None => None,
+        };
 
-        self.repository.update(payroll_id, name, description).await
+        Ok(legal_entity.map_or_else(
+            || organization.employer(),
+            |legal_entity| legal_entity.employer(&organization),
+        ))
     }
 
     pub async fn delete(&self, organization_id: Uuid, payroll_id: Uuid) -> AppResult<bool> {
@@ -124,7 +218,54 @@ impl PayrollService {
             return Ok(false);
         }
 
-        self.repository.delete(payroll_id).await
+        self.cache
+            .invalidating(payroll_id, self.repository.delete(payroll_id))
+            .await
+    }
+
+    /// Archives the payrolls of a soft-deleted organization. Their divisions, employees and
+    /// jobs are only reached through the payroll, so they are hidden along with it.
+    pub(crate) async fn archive_organization(
+        &self,
+        organization_id: Uuid,
+        archived_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        for payroll in self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?
+        {
+            if payroll.archived_at.is_none() {
+                self.cache
+                    .invalidating(
+                        payroll.id,
+                        self.repository
+                            .set_archived_at(payroll.id, Some(archived_at)),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) async fn restore_organization(&self, organization_id: Uuid) -> AppResult<()> {
+        for payroll in self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?
+        {
+            if payroll.archived_at.is_some() {
+                self.cache
+                    .invalidating(
+                        payroll.id,
+                        self.repository.set_archived_at(payroll.id, None),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
     }
 
     pub async fn ensure_belongs_to_organization(
@@ -157,6 +298,26 @@ impl PayrollService {
         }
     }
 
+    async fn ensure_legal_entity_exists(
+        &self,
+        organization_id: Uuid,
+        legal_entity_id: Uuid,
+    ) -> AppResult<()> {
+        let exists = self
+            .legal_entity_repository
+            .fetch(legal_entity_id)
+            .await?
+            .is_some_and(|legal_entity| legal_entity.organization_id == organization_id);
+
+        if exists {
+            Ok(())
+        } else {
+            Err(AppError::not_found(format!(
+                "legal entity `{legal_entity_id}` not found for organization `{organization_id}`"
+            )))
+        }
+    }
+
     fn normalize_name(value: &str) -> AppResult<String> {
         let name = value.trim();
         if name.is_empty() {
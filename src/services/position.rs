@@ -0,0 +1,385 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        division::Division,
+        employee::EmployeeStatus,
+        position::{Position, PositionAssignment},
+    },
+    error::{AppError, AppResult},
+    services::{division::DivisionService, employee::EmployeeService, job::JobService},
+};
+
+#[derive(Debug, Clone)]
+pub struct CreatePositionParams {
+    pub title: String,
+    pub job_id: Uuid,
+    pub budget: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdatePositionParams {
+    pub title: Option<String>,
+    pub job_id: Option<Uuid>,
+    pub budget: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AssignPositionParams {
+    pub employee_id: Uuid,
+    pub start_date: NaiveDate,
+}
+
+#[async_trait]
+pub trait PositionRepository: Send + Sync {
+    async fn insert(&self, position: Position) -> AppResult<Position>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Position>>;
+    async fn fetch_by_division(&self, division_id: Uuid) -> AppResult<Vec<Position>>;
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<Position>>;
+    /// Positions whose assignment history includes `employee_id`, in any division.
+    async fn fetch_by_assignee(&self, employee_id: Uuid) -> AppResult<Vec<Position>>;
+    async fn update(&self, position: Position) -> AppResult<Option<Position>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct PositionService {
+    repository: Arc<dyn PositionRepository>,
+    division_service: Arc<DivisionService>,
+    job_service: Arc<JobService>,
+    employee_service: Arc<EmployeeService>,
+}
+
+impl PositionService {
+    pub fn new(
+        repository: Arc<dyn PositionRepository>,
+        division_service: Arc<DivisionService>,
+        job_service: Arc<JobService>,
+        employee_service: Arc<EmployeeService>,
+    ) -> Self {
+        Self {
+            repository,
+            division_service,
+            job_service,
+            employee_service,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        params: CreatePositionParams,
+    ) -> AppResult<Position> {
+        let title = Self::normalize_title(&params.title)?;
+        let budget = Self::validate_budget(params.budget)?;
+        self.ensure_division_accessible(organization_id, payroll_id, division_id)
+            .await?;
+        self.ensure_job_belongs(organization_id, payroll_id, params.job_id)
+            .await?;
+
+        let position = Position::new(
+            Uuid::new_v4(),
+            title,
+            params.job_id,
+            budget,
+            division_id,
+            payroll_id,
+        );
+        self.repository.insert(position).await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        position_id: Uuid,
+    ) -> AppResult<Option<Position>> {
+        self.ensure_division_accessible(organization_id, payroll_id, division_id)
+            .await?;
+        let position = self.repository.fetch(position_id).await?;
+        Ok(position.filter(|position| {
+            position.division_id == division_id && position.payroll_id == payroll_id
+        }))
+    }
+
+    /// Lists the division's positions by title, optionally only the vacant ones.
+    pub async fn list(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        vacant_only: bool,
+    ) -> AppResult<Vec<Position>> {
+        self.ensure_division_accessible(organization_id, payroll_id, division_id)
+            .await?;
+        let mut positions: Vec<Position> = self
+            .repository
+            .fetch_by_division(division_id)
+            .await?
+            .into_iter()
+            .filter(|position| !vacant_only || position.is_vacant())
+            .collect();
+        positions.sort_by(|a, b| a.title.cmp(&b.title));
+        Ok(positions)
+    }
+
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        position_id: Uuid,
+        params: UpdatePositionParams,
+    ) -> AppResult<Option<Position>> {
+        if params.title.is_none() && params.job_id.is_none() && params.budget.is_none() {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        let Some(mut position) = self
+            .get(organization_id, payroll_id, division_id, position_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if let Some(title) = params.title.as_deref() {
+            position.title = Self::normalize_title(title)?;
+        }
+        if let Some(budget) = params.budget {
+            position.budget = Self::validate_budget(budget)?;
+        }
+        if let Some(job_id) = params.job_id {
+            self.ensure_job_belongs(organization_id, payroll_id, job_id)
+                .await?;
+            position.job_id = job_id;
+        }
+
+        self.repository.update(position).await
+    }
+
+    /// Deletes a vacant position; occupied positions must be unassigned first.
+    pub async fn delete(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        position_id: Uuid,
+    ) -> AppResult<bool> {
+        let Some(position) = self
+            .get(organization_id, payroll_id, division_id, position_id)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        if !position.is_vacant() {
+            return Err(AppError::conflict(format!(
+                "position `{position_id}` is occupied; unassign its employee before deleting it"
+            )));
+        }
+
+        self.repository.delete(position_id).await
+    }
+
+    /// Seats an employee of the same division in a vacant position.
+    pub async fn assign(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        position_id: Uuid,
+        params: AssignPositionParams,
+    ) -> AppResult<Option<Position>> {
+        let Some(mut position) = self
+            .get(organization_id, payroll_id, division_id, position_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if let Some(holder) = position.employee_id {
+            return Err(AppError::conflict(format!(
+                "position `{position_id}` is already held by employee `{holder}`"
+            )));
+        }
+
+        let employee = self
+            .employee_service
+            .get(organization_id, payroll_id, division_id, params.employee_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "employee `{}` not found for division `{division_id}` in payroll `{payroll_id}`",
+                    params.employee_id
+                ))
+            })?;
+        if employee.status == EmployeeStatus::Terminated {
+            return Err(AppError::validation(
+                "terminated employees cannot be assigned to a position",
+            ));
+        }
+
+        if let Some(held) = self
+            .repository
+            .fetch_by_employee(employee.id)
+            .await?
+            .into_iter()
+            .next()
+        {
+            return Err(AppError::conflict(format!(
+                "employee `{}` already holds position `{}`",
+                employee.id, held.id
+            )));
+        }
+
+        if let Some(previous_end) = position.history.last().and_then(|entry| entry.end_date)
+            && params.start_date < previous_end
+        {
+            return Err(AppError::validation(
+                "start date cannot be before the previous assignment ended",
+            ));
+        }
+
+        let division = self
+            .ensure_division_accessible(organization_id, payroll_id, division_id)
+            .await?;
+        let occupied = self
+            .repository
+            .fetch_by_division(division_id)
+            .await?
+            .iter()
+            .filter(|position| !position.is_vacant())
+            .count();
+        DivisionService::ensure_headcount_available(&division, occupied)?;
+
+        position.employee_id = Some(employee.id);
+        position.history.push(PositionAssignment {
+            employee_id: employee.id,
+            start_date: params.start_date,
+            end_date: None,
+        });
+
+        self.repository.update(position).await
+    }
+
+    /// Vacates the position, closing the current assignment on `end_date`.
+    pub async fn unassign(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        position_id: Uuid,
+        end_date: NaiveDate,
+    ) -> AppResult<Option<Position>> {
+        let Some(mut position) = self
+            .get(organization_id, payroll_id, division_id, position_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if position.is_vacant() {
+            return Err(AppError::conflict(format!(
+                "position `{position_id}` is already vacant"
+            )));
+        }
+
+        if let Some(current) = position
+            .history
+            .iter_mut()
+            .rev()
+            .find(|entry| entry.end_date.is_none())
+        {
+            if end_date < current.start_date {
+                return Err(AppError::validation(
+                    "end date cannot be before the assignment start date",
+                ));
+            }
+            current.end_date = Some(end_date);
+        }
+        position.employee_id = None;
+
+        self.repository.update(position).await
+    }
+
+    /// Assignments `employee_id` has held in any division's positions, with the position title.
+    pub async fn assignments_for_employee(
+        &self,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<(String, PositionAssignment)>> {
+        let positions = self.repository.fetch_by_assignee(employee_id).await?;
+        Ok(positions
+            .into_iter()
+            .flat_map(|position| {
+                let title = position.title;
+                position
+                    .history
+                    .into_iter()
+                    .filter(move |entry| entry.employee_id == employee_id)
+                    .map(move |entry| (title.clone(), entry))
+            })
+            .collect())
+    }
+
+    async fn ensure_division_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+    ) -> AppResult<Division> {
+        match self
+            .division_service
+            .get(organization_id, payroll_id, division_id)
+            .await?
+        {
+            Some(division) => Ok(division),
+            None => Err(AppError::not_found(format!(
+                "division `{division_id}` not found for payroll `{payroll_id}` in organization `{organization_id}`"
+            ))),
+        }
+    }
+
+    async fn ensure_job_belongs(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        job_id: Uuid,
+    ) -> AppResult<()> {
+        match self
+            .job_service
+            .get(organization_id, payroll_id, job_id)
+            .await?
+        {
+            Some(job) if job.payroll_id == payroll_id => Ok(()),
+            _ => Err(AppError::not_found(format!(
+                "job `{job_id}` not found for payroll `{payroll_id}`"
+            ))),
+        }
+    }
+
+    fn normalize_title(value: &str) -> AppResult<String> {
+        let title = value.trim();
+        if title.is_empty() {
+            return Err(AppError::validation("position title cannot be empty"));
+        }
+
+        Ok(title.to_string())
+    }
+
+    fn validate_budget(value: f64) -> AppResult<f64> {
+        if !value.is_finite() || value < 0.0 {
+            return Err(AppError::validation(
+                "position budget must be a non-negative number",
+            ));
+        }
+
+        Ok(value)
+    }
+}
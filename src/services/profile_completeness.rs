@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::EmployeeStatus,
+        profile_completeness::{OrganizationCompleteness, ProfileCompleteness},
+    },
+    error::AppResult,
+    services::{custom_field::CustomFieldService, employee::EmployeeService},
+};
+
+#[derive(Clone)]
+pub struct ProfileCompletenessService {
+    employee_service: Arc<EmployeeService>,
+    custom_field_service: Arc<CustomFieldService>,
+}
+
+impl ProfileCompletenessService {
+    pub fn new(
+        employee_service: Arc<EmployeeService>,
+        custom_field_service: Arc<CustomFieldService>,
+    ) -> Self {
+        Self {
+            employee_service,
+            custom_field_service,
+        }
+    }
+
+    pub async fn employee(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Option<ProfileCompleteness>> {
+        let Some(employee) = self
+            .employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let required = self.required_custom_fields(organization_id).await?;
+
+        Ok(Some(ProfileCompleteness::evaluate(&employee, &required)))
+    }
+
+    /// Rolls up every employee still on the books, narrowed to a payroll and/or division when
+    /// given.
+    pub async fn organization(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Option<Uuid>,
+        division_id: Option<Uuid>,
+    ) -> AppResult<OrganizationCompleteness> {
+        let required = self.required_custom_fields(organization_id).await?;
+        let profiles = self
+            .employee_service
+            .search(organization_id, payroll_id, division_id)
+            .await?
+            .iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+            .map(|employee| ProfileCompleteness::evaluate(employee, &required))
+            .collect();
+
+        Ok(OrganizationCompleteness::summarize(
+            organization_id,
+            profiles,
+        ))
+    }
+
+    async fn required_custom_fields(&self, organization_id: Uuid) -> AppResult<Vec<String>> {
+        Ok(self
+            .custom_field_service
+            .list(organization_id)
+            .await?
+            .into_iter()
+            .filter(|definition| definition.required)
+            .map(|definition| definition.name)
+            .collect())
+    }
+}
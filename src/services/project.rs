@@ -0,0 +1,226 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    domain::project::Project,
+    error::{AppError, AppResult},
+    services::{organization::OrganizationService, timesheet::TimesheetRepository},
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateProjectParams {
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateProjectParams {
+    pub code: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub active: Option<bool>,
+}
+
+#[async_trait]
+pub trait ProjectRepository: Send + Sync {
+    async fn insert(&self, project: Project) -> AppResult<Project>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<Project>>;
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<Project>>;
+    async fn update(&self, project: Project) -> AppResult<Option<Project>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct ProjectService {
+    repository: Arc<dyn ProjectRepository>,
+    organization_service: Arc<OrganizationService>,
+    timesheet_repository: Arc<dyn TimesheetRepository>,
+}
+
+impl ProjectService {
+    pub fn new(
+        repository: Arc<dyn ProjectRepository>,
+        organization_service: Arc<OrganizationService>,
+        timesheet_repository: Arc<dyn TimesheetRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            organization_service,
+            timesheet_repository,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        params: CreateProjectParams,
+    ) -> AppResult<Project> {
+        let code = Self::normalize_field(&params.code, "project code")?;
+        let name = Self::normalize_field(&params.name, "project name")?;
+        self.ensure_organization_exists(organization_id).await?;
+        self.ensure_code_available(organization_id, &code, None)
+            .await?;
+
+        let project = Project::new(
+            Uuid::new_v4(),
+            organization_id,
+            code,
+            name,
+            normalize_optional(params.description),
+        );
+        self.repository.insert(project).await
+    }
+
+    pub async fn get(&self, organization_id: Uuid, project_id: Uuid) -> AppResult<Option<Project>> {
+        let project = self.repository.fetch(project_id).await?;
+        Ok(project.filter(|project| project.organization_id == organization_id))
+    }
+
+    /// Projects ordered by code.
+    pub async fn list(&self, organization_id: Uuid) -> AppResult<Vec<Project>> {
+        self.ensure_organization_exists(organization_id).await?;
+        let mut projects = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?;
+        projects.sort_by(|a, b| a.code.cmp(&b.code));
+        Ok(projects)
+    }
+
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        project_id: Uuid,
+        params: UpdateProjectParams,
+    ) -> AppResult<Option<Project>> {
+        if params.code.is_none()
+            && params.name.is_none()
+            && params.description.is_none()
+            && params.active.is_none()
+        {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        let Some(mut project) = self.get(organization_id, project_id).await? else {
+            return Ok(None);
+        };
+
+        if let Some(code) = params.code.as_deref() {
+            let code = Self::normalize_field(code, "project code")?;
+            self.ensure_code_available(organization_id, &code, Some(project_id))
+                .await?;
+            project.code = code;
+        }
+        if let Some(name) = params.name.as_deref() {
+            project.name = Self::normalize_field(name, "project name")?;
+        }
+        if let Some(description) = params.description {
+            project.description = normalize_optional(description);
+        }
+        if let Some(active) = params.active {
+            project.active = active;
+        }
+
+        self.repository.update(project).await
+    }
+
+    /// Refused while timesheet entries are charged to the project; deactivate it instead.
+    pub async fn delete(&self, organization_id: Uuid, project_id: Uuid) -> AppResult<bool> {
+        let Some(project) = self.get(organization_id, project_id).await? else {
+            return Ok(false);
+        };
+        let charged = self
+            .timesheet_repository
+            .fetch_by_project(project_id)
+            .await?
+            .len();
+        if charged > 0 {
+            return Err(AppError::conflict(format!(
+                "project `{}` still has {charged} timesheet entr{} charged to it",
+                project.code,
+                if charged == 1 { "y" } else { "ies" }
+            )));
+        }
+
+        self.repository.delete(project_id).await
+    }
+
+    /// The project, if hours of the organization may still be charged to it.
+    pub async fn ensure_chargeable(
+        &self,
+        organization_id: Uuid,
+        project_id: Uuid,
+    ) -> AppResult<Project> {
+        let project = self
+            .get(organization_id, project_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "project `{project_id}` not found for organization `{organization_id}`"
+                ))
+            })?;
+        if !project.active {
+            return Err(AppError::validation(format!(
+                "project `{}` is inactive and takes no new hours",
+                project.code
+            )));
+        }
+
+        Ok(project)
+    }
+
+    async fn ensure_organization_exists(&self, organization_id: Uuid) -> AppResult<()> {
+        let exists = self
+            .organization_service
+            .get(organization_id)
+            .await?
+            .is_some();
+
+        if exists {
+            Ok(())
+        } else {
+            Err(AppError::not_found(format!(
+                "organization `{organization_id}` not found"
+            )))
+        }
+    }
+
+    async fn ensure_code_available(
+        &self,
+        organization_id: Uuid,
+        code: &str,
+        except: Option<Uuid>,
+    ) -> AppResult<()> {
+        let taken = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?
+            .iter()
+            .any(|project| Some(project.id) != except && project.code.eq_ignore_ascii_case(code));
+        if taken {
+            return Err(AppError::conflict(format!(
+                "project code `{code}` already exists in organization `{organization_id}`"
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn normalize_field(value: &str, field: &str) -> AppResult<String> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return Err(AppError::validation(format!("{field} cannot be empty")));
+        }
+
+        Ok(trimmed.to_string())
+    }
+}
+
+fn normalize_optional(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
@@ -0,0 +1,68 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use tokio::time::{Instant, timeout};
+
+use crate::{
+    domain::health::{DependencyHealth, DependencyStatus, ReadinessReport},
+    error::AppResult,
+};
+
+/// Name the database is reported under.
+pub const DATABASE_DEPENDENCY: &str = "database";
+
+/// Name the read replica is reported under, when one is configured.
+pub const DATABASE_REPLICA_DEPENDENCY: &str = "database_replica";
+
+/// How long a single ping may take before the dependency is reported down.
+pub const PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A backing service that has to answer before the API can take traffic.
+#[async_trait]
+pub trait DependencyProbe: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn ping(&self) -> AppResult<()>;
+}
+
+/// Pings every dependency on demand; unlike the integration monitor it keeps no history.
+pub struct ReadinessService {
+    probes: Vec<Arc<dyn DependencyProbe>>,
+}
+
+impl ReadinessService {
+    pub fn new(probes: Vec<Arc<dyn DependencyProbe>>) -> Self {
+        Self { probes }
+    }
+
+    pub async fn check(&self) -> ReadinessReport {
+        let mut dependencies = Vec::with_capacity(self.probes.len());
+        for probe in &self.probes {
+            dependencies.push(Self::ping(probe.as_ref()).await);
+        }
+
+        ReadinessReport::new(dependencies)
+    }
+
+    async fn ping(probe: &dyn DependencyProbe) -> DependencyHealth {
+        let started = Instant::now();
+        let outcome = timeout(PING_TIMEOUT, probe.ping()).await;
+        let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let (status, error) = match outcome {
+            Ok(Ok(())) => (DependencyStatus::Up, None),
+            Ok(Err(err)) => (DependencyStatus::Down, Some(err.to_string())),
+            Err(_) => (
+                DependencyStatus::Down,
+                Some(format!("no answer within {}ms", PING_TIMEOUT.as_millis())),
+            ),
+        };
+
+        DependencyHealth {
+            name: probe.name().to_string(),
+            status,
+            latency_ms,
+            error,
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use std::{cmp::Reverse, collections::BTreeSet, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::EmployeeStatus,
+        job::PayPeriod,
+        organization_settings::RoundingRule,
+        salary_adjustment::{
+            MAX_ADJUSTMENT_PERCENTAGE, SalaryAdjustmentBatch, SalaryAdjustmentStatus, SalaryChange,
+        },
+    },
+    error::{AppError, AppResult},
+    services::{employee::EmployeeService, job::JobService, payroll::PayrollService},
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateSalaryAdjustmentParams {
+    pub percentage: f64,
+    pub effective_date: NaiveDate,
+    /// Jobs to raise; combined with the jobs held in `division_ids`.
+    pub job_ids: Vec<Uuid>,
+    /// Divisions whose employees' jobs are raised.
+    pub division_ids: Vec<Uuid>,
+}
+
+#[async_trait]
+pub trait SalaryAdjustmentRepository: Send + Sync {
+    async fn insert(&self, batch: SalaryAdjustmentBatch) -> AppResult<SalaryAdjustmentBatch>;
+
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<SalaryAdjustmentBatch>>;
+
+    async fn fetch_by_payroll(&self, payroll_id: Uuid) -> AppResult<Vec<SalaryAdjustmentBatch>>;
+
+    /// Approved batches whose effective date is on or before `today`.
+    async fn fetch_due(&self, today: NaiveDate) -> AppResult<Vec<SalaryAdjustmentBatch>>;
+
+    async fn update(
+        &self,
+        batch: SalaryAdjustmentBatch,
+    ) -> AppResult<Option<SalaryAdjustmentBatch>>;
+}
+
+/// Prepares percentage raises across jobs for review and applies them once approved and due.
+#[derive(Clone)]
+pub struct SalaryAdjustmentService {
+    repository: Arc<dyn SalaryAdjustmentRepository>,
+    payroll_service: Arc<PayrollService>,
+    job_service: Arc<JobService>,
+    employee_service: Arc<EmployeeService>,
+}
+
+impl SalaryAdjustmentService {
+    pub fn new(
+        repository: Arc<dyn SalaryAdjustmentRepository>,
+        payroll_service: Arc<PayrollService>,
+        job_service: Arc<JobService>,
+        employee_service: Arc<EmployeeService>,
+    ) -> Self {
+        Self {
+            repository,
+            payroll_service,
+            job_service,
+            employee_service,
+        }
+    }
+
+    /// Prices the raise for every selected job, or all of the payroll's jobs when nothing is
+    /// selected, and stores it as a pending batch.
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        params: CreateSalaryAdjustmentParams,
+    ) -> AppResult<SalaryAdjustmentBatch> {
+        if !params.percentage.is_finite()
+            || params.percentage <= 0.0
+            || params.percentage > MAX_ADJUSTMENT_PERCENTAGE
+        {
+            return Err(AppError::validation(format!(
+                "percentage must be greater than 0 and at most {MAX_ADJUSTMENT_PERCENTAGE}"
+            )));
+        }
+
+        let jobs = self.job_service.list(organization_id, payroll_id).await?;
+        let mut selected = BTreeSet::new();
+        for job_id in &params.job_ids {
+            if !jobs.iter().any(|job| job.id == *job_id) {
+                return Err(AppError::not_found(format!(
+                    "job `{job_id}` not found for payroll `{payroll_id}`"
+                )));
+            }
+            selected.insert(*job_id);
+        }
+        for division_id in &params.division_ids {
+            let employees = self
+                .employee_service
+                .search(organization_id, Some(payroll_id), Some(*division_id))
+                .await?;
+            selected.extend(
+                employees
+                    .iter()
+                    .filter(|employee| employee.status != EmployeeStatus::Terminated)
+                    .map(|employee| employee.job_id),
+            );
+        }
+        let everything = params.job_ids.is_empty() && params.division_ids.is_empty();
+
+        let holders: Vec<_> = self
+            .employee_service
+            .search(organization_id, Some(payroll_id), None)
+            .await?
+            .into_iter()
+            .filter(|employee| employee.status != EmployeeStatus::Terminated)
+            .collect();
+        let mut changes = Vec::new();
+        for job in jobs
+            .iter()
+            .filter(|job| everything || selected.contains(&job.id))
+        {
+            let to_salary = SalaryAdjustmentBatch::adjusted(job.salary, params.percentage);
+            self.job_service
+                .check_salary(organization_id, job, to_salary)
+                .await?;
+
+            let mut adjusted = job.clone();
+            adjusted.salary = to_salary;
+            let mut headcount = 0;
+            let mut monthly_cost_before = 0.0;
+            let mut monthly_cost_after = 0.0;
+            for employee in holders.iter().filter(|employee| employee.job_id == job.id) {
+                let Some(expectation) = self
+                    .employee_service
+                    .work_expectation(
+                        organization_id,
+                        payroll_id,
+                        employee.division_id,
+                        employee.id,
+                    )
+                    .await?
+                else {
+                    continue;
+                };
+                headcount += 1;
+                monthly_cost_before +=
+                    job.period_amount(PayPeriod::Monthly, &expectation, RoundingRule::default());
+                monthly_cost_after += adjusted.period_amount(
+                    PayPeriod::Monthly,
+                    &expectation,
+                    RoundingRule::default(),
+                );
+            }
+
+            changes.push(SalaryChange {
+                job_id: job.id,
+                job_title: job.job_title.clone(),
+                pay_basis: job.pay_basis,
+                from_salary: job.salary,
+                to_salary,
+                headcount,
+                monthly_cost_before: (monthly_cost_before * 100.0).round() / 100.0,
+                monthly_cost_after: (monthly_cost_after * 100.0).round() / 100.0,
+            });
+        }
+        if changes.is_empty() {
+            return Err(AppError::validation("the selection does not cover any job"));
+        }
+
+        let batch = SalaryAdjustmentBatch::new(
+            Uuid::new_v4(),
+            organization_id,
+            payroll_id,
+            params.percentage,
+            params.effective_date,
+            changes,
+        );
+        self.repository.insert(batch).await
+    }
+
+    /// The payroll's batches, newest first.
+    pub async fn list(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+    ) -> AppResult<Vec<SalaryAdjustmentBatch>> {
+        self.ensure_payroll_accessible(organization_id, payroll_id)
+            .await?;
+        let mut batches = self.repository.fetch_by_payroll(payroll_id).await?;
+        batches.sort_by_key(|batch| Reverse(batch.created_at));
+        Ok(batches)
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        batch_id: Uuid,
+    ) -> AppResult<Option<SalaryAdjustmentBatch>> {
+        self.ensure_payroll_accessible(organization_id, payroll_id)
+            .await?;
+        let batch = self.repository.fetch(batch_id).await?;
+        Ok(batch.filter(|batch| batch.payroll_id == payroll_id))
+    }
+
+    /// Approves a pending batch, applying it straight away when its effective date has come.
+    pub async fn approve(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        batch_id: Uuid,
+        note: Option<String>,
+    ) -> AppResult<Option<SalaryAdjustmentBatch>> {
+        let Some(batch) = self.get(organization_id, payroll_id, batch_id).await? else {
+            return Ok(None);
+        };
+        Self::ensure_pending(&batch)?;
+
+        let mut batch = Self::decide(batch, SalaryAdjustmentStatus::Approved, note);
+        if batch.is_due(Utc::now().date_naive()) {
+            self.apply(&mut batch).await?;
+        }
+        self.repository.update(batch).await
+    }
+
+    pub async fn reject(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        batch_id: Uuid,
+        note: Option<String>,
+    ) -> AppResult<Option<SalaryAdjustmentBatch>> {
+        let Some(batch) = self.get(organization_id, payroll_id, batch_id).await? else {
+            return Ok(None);
+        };
+        Self::ensure_pending(&batch)?;
+
+        let batch = Self::decide(batch, SalaryAdjustmentStatus::Rejected, note);
+        self.repository.update(batch).await
+    }
+
+    /// Applies every approved batch whose effective date has come by `today`.
+    pub async fn apply_due(&self, today: NaiveDate) -> AppResult<Vec<Uuid>> {
+        let mut applied = Vec::new();
+        for mut batch in self.repository.fetch_due(today).await? {
+            if !batch.is_due(today) {
+                continue;
+            }
+
+            self.apply(&mut batch).await?;
+            applied.push(batch.id);
+            self.repository.update(batch).await?;
+        }
+
+        Ok(applied)
+    }
+
+    /// Checks every new salary before writing any, so a batch is not left half applied when a
+    /// job's range or grade changed after review. Jobs deleted since are skipped.
+    async fn apply(&self, batch: &mut SalaryAdjustmentBatch) -> AppResult<()> {
+        let mut jobs = Vec::new();
+        for change in &batch.changes {
+            let Some(job) = self
+                .job_service
+                .get(batch.organization_id, batch.payroll_id, change.job_id)
+                .await?
+            else {
+                continue;
+            };
+            self.job_service
+                .check_salary(batch.organization_id, &job, change.to_salary)
+                .await?;
+            jobs.push((job.id, change.to_salary));
+        }
+
+        for (job_id, salary) in jobs {
+            self.job_service
+                .apply_salary_adjustment(
+                    batch.organization_id,
+                    batch.payroll_id,
+                    job_id,
+                    batch.id,
+                    salary,
+                    batch.effective_date,
+                )
+                .await?;
+        }
+        batch.status = SalaryAdjustmentStatus::Applied;
+        batch.applied_at = Some(Utc::now());
+
+        Ok(())
+    }
+
+    fn decide(
+        mut batch: SalaryAdjustmentBatch,
+        status: SalaryAdjustmentStatus,
+        note: Option<String>,
+    ) -> SalaryAdjustmentBatch {
+        batch.status = status;
+        batch.decision_note = note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        batch.decided_at = Some(Utc::now());
+        batch
+    }
+
+    fn ensure_pending(batch: &SalaryAdjustmentBatch) -> AppResult<()> {
+        if batch.status == SalaryAdjustmentStatus::Pending {
+            Ok(())
+        } else {
+            Err(AppError::conflict(format!(
+                "salary adjustment `{}` has already been decided",
+                batch.id
+            )))
+        }
+    }
+
+    async fn ensure_payroll_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+    ) -> AppResult<()> {
+        self.payroll_service
+            .ensure_belongs_to_organization(organization_id, payroll_id)
+            .await
+    }
+}
@@ -0,0 +1,194 @@
+use std::{cmp::Reverse, sync::Arc};
+
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Duration, Utc};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::Employee,
+        self_service::{IssuedSelfServiceToken, SelfServiceToken},
+    },
+    error::{AppError, AppResult},
+    services::employee::EmployeeService,
+};
+
+/// Longest lifetime a token can be issued with.
+pub const MAX_TOKEN_LIFETIME_DAYS: u32 = 365;
+
+#[derive(Debug, Clone, Default)]
+pub struct IssueSelfServiceTokenParams {
+    pub label: Option<String>,
+    /// Days until the token stops working; it never expires when `None`.
+    pub expires_in_days: Option<u32>,
+}
+
+#[async_trait]
+pub trait SelfServiceTokenRepository: Send + Sync {
+    async fn insert(&self, token: SelfServiceToken) -> AppResult<SelfServiceToken>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<SelfServiceToken>>;
+    async fn fetch_by_secret_hash(&self, secret_hash: &str) -> AppResult<Option<SelfServiceToken>>;
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<SelfServiceToken>>;
+    async fn update(&self, token: SelfServiceToken) -> AppResult<Option<SelfServiceToken>>;
+    /// Sets `last_used_at` alone, and only while the token is unrevoked; `None` when it was
+    /// revoked or removed in the meantime.
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> AppResult<Option<SelfServiceToken>>;
+}
+
+#[derive(Clone)]
+pub struct SelfServiceService {
+    repository: Arc<dyn SelfServiceTokenRepository>,
+    employee_service: Arc<EmployeeService>,
+}
+
+impl SelfServiceService {
+    pub fn new(
+        repository: Arc<dyn SelfServiceTokenRepository>,
+        employee_service: Arc<EmployeeService>,
+    ) -> Self {
+        Self {
+            repository,
+            employee_service,
+        }
+    }
+
+    /// Mints a token for the employee; the returned secret is never shown again.
+    pub async fn issue(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        params: IssueSelfServiceTokenParams,
+    ) -> AppResult<IssuedSelfServiceToken> {
+        let expires_at = match params.expires_in_days {
+            Some(days) if days == 0 || days > MAX_TOKEN_LIFETIME_DAYS => {
+                return Err(AppError::validation(format!(
+                    "expires_in_days must be between 1 and {MAX_TOKEN_LIFETIME_DAYS}"
+                )));
+            }
+            Some(days) => Some(Utc::now() + Duration::days(i64::from(days))),
+            None => None,
+        };
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+
+        let secret = generate_secret();
+        let token = SelfServiceToken::new(
+            Uuid::new_v4(),
+            organization_id,
+            employee_id,
+            params
+                .label
+                .map(|label| label.trim().to_string())
+                .filter(|label| !label.is_empty()),
+            hash_secret(&secret),
+            expires_at,
+        );
+        let token = self.repository.insert(token).await?;
+
+        Ok(IssuedSelfServiceToken { token, secret })
+    }
+
+    /// Tokens issued to the employee, newest first, including revoked and expired ones.
+    pub async fn list(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<SelfServiceToken>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let mut tokens = self.repository.fetch_by_employee(employee_id).await?;
+        tokens.sort_by_key(|token| Reverse(token.created_at));
+        Ok(tokens)
+    }
+
+    /// Revokes the token; revoking it again keeps the original revocation time.
+    pub async fn revoke(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        token_id: Uuid,
+    ) -> AppResult<Option<SelfServiceToken>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let Some(mut token) = self
+            .repository
+            .fetch(token_id)
+            .await?
+            .filter(|token| token.employee_id == employee_id)
+        else {
+            return Ok(None);
+        };
+        if token.revoked_at.is_some() {
+            return Ok(Some(token));
+        }
+
+        token.revoked_at = Some(Utc::now());
+        self.repository.update(token).await
+    }
+
+    /// The employee a bearer secret belongs to, wherever they currently sit in the organization.
+    pub async fn me(&self, secret: &str) -> AppResult<Employee> {
+        let token = self.authenticate(secret).await?;
+        self.employee_service
+            .locate(token.organization_id, token.employee_id)
+            .await?
+            .ok_or_else(invalid_token)
+    }
+
+    async fn authenticate(&self, secret: &str) -> AppResult<SelfServiceToken> {
+        let now = Utc::now();
+        let token = self
+            .repository
+            .fetch_by_secret_hash(&hash_secret(secret))
+            .await?
+            .filter(|token| token.is_usable(now))
+            .ok_or_else(invalid_token)?;
+
+        // Writing the whole token back would undo a revocation made since it was read.
+        self.repository
+            .touch(token.id, now)
+            .await?
+            .ok_or_else(invalid_token)
+    }
+
+    async fn ensure_employee_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Employee> {
+        self.employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "employee `{employee_id}` not found for division `{division_id}` in payroll `{payroll_id}`"
+                ))
+            })
+    }
+}
+
+fn invalid_token() -> AppError {
+    AppError::unauthorized("self-service token is invalid, expired or revoked")
+}
+
+/// 244 random bits from two v4 UUIDs, URL-safe for headers and links.
+fn generate_secret() -> String {
+    let mut bytes = [0_u8; 32];
+    bytes[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+    bytes[16..].copy_from_slice(Uuid::new_v4().as_bytes());
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn hash_secret(secret: &str) -> String {
+    format!("{:x}", Sha256::digest(secret.as_bytes()))
+}
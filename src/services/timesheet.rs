@@ -0,0 +1,844 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        employee::{Employee, EmployeeStatus},
+        job::Job,
+        project::{ProjectLaborCost, ProjectLaborCostReport},
+        timesheet::{
+            ClockPunch, TimesheetEntry, TimesheetEntryStatus, TimesheetPeriod,
+            TimesheetPeriodStatus, UnapprovedTimesheet, UnapprovedTimesheetReason,
+            UnapprovedTimesheetReport,
+        },
+    },
+    error::{AppError, AppResult},
+    services::{
+        division::DivisionService, employee::EmployeeService, job::JobService,
+        project::ProjectService,
+    },
+};
+
+const MAX_DAILY_HOURS: f64 = 24.0;
+
+#[derive(Debug, Clone)]
+pub struct CreateTimesheetEntryParams {
+    pub date: NaiveDate,
+    pub hours: f64,
+    pub cost_code: Option<String>,
+    pub project_id: Option<Uuid>,
+    pub note: Option<String>,
+    /// Raw and adjusted punches the hours were worked out from, kept for audit.
+    pub punch: Option<ClockPunch>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateTimesheetEntryParams {
+    pub date: Option<NaiveDate>,
+    pub hours: Option<f64>,
+    pub cost_code: Option<Option<String>>,
+    pub project_id: Option<Option<Uuid>>,
+    pub note: Option<Option<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitTimesheetParams {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+}
+
+#[async_trait]
+pub trait TimesheetRepository: Send + Sync {
+    async fn insert(&self, entry: TimesheetEntry) -> AppResult<TimesheetEntry>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<TimesheetEntry>>;
+    async fn fetch_by_employee(&self, employee_id: Uuid) -> AppResult<Vec<TimesheetEntry>>;
+    async fn fetch_by_project(&self, project_id: Uuid) -> AppResult<Vec<TimesheetEntry>>;
+    async fn update(&self, entry: TimesheetEntry) -> AppResult<Option<TimesheetEntry>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+    async fn insert_period(&self, period: TimesheetPeriod) -> AppResult<TimesheetPeriod>;
+    async fn fetch_period(&self, id: Uuid) -> AppResult<Option<TimesheetPeriod>>;
+    async fn fetch_periods_by_employee(&self, employee_id: Uuid)
+    -> AppResult<Vec<TimesheetPeriod>>;
+    async fn update_period(&self, period: TimesheetPeriod) -> AppResult<Option<TimesheetPeriod>>;
+}
+
+#[derive(Clone)]
+pub struct TimesheetService {
+    repository: Arc<dyn TimesheetRepository>,
+    division_service: Arc<DivisionService>,
+    employee_service: Arc<EmployeeService>,
+    job_service: Arc<JobService>,
+    project_service: Arc<ProjectService>,
+}
+
+impl TimesheetService {
+    pub fn new(
+        repository: Arc<dyn TimesheetRepository>,
+        division_service: Arc<DivisionService>,
+        employee_service: Arc<EmployeeService>,
+        job_service: Arc<JobService>,
+        project_service: Arc<ProjectService>,
+    ) -> Self {
+        Self {
+            repository,
+            division_service,
+            employee_service,
+            job_service,
+            project_service,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        params: CreateTimesheetEntryParams,
+    ) -> AppResult<TimesheetEntry> {
+        let hours = Self::validate_hours(params.hours)?;
+        let employee = self
+            .ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        if employee.status == EmployeeStatus::Terminated {
+            return Err(AppError::validation("terminated employees cannot log time"));
+        }
+        Self::validate_date(&employee, params.date)?;
+        self.ensure_period_open(employee_id, params.date).await?;
+        self.ensure_day_capacity(employee_id, params.date, hours, None)
+            .await?;
+        if let Some(project_id) = params.project_id {
+            self.project_service
+                .ensure_chargeable(organization_id, project_id)
+                .await?;
+        }
+
+        let mut entry = TimesheetEntry::new(
+            Uuid::new_v4(),
+            employee_id,
+            params.date,
+            hours,
+            normalize_optional(params.cost_code),
+            params.project_id,
+            normalize_optional(params.note),
+        );
+        entry.punch = params.punch;
+        self.repository.insert(entry).await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        entry_id: Uuid,
+    ) -> AppResult<Option<TimesheetEntry>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let entry = self.repository.fetch(entry_id).await?;
+        Ok(entry.filter(|entry| entry.employee_id == employee_id))
+    }
+
+    /// Lists entries by date, optionally only those between `from` and `to`, inclusive.
+    pub async fn list(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> AppResult<Vec<TimesheetEntry>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let mut entries: Vec<TimesheetEntry> = self
+            .repository
+            .fetch_by_employee(employee_id)
+            .await?
+            .into_iter()
+            .filter(|entry| from.is_none_or(|from| entry.date >= from))
+            .filter(|entry| to.is_none_or(|to| entry.date <= to))
+            .collect();
+        entries.sort_by_key(TimesheetEntry::chronological_key);
+        Ok(entries)
+    }
+
+    /// Edits an entry that has not been approved yet; rejected entries go back to review.
+    /// Entries of a submitted or approved timesheet are held until it is rejected.
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        entry_id: Uuid,
+        params: UpdateTimesheetEntryParams,
+    ) -> AppResult<Option<TimesheetEntry>> {
+        if params.date.is_none()
+            && params.hours.is_none()
+            && params.cost_code.is_none()
+            && params.project_id.is_none()
+            && params.note.is_none()
+        {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        let employee = self
+            .ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let Some(mut entry) = self
+            .repository
+            .fetch(entry_id)
+            .await?
+            .filter(|entry| entry.employee_id == employee_id)
+        else {
+            return Ok(None);
+        };
+        Self::ensure_not_approved(&entry)?;
+        self.ensure_period_open(employee_id, entry.date).await?;
+
+        if let Some(date) = params.date {
+            Self::validate_date(&employee, date)?;
+            self.ensure_period_open(employee_id, date).await?;
+            entry.date = date;
+        }
+        if let Some(hours) = params.hours {
+            entry.hours = Self::validate_hours(hours)?;
+        }
+        if let Some(cost_code) = params.cost_code {
+            entry.cost_code = normalize_optional(cost_code);
+        }
+        if let Some(project_id) = params.project_id {
+            if let Some(project_id) = project_id {
+                self.project_service
+                    .ensure_chargeable(organization_id, project_id)
+                    .await?;
+            }
+            entry.project_id = project_id;
+        }
+        if let Some(note) = params.note {
+            entry.note = normalize_optional(note);
+        }
+        self.ensure_day_capacity(employee_id, entry.date, entry.hours, Some(entry.id))
+            .await?;
+
+        entry.status = TimesheetEntryStatus::Submitted;
+        entry.review_note = None;
+        entry.reviewed_at = None;
+        self.repository.update(entry).await
+    }
+
+    /// Deletes an entry unless it has been approved or its timesheet is held for review.
+    pub async fn delete(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        entry_id: Uuid,
+    ) -> AppResult<bool> {
+        let Some(entry) = self
+            .get(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                entry_id,
+            )
+            .await?
+        else {
+            return Ok(false);
+        };
+        Self::ensure_not_approved(&entry)?;
+        self.ensure_period_open(employee_id, entry.date).await?;
+
+        self.repository.delete(entry_id).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn review(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        entry_id: Uuid,
+        status: TimesheetEntryStatus,
+        note: Option<String>,
+    ) -> AppResult<Option<TimesheetEntry>> {
+        if status == TimesheetEntryStatus::Submitted {
+            return Err(AppError::validation(
+                "a review must approve or reject the entry",
+            ));
+        }
+        let Some(mut entry) = self
+            .get(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                entry_id,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+        if entry.status != TimesheetEntryStatus::Submitted {
+            return Err(AppError::conflict(format!(
+                "timesheet entry `{entry_id}` has already been reviewed"
+            )));
+        }
+
+        entry.status = status;
+        entry.review_note = normalize_optional(note);
+        entry.reviewed_at = Some(Utc::now());
+        self.repository.update(entry).await
+    }
+
+    /// Submits the employee's timesheet for `period_start`..=`period_end` to their supervisor
+    /// or division manager. Its entries are held until the timesheet is rejected.
+    pub async fn submit_period(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        params: SubmitTimesheetParams,
+    ) -> AppResult<TimesheetPeriod> {
+        let SubmitTimesheetParams {
+            period_start,
+            period_end,
+        } = params;
+        if period_start > period_end {
+            return Err(AppError::validation(
+                "period_start must not be after period_end",
+            ));
+        }
+        if period_end > Utc::now().date_naive() {
+            return Err(AppError::validation(
+                "a timesheet cannot be submitted before its period ends",
+            ));
+        }
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+
+        if let Some(existing) = self
+            .repository
+            .fetch_periods_by_employee(employee_id)
+            .await?
+            .into_iter()
+            .find(|period| period.locks_entries() && period.overlaps(period_start, period_end))
+        {
+            return Err(AppError::conflict(format!(
+                "a timesheet for {} to {} has already been submitted",
+                existing.period_start, existing.period_end
+            )));
+        }
+
+        let entries: Vec<TimesheetEntry> = self
+            .repository
+            .fetch_by_employee(employee_id)
+            .await?
+            .into_iter()
+            .filter(|entry| period_start <= entry.date && entry.date <= period_end)
+            .collect();
+        if let Some(rejected) = entries
+            .iter()
+            .find(|entry| entry.status == TimesheetEntryStatus::Rejected)
+        {
+            return Err(AppError::validation(format!(
+                "the rejected entry on {} must be corrected or deleted before submitting",
+                rejected.date
+            )));
+        }
+
+        let hours = entries.iter().map(|entry| entry.hours).sum();
+        let period =
+            TimesheetPeriod::new(Uuid::new_v4(), employee_id, period_start, period_end, hours);
+        self.repository.insert_period(period).await
+    }
+
+    /// Timesheets the employee has submitted, by period.
+    pub async fn list_periods(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Vec<TimesheetPeriod>> {
+        self.ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let mut periods = self
+            .repository
+            .fetch_periods_by_employee(employee_id)
+            .await?;
+        periods.sort_by_key(|period| (period.period_start, period.submitted_at));
+        Ok(periods)
+    }
+
+    /// Approves or rejects a submitted timesheet on behalf of `approver_id`, who must be the
+    /// employee's supervisor or the manager of their division. Approving also approves the
+    /// entries still awaiting review; rejecting releases them for correction.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn review_period(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        period_id: Uuid,
+        approver_id: Uuid,
+        status: TimesheetPeriodStatus,
+        note: Option<String>,
+    ) -> AppResult<Option<TimesheetPeriod>> {
+        if status == TimesheetPeriodStatus::Submitted {
+            return Err(AppError::validation(
+                "a review must approve or reject the timesheet",
+            ));
+        }
+        let employee = self
+            .ensure_employee_accessible(organization_id, payroll_id, division_id, employee_id)
+            .await?;
+        let Some(mut period) = self
+            .repository
+            .fetch_period(period_id)
+            .await?
+            .filter(|period| period.employee_id == employee_id)
+        else {
+            return Ok(None);
+        };
+        self.ensure_manager_of(organization_id, payroll_id, &employee, approver_id)
+            .await?;
+        if period.status != TimesheetPeriodStatus::Submitted {
+            return Err(AppError::conflict(format!(
+                "timesheet `{period_id}` has already been reviewed"
+            )));
+        }
+
+        let now = Utc::now();
+        if status == TimesheetPeriodStatus::Approved {
+            for mut entry in self
+                .repository
+                .fetch_by_employee(employee_id)
+                .await?
+                .into_iter()
+                .filter(|entry| {
+                    period.covers(entry.date) && entry.status == TimesheetEntryStatus::Submitted
+                })
+            {
+                entry.status = TimesheetEntryStatus::Approved;
+                entry.reviewed_at = Some(now);
+                self.repository.update(entry).await?;
+            }
+        }
+
+        period.status = status;
+        period.reviewed_by = Some(approver_id);
+        period.review_note = normalize_optional(note);
+        period.reviewed_at = Some(now);
+        self.repository.update_period(period).await
+    }
+
+    /// Employees of the payroll with hours logged between `from` and `to` that no approved
+    /// timesheet covers. A payroll run over the range should wait until the list is empty.
+    pub async fn unapproved_report(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<UnapprovedTimesheetReport> {
+        if from > to {
+            return Err(AppError::validation("from must not be after to"));
+        }
+
+        let mut employees = self
+            .employee_service
+            .search(organization_id, Some(payroll_id), None)
+            .await?;
+        employees.sort_by(|a, b| {
+            (&a.last_name, &a.first_name, a.id).cmp(&(&b.last_name, &b.first_name, b.id))
+        });
+
+        let mut blocking = Vec::new();
+        for employee in employees {
+            let periods = self
+                .repository
+                .fetch_periods_by_employee(employee.id)
+                .await?;
+            let mut entries: Vec<TimesheetEntry> = self
+                .repository
+                .fetch_by_employee(employee.id)
+                .await?
+                .into_iter()
+                .filter(|entry| {
+                    from <= entry.date
+                        && entry.date <= to
+                        && entry.status != TimesheetEntryStatus::Rejected
+                })
+                .collect();
+            entries.sort_by_key(TimesheetEntry::chronological_key);
+
+            let mut unapproved: Vec<UnapprovedTimesheet> = Vec::new();
+            for entry in entries {
+                let covering = |status: TimesheetPeriodStatus| {
+                    periods
+                        .iter()
+                        .filter(|period| period.status == status && period.covers(entry.date))
+                        .max_by_key(|period| period.submitted_at)
+                };
+                if covering(TimesheetPeriodStatus::Approved).is_some() {
+                    continue;
+                }
+                let (reason, period_id) =
+                    if let Some(period) = covering(TimesheetPeriodStatus::Submitted) {
+                        (UnapprovedTimesheetReason::AwaitingApproval, Some(period.id))
+                    } else if let Some(period) = covering(TimesheetPeriodStatus::Rejected) {
+                        (UnapprovedTimesheetReason::Rejected, Some(period.id))
+                    } else {
+                        (UnapprovedTimesheetReason::NotSubmitted, None)
+                    };
+
+                match unapproved
+                    .iter_mut()
+                    .find(|item| item.reason == reason && item.period_id == period_id)
+                {
+                    Some(item) => item.hours += entry.hours,
+                    None => unapproved.push(UnapprovedTimesheet {
+                        employee_id: employee.id,
+                        division_id: employee.division_id,
+                        first_name: employee.first_name.clone(),
+                        last_name: employee.last_name.clone(),
+                        reason,
+                        period_id,
+                        hours: entry.hours,
+                    }),
+                }
+            }
+            blocking.extend(unapproved);
+        }
+
+        Ok(UnapprovedTimesheetReport {
+            payroll_id,
+            from,
+            to,
+            ready: blocking.is_empty(),
+            blocking,
+        })
+    }
+
+    /// Approved hours logged between `from` and `to`, inclusive, that an approved timesheet
+    /// covers.
+    ///
+    /// Payroll runs use this to pay hourly employees for time actually worked.
+    pub async fn approved_hours(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<f64> {
+        let entries = self
+            .approved_entries(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                from,
+                to,
+            )
+            .await?;
+
+        Ok(entries.iter().map(|entry| entry.hours).sum())
+    }
+
+    /// Labor cost of the paid hours charged to each project of the organization between
+    /// `from` and `to`, inclusive, at each employee's current hourly rate.
+    pub async fn project_labor_costs(
+        &self,
+        organization_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<ProjectLaborCostReport> {
+        if from > to {
+            return Err(AppError::validation("from must not be after to"));
+        }
+
+        let projects: HashMap<Uuid, _> = self
+            .project_service
+            .list(organization_id)
+            .await?
+            .into_iter()
+            .map(|project| (project.id, project))
+            .collect();
+        let employees = self
+            .employee_service
+            .search(organization_id, None, None)
+            .await?;
+
+        let mut jobs: HashMap<Uuid, Job> = HashMap::new();
+        let payroll_ids: HashSet<Uuid> = employees
+            .iter()
+            .map(|employee| employee.payroll_id)
+            .collect();
+        for payroll_id in payroll_ids {
+            for job in self.job_service.list(organization_id, payroll_id).await? {
+                jobs.insert(job.id, job);
+            }
+        }
+
+        let mut costs: HashMap<(Uuid, Option<String>), (f64, f64)> = HashMap::new();
+        let mut untagged_hours = 0.0;
+        for employee in employees {
+            let entries = self
+                .approved_entries(
+                    organization_id,
+                    employee.payroll_id,
+                    employee.division_id,
+                    employee.id,
+                    from,
+                    to,
+                )
+                .await?;
+            if entries.is_empty() {
+                continue;
+            }
+
+            let job = jobs.get(&employee.job_id);
+            let rate = match (
+                job,
+                self.employee_service
+                    .work_expectation(
+                        organization_id,
+                        employee.payroll_id,
+                        employee.division_id,
+                        employee.id,
+                    )
+                    .await?,
+            ) {
+                (Some(job), Some(expectation)) => job.hourly_rate(&expectation),
+                _ => 0.0,
+            };
+
+            for entry in entries {
+                let Some(project_id) = entry.project_id.filter(|id| projects.contains_key(id))
+                else {
+                    untagged_hours += entry.hours;
+                    continue;
+                };
+                let currency = job.and_then(|job| job.currency.clone());
+                let (hours, cost) = costs.entry((project_id, currency)).or_default();
+                *hours += entry.hours;
+                *cost += entry.hours * rate;
+            }
+        }
+
+        let mut lines: Vec<ProjectLaborCost> = costs
+            .into_iter()
+            .map(|((project_id, currency), (hours, cost))| {
+                let project = &projects[&project_id];
+                ProjectLaborCost {
+                    project_id,
+                    code: project.code.clone(),
+                    name: project.name.clone(),
+                    currency,
+                    hours,
+                    cost: (cost * 100.0).round() / 100.0,
+                }
+            })
+            .collect();
+        lines.sort_by(|a, b| (&a.code, &a.currency).cmp(&(&b.code, &b.currency)));
+
+        Ok(ProjectLaborCostReport {
+            organization_id,
+            from,
+            to,
+            projects: lines,
+            untagged_hours,
+        })
+    }
+
+    /// Entries logged between `from` and `to`, inclusive, that payroll pays: approved
+    /// themselves and covered by an approved timesheet.
+    pub async fn approved_entries(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> AppResult<Vec<TimesheetEntry>> {
+        let entries = self
+            .list(
+                organization_id,
+                payroll_id,
+                division_id,
+                employee_id,
+                Some(from),
+                Some(to),
+            )
+            .await?;
+        let approved: Vec<TimesheetPeriod> = self
+            .repository
+            .fetch_periods_by_employee(employee_id)
+            .await?
+            .into_iter()
+            .filter(|period| period.status == TimesheetPeriodStatus::Approved)
+            .collect();
+
+        Ok(entries
+            .into_iter()
+            .filter(|entry| entry.status == TimesheetEntryStatus::Approved)
+            .filter(|entry| approved.iter().any(|period| period.covers(entry.date)))
+            .collect())
+    }
+
+    async fn ensure_day_capacity(
+        &self,
+        employee_id: Uuid,
+        date: NaiveDate,
+        hours: f64,
+        except: Option<Uuid>,
+    ) -> AppResult<()> {
+        let logged: f64 = self
+            .repository
+            .fetch_by_employee(employee_id)
+            .await?
+            .iter()
+            .filter(|entry| {
+                entry.date == date
+                    && Some(entry.id) != except
+                    && entry.status != TimesheetEntryStatus::Rejected
+            })
+            .map(|entry| entry.hours)
+            .sum();
+
+        if logged + hours > MAX_DAILY_HOURS {
+            return Err(AppError::validation(format!(
+                "{date} already has {logged} hours logged; a day cannot exceed {MAX_DAILY_HOURS}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_period_open(&self, employee_id: Uuid, date: NaiveDate) -> AppResult<()> {
+        if let Some(period) = self
+            .repository
+            .fetch_periods_by_employee(employee_id)
+            .await?
+            .into_iter()
+            .find(|period| period.locks_entries() && period.covers(date))
+        {
+            let state = match period.status {
+                TimesheetPeriodStatus::Approved => "approved",
+                _ => "awaiting approval",
+            };
+            return Err(AppError::conflict(format!(
+                "the timesheet for {} to {} is {state}; its entries can no longer change",
+                period.period_start, period.period_end
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `approver_id` is the employee's supervisor or the manager of their
+    /// division, the people who may sign off on their time and leave.
+    pub(crate) async fn ensure_manager_of(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        employee: &Employee,
+        approver_id: Uuid,
+    ) -> AppResult<()> {
+        if approver_id == employee.id {
+            return Err(AppError::validation(
+                "employees cannot approve their own time or leave",
+            ));
+        }
+        if employee.supervisor_id == Some(approver_id) {
+            return Ok(());
+        }
+        let manages_division = self
+            .division_service
+            .get(organization_id, payroll_id, employee.division_id)
+            .await?
+            .is_some_and(|division| division.manager_employee_id == Some(approver_id));
+        if !manages_division {
+            return Err(AppError::validation(format!(
+                "employee `{approver_id}` is neither the supervisor of employee `{}` nor the manager of their division",
+                employee.id
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_employee_accessible(
+        &self,
+        organization_id: Uuid,
+        payroll_id: Uuid,
+        division_id: Uuid,
+        employee_id: Uuid,
+    ) -> AppResult<Employee> {
+        self.employee_service
+            .get(organization_id, payroll_id, division_id, employee_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found(format!(
+                    "employee `{employee_id}` not found for division `{division_id}` in payroll `{payroll_id}`"
+                ))
+            })
+    }
+
+    fn ensure_not_approved(entry: &TimesheetEntry) -> AppResult<()> {
+        if entry.status == TimesheetEntryStatus::Approved {
+            Err(AppError::conflict(format!(
+                "timesheet entry `{}` is approved and can no longer change",
+                entry.id
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_date(employee: &Employee, date: NaiveDate) -> AppResult<()> {
+        if date < employee.hire_date {
+            return Err(AppError::validation(
+                "time cannot be logged before the employee's hire date",
+            ));
+        }
+        if date > Utc::now().date_naive() {
+            return Err(AppError::validation("time cannot be logged in the future"));
+        }
+
+        Ok(())
+    }
+
+    fn validate_hours(value: f64) -> AppResult<f64> {
+        if !value.is_finite() || value <= 0.0 || value > MAX_DAILY_HOURS {
+            return Err(AppError::validation(
+                "hours must be greater than 0 and at most 24",
+            ));
+        }
+
+        Ok(value)
+    }
+}
+
+fn normalize_optional(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
@@ -0,0 +1,229 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    domain::work_schedule::{ScheduleDay, WorkSchedule},
+    error::{AppError, AppResult},
+    services::organization::OrganizationService,
+};
+
+#[derive(Debug, Clone)]
+pub struct CreateWorkScheduleParams {
+    pub name: String,
+    pub days: Vec<ScheduleDay>,
+    pub daily_overtime_after: Option<f64>,
+    pub weekly_overtime_after: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateWorkScheduleParams {
+    pub name: Option<String>,
+    pub days: Option<Vec<ScheduleDay>>,
+    pub daily_overtime_after: Option<Option<f64>>,
+    pub weekly_overtime_after: Option<Option<f64>>,
+}
+
+#[async_trait]
+pub trait WorkScheduleRepository: Send + Sync {
+    async fn insert(&self, schedule: WorkSchedule) -> AppResult<WorkSchedule>;
+    async fn fetch(&self, id: Uuid) -> AppResult<Option<WorkSchedule>>;
+    async fn fetch_by_organization(&self, organization_id: Uuid) -> AppResult<Vec<WorkSchedule>>;
+    async fn update(&self, schedule: WorkSchedule) -> AppResult<Option<WorkSchedule>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct WorkScheduleService {
+    repository: Arc<dyn WorkScheduleRepository>,
+    organization_service: Arc<OrganizationService>,
+}
+
+impl WorkScheduleService {
+    pub fn new(
+        repository: Arc<dyn WorkScheduleRepository>,
+        organization_service: Arc<OrganizationService>,
+    ) -> Self {
+        Self {
+            repository,
+            organization_service,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        organization_id: Uuid,
+        params: CreateWorkScheduleParams,
+    ) -> AppResult<WorkSchedule> {
+        let name = Self::normalize_name(&params.name)?;
+        let days = Self::normalize_days(params.days)?;
+        let daily_overtime_after =
+            Self::validate_threshold(params.daily_overtime_after, "daily overtime threshold")?;
+        let weekly_overtime_after =
+            Self::validate_threshold(params.weekly_overtime_after, "weekly overtime threshold")?;
+        self.ensure_organization_exists(organization_id).await?;
+        self.ensure_name_available(organization_id, &name, None)
+            .await?;
+
+        let schedule = WorkSchedule::new(
+            Uuid::new_v4(),
+            name,
+            days,
+            daily_overtime_after,
+            weekly_overtime_after,
+            organization_id,
+        );
+        self.repository.insert(schedule).await
+    }
+
+    pub async fn get(
+        &self,
+        organization_id: Uuid,
+        schedule_id: Uuid,
+    ) -> AppResult<Option<WorkSchedule>> {
+        let schedule = self.repository.fetch(schedule_id).await?;
+        Ok(schedule.filter(|schedule| schedule.organization_id == organization_id))
+    }
+
+    pub async fn list(&self, organization_id: Uuid) -> AppResult<Vec<WorkSchedule>> {
+        self.ensure_organization_exists(organization_id).await?;
+        let mut schedules = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?;
+        schedules.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(schedules)
+    }
+
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        schedule_id: Uuid,
+        params: UpdateWorkScheduleParams,
+    ) -> AppResult<Option<WorkSchedule>> {
+        if params.name.is_none()
+            && params.days.is_none()
+            && params.daily_overtime_after.is_none()
+            && params.weekly_overtime_after.is_none()
+        {
+            return Err(AppError::validation("no fields supplied for update"));
+        }
+
+        let Some(mut schedule) = self.get(organization_id, schedule_id).await? else {
+            return Ok(None);
+        };
+
+        if let Some(name) = params.name.as_deref() {
+            let name = Self::normalize_name(name)?;
+            self.ensure_name_available(organization_id, &name, Some(schedule_id))
+                .await?;
+            schedule.name = name;
+        }
+        if let Some(days) = params.days {
+            schedule.days = Self::normalize_days(days)?;
+        }
+        if let Some(threshold) = params.daily_overtime_after {
+            schedule.daily_overtime_after =
+                Self::validate_threshold(threshold, "daily overtime threshold")?;
+        }
+        if let Some(threshold) = params.weekly_overtime_after {
+            schedule.weekly_overtime_after =
+                Self::validate_threshold(threshold, "weekly overtime threshold")?;
+        }
+
+        self.repository.update(schedule).await
+    }
+
+    /// Employees still pointing at a deleted schedule fall back to their `hours`.
+    pub async fn delete(&self, organization_id: Uuid, schedule_id: Uuid) -> AppResult<bool> {
+        if self.get(organization_id, schedule_id).await?.is_none() {
+            return Ok(false);
+        }
+
+        self.repository.delete(schedule_id).await
+    }
+
+    async fn ensure_name_available(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+        except: Option<Uuid>,
+    ) -> AppResult<()> {
+        let taken = self
+            .repository
+            .fetch_by_organization(organization_id)
+            .await?
+            .into_iter()
+            .any(|schedule| Some(schedule.id) != except && schedule.name == name);
+
+        if taken {
+            Err(AppError::conflict(format!(
+                "work schedule `{name}` already exists"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn ensure_organization_exists(&self, organization_id: Uuid) -> AppResult<()> {
+        let exists = self
+            .organization_service
+            .get(organization_id)
+            .await?
+            .is_some();
+
+        if exists {
+            Ok(())
+        } else {
+            Err(AppError::not_found(format!(
+                "organization `{organization_id}` not found"
+            )))
+        }
+    }
+
+    fn normalize_name(value: &str) -> AppResult<String> {
+        let name = value.trim();
+        if name.is_empty() {
+            return Err(AppError::validation("work schedule name cannot be empty"));
+        }
+
+        Ok(name.to_string())
+    }
+
+    fn normalize_days(mut days: Vec<ScheduleDay>) -> AppResult<Vec<ScheduleDay>> {
+        if days.is_empty() {
+            return Err(AppError::validation(
+                "a work schedule needs at least one working day",
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        for day in &days {
+            if !seen.insert(day.weekday) {
+                return Err(AppError::validation(format!(
+                    "{} appears more than once in the schedule",
+                    day.weekday
+                )));
+            }
+            if !day.hours.is_finite() || day.hours <= 0.0 || day.hours > 24.0 {
+                return Err(AppError::validation(format!(
+                    "hours for {} must be greater than 0 and at most 24",
+                    day.weekday
+                )));
+            }
+        }
+
+        days.sort_by_key(|day| day.weekday.num_days_from_monday());
+        Ok(days)
+    }
+
+    fn validate_threshold(value: Option<f64>, field: &str) -> AppResult<Option<f64>> {
+        match value {
+            Some(hours) if !hours.is_finite() || hours <= 0.0 => Err(AppError::validation(
+                format!("{field} must be a positive number of hours"),
+            )),
+            other => Ok(other),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates an employee without a schedule and returns its URI.
+async fn setup(app: &Router) -> String {
+    let (_, organization) = send_json(
+        app,
+        "POST",
+        "/organizations",
+        json!({"name": "Timesheet Org"}),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Timesheet Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let (status, employee) = send_json(
+        app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "TS-1",
+            "last_name": "Hourly",
+            "first_name": "Robin",
+            "address": "4 Beach Rd",
+            "phone": "555-0404",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1990-04-04",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2020-01-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-TS",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    format!("{employees_uri}/{}", employee["id"].as_str().unwrap())
+}
+
+#[tokio::test]
+async fn attendance_import_logs_shifts_and_flags_anomalies() {
+    let app = support::test_router();
+    let employee_uri = setup(&app).await;
+    let (division_uri, _) = employee_uri.split_once("/employees/").unwrap();
+    let import_uri = format!("{division_uri}/attendance/import");
+
+    let csv = "id_number,date,clock_in,clock_out\n\
+               TS-1,2024-03-04,08:00,17:00\n\
+               TS-1,2024-03-05,07:00,19:30\n\
+               TS-1,2024-03-07,08:00,17:00\n\
+               TS-1,2024-03-08,22:00,06:00\n\
+               TS-1,2024-03-09,08:00,14:00\n\
+               XX-9,2024-03-04,08:00,17:00\n\
+               TS-1,2024-03-04,8am,5pm\n";
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(&import_uri)
+                .header("content-type", "text/csv")
+                .body(Body::from(csv))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let report = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(report["imported"], 5);
+    assert_eq!(report["failed"], 2);
+    assert_eq!(report["rows"][5]["line"], 7);
+    assert_eq!(report["rows"][5]["employee_id"], Value::Null);
+
+    let anomalies = report["anomalies"].as_array().unwrap();
+    let kinds: Vec<(&str, &str)> = anomalies
+        .iter()
+        .map(|anomaly| {
+            (
+                anomaly["kind"].as_str().unwrap(),
+                anomaly["date"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert!(kinds.contains(&("missing_day", "2024-03-06")));
+    assert!(kinds.contains(&("unscheduled_day", "2024-03-09")));
+    assert!(kinds.contains(&("excessive_overtime", "2024-03-04")));
+    assert_eq!(anomalies.len(), 3);
+
+    // The night shift is cut at midnight, so each calendar day gets the hours worked on it.
+    let (status, entries) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/timesheet-entries?from=2024-03-08&to=2024-03-09"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(entries[0]["hours"], 2.0);
+    assert_eq!(entries[0]["note"], "Time clock 22:00-06:00");
+    assert_eq!(entries[0]["punch"]["overnight"], true);
+    assert_eq!(report["rows"][3]["next_day_entry_id"], entries[1]["id"]);
+    assert_eq!(entries[1]["date"], "2024-03-09");
+    assert_eq!(entries[1]["hours"], 6.0);
+    assert_eq!(entries[1]["punch"]["continued"], true);
+    assert_eq!(
+        entries[1]["note"],
+        "Time clock 22:00-06:00, continued from 2024-03-08"
+    );
+
+    let (status, report) = send_json(
+        &app,
+        "POST",
+        &import_uri,
+        json!([{"id_number": "TS-1", "date": "2024-03-04", "clock_in": "08:00:00", "clock_out": "17:00"}]),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["imported"], 0);
+    assert_eq!(report["rows"][0]["error"], "shift was already imported");
+
+    let (status, report) = send_json(
+        &app,
+        "POST",
+        &import_uri,
+        json!([
+            {"id_number": "TS-1", "date": "2024-03-11", "clock_in": "06:00", "clock_out": "10:00"},
+            {"id_number": "TS-1", "date": "2024-03-11", "clock_in": "16:00", "clock_out": "20:00"}
+        ]),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["imported"], 2);
+    let (_, entries) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/timesheet-entries?from=2024-03-11&to=2024-03-11"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(entries.as_array().unwrap().len(), 2);
+    assert!(
+        entries
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|entry| entry["punch"]["split"] == true && entry["punch"]["overnight"] == false)
+    );
+}
@@ -0,0 +1,267 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn create_organization(app: &Router) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Employees Org"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_payroll(app: &Router, organization_id: Uuid) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/payrolls"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": "Employees Payroll",
+                        "description": "Payroll for employees"
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_job(app: &Router, organization_id: Uuid, payroll_id: Uuid, title: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/jobs"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "job_title": title,
+                        "salary": 50000.0
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_division(
+    app: &Router,
+    organization_id: Uuid,
+    payroll_id: Uuid,
+    name: &str,
+) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/divisions"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": name,
+                        "description": format!("{name} division"),
+                        "budget_code": format!("BC-{name}")
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+#[tokio::test]
+async fn bank_account_rules_catch_typos_at_entry() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Cashier").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Tills").await;
+    let banks_uri = format!("/organizations/{organization_id}/banks");
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let send = |method: &str, uri: String, payload: Value| {
+        let app = app.clone();
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request");
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body = if bytes.is_empty() {
+                Value::Null
+            } else {
+                read_json(bytes)
+            };
+            (status, body)
+        }
+    };
+    let employee = |id_number: &str, bank_id: &Value, account: &str| {
+        json!({
+            "id_number": id_number,
+            "last_name": "Till",
+            "first_name": "Casey",
+            "address": "3 Coin St",
+            "phone": "555-3030",
+            "place_of_birth": "Town",
+            "date_of_birth": "1991-03-03",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2023-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": account,
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        })
+    };
+
+    let (status, _) = send(
+        "POST",
+        banks_uri.clone(),
+        json!({"name": "Backwards Bank", "account_rule": {"min_length": 30, "max_length": 15}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, bank) = send(
+        "POST",
+        banks_uri.clone(),
+        json!({
+            "name": "Euro Bank",
+            "account_rule": {
+                "min_length": 15,
+                "max_length": 34,
+                "prefixes": ["gb", "DE"],
+                "checksum": "iban_mod97"
+            }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(bank["account_rule"]["prefixes"], json!(["GB", "DE"]));
+
+    // One digit off, a foreign IBAN and a plainly short number are all refused.
+    for account in [
+        "GB82 WEST 1234 5698 7654 33",
+        "FR14 2004 1010 0505 0001 3M02 606",
+        "GB82",
+    ] {
+        let (status, error) = send(
+            "POST",
+            employees_uri.clone(),
+            employee("TILL-BAD", &bank["id"], account),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{account}");
+        assert!(error["error"].as_str().unwrap().contains("Euro Bank"));
+    }
+
+    let (status, created) = send(
+        "POST",
+        employees_uri.clone(),
+        employee("TILL-1", &bank["id"], "gb82 west 1234 5698 7654 32"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(
+        created["payment_instructions"][0]["account"],
+        "GB82WEST12345698765432"
+    );
+
+    let bank_uri = format!("{banks_uri}/{}", bank["id"].as_str().unwrap());
+    let (status, updated) = send(
+        "PUT",
+        bank_uri.clone(),
+        json!({"account_rule": {"checksum": "luhn"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(updated["account_rule"]["checksum"], "luhn");
+    let (status, _) = send(
+        "POST",
+        employees_uri.clone(),
+        employee("TILL-2", &bank["id"], "7992739871"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send(
+        "POST",
+        employees_uri.clone(),
+        employee("TILL-2", &bank["id"], "79927398713"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, cleared) = send("PUT", bank_uri, json!({"account_rule": null})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(cleared["account_rule"].is_null());
+    let (status, created) = send(
+        "POST",
+        employees_uri.clone(),
+        employee("TILL-3", &bank["id"], " any-thing "),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created["payment_instructions"][0]["account"], "any-thing");
+}
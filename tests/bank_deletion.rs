@@ -0,0 +1,365 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn create_organization(app: &Router) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Employees Org"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_payroll(app: &Router, organization_id: Uuid) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/payrolls"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": "Employees Payroll",
+                        "description": "Payroll for employees"
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_bank(app: &Router, organization_id: Uuid, name: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/banks"))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": name}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_job(app: &Router, organization_id: Uuid, payroll_id: Uuid, title: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/jobs"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "job_title": title,
+                        "salary": 50000.0
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_division(
+    app: &Router,
+    organization_id: Uuid,
+    payroll_id: Uuid,
+    name: &str,
+) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/divisions"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": name,
+                        "description": format!("{name} division"),
+                        "budget_code": format!("BC-{name}")
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+#[tokio::test]
+async fn deleting_a_bank_requires_reassigning_its_employees() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let closing_bank = create_bank(&app, organization_id, "Closing Bank").await;
+    let other_bank = create_bank(&app, organization_id, "Other Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Teller").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Branch").await;
+    let banks_uri = format!("/organizations/{organization_id}/banks");
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let send = |method: &str, uri: String, payload: Value| {
+        let app = app.clone();
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request");
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body = if bytes.is_empty() {
+                Value::Null
+            } else {
+                read_json(bytes)
+            };
+            (status, body)
+        }
+    };
+
+    let mut employee_ids = Vec::new();
+    for (id_number, payment_instructions) in [
+        (
+            "TEL-1",
+            json!([{ "bank_id": closing_bank, "account": "ACCT-1", "amount": { "percentage": 100.0 }, "priority": 1 }]),
+        ),
+        (
+            "TEL-2",
+            json!([
+                { "bank_id": other_bank, "account": "ACCT-2A", "amount": { "percentage": 50.0 }, "priority": 1 },
+                { "bank_id": closing_bank, "account": "ACCT-2B", "amount": { "percentage": 50.0 }, "priority": 2 }
+            ]),
+        ),
+    ] {
+        let (status, created) = send(
+            "POST",
+            employees_uri.clone(),
+            json!({
+                "id_number": id_number,
+                "last_name": "Counter",
+                "first_name": "Jo",
+                "address": "5 Vault Way",
+                "phone": "555-5050",
+                "place_of_birth": "Town",
+                "date_of_birth": "1988-05-05",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": "M",
+                "hire_date": "2021-05-01",
+                "clasification": "Full-time",
+                "job_id": job_id,
+                "payment_instructions": payment_instructions,
+                "status": "Active",
+                "hours": 40
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        employee_ids.push(created["id"].as_str().unwrap().to_string());
+    }
+    let (status, strict_bank) = send(
+        "POST",
+        banks_uri.clone(),
+        json!({"name": "Strict Bank", "account_rule": {"prefixes": ["NL"]}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let closing_uri = format!("{banks_uri}/{closing_bank}");
+    let (status, error) = send("DELETE", closing_uri.clone(), json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert!(error["error"].as_str().unwrap().contains("2 employee(s)"));
+    let (status, _) = send(
+        "DELETE",
+        format!("{closing_uri}?reassign_to={closing_bank}"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send(
+        "DELETE",
+        format!("{closing_uri}?reassign_to={}", Uuid::new_v4()),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // The accounts do not fit the strict bank, so nothing moves.
+    let (status, _) = send(
+        "DELETE",
+        format!(
+            "{closing_uri}?reassign_to={}",
+            strict_bank["id"].as_str().unwrap()
+        ),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send("GET", closing_uri.clone(), json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(
+        "DELETE",
+        format!("{closing_uri}?reassign_to={other_bank}"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send("GET", closing_uri, json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    for employee_id in &employee_ids {
+        let (status, employee) =
+            send("GET", format!("{employees_uri}/{employee_id}"), json!({})).await;
+        assert_eq!(status, StatusCode::OK);
+        for instruction in employee["payment_instructions"].as_array().unwrap() {
+            assert_eq!(instruction["bank_id"], other_bank.to_string());
+        }
+    }
+}
+
+#[tokio::test]
+async fn reassigned_accounts_are_verified_again_at_their_new_bank() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let closing_bank = create_bank(&app, organization_id, "Closing Bank").await;
+    let other_bank = create_bank(&app, organization_id, "Other Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Teller").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Branch").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let send = |method: &str, uri: String, payload: Value| {
+        let app = app.clone();
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request");
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body = if bytes.is_empty() {
+                Value::Null
+            } else {
+                read_json(bytes)
+            };
+            (status, body)
+        }
+    };
+
+    let (status, employee) = send(
+        "POST",
+        employees_uri.clone(),
+        json!({
+            "id_number": "VER-1",
+            "last_name": "Checked",
+            "first_name": "Alex",
+            "address": "6 Vault Way",
+            "phone": "555-6060",
+            "place_of_birth": "Town",
+            "date_of_birth": "1989-06-06",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2021-06-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": closing_bank,
+                "account": "ACCT-9",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let employee_uri = format!("{employees_uri}/{}", employee["id"].as_str().unwrap());
+
+    let (status, verified) = send(
+        "POST",
+        format!("{employee_uri}/payment-instructions/1/document-verification"),
+        json!({"reference": "LETTER-9", "accepted": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified["verification"]["status"], "verified");
+
+    let (status, _) = send(
+        "DELETE",
+        format!("/organizations/{organization_id}/banks/{closing_bank}?reassign_to={other_bank}"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, employee) = send("GET", employee_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    let instruction = &employee["payment_instructions"][0];
+    assert_eq!(instruction["bank_id"], other_bank.to_string());
+    assert_eq!(instruction["account"], "ACCT-9");
+    assert_eq!(instruction["verification"]["status"], "unverified");
+}
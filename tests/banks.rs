@@ -178,3 +178,269 @@ async fn rejects_invalid_payloads_and_ownership() {
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
+
+#[tokio::test]
+async fn validates_and_updates_bank_payment_details() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let banks_uri = format!("/organizations/{organization_id}/banks");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request");
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (status, read_json(body))
+        }
+    };
+
+    for details in [
+        json!({"swift_code": "CHAS US33"}),
+        json!({"swift_code": "CHASUS3"}),
+        json!({"swift_code": "CHASUS33", "country": "GB"}),
+        json!({"country": "USA"}),
+        json!({"country": "US", "routing_number": "021000022"}),
+        json!({"country": "GB", "routing_number": "12-34-5"}),
+        json!({"routing_number": "1"}),
+        json!({"address": "  "}),
+    ] {
+        let mut payload = json!({"name": "Chase"});
+        payload
+            .as_object_mut()
+            .unwrap()
+            .extend(details.as_object().unwrap().clone());
+        let (status, _) = send("POST", banks_uri.clone(), payload).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{details}");
+    }
+
+    let (status, bank) = send(
+        "POST",
+        banks_uri.clone(),
+        json!({
+            "name": "Chase",
+            "swift_code": "chasus33",
+            "routing_number": "021-000-021",
+            "country": "us",
+            "address": " 383 Madison Ave, New York "
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(bank["swift_code"], "CHASUS33");
+    assert_eq!(bank["routing_number"], "021000021");
+    assert_eq!(bank["country"], "US");
+    assert_eq!(bank["address"], "383 Madison Ave, New York");
+    let bank_uri = format!("{banks_uri}/{}", bank["id"].as_str().unwrap());
+
+    // The existing US routing number does not fit a UK bank.
+    let (status, _) = send(
+        "PUT",
+        bank_uri.clone(),
+        json!({"country": "GB", "swift_code": null}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, bank) = send(
+        "PUT",
+        bank_uri.clone(),
+        json!({
+            "country": "GB",
+            "swift_code": "BARCGB22",
+            "routing_number": "20-00-00",
+            "address": null
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(bank["name"], "Chase");
+    assert_eq!(bank["swift_code"], "BARCGB22");
+    assert_eq!(bank["routing_number"], "200000");
+    assert!(bank["address"].is_null());
+}
+
+#[tokio::test]
+async fn payment_format_requires_matching_bank_details() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let banks_uri = format!("/organizations/{organization_id}/banks");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request");
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (status, read_json(body))
+        }
+    };
+
+    for payload in [
+        json!({"name": "Deutsche", "payment_format": {"kind": "sepa"}}),
+        json!({"name": "Deutsche", "country": "DE", "routing_number": "37040044", "payment_format": {"kind": "nacha"}}),
+    ] {
+        let (status, _) = send("POST", banks_uri.clone(), payload.clone()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{payload}");
+    }
+
+    let (status, bank) = send(
+        "POST",
+        banks_uri.clone(),
+        json!({
+            "name": "Deutsche",
+            "swift_code": "DEUTDEFF",
+            "country": "DE",
+            "payment_format": {"kind": "sepa"}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(bank["payment_format"], json!({"kind": "sepa"}));
+    let bank_uri = format!("{banks_uri}/{}", bank["id"].as_str().unwrap());
+
+    // SEPA files carry the BIC, so it cannot be removed while the format is set.
+    let (status, _) = send("PUT", bank_uri.clone(), json!({"swift_code": null})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let template_id = Uuid::new_v4();
+    let (status, bank) = send(
+        "PUT",
+        bank_uri.clone(),
+        json!({
+            "swift_code": null,
+            "payment_format": {"kind": "custom_csv", "template_id": template_id}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(bank["payment_format"]["kind"], "custom_csv");
+    assert_eq!(
+        bank["payment_format"]["template_id"],
+        template_id.to_string()
+    );
+
+    let (status, bank) = send("PUT", bank_uri, json!({"payment_format": null})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(bank["payment_format"].is_null());
+}
+
+#[tokio::test]
+async fn linked_banks_inherit_catalog_details() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let banks_uri = format!("/organizations/{organization_id}/banks");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request");
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let body = if body.is_empty() {
+                Value::Null
+            } else {
+                read_json(body)
+            };
+            (status, body)
+        }
+    };
+
+    let (status, entry) = send(
+        "POST",
+        "/bank-catalog".to_string(),
+        json!({"name": " Deutsche Bank ", "swift_code": "deutdeff", "country": "de"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(entry["name"], "Deutsche Bank");
+    assert_eq!(entry["swift_code"], "DEUTDEFF");
+    let catalog_id = entry["id"].as_str().unwrap().to_string();
+    let entry_uri = format!("/bank-catalog/{catalog_id}");
+
+    let (status, _) = send(
+        "POST",
+        banks_uri.clone(),
+        json!({"name": "Deutsche", "catalog_id": Uuid::new_v4()}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(
+        "POST",
+        banks_uri.clone(),
+        json!({"name": "Deutsche", "catalog_id": catalog_id, "swift_code": "COBADEFF"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, bank) = send(
+        "POST",
+        banks_uri.clone(),
+        json!({
+            "name": "Deutsche",
+            "catalog_id": catalog_id,
+            "payment_format": {"kind": "sepa"}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(bank["catalog_id"], catalog_id.as_str());
+    assert_eq!(bank["swift_code"], "DEUTDEFF");
+    assert_eq!(bank["country"], "DE");
+    let bank_uri = format!("{banks_uri}/{}", bank["id"].as_str().unwrap());
+
+    let (status, _) = send("PUT", bank_uri.clone(), json!({"country": "FR"})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Catalog changes reach linked banks, unless they would break a linked payment format.
+    let (status, _) = send("PUT", entry_uri.clone(), json!({"swift_code": null})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send(
+        "PUT",
+        entry_uri.clone(),
+        json!({"swift_code": "DEUTDEFF500"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, bank) = send("GET", bank_uri.clone(), json!({})).await;
+    assert_eq!(bank["swift_code"], "DEUTDEFF500");
+
+    let (status, _) = send("DELETE", entry_uri.clone(), json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Unlinking keeps the inherited details and frees them for editing.
+    let (status, bank) = send(
+        "PUT",
+        bank_uri.clone(),
+        json!({"catalog_id": null, "address": "Taunusanlage 12"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(bank["catalog_id"].is_null());
+    assert_eq!(bank["swift_code"], "DEUTDEFF500");
+    let (status, _) = send("PUT", bank_uri, json!({"swift_code": "DEUTDEFF"})).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send("DELETE", entry_uri.clone(), json!({})).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send("GET", entry_uri, json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
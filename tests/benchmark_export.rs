@@ -0,0 +1,184 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    content_type: &str,
+    body: String,
+) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", content_type)
+                .body(Body::from(body))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+async fn post_json(app: &Router, uri: &str, payload: Value) -> (StatusCode, Value) {
+    send(app, "POST", uri, "application/json", payload.to_string()).await
+}
+
+struct Fixture {
+    organization_id: String,
+    employees_uri: String,
+    job_id: String,
+    bank_id: String,
+}
+
+async fn setup(app: &Router) -> Fixture {
+    let (_, organization) =
+        post_json(app, "/organizations", json!({"name": "Benchmark Org"})).await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Benchmark Bank"}),
+    )
+    .await;
+    let (_, job) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap().to_string();
+
+    Fixture {
+        employees_uri: format!(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+        ),
+        organization_id,
+        job_id: job["id"].as_str().unwrap().to_string(),
+        bank_id: bank["id"].as_str().unwrap().to_string(),
+    }
+}
+
+#[tokio::test]
+async fn benchmark_export_buckets_pay_without_identifying_anyone() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let divisions_uri = fixture
+        .employees_uri
+        .split("/divisions/")
+        .next()
+        .unwrap()
+        .to_string()
+        + "/divisions";
+    let (status, north) = post_json(
+        &app,
+        &divisions_uri,
+        json!({"name": "North", "description": "Northern depot", "budget_code": "NTH-1", "region": " North "}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(north["region"], "North");
+
+    let rows = |prefix: &str, count: usize| {
+        (0..count)
+            .map(|index| {
+                format!(
+                    "{prefix}-{index},Doe,Jane,1 Main St,555,Town,1985-01-01,XL,Single,F,2020-01-01,Full-time,{},{},ACC-{prefix}-{index},Active,40\n",
+                    fixture.job_id, fixture.bank_id
+                )
+            })
+            .collect::<String>()
+    };
+    let header = "id_number,last_name,first_name,address,phone,place_of_birth,date_of_birth,nationality,marital_status,gender,hire_date,clasification,job_id,bank_id,bank_account,status,hours\n";
+    for (uri, csv) in [
+        (
+            format!(
+                "{divisions_uri}/{}/employees/import",
+                north["id"].as_str().unwrap()
+            ),
+            format!("{header}{}", rows("N", 3)),
+        ),
+        (
+            format!("{}/import", fixture.employees_uri),
+            format!("{header}{}", rows("A", 1)),
+        ),
+    ] {
+        let (status, _) = send(&app, "POST", &uri, "text/csv", csv).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let download = |query: &str| {
+        let app = app.clone();
+        let uri = format!(
+            "/organizations/{}/benchmark-export?{query}",
+            fixture.organization_id
+        );
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri(uri)
+                        .body(Body::empty())
+                        .expect("request"),
+                )
+                .await
+                .expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        }
+    };
+
+    let header =
+        "grade_band,grade_level,grade_code,region,currency,pay_band_from,pay_band_to,headcount\n";
+    let (status, body) = download("min_group_size=2").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, format!("{header},,,North,,10000,15000,3\n"));
+
+    let (_, body) = download("").await;
+    assert_eq!(body, header);
+
+    let (status, _) = download("min_group_size=1").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = download("pay_band_width=0").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
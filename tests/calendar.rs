@@ -0,0 +1,258 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_slice(&bytes).expect("json")
+    };
+    (status, body)
+}
+
+async fn create(app: &Router, uri: &str, payload: Value) -> Value {
+    let (status, created) = send_json(app, "POST", uri, payload).await;
+    assert_eq!(status, StatusCode::CREATED, "{created}");
+    created
+}
+
+fn employee(
+    id_number: &str,
+    first_name: &str,
+    last_name: &str,
+    job: &Value,
+    bank: &Value,
+) -> Value {
+    json!({
+        "id_number": id_number,
+        "last_name": last_name,
+        "first_name": first_name,
+        "address": "4 Beach Rd",
+        "phone": "555-0404",
+        "place_of_birth": "Hometown",
+        "date_of_birth": "1990-04-04",
+        "nationality": "Exampleland",
+        "marital_status": "Single",
+        "gender": "F",
+        "hire_date": "2020-01-01",
+        "clasification": "Full-time",
+        "job_id": job["id"],
+        "payment_instructions": [{
+            "bank_id": bank["id"],
+            "account": format!("ACC-{id_number}"),
+            "amount": { "percentage": 100.0 },
+            "priority": 1
+        }],
+        "status": "Active",
+        "hours": 40
+    })
+}
+
+/// An organization whose division has an employee reporting to a supervisor. Returns the
+/// organization's, division's and employee's URIs and the supervisor's id.
+async fn setup(app: &Router) -> (String, String, String, String) {
+    let organization = create(app, "/organizations", json!({"name": "Calendar Org"})).await;
+    let organization_uri = format!("/organizations/{}", organization["id"].as_str().unwrap());
+    let payroll = create(
+        app,
+        &format!("{organization_uri}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_uri = format!(
+        "{organization_uri}/payrolls/{}",
+        payroll["id"].as_str().unwrap()
+    );
+    let bank = create(
+        app,
+        &format!("{organization_uri}/banks"),
+        json!({"name": "Calendar Bank"}),
+    )
+    .await;
+    let job = create(
+        app,
+        &format!("{payroll_uri}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let division = create(
+        app,
+        &format!("{payroll_uri}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_uri = format!(
+        "{payroll_uri}/divisions/{}",
+        division["id"].as_str().unwrap()
+    );
+
+    let employees_uri = format!("{division_uri}/employees");
+    let supervisor = create(
+        app,
+        &employees_uri,
+        employee("CAL-0", "Sam", "Lead", &job, &bank),
+    )
+    .await;
+    let supervisor_id = supervisor["id"].as_str().unwrap().to_string();
+    let mut payload = employee("CAL-1", "Robin", "Away", &job, &bank);
+    payload["supervisor_id"] = json!(supervisor_id);
+    let employee = create(app, &employees_uri, payload).await;
+    let employee_uri = format!("{employees_uri}/{}", employee["id"].as_str().unwrap());
+
+    (organization_uri, division_uri, employee_uri, supervisor_id)
+}
+
+#[tokio::test]
+async fn organization_calendar_combines_leave_blackouts_and_fiscal_year() {
+    let app = support::test_router();
+    let (organization_uri, division_uri, employee_uri, manager_id) = setup(&app).await;
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{organization_uri}/settings"),
+        json!({"fiscal_year_start": {"month": 12, "day": 1}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let blackout = create(
+        &app,
+        &format!("{division_uri}/leave-blackouts"),
+        json!({
+            "start_date": "2024-12-16",
+            "end_date": "2024-12-31",
+            "enforcement": "reject",
+            "reason": "Year-end close"
+        }),
+    )
+    .await;
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/vacation"),
+        json!({"available_days": 10.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let mut requests = Vec::new();
+    for (start_date, end_date) in [("2024-11-11", "2024-11-12"), ("2024-11-18", "2024-11-19")] {
+        let request = create(
+            &app,
+            &format!("{employee_uri}/leave-requests"),
+            json!({"leave_type": "vacation", "start_date": start_date, "end_date": end_date}),
+        )
+        .await;
+        requests.push(request["id"].as_str().unwrap().to_string());
+    }
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests/{}/approve", requests[1]),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, calendar) = send_json(
+        &app,
+        "GET",
+        &format!("{organization_uri}/calendar?from=2024-11-01&to=2024-12-31"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let events = calendar["events"].as_array().unwrap();
+    let kinds: Vec<&str> = events
+        .iter()
+        .map(|event| event["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            "approval_deadline",
+            "leave",
+            "fiscal_year_start",
+            "leave_blackout"
+        ]
+    );
+    assert_eq!(events[0]["date"], "2024-11-11");
+    assert_eq!(events[0]["source_id"], requests[0].as_str());
+    assert_eq!(
+        events[0]["title"],
+        "Decide Robin Away's vacation leave request"
+    );
+    assert_eq!(events[1]["end_date"], "2024-11-19");
+    assert_eq!(events[2]["date"], "2024-12-01");
+    assert_eq!(events[3]["source_id"], blackout["id"]);
+}
+
+#[tokio::test]
+async fn organization_calendar_covers_whole_events_and_refuses_bad_ranges() {
+    let app = support::test_router();
+    let (organization_uri, division_uri, _, _) = setup(&app).await;
+    create(
+        &app,
+        &format!("{division_uri}/leave-blackouts"),
+        json!({"start_date": "2024-12-16", "end_date": "2024-12-31", "enforcement": "reject"}),
+    )
+    .await;
+
+    // Multi-day events show up on every day they cover.
+    let (status, calendar) = send_json(
+        &app,
+        "GET",
+        &format!("{organization_uri}/calendar?from=2024-12-20&to=2024-12-20"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(calendar["events"].as_array().unwrap().len(), 1);
+    let (status, calendar) = send_json(
+        &app,
+        "GET",
+        &format!("{organization_uri}/calendar?from=2025-02-01&to=2025-02-28"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(calendar["events"], json!([]));
+
+    for range in [
+        "from=2024-12-31&to=2024-12-01",
+        "from=2024-01-01&to=2025-01-01",
+    ] {
+        let (status, _) = send_json(
+            &app,
+            "GET",
+            &format!("{organization_uri}/calendar?{range}"),
+            json!({}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{range}");
+    }
+}
@@ -0,0 +1,168 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates the organization hierarchy and returns `(organization_id, employees_uri, employee_payload)`.
+async fn setup(app: &Router) -> (String, String, Value) {
+    let (_, organization) =
+        send_json(app, "POST", "/organizations", json!({"name": "Custom Org"})).await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Custom Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let employee = json!({
+        "id_number": "CF-1",
+        "last_name": "Field",
+        "first_name": "Casey",
+        "address": "1 Custom Rd",
+        "phone": "555-0202",
+        "place_of_birth": "Hometown",
+        "date_of_birth": "1988-08-08",
+        "nationality": "Exampleland",
+        "marital_status": "Single",
+        "gender": "M",
+        "hire_date": "2019-01-01",
+        "clasification": "Full-time",
+        "job_id": job["id"],
+        "payment_instructions": [{
+            "bank_id": bank["id"],
+            "account": "ACC-CF",
+            "amount": { "percentage": 100.0 },
+            "priority": 1
+        }],
+        "status": "Active",
+        "hours": 40
+    });
+
+    (organization_id, employees_uri, employee)
+}
+
+#[tokio::test]
+async fn employees_carry_custom_fields_validated_against_definitions() {
+    let app = support::test_router();
+    let (organization_id, employees_uri, mut employee) = setup(&app).await;
+    let fields_uri = format!("/organizations/{organization_id}/custom-fields");
+
+    for (name, field_type, required) in [
+        ("union_code", "text", true),
+        ("locker_number", "number", false),
+    ] {
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &fields_uri,
+            json!({"name": name, "field_type": field_type, "required": required}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["field_type"], field_type);
+    }
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &fields_uri,
+        json!({"name": "union_code", "field_type": "text"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = send_json(&app, "POST", &employees_uri, employee.clone()).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    employee["custom_fields"] = json!({"union_code": "U-7", "locker_number": "twelve"});
+    let (status, _) = send_json(&app, "POST", &employees_uri, employee.clone()).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    employee["custom_fields"] = json!({"union_code": "U-7", "shoe_size": 42});
+    let (status, _) = send_json(&app, "POST", &employees_uri, employee.clone()).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    employee["custom_fields"] = json!({"union_code": "U-7", "locker_number": 12});
+    let (status, created) = send_json(&app, "POST", &employees_uri, employee).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created["custom_fields"]["locker_number"], 12);
+
+    let employee_uri = format!("{employees_uri}/{}", created["id"].as_str().unwrap());
+    let (status, updated) = send_json(
+        &app,
+        "PUT",
+        &employee_uri,
+        json!({"custom_fields": {"union_code": "U-9"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(updated["custom_fields"], json!({"union_code": "U-9"}));
+
+    let (status, _) = send_json(&app, "PUT", &employee_uri, json!({"custom_fields": {}})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use nomina::infrastructure::surreal::{RetryPolicy, SurrealConfig};
+
+#[test]
+fn connection_retries_back_off_exponentially_up_to_the_cap() {
+    let policy = RetryPolicy {
+        initial_delay: Duration::from_millis(200),
+        max_delay: Duration::from_secs(1),
+        timeout: Duration::from_secs(30),
+    };
+
+    let mut delay = policy.initial_delay;
+    let mut delays = vec![delay];
+    for _ in 0..4 {
+        delay = policy.next_delay(delay);
+        delays.push(delay);
+    }
+
+    assert_eq!(
+        delays,
+        [200, 400, 800, 1000, 1000].map(Duration::from_millis)
+    );
+}
+
+#[test]
+fn embedded_database_uses_the_default_retry_policy() {
+    let config = SurrealConfig::embedded();
+
+    assert!(config.is_embedded());
+    assert_eq!(config.retry, RetryPolicy::default());
+    assert!(config.retry.initial_delay <= config.retry.max_delay);
+    assert!(config.retry.max_delay < config.retry.timeout);
+}
@@ -0,0 +1,222 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn post_json(app: &Router, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates an organization, payroll, bank, job, division, and employee, returning the employee URI.
+async fn create_employee(app: &Router) -> String {
+    let (_, organization) =
+        post_json(app, "/organizations", json!({"name": "Dependents Org"})).await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Family Bank"}),
+    )
+    .await;
+    let (_, job) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap().to_string();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let (status, employee) = post_json(
+        app,
+        &employees_uri,
+        json!({
+            "id_number": "DEP-1",
+            "last_name": "Parent",
+            "first_name": "Pat",
+            "address": "1 Family Rd",
+            "phone": "555-0101",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1980-02-02",
+            "nationality": "Exampleland",
+            "marital_status": "Married",
+            "gender": "F",
+            "hire_date": "2015-03-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-DEP",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    format!("{employees_uri}/{}", employee["id"].as_str().unwrap())
+}
+
+#[tokio::test]
+async fn can_create_list_update_and_delete_dependents() {
+    let app = support::test_router();
+    let employee_uri = create_employee(&app).await;
+
+    for (name, relationship, date_of_birth) in [
+        ("Sam Parent", "Child", "2012-06-01"),
+        ("Alex Parent", "Spouse", "1981-09-15"),
+    ] {
+        let (status, body) = post_json(
+            &app,
+            &format!("{employee_uri}/dependents"),
+            json!({
+                "name": name,
+                "relationship": relationship,
+                "date_of_birth": date_of_birth
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["relationship"], relationship);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("{employee_uri}/dependents"))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let list = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(list.as_array().unwrap().len(), 2);
+    assert_eq!(list[0]["name"], "Alex Parent");
+    let dependent_id = list[1]["id"].as_str().unwrap().to_string();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(format!("{employee_uri}/dependents/{dependent_id}"))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"relationship": "Stepchild"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(updated["relationship"], "Stepchild");
+    assert_eq!(updated["name"], "Sam Parent");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("{employee_uri}/dependents/{dependent_id}"))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("{employee_uri}/dependents/{dependent_id}"))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn rejects_invalid_dependents_and_unknown_employees() {
+    let app = support::test_router();
+    let employee_uri = create_employee(&app).await;
+
+    let (status, _) = post_json(
+        &app,
+        &format!("{employee_uri}/dependents"),
+        json!({"name": "  ", "relationship": "Child", "date_of_birth": "2010-01-01"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = post_json(
+        &app,
+        &format!("{employee_uri}/dependents"),
+        json!({"name": "Future Kid", "relationship": "Child", "date_of_birth": "2999-01-01"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (prefix, _) = employee_uri.rsplit_once('/').unwrap();
+    let (status, _) = post_json(
+        &app,
+        &format!("{prefix}/{}/dependents", uuid::Uuid::new_v4()),
+        json!({"name": "Orphan", "relationship": "Child", "date_of_birth": "2010-01-01"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,99 @@
+use nomina::{
+    error::AppError,
+    infrastructure::{
+        division_repository::SurrealDivisionRepository,
+        surreal::{self, SurrealConfig},
+    },
+    services::division::{DivisionRelocation, DivisionRepository},
+};
+use surrealdb::{Surreal, engine::any::Any};
+use uuid::Uuid;
+
+async fn insert(
+    repository: &SurrealDivisionRepository<Any>,
+    payroll_id: Uuid,
+    parent_division_id: Option<Uuid>,
+) -> Uuid {
+    let id = Uuid::new_v4();
+    repository
+        .insert(
+            id,
+            "Division".to_string(),
+            "Division".to_string(),
+            "BC-1".to_string(),
+            payroll_id,
+            parent_division_id,
+            None,
+            None,
+        )
+        .await
+        .expect("insert");
+    id
+}
+
+async fn payroll_of(repository: &SurrealDivisionRepository<Any>, id: Uuid) -> Uuid {
+    repository
+        .fetch(id)
+        .await
+        .expect("fetch")
+        .expect("division")
+        .payroll_id
+}
+
+#[tokio::test]
+async fn a_subtree_moves_in_one_transaction() {
+    let database: Surreal<Any> = surreal::connect(&SurrealConfig::embedded())
+        .await
+        .expect("embedded database");
+    let repository = SurrealDivisionRepository::new(database);
+    let (payroll_id, target_payroll_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let root = insert(&repository, payroll_id, None).await;
+    let child = insert(&repository, payroll_id, Some(root)).await;
+    let grandchild = insert(&repository, payroll_id, Some(child)).await;
+    let parent = insert(&repository, target_payroll_id, None).await;
+
+    // A descendant the caller did not know about fails the move before anything is written.
+    let stale = DivisionRelocation {
+        division_id: root,
+        descendants: vec![child],
+        payroll_id: target_payroll_id,
+        parent_division_id: Some(parent),
+    };
+    assert!(matches!(
+        repository.relocate(stale).await,
+        Err(AppError::Conflict { .. })
+    ));
+    for id in [root, child, grandchild] {
+        assert_eq!(payroll_of(&repository, id).await, payroll_id);
+    }
+
+    let relocation = DivisionRelocation {
+        division_id: root,
+        descendants: vec![child, grandchild],
+        payroll_id: target_payroll_id,
+        parent_division_id: Some(parent),
+    };
+    let moved = repository
+        .relocate(relocation)
+        .await
+        .expect("relocate")
+        .expect("division");
+    let ids: Vec<Uuid> = moved.iter().map(|division| division.id).collect();
+    assert_eq!(ids, [root, child, grandchild]);
+    assert_eq!(moved[0].parent_division_id, Some(parent));
+    assert_eq!(moved[1].parent_division_id, Some(root));
+    assert_eq!(moved[2].parent_division_id, Some(child));
+    assert!(
+        moved
+            .iter()
+            .all(|division| division.payroll_id == target_payroll_id)
+    );
+
+    let gone = DivisionRelocation {
+        division_id: Uuid::new_v4(),
+        descendants: Vec::new(),
+        payroll_id: target_payroll_id,
+        parent_division_id: None,
+    };
+    assert!(repository.relocate(gone).await.expect("relocate").is_none());
+}
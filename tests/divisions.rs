@@ -1,12 +1,30 @@
 #[path = "support/mod.rs"]
 mod support;
 
+use std::{collections::BTreeMap, sync::Arc};
+
 use axum::{
     Router,
     body::{Body, Bytes},
     http::{Request, StatusCode},
 };
+use chrono::NaiveDate;
 use http_body_util::BodyExt;
+use nomina::{
+    domain::{
+        employee::{Employee, EmployeeStatus},
+        position::Position,
+    },
+    error::AppError,
+    infrastructure::in_memory::{
+        InMemoryDivisionRepository, InMemoryEmployeeRepository, InMemoryPositionRepository,
+    },
+    services::{
+        division::{DivisionDeletePolicy, DivisionRemoval, DivisionRepository},
+        employee::EmployeeRepository,
+        position::PositionRepository,
+    },
+};
 use serde_json::{Value, json};
 use tower::ServiceExt;
 use uuid::Uuid;
@@ -246,3 +264,483 @@ async fn can_update_and_clear_parent() {
 
     assert_eq!(response.status(), StatusCode::NO_CONTENT);
 }
+
+#[tokio::test]
+async fn rejects_reparenting_onto_a_descendant() {
+    let app = support::test_router();
+    let org = create_organization(&app).await;
+    let payroll = create_payroll(&app, org).await;
+
+    let top = create_division(&app, org, payroll, "Top", None).await;
+    let top_id = Uuid::parse_str(top["id"].as_str().unwrap()).unwrap();
+    let middle = create_division(&app, org, payroll, "Middle", Some(top_id)).await;
+    let middle_id = Uuid::parse_str(middle["id"].as_str().unwrap()).unwrap();
+    let bottom = create_division(&app, org, payroll, "Bottom", Some(middle_id)).await;
+    let bottom_id = Uuid::parse_str(bottom["id"].as_str().unwrap()).unwrap();
+
+    let reparent = |division_id: Uuid, parent_id: Uuid| {
+        let app = app.clone();
+        async move {
+            app.oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!(
+                        "/organizations/{org}/payrolls/{payroll}/divisions/{division_id}"
+                    ))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({"parent_division_id": parent_id}).to_string(),
+                    ))
+                    .expect("request"),
+            )
+            .await
+            .expect("response")
+            .status()
+        }
+    };
+
+    assert_eq!(
+        reparent(top_id, middle_id).await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(
+        reparent(top_id, bottom_id).await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(reparent(bottom_id, top_id).await, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn moves_a_division_with_its_descendants() {
+    let app = support::test_router();
+    let org = create_organization(&app).await;
+    let payroll = create_payroll(&app, org).await;
+    let other_payroll = create_payroll(&app, org).await;
+
+    let top = create_division(&app, org, payroll, "Top", None).await;
+    let top_id = Uuid::parse_str(top["id"].as_str().unwrap()).unwrap();
+    let middle = create_division(&app, org, payroll, "Middle", Some(top_id)).await;
+    let middle_id = Uuid::parse_str(middle["id"].as_str().unwrap()).unwrap();
+    let bottom = create_division(&app, org, payroll, "Bottom", Some(middle_id)).await;
+    let bottom_id = Uuid::parse_str(bottom["id"].as_str().unwrap()).unwrap();
+    let side = create_division(&app, org, payroll, "Side", None).await;
+    let side_id = Uuid::parse_str(side["id"].as_str().unwrap()).unwrap();
+
+    let move_division = |division_id: Uuid, body: Value| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!(
+                            "/organizations/{org}/payrolls/{payroll}/divisions/{division_id}/move"
+                        ))
+                        .header("content-type", "application/json")
+                        .body(Body::from(body.to_string()))
+                        .expect("request"),
+                )
+                .await
+                .expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (
+                status,
+                serde_json::from_slice::<Value>(&body).unwrap_or(Value::Null),
+            )
+        }
+    };
+
+    let (status, _) = move_division(top_id, json!({"parent_division_id": bottom_id})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, moved) = move_division(middle_id, json!({"parent_division_id": side_id})).await;
+    assert_eq!(status, StatusCode::OK);
+    let moved = moved.as_array().unwrap();
+    assert_eq!(moved.len(), 2);
+    assert_eq!(moved[0]["id"], middle_id.to_string());
+    assert_eq!(moved[0]["parent_division_id"], side_id.to_string());
+    assert_eq!(moved[1]["id"], bottom_id.to_string());
+    assert_eq!(moved[1]["parent_division_id"], middle_id.to_string());
+
+    let (status, _) = move_division(
+        middle_id,
+        json!({"payroll_id": other_payroll, "parent_division_id": top_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, moved) = move_division(middle_id, json!({"payroll_id": other_payroll})).await;
+    assert_eq!(status, StatusCode::OK);
+    let moved = moved.as_array().unwrap();
+    assert!(moved[0]["parent_division_id"].is_null());
+    assert!(
+        moved
+            .iter()
+            .all(|division| division["payroll_id"] == other_payroll.to_string())
+    );
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/organizations/{org}/payrolls/{other_payroll}/divisions"
+                ))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let list = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(list.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn flat_routes_scope_divisions_by_query() {
+    let app = support::test_router();
+    let org = create_organization(&app).await;
+    let other_org = create_organization(&app).await;
+    let payroll_a = create_payroll(&app, org).await;
+    let payroll_b = create_payroll(&app, org).await;
+
+    let alpha = create_division(&app, org, payroll_a, "Alpha", None).await;
+    create_division(&app, org, payroll_b, "Beta", None).await;
+    let alpha_id = alpha["id"].as_str().unwrap();
+
+    let get = |uri: String| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri(uri)
+                        .body(Body::empty())
+                        .expect("request"),
+                )
+                .await
+                .expect("response");
+            let status = response.status();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            (status, bytes)
+        }
+    };
+
+    let (status, body) = get(format!("/divisions?organization_id={org}")).await;
+    assert_eq!(status, StatusCode::OK);
+    let names: Vec<_> = read_json(body)
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|division| division["name"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(names, ["Alpha", "Beta"]);
+
+    let (status, body) = get(format!(
+        "/divisions?organization_id={org}&payroll_id={payroll_b}"
+    ))
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(read_json(body)[0]["name"], "Beta");
+
+    let (status, _) = get(format!("/divisions/{alpha_id}?organization_id={other_org}")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(format!("/divisions/{alpha_id}?organization_id={org}"))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Alpha Prime"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let (status, body) = get(format!(
+        "/organizations/{org}/payrolls/{payroll_a}/divisions/{alpha_id}"
+    ))
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(read_json(body)["name"], "Alpha Prime");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("/divisions/{alpha_id}?organization_id={org}"))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let (status, _) = get(format!("/divisions/{alpha_id}?organization_id={org}")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn enforces_the_organization_budget_code_format() {
+    let app = support::test_router();
+    let org = create_organization(&app).await;
+    let payroll = create_payroll(&app, org).await;
+    let divisions_uri = format!("/organizations/{org}/payrolls/{payroll}/divisions");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request");
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (status, read_json(body))
+        }
+    };
+    let division = |budget_code: &str| {
+        json!({
+            "name": "Finance",
+            "description": "Finance division",
+            "budget_code": budget_code
+        })
+    };
+
+    let (status, _) = send(
+        "PUT",
+        format!("/organizations/{org}"),
+        json!({"budget_code_pattern": "CC-("}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, organization) = send(
+        "PUT",
+        format!("/organizations/{org}"),
+        json!({"budget_code_pattern": r"CC-\d{4}"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(organization["budget_code_pattern"], r"CC-\d{4}");
+
+    for budget_code in ["BC-Finance", "CC-12345", "xCC-1234"] {
+        let (status, error) = send("POST", divisions_uri.clone(), division(budget_code)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{budget_code}");
+        assert!(error.to_string().contains(r"CC-\\d{4}"), "{error}");
+    }
+
+    let (status, created) = send("POST", divisions_uri.clone(), division("CC-1234")).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let division_uri = format!("{divisions_uri}/{}", created["id"].as_str().unwrap());
+
+    let (status, _) = send("PUT", division_uri.clone(), json!({"budget_code": "CC-12"})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, updated) = send(
+        "PUT",
+        division_uri.clone(),
+        json!({"budget_code": "CC-9999"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(updated["budget_code"], "CC-9999");
+
+    let (status, organization) = send(
+        "PUT",
+        format!("/organizations/{org}"),
+        json!({"budget_code_pattern": null}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(organization["budget_code_pattern"].is_null());
+
+    let (status, _) = send("POST", divisions_uri, division("BC-Finance")).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+struct RemovalFixture {
+    divisions: InMemoryDivisionRepository,
+    employees: Arc<InMemoryEmployeeRepository>,
+    positions: Arc<InMemoryPositionRepository>,
+    branch_id: Uuid,
+    child_id: Uuid,
+}
+
+/// A branch with a child division, an employee in each and a position in the branch.
+async fn removal_fixture() -> RemovalFixture {
+    let employees = Arc::new(InMemoryEmployeeRepository::default());
+    let positions = Arc::new(InMemoryPositionRepository::default());
+    let divisions = InMemoryDivisionRepository::new(Arc::clone(&employees), Arc::clone(&positions));
+    let (payroll_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let branch_id = Uuid::new_v4();
+    let child_id = Uuid::new_v4();
+    for (id, parent) in [(branch_id, None), (child_id, Some(branch_id))] {
+        divisions
+            .insert(
+                id,
+                "Division".to_string(),
+                "Division".to_string(),
+                "BC-1".to_string(),
+                payroll_id,
+                parent,
+                None,
+                None,
+            )
+            .await
+            .expect("division");
+    }
+    let employee = |division_id: Uuid| {
+        Employee::new(
+            Uuid::new_v4(),
+            "ID-1",
+            None,
+            "Doe",
+            "Sam",
+            "1 Main St",
+            "555",
+            None,
+            None,
+            "Town",
+            NaiveDate::from_ymd_opt(1990, 1, 1).unwrap(),
+            "XL",
+            "Single",
+            "F",
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            "Full-time",
+            job_id,
+            Vec::new(),
+            EmployeeStatus::Active,
+            40,
+            None,
+            division_id,
+            payroll_id,
+            None,
+            None,
+            BTreeMap::new(),
+        )
+    };
+    employees
+        .insert_many(vec![employee(branch_id), employee(child_id)])
+        .await
+        .expect("employees");
+    positions
+        .insert(Position::new(
+            Uuid::new_v4(),
+            "Porter",
+            job_id,
+            900.0,
+            branch_id,
+            payroll_id,
+        ))
+        .await
+        .expect("position");
+
+    RemovalFixture {
+        divisions,
+        employees,
+        positions,
+        branch_id,
+        child_id,
+    }
+}
+
+impl RemovalFixture {
+    /// Employees, positions and divisions still stored, for comparing before and after.
+    async fn counts(&self) -> (usize, usize, usize) {
+        let mut employees = 0;
+        let mut positions = 0;
+        let mut divisions = 0;
+        for division_id in [self.branch_id, self.child_id] {
+            employees += self
+                .employees
+                .fetch_by_division(division_id)
+                .await
+                .unwrap()
+                .len();
+            positions += self
+                .positions
+                .fetch_by_division(division_id)
+                .await
+                .unwrap()
+                .len();
+            divisions += usize::from(self.divisions.fetch(division_id).await.unwrap().is_some());
+        }
+        (employees, positions, divisions)
+    }
+}
+
+#[tokio::test]
+async fn a_division_removal_that_fails_partway_changes_nothing() {
+    let fixture = removal_fixture().await;
+    assert_eq!(fixture.counts().await, (2, 1, 2));
+
+    // The plan predates a child division added concurrently, so cascading must stop before
+    // deleting the employees it already reached.
+    let late_child = Uuid::new_v4();
+    fixture
+        .divisions
+        .insert(
+            late_child,
+            "Late".to_string(),
+            "Added during the delete".to_string(),
+            "BC-2".to_string(),
+            Uuid::new_v4(),
+            Some(fixture.child_id),
+            None,
+            None,
+        )
+        .await
+        .expect("late child");
+    let error = fixture
+        .divisions
+        .remove(DivisionRemoval {
+            division_id: fixture.branch_id,
+            descendants: vec![fixture.child_id],
+            policy: DivisionDeletePolicy::Cascade,
+        })
+        .await
+        .expect_err("stale cascade");
+    assert!(matches!(error, AppError::Conflict { .. }));
+    assert_eq!(fixture.counts().await, (2, 1, 2));
+
+    // A reassignment target deleted after the service checked it.
+    let error = fixture
+        .divisions
+        .remove(DivisionRemoval {
+            division_id: fixture.branch_id,
+            descendants: vec![fixture.child_id, late_child],
+            policy: DivisionDeletePolicy::ReassignTo(Uuid::new_v4()),
+        })
+        .await
+        .expect_err("missing target");
+    assert!(matches!(error, AppError::Conflict { .. }));
+    assert_eq!(fixture.counts().await, (2, 1, 2));
+    let child = fixture.divisions.fetch(fixture.child_id).await.unwrap();
+    assert_eq!(child.unwrap().parent_division_id, Some(fixture.branch_id));
+
+    let removed = fixture
+        .divisions
+        .remove(DivisionRemoval {
+            division_id: fixture.branch_id,
+            descendants: vec![fixture.child_id, late_child],
+            policy: DivisionDeletePolicy::Cascade,
+        })
+        .await
+        .expect("cascade")
+        .expect("division found");
+    assert_eq!(removed.len(), 2);
+    assert_eq!(fixture.counts().await, (0, 0, 0));
+}
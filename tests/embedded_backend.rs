@@ -0,0 +1,126 @@
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use nomina::server::{self, AppState};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).expect("json"))
+}
+
+/// Boots the state `cargo run` uses without `SURREALDB_URL` and walks the path from a new
+/// organization to its first employee through the embedded database.
+#[tokio::test]
+async fn default_backend_serves_an_organization_end_to_end() {
+    assert!(std::env::var_os("SURREALDB_URL").is_none());
+    let app = server::router(AppState::initialize().await.expect("embedded state"));
+
+    let (status, organization) = send_json(
+        &app,
+        "POST",
+        "/organizations",
+        json!({"name": "Embedded Org"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let organization_uri = format!("/organizations/{}", organization["id"].as_str().unwrap());
+
+    let (status, payroll) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let payroll_uri = format!(
+        "{organization_uri}/payrolls/{}",
+        payroll["id"].as_str().unwrap()
+    );
+
+    let (status, division) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/divisions"),
+        json!({"name": "Operations", "description": "Operations", "budget_code": "OPS-1"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, job) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/jobs"),
+        json!({"job_title": "Operator", "salary": 1200.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, bank) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/banks"),
+        json!({"name": "Embedded Bank"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let employees_uri = format!(
+        "{payroll_uri}/divisions/{}/employees",
+        division["id"].as_str().unwrap()
+    );
+    let (status, employee) = send_json(
+        &app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "EMB-1",
+            "last_name": "Local",
+            "first_name": "Dana",
+            "address": "1 Loopback Way",
+            "phone": "555-0127",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1991-01-27",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2024-01-02",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-EMB-1",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, listed) = send_json(&app, "GET", &employees_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    let listed = listed.as_array().expect("employees");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0]["id"], employee["id"]);
+    assert_eq!(listed[0]["payment_instructions"][0]["bank_id"], bank["id"]);
+}
@@ -0,0 +1,164 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    content_type: &str,
+    body: String,
+) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", content_type)
+                .body(Body::from(body))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+async fn post_json(app: &Router, uri: &str, payload: Value) -> (StatusCode, Value) {
+    send(app, "POST", uri, "application/json", payload.to_string()).await
+}
+
+struct Fixture {
+    employees_uri: String,
+    job_id: String,
+    bank_id: String,
+}
+
+async fn setup(app: &Router) -> Fixture {
+    let (_, organization) = post_json(app, "/organizations", json!({"name": "Export Org"})).await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Export Bank"}),
+    )
+    .await;
+    let (_, job) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap().to_string();
+
+    Fixture {
+        employees_uri: format!(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+        ),
+        job_id: job["id"].as_str().unwrap().to_string(),
+        bank_id: bank["id"].as_str().unwrap().to_string(),
+    }
+}
+
+#[tokio::test]
+async fn exports_selected_columns_as_csv_and_xlsx() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+
+    let csv = format!(
+        "id_number,last_name,first_name,address,phone,place_of_birth,date_of_birth,nationality,marital_status,gender,hire_date,clasification,job_id,bank_id,bank_account,status,hours\n\
+         E-1,Doe,Jane,1 Main St,555,Town,1985-01-01,XL,Single,F,2020-01-01,Full-time,{job},{bank},ACC-1,Active,40\n\
+         E-2,Roe,\"Rick, Jr\",2 Main St,556,Town,1986-02-02,XL,Single,M,2021-01-01,Full-time,{job},{bank},ACC-2,Inactive,20\n",
+        job = fixture.job_id,
+        bank = fixture.bank_id
+    );
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{}/import", fixture.employees_uri),
+        "text/csv",
+        csv,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let download = |query: &str| {
+        Request::builder()
+            .uri(format!("{}/export?{query}", fixture.employees_uri))
+            .body(Body::empty())
+            .expect("request")
+    };
+
+    let response = app
+        .clone()
+        .oneshot(download("columns=last_name,first_name,hours"))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()["content-disposition"]
+            .to_str()
+            .unwrap()
+            .contains("employees.csv")
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(
+        String::from_utf8(body.to_vec()).unwrap(),
+        "last_name,first_name,hours\nDoe,Jane,40\nRoe,\"Rick, Jr\",20\n"
+    );
+
+    let response = app
+        .clone()
+        .oneshot(download("format=xlsx&status=Active"))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert!(body.starts_with(b"PK"));
+
+    let response = app
+        .clone()
+        .oneshot(download("columns=salary"))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
@@ -0,0 +1,355 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    content_type: &str,
+    body: String,
+) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", content_type)
+                .body(Body::from(body))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+async fn post_json(app: &Router, uri: &str, payload: Value) -> (StatusCode, Value) {
+    send(app, "POST", uri, "application/json", payload.to_string()).await
+}
+
+struct Fixture {
+    organization_id: String,
+    employees_uri: String,
+    job_id: String,
+    bank_id: String,
+}
+
+async fn setup(app: &Router) -> Fixture {
+    let (_, organization) = post_json(app, "/organizations", json!({"name": "Import Org"})).await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Import Bank"}),
+    )
+    .await;
+    let (_, job) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = post_json(
+        app,
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap().to_string();
+
+    Fixture {
+        employees_uri: format!(
+            "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+        ),
+        organization_id,
+        job_id: job["id"].as_str().unwrap().to_string(),
+        bank_id: bank["id"].as_str().unwrap().to_string(),
+    }
+}
+
+#[tokio::test]
+async fn imports_legacy_layout_through_a_mapping_profile() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+
+    let (status, profile) = post_json(
+        &app,
+        &format!("/organizations/{}/import-profiles", fixture.organization_id),
+        json!({
+            "name": "Legacy HR",
+            "column_mappings": {
+                "CEDULA": "id_number", "APELLIDO": "last_name", "NOMBRE": "first_name",
+                "DIRECCION": "address", "TELEFONO": "phone", "LUGAR_NAC": "place_of_birth",
+                "FECHA_NAC": "date_of_birth", "NACIONALIDAD": "nationality",
+                "EDO_CIVIL": "marital_status", "SEXO": "gender", "INGRESO": "hire_date",
+                "TIPO": "clasification", "CARGO": "job_id", "BANCO": "bank_id",
+                "CUENTA": "bank_account", "ESTATUS": "status", "HORAS": "hours"
+            },
+            "date_format": "%d/%m/%Y",
+            "value_translations": {
+                "status": {"A": "Active", "I": "Suspended"},
+                "marital_status": {"S": "Single", "C": "Married"}
+            }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(profile["date_format"], "%d/%m/%Y");
+    let profile_id = profile["id"].as_str().unwrap();
+
+    let (job, bank) = (&fixture.job_id, &fixture.bank_id);
+    let csv = format!(
+        "CEDULA,APELLIDO,NOMBRE,DIRECCION,TELEFONO,LUGAR_NAC,FECHA_NAC,NACIONALIDAD,EDO_CIVIL,SEXO,INGRESO,TIPO,CARGO,BANCO,CUENTA,ESTATUS,HORAS,IGNORADA\n\
+         V-1,Perez,Ana,Calle 1,555-1,Caracas,15/04/1990,VE,C,F,01/02/2020,Fijo,{job},{bank},0102-1,A,40,x\n\
+         V-2,Gomez,Luis,Calle 2,555-2,Maracay,1990-04-15,VE,S,M,01/02/2020,Fijo,{job},{bank},0102-2,I,40,y\n"
+    );
+    let (status, report) = send(
+        &app,
+        "POST",
+        &format!("{}/import?profile_id={profile_id}", fixture.employees_uri),
+        "text/csv",
+        csv,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["created"], 1);
+    assert_eq!(report["failed"], 1);
+    assert_eq!(report["rows"][0]["status"], "created");
+    assert_eq!(report["rows"][1]["line"], 3);
+    assert!(
+        report["rows"][1]["error"]
+            .as_str()
+            .unwrap()
+            .contains("date_of_birth")
+    );
+
+    let employee_id = report["rows"][0]["employee_id"].as_str().unwrap();
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("{}/{employee_id}", fixture.employees_uri))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let employee = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(employee["status"], "Active");
+    assert_eq!(employee["marital_status"], "Married");
+    assert_eq!(employee["date_of_birth"], "1990-04-15");
+}
+
+#[tokio::test]
+async fn imports_without_profile_when_headers_match_fields() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+
+    let csv = format!(
+        "id_number,last_name,first_name,address,phone,place_of_birth,date_of_birth,nationality,marital_status,gender,hire_date,termination_date,clasification,job_id,bank_id,bank_account,status,hours\n\
+         E-1,Doe,Jane,1 Main St,555,Town,1985-01-01,XL,Single,F,2020-01-01,,Full-time,{},{},ACC-1,Active,40\n",
+        fixture.job_id, fixture.bank_id
+    );
+    let (status, report) = send(
+        &app,
+        "POST",
+        &format!("{}/import", fixture.employees_uri),
+        "text/csv",
+        csv,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["created"], 1);
+    assert_eq!(report["failed"], 0);
+}
+
+#[tokio::test]
+async fn rejects_invalid_profiles() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let uri = format!("/organizations/{}/import-profiles", fixture.organization_id);
+
+    for payload in [
+        json!({"name": "Bad field", "column_mappings": {"X": "salary"}}),
+        json!({"name": "Duplicate", "column_mappings": {"A": "last_name", "B": "last_name"}}),
+        json!({"name": "Bad date", "column_mappings": {"A": "hire_date"}, "date_format": "%Q"}),
+        json!({"name": "Bad translation", "column_mappings": {"A": "status"}, "value_translations": {"nope": {}}}),
+    ] {
+        let (status, _) = post_json(&app, &uri, payload).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!(
+            "{}/import?profile_id={}",
+            fixture.employees_uri,
+            uuid::Uuid::new_v4()
+        ),
+        "text/csv",
+        "id_number\nE-1\n".to_string(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn repeated_imports_update_matches_and_flag_leavers() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let header = "id_number,external_id,last_name,first_name,address,phone,place_of_birth,date_of_birth,nationality,marital_status,gender,hire_date,clasification,job_id,bank_id,bank_account,status,hours";
+    let row = |external_id: &str, last_name: &str, hours: u32| {
+        format!(
+            "ID-{external_id},{external_id},{last_name},Sam,1 Main St,555,Town,1985-01-01,XL,Single,F,2020-01-01,Full-time,{},{},ACC-{external_id},Active,{hours}",
+            fixture.job_id, fixture.bank_id
+        )
+    };
+
+    let first = format!(
+        "{header}\n{}\n{}\n",
+        row("EXT-1", "Stays", 40),
+        row("EXT-2", "Leaves", 40)
+    );
+    let (status, report) = send(
+        &app,
+        "POST",
+        &format!("{}/import?match_on=external_id", fixture.employees_uri),
+        "text/csv",
+        first,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["created"], 2);
+    let staying_id = report["rows"][0]["employee_id"].clone();
+    let leaving_id = report["rows"][1]["employee_id"].clone();
+
+    let second = format!(
+        "{header}\n{}\n{}\n",
+        row("EXT-1", "Stays", 20),
+        row("EXT-3", "Joins", 40)
+    );
+    let (status, report) = send(
+        &app,
+        "POST",
+        &format!(
+            "{}/import?match_on=external_id&flag_leavers=true",
+            fixture.employees_uri
+        ),
+        "text/csv",
+        second,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["updated"], 1);
+    assert_eq!(report["created"], 1);
+    assert_eq!(report["rows"][0]["status"], "updated");
+    assert_eq!(report["rows"][0]["employee_id"], staying_id);
+    assert_eq!(report["flagged_leavers"], json!([leaving_id]));
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "{}/{}",
+                    fixture.employees_uri,
+                    leaving_id.as_str().unwrap()
+                ))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let leaver = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(leaver["status"], "Active");
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{}/import?flag_leavers=true", fixture.employees_uri),
+        "text/csv",
+        format!("{header}\n"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn rows_of_one_file_see_the_employees_created_before_them() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let header = "id_number,external_id,last_name,first_name,address,phone,email,place_of_birth,date_of_birth,nationality,marital_status,gender,hire_date,clasification,job_id,bank_id,bank_account,status,hours";
+    let row = |external_id: &str, email: &str, hours: u32| {
+        format!(
+            "ID-{external_id},{external_id},Doe,Sam,1 Main St,555,{email},Town,1985-01-01,XL,Single,F,2020-01-01,Full-time,{},{},ACC-{external_id},Active,{hours}",
+            fixture.job_id, fixture.bank_id
+        )
+    };
+
+    let csv = format!(
+        "{header}\n{}\n{}\n{}\n",
+        row("EXT-1", "sam@example.com", 40),
+        row("EXT-2", "sam@example.com", 40),
+        row("EXT-1", "sam@example.com", 20)
+    );
+    let (status, report) = send(
+        &app,
+        "POST",
+        &format!("{}/import?match_on=external_id", fixture.employees_uri),
+        "text/csv",
+        csv,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["created"], 1);
+    assert_eq!(report["failed"], 1);
+    assert_eq!(report["updated"], 1);
+    let statuses: Vec<&str> = report["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| row["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(statuses, ["created", "failed", "updated"]);
+    assert!(
+        report["rows"][1]["error"]
+            .as_str()
+            .unwrap()
+            .contains("sam@example.com")
+    );
+    assert_eq!(
+        report["rows"][2]["employee_id"],
+        report["rows"][0]["employee_id"]
+    );
+}
@@ -0,0 +1,1125 @@
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, Utc};
+use nomina::{
+    domain::{
+        employee::{
+            AccountVerification, Employee, EmployeeStatus, PaymentAmount, PaymentInstruction,
+        },
+        employment_history::{EmploymentEvent, EmploymentEventKind, JobChange, TerminationTerms},
+        pagination::PageRequest,
+        position::{Position, PositionAssignment},
+    },
+    error::AppError,
+    infrastructure::{
+        bank_repository::SurrealBankRepository,
+        division_repository::SurrealDivisionRepository,
+        employee_repository::SurrealEmployeeRepository,
+        employment_history_repository::SurrealEmploymentHistoryRepository,
+        job_repository::SurrealJobRepository,
+        position_repository::SurrealPositionRepository,
+        surreal::{self, SurrealConfig},
+    },
+    services::{
+        bank::{BankReassignment, BankRepository},
+        division::{DivisionDeletePolicy, DivisionRemoval, DivisionRepository},
+        employee::{EmployeePageFilter, EmployeeRepository, UpdateEmployeeParams},
+        employment_history::EmploymentHistoryRepository,
+        job::{JobReassignment, JobRepository},
+        position::PositionRepository,
+    },
+};
+use surrealdb::{Surreal, engine::any::Any};
+use uuid::Uuid;
+
+async fn embedded_database() -> Surreal<Any> {
+    surreal::connect(&SurrealConfig::embedded())
+        .await
+        .expect("embedded database")
+}
+
+async fn create_record(database: &Surreal<Any>, table: &str, id: Uuid) {
+    database
+        .query("CREATE type::thing($table, $id) SET name = $table")
+        .bind(("table", table.to_string()))
+        .bind(("id", id.to_string()))
+        .await
+        .expect("query")
+        .check()
+        .expect("create");
+}
+
+fn instruction(bank_id: Uuid, account: &str, amount: PaymentAmount) -> PaymentInstruction {
+    PaymentInstruction {
+        bank_id,
+        account: account.to_string(),
+        amount,
+        priority: 0,
+        verification: AccountVerification::default(),
+    }
+}
+
+fn employee(
+    division_id: Uuid,
+    job_id: Uuid,
+    payment_instructions: Vec<PaymentInstruction>,
+) -> Employee {
+    let date = NaiveDate::from_ymd_opt(1990, 5, 17).expect("date");
+    Employee::new(
+        Uuid::new_v4(),
+        "V-1",
+        None,
+        "Doe",
+        "Jane",
+        "Main St",
+        "555-0100",
+        None,
+        None,
+        "Caracas",
+        date,
+        "VE",
+        "single",
+        "female",
+        date,
+        None,
+        None,
+        None,
+        None,
+        "staff",
+        job_id,
+        payment_instructions,
+        EmployeeStatus::Active,
+        40,
+        None,
+        division_id,
+        Uuid::new_v4(),
+        None,
+        None,
+        BTreeMap::new(),
+    )
+}
+
+#[tokio::test]
+async fn payment_instructions_round_trip_through_the_database() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let (bank_id, other_bank_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+    create_record(&database, "bank", bank_id).await;
+    create_record(&database, "bank", other_bank_id).await;
+
+    let instructions = vec![
+        instruction(bank_id, "0102-01", PaymentAmount::Fixed(250.0)),
+        instruction(other_bank_id, "0134-02", PaymentAmount::Percentage(100.0)),
+    ];
+    let created = repository
+        .insert_many(vec![employee(division_id, job_id, instructions.clone())])
+        .await
+        .expect("insert")
+        .pop()
+        .expect("employee");
+    assert_eq!(created.payment_instructions, instructions);
+
+    let fetched = repository
+        .fetch(created.id)
+        .await
+        .expect("fetch")
+        .expect("employee");
+    assert_eq!(fetched.payment_instructions, instructions);
+    let paid_by_bank = repository
+        .fetch_by_bank(other_bank_id)
+        .await
+        .expect("fetch by bank");
+    assert_eq!(paid_by_bank.len(), 1);
+    assert_eq!(paid_by_bank[0].id, created.id);
+
+    let replaced = vec![instruction(
+        bank_id,
+        "0102-03",
+        PaymentAmount::Percentage(100.0),
+    )];
+    let updated = repository
+        .update(
+            created.id,
+            UpdateEmployeeParams {
+                payment_instructions: Some(replaced.clone()),
+                ..UpdateEmployeeParams::default()
+            },
+        )
+        .await
+        .expect("update")
+        .expect("employee");
+    assert_eq!(updated.payment_instructions, replaced);
+    assert!(
+        repository
+            .fetch_by_bank(other_bank_id)
+            .await
+            .expect("fetch by bank")
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn bulk_creates_are_all_or_nothing() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, job_id, bank_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+    create_record(&database, "bank", bank_id).await;
+
+    let paid = || {
+        vec![instruction(
+            bank_id,
+            "0102-01",
+            PaymentAmount::Percentage(100.0),
+        )]
+    };
+    let lead = employee(division_id, job_id, paid());
+    let mut report = employee(division_id, job_id, paid());
+    report.supervisor_id = Some(lead.id);
+    let created = repository
+        .insert_many(vec![
+            lead.clone(),
+            report.clone(),
+            employee(division_id, job_id, paid()),
+        ])
+        .await
+        .expect("insert");
+    assert_eq!(created.len(), 3);
+    assert_eq!(created[1].supervisor_id, Some(lead.id));
+
+    let missing_bank = Uuid::new_v4();
+    let stranded = employee(
+        division_id,
+        job_id,
+        vec![instruction(
+            missing_bank,
+            "0134-09",
+            PaymentAmount::Percentage(100.0),
+        )],
+    );
+    let error = repository
+        .insert_many(vec![employee(division_id, job_id, paid()), stranded])
+        .await
+        .expect_err("missing bank");
+    assert!(matches!(error, AppError::Conflict { .. }), "{error:?}");
+
+    let mut supervised = report.clone();
+    supervised.id = Uuid::new_v4();
+    supervised.supervisor_id = Some(Uuid::new_v4());
+    let error = repository
+        .insert_many(vec![employee(division_id, job_id, paid()), supervised])
+        .await
+        .expect_err("missing supervisor");
+    assert!(matches!(error, AppError::Conflict { .. }), "{error:?}");
+
+    let mut duplicate = lead.clone();
+    duplicate.id_number = "V-2".to_string();
+    let error = repository
+        .insert_many(vec![employee(division_id, job_id, paid()), duplicate])
+        .await
+        .expect_err("existing id");
+    assert!(matches!(error, AppError::Conflict { .. }), "{error:?}");
+    let kept = repository
+        .fetch(lead.id)
+        .await
+        .expect("fetch")
+        .expect("employee");
+    assert_eq!(kept.id_number, "V-1");
+
+    assert_eq!(
+        repository
+            .fetch_by_division(division_id)
+            .await
+            .expect("fetch by division")
+            .len(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn concurrent_creates_share_their_references() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+
+    let creates = (0..8).map(|_| {
+        let repository = repository.clone();
+        tokio::spawn(async move {
+            repository
+                .insert_many(vec![employee(division_id, job_id, Vec::new())])
+                .await
+        })
+    });
+    for create in creates {
+        create.await.expect("task").expect("insert");
+    }
+    assert_eq!(
+        repository
+            .fetch_by_division(division_id)
+            .await
+            .expect("fetch by division")
+            .len(),
+        8
+    );
+}
+
+#[tokio::test]
+async fn deleting_a_job_never_leaves_a_concurrently_created_employee_dangling() {
+    let database = embedded_database().await;
+    let employees = SurrealEmployeeRepository::new(database.clone());
+    let jobs = SurrealJobRepository::new(database.clone());
+    let division_id = Uuid::new_v4();
+    create_record(&database, "division", division_id).await;
+
+    for _ in 0..16 {
+        let job_id = Uuid::new_v4();
+        create_record(&database, "job", job_id).await;
+
+        let create = tokio::spawn({
+            let employees = employees.clone();
+            async move {
+                employees
+                    .insert_many(vec![employee(division_id, job_id, Vec::new())])
+                    .await
+            }
+        });
+        let delete = tokio::spawn({
+            let jobs = jobs.clone();
+            async move { jobs.delete(job_id, None).await }
+        });
+        let created = create.await.expect("task");
+        let deleted = delete.await.expect("task");
+
+        let assigned = employees.fetch_by_job(job_id).await.expect("fetch by job");
+        match (created, deleted) {
+            (Ok(_), Err(AppError::Conflict { .. })) => {
+                assert_eq!(assigned.len(), 1);
+                let mut response = database
+                    .query("SELECT VALUE id FROM type::thing('job', $id)")
+                    .bind(("id", job_id.to_string()))
+                    .await
+                    .expect("query");
+                let remaining: Vec<surrealdb::sql::Thing> = response.take(0).expect("job");
+                assert_eq!(remaining.len(), 1);
+            }
+            (Err(AppError::Conflict { .. }), Ok(true)) => assert!(assigned.is_empty()),
+            (created, deleted) => panic!("create {created:?} and delete {deleted:?} both ran"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn deleting_a_job_moves_its_employees_in_the_same_transaction() {
+    let database = embedded_database().await;
+    let employees = SurrealEmployeeRepository::new(database.clone());
+    let jobs = SurrealJobRepository::new(database.clone());
+    let history = SurrealEmploymentHistoryRepository::new(database.clone());
+    let (division_id, job_id, target_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+    create_record(&database, "job", target_id).await;
+    let assigned = employees
+        .insert_many(vec![
+            employee(division_id, job_id, Vec::new()),
+            employee(division_id, job_id, Vec::new()),
+        ])
+        .await
+        .expect("insert");
+
+    let change = |employee: &Employee| {
+        let mut event = EmploymentEvent::new(
+            Uuid::new_v4(),
+            employee.id,
+            EmploymentEventKind::JobChange,
+            employee.hire_date,
+            Utc::now(),
+        );
+        event.job_change = Some(JobChange {
+            from_job_id: job_id,
+            to_job_id: target_id,
+            from_salary: 100.0,
+            to_salary: 120.0,
+        });
+        event
+    };
+    let stale = JobReassignment {
+        to_job_id: target_id,
+        events: vec![change(&assigned[0])],
+    };
+    assert!(matches!(
+        jobs.delete(job_id, Some(stale)).await,
+        Err(AppError::Conflict { .. })
+    ));
+    assert!(
+        employees
+            .fetch_by_job(target_id)
+            .await
+            .expect("fetch by job")
+            .is_empty()
+    );
+
+    let reassignment = JobReassignment {
+        to_job_id: target_id,
+        events: assigned.iter().map(change).collect(),
+    };
+    assert!(
+        jobs.delete(job_id, Some(reassignment))
+            .await
+            .expect("delete")
+    );
+    assert_eq!(
+        employees
+            .fetch_by_job(target_id)
+            .await
+            .expect("fetch by job")
+            .len(),
+        2
+    );
+    for employee in &assigned {
+        let events = history
+            .fetch_by_employee(employee.id)
+            .await
+            .expect("history");
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].job_change.as_ref().expect("job change").to_job_id,
+            target_id
+        );
+    }
+}
+
+#[tokio::test]
+async fn deleting_a_bank_moves_its_accounts_in_the_same_transaction() {
+    let database = embedded_database().await;
+    let employees = SurrealEmployeeRepository::new(database.clone());
+    let banks = SurrealBankRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    let (bank_id, target_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+    create_record(&database, "bank", bank_id).await;
+    create_record(&database, "bank", target_id).await;
+    let paid = employees
+        .insert_many(vec![
+            employee(
+                division_id,
+                job_id,
+                vec![instruction(
+                    bank_id,
+                    "ACC-1",
+                    PaymentAmount::Percentage(100.0),
+                )],
+            ),
+            employee(
+                division_id,
+                job_id,
+                vec![instruction(
+                    bank_id,
+                    "ACC-2",
+                    PaymentAmount::Percentage(100.0),
+                )],
+            ),
+        ])
+        .await
+        .expect("insert");
+
+    let moved = |employee: &Employee| {
+        let mut instructions = employee.payment_instructions.clone();
+        instructions[0].bank_id = target_id;
+        (employee.id, instructions)
+    };
+    let stale = BankReassignment {
+        to_bank_id: target_id,
+        payment_instructions: vec![moved(&paid[0])],
+    };
+    assert!(matches!(
+        banks.delete(bank_id, Some(stale)).await,
+        Err(AppError::Conflict { .. })
+    ));
+    assert!(
+        employees
+            .fetch_by_bank(target_id)
+            .await
+            .expect("fetch by bank")
+            .is_empty()
+    );
+
+    let reassignment = BankReassignment {
+        to_bank_id: target_id,
+        payment_instructions: paid.iter().map(moved).collect(),
+    };
+    assert!(
+        banks
+            .delete(bank_id, Some(reassignment))
+            .await
+            .expect("delete")
+    );
+    assert_eq!(
+        employees
+            .fetch_by_bank(target_id)
+            .await
+            .expect("fetch by bank")
+            .len(),
+        2
+    );
+    assert!(banks.fetch(bank_id).await.expect("fetch").is_none());
+}
+
+#[tokio::test]
+async fn terminating_vacates_positions_and_records_the_event_together() {
+    let database = embedded_database().await;
+    let employees = SurrealEmployeeRepository::new(database.clone());
+    let positions = SurrealPositionRepository::new(database.clone());
+    let history = SurrealEmploymentHistoryRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+    let employee = employees
+        .insert_many(vec![employee(division_id, job_id, Vec::new())])
+        .await
+        .expect("insert")
+        .pop()
+        .expect("employee");
+
+    let former = Uuid::new_v4();
+    let mut position = Position::new(
+        Uuid::new_v4(),
+        "Analyst",
+        job_id,
+        1800.0,
+        division_id,
+        employee.payroll_id,
+    );
+    position.employee_id = Some(employee.id);
+    position.history = vec![
+        PositionAssignment {
+            employee_id: former,
+            start_date: NaiveDate::from_ymd_opt(2020, 1, 6).expect("date"),
+            end_date: NaiveDate::from_ymd_opt(2023, 3, 31),
+        },
+        PositionAssignment {
+            employee_id: employee.id,
+            start_date: NaiveDate::from_ymd_opt(2023, 4, 3).expect("date"),
+            end_date: None,
+        },
+    ];
+    positions.insert(position.clone()).await.expect("position");
+
+    let effective_date = NaiveDate::from_ymd_opt(2024, 8, 30).expect("date");
+    let termination = |employee_id| {
+        let mut event = EmploymentEvent::new(
+            Uuid::new_v4(),
+            employee_id,
+            EmploymentEventKind::Termination,
+            effective_date,
+            Utc::now(),
+        );
+        event.termination = Some(TerminationTerms {
+            reason_code: "RESIGNED".to_string(),
+            eligible_for_rehire: true,
+            eligible_for_severance: false,
+            final_pay_pending: true,
+        });
+        event
+    };
+    let terminated = employees
+        .terminate(employee.id, termination(employee.id))
+        .await
+        .expect("terminate")
+        .expect("employee");
+    assert_eq!(terminated.status, EmployeeStatus::Terminated);
+    assert_eq!(terminated.termination_date, Some(effective_date));
+
+    position.employee_id = None;
+    position.history[1].end_date = Some(effective_date);
+    let vacated = positions
+        .fetch(position.id)
+        .await
+        .expect("fetch")
+        .expect("position");
+    assert_eq!(vacated, position);
+    let events = history
+        .fetch_by_employee(employee.id)
+        .await
+        .expect("history");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, EmploymentEventKind::Termination);
+
+    assert!(matches!(
+        employees
+            .terminate(employee.id, termination(employee.id))
+            .await,
+        Err(AppError::Conflict { .. })
+    ));
+    assert!(
+        employees
+            .terminate(Uuid::new_v4(), termination(employee.id))
+            .await
+            .expect("terminate")
+            .is_none()
+    );
+    assert_eq!(
+        history
+            .fetch_by_employee(employee.id)
+            .await
+            .expect("history")
+            .len(),
+        1
+    );
+}
+
+/// Tables keeping rows under an employee in `employee_id`.
+const EMPLOYEE_RECORD_TABLES: [&str; 9] = [
+    "dependent",
+    "employment_event",
+    "leave_adjustment",
+    "leave_balance",
+    "leave_request",
+    "overtime_request",
+    "self_service_token",
+    "timesheet_entry",
+    "timesheet_period",
+];
+
+/// One row in every table kept under the employee, plus their email verification and photo.
+async fn create_employee_records(database: &Surreal<Any>, employee_id: Uuid) {
+    for table in EMPLOYEE_RECORD_TABLES {
+        database
+            .query("CREATE type::table($table) SET employee_id = $employee_id")
+            .bind(("table", table))
+            .bind(("employee_id", employee_id.to_string()))
+            .await
+            .expect("query")
+            .check()
+            .expect("create");
+    }
+    database
+        .query(
+            "CREATE type::thing('email_verification', $employee_id) SET email = 'jane@example.com';
+             CREATE type::thing('blob', $photo_key) SET content_type = 'image/png', data = '';",
+        )
+        .bind(("employee_id", employee_id.to_string()))
+        .bind(("photo_key", Employee::photo_key(employee_id)))
+        .await
+        .expect("query")
+        .check()
+        .expect("create");
+}
+
+async fn employee_records_left(database: &Surreal<Any>, employee_id: Uuid) -> usize {
+    let mut response = database
+        .query(format!(
+            "SELECT VALUE id FROM {} WHERE employee_id = $employee_id;
+             SELECT VALUE id FROM type::thing('email_verification', $employee_id),
+                 type::thing('blob', $photo_key);",
+            EMPLOYEE_RECORD_TABLES.join(", ")
+        ))
+        .bind(("employee_id", employee_id.to_string()))
+        .bind(("photo_key", Employee::photo_key(employee_id)))
+        .await
+        .expect("query")
+        .check()
+        .expect("select");
+    let owned: Vec<surrealdb::sql::Thing> = response.take(0).expect("owned");
+    let keyed: Vec<surrealdb::sql::Thing> = response.take(1).expect("keyed");
+    owned.len() + keyed.len()
+}
+
+async fn division_manager(database: &Surreal<Any>, division_id: Uuid) -> Option<String> {
+    let mut response = database
+        .query("SELECT VALUE manager_employee_id FROM type::thing('division', $id)")
+        .bind(("id", division_id.to_string()))
+        .await
+        .expect("query");
+    let managers: Vec<Option<String>> = response.take(0).expect("manager");
+    managers.into_iter().flatten().next()
+}
+
+async fn set_division_manager(database: &Surreal<Any>, division_id: Uuid, employee_id: Uuid) {
+    database
+        .query("UPDATE type::thing('division', $id) SET manager_employee_id = $employee_id")
+        .bind(("id", division_id.to_string()))
+        .bind(("employee_id", employee_id.to_string()))
+        .await
+        .expect("query")
+        .check()
+        .expect("update");
+}
+
+fn held_position(
+    division_id: Uuid,
+    job_id: Uuid,
+    payroll_id: Uuid,
+    former: Uuid,
+    holder: Uuid,
+) -> Position {
+    let mut position = Position::new(
+        Uuid::new_v4(),
+        "Analyst",
+        job_id,
+        1800.0,
+        division_id,
+        payroll_id,
+    );
+    position.employee_id = Some(holder);
+    position.history = vec![
+        PositionAssignment {
+            employee_id: former,
+            start_date: NaiveDate::from_ymd_opt(2020, 1, 6).expect("date"),
+            end_date: NaiveDate::from_ymd_opt(2023, 3, 31),
+        },
+        PositionAssignment {
+            employee_id: holder,
+            start_date: NaiveDate::from_ymd_opt(2023, 4, 3).expect("date"),
+            end_date: None,
+        },
+    ];
+    position
+}
+
+#[tokio::test]
+async fn deleting_an_employee_leaves_nothing_dangling() {
+    let database = embedded_database().await;
+    let employees = SurrealEmployeeRepository::new(database.clone());
+    let positions = SurrealPositionRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+
+    let manager = employees
+        .insert_many(vec![employee(division_id, job_id, Vec::new())])
+        .await
+        .expect("insert")
+        .pop()
+        .expect("manager");
+    let mut report = employee(division_id, job_id, Vec::new());
+    report.supervisor_id = Some(manager.id);
+    let report = employees
+        .insert_many(vec![report])
+        .await
+        .expect("insert")
+        .pop()
+        .expect("report");
+    set_division_manager(&database, division_id, manager.id).await;
+    assert_eq!(
+        division_manager(&database, division_id).await,
+        Some(manager.id.to_string())
+    );
+    let position = held_position(
+        division_id,
+        job_id,
+        manager.payroll_id,
+        report.id,
+        manager.id,
+    );
+    positions.insert(position.clone()).await.expect("position");
+    create_employee_records(&database, manager.id).await;
+    create_employee_records(&database, report.id).await;
+
+    assert!(employees.delete(manager.id).await.expect("delete"));
+    assert!(!employees.delete(manager.id).await.expect("delete again"));
+
+    assert_eq!(employee_records_left(&database, manager.id).await, 0);
+    assert_eq!(
+        employee_records_left(&database, report.id).await,
+        EMPLOYEE_RECORD_TABLES.len() + 2
+    );
+    let report = employees
+        .fetch(report.id)
+        .await
+        .expect("fetch")
+        .expect("report");
+    assert_eq!(report.supervisor_id, None);
+    assert_eq!(division_manager(&database, division_id).await, None);
+    let position = positions
+        .fetch(position.id)
+        .await
+        .expect("fetch")
+        .expect("position");
+    assert_eq!(position.employee_id, None);
+    assert_eq!(position.history.len(), 1);
+    assert_eq!(position.history[0].employee_id, report.id);
+}
+
+#[tokio::test]
+async fn cascading_a_division_delete_leaves_nothing_dangling() {
+    let database = embedded_database().await;
+    let employees = SurrealEmployeeRepository::new(database.clone());
+    let positions = SurrealPositionRepository::new(database.clone());
+    let divisions = SurrealDivisionRepository::new(database.clone());
+    let (division_id, other_division, job_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "division", other_division).await;
+    create_record(&database, "job", job_id).await;
+
+    let removed = employees
+        .insert_many(vec![employee(division_id, job_id, Vec::new())])
+        .await
+        .expect("insert")
+        .pop()
+        .expect("employee");
+    let mut kept = employee(other_division, job_id, Vec::new());
+    kept.supervisor_id = Some(removed.id);
+    let kept = employees
+        .insert_many(vec![kept])
+        .await
+        .expect("insert")
+        .pop()
+        .expect("employee");
+    set_division_manager(&database, other_division, removed.id).await;
+    let position = held_position(other_division, job_id, kept.payroll_id, kept.id, removed.id);
+    positions.insert(position.clone()).await.expect("position");
+    create_employee_records(&database, removed.id).await;
+
+    let deleted = divisions
+        .remove(DivisionRemoval {
+            division_id,
+            descendants: Vec::new(),
+            policy: DivisionDeletePolicy::Cascade,
+        })
+        .await
+        .expect("remove")
+        .expect("division");
+    assert_eq!(deleted, vec![removed.id]);
+
+    assert_eq!(employee_records_left(&database, removed.id).await, 0);
+    let kept = employees
+        .fetch(kept.id)
+        .await
+        .expect("fetch")
+        .expect("employee");
+    assert_eq!(kept.supervisor_id, None);
+    assert_eq!(division_manager(&database, other_division).await, None);
+    let position = positions
+        .fetch(position.id)
+        .await
+        .expect("fetch")
+        .expect("position");
+    assert_eq!(position.employee_id, None);
+    assert_eq!(position.history.len(), 1);
+}
+
+#[tokio::test]
+async fn employees_are_found_by_email_within_the_given_payrolls() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+
+    let mut taken = employee(division_id, job_id, Vec::new());
+    taken.email = Some("jane@example.com".to_string());
+    let mut other = employee(division_id, job_id, Vec::new());
+    other.email = Some("john@example.com".to_string());
+    let unset = employee(division_id, job_id, Vec::new());
+    repository
+        .insert_many(vec![taken.clone(), other, unset])
+        .await
+        .expect("insert");
+
+    let found = repository
+        .fetch_by_email("jane@example.com", vec![Uuid::new_v4(), taken.payroll_id])
+        .await
+        .expect("fetch by email");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, taken.id);
+    assert!(
+        repository
+            .fetch_by_email("jane@example.com", vec![Uuid::new_v4()])
+            .await
+            .expect("fetch by email")
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn active_employees_are_counted_by_job() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, job_id, other_job_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+    create_record(&database, "job", other_job_id).await;
+
+    let mut terminated = employee(division_id, job_id, Vec::new());
+    terminated.status = EmployeeStatus::Terminated;
+    repository
+        .insert_many(vec![
+            employee(division_id, job_id, Vec::new()),
+            employee(division_id, job_id, Vec::new()),
+            terminated,
+            employee(division_id, other_job_id, Vec::new()),
+        ])
+        .await
+        .expect("insert");
+
+    assert_eq!(
+        repository.count_active_by_job(job_id).await.expect("count"),
+        2
+    );
+    assert_eq!(
+        repository
+            .count_active_by_job(Uuid::new_v4())
+            .await
+            .expect("count"),
+        0
+    );
+}
+
+#[test]
+fn transaction_conflicts_are_reported_as_conflicts() {
+    let conflict = surrealdb::Error::Db(surrealdb::error::Db::Tx(
+        "Transaction write conflict".to_string(),
+    ));
+    assert!(matches!(
+        AppError::from(conflict),
+        AppError::Conflict { .. }
+    ));
+    assert!(matches!(
+        AppError::from(surrealdb::Error::Db(
+            surrealdb::error::Db::TxConditionNotMet
+        )),
+        AppError::Conflict { .. }
+    ));
+
+    let failure = surrealdb::Error::Db(surrealdb::error::Db::Tx("disk full".to_string()));
+    assert!(matches!(AppError::from(failure), AppError::Database { .. }));
+
+    let thrown = surrealdb::Error::Db(surrealdb::error::Db::Thrown(
+        "schema conflict: resource busy, can be retried".to_string(),
+    ));
+    assert!(matches!(AppError::from(thrown), AppError::Database { .. }));
+}
+
+#[tokio::test]
+async fn employees_are_found_across_divisions_in_one_query() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (first, second, other, job_id) = (
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+    );
+    for division_id in [first, second, other] {
+        create_record(&database, "division", division_id).await;
+    }
+    create_record(&database, "job", job_id).await;
+
+    let created = repository
+        .insert_many(vec![
+            employee(first, job_id, Vec::new()),
+            employee(second, job_id, Vec::new()),
+            employee(other, job_id, Vec::new()),
+        ])
+        .await
+        .expect("insert");
+
+    let mut found: Vec<Uuid> = repository
+        .fetch_by_divisions(vec![first, second])
+        .await
+        .expect("fetch by divisions")
+        .iter()
+        .map(|employee| employee.id)
+        .collect();
+    found.sort();
+    let mut expected = vec![created[0].id, created[1].id];
+    expected.sort();
+    assert_eq!(found, expected);
+    assert!(
+        repository
+            .fetch_by_divisions(Vec::new())
+            .await
+            .expect("fetch by divisions")
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn direct_reports_are_found_by_supervisor() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+
+    let supervisor = employee(division_id, job_id, Vec::new());
+    let mut report = employee(division_id, job_id, Vec::new());
+    report.supervisor_id = Some(supervisor.id);
+    let created = repository
+        .insert_many(vec![supervisor.clone(), report.clone()])
+        .await
+        .expect("insert");
+    assert_eq!(created.len(), 2);
+
+    let reports = repository
+        .fetch_by_supervisor(supervisor.id)
+        .await
+        .expect("fetch by supervisor");
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].id, report.id);
+    assert!(
+        repository
+            .fetch_by_supervisor(report.id)
+            .await
+            .expect("fetch by supervisor")
+            .is_empty()
+    );
+}
+
+async fn set_stored_status(database: &Surreal<Any>, id: Uuid, status: &str) {
+    database
+        .query("UPDATE type::thing('employee', $id) SET status = $status")
+        .bind(("id", id.to_string()))
+        .bind(("status", status.to_string()))
+        .await
+        .expect("query")
+        .check()
+        .expect("update");
+}
+
+async fn stored_status(database: &Surreal<Any>, id: Uuid) -> String {
+    let mut response = database
+        .query("SELECT VALUE status FROM type::thing('employee', $id)")
+        .bind(("id", id.to_string()))
+        .await
+        .expect("query");
+    let status: Option<String> = response.take(0).expect("status");
+    status.expect("employee")
+}
+
+#[tokio::test]
+async fn legacy_statuses_are_migrated_and_unknown_ones_fail_the_migration() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+
+    let created = repository
+        .insert_many(vec![
+            employee(division_id, job_id, Vec::new()),
+            employee(division_id, job_id, Vec::new()),
+            employee(division_id, job_id, Vec::new()),
+        ])
+        .await
+        .expect("insert");
+    let (on_leave, leaver, retired) = (created[0].id, created[1].id, created[2].id);
+    set_stored_status(&database, on_leave, "on leave").await;
+    set_stored_status(&database, leaver, "Leaver").await;
+    set_stored_status(&database, retired, "Retired").await;
+
+    let error = surreal::migrate(&database)
+        .await
+        .expect_err("unknown status");
+    assert!(error.to_string().contains(&retired.to_string()), "{error}");
+    assert_eq!(stored_status(&database, on_leave).await, "OnLeave");
+    assert_eq!(stored_status(&database, leaver).await, "Terminated");
+    assert_eq!(stored_status(&database, retired).await, "Retired");
+
+    let migrated = repository
+        .fetch(on_leave)
+        .await
+        .expect("fetch")
+        .expect("employee");
+    assert_eq!(migrated.status, EmployeeStatus::OnLeave);
+    let error = repository.fetch(retired).await.expect_err("unknown status");
+    assert!(matches!(error, AppError::Internal { .. }), "{error:?}");
+}
+
+#[tokio::test]
+async fn job_pages_skip_migrated_terminated_statuses() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, job_id) = (Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "job", job_id).await;
+
+    let created = repository
+        .insert_many(vec![
+            employee(division_id, job_id, Vec::new()),
+            employee(division_id, job_id, Vec::new()),
+            employee(division_id, job_id, Vec::new()),
+            employee(division_id, job_id, Vec::new()),
+        ])
+        .await
+        .expect("insert");
+    set_stored_status(&database, created[0].id, "terminated").await;
+    set_stored_status(&database, created[1].id, "Leaver").await;
+    set_stored_status(&database, created[2].id, "On Leave").await;
+    surreal::migrate(&database).await.expect("migrate");
+
+    let page = repository
+        .fetch_page_by_job(job_id, PageRequest::new(Some(1), Some(10)).expect("page"))
+        .await
+        .expect("job page");
+    assert_eq!(page.total, 2);
+    let mut listed: Vec<Uuid> = page.items.iter().map(|employee| employee.id).collect();
+    listed.sort();
+    let mut expected = vec![created[2].id, created[3].id];
+    expected.sort();
+    assert_eq!(listed, expected);
+}
+
+#[tokio::test]
+async fn pages_are_filtered_and_ordered_in_the_database() {
+    let database = embedded_database().await;
+    let repository = SurrealEmployeeRepository::new(database.clone());
+    let (division_id, other_division, job_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    create_record(&database, "division", division_id).await;
+    create_record(&database, "division", other_division).await;
+    create_record(&database, "job", job_id).await;
+
+    let named = |division_id: Uuid, last_name: &str, id_number: &str| {
+        let mut employee = employee(division_id, job_id, Vec::new());
+        employee.last_name = last_name.to_string();
+        employee.id_number = id_number.to_string();
+        employee
+    };
+    let created = repository
+        .insert_many(vec![
+            named(division_id, "Zamora", "V-100"),
+            named(division_id, "Alvarez", "V-101"),
+            named(division_id, "Blanco", "V-200"),
+            named(other_division, "Aranda", "V-102"),
+        ])
+        .await
+        .expect("insert");
+    set_stored_status(&database, created[2].id, "Leaver").await;
+    surreal::migrate(&database).await.expect("migrate");
+
+    let page = repository
+        .fetch_page(
+            EmployeePageFilter {
+                division_ids: vec![division_id],
+                query: Some("v-1".to_string()),
+                ..EmployeePageFilter::default()
+            },
+            PageRequest::new(Some(1), Some(10)).expect("page"),
+        )
+        .await
+        .expect("page");
+    assert_eq!(page.total, 2);
+    let listed: Vec<Uuid> = page.items.iter().map(|employee| employee.id).collect();
+    assert_eq!(listed, vec![created[1].id, created[0].id]);
+
+    let terminated = repository
+        .fetch_page(
+            EmployeePageFilter {
+                division_ids: vec![division_id, other_division],
+                status: Some(EmployeeStatus::Terminated),
+                ..EmployeePageFilter::default()
+            },
+            PageRequest::new(Some(1), Some(10)).expect("page"),
+        )
+        .await
+        .expect("page");
+    assert_eq!(terminated.total, 1);
+    assert_eq!(terminated.items[0].id, created[2].id);
+}
@@ -7,6 +7,7 @@ use axum::{
     http::{Request, StatusCode},
 };
 use http_body_util::BodyExt;
+use nomina::domain::employee::PaymentInstruction;
 use serde_json::{Value, json};
 use tower::ServiceExt;
 use uuid::Uuid;
@@ -168,8 +169,12 @@ async fn can_create_and_list_employees() {
                     "hire_date": "2020-01-01",
                     "clasification": "Full-time",
                     "job_id": job_id,
-                    "bank_id": bank_id,
-                    "bank_account": "ACC123",
+                    "payment_instructions": [{
+                        "bank_id": bank_id,
+                        "account": "ACC123",
+                        "amount": { "percentage": 100.0 },
+                        "priority": 1
+                    }],
                     "status": "Active",
                     "hours": 40
                 }).to_string()))
@@ -182,7 +187,10 @@ async fn can_create_and_list_employees() {
     let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
     assert_eq!(created["division_id"], division_id.to_string());
     assert_eq!(created["job_id"], job_id.to_string());
-    assert_eq!(created["bank_id"], bank_id.to_string());
+    assert_eq!(
+        created["payment_instructions"][0]["bank_id"],
+        bank_id.to_string()
+    );
     assert!(created["termination_date"].is_null());
 
     let response = app
@@ -235,8 +243,12 @@ async fn rejects_invalid_references_and_dates() {
                     "hire_date": "2022-01-01",
                     "clasification": "Temp",
                     "job_id": job_in_payroll,
-                    "bank_id": bank_other_org,
-                    "bank_account": "ACC000",
+                    "payment_instructions": [{
+                        "bank_id": bank_other_org,
+                        "account": "ACC000",
+                        "amount": { "percentage": 100.0 },
+                        "priority": 1
+                    }],
                     "status": "Active",
                     "hours": 10
                 }).to_string()))
@@ -270,8 +282,12 @@ async fn rejects_invalid_references_and_dates() {
                     "termination_date": "2022-01-01",
                     "clasification": "Full-time",
                     "job_id": job_in_payroll,
-                    "bank_id": bank_valid,
-                    "bank_account": "ACC999",
+                    "payment_instructions": [{
+                        "bank_id": bank_valid,
+                        "account": "ACC999",
+                        "amount": { "percentage": 100.0 },
+                        "priority": 1
+                    }],
                     "status": "Inactive",
                     "hours": 20
                 }).to_string()))
@@ -283,6 +299,92 @@ async fn rejects_invalid_references_and_dates() {
     assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
 }
 
+#[tokio::test]
+async fn rejects_employees_of_missing_or_deleted_divisions_and_jobs() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Link Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Linked").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Linked").await;
+    let deleted_job = create_job(&app, organization_id, payroll_id, "Closed").await;
+    let deleted_division = create_division(&app, organization_id, payroll_id, "Closed").await;
+    let payroll_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+
+    let send = |method: &str, uri: String, body: Body| {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(body)
+            .expect("request");
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (status, body)
+        }
+    };
+    for uri in [
+        format!("{payroll_uri}/jobs/{deleted_job}"),
+        format!("{payroll_uri}/divisions/{deleted_division}"),
+    ] {
+        let (status, _) = send("DELETE", uri, Body::empty()).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+
+    let employee = |job_id: Uuid| {
+        json!({
+            "id_number": "LINK-1",
+            "last_name": "Dangling",
+            "first_name": "Lee",
+            "address": "1 Link St",
+            "phone": "555-1111",
+            "place_of_birth": "Town",
+            "date_of_birth": "1990-01-01",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2022-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": "ACC-LINK",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        })
+        .to_string()
+    };
+    for (division_id, job_id) in [
+        (Uuid::new_v4(), job_id),
+        (deleted_division, job_id),
+        (division_id, Uuid::new_v4()),
+        (division_id, deleted_job),
+    ] {
+        let (status, _) = send(
+            "POST",
+            format!("{payroll_uri}/divisions/{division_id}/employees"),
+            Body::from(employee(job_id)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    let (status, body) = send(
+        "GET",
+        format!("/employees?organization_id={organization_id}"),
+        Body::empty(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(read_json(body), json!([]));
+}
+
 #[tokio::test]
 async fn can_update_and_delete_employee() {
     let app = support::test_router();
@@ -313,8 +415,12 @@ async fn can_update_and_delete_employee() {
                     "hire_date": "2021-06-01",
                     "clasification": "Part-time",
                     "job_id": job_id,
-                    "bank_id": bank_id,
-                    "bank_account": "ACCT-456",
+                    "payment_instructions": [{
+                        "bank_id": bank_id,
+                        "account": "ACCT-456",
+                        "amount": { "percentage": 100.0 },
+                        "priority": 1
+                    }],
                     "status": "Active",
                     "hours": 25
                 }).to_string()))
@@ -346,7 +452,7 @@ async fn can_update_and_delete_employee() {
     assert_eq!(response.status(), StatusCode::OK);
     let updated = read_json(response.into_body().collect().await.unwrap().to_bytes());
     assert_eq!(updated["hours"], 30);
-    assert_eq!(updated["status"], "On Leave");
+    assert_eq!(updated["status"], "OnLeave");
     assert!(updated["termination_date"].is_null());
 
     let response = app
@@ -376,3 +482,2537 @@ async fn can_update_and_delete_employee() {
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
+
+#[tokio::test]
+async fn can_upload_and_fetch_employee_photo() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Photo Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Designer").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Creative").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(&employees_uri)
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "id_number": "PIC-1",
+                        "last_name": "Lens",
+                        "first_name": "Lola",
+                        "address": "9 Shutter Ave",
+                        "phone": "555-3333",
+                        "place_of_birth": "Focus City",
+                        "date_of_birth": "1992-07-07",
+                        "nationality": "Exampleland",
+                        "marital_status": "Single",
+                        "gender": "F",
+                        "hire_date": "2023-02-01",
+                        "clasification": "Full-time",
+                        "job_id": job_id,
+                        "payment_instructions": [{
+                            "bank_id": bank_id,
+                            "account": "ACC-PIC",
+                            "amount": { "percentage": 100.0 },
+                            "priority": 1
+                        }],
+                        "status": "Active",
+                        "hours": 40
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(created["photo_url"].is_null());
+    let photo_uri = format!("{employees_uri}/{}/photo", created["id"].as_str().unwrap());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(&photo_uri)
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(&photo_uri)
+                .header("content-type", "application/pdf")
+                .body(Body::from(vec![1_u8, 2, 3]))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let png = vec![0x89_u8, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(&photo_uri)
+                .header("content-type", "image/png")
+                .body(Body::from(png.clone()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(updated["photo_url"], photo_uri);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(&photo_uri)
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/png");
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(bytes.to_vec(), png);
+}
+
+#[tokio::test]
+async fn can_assign_division_manager_and_clears_it_on_employee_delete() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Manager Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Lead").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Ops").await;
+    let division_uri =
+        format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("{division_uri}/employees"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "id_number": "MGR-1",
+                        "last_name": "Boss",
+                        "first_name": "Morgan",
+                        "address": "1 Lead Ave",
+                        "phone": "555-9999",
+                        "place_of_birth": "Town",
+                        "date_of_birth": "1975-01-01",
+                        "nationality": "Testland",
+                        "marital_status": "Single",
+                        "gender": "F",
+                        "hire_date": "2010-01-01",
+                        "clasification": "Full-time",
+                        "job_id": job_id,
+                        "payment_instructions": [{
+                            "bank_id": bank_id,
+                            "account": "ACCT-MGR",
+                            "amount": { "percentage": 100.0 },
+                            "priority": 1
+                        }],
+                        "status": "Active",
+                        "hours": 40
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let employee_id = created["id"].as_str().unwrap();
+
+    let assign = |employee_id: Value| {
+        Request::builder()
+            .method("PUT")
+            .uri(format!("{division_uri}/manager"))
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!({ "employee_id": employee_id }).to_string(),
+            ))
+            .expect("request")
+    };
+
+    let response = app
+        .clone()
+        .oneshot(assign(json!(Uuid::new_v4())))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = app
+        .clone()
+        .oneshot(assign(json!(employee_id)))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let division = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(division["manager_employee_id"], employee_id);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("{division_uri}/employees/{employee_id}"))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(&division_uri)
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let division = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(division["manager_employee_id"].is_null());
+}
+
+#[tokio::test]
+async fn supervisors_expose_direct_reports_and_reject_cycles() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Reporting Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Analyst").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Finance").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+    let employee = |id_number: &str, last_name: &str, supervisor_id: Value| {
+        json!({
+            "id_number": id_number,
+            "last_name": last_name,
+            "first_name": "Riley",
+            "address": "2 Ledger St",
+            "phone": "555-2000",
+            "place_of_birth": "Town",
+            "date_of_birth": "1985-05-05",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "M",
+            "hire_date": "2018-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": format!("ACCT-{id_number}"),
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40,
+            "supervisor_id": supervisor_id
+        })
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            employees_uri.clone(),
+            employee("SUP-1", "Head", Value::Null),
+        ))
+        .await
+        .expect("response");
+    let head = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let head_id = head["id"].as_str().unwrap().to_string();
+
+    let mut report_ids = Vec::new();
+    for (id_number, last_name) in [("SUP-3", "Zeller"), ("SUP-2", "Adams")] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                employees_uri.clone(),
+                employee(id_number, last_name, json!(head_id)),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let report = read_json(response.into_body().collect().await.unwrap().to_bytes());
+        assert_eq!(report["supervisor_id"], head_id.as_str());
+        report_ids.push(report["id"].as_str().unwrap().to_string());
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("{employees_uri}/{head_id}/direct-reports"))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let reports = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let names: Vec<&str> = reports
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|report| report["last_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["Adams", "Zeller"]);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{employees_uri}/{head_id}"),
+            json!({ "supervisor_id": report_ids[0] }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{employees_uri}/{head_id}"),
+            json!({ "supervisor_id": head_id }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("{employees_uri}/{head_id}"))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("{employees_uri}/{}", report_ids[1]))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let report = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(report["supervisor_id"].is_null());
+}
+
+#[tokio::test]
+async fn enforces_status_transitions_and_rehire() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Status Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Operator").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Plant").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            employees_uri.clone(),
+            json!({
+                "id_number": "STS-1",
+                "last_name": "Shift",
+                "first_name": "Casey",
+                "address": "3 Plant Rd",
+                "phone": "555-3000",
+                "place_of_birth": "Town",
+                "date_of_birth": "1990-03-03",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": "F",
+                "hire_date": "2019-01-01",
+                "clasification": "Full-time",
+                "job_id": job_id,
+                "payment_instructions": [{
+                    "bank_id": bank_id,
+                    "account": "ACCT-STS",
+                    "amount": { "percentage": 100.0 },
+                    "priority": 1
+                }],
+                "status": "Onboarding",
+                "hours": 40
+            }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let employee_uri = format!("{employees_uri}/{}", created["id"].as_str().unwrap());
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            employee_uri.clone(),
+            json!({ "status": "Retired" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            employee_uri.clone(),
+            json!({ "status": "OnLeave" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            employee_uri.clone(),
+            json!({ "status": "Terminated", "termination_date": "2023-06-30" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let error = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(error.to_string().contains("terminate the employee instead"));
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{employee_uri}/terminate"),
+            json!({ "effective_date": "2023-06-30", "reason_code": "RESIGNATION" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            employee_uri.clone(),
+            json!({ "status": "Active" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{employee_uri}/rehire"),
+            json!({ "hire_date": "2024-01-15" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let rehired = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(rehired["status"], "Onboarding");
+    assert_eq!(rehired["hire_date"], "2024-01-15");
+    assert!(rehired["termination_date"].is_null());
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{employee_uri}/rehire"),
+            json!({ "hire_date": "2024-02-01" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn enforces_division_headcount_budget() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Headcount Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Teller").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Branch").await;
+    let division_uri =
+        format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+    let employee = |id_number: &str, status: &str| {
+        json!({
+            "id_number": id_number,
+            "last_name": "Counted",
+            "first_name": "Jamie",
+            "address": "5 Branch St",
+            "phone": "555-5000",
+            "place_of_birth": "Town",
+            "date_of_birth": "1992-02-02",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "M",
+            "hire_date": "2021-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": format!("ACCT-{id_number}"),
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": status,
+            "hours": 40
+        })
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            division_uri.clone(),
+            json!({ "headcount_budget": 1 }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let division = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(division["headcount_budget"], 1);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{division_uri}/employees"),
+            employee("HC-1", "Active"),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{division_uri}/employees"),
+            employee("HC-2", "Active"),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let error = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(error.to_string().contains("1 of 1 in use"));
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{division_uri}/employees"),
+            employee("HC-3", "Terminated"),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let leaver = read_json(response.into_body().collect().await.unwrap().to_bytes());
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!(
+                "{division_uri}/employees/{}/rehire",
+                leaver["id"].as_str().unwrap()
+            ),
+            json!({ "hire_date": "2024-01-01" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            division_uri.clone(),
+            json!({ "headcount_budget": null }),
+        ))
+        .await
+        .expect("response");
+    let division = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(division["headcount_budget"].is_null());
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{division_uri}/employees"),
+            employee("HC-2", "Active"),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn terminate_records_history_and_gates_rehire() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Exit Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Porter").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Lobby").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let send = |method: &str, uri: String, payload: Option<Value>| {
+        let builder = Request::builder().method(method).uri(uri);
+        match payload {
+            Some(payload) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request")
+    };
+
+    let mut employee_uris = Vec::new();
+    for id_number in ["EXIT-1", "EXIT-2"] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                employees_uri.clone(),
+                Some(json!({
+                    "id_number": id_number,
+                    "last_name": "Leaving",
+                    "first_name": "Drew",
+                    "address": "6 Exit Way",
+                    "phone": "555-6000",
+                    "place_of_birth": "Town",
+                    "date_of_birth": "1980-06-06",
+                    "nationality": "Testland",
+                    "marital_status": "Single",
+                    "gender": "M",
+                    "hire_date": "2015-01-01",
+                    "clasification": "Full-time",
+                    "job_id": job_id,
+                    "payment_instructions": [{
+                        "bank_id": bank_id,
+                        "account": format!("ACCT-{id_number}"),
+                        "amount": { "percentage": 100.0 },
+                        "priority": 1
+                    }],
+                    "status": "Active",
+                    "hours": 40
+                })),
+            ))
+            .await
+            .expect("response");
+        let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+        employee_uris.push(format!(
+            "{employees_uri}/{}",
+            created["id"].as_str().unwrap()
+        ));
+    }
+
+    let terminate = |uri: &str, eligible_for_rehire: bool| {
+        send(
+            "POST",
+            format!("{uri}/terminate"),
+            Some(json!({
+                "effective_date": "2023-03-31",
+                "reason_code": "RESIGNATION",
+                "eligible_for_rehire": eligible_for_rehire,
+                "flag_final_pay": true
+            })),
+        )
+    };
+
+    let response = app
+        .clone()
+        .oneshot(terminate(&employee_uris[0], false))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let terminated = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(terminated["status"], "Terminated");
+    assert_eq!(terminated["termination_date"], "2023-03-31");
+
+    let response = app
+        .clone()
+        .oneshot(terminate(&employee_uris[0], false))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{}/rehire", employee_uris[0]),
+            Some(json!({ "hire_date": "2024-01-01" })),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{}/employment-history", employee_uris[0]),
+            None,
+        ))
+        .await
+        .expect("response");
+    let history = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(history.as_array().unwrap().len(), 1);
+    assert_eq!(history[0]["kind"], "termination");
+    assert_eq!(history[0]["termination"]["reason_code"], "RESIGNATION");
+    assert_eq!(history[0]["termination"]["final_pay_pending"], true);
+
+    let response = app
+        .clone()
+        .oneshot(terminate(&employee_uris[1], true))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{}/rehire", employee_uris[1]),
+            Some(json!({ "hire_date": "2024-01-01" })),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{}/employment-history", employee_uris[1]),
+            None,
+        ))
+        .await
+        .expect("response");
+    let history = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let kinds: Vec<&str> = history
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(kinds, ["termination", "rehire"]);
+}
+
+#[tokio::test]
+async fn timeline_merges_hire_changes_and_positions() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Feed Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Clerk").await;
+    let promoted_job_id = create_job(&app, organization_id, payroll_id, "Senior Clerk").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Records").await;
+    let division_uri =
+        format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}");
+
+    let send = |method: &str, uri: String, payload: Option<Value>| {
+        let builder = Request::builder().method(method).uri(uri);
+        match payload {
+            Some(payload) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string())),
+            None => builder.body(Body::empty()),
+        }
+        .expect("request")
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{division_uri}/employees"),
+            Some(json!({
+                "id_number": "FEED-1",
+                "last_name": "Archive",
+                "first_name": "Robin",
+                "address": "8 Ledger Lane",
+                "phone": "555-8000",
+                "place_of_birth": "Town",
+                "date_of_birth": "1988-08-08",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": "F",
+                "hire_date": "2015-01-01",
+                "clasification": "Full-time",
+                "job_id": job_id,
+                "payment_instructions": [{
+                    "bank_id": bank_id,
+                    "account": "ACCT-FEED-1",
+                    "amount": { "percentage": 100.0 },
+                    "priority": 1
+                }],
+                "status": "Active",
+                "hours": 40
+            })),
+        ))
+        .await
+        .expect("response");
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let employee_id = created["id"].as_str().unwrap().to_string();
+    let employee_uri = format!("{division_uri}/employees/{employee_id}");
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{division_uri}/positions"),
+            Some(json!({ "title": "Records Clerk", "job_id": job_id, "budget": 50000.0 })),
+        ))
+        .await
+        .expect("response");
+    let position = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!(
+                "{division_uri}/positions/{}/assign",
+                position["id"].as_str().unwrap()
+            ),
+            Some(json!({ "employee_id": employee_id, "start_date": "2016-02-01" })),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            employee_uri.clone(),
+            Some(json!({ "status": "OnLeave", "job_id": promoted_job_id })),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send("GET", format!("{employee_uri}/timeline"), None))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let timeline = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let kinds: Vec<&str> = timeline
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        kinds,
+        ["hire", "position_assigned", "status_change", "job_change"]
+    );
+    assert_eq!(timeline[0]["date"], "2015-01-01");
+    assert_eq!(
+        timeline[2]["description"],
+        "status changed from Active to OnLeave"
+    );
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{division_uri}/employees/{}/timeline", Uuid::new_v4()),
+            None,
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn timeline_follows_transfers_and_salary_adjustments() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Transfer Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Courier").await;
+    let closing_id = create_division(&app, organization_id, payroll_id, "Closing").await;
+    let target_id = create_division(&app, organization_id, payroll_id, "Target").await;
+    let payroll_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+    let closing_uri = format!("{payroll_uri}/divisions/{closing_id}");
+
+    let send = |method: &str, uri: String, payload: Option<Value>| {
+        let app = app.clone();
+        let method = method.to_string();
+        async move {
+            let builder = Request::builder().method(method.as_str()).uri(uri);
+            let request = match payload {
+                Some(payload) => builder
+                    .header("content-type", "application/json")
+                    .body(Body::from(payload.to_string())),
+                None => builder.body(Body::empty()),
+            }
+            .expect("request");
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body = if bytes.is_empty() {
+                Value::Null
+            } else {
+                read_json(bytes)
+            };
+            (status, body)
+        }
+    };
+
+    let (status, created) = send(
+        "POST",
+        format!("{closing_uri}/employees"),
+        Some(json!({
+            "id_number": "MOVE-1",
+            "last_name": "Mover",
+            "first_name": "Kai",
+            "address": "3 Depot Road",
+            "phone": "555-8100",
+            "place_of_birth": "Town",
+            "date_of_birth": "1990-03-03",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "M",
+            "hire_date": "2015-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": "ACCT-MOVE-1",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let employee_id = created["id"].as_str().unwrap().to_string();
+
+    let (status, position) = send(
+        "POST",
+        format!("{closing_uri}/positions"),
+        Some(json!({ "title": "Route Courier", "job_id": job_id, "budget": 50000.0 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = send(
+        "POST",
+        format!(
+            "{closing_uri}/positions/{}/assign",
+            position["id"].as_str().unwrap()
+        ),
+        Some(json!({ "employee_id": employee_id, "start_date": "2016-02-01" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(
+        "DELETE",
+        format!("{closing_uri}?reassign_to={target_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let today = chrono::Utc::now().date_naive();
+    let (status, batch) = send(
+        "POST",
+        format!("{payroll_uri}/salary-adjustments"),
+        Some(json!({
+            "percentage": 10.0,
+            "effective_date": today.to_string(),
+            "job_ids": [job_id]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, approved) = send(
+        "POST",
+        format!(
+            "{payroll_uri}/salary-adjustments/{}/approve",
+            batch["id"].as_str().unwrap()
+        ),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "applied");
+
+    let (status, timeline) = send(
+        "GET",
+        format!("{payroll_uri}/divisions/{target_id}/employees/{employee_id}/timeline"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let kinds: Vec<&str> = timeline
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        kinds,
+        ["hire", "position_assigned", "transfer", "salary_adjustment"]
+    );
+    assert_eq!(
+        timeline[2]["description"],
+        format!("transferred from division `{closing_id}` to division `{target_id}`")
+    );
+    assert_eq!(
+        timeline[3]["description"],
+        format!(
+            "salary adjusted from 50000.00 to 55000.00 by adjustment `{}`",
+            batch["id"].as_str().unwrap()
+        )
+    );
+}
+
+#[tokio::test]
+async fn payment_instructions_split_pay_across_accounts() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let main_bank = create_bank(&app, organization_id, "Main Bank").await;
+    let savings_bank = create_bank(&app, organization_id, "Savings Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Accountant").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Treasury").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+    let employee = |id_number: &str, payment_instructions: Value| {
+        json!({
+            "id_number": id_number,
+            "last_name": "Split",
+            "first_name": "Sam",
+            "address": "9 Ledger Row",
+            "phone": "555-9000",
+            "place_of_birth": "Town",
+            "date_of_birth": "1985-09-09",
+            "nationality": "Testland",
+            "marital_status": "Married",
+            "gender": "F",
+            "hire_date": "2021-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": payment_instructions,
+            "status": "Active",
+            "hours": 40
+        })
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            employees_uri.clone(),
+            employee(
+                "SPLIT-BAD",
+                json!([
+                    { "bank_id": main_bank, "account": "MAIN-1", "amount": { "percentage": 60.0 }, "priority": 1 },
+                    { "bank_id": savings_bank, "account": "SAV-1", "amount": { "percentage": 30.0 }, "priority": 2 }
+                ]),
+            ),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            employees_uri.clone(),
+            employee(
+                "SPLIT-DUP",
+                json!([
+                    { "bank_id": main_bank, "account": "MAIN-1", "amount": { "percentage": 50.0 }, "priority": 1 },
+                    { "bank_id": savings_bank, "account": "SAV-1", "amount": { "percentage": 50.0 }, "priority": 1 }
+                ]),
+            ),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            employees_uri.clone(),
+            employee(
+                "SPLIT-1",
+                json!([
+                    { "bank_id": savings_bank, "account": " SAV-1 ", "amount": { "percentage": 40.0 }, "priority": 3 },
+                    { "bank_id": main_bank, "account": "MAIN-1", "amount": { "percentage": 60.0 }, "priority": 2 },
+                    { "bank_id": savings_bank, "account": "SAV-2", "amount": { "fixed": 250.0 }, "priority": 1 }
+                ]),
+            ),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let instructions = created["payment_instructions"].as_array().unwrap();
+    let accounts: Vec<&str> = instructions
+        .iter()
+        .map(|instruction| instruction["account"].as_str().unwrap())
+        .collect();
+    assert_eq!(accounts, ["SAV-2", "MAIN-1", "SAV-1"]);
+    assert_eq!(instructions[0]["amount"]["fixed"], 250.0);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{employees_uri}/{}", created["id"].as_str().unwrap()),
+            json!({
+                "payment_instructions": [
+                    { "bank_id": main_bank, "account": "MAIN-2", "amount": { "percentage": 100.0 }, "priority": 1 }
+                ]
+            }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(updated["payment_instructions"].as_array().unwrap().len(), 1);
+    assert_eq!(updated["payment_instructions"][0]["account"], "MAIN-2");
+
+    let instructions: Vec<PaymentInstruction> =
+        serde_json::from_value(created["payment_instructions"].clone()).unwrap();
+    let amounts: Vec<f64> = PaymentInstruction::allocate(&instructions, 1250.0)
+        .into_iter()
+        .map(|allocation| allocation.amount)
+        .collect();
+    assert_eq!(amounts, [250.0, 600.0, 400.0]);
+}
+
+#[tokio::test]
+async fn profile_completeness_scores_missing_details() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Profile Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Archivist").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Records").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(&employees_uri)
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "id_number": "PRF-1",
+                        "last_name": "Ledger",
+                        "first_name": "Lin",
+                        "address": "1 Archive Way",
+                        "phone": "555-7070",
+                        "place_of_birth": "Filetown",
+                        "date_of_birth": "1988-08-08",
+                        "nationality": "Exampleland",
+                        "marital_status": "Single",
+                        "gender": "F",
+                        "hire_date": "2021-05-01",
+                        "clasification": "Full-time",
+                        "job_id": job_id,
+                        "payment_instructions": [{
+                            "bank_id": bank_id,
+                            "account": "ACC-PRF",
+                            "amount": { "percentage": 100.0 },
+                            "priority": 1
+                        }],
+                        "status": "Active",
+                        "hours": 40
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let employee_id = created["id"].as_str().unwrap();
+
+    let get = |uri: String| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .expect("response");
+            let status = response.status();
+            let body = read_json(response.into_body().collect().await.unwrap().to_bytes());
+            (status, body)
+        }
+    };
+
+    let (status, completeness) = get(format!(
+        "{employees_uri}/{employee_id}/profile-completeness"
+    ))
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(completeness["missing"], json!(["email", "photo"]));
+    assert_eq!(completeness["score"], 71.4);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/custom-fields"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({"name": "badge", "field_type": "text", "required": true}).to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let (status, rollup) = get(format!(
+        "/organizations/{organization_id}/profile-completeness?division_id={division_id}"
+    ))
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(rollup["employees"], 1);
+    assert_eq!(rollup["complete"], 0);
+    assert_eq!(rollup["average_score"], 62.5);
+    assert_eq!(rollup["missing_counts"]["custom_fields.badge"], 1);
+    assert_eq!(rollup["incomplete"][0]["employee_id"], employee_id);
+}
+
+#[tokio::test]
+async fn email_is_unique_and_verified_with_a_code() {
+    let (app, outbox) = support::test_router_with_outbox();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Mail Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Courier").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Mailroom").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+
+    let send = |method: &'static str, uri: String, body: Option<Value>| {
+        let app = app.clone();
+        async move {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json");
+            let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+            let response = app
+                .oneshot(request.body(body).expect("request"))
+                .await
+                .expect("response");
+            let status = response.status();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body = if bytes.is_empty() {
+                Value::Null
+            } else {
+                read_json(bytes)
+            };
+            (status, body)
+        }
+    };
+    let employee = |id_number: &str, email: &str| {
+        json!({
+            "id_number": id_number,
+            "last_name": "Post",
+            "first_name": "Pat",
+            "email": email,
+            "address": "2 Letter Lane",
+            "phone": "555-8080",
+            "place_of_birth": "Stampville",
+            "date_of_birth": "1990-02-02",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "M",
+            "hire_date": "2022-01-10",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": "ACC-MAIL",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        })
+    };
+
+    let (status, created) = send(
+        "POST",
+        employees_uri.clone(),
+        Some(employee("MAIL-1", "  Pat.Post@Example.COM ")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created["email"], "pat.post@example.com");
+    assert!(created["email_verified_at"].is_null());
+    let employee_uri = format!("{employees_uri}/{}", created["id"].as_str().unwrap());
+
+    let (status, _) = send(
+        "POST",
+        employees_uri.clone(),
+        Some(employee("MAIL-2", "pat.post@example.com")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send(
+        "POST",
+        employees_uri.clone(),
+        Some(employee("MAIL-3", "not-an-address")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, sent) = send("POST", format!("{employee_uri}/email-verification"), None).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(sent["email"], "pat.post@example.com");
+    let messages = outbox.sent().await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].to, "pat.post@example.com");
+    let code: String = messages[0]
+        .body
+        .split_whitespace()
+        .find(|word| word.len() == 7 && word.starts_with(|c: char| c.is_ascii_digit()))
+        .expect("code in body")
+        .trim_end_matches('.')
+        .to_string();
+
+    let wrong = if code == "000000" { "111111" } else { "000000" };
+    let (status, error) = send(
+        "POST",
+        format!("{employee_uri}/email-verification/confirm"),
+        Some(json!({ "code": wrong })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(
+        error["error"]
+            .as_str()
+            .unwrap()
+            .contains("4 attempt(s) left")
+    );
+
+    let (status, verified) = send(
+        "POST",
+        format!("{employee_uri}/email-verification/confirm"),
+        Some(json!({ "code": code })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(verified["email_verified_at"].is_string());
+
+    let (status, _) = send("POST", format!("{employee_uri}/email-verification"), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, updated) = send(
+        "PUT",
+        employee_uri.clone(),
+        Some(json!({ "email": "pat@example.org" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(updated["email"], "pat@example.org");
+    assert!(updated["email_verified_at"].is_null());
+
+    let (status, cleared) = send("PUT", employee_uri, Some(json!({ "email": null }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(cleared["email"].is_null());
+}
+
+#[tokio::test]
+async fn lists_organization_employees_with_filters_and_pages() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Roster Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Clerk").await;
+    let north = create_division(&app, organization_id, payroll_id, "North").await;
+    let south = create_division(&app, organization_id, payroll_id, "South").await;
+
+    for (division_id, id_number, last_name, status) in [
+        (north, "ROS-1", "Adams", "Active"),
+        (north, "ROS-2", "Baker", "Suspended"),
+        (south, "ROS-3", "Cohen", "Active"),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!(
+                        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+                    ))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "id_number": id_number,
+                            "last_name": last_name,
+                            "first_name": "Robin",
+                            "address": "3 Roster Road",
+                            "phone": "555-9090",
+                            "place_of_birth": "Listville",
+                            "date_of_birth": "1985-03-03",
+                            "nationality": "Exampleland",
+                            "marital_status": "Single",
+                            "gender": "F",
+                            "hire_date": "2020-06-01",
+                            "clasification": "Full-time",
+                            "job_id": job_id,
+                            "payment_instructions": [{
+                                "bank_id": bank_id,
+                                "account": format!("ACC-{id_number}"),
+                                "amount": { "percentage": 100.0 },
+                                "priority": 1
+                            }],
+                            "status": status,
+                            "hours": 40
+                        })
+                        .to_string(),
+                    ))
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let get = |query: &str| {
+        let app = app.clone();
+        let uri = format!("/organizations/{organization_id}/employees{query}");
+        async move {
+            let response = app
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .expect("response");
+            let status = response.status();
+            let body = read_json(response.into_body().collect().await.unwrap().to_bytes());
+            (status, body)
+        }
+    };
+    let last_names = |page: &Value| -> Vec<String> {
+        page["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|employee| employee["last_name"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let (status, page) = get("?per_page=2").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(page["total"], 3);
+    assert_eq!(last_names(&page), ["Adams", "Baker"]);
+
+    let (_, page) = get("?per_page=2&page=2").await;
+    assert_eq!(last_names(&page), ["Cohen"]);
+
+    let (_, page) = get("?status=Active").await;
+    assert_eq!(last_names(&page), ["Adams", "Cohen"]);
+
+    let (_, page) = get(&format!("?division_id={north}&q=bak")).await;
+    assert_eq!(page["total"], 1);
+    assert_eq!(last_names(&page), ["Baker"]);
+
+    let (status, _) = get("?per_page=500").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/organizations/{}/employees", Uuid::new_v4()))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn period_pay_converts_job_salary_by_pay_basis() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Rate Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Barista").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Cafe").await;
+    let payroll_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{payroll_uri}/jobs/{job_id}"),
+            json!({ "currency": "US1" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{payroll_uri}/jobs/{job_id}"),
+            json!({ "salary": 20.0, "pay_basis": "hourly", "currency": "usd" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let job = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(job["pay_basis"], "hourly");
+    assert_eq!(job["currency"], "USD");
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{payroll_uri}/divisions/{division_id}/employees"),
+            json!({
+                "id_number": "RATE-1",
+                "last_name": "Hourly",
+                "first_name": "Sam",
+                "address": "3 Bean Street",
+                "phone": "555-8000",
+                "place_of_birth": "Town",
+                "date_of_birth": "1991-05-05",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": "M",
+                "hire_date": "2023-01-01",
+                "clasification": "Part-time",
+                "job_id": job_id,
+                "payment_instructions": [{
+                    "bank_id": bank_id,
+                    "account": "ACCT-RATE-1",
+                    "amount": { "percentage": 100.0 },
+                    "priority": 1
+                }],
+                "status": "Active",
+                "hours": 30
+            }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let employee = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let employee_uri = format!(
+        "{payroll_uri}/divisions/{division_id}/employees/{}",
+        employee["id"].as_str().unwrap()
+    );
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{employee_uri}/period-pay?period=biweekly"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let pay = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(pay["period"], "biweekly");
+    assert_eq!(pay["weekly_hours"], 30.0);
+    assert_eq!(pay["currency"], "USD");
+    assert_eq!(pay["amount"], 1200.0);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{employee_uri}/period-pay"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    let pay = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(pay["period"], "monthly");
+    assert_eq!(pay["amount"], 2600.0);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{payroll_uri}/jobs/{job_id}"),
+            json!({ "salary": 52000.0, "pay_basis": "annual" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{employee_uri}/period-pay?period=weekly"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    let pay = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(pay["pay_basis"], "annual");
+    assert_eq!(pay["amount"], 1000.0);
+}
+
+#[tokio::test]
+async fn organization_settings_round_period_pay_and_supply_the_currency() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Settings Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Courier").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Depot").await;
+    let payroll_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("/organizations/{organization_id}/settings"),
+            json!({
+                "default_currency": "eur",
+                "working_days_per_week": 6,
+                "rounding": { "increment": 0.05, "mode": "up" }
+            }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{payroll_uri}/jobs/{job_id}"),
+            json!({ "salary": 101.01, "pay_basis": "daily" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("{payroll_uri}/divisions/{division_id}/employees"),
+            json!({
+                "id_number": "SET-1",
+                "last_name": "Daily",
+                "first_name": "Robin",
+                "address": "4 Depot Road",
+                "phone": "555-8100",
+                "place_of_birth": "Town",
+                "date_of_birth": "1990-02-02",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": "F",
+                "hire_date": "2023-01-01",
+                "clasification": "Full-time",
+                "job_id": job_id,
+                "payment_instructions": [{
+                    "bank_id": bank_id,
+                    "account": "ACCT-SET-1",
+                    "amount": { "percentage": 100.0 },
+                    "priority": 1
+                }],
+                "status": "Active",
+                "hours": 48
+            }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let employee = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let employee_uri = format!(
+        "{payroll_uri}/divisions/{division_id}/employees/{}",
+        employee["id"].as_str().unwrap()
+    );
+
+    // Six working days a week at 101.01 is 606.06, rounded up to the next 0.05.
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{employee_uri}/period-pay?period=weekly"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let pay = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(pay["currency"], "EUR");
+    assert_eq!(pay["amount"], 606.1);
+    assert_eq!(pay["employer"]["name"], "Employees Org");
+    assert_eq!(pay["employer"]["tax_id"], Value::Null);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            format!("/organizations/{organization_id}/legal-entities"),
+            json!({ "name": "Depot Co", "legal_name": "Depot Company Ltd", "tax_id": "99-1234567" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let legal_entity = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            payroll_uri.clone(),
+            json!({ "legal_entity_id": legal_entity["id"] }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{employee_uri}/period-pay?period=weekly"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    let pay = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(pay["employer"]["name"], "Depot Company Ltd");
+    assert_eq!(pay["employer"]["tax_id"], "99-1234567");
+    assert_eq!(pay["employer"]["legal_entity_id"], legal_entity["id"]);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{employee_uri}/expected-hours"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    let expectation = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(expectation["working_days_per_week"], 6);
+}
+
+#[tokio::test]
+async fn expiring_permits_are_validated_masked_and_include_lapsed_ones() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Permit Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Engineer").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Plant").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let today = chrono::Utc::now().date_naive();
+    let in_days = |days: i64| (today + chrono::Duration::days(days)).to_string();
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+    let employee = |id_number: &str, last_name: &str, permit: Value| {
+        json!({
+            "id_number": id_number,
+            "last_name": last_name,
+            "first_name": "Ola",
+            "address": "4 Border Way",
+            "phone": "555-4400",
+            "place_of_birth": "Abroad",
+            "date_of_birth": "1985-03-03",
+            "nationality": "Elsewhere",
+            "marital_status": "Married",
+            "gender": "M",
+            "hire_date": in_days(-400),
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": format!("ACCT-{id_number}"),
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40,
+            "work_permit": permit
+        })
+    };
+
+    for permit in [
+        json!({ "number": "WP-1", "country": "Germany", "expires_on": in_days(10) }),
+        json!({ "number": "WP#1", "country": "DE", "expires_on": in_days(10) }),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                employees_uri.clone(),
+                employee("PRM-0", "Invalid", permit),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    for (id_number, last_name, number, expires_in) in [
+        ("PRM-1", "Lapsed", "AB-123456", -3),
+        ("PRM-2", "Soon", "CD-654321", 12),
+        ("PRM-3", "Later", "EF-111111", 200),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                employees_uri.clone(),
+                employee(
+                    id_number,
+                    last_name,
+                    json!({ "number": number, "country": "no", "expires_on": in_days(expires_in) }),
+                ),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+        assert_eq!(
+            created["work_permit"]["number"],
+            format!("*****{}", &number[5..])
+        );
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/organizations/{organization_id}/expiring-permits?within_days=30"
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let permits = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let items: Vec<(&str, i64, bool, &str)> = permits
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| {
+            (
+                item["last_name"].as_str().unwrap(),
+                item["days_remaining"].as_i64().unwrap(),
+                item["expired"].as_bool().unwrap(),
+                item["work_permit"]["number"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        items,
+        [
+            ("Lapsed", -3, true, "*****3456"),
+            ("Soon", 12, false, "*****4321")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn lists_current_employees_of_a_job_page_by_page() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Roster Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Driver").await;
+    let other_job_id = create_job(&app, organization_id, payroll_id, "Loader").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Fleet").await;
+    let payroll_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+
+    for (id_number, last_name, status, job) in [
+        ("DRV-1", "Young", "Active", job_id),
+        ("DRV-2", "Adams", "OnLeave", job_id),
+        ("DRV-3", "Moore", "Terminated", job_id),
+        ("DRV-4", "Baker", "Active", other_job_id),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                format!("{payroll_uri}/divisions/{division_id}/employees"),
+                json!({
+                    "id_number": id_number,
+                    "last_name": last_name,
+                    "first_name": "Lee",
+                    "address": "6 Depot Road",
+                    "phone": "555-6600",
+                    "place_of_birth": "Town",
+                    "date_of_birth": "1984-06-06",
+                    "nationality": "Testland",
+                    "marital_status": "Single",
+                    "gender": "M",
+                    "hire_date": "2021-01-01",
+                    "clasification": "Full-time",
+                    "job_id": job,
+                    "payment_instructions": [{
+                        "bank_id": bank_id,
+                        "account": format!("ACCT-{id_number}"),
+                        "amount": { "percentage": 100.0 },
+                        "priority": 1
+                    }],
+                    "status": status,
+                    "hours": 40
+                }),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let jobs_uri = format!("{payroll_uri}/jobs/{job_id}/employees");
+    let mut names = Vec::new();
+    for page in [1, 2] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "GET",
+                format!("{jobs_uri}?page={page}&per_page=1"),
+                Value::Null,
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+        let listing = read_json(response.into_body().collect().await.unwrap().to_bytes());
+        assert_eq!(listing["total"], 2);
+        assert_eq!(listing["items"].as_array().unwrap().len(), 1);
+        names.push(
+            listing["items"][0]["last_name"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    assert_eq!(names, ["Adams", "Young"]);
+
+    let response = app
+        .clone()
+        .oneshot(send("GET", format!("{jobs_uri}?per_page=500"), Value::Null))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{payroll_uri}/jobs/{}/employees", uuid::Uuid::new_v4()),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn deleting_a_division_requires_reassigning_or_cascading_dependents() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Removal Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Porter").await;
+    let branch_id = create_division(&app, organization_id, payroll_id, "Branch").await;
+    let target_id = create_division(&app, organization_id, payroll_id, "Target").await;
+    let divisions_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions");
+
+    let send = |method: &str, uri: String, payload: Option<Value>| {
+        let request = Request::builder().method(method).uri(uri);
+        let request = match payload {
+            Some(payload) => request
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .expect("request");
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let body = if body.is_empty() {
+                Value::Null
+            } else {
+                read_json(body)
+            };
+            (status, body)
+        }
+    };
+    let employee = |id_number: &str| {
+        json!({
+            "id_number": id_number,
+            "last_name": "Moved",
+            "first_name": "Robin",
+            "address": "7 Branch St",
+            "phone": "555-7000",
+            "place_of_birth": "Town",
+            "date_of_birth": "1990-03-03",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2021-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": format!("ACCT-{id_number}"),
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        })
+    };
+
+    let (status, child) = send(
+        "POST",
+        divisions_uri.clone(),
+        Some(json!({
+            "name": "Child",
+            "description": "Child division",
+            "budget_code": "BC-Child",
+            "parent_division_id": branch_id
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let child_id = child["id"].as_str().unwrap().to_string();
+    for (division_id, id_number) in [
+        (branch_id.to_string(), "DEL-1"),
+        (branch_id.to_string(), "DEL-2"),
+        (child_id.clone(), "DEL-3"),
+    ] {
+        let (status, _) = send(
+            "POST",
+            format!("{divisions_uri}/{division_id}/employees"),
+            Some(employee(id_number)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+    let (status, _) = send(
+        "POST",
+        format!("{divisions_uri}/{branch_id}/positions"),
+        Some(json!({"title": "Night porter", "job_id": job_id, "budget": 900.0})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let branch_uri = format!("{divisions_uri}/{branch_id}");
+    let (status, _) = send("DELETE", branch_uri.clone(), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send(
+        "DELETE",
+        format!("{branch_uri}?reassign_to={child_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send(
+        "DELETE",
+        format!("{branch_uri}?reassign_to={target_id}&cascade=true"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(
+        "PUT",
+        format!("{divisions_uri}/{target_id}"),
+        Some(json!({"headcount_budget": 1})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        "DELETE",
+        format!("{branch_uri}?reassign_to={target_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send(
+        "PUT",
+        format!("{divisions_uri}/{target_id}"),
+        Some(json!({"headcount_budget": null})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(
+        "DELETE",
+        format!("{branch_uri}?reassign_to={target_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, moved) = send(
+        "GET",
+        format!("{divisions_uri}/{target_id}/employees"),
+        None,
+    )
+    .await;
+    assert_eq!(moved.as_array().unwrap().len(), 2);
+    let (_, positions) = send(
+        "GET",
+        format!("{divisions_uri}/{target_id}/positions"),
+        None,
+    )
+    .await;
+    assert_eq!(positions.as_array().unwrap().len(), 1);
+    let (_, child) = send("GET", format!("{divisions_uri}/{child_id}"), None).await;
+    assert_eq!(child["parent_division_id"], target_id.to_string());
+
+    let (status, _) = send(
+        "DELETE",
+        format!("{divisions_uri}/{target_id}?cascade=true"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send("GET", format!("{divisions_uri}/{child_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (_, remaining) = send(
+        "GET",
+        format!("/employees?organization_id={organization_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(remaining.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn lists_employees_of_a_division_and_its_descendants() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Tree Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Clerk").await;
+    let root_id = create_division(&app, organization_id, payroll_id, "Root").await;
+    let sibling_id = create_division(&app, organization_id, payroll_id, "Sibling").await;
+    let divisions_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions");
+
+    let send = |method: &str, uri: String, payload: Option<Value>| {
+        let request = Request::builder().method(method).uri(uri);
+        let request = match payload {
+            Some(payload) => request
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .expect("request");
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (status, read_json(body))
+        }
+    };
+
+    let mut parent_id = root_id.to_string();
+    let mut division_ids = vec![root_id.to_string()];
+    for name in ["Child", "Grandchild"] {
+        let (status, division) = send(
+            "POST",
+            divisions_uri.clone(),
+            Some(json!({
+                "name": name,
+                "description": format!("{name} division"),
+                "budget_code": format!("BC-{name}"),
+                "parent_division_id": parent_id
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        parent_id = division["id"].as_str().unwrap().to_string();
+        division_ids.push(parent_id.clone());
+    }
+    division_ids.push(sibling_id.to_string());
+
+    for (index, division_id) in division_ids.iter().enumerate() {
+        let (status, _) = send(
+            "POST",
+            format!("{divisions_uri}/{division_id}/employees"),
+            Some(json!({
+                "id_number": format!("TREE-{index}"),
+                "last_name": format!("Member{index}"),
+                "first_name": "Sam",
+                "address": "1 Tree Rd",
+                "phone": "555-8000",
+                "place_of_birth": "Town",
+                "date_of_birth": "1991-04-04",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": "M",
+                "hire_date": "2022-01-01",
+                "clasification": "Full-time",
+                "job_id": job_id,
+                "payment_instructions": [{
+                    "bank_id": bank_id,
+                    "account": format!("ACCT-TREE-{index}"),
+                    "amount": { "percentage": 100.0 },
+                    "priority": 1
+                }],
+                "status": "Active",
+                "hours": 40
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    let (status, direct) = send("GET", format!("{divisions_uri}/{root_id}/employees"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let direct = direct.as_array().unwrap();
+    assert_eq!(direct.len(), 1);
+    assert_eq!(direct[0]["id_number"], "TREE-0");
+
+    let (status, recursive) = send(
+        "GET",
+        format!("{divisions_uri}/{root_id}/employees?include_descendants=true"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let id_numbers: Vec<&str> = recursive
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|employee| employee["id_number"].as_str().unwrap())
+        .collect();
+    assert_eq!(id_numbers, vec!["TREE-0", "TREE-1", "TREE-2"]);
+
+    let (status, leaf) = send(
+        "GET",
+        format!(
+            "{divisions_uri}/{}/employees?include_descendants=true",
+            division_ids[2]
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(leaf.as_array().unwrap().len(), 1);
+
+    let (status, _) = send(
+        "GET",
+        format!(
+            "{divisions_uri}/{}/employees?include_descendants=true",
+            Uuid::new_v4()
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn unverified_accounts_are_held_out_of_payment_batches() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Courier").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Dispatch").await;
+    let bank_id = create_bank(&app, organization_id, "Verify Bank").await;
+    let payroll_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+    let employees_uri = format!("{payroll_uri}/divisions/{division_id}/employees");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        let app = app.clone();
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request");
+        async move {
+            let response = app.oneshot(request).await.expect("response");
+            let status = response.status();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            (status, read_json(bytes))
+        }
+    };
+    let instructions = |savings: &str| {
+        json!([
+            {
+                "bank_id": bank_id,
+                "account": "MAIN-1",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            },
+            {
+                "bank_id": bank_id,
+                "account": savings,
+                "amount": { "fixed": 100.0 },
+                "priority": 0,
+                "verification": { "status": "verified" }
+            }
+        ])
+    };
+
+    let (status, employee) = send(
+        "POST",
+        employees_uri.clone(),
+        json!({
+            "id_number": "POST-1",
+            "last_name": "Rider",
+            "first_name": "Robin",
+            "address": "4 Parcel Rd",
+            "phone": "555-4040",
+            "place_of_birth": "Town",
+            "date_of_birth": "1992-04-04",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2023-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": instructions("SAVE-1"),
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    // Clients cannot mark their own accounts verified.
+    assert_eq!(
+        employee["payment_instructions"][0]["verification"]["status"],
+        "unverified"
+    );
+    let employee_uri = format!("{employees_uri}/{}", employee["id"].as_str().unwrap());
+
+    let (status, _) = send(
+        "POST",
+        format!("{employee_uri}/payment-instructions/0/micro-deposits"),
+        json!({"outcome": "confirmed"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, pending) = send(
+        "POST",
+        format!("{employee_uri}/payment-instructions/0/micro-deposits"),
+        json!({"outcome": "sent", "reference": "TRACE-77"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(pending["verification"]["status"], "pending");
+    let (status, verified) = send(
+        "POST",
+        format!("{employee_uri}/payment-instructions/0/micro-deposits"),
+        json!({"outcome": "confirmed"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified["verification"]["status"], "verified");
+    assert_eq!(verified["verification"]["method"], "micro_deposit");
+    assert_eq!(verified["verification"]["reference"], "TRACE-77");
+
+    let (status, _) = send(
+        "PUT",
+        payroll_uri.clone(),
+        json!({"blocks_unverified_accounts": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, report) = send(
+        "GET",
+        format!("{payroll_uri}/unverified-accounts"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["blocks_unverified_accounts"], true);
+    assert_eq!(report["accounts"].as_array().unwrap().len(), 1);
+    assert_eq!(report["accounts"][0]["account"], "MAIN-1");
+
+    let (status, _) = send(
+        "POST",
+        format!("{employee_uri}/payment-instructions/9/document-verification"),
+        json!({"reference": "LETTER-1", "accepted": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send(
+        "POST",
+        format!("{employee_uri}/payment-instructions/1/document-verification"),
+        json!({"reference": "LETTER-1", "accepted": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, report) = send(
+        "GET",
+        format!("{payroll_uri}/unverified-accounts"),
+        Value::Null,
+    )
+    .await;
+    assert!(report["accounts"].as_array().unwrap().is_empty());
+
+    // Moving pay to a new account starts its verification over; the unchanged one keeps it.
+    let (status, updated) = send(
+        "PUT",
+        employee_uri.clone(),
+        json!({"payment_instructions": instructions("SAVE-2")}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        updated["payment_instructions"][0]["verification"]["status"],
+        "unverified"
+    );
+    assert_eq!(
+        updated["payment_instructions"][1]["verification"]["status"],
+        "verified"
+    );
+
+    let instructions: Vec<PaymentInstruction> =
+        serde_json::from_value(updated["payment_instructions"].clone()).unwrap();
+    assert!(PaymentInstruction::allocate_for_batch(&instructions, 900.0, true).is_none());
+    let amounts: Vec<f64> = PaymentInstruction::allocate_for_batch(&instructions, 900.0, false)
+        .unwrap()
+        .into_iter()
+        .map(|allocation| allocation.amount)
+        .collect();
+    assert_eq!(amounts, [100.0, 800.0]);
+}
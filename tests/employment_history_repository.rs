@@ -0,0 +1,81 @@
+use chrono::{NaiveDate, Utc};
+use nomina::{
+    domain::employment_history::{
+        DivisionTransfer, EmploymentEvent, EmploymentEventKind, JobChange, SalaryAdjustmentChange,
+    },
+    infrastructure::{
+        employment_history_repository::SurrealEmploymentHistoryRepository,
+        surreal::{self, SurrealConfig},
+    },
+    services::employment_history::EmploymentHistoryRepository,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn event_details_round_trip_through_the_database() {
+    let database = surreal::connect(&SurrealConfig::embedded())
+        .await
+        .expect("embedded database");
+    let repository = SurrealEmploymentHistoryRepository::new(database);
+    let employee_id = Uuid::new_v4();
+    let effective_date = NaiveDate::from_ymd_opt(2025, 3, 1).expect("date");
+    let event = |kind| {
+        EmploymentEvent::new(
+            Uuid::new_v4(),
+            employee_id,
+            kind,
+            effective_date,
+            Utc::now(),
+        )
+    };
+
+    let mut job_change = event(EmploymentEventKind::JobChange);
+    job_change.job_change = Some(JobChange {
+        from_job_id: Uuid::new_v4(),
+        to_job_id: Uuid::new_v4(),
+        from_salary: 1000.0,
+        to_salary: 1200.0,
+    });
+    let mut transfer = event(EmploymentEventKind::Transfer);
+    transfer.transfer = Some(DivisionTransfer {
+        from_division_id: Uuid::new_v4(),
+        to_division_id: Uuid::new_v4(),
+    });
+    let mut salary_adjustment = event(EmploymentEventKind::SalaryAdjustment);
+    salary_adjustment.salary_adjustment = Some(SalaryAdjustmentChange {
+        batch_id: Uuid::new_v4(),
+        job_id: Uuid::new_v4(),
+        from_salary: 1200.0,
+        to_salary: 1260.0,
+    });
+
+    let mut expected = vec![job_change, transfer, salary_adjustment];
+    for event in &expected {
+        let stored = repository.insert(event.clone()).await.expect("insert");
+        assert_eq!(stored.job_change, event.job_change);
+        assert_eq!(stored.transfer, event.transfer);
+        assert_eq!(stored.salary_adjustment, event.salary_adjustment);
+    }
+
+    let mut fetched = repository
+        .fetch_by_employee(employee_id)
+        .await
+        .expect("fetch by employee");
+    fetched.sort_by_key(|event| event.id);
+    expected.sort_by_key(|event| event.id);
+    let details = |events: &[EmploymentEvent]| {
+        events
+            .iter()
+            .map(|event| {
+                (
+                    event.id,
+                    event.kind,
+                    event.job_change,
+                    event.transfer,
+                    event.salary_adjustment,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(details(&fetched), details(&expected));
+}
@@ -0,0 +1,297 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn create_organization(app: &Router) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Employees Org"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_payroll(app: &Router, organization_id: Uuid) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/payrolls"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": "Employees Payroll",
+                        "description": "Payroll for employees"
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_bank(app: &Router, organization_id: Uuid, name: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/banks"))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": name}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_job(app: &Router, organization_id: Uuid, payroll_id: Uuid, title: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/jobs"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "job_title": title,
+                        "salary": 50000.0
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_division(
+    app: &Router,
+    organization_id: Uuid,
+    payroll_id: Uuid,
+    name: &str,
+) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/divisions"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": name,
+                        "description": format!("{name} division"),
+                        "budget_code": format!("BC-{name}")
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+#[tokio::test]
+async fn expiring_soon_lists_contracts_probations_and_permits() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Term Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Contractor").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Projects").await;
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let today = chrono::Utc::now().date_naive();
+    let in_days = |days: i64| (today + chrono::Duration::days(days)).to_string();
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+    let employee = |id_number: &str, last_name: &str, status: &str, extra: Value| {
+        let mut body = json!({
+            "id_number": id_number,
+            "last_name": last_name,
+            "first_name": "Kim",
+            "address": "8 Term Road",
+            "phone": "555-3300",
+            "place_of_birth": "Town",
+            "date_of_birth": "1988-08-08",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": in_days(-200),
+            "clasification": "Fixed-term",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": format!("ACCT-{id_number}"),
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": status,
+            "hours": 40
+        });
+        body.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        body
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            employees_uri.clone(),
+            employee(
+                "EXP-0",
+                "Early",
+                "Active",
+                json!({ "probation_end_date": in_days(-300) }),
+            ),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    for (id_number, last_name, status, extra) in [
+        (
+            "EXP-1",
+            "Abbott",
+            "Active",
+            json!({
+                "contract_end_date": in_days(20),
+                "work_permit": { "number": " WP-77 ", "country": "de", "expires_on": in_days(5) }
+            }),
+        ),
+        (
+            "EXP-2",
+            "Baker",
+            "Onboarding",
+            json!({ "probation_end_date": in_days(10), "contract_end_date": in_days(90) }),
+        ),
+        (
+            "EXP-3",
+            "Carter",
+            "Terminated",
+            json!({ "contract_end_date": in_days(3) }),
+        ),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                employees_uri.clone(),
+                employee(id_number, last_name, status, extra),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+        if id_number == "EXP-1" {
+            assert_eq!(created["work_permit"]["number"], "***77");
+            assert_eq!(created["work_permit"]["country"], "DE");
+        }
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/organizations/{organization_id}/expiring-soon?within_days=30"
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let expiring = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let items: Vec<(&str, &str, i64)> = expiring
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| {
+            (
+                item["last_name"].as_str().unwrap(),
+                item["kind"].as_str().unwrap(),
+                item["days_remaining"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        items,
+        [
+            ("Abbott", "WorkPermit", 5),
+            ("Baker", "ProbationEnd", 10),
+            ("Abbott", "ContractEnd", 20)
+        ]
+    );
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/organizations/{organization_id}/expiring-soon?within_days=400"
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
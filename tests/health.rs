@@ -2,12 +2,40 @@
 mod support;
 
 use axum::{
+    Router,
     body::Body,
     http::{Request, StatusCode},
 };
 use http_body_util::BodyExt;
+use serde_json::{Value, json};
 use tower::ServiceExt;
 
+async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json");
+    let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+    let response = app
+        .clone()
+        .oneshot(request.body(body).expect("request"))
+        .await
+        .expect("response");
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+/// Creates a record by posting `body` to `uri` and returns its id.
+async fn create(app: &Router, uri: &str, body: Value) -> String {
+    let (status, created) = send(app, "POST", uri, Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED, "{created}");
+    created["id"].as_str().expect("id").to_string()
+}
+
 #[tokio::test]
 async fn health_endpoint_returns_package_metadata() {
     let app = support::test_router();
@@ -31,3 +59,147 @@ async fn health_endpoint_returns_package_metadata() {
     assert_eq!(body["authors"], env!("CARGO_PKG_AUTHORS"));
     assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
 }
+
+#[tokio::test]
+async fn readiness_probe_reports_each_dependency() {
+    let (app, database_probe) = support::test_router_with_database_probe();
+    let ready = || async {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/health/ready")
+                    .body(Body::empty())
+                    .expect("request body"),
+            )
+            .await
+            .expect("response");
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).expect("valid json");
+        (status, body)
+    };
+
+    let (status, body) = ready().await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "up");
+    assert_eq!(body["dependencies"][0]["name"], "database");
+    assert_eq!(body["dependencies"][0]["status"], "up");
+    assert!(body["dependencies"][0]["latency_ms"].is_u64());
+    assert!(body["dependencies"][0]["error"].is_null());
+
+    database_probe.set_failing(true);
+    let (status, body) = ready().await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["status"], "down");
+    assert_eq!(body["dependencies"][0]["status"], "down");
+    assert!(
+        body["dependencies"][0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("database unreachable")
+    );
+
+    database_probe.set_failing(false);
+    let (status, _) = ready().await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn email_delivery_is_reported_on_integration_health() {
+    let (app, outbox) = support::test_router_with_outbox();
+    let organization_id = create(&app, "/organizations", json!({"name": "Relay Org"})).await;
+    let organization_uri = format!("/organizations/{organization_id}");
+    let payroll_id = create(
+        &app,
+        &format!("{organization_uri}/payrolls"),
+        json!({"name": "Relay Payroll", "description": "Payroll for relay staff"}),
+    )
+    .await;
+    let payroll_uri = format!("{organization_uri}/payrolls/{payroll_id}");
+    let bank_id = create(
+        &app,
+        &format!("{organization_uri}/banks"),
+        json!({"name": "Relay Bank"}),
+    )
+    .await;
+    let job_id = create(
+        &app,
+        &format!("{payroll_uri}/jobs"),
+        json!({"job_title": "Clerk", "salary": 50000.0}),
+    )
+    .await;
+    let division_id = create(
+        &app,
+        &format!("{payroll_uri}/divisions"),
+        json!({"name": "Post", "description": "Post division", "budget_code": "BC-Post"}),
+    )
+    .await;
+    let employee_id = create(
+        &app,
+        &format!("{payroll_uri}/divisions/{division_id}/employees"),
+        json!({
+            "id_number": "RELAY-1",
+            "last_name": "Relay",
+            "first_name": "Kim",
+            "email": "kim.relay@example.com",
+            "address": "9 Wire Way",
+            "phone": "555-9090",
+            "place_of_birth": "Town",
+            "date_of_birth": "1991-03-03",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2022-01-10",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": "ACCT-RELAY-1",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    let verification_uri =
+        format!("{payroll_uri}/divisions/{division_id}/employees/{employee_id}/email-verification");
+
+    let (status, report) = send(&app, "GET", "/health/integrations", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["integrations"][0]["name"], "email");
+    assert_eq!(report["integrations"][0]["status"], "unknown");
+
+    outbox.set_failing(true);
+    let (status, _) = send(&app, "POST", &verification_uri, None).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+    let (status, report) = send(&app, "GET", "/health/integrations", None).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(report["status"], "failing");
+    let email = &report["integrations"][0];
+    assert_eq!(email["status"], "failing");
+    assert!(
+        email["last_error"]
+            .as_str()
+            .unwrap()
+            .contains("unreachable")
+    );
+    assert_eq!(email["consecutive_failures"], 1);
+    assert!(email["last_success_at"].is_null());
+
+    outbox.set_failing(false);
+    let (status, _) = send(&app, "POST", &verification_uri, None).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+
+    let (status, report) = send(&app, "GET", "/health/integrations", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["status"], "healthy");
+    let email = &report["integrations"][0];
+    assert_eq!(email["status"], "healthy");
+    assert_eq!(email["consecutive_failures"], 0);
+    assert!(email["last_success_at"].is_string());
+    assert!(email["last_failure_at"].is_string());
+}
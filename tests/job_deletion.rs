@@ -0,0 +1,291 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn create_organization(app: &Router) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Employees Org"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_payroll(app: &Router, organization_id: Uuid) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/payrolls"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": "Employees Payroll",
+                        "description": "Payroll for employees"
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_bank(app: &Router, organization_id: Uuid, name: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/banks"))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": name}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_job(app: &Router, organization_id: Uuid, payroll_id: Uuid, title: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/jobs"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "job_title": title,
+                        "salary": 50000.0
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_division(
+    app: &Router,
+    organization_id: Uuid,
+    payroll_id: Uuid,
+    name: &str,
+) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/divisions"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": name,
+                        "description": format!("{name} division"),
+                        "budget_code": format!("BC-{name}")
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+#[tokio::test]
+async fn deleting_a_job_requires_reassigning_its_employees() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Reassign Bank").await;
+    let old_job_id = create_job(&app, organization_id, payroll_id, "Typist").await;
+    let new_job_id = create_job(&app, organization_id, payroll_id, "Clerk").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Office").await;
+    let payroll_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+    let employees_uri = format!("{payroll_uri}/divisions/{division_id}/employees");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+
+    let mut employee_ids = Vec::new();
+    for id_number in ["TYP-1", "TYP-2"] {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                employees_uri.clone(),
+                json!({
+                    "id_number": id_number,
+                    "last_name": "Keys",
+                    "first_name": "Pat",
+                    "address": "2 Desk Row",
+                    "phone": "555-9100",
+                    "place_of_birth": "Town",
+                    "date_of_birth": "1980-02-02",
+                    "nationality": "Testland",
+                    "marital_status": "Single",
+                    "gender": "F",
+                    "hire_date": "2020-01-01",
+                    "clasification": "Full-time",
+                    "job_id": old_job_id,
+                    "payment_instructions": [{
+                        "bank_id": bank_id,
+                        "account": format!("ACCT-{id_number}"),
+                        "amount": { "percentage": 100.0 },
+                        "priority": 1
+                    }],
+                    "status": "Active",
+                    "hours": 40
+                }),
+            ))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+        employee_ids.push(created["id"].as_str().unwrap().to_string());
+    }
+
+    let old_job_uri = format!("{payroll_uri}/jobs/{old_job_id}");
+    let response = app
+        .clone()
+        .oneshot(send("DELETE", old_job_uri.clone(), Value::Null))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let error = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(error["error"].as_str().unwrap().contains("2 employee(s)"));
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "DELETE",
+            format!("{old_job_uri}?reassign_to={old_job_id}"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{payroll_uri}/jobs/{new_job_id}"),
+            json!({ "budgeted_headcount": 1, "headcount_policy": "enforce" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = app
+        .clone()
+        .oneshot(send(
+            "DELETE",
+            format!("{old_job_uri}?reassign_to={new_job_id}"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{payroll_uri}/jobs/{new_job_id}"),
+            json!({ "budgeted_headcount": null }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = app
+        .clone()
+        .oneshot(send(
+            "DELETE",
+            format!("{old_job_uri}?reassign_to={new_job_id}"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    for employee_id in &employee_ids {
+        let response = app
+            .clone()
+            .oneshot(send(
+                "GET",
+                format!("{employees_uri}/{employee_id}"),
+                Value::Null,
+            ))
+            .await
+            .expect("response");
+        let employee = read_json(response.into_body().collect().await.unwrap().to_bytes());
+        assert_eq!(employee["job_id"], new_job_id.to_string());
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "GET",
+                format!("{employees_uri}/{employee_id}/employment-history"),
+                Value::Null,
+            ))
+            .await
+            .expect("response");
+        let history = read_json(response.into_body().collect().await.unwrap().to_bytes());
+        assert!(history.as_array().unwrap().iter().any(|event| {
+            event["job_change"]["from_job_id"] == old_job_id.to_string()
+                && event["job_change"]["to_job_id"] == new_job_id.to_string()
+        }));
+    }
+}
@@ -0,0 +1,243 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn create_organization(app: &Router) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Employees Org"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_payroll(app: &Router, organization_id: Uuid) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/payrolls"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": "Employees Payroll",
+                        "description": "Payroll for employees"
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_bank(app: &Router, organization_id: Uuid, name: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/banks"))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": name}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_job(app: &Router, organization_id: Uuid, payroll_id: Uuid, title: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/jobs"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "job_title": title,
+                        "salary": 50000.0
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_division(
+    app: &Router,
+    organization_id: Uuid,
+    payroll_id: Uuid,
+    name: &str,
+) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/divisions"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": name,
+                        "description": format!("{name} division"),
+                        "budget_code": format!("BC-{name}")
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+#[tokio::test]
+async fn job_headcount_budget_warns_or_enforces_by_policy() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Seats Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Cashier").await;
+    let division_id = create_division(&app, organization_id, payroll_id, "Store").await;
+    let payroll_uri = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+    let employees_uri = format!("{payroll_uri}/divisions/{division_id}/employees");
+
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+    let employee = |id_number: &str| {
+        json!({
+            "id_number": id_number,
+            "last_name": "Seated",
+            "first_name": "Alex",
+            "address": "9 Till Lane",
+            "phone": "555-7000",
+            "place_of_birth": "Town",
+            "date_of_birth": "1990-04-04",
+            "nationality": "Testland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2022-01-01",
+            "clasification": "Full-time",
+            "job_id": job_id,
+            "payment_instructions": [{
+                "bank_id": bank_id,
+                "account": format!("ACCT-{id_number}"),
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        })
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{payroll_uri}/jobs/{job_id}"),
+            json!({ "budgeted_headcount": 1 }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let job = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(job["headcount_policy"], "warn");
+
+    for id_number in ["SEAT-1", "SEAT-2"] {
+        let response = app
+            .clone()
+            .oneshot(send("POST", employees_uri.clone(), employee(id_number)))
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("{payroll_uri}/job-headcount"),
+            Value::Null,
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let headcount = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(headcount[0]["job_id"], job_id.to_string());
+    assert_eq!(headcount[0]["filled"], 2);
+    assert_eq!(headcount[0]["available"], 0);
+    assert_eq!(headcount[0]["over_budget"], true);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("{payroll_uri}/jobs/{job_id}"),
+            json!({ "headcount_policy": "enforce" }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(send("POST", employees_uri.clone(), employee("SEAT-3")))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let error = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(error.to_string().contains("2 of 1 in use"));
+}
@@ -61,6 +61,30 @@ fn read_json(body: Bytes) -> Value {
     serde_json::from_slice(&body).expect("json")
 }
 
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
 #[tokio::test]
 async fn can_create_and_list_jobs() {
     let app = support::test_router();
@@ -231,3 +255,189 @@ async fn can_update_and_delete_job() {
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
+
+#[tokio::test]
+async fn grades_bound_job_salaries_to_their_band() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let grades = format!("/organizations/{organization_id}/payrolls/{payroll_id}/job-grades");
+    let jobs = format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs");
+
+    let (status, grade) = send_json(
+        &app,
+        "POST",
+        &grades,
+        json!({"code": "P2", "band": "Professional", "level": 2, "salary_min": 60_000.0, "salary_max": 80_000.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let grade_id = grade["id"].as_str().unwrap().to_string();
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &grades,
+        json!({"code": "p2", "band": "Professional", "level": 3, "salary_min": 70_000.0, "salary_max": 90_000.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &grades,
+        json!({"code": "P1", "band": "Professional", "level": 1, "salary_min": 50_000.0, "salary_max": 40_000.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &jobs,
+        json!({"job_title": "Analyst", "salary": 95_000.0, "grade_id": grade_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, job) = send_json(
+        &app,
+        "POST",
+        &jobs,
+        json!({"job_title": "Analyst", "salary": 75_000.0, "grade_id": grade_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(job["grade_id"], grade_id.as_str());
+    let job_uri = format!("{jobs}/{}", job["id"].as_str().unwrap());
+
+    let (status, _) = send_json(&app, "PUT", &job_uri, json!({"salary": 85_000.0})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{grades}/{grade_id}"),
+        json!({"salary_max": 70_000.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(&app, "DELETE", &format!("{grades}/{grade_id}"), json!(null)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, job) = send_json(&app, "PUT", &job_uri, json!({"grade_id": null})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(job["grade_id"], Value::Null);
+    let (status, _) = send_json(&app, "DELETE", &format!("{grades}/{grade_id}"), json!(null)).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn salary_ranges_are_enforced_and_reported() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let jobs = format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs");
+
+    let (status, job) = send_json(
+        &app,
+        "POST",
+        &jobs,
+        json!({"job_title": "Engineer", "salary": 70_000.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(job["salary_range_status"], "unset");
+    let job_uri = format!("{jobs}/{}", job["id"].as_str().unwrap());
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &jobs,
+        json!({"job_title": "Lead", "salary": 50_000.0, "salary_min": 60_000.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &job_uri,
+        json!({"salary_min": 80_000.0, "salary_max": 60_000.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, job) = send_json(
+        &app,
+        "PUT",
+        &job_uri,
+        json!({"salary_min": 60_000.0, "salary_max": 90_000.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(job["salary_min"], 60000.0);
+    assert_eq!(job["salary_range_status"], "within");
+
+    let (status, _) = send_json(&app, "PUT", &job_uri, json!({"salary": 95_000.0})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, job) = send_json(
+        &app,
+        "PUT",
+        &job_uri,
+        json!({"salary": 95_000.0, "salary_max": null}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(job["salary_max"], Value::Null);
+    assert_eq!(job["salary_range_status"], "within");
+}
+
+#[tokio::test]
+async fn jobs_carry_descriptions_and_requirements() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let jobs = format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs");
+
+    let (status, job) = send_json(
+        &app,
+        "POST",
+        &jobs,
+        json!({
+            "job_title": "Accountant",
+            "salary": 55_000.0,
+            "description": "  Keeps the books.  ",
+            "responsibilities": [" Month-end close ", "Reconciliations"],
+            "qualifications": ["CPA"]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(job["description"], "Keeps the books.");
+    assert_eq!(
+        job["responsibilities"],
+        json!(["Month-end close", "Reconciliations"])
+    );
+    assert_eq!(job["qualifications"], json!(["CPA"]));
+    let job_uri = format!("{jobs}/{}", job["id"].as_str().unwrap());
+
+    let (status, _) = send_json(&app, "PUT", &job_uri, json!({"qualifications": ["  "]})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &job_uri,
+        json!({"description": "x".repeat(4001)}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, job) = send_json(
+        &app,
+        "PUT",
+        &job_uri,
+        json!({"description": null, "responsibilities": []}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(job["description"], Value::Null);
+    assert_eq!(job["responsibilities"], json!([]));
+    assert_eq!(job["qualifications"], json!(["CPA"]));
+}
@@ -0,0 +1,887 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use chrono::{Datelike, Days, NaiveDate, Utc};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates an employee without a schedule, reporting to a supervisor, and returns the employee's
+/// URI and the supervisor's id.
+async fn setup(app: &Router) -> (String, String) {
+    let (_, organization) =
+        send_json(app, "POST", "/organizations", json!({"name": "Leave Org"})).await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Leave Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let (status, supervisor) = send_json(
+        app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "LV-0",
+            "last_name": "Lead",
+            "first_name": "Sam",
+            "address": "5 Beach Rd",
+            "phone": "555-0405",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1985-05-05",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "M",
+            "hire_date": "2018-01-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-LV0",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let supervisor_id = supervisor["id"].as_str().unwrap().to_string();
+
+    let (status, employee) = send_json(
+        app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "LV-1",
+            "last_name": "Away",
+            "first_name": "Robin",
+            "email": "robin.away@example.com",
+            "address": "4 Beach Rd",
+            "phone": "555-0404",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1990-04-04",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2020-01-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-LV",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40,
+            "supervisor_id": supervisor_id
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    (
+        format!("{employees_uri}/{}", employee["id"].as_str().unwrap()),
+        supervisor_id,
+    )
+}
+
+#[tokio::test]
+async fn approving_leave_deducts_balance_once() {
+    let app = support::test_router();
+    let (employee_uri, manager_id) = setup(&app).await;
+
+    let (status, balance) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/vacation"),
+        json!({"available_days": 5.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(balance["available_days"], 5.0);
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/unpaid"),
+        json!({"available_days": 5.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Monday to Sunday holds five working days for an unscheduled employee.
+    let (status, request) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({
+            "leave_type": "vacation",
+            "start_date": "2024-03-04",
+            "end_date": "2024-03-10",
+            "reason": "Family trip"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(request["days"], 5.0);
+    assert_eq!(request["status"], "pending");
+    let request_id = request["id"].as_str().unwrap();
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "unpaid", "start_date": "2024-03-08", "end_date": "2024-03-12"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "sick", "start_date": "2024-04-01", "end_date": "2024-04-01"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let approve_uri = format!("{employee_uri}/leave-requests/{request_id}/approve");
+    let employee_id = employee_uri.rsplit('/').next().unwrap();
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &approve_uri,
+        json!({"approver_id": employee_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, approved) = send_json(
+        &app,
+        "POST",
+        &approve_uri,
+        json!({"approver_id": manager_id, "note": "Enjoy"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "approved");
+    assert_eq!(approved["decided_by"], manager_id.as_str());
+    assert_eq!(approved["decision_note"], "Enjoy");
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests/{request_id}/approve"),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, balances) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-balances"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        balances,
+        json!([
+            {
+                "employee_id": approved["employee_id"],
+                "leave_type": "vacation",
+                "available_days": 0.0,
+                "leave_year": Utc::now().year(),
+                "carried_over_days": 0.0,
+                "carryover_expires_on": null,
+                "expiry_notified_at": null
+            },
+            {
+                "employee_id": approved["employee_id"],
+                "leave_type": "sick",
+                "available_days": 0.0,
+                "leave_year": null,
+                "carried_over_days": 0.0,
+                "carryover_expires_on": null,
+                "expiry_notified_at": null
+            }
+        ])
+    );
+
+    let (status, unpaid) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "unpaid", "start_date": "2024-05-06", "end_date": "2024-05-07"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, rejected) = send_json(
+        &app,
+        "POST",
+        &format!(
+            "{employee_uri}/leave-requests/{}/reject",
+            unpaid["id"].as_str().unwrap()
+        ),
+        json!({"approver_id": manager_id, "note": "Busy season"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(rejected["status"], "rejected");
+
+    let (status, requests) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-requests"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(requests.as_array().unwrap().len(), 2);
+    assert_eq!(requests[0]["leave_type"], "unpaid");
+}
+
+#[tokio::test]
+async fn sick_pay_follows_the_payroll_policy_tiers() {
+    let app = support::test_router();
+    let (employee_uri, manager_id) = setup(&app).await;
+    let (payroll_uri, _) = employee_uri.split_once("/divisions/").unwrap();
+    let policy_uri = format!("{payroll_uri}/leave-pay-policies/sick");
+
+    let (status, policy) = send_json(&app, "GET", &policy_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        policy["tiers"],
+        json!([{"from_day": 1, "pay_percentage": 100.0}])
+    );
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{payroll_uri}/leave-pay-policies/vacation"),
+        json!({"tiers": [{"from_day": 1, "pay_percentage": 100.0}]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    for tiers in [
+        json!([{"from_day": 2, "pay_percentage": 100.0}]),
+        json!([{"from_day": 1, "pay_percentage": 100.0}, {"from_day": 1, "pay_percentage": 70.0}]),
+        json!([{"from_day": 1, "pay_percentage": 120.0}]),
+    ] {
+        let (status, _) = send_json(&app, "PUT", &policy_uri, json!({"tiers": tiers})).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{tiers}");
+    }
+
+    let (status, policy) = send_json(
+        &app,
+        "PUT",
+        &policy_uri,
+        json!({"tiers": [
+            {"from_day": 4, "pay_percentage": 70.0},
+            {"from_day": 1, "pay_percentage": 100.0}
+        ]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(policy["tiers"][0]["from_day"], 1);
+    assert_eq!(policy["tiers"][1]["pay_percentage"], 70.0);
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/sick"),
+        json!({"available_days": 10.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({
+            "leave_type": "unpaid",
+            "start_date": "2024-03-04",
+            "end_date": "2024-03-04",
+            "certificate_reference": "MED-0"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, request) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({
+            "leave_type": "sick",
+            "start_date": "2024-03-04",
+            "end_date": "2024-03-08",
+            "certificate_reference": " MED-1 "
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(request["certificate_reference"], "MED-1");
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!(
+            "{employee_uri}/leave-requests/{}/approve",
+            request["id"].as_str().unwrap()
+        ),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, days) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-pay?from=2024-03-01&to=2024-03-31"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        days,
+        json!([
+            {"leave_type": "sick", "pay_percentage": 100.0, "days": 3.0},
+            {"leave_type": "sick", "pay_percentage": 70.0, "days": 2.0}
+        ])
+    );
+
+    // Days keep their place in the spell when the period starts midway through it.
+    let (status, days) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-pay?from=2024-03-07&to=2024-03-31"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        days,
+        json!([{"leave_type": "sick", "pay_percentage": 70.0, "days": 2.0}])
+    );
+
+    let (status, _) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-pay?from=2024-03-31&to=2024-03-01"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn statutory_leave_is_capped_and_paid_by_the_payroll_policy() {
+    let app = support::test_router();
+    let (employee_uri, manager_id) = setup(&app).await;
+    let (payroll_uri, _) = employee_uri.split_once("/divisions/").unwrap();
+    let policy_uri = format!("{payroll_uri}/leave-pay-policies/maternity");
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &policy_uri,
+        json!({"tiers": [{"from_day": 1, "pay_percentage": 100.0}], "max_days": 0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, policy) = send_json(
+        &app,
+        "PUT",
+        &policy_uri,
+        json!({
+            "tiers": [
+                {"from_day": 1, "pay_percentage": 100.0},
+                {"from_day": 6, "pay_percentage": 50.0}
+            ],
+            "max_days": 10
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(policy["max_days"], 10);
+
+    // Three weeks hold fifteen working days, more than the policy allows.
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "maternity", "start_date": "2024-06-03", "end_date": "2024-06-21"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // No balance is needed for statutory leave.
+    let (status, request) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({
+            "leave_type": "maternity",
+            "start_date": "2024-06-03",
+            "end_date": "2024-06-14",
+            "certificate_reference": "MAT-7"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(request["days"], 10.0);
+    let (status, approved) = send_json(
+        &app,
+        "POST",
+        &format!(
+            "{employee_uri}/leave-requests/{}/approve",
+            request["id"].as_str().unwrap()
+        ),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "approved");
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/maternity"),
+        json!({"available_days": 5.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, paternity) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "paternity", "start_date": "2024-07-01", "end_date": "2024-07-02"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!(
+            "{employee_uri}/leave-requests/{}/approve",
+            paternity["id"].as_str().unwrap()
+        ),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, days) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-pay?from=2024-06-01&to=2024-07-31"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        days,
+        json!([
+            {"leave_type": "maternity", "pay_percentage": 100.0, "days": 5.0},
+            {"leave_type": "maternity", "pay_percentage": 50.0, "days": 5.0},
+            {"leave_type": "paternity", "pay_percentage": 100.0, "days": 2.0}
+        ])
+    );
+}
+
+#[tokio::test]
+async fn year_end_carryover_caps_warns_and_expires_balances() {
+    let (app, outbox) = support::test_router_with_outbox();
+    let (employee_uri, _) = setup(&app).await;
+    let (payroll_uri, _) = employee_uri.split_once("/divisions/").unwrap();
+    let rule_uri = format!("{payroll_uri}/leave-carryover-rules/vacation");
+    let carryover_uri = format!("{payroll_uri}/leave-carryover");
+
+    let (status, rule) = send_json(&app, "GET", &rule_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(rule["max_days"].is_null());
+    assert!(rule["expires_after_days"].is_null());
+    let (status, _) = send_json(
+        &app,
+        "GET",
+        &format!("{payroll_uri}/leave-carryover-rules/unpaid"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &rule_uri,
+        json!({"max_days": 5.0, "expires_after_days": 0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, rule) = send_json(
+        &app,
+        "PUT",
+        &rule_uri,
+        json!({"max_days": 5.0, "expires_after_days": 60}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(rule["max_days"], 5.0);
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/vacation"),
+        json!({"available_days": 12.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let next_year = Utc::now().year() + 1;
+    let year_start = NaiveDate::from_ymd_opt(next_year, 1, 1).unwrap();
+    let expires_on = year_start.checked_add_days(Days::new(60)).unwrap();
+
+    let (status, adjustments) = send_json(
+        &app,
+        "POST",
+        &carryover_uri,
+        json!({"as_of": year_start.checked_add_days(Days::new(14)).unwrap()}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(adjustments.as_array().unwrap().len(), 1);
+    assert_eq!(adjustments[0]["reason"], "year_end_carryover");
+    assert_eq!(adjustments[0]["effective_on"], year_start.to_string());
+    assert_eq!(adjustments[0]["days_before"], 12.0);
+    assert_eq!(adjustments[0]["days_after"], 5.0);
+
+    // Running again for the same year changes nothing.
+    let (status, adjustments) = send_json(
+        &app,
+        "POST",
+        &carryover_uri,
+        json!({"as_of": year_start.checked_add_days(Days::new(14)).unwrap()}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(adjustments, json!([]));
+
+    let (_, balances) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-balances"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(balances[0]["available_days"], 5.0);
+    assert_eq!(balances[0]["carried_over_days"], 5.0);
+    assert_eq!(balances[0]["leave_year"], next_year);
+    assert_eq!(balances[0]["carryover_expires_on"], expires_on.to_string());
+    assert!(outbox.sent().await.is_empty());
+
+    // The new year's grant keeps the carried-over days.
+    let (status, balance) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/vacation"),
+        json!({"available_days": 15.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(balance["carried_over_days"], 5.0);
+
+    for _ in 0..2 {
+        let (status, adjustments) = send_json(
+            &app,
+            "POST",
+            &carryover_uri,
+            json!({"as_of": expires_on.checked_sub_days(Days::new(10)).unwrap()}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(adjustments, json!([]));
+    }
+    let messages = outbox.sent().await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].to, "robin.away@example.com");
+    assert!(messages[0].body.contains(&expires_on.to_string()));
+
+    let (status, adjustments) =
+        send_json(&app, "POST", &carryover_uri, json!({"as_of": expires_on})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(adjustments.as_array().unwrap().len(), 1);
+    assert_eq!(adjustments[0]["reason"], "carryover_expired");
+    assert_eq!(adjustments[0]["days_before"], 15.0);
+    assert_eq!(adjustments[0]["days_after"], 10.0);
+
+    let (status, history) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-adjustments"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(history.as_array().unwrap().len(), 2);
+    assert_eq!(history[0]["reason"], "carryover_expired");
+    assert_eq!(history[1]["reason"], "year_end_carryover");
+}
+
+#[tokio::test]
+async fn grants_made_before_the_carryover_sweep_are_not_capped() {
+    let app = support::test_router();
+    let (employee_uri, _) = setup(&app).await;
+    let (payroll_uri, _) = employee_uri.split_once("/divisions/").unwrap();
+    let balance_uri = format!("{employee_uri}/leave-balances/vacation");
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{payroll_uri}/leave-carryover-rules/vacation"),
+        json!({"max_days": 5.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send_json(&app, "PUT", &balance_uri, json!({"available_days": 12.0})).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // The new year's grant lands before the sweep has carried last year's balance over.
+    let next_year = Utc::now().year() + 1;
+    let year_start = NaiveDate::from_ymd_opt(next_year, 1, 1).unwrap();
+    let (status, balance) = send_json(
+        &app,
+        "PUT",
+        &balance_uri,
+        json!({"available_days": 20.0, "as_of": year_start}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(balance["available_days"], 20.0);
+    assert_eq!(balance["carried_over_days"], 5.0);
+    assert_eq!(balance["leave_year"], next_year);
+
+    let (status, adjustments) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/leave-carryover"),
+        json!({"as_of": year_start.checked_add_days(Days::new(1)).unwrap()}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(adjustments, json!([]));
+
+    let (_, balances) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-balances"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(balances[0]["available_days"], 20.0);
+
+    let (_, history) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/leave-adjustments"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(history.as_array().unwrap().len(), 1);
+    assert_eq!(history[0]["reason"], "year_end_carryover");
+    assert_eq!(history[0]["days_after"], 5.0);
+}
+
+#[tokio::test]
+async fn blackout_periods_hold_or_refuse_planned_leave() {
+    let app = support::test_router();
+    let (employee_uri, manager_id) = setup(&app).await;
+    let (division_uri, _) = employee_uri.split_once("/employees/").unwrap();
+    let blackouts_uri = format!("{division_uri}/leave-blackouts");
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &blackouts_uri,
+        json!({"start_date": "2024-12-31", "end_date": "2024-12-16", "enforcement": "reject"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, closing) = send_json(
+        &app,
+        "POST",
+        &blackouts_uri,
+        json!({
+            "start_date": "2024-12-16",
+            "end_date": "2024-12-31",
+            "enforcement": "reject",
+            "reason": "Year-end close"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, audit) = send_json(
+        &app,
+        "POST",
+        &blackouts_uri,
+        json!({"start_date": "2024-11-25", "end_date": "2024-11-29", "enforcement": "require_override"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, blackouts) = send_json(&app, "GET", &blackouts_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(blackouts.as_array().unwrap().len(), 2);
+    assert_eq!(blackouts[0]["id"], audit["id"]);
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/vacation"),
+        json!({"available_days": 10.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, error) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "vacation", "start_date": "2024-12-30", "end_date": "2024-12-31"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(error["error"].as_str().unwrap().contains("Year-end close"));
+
+    // Nobody plans to be ill, so sick leave is not held back.
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{employee_uri}/leave-balances/sick"),
+        json!({"available_days": 5.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "sick", "start_date": "2024-12-17", "end_date": "2024-12-17"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, held) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "vacation", "start_date": "2024-11-25", "end_date": "2024-11-26"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(held["blackout_id"], audit["id"]);
+    let approve_uri = format!(
+        "{employee_uri}/leave-requests/{}/approve",
+        held["id"].as_str().unwrap()
+    );
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &approve_uri,
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, approved) = send_json(
+        &app,
+        "POST",
+        &approve_uri,
+        json!({"approver_id": manager_id, "note": "Cover arranged", "override_blackout": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "approved");
+
+    let closing_uri = format!("{blackouts_uri}/{}", closing["id"].as_str().unwrap());
+    let (status, _) = send_json(&app, "DELETE", &closing_uri, json!({})).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send_json(&app, "DELETE", &closing_uri, json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/leave-requests"),
+        json!({"leave_type": "vacation", "start_date": "2024-12-30", "end_date": "2024-12-31"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+}
@@ -0,0 +1,89 @@
+use chrono::{NaiveDate, Utc};
+use nomina::{
+    domain::leave::{LeaveBalance, LeaveRequest, LeaveRequestStatus, LeaveType},
+    error::AppError,
+    infrastructure::{
+        leave_repository::SurrealLeaveRepository,
+        surreal::{self, SurrealConfig},
+    },
+    services::leave::LeaveRepository,
+};
+use uuid::Uuid;
+
+fn approved(request: &LeaveRequest) -> LeaveRequest {
+    let mut request = request.clone();
+    request.status = LeaveRequestStatus::Approved;
+    request.decided_by = Some(Uuid::new_v4());
+    request.decided_at = Some(Utc::now());
+    request
+}
+
+#[tokio::test]
+async fn approvals_deduct_the_balance_once() {
+    let database = surreal::connect(&SurrealConfig::embedded())
+        .await
+        .expect("embedded database");
+    let repository = SurrealLeaveRepository::new(database);
+    let employee_id = Uuid::new_v4();
+    let date = NaiveDate::from_ymd_opt(2026, 3, 2).expect("date");
+
+    repository
+        .upsert_balance(LeaveBalance {
+            available_days: 5.0,
+            carried_over_days: 2.0,
+            ..LeaveBalance::empty(employee_id, LeaveType::Vacation)
+        })
+        .await
+        .expect("balance");
+    let first = LeaveRequest::new(
+        Uuid::new_v4(),
+        employee_id,
+        LeaveType::Vacation,
+        date,
+        date,
+        3.0,
+        None,
+    );
+    let second = LeaveRequest {
+        id: Uuid::new_v4(),
+        ..first.clone()
+    };
+    for request in [&first, &second] {
+        repository
+            .insert_request(request.clone())
+            .await
+            .expect("request");
+    }
+
+    let decided = repository
+        .decide_request(approved(&first))
+        .await
+        .expect("approve")
+        .expect("pending request");
+    assert_eq!(decided.status, LeaveRequestStatus::Approved);
+    assert!(
+        repository
+            .decide_request(approved(&first))
+            .await
+            .expect("approve again")
+            .is_none()
+    );
+    assert!(matches!(
+        repository.decide_request(approved(&second)).await,
+        Err(AppError::Conflict { .. })
+    ));
+
+    let balances = repository
+        .fetch_balances(employee_id)
+        .await
+        .expect("balances");
+    assert_eq!(balances.len(), 1);
+    assert_eq!(balances[0].available_days, 2.0);
+    assert_eq!(balances[0].carried_over_days, 0.0);
+    let second = repository
+        .fetch_request(second.id)
+        .await
+        .expect("fetch")
+        .expect("request");
+    assert_eq!(second.status, LeaveRequestStatus::Pending);
+}
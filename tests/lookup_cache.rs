@@ -0,0 +1,98 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use nomina::services::cache::{CacheStore, MemoryCacheStore, TtlCache};
+
+async fn load(
+    cache: &TtlCache<u32, String>,
+    loads: &AtomicUsize,
+    value: Option<&str>,
+) -> Option<String> {
+    cache
+        .get_or_load(1, || async {
+            loads.fetch_add(1, Ordering::SeqCst);
+            Ok(value.map(str::to_string))
+        })
+        .await
+        .expect("load")
+}
+
+#[tokio::test]
+async fn lookups_are_served_from_the_cache_until_invalidated() {
+    let store: Arc<dyn CacheStore> = Arc::new(MemoryCacheStore::default());
+    let cache = TtlCache::new(Arc::clone(&store), "test", Duration::from_secs(60));
+    let loads = AtomicUsize::new(0);
+
+    assert_eq!(
+        load(&cache, &loads, Some("first")).await.as_deref(),
+        Some("first")
+    );
+    assert_eq!(
+        load(&cache, &loads, Some("second")).await.as_deref(),
+        Some("first")
+    );
+    assert_eq!(loads.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        store.get("nomina:test:1").await.unwrap().as_deref(),
+        Some("\"first\"")
+    );
+
+    let written = cache.invalidating(1, async { "written" }).await;
+    assert_eq!(written, "written");
+    assert_eq!(store.get("nomina:test:1").await.unwrap(), None);
+    assert_eq!(
+        load(&cache, &loads, Some("second")).await.as_deref(),
+        Some("second")
+    );
+    assert_eq!(loads.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn invalidations_reach_every_cache_sharing_the_store() {
+    let store: Arc<dyn CacheStore> = Arc::new(MemoryCacheStore::default());
+    let first = TtlCache::new(Arc::clone(&store), "test", Duration::from_secs(60));
+    let second = TtlCache::new(Arc::clone(&store), "test", Duration::from_secs(60));
+    let loads = AtomicUsize::new(0);
+
+    assert_eq!(
+        load(&first, &loads, Some("old")).await.as_deref(),
+        Some("old")
+    );
+    assert_eq!(
+        load(&second, &loads, Some("new")).await.as_deref(),
+        Some("old")
+    );
+
+    first.invalidate(&1).await;
+    assert_eq!(
+        load(&second, &loads, Some("new")).await.as_deref(),
+        Some("new")
+    );
+    assert_eq!(loads.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn misses_are_not_cached_and_entries_expire() {
+    let store: Arc<dyn CacheStore> = Arc::new(MemoryCacheStore::default());
+    let cache = TtlCache::new(store, "test", Duration::from_millis(20));
+    let loads = AtomicUsize::new(0);
+
+    assert_eq!(load(&cache, &loads, None).await, None);
+    assert_eq!(
+        load(&cache, &loads, Some("created")).await.as_deref(),
+        Some("created")
+    );
+    assert_eq!(loads.load(Ordering::SeqCst), 2);
+
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    assert_eq!(
+        load(&cache, &loads, Some("updated")).await.as_deref(),
+        Some("updated")
+    );
+    assert_eq!(loads.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,270 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn create_organization(app: &Router) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Employees Org"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_payroll(app: &Router, organization_id: Uuid) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/payrolls"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": "Employees Payroll",
+                        "description": "Payroll for employees"
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_bank(app: &Router, organization_id: Uuid, name: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/banks"))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": name}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_job(app: &Router, organization_id: Uuid, payroll_id: Uuid, title: &str) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/jobs"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "job_title": title,
+                        "salary": 50000.0
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_division(
+    app: &Router,
+    organization_id: Uuid,
+    payroll_id: Uuid,
+    name: &str,
+) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/organizations/{organization_id}/payrolls/{payroll_id}/divisions"
+                ))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": name,
+                        "description": format!("{name} division"),
+                        "budget_code": format!("BC-{name}")
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    Uuid::parse_str(payload["id"].as_str().unwrap()).expect("uuid")
+}
+
+#[tokio::test]
+async fn milestones_group_birthdays_and_anniversaries_by_division() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let bank_id = create_bank(&app, organization_id, "Party Bank").await;
+    let job_id = create_job(&app, organization_id, payroll_id, "Host").await;
+    let north = create_division(&app, organization_id, payroll_id, "North").await;
+    let annex = create_division(&app, organization_id, payroll_id, "Annex").await;
+
+    for (division_id, id_number, last_name, date_of_birth, hire_date, status) in [
+        (
+            north,
+            "MIL-1",
+            "Fields",
+            "1990-03-15",
+            "2020-03-20",
+            "Active",
+        ),
+        (annex, "MIL-2", "Leap", "2000-02-29", "2026-01-05", "Active"),
+        (
+            north,
+            "MIL-3",
+            "Gone",
+            "1991-03-16",
+            "2019-03-21",
+            "Terminated",
+        ),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!(
+                        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+                    ))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "id_number": id_number,
+                            "last_name": last_name,
+                            "first_name": "Sam",
+                            "address": "4 Cake Court",
+                            "phone": "555-1212",
+                            "place_of_birth": "Partyville",
+                            "date_of_birth": date_of_birth,
+                            "nationality": "Exampleland",
+                            "marital_status": "Single",
+                            "gender": "M",
+                            "hire_date": hire_date,
+                            "clasification": "Full-time",
+                            "job_id": job_id,
+                            "payment_instructions": [{
+                                "bank_id": bank_id,
+                                "account": format!("ACC-{id_number}"),
+                                "amount": { "percentage": 100.0 },
+                                "priority": 1
+                            }],
+                            "status": status,
+                            "hours": 40
+                        })
+                        .to_string(),
+                    ))
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let get = |query: &str| {
+        let app = app.clone();
+        let uri = format!("/organizations/{organization_id}/milestones{query}");
+        async move {
+            let response = app
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .expect("response");
+            let status = response.status();
+            let body = read_json(response.into_body().collect().await.unwrap().to_bytes());
+            (status, body)
+        }
+    };
+
+    let (status, groups) = get("?from=2026-02-20&to=2026-03-31").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(groups.as_array().unwrap().len(), 2);
+    assert_eq!(groups[0]["division_name"], "Annex");
+    assert_eq!(
+        groups[0]["milestones"],
+        json!([{
+            "employee_id": groups[0]["milestones"][0]["employee_id"],
+            "id_number": "MIL-2",
+            "first_name": "Sam",
+            "last_name": "Leap",
+            "kind": "Birthday",
+            "date": "2026-02-28",
+            "years": 26
+        }])
+    );
+    assert_eq!(groups[1]["division_name"], "North");
+    let north_milestones: Vec<(&str, &str, i64)> = groups[1]["milestones"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|milestone| {
+            (
+                milestone["kind"].as_str().unwrap(),
+                milestone["date"].as_str().unwrap(),
+                milestone["years"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        north_milestones,
+        [
+            ("Birthday", "2026-03-15", 36),
+            ("WorkAnniversary", "2026-03-20", 6)
+        ]
+    );
+
+    let (status, _) = get(&format!(
+        "?from=2026-03-01&to=2026-02-01&division_id={north}"
+    ))
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = get("?from=2026-01-01&to=2027-06-01").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
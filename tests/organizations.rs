@@ -1,11 +1,25 @@
 #[path = "support/mod.rs"]
 mod support;
 
+use std::sync::Arc;
+
 use axum::{
+    Router,
     body::{Body, Bytes},
     http::{Request, StatusCode},
 };
 use http_body_util::BodyExt;
+use nomina::{
+    infrastructure::in_memory,
+    services::{
+        bank::{BankService, CreateBankParams},
+        cache::MemoryCacheStore,
+        lease::{LeaseRepository, LeaseService, ORGANIZATION_DELETION_SWEEP_LEASE},
+        organization::{CreateOrganizationParams, OrganizationService},
+        organization_deletion::{OrganizationDeletionService, RequestOrganizationDeletionParams},
+        payroll::{CreatePayrollParams, PayrollService},
+    },
+};
 use serde_json::{Value, json};
 use tower::ServiceExt;
 use uuid::Uuid;
@@ -14,6 +28,30 @@ fn read_json(body: Bytes) -> Value {
     serde_json::from_slice(&body).expect("valid json")
 }
 
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
 #[tokio::test]
 async fn creating_an_organization_returns_created_payload() {
     let app = support::test_router();
@@ -118,15 +156,21 @@ async fn can_update_and_delete_an_organization() {
         .oneshot(
             Request::builder()
                 .method("DELETE")
-                .uri(format!("/organizations/{id}"))
+                .uri(format!("/organizations/{id}?acknowledgement=Acme%20Two"))
                 .body(Body::empty())
                 .expect("request"),
         )
         .await
         .expect("response");
 
-    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let deletion = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(deletion["organization_id"], id);
+    assert!(
+        deletion["scheduled_for"].as_str().unwrap() > deletion["requested_at"].as_str().unwrap()
+    );
 
+    // The organization stays readable until the cancellation window elapses.
     let response = app
         .clone()
         .oneshot(
@@ -138,9 +182,948 @@ async fn can_update_and_delete_an_organization() {
         .await
         .expect("response");
 
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn deletion_requires_acknowledgement_and_can_be_cancelled() {
+    let app = support::test_router();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Acme"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let id = created["id"].as_str().unwrap();
+
+    for (uri, expected) in [
+        (
+            format!("/organizations/{id}"),
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+        (
+            format!("/organizations/{id}?acknowledgement=Wrong"),
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+        (
+            format!("/organizations/{id}?acknowledgement=Acme"),
+            StatusCode::ACCEPTED,
+        ),
+        (
+            format!("/organizations/{id}?acknowledgement=Acme"),
+            StatusCode::CONFLICT,
+        ),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(uri)
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), expected);
+    }
+
+    for (method, expected) in [
+        ("GET", StatusCode::OK),
+        ("DELETE", StatusCode::NO_CONTENT),
+        ("GET", StatusCode::NOT_FOUND),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(format!("/organizations/{id}/deletion"))
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), expected);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{id}/restore"))
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn purging_soft_deletes_organizations_with_their_payrolls_and_banks() {
+    let organization_service = Arc::new(OrganizationService::new(
+        Arc::new(in_memory::InMemoryOrganizationRepository::default()),
+        Arc::new(in_memory::InMemoryBlobStore::default()),
+        Arc::new(MemoryCacheStore::default()),
+    ));
+    let payroll_service = Arc::new(PayrollService::new(
+        Arc::new(in_memory::InMemoryPayrollRepository::default()),
+        Arc::clone(&organization_service),
+        Arc::new(in_memory::InMemoryLegalEntityRepository::default()),
+        Arc::new(MemoryCacheStore::default()),
+    ));
+    let employees = Arc::new(in_memory::InMemoryEmployeeRepository::default());
+    let bank_service = Arc::new(BankService::new(
+        Arc::new(in_memory::InMemoryBankRepository::new(Arc::clone(
+            &employees,
+        ))),
+        Arc::new(in_memory::InMemoryBankCatalogRepository::default()),
+        Arc::clone(&organization_service),
+        employees,
+    ));
+    let deletion_service = OrganizationDeletionService::new(
+        Arc::new(in_memory::InMemoryOrganizationDeletionRepository::default()),
+        Arc::clone(&organization_service),
+        Arc::clone(&payroll_service),
+        Arc::clone(&bank_service),
+        chrono::Duration::hours(24),
+    );
+
+    let organization = organization_service
+        .create(CreateOrganizationParams {
+            name: "Doomed".to_string(),
+            budget_code_pattern: None,
+            legal_identity: Default::default(),
+        })
+        .await
+        .expect("organization");
+    let payroll = payroll_service
+        .create(
+            organization.id,
+            CreatePayrollParams {
+                name: "Monthly".to_string(),
+                description: "Monthly payroll".to_string(),
+                blocks_unverified_accounts: false,
+                legal_entity_id: None,
+            },
+        )
+        .await
+        .expect("payroll");
+    let bank = bank_service
+        .create(
+            organization.id,
+            CreateBankParams {
+                name: "Doomed Bank".to_string(),
+                swift_code: None,
+                routing_number: None,
+                country: None,
+                address: None,
+                account_rule: None,
+                payment_format: None,
+                catalog_id: None,
+            },
+        )
+        .await
+        .expect("bank");
+    let deletion = deletion_service
+        .request(
+            organization.id,
+            RequestOrganizationDeletionParams {
+                acknowledgement: "Doomed".to_string(),
+            },
+        )
+        .await
+        .expect("deletion");
+
+    let purged = deletion_service
+        .purge_due(deletion.requested_at)
+        .await
+        .expect("purge");
+    assert!(purged.is_empty());
+
+    let purged = deletion_service
+        .purge_due(deletion.scheduled_for)
+        .await
+        .expect("purge");
+    assert_eq!(purged, vec![organization.id]);
+    assert!(
+        organization_service
+            .get(organization.id)
+            .await
+            .expect("lookup")
+            .is_none()
+    );
+    let deleted = organization_service
+        .get_deleted(organization.id)
+        .await
+        .expect("lookup")
+        .expect("tombstone");
+    assert_eq!(deleted.deleted_at, Some(deletion.scheduled_for));
+    assert!(
+        deletion_service
+            .get(organization.id)
+            .await
+            .expect("lookup")
+            .is_none()
+    );
+    assert!(
+        payroll_service
+            .get(organization.id, payroll.id)
+            .await
+            .expect("lookup")
+            .is_none()
+    );
+    assert!(
+        bank_service
+            .get(organization.id, bank.id)
+            .await
+            .expect("lookup")
+            .is_none()
+    );
+
+    let restored = deletion_service
+        .restore(organization.id)
+        .await
+        .expect("restore")
+        .expect("restored organization");
+    assert_eq!(restored.deleted_at, None);
+    assert_eq!(
+        payroll_service
+            .list(organization.id)
+            .await
+            .expect("payrolls")
+            .len(),
+        1
+    );
+    assert!(
+        bank_service
+            .get(organization.id, bank.id)
+            .await
+            .expect("lookup")
+            .is_some()
+    );
+    assert!(
+        deletion_service
+            .restore(organization.id)
+            .await
+            .expect("restore")
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn legal_identity_is_validated_and_updated_field_by_field() {
+    let app = support::test_router();
+    let send = |method: &str, uri: String, payload: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+
+    for invalid in [
+        json!({"name": "Acme", "tax_id": "12"}),
+        json!({"name": "Acme", "tax_id": "12_3456789"}),
+        json!({"name": "Acme", "legal_name": "  "}),
+        json!({"name": "Acme", "registration_number": "#"}),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(send("POST", "/organizations".to_string(), invalid.clone()))
+            .await
+            .expect("response");
+        assert_eq!(
+            response.status(),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "{invalid}"
+        );
+    }
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            "/organizations".to_string(),
+            json!({
+                "name": "Acme",
+                "legal_name": " Acme Holdings LLC ",
+                "tax_id": "12-3456789",
+                "registration_number": "de 1234 567",
+                "address": "1 Main Street, Dover"
+            }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(created["legal_name"], "Acme Holdings LLC");
+    assert_eq!(created["registration_number"], "DE1234567");
+    let uri = format!("/organizations/{}", created["id"].as_str().unwrap());
+
+    let response = app
+        .clone()
+        .oneshot(send("PUT", uri.clone(), json!({"tax_id": "x"})))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            uri.clone(),
+            json!({"legal_name": null, "tax_id": "98 7654321"}),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(updated["name"], "Acme");
+    assert_eq!(updated["legal_name"], Value::Null);
+    assert_eq!(updated["tax_id"], "987654321");
+    assert_eq!(updated["registration_number"], "DE1234567");
+    assert_eq!(updated["address"], "1 Main Street, Dover");
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "PUT",
+            format!("/organizations/{}", Uuid::new_v4()),
+            json!({"tax_id": "987654321"}),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn organization_settings_default_and_validate_updates() {
+    let app = support::test_router();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Acme"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    let settings_uri = format!(
+        "/organizations/{}/settings",
+        created["id"].as_str().unwrap()
+    );
+
+    let get = |uri: String| {
+        Request::builder()
+            .uri(uri)
+            .body(Body::empty())
+            .expect("request")
+    };
+    let put = |uri: String, payload: Value| {
+        Request::builder()
+            .method("PUT")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("request")
+    };
+
+    let response = app
+        .clone()
+        .oneshot(get(settings_uri.clone()))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let settings = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(settings["default_currency"], Value::Null);
+    assert_eq!(settings["locale"], "en-US");
+    assert_eq!(settings["fiscal_year_start"], json!({"month": 1, "day": 1}));
+    assert_eq!(settings["working_days_per_week"], 5);
+    assert_eq!(
+        settings["rounding"],
+        json!({"increment": 0.01, "mode": "half_up"})
+    );
+
+    for invalid in [
+        json!({}),
+        json!({"default_currency": "EURO"}),
+        json!({"locale": "spanish"}),
+        json!({"fiscal_year_start": {"month": 2, "day": 29}}),
+        json!({"working_days_per_week": 0}),
+        json!({"rounding": {"increment": 0.005, "mode": "half_even"}}),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(put(settings_uri.clone(), invalid.clone()))
+            .await
+            .expect("response");
+        assert_eq!(
+            response.status(),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "{invalid}"
+        );
+    }
+
+    let response = app
+        .clone()
+        .oneshot(put(
+            settings_uri.clone(),
+            json!({
+                "default_currency": "mxn",
+                "locale": "es_mx",
+                "fiscal_year_start": {"month": 4, "day": 1}
+            }),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(put(
+            settings_uri.clone(),
+            json!({"default_currency": null, "working_days_per_week": 4}),
+        ))
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(get(settings_uri))
+        .await
+        .expect("response");
+    let settings = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(settings["default_currency"], Value::Null);
+    assert_eq!(settings["locale"], "es-MX");
+    assert_eq!(settings["fiscal_year_start"], json!({"month": 4, "day": 1}));
+    assert_eq!(settings["working_days_per_week"], 4);
+
+    let response = app
+        .clone()
+        .oneshot(get(format!("/organizations/{}/settings", Uuid::new_v4())))
+        .await
+        .expect("response");
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn organization_logo_can_be_uploaded_fetched_and_removed() {
+    let app = support::test_router();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Brand"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let created = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(created["logo_url"].is_null());
+    let organization_uri = format!("/organizations/{}", created["id"].as_str().unwrap());
+    let logo_uri = format!("{organization_uri}/logo");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(&logo_uri)
+                .header("content-type", "image/svg+xml")
+                .body(Body::from("<svg/>"))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let png = vec![0x89_u8, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(&logo_uri)
+                .header("content-type", "image/png")
+                .body(Body::from(png.clone()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert_eq!(updated["logo_url"], logo_uri);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(&logo_uri)
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/png");
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(bytes.to_vec(), png);
+
+    for expected in [StatusCode::NO_CONTENT, StatusCode::NOT_FOUND] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(&logo_uri)
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), expected);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(&organization_uri)
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let fetched = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    assert!(fetched["logo_url"].is_null());
+}
+
+#[tokio::test]
+async fn cloning_copies_the_structure_without_employees() {
+    let app = support::test_router();
+
+    let (_, template) = send_json(
+        &app,
+        "POST",
+        "/organizations",
+        json!({"name": "Template", "budget_code_pattern": "CC-\\d{2}", "tax_id": "11-1111111"}),
+    )
+    .await;
+    let template_uri = format!("/organizations/{}", template["id"].as_str().unwrap());
+    send_json(
+        &app,
+        "PUT",
+        &format!("{template_uri}/settings"),
+        json!({"default_currency": "EUR", "working_days_per_week": 6}),
+    )
+    .await;
+    let (_, payroll) = send_json(
+        &app,
+        "POST",
+        &format!("{template_uri}/payrolls"),
+        json!({"name": "Monthly", "description": "Monthly payroll"}),
+    )
+    .await;
+    let payroll_uri = format!(
+        "{template_uri}/payrolls/{}",
+        payroll["id"].as_str().unwrap()
+    );
+    let (_, grade) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/job-grades"),
+        json!({"code": "G1", "band": "Staff", "level": 1, "salary_min": 1000.0, "salary_max": 3000.0}),
+    )
+    .await;
+    send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/jobs"),
+        json!({"job_title": "Clerk", "salary": 2000.0, "grade_id": grade["id"]}),
+    )
+    .await;
+    let (_, root) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/divisions"),
+        json!({"name": "Head Office", "description": "Root", "budget_code": "CC-01"}),
+    )
+    .await;
+    send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/divisions"),
+        json!({"name": "Accounts", "description": "Child", "budget_code": "CC-02", "parent_division_id": root["id"]}),
+    )
+    .await;
+    send_json(
+        &app,
+        "POST",
+        &format!("{template_uri}/banks"),
+        json!({"name": "Template Bank"}),
+    )
+    .await;
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("/organizations/{}/clone", Uuid::new_v4()),
+        json!({"name": "Nowhere"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, clone) = send_json(
+        &app,
+        "POST",
+        &format!("{template_uri}/clone"),
+        json!({"name": "Template Two", "tax_id": "22-2222222"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(clone["payrolls"], 1);
+    assert_eq!(clone["job_grades"], 1);
+    assert_eq!(clone["jobs"], 1);
+    assert_eq!(clone["divisions"], 2);
+    assert_eq!(clone["banks"], 1);
+    let organization = &clone["organization"];
+    assert_eq!(organization["name"], "Template Two");
+    assert_eq!(organization["tax_id"], "22-2222222");
+    assert_eq!(organization["budget_code_pattern"], "CC-\\d{2}");
+    assert_ne!(organization["id"], template["id"]);
+
+    let clone_uri = format!("/organizations/{}", organization["id"].as_str().unwrap());
+    let (_, settings) = send_json(&app, "GET", &format!("{clone_uri}/settings"), json!({})).await;
+    assert_eq!(settings["default_currency"], "EUR");
+    assert_eq!(settings["working_days_per_week"], 6);
+
+    let (_, payrolls) = send_json(&app, "GET", &format!("{clone_uri}/payrolls"), json!({})).await;
+    assert_eq!(payrolls[0]["name"], "Monthly");
+    assert_ne!(payrolls[0]["id"], payroll["id"]);
+    let cloned_payroll_uri = format!(
+        "{clone_uri}/payrolls/{}",
+        payrolls[0]["id"].as_str().unwrap()
+    );
+    let (_, grades) = send_json(
+        &app,
+        "GET",
+        &format!("{cloned_payroll_uri}/job-grades"),
+        json!({}),
+    )
+    .await;
+    let (_, jobs) = send_json(
+        &app,
+        "GET",
+        &format!("{cloned_payroll_uri}/jobs"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(jobs[0]["job_title"], "Clerk");
+    assert_eq!(jobs[0]["grade_id"], grades[0]["id"]);
+    assert_ne!(jobs[0]["grade_id"], grade["id"]);
+
+    let (_, divisions) = send_json(
+        &app,
+        "GET",
+        &format!("{cloned_payroll_uri}/divisions"),
+        json!({}),
+    )
+    .await;
+    let divisions = divisions.as_array().unwrap();
+    let cloned_root = divisions
+        .iter()
+        .find(|division| division["name"] == "Head Office")
+        .unwrap();
+    let cloned_child = divisions
+        .iter()
+        .find(|division| division["name"] == "Accounts")
+        .unwrap();
+    assert_eq!(cloned_child["parent_division_id"], cloned_root["id"]);
+
+    let (_, banks) = send_json(&app, "GET", &format!("{clone_uri}/banks"), json!({})).await;
+    assert_eq!(banks[0]["name"], "Template Bank");
+    let (_, employees) = send_json(&app, "GET", &format!("{clone_uri}/employees"), json!({})).await;
+    assert_eq!(employees["total"], 0);
+}
+
+#[tokio::test]
+async fn members_are_invited_and_the_last_owner_is_kept() {
+    let (app, outbox) = support::test_router_with_outbox();
+
+    let (_, organization) = send_json(
+        &app,
+        "POST",
+        "/organizations",
+        json!({"name": "Acme", "budget_code_pattern": "CC-\\d{2}"}),
+    )
+    .await;
+    let members_uri = format!(
+        "/organizations/{}/members",
+        organization["id"].as_str().unwrap()
+    );
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &members_uri,
+        json!({"email": "clerk@example.com", "role": "member"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, owner) = send_json(
+        &app,
+        "POST",
+        &members_uri,
+        json!({"email": " Owner@Example.com ", "role": "owner"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(owner["email"], "owner@example.com");
+    let messages = outbox.sent().await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].to, "owner@example.com");
+    assert!(messages[0].subject.contains("Acme"));
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &members_uri,
+        json!({"email": "owner@example.com", "role": "admin"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, admin) = send_json(
+        &app,
+        "POST",
+        &members_uri,
+        json!({"email": "admin@example.com", "role": "admin"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, members) = send_json(&app, "GET", &members_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    let emails: Vec<_> = members
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|member| member["email"].as_str().unwrap())
+        .collect();
+    assert_eq!(emails, ["owner@example.com", "admin@example.com"]);
+
+    let owner_uri = format!("{members_uri}/{}", owner["id"].as_str().unwrap());
+    let admin_uri = format!("{members_uri}/{}", admin["id"].as_str().unwrap());
+    let (status, _) = send_json(&app, "PUT", &owner_uri, json!({"role": "admin"})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(&app, "DELETE", &owner_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, promoted) = send_json(&app, "PUT", &admin_uri, json!({"role": "owner"})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(promoted["role"], "owner");
+    let (status, _) = send_json(&app, "DELETE", &owner_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = send_json(&app, "DELETE", &owner_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn archived_organizations_reject_writes_through_flat_routes() {
+    let app = support::test_router();
+
+    let (_, organization) = send_json(
+        &app,
+        "POST",
+        "/organizations",
+        json!({"name": "Flat Churned"}),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap();
+    let (_, payroll) = send_json(
+        &app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Monthly", "description": "Monthly payroll"}),
+    )
+    .await;
+    let payroll_uri = format!(
+        "/organizations/{organization_id}/payrolls/{}",
+        payroll["id"].as_str().unwrap()
+    );
+    let (_, division) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll_uri}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let division_uri = format!(
+        "/divisions/{}?organization_id={organization_id}",
+        division["id"].as_str().unwrap()
+    );
+    let job_uri = format!(
+        "/jobs/{}?organization_id={organization_id}",
+        job["id"].as_str().unwrap()
+    );
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("/organizations/{organization_id}/archive"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send_json(&app, "PUT", &division_uri, json!({"name": "Renamed"})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(&app, "DELETE", &job_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(
+        &app,
+        "DELETE",
+        &format!(
+            "/employees/{}?organization_id={organization_id}",
+            Uuid::new_v4()
+        ),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, fetched) = send_json(&app, "GET", &division_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(fetched["name"], "Admin");
+    let (status, _) = send_json(&app, "GET", &job_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn archived_organizations_are_read_only_until_reactivated() {
+    let app = support::test_router();
+
+    let (_, organization) =
+        send_json(&app, "POST", "/organizations", json!({"name": "Churned"})).await;
+    let organization_uri = format!("/organizations/{}", organization["id"].as_str().unwrap());
+    let (_, payroll) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/payrolls"),
+        json!({"name": "Monthly", "description": "Monthly payroll"}),
+    )
+    .await;
+
+    let (status, archived) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/archive"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(archived["archived_at"].is_string());
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/archive"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, payrolls) = send_json(
+        &app,
+        "GET",
+        &format!("{organization_uri}/payrolls"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(payrolls.as_array().unwrap().len(), 1);
+    let (status, fetched) = send_json(&app, "GET", &organization_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(fetched["archived_at"], archived["archived_at"]);
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/payrolls"),
+        json!({"name": "Weekly", "description": "Weekly payroll"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!(
+            "{organization_uri}/payrolls/{}",
+            payroll["id"].as_str().unwrap()
+        ),
+        json!({"name": "Renamed"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(&app, "PUT", &organization_uri, json!({"name": "Renamed"})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, reactivated) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/reactivate"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(reactivated["archived_at"].is_null());
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/reactivate"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{organization_uri}/payrolls"),
+        json!({"name": "Weekly", "description": "Weekly payroll"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
 #[tokio::test]
 async fn rejecting_empty_names() {
     let app = support::test_router();
@@ -160,3 +1143,56 @@ async fn rejecting_empty_names() {
 
     assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
 }
+
+#[tokio::test]
+async fn background_leases_admit_one_holder_at_a_time() {
+    let repository: Arc<dyn LeaseRepository> =
+        Arc::new(in_memory::InMemoryLeaseRepository::default());
+    let first = LeaseService::new(Arc::clone(&repository), chrono::Duration::minutes(30));
+    let second = LeaseService::new(Arc::clone(&repository), chrono::Duration::minutes(30));
+    assert_ne!(first.holder(), second.holder());
+
+    assert!(
+        first
+            .try_acquire(ORGANIZATION_DELETION_SWEEP_LEASE)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !second
+            .try_acquire(ORGANIZATION_DELETION_SWEEP_LEASE)
+            .await
+            .unwrap()
+    );
+    assert!(
+        first
+            .try_acquire(ORGANIZATION_DELETION_SWEEP_LEASE)
+            .await
+            .unwrap()
+    );
+
+    second
+        .release(ORGANIZATION_DELETION_SWEEP_LEASE)
+        .await
+        .unwrap();
+    assert!(
+        !second
+            .try_acquire(ORGANIZATION_DELETION_SWEEP_LEASE)
+            .await
+            .unwrap()
+    );
+    first
+        .release(ORGANIZATION_DELETION_SWEEP_LEASE)
+        .await
+        .unwrap();
+    assert!(
+        second
+            .try_acquire(ORGANIZATION_DELETION_SWEEP_LEASE)
+            .await
+            .unwrap()
+    );
+
+    let lapsing = LeaseService::new(Arc::clone(&repository), chrono::Duration::zero());
+    assert!(lapsing.try_acquire("short-job").await.unwrap());
+    assert!(first.try_acquire("short-job").await.unwrap());
+}
@@ -0,0 +1,309 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates an employee without a schedule and returns its URI.
+async fn setup(app: &Router) -> String {
+    let (_, organization) = send_json(
+        app,
+        "POST",
+        "/organizations",
+        json!({"name": "Timesheet Org"}),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Timesheet Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let (status, employee) = send_json(
+        app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "TS-1",
+            "last_name": "Hourly",
+            "first_name": "Robin",
+            "address": "4 Beach Rd",
+            "phone": "555-0404",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1990-04-04",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2020-01-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-TS",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    format!("{employees_uri}/{}", employee["id"].as_str().unwrap())
+}
+
+/// Creates a second employee in the same division, e.g. to act as manager, and returns its id.
+async fn create_colleague(app: &Router, employee_uri: &str) -> String {
+    let (employees_uri, _) = employee_uri.rsplit_once('/').unwrap();
+    let (_, employee) = send_json(app, "GET", employee_uri, json!({})).await;
+    let (status, colleague) = send_json(
+        app,
+        "POST",
+        employees_uri,
+        json!({
+            "id_number": "TS-2",
+            "last_name": "Lead",
+            "first_name": "Sam",
+            "address": "5 Beach Rd",
+            "phone": "555-0405",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1985-05-05",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "M",
+            "hire_date": "2018-01-01",
+            "clasification": "Full-time",
+            "job_id": employee["job_id"],
+            "payment_instructions": [{
+                "bank_id": employee["payment_instructions"][0]["bank_id"],
+                "account": "ACC-TS2",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    colleague["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn overtime_earns_the_multiplier_only_when_pre_approved() {
+    let app = support::test_router();
+    let employee_uri = setup(&app).await;
+    let (division_uri, _) = employee_uri.split_once("/employees/").unwrap();
+    let (payroll_uri, _) = division_uri.split_once("/divisions/").unwrap();
+    let policy_uri = format!("{payroll_uri}/overtime-policy");
+    let requests_uri = format!("{employee_uri}/overtime-requests");
+    let pay_uri = format!("{employee_uri}/overtime-pay?from=2024-03-01&to=2024-03-31");
+    let warnings_uri = format!("{payroll_uri}/overtime-warnings?from=2024-03-01&to=2024-03-31");
+
+    let (status, policy) = send_json(&app, "GET", &policy_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(policy["multiplier"], 1.5);
+    assert_eq!(policy["requires_preapproval"], false);
+
+    // 40 hours by Thursday, so Friday and Saturday are overtime against the weekly threshold.
+    for (date, hours) in [
+        ("2024-03-04", 10.0),
+        ("2024-03-05", 10.0),
+        ("2024-03-06", 10.0),
+        ("2024-03-07", 10.0),
+        ("2024-03-08", 6.0),
+        ("2024-03-09", 4.0),
+    ] {
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("{employee_uri}/timesheet-entries"),
+            json!({"date": date, "hours": hours}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+    let manager_id = create_colleague(&app, &employee_uri).await;
+    send_json(
+        &app,
+        "PUT",
+        &format!("{division_uri}/manager"),
+        json!({"employee_id": manager_id}),
+    )
+    .await;
+    let (_, timesheet) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/timesheets"),
+        json!({"period_start": "2024-03-01", "period_end": "2024-03-31"}),
+    )
+    .await;
+    send_json(
+        &app,
+        "POST",
+        &format!(
+            "{employee_uri}/timesheets/{}/approve",
+            timesheet["id"].as_str().unwrap()
+        ),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+
+    let (status, pay) = send_json(&app, "GET", &pay_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(pay["regular_hours"], 40.0);
+    assert_eq!(pay["overtime_hours"], 10.0);
+    assert_eq!(pay["unapproved_overtime_hours"], 0.0);
+    let (_, report) = send_json(&app, "GET", &warnings_uri, json!({})).await;
+    assert_eq!(report["warnings"], json!([]));
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &policy_uri,
+        json!({"multiplier": 0.5, "requires_preapproval": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, policy) = send_json(
+        &app,
+        "PUT",
+        &policy_uri,
+        json!({"multiplier": 2.0, "requires_preapproval": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(policy["multiplier"], 2.0);
+
+    let (status, request) = send_json(
+        &app,
+        "POST",
+        &requests_uri,
+        json!({"date": "2024-03-08", "hours": 4.0, "reason": " Quarter close "}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(request["status"], "pending");
+    assert_eq!(request["reason"], "Quarter close");
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &requests_uri,
+        json!({"date": "2024-03-08", "hours": 2.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let approve_uri = format!("{requests_uri}/{}/approve", request["id"].as_str().unwrap());
+    let employee_id = employee_uri.rsplit('/').next().unwrap();
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &approve_uri,
+        json!({"approver_id": employee_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, request) = send_json(
+        &app,
+        "POST",
+        &approve_uri,
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(request["status"], "approved");
+    assert_eq!(request["reviewed_by"], manager_id.as_str());
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &approve_uri,
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Friday's approval covers 4 of its 6 overtime hours; Saturday had none.
+    let (_, pay) = send_json(&app, "GET", &pay_uri, json!({})).await;
+    assert_eq!(pay["multiplier"], 2.0);
+    assert_eq!(pay["regular_hours"], 46.0);
+    assert_eq!(pay["overtime_hours"], 4.0);
+    assert_eq!(pay["unapproved_overtime_hours"], 6.0);
+
+    let (status, report) = send_json(&app, "GET", &warnings_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["requires_preapproval"], true);
+    let warnings = report["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0]["date"], "2024-03-08");
+    assert_eq!(warnings[0]["unapproved_hours"], 2.0);
+    assert_eq!(warnings[1]["date"], "2024-03-09");
+    assert_eq!(warnings[1]["unapproved_hours"], 4.0);
+}
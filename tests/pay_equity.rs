@@ -0,0 +1,230 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn create_organization(app: &Router) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/organizations")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"name": "Pay Equity Org"}).to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).expect("json");
+    Uuid::parse_str(body["id"].as_str().unwrap()).expect("uuid")
+}
+
+async fn create_payroll(app: &Router, organization_id: Uuid) -> Uuid {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/organizations/{organization_id}/payrolls"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({
+                        "name": "August Payroll",
+                        "description": "Pay equity payroll",
+                    })
+                    .to_string(),
+                ))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).expect("json");
+    Uuid::parse_str(body["id"].as_str().unwrap()).expect("uuid")
+}
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+#[tokio::test]
+async fn pay_equity_compares_grade_pay_and_suppresses_small_groups() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let payroll_id = create_payroll(&app, organization_id).await;
+    let payroll = format!("/organizations/{organization_id}/payrolls/{payroll_id}");
+
+    let (_, grade) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll}/job-grades"),
+        json!({"code": "P1", "band": "Professional", "level": 1, "salary_min": 1_000.0, "salary_max": 3_000.0}),
+    )
+    .await;
+    let mut job_ids = Vec::new();
+    for job in [
+        json!({"job_title": "Junior", "salary": 1_000.0, "grade_id": grade["id"]}),
+        json!({"job_title": "Senior", "salary": 2_000.0, "grade_id": grade["id"]}),
+        json!({"job_title": "Intern", "salary": 500.0}),
+    ] {
+        let (status, job) = send_json(&app, "POST", &format!("{payroll}/jobs"), job).await;
+        assert_eq!(status, StatusCode::CREATED);
+        job_ids.push(job["id"].clone());
+    }
+    let (_, bank) = send_json(
+        &app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Equity Bank"}),
+    )
+    .await;
+    let (_, division) = send_json(
+        &app,
+        "POST",
+        &format!("{payroll}/divisions"),
+        json!({"name": "Ops", "description": "Operations", "budget_code": "OPS"}),
+    )
+    .await;
+
+    for (index, (gender, clasification, job_id)) in [
+        ("F", "Full-time", &job_ids[0]),
+        ("F", "Part-time", &job_ids[0]),
+        ("F", "Full-time", &job_ids[1]),
+        ("M", "Full-time", &job_ids[1]),
+        ("M", "Full-time", &job_ids[1]),
+        ("M", "Full-time", &job_ids[2]),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!(
+                "{payroll}/divisions/{}/employees",
+                division["id"].as_str().unwrap()
+            ),
+            json!({
+                "id_number": format!("EQ-{index}"),
+                "last_name": "Equal",
+                "first_name": format!("Pay {index}"),
+                "address": "1 Fair St",
+                "phone": "555-0101",
+                "place_of_birth": "Town",
+                "date_of_birth": "1990-01-01",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": gender,
+                "hire_date": "2020-01-01",
+                "clasification": clasification,
+                "job_id": job_id,
+                "payment_instructions": [{
+                    "bank_id": bank["id"],
+                    "account": format!("EQ-ACCT-{index}"),
+                    "amount": { "percentage": 100.0 },
+                    "priority": 1
+                }],
+                "status": "Active",
+                "hours": 40
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    let report = |min_group_size: Option<usize>| {
+        let app = app.clone();
+        let uri = match min_group_size {
+            Some(size) => format!("{payroll}/pay-equity?min_group_size={size}"),
+            None => format!("{payroll}/pay-equity"),
+        };
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri(uri)
+                        .body(Body::empty())
+                        .expect("request"),
+                )
+                .await
+                .expect("response");
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (status, read_json(body))
+        }
+    };
+
+    let (status, _) = report(Some(1)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, body) = report(None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["min_group_size"], 5);
+    assert_eq!(body["ungraded_employees"], 1);
+    let grade = &body["grades"][0];
+    assert_eq!(grade["headcount"], 5);
+    assert_eq!(grade["average_annual_pay"], 19_200.0);
+    assert_eq!(grade["median_annual_pay"], 24_000.0);
+    assert!(
+        grade["by_gender"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|group| group["suppressed"] == true && group["headcount"].is_null())
+    );
+
+    let (_, body) = report(Some(2)).await;
+    let grade = &body["grades"][0];
+    let women = &grade["by_gender"][0];
+    assert_eq!(women["group"], "F");
+    assert_eq!(women["headcount"], 3);
+    assert_eq!(women["average_annual_pay"], 16_000.0);
+    assert_eq!(women["median_annual_pay"], 12_000.0);
+    assert_eq!(women["median_gap_percentage"], -50.0);
+    let men = &grade["by_gender"][1];
+    assert_eq!(men["median_annual_pay"], 24_000.0);
+    assert_eq!(men["median_gap_percentage"], 0.0);
+    let classifications = grade["by_classification"].as_array().unwrap();
+    assert_eq!(classifications[0]["group"], "Full-time");
+    assert_eq!(classifications[0]["headcount"], 4);
+    assert_eq!(classifications[1]["group"], "Part-time");
+    assert_eq!(classifications[1]["suppressed"], true);
+}
@@ -188,3 +188,107 @@ async fn can_update_and_delete_payroll() {
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
+
+#[tokio::test]
+async fn payrolls_report_under_legal_entities_of_their_organization() {
+    let app = support::test_router();
+    let organization_id = create_organization(&app).await;
+    let other_organization_id = create_organization(&app).await;
+    let send = |method: &'static str, uri: String, payload: Value| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .method(method)
+                        .uri(uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(payload.to_string()))
+                        .expect("request"),
+                )
+                .await
+                .expect("response");
+            let status = response.status();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let body = if bytes.is_empty() {
+                Value::Null
+            } else {
+                read_json(bytes)
+            };
+            (status, body)
+        }
+    };
+    let entities_uri = format!("/organizations/{organization_id}/legal-entities");
+
+    let (_, bank) = send(
+        "POST",
+        format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Group Bank"}),
+    )
+    .await;
+    let (status, north) = send(
+        "POST",
+        entities_uri.clone(),
+        json!({
+            "name": "Acme North",
+            "tax_id": "11 111 1111",
+            "debtor_account": {"bank_id": bank["id"], "account_number": " 0011-22 "}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(north["tax_id"], "111111111");
+    assert_eq!(north["debtor_account"]["account_number"], "0011-22");
+
+    let (status, _) = send(
+        "POST",
+        entities_uri.clone(),
+        json!({"name": "Acme Copy", "tax_id": "111111111"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send(
+        "POST",
+        entities_uri.clone(),
+        json!({
+            "name": "Acme South",
+            "debtor_account": {"bank_id": Uuid::new_v4(), "account_number": "1"}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        "POST",
+        format!("/organizations/{other_organization_id}/payrolls"),
+        json!({"name": "Elsewhere", "description": "Other group", "legal_entity_id": north["id"]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, payroll) = send(
+        "POST",
+        format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "North", "description": "North payroll", "legal_entity_id": north["id"]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(payroll["legal_entity_id"], north["id"]);
+
+    let north_uri = format!("{entities_uri}/{}", north["id"].as_str().unwrap());
+    let (status, _) = send("DELETE", north_uri.clone(), Value::Null).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let payroll_uri = format!(
+        "/organizations/{organization_id}/payrolls/{}",
+        payroll["id"].as_str().unwrap()
+    );
+    let (status, moved) = send("PUT", payroll_uri, json!({"legal_entity_id": null})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(moved["legal_entity_id"], Value::Null);
+    let (status, _) = send("DELETE", north_uri.clone(), Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, entities) = send("GET", entities_uri, Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(entities.as_array().unwrap().is_empty());
+}
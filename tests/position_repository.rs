@@ -0,0 +1,72 @@
+use chrono::NaiveDate;
+use nomina::{
+    domain::position::{Position, PositionAssignment},
+    infrastructure::{
+        position_repository::SurrealPositionRepository,
+        surreal::{self, SurrealConfig},
+    },
+    services::position::PositionRepository,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn assignment_history_round_trips_through_the_database() {
+    let database = surreal::connect(&SurrealConfig::embedded())
+        .await
+        .expect("embedded database");
+    let repository = SurrealPositionRepository::new(database);
+
+    let (former, current) = (Uuid::new_v4(), Uuid::new_v4());
+    let mut position = Position::new(
+        Uuid::new_v4(),
+        "Analyst",
+        Uuid::new_v4(),
+        1800.0,
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+    );
+    position.employee_id = Some(current);
+    position.history = vec![
+        PositionAssignment {
+            employee_id: former,
+            start_date: NaiveDate::from_ymd_opt(2020, 1, 6).expect("date"),
+            end_date: NaiveDate::from_ymd_opt(2023, 3, 31),
+        },
+        PositionAssignment {
+            employee_id: current,
+            start_date: NaiveDate::from_ymd_opt(2023, 4, 3).expect("date"),
+            end_date: None,
+        },
+    ];
+
+    let created = repository.insert(position.clone()).await.expect("insert");
+    assert_eq!(created, position);
+    let fetched = repository
+        .fetch(position.id)
+        .await
+        .expect("fetch")
+        .expect("position");
+    assert_eq!(fetched, position);
+
+    position.employee_id = None;
+    position.history[1].end_date = NaiveDate::from_ymd_opt(2024, 8, 30);
+    let updated = repository
+        .update(position.clone())
+        .await
+        .expect("update")
+        .expect("position");
+    assert_eq!(updated, position);
+
+    let held_by_former = repository
+        .fetch_by_assignee(former)
+        .await
+        .expect("fetch by assignee");
+    assert_eq!(held_by_former, vec![position]);
+    assert!(
+        repository
+            .fetch_by_assignee(Uuid::new_v4())
+            .await
+            .expect("fetch by assignee")
+            .is_empty()
+    );
+}
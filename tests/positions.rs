@@ -0,0 +1,357 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    payload: Option<Value>,
+) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match payload {
+        Some(payload) => builder
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("request");
+
+    let response = app.clone().oneshot(request).await.expect("response");
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+struct Fixture {
+    division_uri: String,
+    job_id: String,
+    employee_ids: Vec<String>,
+}
+
+/// Creates a division with two employees and returns its URI alongside their ids.
+async fn setup(app: &Router) -> Fixture {
+    let (_, organization) = send(
+        app,
+        "POST",
+        "/organizations",
+        Some(json!({"name": "Positions Org"})),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        Some(json!({"name": "Main", "description": "Main payroll"})),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        Some(json!({"name": "Seat Bank"})),
+    )
+    .await;
+    let (_, job) = send(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        Some(json!({"job_title": "Clerk", "salary": 1000.0})),
+    )
+    .await;
+    let (_, division) = send(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        Some(json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"})),
+    )
+    .await;
+    let division_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{}",
+        division["id"].as_str().unwrap()
+    );
+
+    let mut employee_ids = Vec::new();
+    for id_number in ["POS-1", "POS-2"] {
+        let (status, employee) = send(
+            app,
+            "POST",
+            &format!("{division_uri}/employees"),
+            Some(json!({
+                "id_number": id_number,
+                "last_name": "Seated",
+                "first_name": id_number,
+                "address": "4 Desk Ln",
+                "phone": "555-4000",
+                "place_of_birth": "Town",
+                "date_of_birth": "1988-08-08",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": "F",
+                "hire_date": "2020-01-01",
+                "clasification": "Full-time",
+                "job_id": job["id"],
+                "payment_instructions": [{
+                    "bank_id": bank["id"],
+                    "account": format!("ACCT-{id_number}"),
+                    "amount": { "percentage": 100.0 },
+                    "priority": 1
+                }],
+                "status": "Active",
+                "hours": 40
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        employee_ids.push(employee["id"].as_str().unwrap().to_string());
+    }
+
+    Fixture {
+        division_uri,
+        job_id: job["id"].as_str().unwrap().to_string(),
+        employee_ids,
+    }
+}
+
+#[tokio::test]
+async fn positions_track_vacancies_and_assignment_history() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let positions_uri = format!("{}/positions", fixture.division_uri);
+
+    let mut position_ids = Vec::new();
+    for title in ["Clerk II", "Clerk I"] {
+        let (status, position) = send(
+            &app,
+            "POST",
+            &positions_uri,
+            Some(json!({"title": title, "job_id": fixture.job_id, "budget": 14000.0})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(position["vacant"], true);
+        position_ids.push(position["id"].as_str().unwrap().to_string());
+    }
+    let position_uri = format!("{positions_uri}/{}", position_ids[0]);
+    let (alice, bob) = (&fixture.employee_ids[0], &fixture.employee_ids[1]);
+
+    let (status, position) = send(
+        &app,
+        "POST",
+        &format!("{position_uri}/assign"),
+        Some(json!({"employee_id": alice, "start_date": "2021-01-01"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(position["employee_id"], alice.as_str());
+    assert_eq!(position["vacant"], false);
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{position_uri}/assign"),
+        Some(json!({"employee_id": bob, "start_date": "2021-02-01"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{positions_uri}/{}/assign", position_ids[1]),
+        Some(json!({"employee_id": alice, "start_date": "2021-02-01"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, vacancies) =
+        send(&app, "GET", &format!("{positions_uri}?vacant=true"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(vacancies.as_array().unwrap().len(), 1);
+    assert_eq!(vacancies[0]["title"], "Clerk I");
+
+    let (status, _) = send(&app, "DELETE", &position_uri, None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, position) = send(
+        &app,
+        "POST",
+        &format!("{position_uri}/unassign"),
+        Some(json!({"end_date": "2022-06-30"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(position["employee_id"].is_null());
+
+    let (status, position) = send(
+        &app,
+        "POST",
+        &format!("{position_uri}/assign"),
+        Some(json!({"employee_id": bob, "start_date": "2022-07-01"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let history = position["history"].as_array().unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0]["employee_id"], alice.as_str());
+    assert_eq!(history[0]["end_date"], "2022-06-30");
+    assert_eq!(history[1]["employee_id"], bob.as_str());
+    assert!(history[1]["end_date"].is_null());
+}
+
+#[tokio::test]
+async fn rejects_invalid_positions_and_unknown_employees() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let positions_uri = format!("{}/positions", fixture.division_uri);
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &positions_uri,
+        Some(json!({"title": "Clerk", "job_id": fixture.job_id, "budget": -1.0})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &positions_uri,
+        Some(json!({"title": "Clerk", "job_id": uuid::Uuid::new_v4(), "budget": 1.0})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, position) = send(
+        &app,
+        "POST",
+        &positions_uri,
+        Some(json!({"title": "Clerk", "job_id": fixture.job_id, "budget": 1.0})),
+    )
+    .await;
+    let position_uri = format!("{positions_uri}/{}", position["id"].as_str().unwrap());
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{position_uri}/assign"),
+        Some(json!({"employee_id": uuid::Uuid::new_v4(), "start_date": "2021-01-01"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{position_uri}/unassign"),
+        Some(json!({"end_date": "2021-01-01"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = send(&app, "DELETE", &position_uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn filling_positions_respects_division_headcount_budget() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let positions_uri = format!("{}/positions", fixture.division_uri);
+
+    let (status, _) = send(
+        &app,
+        "PUT",
+        &fixture.division_uri,
+        Some(json!({"headcount_budget": 1})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    for (index, employee_id) in fixture.employee_ids.iter().enumerate() {
+        let (_, position) = send(
+            &app,
+            "POST",
+            &positions_uri,
+            Some(
+                json!({"title": format!("Seat {index}"), "job_id": fixture.job_id, "budget": 1.0}),
+            ),
+        )
+        .await;
+        let (status, _) = send(
+            &app,
+            "POST",
+            &format!(
+                "{positions_uri}/{}/assign",
+                position["id"].as_str().unwrap()
+            ),
+            Some(json!({"employee_id": employee_id, "start_date": "2021-01-01"})),
+        )
+        .await;
+        let expected = if index == 0 {
+            StatusCode::OK
+        } else {
+            StatusCode::CONFLICT
+        };
+        assert_eq!(status, expected);
+    }
+}
+
+#[tokio::test]
+async fn terminating_an_employee_vacates_their_position() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let positions_uri = format!("{}/positions", fixture.division_uri);
+    let employee_id = &fixture.employee_ids[0];
+
+    let (_, position) = send(
+        &app,
+        "POST",
+        &positions_uri,
+        Some(json!({"title": "Clerk", "job_id": fixture.job_id, "budget": 1.0})),
+    )
+    .await;
+    let position_uri = format!("{positions_uri}/{}", position["id"].as_str().unwrap());
+    send(
+        &app,
+        "POST",
+        &format!("{position_uri}/assign"),
+        Some(json!({"employee_id": employee_id, "start_date": "2021-01-01"})),
+    )
+    .await;
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{}/employees/{employee_id}/terminate", fixture.division_uri),
+        Some(json!({"effective_date": "2022-12-31", "reason_code": "LAYOFF"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, position) = send(&app, "GET", &position_uri, None).await;
+    assert_eq!(position["vacant"], true);
+    assert_eq!(position["history"][0]["end_date"], "2022-12-31");
+}
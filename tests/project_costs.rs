@@ -0,0 +1,279 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates an employee without a schedule and returns its URI.
+async fn setup(app: &Router) -> String {
+    let (_, organization) = send_json(
+        app,
+        "POST",
+        "/organizations",
+        json!({"name": "Timesheet Org"}),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Timesheet Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let (status, employee) = send_json(
+        app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "TS-1",
+            "last_name": "Hourly",
+            "first_name": "Robin",
+            "address": "4 Beach Rd",
+            "phone": "555-0404",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1990-04-04",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2020-01-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-TS",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    format!("{employees_uri}/{}", employee["id"].as_str().unwrap())
+}
+
+/// Creates a second employee in the same division, e.g. to act as manager, and returns its id.
+async fn create_colleague(app: &Router, employee_uri: &str) -> String {
+    let (employees_uri, _) = employee_uri.rsplit_once('/').unwrap();
+    let (_, employee) = send_json(app, "GET", employee_uri, json!({})).await;
+    let (status, colleague) = send_json(
+        app,
+        "POST",
+        employees_uri,
+        json!({
+            "id_number": "TS-2",
+            "last_name": "Lead",
+            "first_name": "Sam",
+            "address": "5 Beach Rd",
+            "phone": "555-0405",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1985-05-05",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "M",
+            "hire_date": "2018-01-01",
+            "clasification": "Full-time",
+            "job_id": employee["job_id"],
+            "payment_instructions": [{
+                "bank_id": employee["payment_instructions"][0]["bank_id"],
+                "account": "ACC-TS2",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    colleague["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn project_hours_are_costed_once_paid() {
+    let app = support::test_router();
+    let employee_uri = setup(&app).await;
+    let organization_uri = employee_uri.split("/payrolls/").next().unwrap().to_string();
+    let (division_uri, _) = employee_uri.split_once("/employees/").unwrap();
+    let projects_uri = format!("{organization_uri}/projects");
+    let entries_uri = format!("{employee_uri}/timesheet-entries");
+    let costs_uri = format!("{organization_uri}/project-labor-costs?from=2024-03-01&to=2024-03-31");
+
+    let (status, acme) = send_json(
+        &app,
+        "POST",
+        &projects_uri,
+        json!({"code": " ACME-01 ", "name": "Acme rollout"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(acme["code"], "ACME-01");
+    assert_eq!(acme["active"], true);
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &projects_uri,
+        json!({"code": "acme-01", "name": "Duplicate"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (_, internal) = send_json(
+        &app,
+        "POST",
+        &projects_uri,
+        json!({"code": "INT", "name": "Internal"}),
+    )
+    .await;
+    let internal_uri = format!("{projects_uri}/{}", internal["id"].as_str().unwrap());
+    let (status, internal) = send_json(&app, "PUT", &internal_uri, json!({"active": false})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(internal["active"], false);
+
+    for (project_id, expected) in [
+        (json!(uuid::Uuid::new_v4()), StatusCode::NOT_FOUND),
+        (internal["id"].clone(), StatusCode::UNPROCESSABLE_ENTITY),
+    ] {
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &entries_uri,
+            json!({"date": "2024-03-04", "hours": 8.0, "project_id": project_id}),
+        )
+        .await;
+        assert_eq!(status, expected);
+    }
+
+    let (status, entry) = send_json(
+        &app,
+        "POST",
+        &entries_uri,
+        json!({"date": "2024-03-04", "hours": 8.0, "project_id": acme["id"]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(entry["project_id"], acme["id"]);
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &entries_uri,
+        json!({"date": "2024-03-05", "hours": 6.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // Hours only count once payroll would pay them.
+    let (status, report) = send_json(&app, "GET", &costs_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["projects"], json!([]));
+    assert_eq!(report["untagged_hours"], 0.0);
+
+    let manager_id = create_colleague(&app, &employee_uri).await;
+    send_json(
+        &app,
+        "PUT",
+        &format!("{division_uri}/manager"),
+        json!({"employee_id": manager_id}),
+    )
+    .await;
+    let (_, timesheet) = send_json(
+        &app,
+        "POST",
+        &format!("{employee_uri}/timesheets"),
+        json!({"period_start": "2024-03-01", "period_end": "2024-03-31"}),
+    )
+    .await;
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!(
+            "{employee_uri}/timesheets/{}/approve",
+            timesheet["id"].as_str().unwrap()
+        ),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // A 1000 monthly salary over 40 hours a week comes to 12000 / 2080 an hour.
+    let (_, report) = send_json(&app, "GET", &costs_uri, json!({})).await;
+    assert_eq!(report["projects"].as_array().unwrap().len(), 1);
+    assert_eq!(report["projects"][0]["code"], "ACME-01");
+    assert_eq!(report["projects"][0]["hours"], 8.0);
+    assert_eq!(report["projects"][0]["cost"], 46.15);
+    assert_eq!(report["untagged_hours"], 6.0);
+
+    let acme_uri = format!("{projects_uri}/{}", acme["id"].as_str().unwrap());
+    let (status, _) = send_json(&app, "DELETE", &acme_uri, json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(&app, "DELETE", &internal_uri, json!({})).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, projects) = send_json(&app, "GET", &projects_uri, json!({})).await;
+    assert_eq!(projects.as_array().unwrap().len(), 1);
+}
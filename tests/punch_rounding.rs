@@ -0,0 +1,186 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates an employee without a schedule and returns its URI.
+async fn setup(app: &Router) -> String {
+    let (_, organization) = send_json(
+        app,
+        "POST",
+        "/organizations",
+        json!({"name": "Timesheet Org"}),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Timesheet Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let (status, employee) = send_json(
+        app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "TS-1",
+            "last_name": "Hourly",
+            "first_name": "Robin",
+            "address": "4 Beach Rd",
+            "phone": "555-0404",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1990-04-04",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2020-01-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-TS",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    format!("{employees_uri}/{}", employee["id"].as_str().unwrap())
+}
+
+#[tokio::test]
+async fn imported_punches_are_rounded_with_grace_and_keep_raw_times() {
+    let app = support::test_router();
+    let employee_uri = setup(&app).await;
+    let (division_uri, _) = employee_uri.split_once("/employees/").unwrap();
+    let (payroll_uri, _) = division_uri.split_once("/divisions/").unwrap();
+    let policy_uri = format!("{payroll_uri}/punch-policy");
+
+    let (status, policy) = send_json(&app, "GET", &policy_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(policy["rounding_minutes"], Value::Null);
+    for invalid in [
+        json!({"rounding_minutes": 7}),
+        json!({"rounding_minutes": 15, "grace_minutes": 15}),
+        json!({"rounding_minutes": null, "grace_minutes": 5}),
+    ] {
+        let (status, _) = send_json(&app, "PUT", &policy_uri, invalid.clone()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{invalid}");
+    }
+    let (status, policy) = send_json(
+        &app,
+        "PUT",
+        &policy_uri,
+        json!({"rounding_minutes": 15, "grace_minutes": 10}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(policy["grace_minutes"], 10);
+
+    // Within grace the punches count from the quarter hour; beyond it they round normally.
+    let (status, report) = send_json(
+        &app,
+        "POST",
+        &format!("{division_uri}/attendance/import"),
+        json!([
+            {"id_number": "TS-1", "date": "2024-03-04", "clock_in": "08:08", "clock_out": "16:52"},
+            {"id_number": "TS-1", "date": "2024-03-05", "clock_in": "08:12", "clock_out": "16:44"},
+            {"id_number": "TS-1", "date": "2024-03-06", "clock_in": "08:01", "clock_out": "08:04"}
+        ]),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["imported"], 2);
+    assert_eq!(
+        report["rows"][2]["error"],
+        "the shift is shorter than the punch rounding"
+    );
+
+    let (status, entries) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/timesheet-entries?from=2024-03-04&to=2024-03-05"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(entries[0]["hours"], 9.0);
+    assert_eq!(entries[0]["note"], "Time clock 08:08-16:52");
+    assert_eq!(entries[0]["punch"]["clock_in"], "08:08:00");
+    assert_eq!(entries[0]["punch"]["adjusted_clock_in"], "08:00:00");
+    assert_eq!(entries[0]["punch"]["clock_out"], "16:52:00");
+    assert_eq!(entries[0]["punch"]["adjusted_clock_out"], "17:00:00");
+    assert_eq!(entries[1]["hours"], 8.5);
+    assert_eq!(entries[1]["punch"]["adjusted_clock_in"], "08:15:00");
+    assert_eq!(entries[1]["punch"]["adjusted_clock_out"], "16:45:00");
+}
@@ -0,0 +1,133 @@
+use nomina::{
+    infrastructure::{
+        division_repository::SurrealDivisionRepository,
+        payroll_repository::SurrealPayrollRepository,
+        surreal::{self, RetryPolicy, SurrealConfig, SurrealCredentials},
+    },
+    services::{division::DivisionRepository, payroll::PayrollRepository},
+};
+use uuid::Uuid;
+
+#[test]
+fn read_replica_shares_the_primary_database_and_credentials() {
+    let primary = SurrealConfig {
+        url: "wss://primary.example.com".to_string(),
+        read_url: Some("wss://replica.example.com".to_string()),
+        namespace: "acme".to_string(),
+        database: "payroll".to_string(),
+        credentials: Some(SurrealCredentials {
+            username: "root".to_string(),
+            password: "secret".to_string(),
+        }),
+        retry: RetryPolicy::default(),
+    };
+
+    let replica = primary.replica().expect("replica configured");
+
+    assert_eq!(replica.url, "wss://replica.example.com");
+    assert_eq!(replica.read_url, None);
+    assert_eq!(replica.namespace, "acme");
+    assert_eq!(replica.database, "payroll");
+    assert_eq!(
+        replica.credentials.map(|credentials| credentials.username),
+        Some("root".to_string())
+    );
+    assert!(SurrealConfig::embedded().replica().is_none());
+}
+
+async fn insert(repository: &impl DivisionRepository, id: Uuid, payroll_id: Uuid, name: &str) {
+    repository
+        .insert(
+            id,
+            name.to_string(),
+            "Division".to_string(),
+            "BC-1".to_string(),
+            payroll_id,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("insert");
+}
+
+#[tokio::test]
+async fn lookups_are_served_by_the_reader_and_writes_by_the_primary() {
+    // Two embedded databases that do not replicate, so each read shows where it went.
+    let config = SurrealConfig::embedded();
+    let primary = surreal::connect(&config).await.expect("primary");
+    let reader = surreal::connect_reader(&config).await.expect("reader");
+    let repository = SurrealDivisionRepository::new(primary).with_reader(reader.clone());
+    let replica = SurrealDivisionRepository::new(reader);
+
+    let (id, payroll_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+    insert(&repository, id, payroll_id, "On the primary").await;
+    assert!(repository.fetch(id).await.expect("fetch").is_none());
+
+    insert(&replica, id, payroll_id, "On the replica").await;
+    let fetched = repository
+        .fetch(id)
+        .await
+        .expect("fetch")
+        .expect("division");
+    assert_eq!(fetched.name, "On the replica");
+    let listed = repository
+        .fetch_by_payroll(payroll_id)
+        .await
+        .expect("fetch by payroll");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].name, "On the replica");
+
+    // Updates go to the primary, so the reader keeps its own copy.
+    repository
+        .update(
+            id,
+            Some("Renamed".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("update");
+    let fetched = repository
+        .fetch(id)
+        .await
+        .expect("fetch")
+        .expect("division");
+    assert_eq!(fetched.name, "On the replica");
+}
+
+#[tokio::test]
+async fn cached_lookups_are_served_by_the_primary() {
+    let config = SurrealConfig::embedded();
+    let primary = surreal::connect(&config).await.expect("primary");
+    let reader = surreal::connect_reader(&config).await.expect("reader");
+    let repository = SurrealPayrollRepository::new(primary).with_reader(reader);
+
+    let (id, organization_id) = (Uuid::new_v4(), Uuid::new_v4());
+    repository
+        .insert(
+            id,
+            "Monthly".to_string(),
+            "Monthly".to_string(),
+            organization_id,
+            false,
+            None,
+        )
+        .await
+        .expect("insert");
+
+    // The cache would otherwise hold on to whatever the replica had not caught up with yet.
+    let fetched = repository.fetch(id).await.expect("fetch").expect("payroll");
+    assert_eq!(fetched.description, "Monthly");
+    assert!(
+        repository
+            .fetch_by_organization(organization_id)
+            .await
+            .expect("fetch by organization")
+            .is_empty()
+    );
+}
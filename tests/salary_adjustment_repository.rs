@@ -0,0 +1,66 @@
+use chrono::NaiveDate;
+use nomina::{
+    domain::{
+        job::PayBasis,
+        salary_adjustment::{SalaryAdjustmentBatch, SalaryAdjustmentStatus, SalaryChange},
+    },
+    infrastructure::{
+        salary_adjustment_repository::SurrealSalaryAdjustmentRepository,
+        surreal::{self, SurrealConfig},
+    },
+    services::salary_adjustment::SalaryAdjustmentRepository,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn salary_changes_round_trip_through_the_database() {
+    let database = surreal::connect(&SurrealConfig::embedded())
+        .await
+        .expect("embedded database");
+    let repository = SurrealSalaryAdjustmentRepository::new(database);
+
+    let payroll_id = Uuid::new_v4();
+    let effective_date = NaiveDate::from_ymd_opt(2025, 1, 1).expect("date");
+    let mut batch = SalaryAdjustmentBatch::new(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        payroll_id,
+        5.0,
+        effective_date,
+        vec![SalaryChange {
+            job_id: Uuid::new_v4(),
+            job_title: "Analyst".to_string(),
+            pay_basis: PayBasis::Monthly,
+            from_salary: 1000.0,
+            to_salary: 1050.0,
+            headcount: 3,
+            monthly_cost_before: 3000.0,
+            monthly_cost_after: 3150.0,
+        }],
+    );
+
+    let created = repository.insert(batch.clone()).await.expect("insert");
+    assert_eq!(created, batch);
+    let listed = repository
+        .fetch_by_payroll(payroll_id)
+        .await
+        .expect("fetch by payroll");
+    assert_eq!(listed, vec![batch.clone()]);
+
+    batch.status = SalaryAdjustmentStatus::Approved;
+    repository
+        .update(batch.clone())
+        .await
+        .expect("update")
+        .expect("batch");
+    let due = repository
+        .fetch_due(effective_date)
+        .await
+        .expect("fetch due");
+    assert_eq!(due, vec![batch]);
+    let early = repository
+        .fetch_due(effective_date.pred_opt().expect("date"))
+        .await
+        .expect("fetch due");
+    assert!(early.is_empty());
+}
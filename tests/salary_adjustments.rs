@@ -0,0 +1,270 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    payload: Option<Value>,
+) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match payload {
+        Some(payload) => builder
+            .header("content-type", "application/json")
+            .body(Body::from(payload.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("request");
+
+    let response = app.clone().oneshot(request).await.expect("response");
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+struct Fixture {
+    payroll_uri: String,
+    office_id: String,
+    clerk_id: String,
+    driver_id: String,
+    manager_id: String,
+}
+
+/// Two monthly clerks in an office, one hourly driver in a depot and a vacant manager job
+/// capped just above its salary.
+async fn setup(app: &Router) -> Fixture {
+    let (_, organization) = send(
+        app,
+        "POST",
+        "/organizations",
+        Some(json!({"name": "Adjustments Org"})),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+    let (_, payroll) = send(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        Some(json!({"name": "Main", "description": "Main payroll"})),
+    )
+    .await;
+    let payroll_uri = format!(
+        "/organizations/{organization_id}/payrolls/{}",
+        payroll["id"].as_str().unwrap()
+    );
+    let (_, bank) = send(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        Some(json!({"name": "Raise Bank"})),
+    )
+    .await;
+
+    let mut job_ids = Vec::new();
+    for job in [
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+        json!({"job_title": "Driver", "salary": 20.0, "pay_basis": "hourly"}),
+        json!({"job_title": "Manager", "salary": 5000.0, "salary_max": 5100.0}),
+    ] {
+        let (status, job) = send(app, "POST", &format!("{payroll_uri}/jobs"), Some(job)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        job_ids.push(job["id"].as_str().unwrap().to_string());
+    }
+
+    let mut division_ids = Vec::new();
+    for (name, budget_code) in [("Office", "OFF-1"), ("Depot", "DEP-1")] {
+        let (status, division) = send(
+            app,
+            "POST",
+            &format!("{payroll_uri}/divisions"),
+            Some(json!({"name": name, "description": name, "budget_code": budget_code})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        division_ids.push(division["id"].as_str().unwrap().to_string());
+    }
+
+    for (id_number, division_id, job_id) in [
+        ("ADJ-1", &division_ids[0], &job_ids[0]),
+        ("ADJ-2", &division_ids[0], &job_ids[0]),
+        ("ADJ-3", &division_ids[1], &job_ids[1]),
+    ] {
+        let (status, _) = send(
+            app,
+            "POST",
+            &format!("{payroll_uri}/divisions/{division_id}/employees"),
+            Some(json!({
+                "id_number": id_number,
+                "last_name": "Raised",
+                "first_name": id_number,
+                "address": "1 Pay Rise Rd",
+                "phone": "555-1300",
+                "place_of_birth": "Town",
+                "date_of_birth": "1990-01-01",
+                "nationality": "Testland",
+                "marital_status": "Single",
+                "gender": "F",
+                "hire_date": "2020-01-01",
+                "clasification": "Full-time",
+                "job_id": job_id,
+                "payment_instructions": [{
+                    "bank_id": bank["id"],
+                    "account": format!("ACCT-{id_number}"),
+                    "amount": { "percentage": 100.0 },
+                    "priority": 1
+                }],
+                "status": "Active",
+                "hours": 40
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    Fixture {
+        payroll_uri,
+        office_id: division_ids[0].clone(),
+        clerk_id: job_ids[0].clone(),
+        driver_id: job_ids[1].clone(),
+        manager_id: job_ids[2].clone(),
+    }
+}
+
+#[tokio::test]
+async fn adjustments_preview_costs_and_apply_once_approved_and_due() {
+    let app = support::test_router();
+    let fixture = setup(&app).await;
+    let adjustments_uri = format!("{}/salary-adjustments", fixture.payroll_uri);
+    let today = chrono::Utc::now().date_naive();
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &adjustments_uri,
+        Some(json!({"percentage": 0.0, "effective_date": today.to_string()})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &adjustments_uri,
+        Some(json!({
+            "percentage": 3.0,
+            "effective_date": today.to_string(),
+            "job_ids": [fixture.manager_id]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, office_batch) = send(
+        &app,
+        "POST",
+        &adjustments_uri,
+        Some(json!({
+            "percentage": 3.0,
+            "effective_date": today.to_string(),
+            "division_ids": [fixture.office_id]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(office_batch["status"], "pending");
+    assert_eq!(office_batch["changes"].as_array().unwrap().len(), 1);
+    let change = &office_batch["changes"][0];
+    assert_eq!(change["job_id"], fixture.clerk_id.as_str());
+    assert_eq!(change["to_salary"], 1030.0);
+    assert_eq!(change["headcount"], 2);
+    assert_eq!(office_batch["totals"]["monthly_cost_before"], 2000.0);
+    assert_eq!(office_batch["totals"]["monthly_difference"], 60.0);
+
+    let future = today + chrono::Duration::days(30);
+    let (status, driver_batch) = send(
+        &app,
+        "POST",
+        &adjustments_uri,
+        Some(json!({
+            "percentage": 3.0,
+            "effective_date": future.to_string(),
+            "job_ids": [fixture.driver_id]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(driver_batch["changes"][0]["to_salary"], 20.6);
+    assert_eq!(driver_batch["totals"]["monthly_cost_before"], 3466.67);
+    assert_eq!(driver_batch["totals"]["monthly_cost_after"], 3570.67);
+
+    let office_uri = format!("{adjustments_uri}/{}", office_batch["id"].as_str().unwrap());
+    let (status, approved) = send(
+        &app,
+        "POST",
+        &format!("{office_uri}/approve"),
+        Some(json!({"note": "annual COLA"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "applied");
+    assert_eq!(approved["decision_note"], "annual COLA");
+    let (_, clerk) = send(
+        &app,
+        "GET",
+        &format!("{}/jobs/{}", fixture.payroll_uri, fixture.clerk_id),
+        None,
+    )
+    .await;
+    assert_eq!(clerk["salary"], 1030.0);
+
+    let (status, _) = send(
+        &app,
+        "POST",
+        &format!("{office_uri}/reject"),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let driver_uri = format!("{adjustments_uri}/{}", driver_batch["id"].as_str().unwrap());
+    let (status, approved) = send(
+        &app,
+        "POST",
+        &format!("{driver_uri}/approve"),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "approved");
+    assert!(approved["applied_at"].is_null());
+    let (_, driver) = send(
+        &app,
+        "GET",
+        &format!("{}/jobs/{}", fixture.payroll_uri, fixture.driver_id),
+        None,
+    )
+    .await;
+    assert_eq!(driver["salary"], 20.0);
+
+    let (status, batches) = send(&app, "GET", &adjustments_uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(batches.as_array().unwrap().len(), 2);
+}
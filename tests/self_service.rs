@@ -0,0 +1,184 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates an employee without a schedule and returns its URI.
+async fn setup(app: &Router) -> String {
+    let (_, organization) = send_json(
+        app,
+        "POST",
+        "/organizations",
+        json!({"name": "Self-Service Org"}),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Self-Service Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let (status, employee) = send_json(
+        app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "SS-1",
+            "last_name": "Hourly",
+            "first_name": "Robin",
+            "address": "4 Beach Rd",
+            "phone": "555-0404",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1990-04-04",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2020-01-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-SS",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    format!("{employees_uri}/{}", employee["id"].as_str().unwrap())
+}
+
+async fn get_me(app: &Router, secret: Option<&str>) -> (StatusCode, Value) {
+    let mut request = Request::builder().uri("/me");
+    if let Some(secret) = secret {
+        request = request.header("authorization", format!("Bearer {secret}"));
+    }
+    let response = app
+        .clone()
+        .oneshot(request.body(Body::empty()).expect("request"))
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let body = read_json(response.into_body().collect().await.unwrap().to_bytes());
+    (status, body)
+}
+
+#[tokio::test]
+async fn tokens_grant_read_access_until_revoked() {
+    let app = support::test_router();
+    let employee_uri = setup(&app).await;
+    let tokens_uri = format!("{employee_uri}/self-service-tokens");
+
+    let (status, _) = send_json(&app, "POST", &tokens_uri, json!({"expires_in_days": 0})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, issued) = send_json(
+        &app,
+        "POST",
+        &tokens_uri,
+        json!({"label": " Portal ", "expires_in_days": 30}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(issued["label"], "Portal");
+    assert!(issued.get("secret_hash").is_none());
+    let secret = issued["secret"].as_str().unwrap();
+
+    let (status, me) = get_me(&app, Some(secret)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(me["id_number"], "SS-1");
+
+    let (status, _) = get_me(&app, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = get_me(&app, Some("not-a-token")).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = send_json(
+        &app,
+        "DELETE",
+        &format!("{tokens_uri}/{}", issued["id"].as_str().unwrap()),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = get_me(&app, Some(secret)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, tokens) = send_json(&app, "GET", &tokens_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(tokens.as_array().unwrap().len(), 1);
+    assert!(tokens[0]["revoked_at"].is_string());
+    assert!(tokens[0]["last_used_at"].is_string());
+    assert!(tokens[0].get("secret").is_none());
+}
@@ -0,0 +1,120 @@
+use chrono::Utc;
+use nomina::{
+    domain::self_service::SelfServiceToken,
+    infrastructure::{
+        self_service_repository::SurrealSelfServiceTokenRepository,
+        surreal::{self, SurrealConfig},
+    },
+    services::self_service::SelfServiceTokenRepository,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn tokens_are_found_by_secret_hash_and_employee() {
+    let database = surreal::connect(&SurrealConfig::embedded())
+        .await
+        .expect("embedded database");
+    let repository = SurrealSelfServiceTokenRepository::new(database);
+    let (organization_id, employee_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let kiosk = SelfServiceToken::new(
+        Uuid::new_v4(),
+        organization_id,
+        employee_id,
+        Some("kiosk".to_string()),
+        "hash-kiosk".to_string(),
+        None,
+    );
+    let phone = SelfServiceToken::new(
+        Uuid::new_v4(),
+        organization_id,
+        employee_id,
+        Some("phone".to_string()),
+        "hash-phone".to_string(),
+        None,
+    );
+    let other = SelfServiceToken::new(
+        Uuid::new_v4(),
+        organization_id,
+        Uuid::new_v4(),
+        None,
+        "hash-other".to_string(),
+        None,
+    );
+    for token in [&kiosk, &phone, &other] {
+        repository.insert(token.clone()).await.expect("insert");
+    }
+
+    let found = repository
+        .fetch_by_secret_hash("hash-phone")
+        .await
+        .expect("fetch by secret hash")
+        .expect("token");
+    assert_eq!(found.id, phone.id);
+    assert!(
+        repository
+            .fetch_by_secret_hash("hash-missing")
+            .await
+            .expect("fetch by secret hash")
+            .is_none()
+    );
+
+    let mut held: Vec<Uuid> = repository
+        .fetch_by_employee(employee_id)
+        .await
+        .expect("fetch by employee")
+        .iter()
+        .map(|token| token.id)
+        .collect();
+    held.sort();
+    let mut expected = vec![kiosk.id, phone.id];
+    expected.sort();
+    assert_eq!(held, expected);
+}
+
+#[tokio::test]
+async fn touching_a_token_keeps_its_revocation() {
+    let database = surreal::connect(&SurrealConfig::embedded())
+        .await
+        .expect("embedded database");
+    let repository = SurrealSelfServiceTokenRepository::new(database);
+    let token = SelfServiceToken::new(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        None,
+        "hash-touch".to_string(),
+        None,
+    );
+    let token = repository.insert(token).await.expect("insert");
+
+    let used_at = Utc::now();
+    let touched = repository
+        .touch(token.id, used_at)
+        .await
+        .expect("touch")
+        .expect("token");
+    assert_eq!(
+        touched.last_used_at.map(|at| at.timestamp_millis()),
+        Some(used_at.timestamp_millis())
+    );
+    assert_eq!(touched.label, token.label);
+
+    let mut revoked = touched;
+    revoked.revoked_at = Some(Utc::now());
+    repository.update(revoked.clone()).await.expect("revoke");
+    assert!(
+        repository
+            .touch(token.id, Utc::now())
+            .await
+            .expect("touch")
+            .is_none()
+    );
+    let stored = repository
+        .fetch(token.id)
+        .await
+        .expect("fetch")
+        .expect("token");
+    assert!(stored.revoked_at.is_some());
+    assert_eq!(stored.last_used_at, revoked.last_used_at);
+}
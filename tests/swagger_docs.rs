@@ -37,6 +37,7 @@ async fn openapi_document_is_available() {
         "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}",
         "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees",
         "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}",
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees/{employee_id}/dependents",
     ] {
         assert!(
             body["paths"].get(path).is_some(),
@@ -44,3 +45,69 @@ async fn openapi_document_is_available() {
         );
     }
 }
+
+#[tokio::test]
+async fn postman_collection_is_generated_from_the_document() {
+    let app = support::test_router();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api-docs/postman.json?base_url=https://payroll.example.com/")
+                .body(Body::empty())
+                .expect("request body"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()["content-disposition"]
+            .to_str()
+            .unwrap()
+            .starts_with("attachment")
+    );
+
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).expect("valid json");
+
+    assert_eq!(body["info"]["name"], env!("CARGO_PKG_NAME"));
+    assert_eq!(
+        body["info"]["schema"],
+        "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+    );
+
+    let variables = body["variable"].as_array().unwrap();
+    assert_eq!(variables[0]["key"], "baseUrl");
+    assert_eq!(variables[0]["value"], "https://payroll.example.com");
+    for key in ["organization_id", "payroll_id", "division_id", "job_id"] {
+        assert!(
+            variables.iter().any(|variable| variable["key"] == key),
+            "missing variable {key}"
+        );
+    }
+
+    let employees = body["item"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|folder| folder["name"] == "Employees")
+        .expect("employees folder");
+    let create = employees["item"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["name"] == "create_employee")
+        .expect("create employee request");
+    assert_eq!(create["request"]["method"], "POST");
+    assert_eq!(
+        create["request"]["url"]["raw"],
+        "{{baseUrl}}/organizations/:organization_id/payrolls/:payroll_id/divisions/:division_id/employees"
+    );
+
+    let example: serde_json::Value =
+        serde_json::from_str(create["request"]["body"]["raw"].as_str().unwrap()).unwrap();
+    assert_eq!(example["job_id"], "{{job_id}}");
+    assert_eq!(example["hire_date"], "2024-01-31");
+    assert_eq!(example["payment_instructions"][0]["bank_id"], "{{bank_id}}");
+}
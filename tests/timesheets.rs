@@ -0,0 +1,399 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates an employee without a schedule and returns its URI.
+async fn setup(app: &Router) -> String {
+    let (_, organization) = send_json(
+        app,
+        "POST",
+        "/organizations",
+        json!({"name": "Timesheet Org"}),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Timesheet Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let (status, employee) = send_json(
+        app,
+        "POST",
+        &employees_uri,
+        json!({
+            "id_number": "TS-1",
+            "last_name": "Hourly",
+            "first_name": "Robin",
+            "address": "4 Beach Rd",
+            "phone": "555-0404",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1990-04-04",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "F",
+            "hire_date": "2020-01-01",
+            "clasification": "Full-time",
+            "job_id": job["id"],
+            "payment_instructions": [{
+                "bank_id": bank["id"],
+                "account": "ACC-TS",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    format!("{employees_uri}/{}", employee["id"].as_str().unwrap())
+}
+
+/// Creates a second employee in the same division, e.g. to act as manager, and returns its id.
+async fn create_colleague(app: &Router, employee_uri: &str) -> String {
+    let (employees_uri, _) = employee_uri.rsplit_once('/').unwrap();
+    let (_, employee) = send_json(app, "GET", employee_uri, json!({})).await;
+    let (status, colleague) = send_json(
+        app,
+        "POST",
+        employees_uri,
+        json!({
+            "id_number": "TS-2",
+            "last_name": "Lead",
+            "first_name": "Sam",
+            "address": "5 Beach Rd",
+            "phone": "555-0405",
+            "place_of_birth": "Hometown",
+            "date_of_birth": "1985-05-05",
+            "nationality": "Exampleland",
+            "marital_status": "Single",
+            "gender": "M",
+            "hire_date": "2018-01-01",
+            "clasification": "Full-time",
+            "job_id": employee["job_id"],
+            "payment_instructions": [{
+                "bank_id": employee["payment_instructions"][0]["bank_id"],
+                "account": "ACC-TS2",
+                "amount": { "percentage": 100.0 },
+                "priority": 1
+            }],
+            "status": "Active",
+            "hours": 40
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    colleague["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn approved_entries_are_locked_and_days_are_capped() {
+    let app = support::test_router();
+    let employee_uri = setup(&app).await;
+    let entries_uri = format!("{employee_uri}/timesheet-entries");
+
+    let (status, entry) = send_json(
+        &app,
+        "POST",
+        &entries_uri,
+        json!({"date": "2024-03-04", "hours": 8.0, "cost_code": " PRJ-7 "}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(entry["status"], "submitted");
+    assert_eq!(entry["cost_code"], "PRJ-7");
+    let entry_uri = format!("{entries_uri}/{}", entry["id"].as_str().unwrap());
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &entries_uri,
+        json!({"date": "2024-03-04", "hours": 17.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &entries_uri,
+        json!({"date": "2019-12-31", "hours": 4.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, other) = send_json(
+        &app,
+        "POST",
+        &entries_uri,
+        json!({"date": "2024-03-05", "hours": 6.5}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let other_uri = format!("{entries_uri}/{}", other["id"].as_str().unwrap());
+
+    let (status, approved) =
+        send_json(&app, "POST", &format!("{entry_uri}/approve"), json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "approved");
+
+    let (status, _) = send_json(&app, "PUT", &entry_uri, json!({"hours": 9.0})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(&app, "DELETE", &entry_uri, json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, rejected) = send_json(
+        &app,
+        "POST",
+        &format!("{other_uri}/reject"),
+        json!({"note": "Wrong project"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(rejected["review_note"], "Wrong project");
+
+    let (status, resubmitted) =
+        send_json(&app, "PUT", &other_uri, json!({"cost_code": "PRJ-8"})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(resubmitted["status"], "submitted");
+    assert_eq!(resubmitted["review_note"], Value::Null);
+
+    let (status, listed) = send_json(
+        &app,
+        "GET",
+        &format!("{entries_uri}?from=2024-03-05&to=2024-03-31"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(listed.as_array().unwrap().len(), 1);
+    assert_eq!(listed[0]["cost_code"], "PRJ-8");
+}
+
+#[tokio::test]
+async fn submitted_timesheets_need_manager_approval_before_payroll() {
+    let app = support::test_router();
+    let employee_uri = setup(&app).await;
+    let (employees_uri, employee_id) = employee_uri.rsplit_once('/').unwrap();
+    let (division_uri, _) = employees_uri.rsplit_once("/employees").unwrap();
+    let (payroll_uri, _) = division_uri.split_once("/divisions/").unwrap();
+    let entries_uri = format!("{employee_uri}/timesheet-entries");
+    let timesheets_uri = format!("{employee_uri}/timesheets");
+    let report_uri = format!("{payroll_uri}/timesheets/unapproved?from=2024-03-01&to=2024-03-31");
+
+    let manager_id = create_colleague(&app, &employee_uri).await;
+
+    let mut entry_uris = Vec::new();
+    for (date, hours) in [
+        ("2024-03-04", 8.0),
+        ("2024-03-05", 6.0),
+        ("2024-04-01", 4.0),
+    ] {
+        let (status, entry) = send_json(
+            &app,
+            "POST",
+            &entries_uri,
+            json!({"date": date, "hours": hours}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        entry_uris.push(format!("{entries_uri}/{}", entry["id"].as_str().unwrap()));
+    }
+
+    let (status, report) = send_json(&app, "GET", &report_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["ready"], false);
+    assert_eq!(report["blocking"][0]["employee_id"], employee_id);
+    assert_eq!(report["blocking"][0]["reason"], "not_submitted");
+    assert_eq!(report["blocking"][0]["hours"], 14.0);
+
+    let march = json!({"period_start": "2024-03-01", "period_end": "2024-03-31"});
+    let (status, timesheet) = send_json(&app, "POST", &timesheets_uri, march.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(timesheet["status"], "submitted");
+    assert_eq!(timesheet["hours"], 14.0);
+    let timesheet_uri = format!("{timesheets_uri}/{}", timesheet["id"].as_str().unwrap());
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &timesheets_uri,
+        json!({"period_start": "2024-03-15", "period_end": "2024-04-15"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &entries_uri,
+        json!({"date": "2024-03-06", "hours": 8.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = send_json(&app, "PUT", &entry_uris[0], json!({"hours": 7.5})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (_, report) = send_json(&app, "GET", &report_uri, json!({})).await;
+    assert_eq!(report["blocking"][0]["reason"], "awaiting_approval");
+    assert_eq!(report["blocking"][0]["period_id"], timesheet["id"]);
+
+    for approver_id in [employee_id.to_string(), manager_id.to_string()] {
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("{timesheet_uri}/approve"),
+            json!({"approver_id": approver_id}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{division_uri}/manager"),
+        json!({"employee_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, rejected) = send_json(
+        &app,
+        "POST",
+        &format!("{timesheet_uri}/reject"),
+        json!({"approver_id": manager_id, "note": "4 March was a half day"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(rejected["status"], "rejected");
+    assert_eq!(rejected["reviewed_by"], manager_id.as_str());
+
+    let (status, _) = send_json(&app, "PUT", &entry_uris[0], json!({"hours": 4.0})).await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, report) = send_json(&app, "GET", &report_uri, json!({})).await;
+    assert_eq!(report["blocking"][0]["reason"], "rejected");
+
+    let (status, resubmitted) = send_json(&app, "POST", &timesheets_uri, march).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(resubmitted["hours"], 10.0);
+    let resubmitted_uri = format!("{timesheets_uri}/{}", resubmitted["id"].as_str().unwrap());
+    let (status, approved) = send_json(
+        &app,
+        "POST",
+        &format!("{resubmitted_uri}/approve"),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "approved");
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &format!("{resubmitted_uri}/approve"),
+        json!({"approver_id": manager_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (_, entries) = send_json(
+        &app,
+        "GET",
+        &format!("{entries_uri}?from=2024-03-01&to=2024-03-31"),
+        json!({}),
+    )
+    .await;
+    assert!(
+        entries
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|entry| entry["status"] == "approved")
+    );
+
+    let (_, report) = send_json(&app, "GET", &report_uri, json!({})).await;
+    assert_eq!(report["ready"], true);
+    assert_eq!(report["blocking"], json!([]));
+    let (_, report) = send_json(
+        &app,
+        "GET",
+        &format!("{payroll_uri}/timesheets/unapproved?from=2024-03-01&to=2024-04-30"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(report["ready"], false);
+    assert_eq!(report["blocking"].as_array().unwrap().len(), 1);
+    assert_eq!(report["blocking"][0]["hours"], 4.0);
+
+    let (status, timesheets) = send_json(&app, "GET", &timesheets_uri, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(timesheets.as_array().unwrap().len(), 2);
+}
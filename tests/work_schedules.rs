@@ -0,0 +1,231 @@
+#[path = "support/mod.rs"]
+mod support;
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+fn read_json(body: Bytes) -> Value {
+    serde_json::from_slice(&body).expect("json")
+}
+
+async fn send_json(app: &Router, method: &str, uri: &str, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else {
+        read_json(bytes)
+    };
+    (status, body)
+}
+
+/// Creates the organization hierarchy and returns `(organization_id, employees_uri, employee_payload)`.
+async fn setup(app: &Router) -> (String, String, Value) {
+    let (_, organization) = send_json(
+        app,
+        "POST",
+        "/organizations",
+        json!({"name": "Schedule Org"}),
+    )
+    .await;
+    let organization_id = organization["id"].as_str().unwrap().to_string();
+
+    let (_, payroll) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls"),
+        json!({"name": "Main", "description": "Main payroll"}),
+    )
+    .await;
+    let payroll_id = payroll["id"].as_str().unwrap().to_string();
+
+    let (_, bank) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/banks"),
+        json!({"name": "Schedule Bank"}),
+    )
+    .await;
+    let (_, job) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/jobs"),
+        json!({"job_title": "Clerk", "salary": 1000.0}),
+    )
+    .await;
+    let (_, division) = send_json(
+        app,
+        "POST",
+        &format!("/organizations/{organization_id}/payrolls/{payroll_id}/divisions"),
+        json!({"name": "Admin", "description": "Administration", "budget_code": "ADM-1"}),
+    )
+    .await;
+    let division_id = division["id"].as_str().unwrap();
+
+    let employees_uri = format!(
+        "/organizations/{organization_id}/payrolls/{payroll_id}/divisions/{division_id}/employees"
+    );
+    let employee = json!({
+        "id_number": "WS-1",
+        "last_name": "Shift",
+        "first_name": "Casey",
+        "address": "2 Rota Rd",
+        "phone": "555-0202",
+        "place_of_birth": "Hometown",
+        "date_of_birth": "1988-08-08",
+        "nationality": "Exampleland",
+        "marital_status": "Single",
+        "gender": "M",
+        "hire_date": "2019-01-01",
+        "clasification": "Full-time",
+        "job_id": job["id"],
+        "payment_instructions": [{
+            "bank_id": bank["id"],
+            "account": "ACC-WS",
+            "amount": { "percentage": 100.0 },
+            "priority": 1
+        }],
+        "status": "Active",
+        "hours": 40
+    });
+
+    (organization_id, employees_uri, employee)
+}
+
+#[tokio::test]
+async fn schedules_drive_expected_hours_and_overtime() {
+    let app = support::test_router();
+    let (organization_id, employees_uri, mut employee) = setup(&app).await;
+    let schedules_uri = format!("/organizations/{organization_id}/work-schedules");
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &schedules_uri,
+        json!({
+            "name": "Broken",
+            "days": [
+                {"weekday": "Mon", "hours": 8.0},
+                {"weekday": "Monday", "hours": 4.0}
+            ]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, schedule) = send_json(
+        &app,
+        "POST",
+        &schedules_uri,
+        json!({
+            "name": "Compressed week",
+            "days": [
+                {"weekday": "Thu", "hours": 10.0},
+                {"weekday": "Mon", "hours": 10.0},
+                {"weekday": "Tue", "hours": 10.0},
+                {"weekday": "Wed", "hours": 10.0}
+            ],
+            "daily_overtime_after": 10.0
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(schedule["weekly_hours"], 40.0);
+    assert_eq!(schedule["days"][0]["weekday"], "Mon");
+
+    let (status, _) = send_json(
+        &app,
+        "POST",
+        &schedules_uri,
+        json!({"name": "Compressed week", "days": [{"weekday": "Fri", "hours": 8.0}]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, created) = send_json(&app, "POST", &employees_uri, employee.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let employee_uri = format!("{employees_uri}/{}", created["id"].as_str().unwrap());
+
+    let (status, expectation) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/expected-hours"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(expectation["schedule_id"].is_null());
+    assert_eq!(expectation["weekly_hours"], 40.0);
+
+    let schedule_id = schedule["id"].as_str().unwrap();
+    let (status, updated) = send_json(
+        &app,
+        "PUT",
+        &employee_uri,
+        json!({"schedule_id": schedule_id}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(updated["schedule_id"], schedule_id);
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &format!("{schedules_uri}/{schedule_id}"),
+        json!({"weekly_overtime_after": 38.0}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, expectation) = send_json(
+        &app,
+        "GET",
+        &format!("{employee_uri}/expected-hours"),
+        Value::Null,
+    )
+    .await;
+    assert_eq!(expectation["schedule_id"], schedule_id);
+    assert_eq!(expectation["weekly_hours"], 40.0);
+    assert_eq!(expectation["daily_overtime_after"], 10.0);
+    assert_eq!(expectation["weekly_overtime_after"], 38.0);
+    assert_eq!(expectation["days"].as_array().unwrap().len(), 4);
+
+    let (status, _) = send_json(
+        &app,
+        "PUT",
+        &employee_uri,
+        json!({"schedule_id": uuid::Uuid::new_v4()}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, cleared) =
+        send_json(&app, "PUT", &employee_uri, json!({"schedule_id": null})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(cleared["schedule_id"].is_null());
+
+    employee["id_number"] = json!("WS-2");
+    employee["schedule_id"] = json!(schedule_id);
+    let (status, created) = send_json(&app, "POST", &employees_uri, employee).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created["schedule_id"], schedule_id);
+}